@@ -0,0 +1,43 @@
+// Copyright 2019 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT> or the Modified
+// BSD license <LICENSE-BSD or https://opensource.org/licenses/BSD-3-Clause>,
+// at your option. This file may not be copied, modified, or distributed
+// except according to those terms. Please review the Licences for the
+// specific language governing permissions and limitations relating to use
+// of the SAFE Network Software.
+
+#ifndef bindgen_safe_app_root
+#define bindgen_safe_app_root
+
+typedef void* App;
+typedef void* SignatureTx;
+
+#include "safe_core/arrays.h"
+#include "safe_core/safe_core.h"
+#include "safe_core/nfs.h"
+#include "safe_core/coins.h"
+#include "safe_core/ipc/req.h"
+#include "safe_core/ipc/resp.h"
+#include "ffi_utils.h"
+#include "safe_app/test_utils.h"
+#include "safe_app/mdata_info.h"
+#include "safe_app/mutable_data/metadata.h"
+#include "safe_app/safe_app.h"
+#include "safe_app/ipc.h"
+#include "safe_app/logging.h"
+#include "safe_app/access_container.h"
+#include "safe_app/object_cache.h"
+#include "safe_app/mutable_data/entries.h"
+#include "safe_app/crypto.h"
+#include "safe_app/coins.h"
+#include "safe_app/nfs.h"
+#include "safe_app/mutable_data/entry_actions.h"
+#include "safe_app/cipher_opt.h"
+#include "safe_app/immutable_data.h"
+#include "safe_app/mutable_data/permissions.h"
+#include "safe_app/mutable_data.h"
+
+
+#endif
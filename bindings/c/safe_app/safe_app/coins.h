@@ -0,0 +1,41 @@
+// Copyright 2019 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT> or the Modified
+// BSD license <LICENSE-BSD or https://opensource.org/licenses/BSD-3-Clause>,
+// at your option. This file may not be copied, modified, or distributed
+// except according to those terms. Please review the Licences for the
+// specific language governing permissions and limitations relating to use
+// of the SAFE Network Software.
+
+#ifndef bindgen_safe_appcoinsh
+#define bindgen_safe_appcoinsh
+
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+#include <stdint.h>
+#include <stdbool.h>
+
+/// Get the coin balance of the account the app is authorised for.////// Fails with `ERR_GET_BALANCE_DENIED` if the app was not granted the `get_balance` permission.////// Callback parameters: user data, error code, balancevoid app_get_balance(App const* app, void* user_data, void (*o_cb)(void* user_data, FfiResult const* result, uint64_t balance));
+
+/// Transfer `amount` coins from the account's balance to the balance at `destination`.////// Fails with `ERR_TRANSFER_COINS_DENIED` if the app was not granted the `transfer_coins`/// permission, which is also the case once the app has been given a coin allowance, and with/// `ERR_INSUFFICIENT_BALANCE` if the balance can't cover the amount.////// Callback parameters: user data, error code, transaction IDvoid app_transfer_coins(App const* app, XorNameArray const* destination, uint64_t amount, void* user_data, void (*o_cb)(void* user_data, FfiResult const* result, uint64_t transaction_id));
+
+/// Get the balance of the app's allowance wallet, the coins left of the allowance the user/// granted the app.////// Fails with `ERR_NO_SUCH_BALANCE` if the app hasn't been granted an allowance.////// Callback parameters: user data, error code, balancevoid app_allowance_balance(App const* app, void* user_data, void (*o_cb)(void* user_data, FfiResult const* result, uint64_t balance));
+
+/// Transfer `amount` coins from the app's allowance wallet to the balance at `destination`.////// Fails with `ERR_INSUFFICIENT_BALANCE` if what's left of the allowance can't cover the amount/// and with `ERR_NO_SUCH_BALANCE` if the app hasn't been granted an allowance.////// Callback parameters: user data, error code, transaction IDvoid app_allowance_transfer_coins(App const* app, XorNameArray const* destination, uint64_t amount, void* user_data, void (*o_cb)(void* user_data, FfiResult const* result, uint64_t transaction_id));
+
+/// Create a new coin balance owned by the key at `new_balance_owner_h`, preloading it with/// `amount` coins from the account's balance.////// Fails with `ERR_TRANSFER_COINS_DENIED` if the app is not permitted to spend from the balance/// and with `ERR_INSUFFICIENT_BALANCE` if the balance can't cover the amount and the fee.////// Callback parameters: user data, error code, transaction IDvoid app_create_balance(App const* app, SignPubKeyHandle new_balance_owner_h, uint64_t amount, void* user_data, void (*o_cb)(void* user_data, FfiResult const* result, uint64_t transaction_id));
+
+/// Get the transaction history of the account's coin balance, oldest first.////// Fails with `ERR_GET_BALANCE_DENIED` if the app was not granted the `get_balance` permission./// Only available against the mock vault, as vaults don't keep a queryable transaction history/// yet.////// Callback parameters: user data, error code, transaction records vector, vector sizevoid app_transaction_history(App const* app, void* user_data, void (*o_cb)(void* user_data, FfiResult const* result, TransactionRecord const* transactions, uintptr_t transactions_len));
+
+
+
+#ifdef __cplusplus
+}
+#endif
+
+
+#endif
@@ -0,0 +1,59 @@
+// Copyright 2019 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT> or the Modified
+// BSD license <LICENSE-BSD or https://opensource.org/licenses/BSD-3-Clause>,
+// at your option. This file may not be copied, modified, or distributed
+// except according to those terms. Please review the Licences for the
+// specific language governing permissions and limitations relating to use
+// of the SAFE Network Software.
+
+#ifndef bindgen_safe_appipch
+#define bindgen_safe_appipch
+
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+#include <stdint.h>
+#include <stdbool.h>
+
+/// Encode `AuthReq`.void encode_auth_req(AuthReq const* req, void* user_data, void (*o_cb)(void* user_data, FfiResult const* result, uint32_t req_id, char const* encoded));
+
+/// Encode `ContainersReq`.void encode_containers_req(ContainersReq const* req, void* user_data, void (*o_cb)(void* user_data, FfiResult const* result, uint32_t req_id, char const* encoded));
+
+/// Encode `PermissionsReq`.void encode_permissions_req(PermissionsReq const* req, void* user_data, void (*o_cb)(void* user_data, FfiResult const* result, uint32_t req_id, char const* encoded));
+
+/// Encode `AuthReq` for an unregistered client.void encode_unregistered_req(uint8_t const* extra_data, uintptr_t extra_data_len, void* user_data, void (*o_cb)(void* user_data, FfiResult const* result, uint32_t req_id, char const* encoded));
+
+/// Encode `ShareMDataReq`.void encode_share_mdata_req(ShareMDataRequest const* req, void* user_data, void (*o_cb)(void* user_data, FfiResult const* result, uint32_t req_id, char const* encoded));
+
+/// Encode `ShareADataReq`.void encode_share_adata_req(ShareADataRequest const* req, void* user_data, void (*o_cb)(void* user_data, FfiResult const* result, uint32_t req_id, char const* encoded));
+
+/// Encode `TransOwnershipReq`.void encode_trans_ownership_req(TransOwnershipRequest const* req, void* user_data, void (*o_cb)(void* user_data, FfiResult const* result, uint32_t req_id, char const* encoded));
+
+/// IPC response decoded by `decode_ipc_resp`. Only the fields of the response's `kind` are set,/// the others are null. Fields for new kinds of responses are appended, so callers built against/// an older version keep working.typedef struct DecodedIpcResp {
+///     Kind of the response, one of the `IPC_RESP_*` constants.    uint32_t kind;
+///     ID of the request the response answers.    uint32_t req_id;
+///     Set for `IPC_RESP_AUTH`.    AuthGranted const* auth_granted;
+///     Serialised bootstrap config, set for `IPC_RESP_UNREGISTERED`.    uint8_t const* serialised_cfg;
+///     Length of `serialised_cfg`.    uintptr_t serialised_cfg_len;
+} DecodedIpcResp;
+
+/// Decode IPC message.////// Only the responses this function has a callback for are decoded. Any other response is/// reported through `o_err`; use `decode_ipc_resp` to decode every kind of response.void decode_ipc_msg(char const* msg, void* user_data, void (*o_auth)(void* user_data, uint32_t req_id, AuthGranted const* auth_granted), void (*o_unregistered)(void* user_data, uint32_t req_id, uint8_t const* serialised_cfg, uintptr_t serialised_cfg_len), void (*o_containers)(void* user_data, uint32_t req_id), void (*o_share_mdata)(void* user_data, uint32_t req_id), void (*o_revoked)(void* user_data), void (*o_err)(void* user_data, FfiResult const* result, uint32_t req_id));
+
+/// Decode an IPC response of any kind and pass it to `o_resp`. The decoded response is only/// valid for the duration of the callback. Responses denying a request are reported through/// `o_err`.////// New kinds of responses are passed to the same callback, so unlike `decode_ipc_msg` this/// function keeps its signature as requests are added.void decode_ipc_resp(char const* msg, void* user_data, void (*o_resp)(void* user_data, DecodedIpcResp const* resp), void (*o_err)(void* user_data, FfiResult const* result, uint32_t req_id));
+
+/// Generate an ephemeral key pair for sealed IPC responses.////// The public key goes into `AuthReq::response_key`. The serialised secret key is needed to open/// the response with `open_sealed_ipc_msg`.void gen_response_key_pair(void* user_data, void (*o_cb)(void* user_data, FfiResult const* result, BlsPublicKey const* public_key, uint8_t const* secret_key, uintptr_t secret_key_len));
+
+/// Open a sealed IPC response to the request with the given ID.////// The response is decrypted with the serialised secret key obtained from/// `gen_response_key_pair`, and must be signed by `signer`: the public key of the user's account,/// pinned from an earlier response or given to the app out of band by the authenticator's/// `auth_account_public_key`. Apps which don't know the key yet pass a null `signer` to trust the/// signer of their first response, and should pin the key passed to the callback for the later/// ones. Responses to other requests are rejected, and so are unsealed responses. On success the/// callback receives the response in the legacy unsealed encoding, ready to be passed to/// `decode_ipc_resp`, along with the verified signer. Messages which aren't responses, like/// revocation notices, are passed through unchanged, with a null signer.void open_sealed_ipc_msg(char const* msg, uint8_t const* secret_key, uintptr_t secret_key_len, BlsPublicKey const* signer, uint32_t req_id, void* user_data, void (*o_cb)(void* user_data, FfiResult const* result, char const* msg, BlsPublicKey const* signer));
+
+
+
+#ifdef __cplusplus
+}
+#endif
+
+
+#endif
@@ -0,0 +1,49 @@
+// Copyright 2019 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT> or the Modified
+// BSD license <LICENSE-BSD or https://opensource.org/licenses/BSD-3-Clause>,
+// at your option. This file may not be copied, modified, or distributed
+// except according to those terms. Please review the Licences for the
+// specific language governing permissions and limitations relating to use
+// of the SAFE Network Software.
+
+#ifndef bindgen_safe_appsafe_apph
+#define bindgen_safe_appsafe_apph
+
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+#include <stdint.h>
+#include <stdbool.h>
+
+/// Create unregistered app./// The `user_data` parameter corresponds to the first parameter of the/// `o_cb` and `o_disconnect_notifier_cb` callbacks.void app_unregistered(uint8_t const* bootstrap_config, uintptr_t bootstrap_config_len, void* user_data, void (*o_disconnect_notifier_cb)(void* user_data), void (*o_cb)(void* user_data, FfiResult const* result, App* app));
+
+/// Create a registered app./// The `user_data` parameter corresponds to the first parameter of the/// `o_cb` and `o_disconnect_notifier_cb` callbacks.void app_registered(char const* app_id, AuthGranted const* auth_granted, void* user_data, void (*o_disconnect_notifier_cb)(void* user_data), void (*o_cb)(void* user_data, FfiResult const* result, App* app));
+
+/// Create a registered app whose requests are signed by the caller, e.g. with a hardware wallet/// holding the app's key, instead of with the secret key in `auth_granted`./// `o_sign` is called, possibly from another thread, with each message to sign and the channel/// to send the signature back on with `app_send_signature`./// The `user_data` parameter corresponds to the first parameter of the/// `o_sign`, `o_cb` and `o_disconnect_notifier_cb` callbacks, and must stay valid as long as/// the app.void app_registered_with_signer(char const* app_id, AuthGranted const* auth_granted, void* user_data, void (*o_sign)(void* user_data, uint8_t const* msg, uintptr_t msg_len, SignatureTx* signature_tx), void (*o_disconnect_notifier_cb)(void* user_data), void (*o_cb)(void* user_data, FfiResult const* result, App* app));
+
+/// Send the signature requested by `o_sign` of `app_registered_with_signer` back on/// `signature_tx`, which can't be used afterwards. Pass a null `signature` to refuse to sign.void app_send_signature(SignatureTx* signature_tx, Signature const* signature, void* user_data, void (*o_cb)(void* user_data, FfiResult const* result));
+
+/// Try to restore a failed connection with the network.void app_reconnect(App* app, void* user_data, void (*o_cb)(void* user_data, FfiResult const* result));
+
+/// Sets the path from which the `safe_core.config` file will be read.void app_set_config_dir_path(char const* new_path, void* user_data, void (*o_cb)(void* user_data, FfiResult const* result));
+
+/// Discard and clean up the previously allocated app instance./// Use this only if the app is obtained from one of the auth/// functions in this crate. Using `app` after a call to this/// function is undefined behaviour.void app_free(App* app);
+
+/// Resets the object cache. Removes all objects currently in the object cache/// and invalidates all existing object handles.void app_reset_object_cache(App* app, void* user_data, void (*o_cb)(void* user_data, FfiResult const* result));
+
+/// Returns the name of the app's container.void app_container_name(char const* app_id, void* user_data, void (*o_cb)(void* user_data, FfiResult const* result, char const* container_name));
+
+/// Returns true if this crate was compiled against mock-routing.bool app_is_mock(void);
+
+
+
+#ifdef __cplusplus
+}
+#endif
+
+
+#endif
@@ -0,0 +1,41 @@
+// Copyright 2019 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT> or the Modified
+// BSD license <LICENSE-BSD or https://opensource.org/licenses/BSD-3-Clause>,
+// at your option. This file may not be copied, modified, or distributed
+// except according to those terms. Please review the Licences for the
+// specific language governing permissions and limitations relating to use
+// of the SAFE Network Software.
+
+#ifndef bindgen_safe_corearraysh
+#define bindgen_safe_corearraysh
+
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+#include <stdint.h>
+#include <stdbool.h>
+
+/// Array containing public key bytes.typedef uint8_t const* AsymPublicKey;
+
+/// Array containing private key bytes.typedef uint8_t const* SymSecretKey;
+
+/// Array containing nonce bytes.typedef uint8_t const* SymNonce;
+
+/// Array containing BLS public key.typedef uint8_t const* BlsPublicKey;
+
+/// Array containing a BLS Signature.typedef uint8_t const* Signature;
+
+/// Array containing `XorName` bytes.typedef uint8_t const* XorNameArray;
+
+
+
+#ifdef __cplusplus
+}
+#endif
+
+
+#endif
@@ -0,0 +1,38 @@
+// Copyright 2019 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT> or the Modified
+// BSD license <LICENSE-BSD or https://opensource.org/licenses/BSD-3-Clause>,
+// at your option. This file may not be copied, modified, or distributed
+// except according to those terms. Please review the Licences for the
+// specific language governing permissions and limitations relating to use
+// of the SAFE Network Software.
+
+#ifndef bindgen_safe_corecoinsh
+#define bindgen_safe_corecoinsh
+
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+#include <stdint.h>
+#include <stdbool.h>
+
+/// FFI-wrapper for `TransactionRecord`.typedef struct TransactionRecord {
+///     Transaction ID.    uint64_t id;
+///     `true` if the coins were sent from the balance, `false` if they were received.    bool sent;
+///     Name of the balance on the other side of the transaction.    XorNameArray counterparty;
+///     Amount of coins transferred, in nano coins.    uint64_t amount;
+///     Time of the transaction (seconds part).    int64_t timestamp_sec;
+///     Time of the transaction (nanoseconds part).    uint32_t timestamp_nsec;
+} TransactionRecord;
+
+
+
+#ifdef __cplusplus
+}
+#endif
+
+
+#endif
@@ -0,0 +1,125 @@
+// Copyright 2019 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT> or the Modified
+// BSD license <LICENSE-BSD or https://opensource.org/licenses/BSD-3-Clause>,
+// at your option. This file may not be copied, modified, or distributed
+// except according to those terms. Please review the Licences for the
+// specific language governing permissions and limitations relating to use
+// of the SAFE Network Software.
+
+#ifndef bindgen_safe_coreipcreqh
+#define bindgen_safe_coreipcreqh
+
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+#include <stdint.h>
+#include <stdbool.h>
+
+/// Represents a requested set of changes to the permissions of a mutable data.typedef struct PermissionSet {
+///     How to modify the read permission.    bool read;
+///     How to modify the insert permission.    bool insert;
+///     How to modify the update permission.    bool update;
+///     How to modify the delete permission.    bool delete;
+///     How to modify the manage permissions permission.    bool manage_permissions;
+} PermissionSet;
+
+/// Represents an application ID in the process of asking permissions.typedef struct AppExchangeInfo {
+///     UTF-8 encoded id.    char const* id;
+///     Reserved by the frontend.///    ///     null if not present.    char const* scope;
+///     UTF-8 encoded application friendly-name.    char const* name;
+///     UTF-8 encoded application provider/vendor (e.g. MaidSafe).    char const* vendor;
+} AppExchangeInfo;
+
+/// Represents the set of permissions for a given containertypedef struct ContainerPermissions {
+///     The UTF-8 encoded id    char const* cont_name;
+///     The requested permission set    PermissionSet access;
+} ContainerPermissions;
+
+/// Represents an authorisation request.typedef struct AuthReq {
+///     The application identifier for this request.    AppExchangeInfo app;
+///     `true` if the app wants dedicated container for itself. `false` otherwise.    bool app_container;
+///     App has permission to transfer coins on behalf of the user.    bool app_permission_transfer_coins;
+///     App has permission to perform mutations on behalf of the user.    bool app_permission_perform_mutations;
+///     App has permission to read balance on behalf of the user.    bool app_permission_get_balance;
+///     Array of `ContainerPermissions`.    ContainerPermissions const* containers;
+///     Size of container permissions array.    uintptr_t containers_len;
+///     `true` if the response should be sealed to `response_key`.    bool seal_response;
+///     Ephemeral public key of the app. Ignored if `seal_response` is `false`.    BlsPublicKey response_key;
+///     Number of seconds the authorisation stays valid for, or `0` if it never expires.    uint64_t ttl;
+} AuthReq;
+
+/// Containers request.typedef struct ContainersReq {
+///     Exchange info.    AppExchangeInfo app;
+///     Requested containers.    ContainerPermissions const* containers;
+///     Size of requested containers array.    uintptr_t containers_len;
+} ContainersReq;
+
+/// Request to modify the permissions of an authorised app.typedef struct PermissionsReq {
+///     Exchange info.    AppExchangeInfo app;
+///     New permissions for the listed containers. A permission set with no permissions///     drops the access to the container.    ContainerPermissions const* containers;
+///     Size of containers array.    uintptr_t containers_len;
+///     `true` if the app permissions below should replace the current ones.    bool update_app_permissions;
+///     App has permission to transfer coins on behalf of the user.    bool app_permission_transfer_coins;
+///     App has permission to perform mutations on behalf of the user.    bool app_permission_perform_mutations;
+///     App has permission to read balance on behalf of the user.    bool app_permission_get_balance;
+} PermissionsReq;
+
+/// For use in `ShareMDataReq`. Represents a specific `MutableData` that is being shared.typedef struct ShareMData {
+///     The mutable data type.    uint64_t type_tag;
+///     The mutable data name.    XorNameArray name;
+///     The permissions being requested.    PermissionSet perms;
+} ShareMData;
+
+/// Represents a request to share mutable datatypedef struct ShareMDataRequest {
+///     Info about the app requesting shared access    AppExchangeInfo app;
+///     List of MD names & type tags and permissions that need to be shared    ShareMData const* mdata;
+///     Length of the mdata array    uintptr_t mdata_len;
+} ShareMDataRequest;
+
+/// Represents a requested set of permissions for an append-only data.typedef struct ADataPermissionSet {
+///     Whether to allow reading. Ignored for published data.    bool read;
+///     Whether to allow appending.    bool append;
+///     Whether to allow modifying permissions.    bool manage_permissions;
+} ADataPermissionSet;
+
+/// For use in `ShareADataReq`. Represents a specific `AppendOnlyData` that is being shared.typedef struct ShareAData {
+///     The append-only data name.    XorNameArray name;
+///     The append-only data type.    uint64_t type_tag;
+///     Whether the data is published.    bool published;
+///     Whether the data is sequenced.    bool sequenced;
+///     The permissions being requested.    ADataPermissionSet perms;
+} ShareAData;
+
+/// Represents a request to share append-only datatypedef struct ShareADataRequest {
+///     Info about the app requesting shared access    AppExchangeInfo app;
+///     List of AData addresses and permissions that need to be shared    ShareAData const* adata;
+///     Length of the adata array    uintptr_t adata_len;
+} ShareADataRequest;
+
+/// For use in `TransOwnershipReq`. Represents a specific data object whose owner should change.typedef struct TransOwnership {
+///     The data name.    XorNameArray name;
+///     The data type.    uint64_t type_tag;
+///     Whether the data is an append-only data (otherwise it is a mutable data).    bool append_only;
+///     Whether the data is published. Ignored for mutable data.    bool published;
+///     Whether the data is sequenced.    bool sequenced;
+///     The key that should own the data.    BlsPublicKey new_owner;
+} TransOwnership;
+
+/// Represents a request to transfer the ownership of datatypedef struct TransOwnershipRequest {
+///     Info about the app requesting the transfer    AppExchangeInfo app;
+///     List of data addresses and their new owners    TransOwnership const* data;
+///     Length of the data array    uintptr_t data_len;
+} TransOwnershipRequest;
+
+
+
+#ifdef __cplusplus
+}
+#endif
+
+
+#endif
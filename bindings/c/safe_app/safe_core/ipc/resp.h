@@ -0,0 +1,99 @@
+// Copyright 2019 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT> or the Modified
+// BSD license <LICENSE-BSD or https://opensource.org/licenses/BSD-3-Clause>,
+// at your option. This file may not be copied, modified, or distributed
+// except according to those terms. Please review the Licences for the
+// specific language governing permissions and limitations relating to use
+// of the SAFE Network Software.
+
+#ifndef bindgen_safe_coreipcresph
+#define bindgen_safe_coreipcresph
+
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+#include <stdint.h>
+#include <stdbool.h>
+
+/// Represents the needed keys to work with the data.typedef struct AppKeys {
+///     Application identity.    uint8_t const* full_id;
+///     `full_id`s length    uintptr_t full_id_len;
+///     Data symmetric encryption key.    SymSecretKey enc_key;
+///     Asymmetric enc public key.    AsymPublicKey enc_public_key;
+///     Pointer to serialized asymmetric encryption private key.    uint8_t const* enc_secret_key;
+///     Asymmetric enc private key's length.    uintptr_t enc_secret_key_len;
+} AppKeys;
+
+/// Access container info.typedef struct AccessContInfo {
+///     ID    XorNameArray id;
+///     Type tag    uint64_t tag;
+///     Nonce    SymNonce nonce;
+} AccessContInfo;
+
+/// Information about a container (name, `MDataInfo` and permissions).typedef struct ContainerInfo {
+///     Container name as UTF-8 encoded null-terminated string.    char const* name;
+///     Container's `MDataInfo`.    MDataInfo mdata_info;
+///     App's permissions in the container.    PermissionSet permissions;
+} ContainerInfo;
+
+/// Access container entry for a single app.typedef struct AccessContainerEntry {
+///     Pointer to the array of `ContainerInfo`.    ContainerInfo const* containers;
+///     Size of the array.    uintptr_t containers_len;
+} AccessContainerEntry;
+
+/// Represents the authentication response.typedef struct AuthGranted {
+///     The access keys.    AppKeys app_keys;
+///     Access container info.    AccessContInfo access_container_info;
+///     Access container entry.    AccessContainerEntry access_container_entry;
+///     QuicP2P bootstrap config.    uint8_t const* bootstrap_config;
+///     `bootstrap_config`'s length.    uintptr_t bootstrap_config_len;
+} AuthGranted;
+
+/// Information about an application that has access to an MD through `sign_key`.typedef struct AppAccess {
+///     App's or user's public key.    BlsPublicKey sign_key;
+///     A list of permissions.    PermissionSet permissions;
+///     App's user-facing name.///    ///     null if not present.    char const* name;
+///     App id.///    ///     null if not present.    char const* app_id;
+} AppAccess;
+
+/// User metadata for mutable data.typedef struct MetadataResponse {
+///     Name or purpose of this mutable data.///    ///     null if not present.    char const* name;
+///     Description of how this mutable data should or should not be shared.///    ///     null if not present.    char const* description;
+///     Xor name of this struct's corresponding MData object.    XorNameArray xor_name;
+///     Type tag of this struct's corresponding MData object.    uint64_t type_tag;
+} MetadataResponse;
+
+/// Represents an FFI-safe mutable data key.typedef struct MDataKey {
+///     Pointer to the key.    uint8_t const* key;
+///     Size of the key.    uintptr_t key_len;
+} MDataKey;
+
+/// Represents the FFI-safe mutable data value.typedef struct MDataValue {
+///     Pointer to the content.    uint8_t const* content;
+///     Size of the content.    uintptr_t content_len;
+///     Entry version.    uint64_t entry_version;
+} MDataValue;
+
+/// Represents an FFI-safe mutable data (key, value) entry.typedef struct MDataEntry {
+///     Mutable data key.    MDataKey key;
+///     Mutable data value.    MDataValue value;
+} MDataEntry;
+
+/// Description of an ownership change, shown to the user before granting a transfer.typedef struct OwnershipChange {
+///     Key currently owning the data.    BlsPublicKey current_owner;
+///     Key that will own the data.    BlsPublicKey new_owner;
+///     Human-readable description of the change.    char const* description;
+} OwnershipChange;
+
+
+
+#ifdef __cplusplus
+}
+#endif
+
+
+#endif
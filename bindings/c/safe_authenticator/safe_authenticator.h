@@ -0,0 +1,34 @@
+// Copyright 2019 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT> or the Modified
+// BSD license <LICENSE-BSD or https://opensource.org/licenses/BSD-3-Clause>,
+// at your option. This file may not be copied, modified, or distributed
+// except according to those terms. Please review the Licences for the
+// specific language governing permissions and limitations relating to use
+// of the SAFE Network Software.
+
+#ifndef bindgen_safe_authenticator_root
+#define bindgen_safe_authenticator_root
+
+typedef void* Authenticator;
+typedef void* SignatureTx;
+
+#include "ffi_utils.h"
+#include "safe_authenticator/audit.h"
+#include "safe_authenticator/devices.h"
+#include "safe_core/arrays.h"
+#include "safe_authenticator/safe_authenticator.h"
+#include "safe_authenticator/deletion.h"
+#include "safe_core/ipc/req.h"
+#include "safe_core/coins.h"
+#include "safe_authenticator/coins.h"
+#include "safe_core/nfs.h"
+#include "safe_core/safe_core.h"
+#include "safe_core/ipc/resp.h"
+#include "safe_authenticator/apps.h"
+#include "safe_authenticator/ipc.h"
+#include "safe_authenticator/logging.h"
+
+
+#endif
@@ -0,0 +1,60 @@
+// Copyright 2019 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT> or the Modified
+// BSD license <LICENSE-BSD or https://opensource.org/licenses/BSD-3-Clause>,
+// at your option. This file may not be copied, modified, or distributed
+// except according to those terms. Please review the Licences for the
+// specific language governing permissions and limitations relating to use
+// of the SAFE Network Software.
+
+#ifndef bindgen_safe_authenticatorappsh
+#define bindgen_safe_authenticatorappsh
+
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+#include <stdint.h>
+#include <stdbool.h>
+
+/// Application registered in the authenticator.typedef struct RegisteredApp {
+///     Unique application identifier.    AppExchangeInfo app_info;
+///     List of containers that this application has access to.    ContainerPermissions const* containers;
+///     Length of the containers array.    uintptr_t containers_len;
+///     Permissions allowed for the application    AppPermissions app_permissions;
+///     Time the authorisation of the application expires at, in seconds since the Unix epoch.///     `0` if it never expires.    int64_t expires_at_sec;
+} RegisteredApp;
+
+/// Resources consumed by an application registered in the authenticator.typedef struct AppUsageSummary {
+///     Unique application identifier.    AppExchangeInfo app_info;
+///     Number of successful mutations performed by the application.    uint64_t mutations;
+///     Total size of the mutation requests made by the application, in bytes.    uint64_t bytes;
+///     Amount of coins spent on the application's mutations, in nano coins.    uint64_t spent;
+} AppUsageSummary;
+
+/// Permission for Appstypedef struct AppPermissions {
+///     Whether this app has permissions to transfer coins.    bool transfer_coins;
+///     Whether this app has permissions to perform mutations.    bool perform_mutations;
+///     Whether this app has permissions to read the coin balance.    bool get_balance;
+} AppPermissions;
+
+/// Remove a revoked app from the authenticator config.void auth_rm_revoked_app(Authenticator const* auth, char const* app_id, void* user_data, void (*o_cb)(void* user_data, FfiResult const* result));
+
+/// Get a list of apps revoked from authenticator.void auth_revoked_apps(Authenticator const* auth, void* user_data, void (*o_cb)(void* user_data, FfiResult const* result, AppExchangeInfo const* app_exchange_info, uintptr_t app_exchange_info_len));
+
+/// Get a list of apps registered in authenticator.void auth_registered_apps(Authenticator const* auth, void* user_data, void (*o_cb)(void* user_data, FfiResult const* result, RegisteredApp const* registered_app, uintptr_t registered_app_len));
+
+/// Get the resources consumed by each app registered in the authenticator, including revoked/// apps.////// Only available against the mock vault, as vaults don't attribute mutations to app keys yet.////// Callback parameters: user data, error code, app usage vector, vector sizevoid auth_apps_usage(Authenticator const* auth, void* user_data, void (*o_cb)(void* user_data, FfiResult const* result, AppUsageSummary const* app_usage, uintptr_t app_usage_len));
+
+/// Return a list of apps having access to an arbitrary MD object./// `md_name` and `md_type_tag` together correspond to a single MD.void auth_apps_accessing_mutable_data(Authenticator const* auth, XorNameArray const* md_name, uint64_t md_type_tag, void* user_data, void (*o_cb)(void* user_data, FfiResult const* result, AppAccess const* app_access, uintptr_t app_access_len));
+
+
+
+#ifdef __cplusplus
+}
+#endif
+
+
+#endif
@@ -0,0 +1,40 @@
+// Copyright 2019 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT> or the Modified
+// BSD license <LICENSE-BSD or https://opensource.org/licenses/BSD-3-Clause>,
+// at your option. This file may not be copied, modified, or distributed
+// except according to those terms. Please review the Licences for the
+// specific language governing permissions and limitations relating to use
+// of the SAFE Network Software.
+
+#ifndef bindgen_safe_authenticatoraudith
+#define bindgen_safe_authenticatoraudith
+
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+#include <stdint.h>
+#include <stdbool.h>
+
+/// Entry of the audit log, recording a decision of the authenticator about an app.typedef struct AuditEntry {
+///     Time the decision was made, in seconds since the Unix epoch.    int64_t timestamp_sec;
+///     Nanoseconds part of the time the decision was made.    uint32_t timestamp_nsec;
+///     ID of the app the decision is about.    char const* app_id;
+///     The decision, encoded as JSON.    char const* decision;
+} AuditEntry;
+
+/// Get the audit log of the decisions made by the authenticator, oldest first.////// Callback parameters: user data, error code, audit entries vector, vector sizevoid auth_audit_log(Authenticator const* auth, void* user_data, void (*o_cb)(void* user_data, FfiResult const* result, AuditEntry const* entries, uintptr_t entries_len));
+
+/// Export the audit log of the decisions made by the authenticator as a JSON array, oldest/// first.////// Callback parameters: user data, error code, JSON stringvoid auth_export_audit_log(Authenticator const* auth, void* user_data, void (*o_cb)(void* user_data, FfiResult const* result, char const* json));
+
+
+
+#ifdef __cplusplus
+}
+#endif
+
+
+#endif
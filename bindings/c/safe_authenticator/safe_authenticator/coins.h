@@ -0,0 +1,49 @@
+// Copyright 2019 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT> or the Modified
+// BSD license <LICENSE-BSD or https://opensource.org/licenses/BSD-3-Clause>,
+// at your option. This file may not be copied, modified, or distributed
+// except according to those terms. Please review the Licences for the
+// specific language governing permissions and limitations relating to use
+// of the SAFE Network Software.
+
+#ifndef bindgen_safe_authenticatorcoinsh
+#define bindgen_safe_authenticatorcoinsh
+
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+#include <stdint.h>
+#include <stdbool.h>
+
+/// Spending of an app measured against its coin allowance. Amounts are in nano coins.typedef struct AppAllowanceStatus {
+///     App ID.    char const* app_id;
+///     Amount of coins the app may spend.    uint64_t limit;
+///     `true` if the limit applies per day, `false` if it applies in total.    bool daily;
+///     Amount of coins the app has spent within the current period.    uint64_t spent;
+///     Amount of coins left in the app's allowance wallet.    uint64_t remaining;
+///     `true` if the app has spent its whole allowance.    bool exhausted;
+///     `true` if the app is close to its limit and the user should be notified.    bool near_limit;
+} AppAllowanceStatus;
+
+/// Grant a coin allowance of `limit` nano coins to a registered app, or change the limit of its/// existing allowance. If `daily` is `true` the limit applies to each day, otherwise it applies/// to everything the app spends from now on. The allowance is moved from the account's balance/// to the app's allowance wallet, which is the only balance the app can spend from afterwards.void auth_set_app_allowance(Authenticator const* auth, char const* app_id, uint64_t limit, bool daily, void* user_data, void (*o_cb)(void* user_data, FfiResult const* result));
+
+/// Remove the coin allowance of an app. Coins left in its allowance wallet are moved back to the/// account's balance.void auth_rm_app_allowance(Authenticator const* auth, char const* app_id, void* user_data, void (*o_cb)(void* user_data, FfiResult const* result));
+
+/// Get the spending of every app with a coin allowance, refilling any daily allowances that are/// due first. Apps that are `near_limit` should be brought to the user's attention.////// Callback parameters: user data, error code, allowance statuses vector, vector sizevoid auth_app_allowances(Authenticator const* auth, void* user_data, void (*o_cb)(void* user_data, FfiResult const* result, AppAllowanceStatus const* statuses, uintptr_t statuses_len));
+
+/// Register `o_near_limit` to be called with the allowance status of each app that comes near the/// limit of its coin allowance, replacing any function registered before. The apps already near/// their limit are reported right away. Apps spend without going through the authenticator, so/// their spending is noticed on login, whenever `auth_app_allowances` is called and after an/// allowance is changed.////// Callback parameters: user data, error codevoid auth_set_near_limit_observer(Authenticator const* auth, void* user_data, void (*o_near_limit)(void* user_data, AppAllowanceStatus const* status), void (*o_cb)(void* user_data, FfiResult const* result));
+
+/// Get the transaction history of the account's coin balance, oldest first.////// Callback parameters: user data, error code, transaction records vector, vector sizevoid auth_transaction_history(Authenticator const* auth, void* user_data, void (*o_cb)(void* user_data, FfiResult const* result, TransactionRecord const* transactions, uintptr_t transactions_len));
+
+
+
+#ifdef __cplusplus
+}
+#endif
+
+
+#endif
@@ -0,0 +1,43 @@
+// Copyright 2019 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT> or the Modified
+// BSD license <LICENSE-BSD or https://opensource.org/licenses/BSD-3-Clause>,
+// at your option. This file may not be copied, modified, or distributed
+// except according to those terms. Please review the Licences for the
+// specific language governing permissions and limitations relating to use
+// of the SAFE Network Software.
+
+#ifndef bindgen_safe_authenticatordeletionh
+#define bindgen_safe_authenticatordeletionh
+
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+#include <stdint.h>
+#include <stdbool.h>
+
+/// Summary of what deleting the account removes, or would remove in case of a dry run.typedef struct DeletionReport {
+///     Number of containers, including the access container and the config root.    uintptr_t mdata_count;
+///     Number of unpublished immutable data chunks holding the contents of files.    uintptr_t idata_count;
+///     Number of containers that are kept, as they're owned by other keys.    uintptr_t kept_mdata_count;
+///     Number of files that are kept, as they're published or owned by other keys.    uintptr_t kept_files_count;
+///     Number of auth keys of apps.    uintptr_t auth_keys_count;
+///     Nano coins transferred to the destination.    uint64_t balance;
+///     Number of login packets that are wiped.    uintptr_t login_packets_count;
+///     Number of append-only data, including the audit log.    uintptr_t adata_count;
+///     Number of append-only data that is kept, as it's published or owned by other keys.    uintptr_t kept_adata_count;
+} DeletionReport;
+
+/// Delete the logged in account, transferring its coin balance to the balance at `destination`./// `password` must match the password the account is currently encrypted with. If `dry_run` is/// `true`, nothing is changed and the report lists what would be deleted. The account can't be/// logged into anymore once the deletion succeeded.////// Callback parameters: user data, error code, deletion reportvoid auth_delete_account(Authenticator const* auth, char const* password, XorNameArray const* destination, bool dry_run, void* user_data, void (*o_cb)(void* user_data, FfiResult const* result, DeletionReport const* report));
+
+
+
+#ifdef __cplusplus
+}
+#endif
+
+
+#endif
@@ -0,0 +1,42 @@
+// Copyright 2019 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT> or the Modified
+// BSD license <LICENSE-BSD or https://opensource.org/licenses/BSD-3-Clause>,
+// at your option. This file may not be copied, modified, or distributed
+// except according to those terms. Please review the Licences for the
+// specific language governing permissions and limitations relating to use
+// of the SAFE Network Software.
+
+#ifndef bindgen_safe_authenticatordevicesh
+#define bindgen_safe_authenticatordevicesh
+
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+#include <stdint.h>
+#include <stdbool.h>
+
+/// Device registered to use the account.typedef struct Device {
+///     ID of the device.    DeviceId id;
+///     Name of the device.    char const* name;
+///     Time the device was registered at, in seconds since the Unix epoch.    int64_t registered_at_sec;
+///     Time the device last logged in at, in seconds since the Unix epoch.    int64_t last_login_sec;
+} Device;
+
+/// Register the device the authenticator runs on with the given name. The device key passed to/// the callback has to be stored by the device, as it's needed to log in with `login_device`.////// Callback parameters: user data, error code, device keyvoid auth_register_device(Authenticator const* auth, char const* name, void* user_data, void (*o_cb)(void* user_data, FfiResult const* result, DeviceKey const* device_key));
+
+/// Get the devices registered to use the account.////// Callback parameters: user data, error code, devices vector, vector sizevoid auth_devices(Authenticator const* auth, void* user_data, void (*o_cb)(void* user_data, FfiResult const* result, Device const* devices, uintptr_t devices_len));
+
+/// Log out the device with the given ID, so that it can't log in with its device key anymore./// The client keys are rotated as well, ending the sessions the device has open. Other sessions/// of the account, including other authenticators, have to log in again too.void auth_log_out_device(Authenticator const* auth, DeviceId const* device_id, void* user_data, void (*o_cb)(void* user_data, FfiResult const* result));
+
+
+
+#ifdef __cplusplus
+}
+#endif
+
+
+#endif
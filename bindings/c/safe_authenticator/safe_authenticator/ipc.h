@@ -0,0 +1,74 @@
+// Copyright 2019 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT> or the Modified
+// BSD license <LICENSE-BSD or https://opensource.org/licenses/BSD-3-Clause>,
+// at your option. This file may not be copied, modified, or distributed
+// except according to those terms. Please review the Licences for the
+// specific language governing permissions and limitations relating to use
+// of the SAFE Network Software.
+
+#ifndef bindgen_safe_authenticatoripch
+#define bindgen_safe_authenticatoripch
+
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+#include <stdint.h>
+#include <stdbool.h>
+
+/// Decodes a given encoded IPC message without requiring an authorised account.void auth_unregistered_decode_ipc_msg(char const* msg, void* user_data, void (*o_unregistered)(void* user_data, uint32_t req_id, uint8_t const* extra_data, uintptr_t extra_data_len), void (*o_err)(void* user_data, FfiResult const* result, char const* response));
+
+/// IPC request decoded by `auth_decode_ipc_req`. Only the fields of the request's `kind` are set,/// the others are null. Fields for new kinds of requests are appended, so callers built against/// an older version keep working.typedef struct DecodedIpcReq {
+///     Kind of the request, one of the `IPC_REQ_*` constants.    uint32_t kind;
+///     Request ID.    uint32_t req_id;
+///     Set for `IPC_REQ_AUTH`.    AuthReq const* auth;
+///     Set for `IPC_REQ_CONTAINERS`.    ContainersReq const* containers;
+///     Set for `IPC_REQ_UNREGISTERED`.    uint8_t const* extra_data;
+///     Length of `extra_data`.    uintptr_t extra_data_len;
+///     Set for `IPC_REQ_SHARE_MDATA`.    ShareMDataRequest const* share_mdata;
+///     Metadata of the shared mutable data, set for `IPC_REQ_SHARE_MDATA`.    MetadataResponse const* metadata;
+///     Length of `metadata`.    uintptr_t metadata_len;
+///     Set for `IPC_REQ_SHARE_ADATA`.    ShareADataRequest const* share_adata;
+///     Set for `IPC_REQ_TRANS_OWNERSHIP`.    TransOwnershipRequest const* trans_ownership;
+///     Ownership changes the request asks for, set for `IPC_REQ_TRANS_OWNERSHIP`.    OwnershipChange const* ownership_changes;
+///     Length of `ownership_changes`.    uintptr_t ownership_changes_len;
+///     Set for `IPC_REQ_PERMISSIONS`.    PermissionsReq const* permissions;
+} DecodedIpcReq;
+
+/// Decodes a given encoded IPC message and calls a corresponding callback.////// Only the requests this function has a callback for are decoded. Any other request is reported/// through `o_err`; use `auth_decode_ipc_req` or `auth_decode_ipc_req_by_kind` to decode every kind/// of request.void auth_decode_ipc_msg(Authenticator const* auth, char const* msg, void* user_data, void (*o_auth)(void* user_data, uint32_t req_id, AuthReq const* req), void (*o_containers)(void* user_data, uint32_t req_id, ContainersReq const* req), void (*o_unregistered)(void* user_data, uint32_t req_id, uint8_t const* extra_data, uintptr_t extra_data_len), void (*o_share_mdata)(void* user_data, uint32_t req_id, ShareMDataRequest const* req, MetadataResponse const* metadata, uintptr_t metadata_len), void (*o_err)(void* user_data, FfiResult const* result, char const* response));
+
+/// Decodes a given encoded IPC request of any kind and passes it to `o_req`. The decoded request/// is only valid for the duration of the callback.////// New kinds of requests are passed to the same callback, so unlike `auth_decode_ipc_msg` this/// function keeps its signature as requests are added.void auth_decode_ipc_req(Authenticator const* auth, char const* msg, void* user_data, void (*o_req)(void* user_data, DecodedIpcReq const* req), void (*o_err)(void* user_data, FfiResult const* result, char const* response));
+
+/// Decodes a given encoded IPC request and calls the callback of its kind. Decodes the same/// requests as `auth_decode_ipc_req`, for bindings that can't read `DecodedIpcReq`.////// A callback is added here with every new kind of request; until then the request is reported/// through `o_err`, as `auth_decode_ipc_msg` does.void auth_decode_ipc_req_by_kind(Authenticator const* auth, char const* msg, void* user_data, void (*o_auth)(void* user_data, uint32_t req_id, AuthReq const* req), void (*o_containers)(void* user_data, uint32_t req_id, ContainersReq const* req), void (*o_unregistered)(void* user_data, uint32_t req_id, uint8_t const* extra_data, uintptr_t extra_data_len), void (*o_share_mdata)(void* user_data, uint32_t req_id, ShareMDataRequest const* req, MetadataResponse const* metadata, uintptr_t metadata_len), void (*o_share_adata)(void* user_data, uint32_t req_id, ShareADataRequest const* req), void (*o_trans_ownership)(void* user_data, uint32_t req_id, TransOwnershipRequest const* req, OwnershipChange const* changes, uintptr_t changes_len), void (*o_permissions)(void* user_data, uint32_t req_id, PermissionsReq const* req), void (*o_err)(void* user_data, FfiResult const* result, char const* response));
+
+/// Revoke app access.void auth_revoke_app(Authenticator const* auth, char const* app_id, void* user_data, void (*o_cb)(void* user_data, FfiResult const* result, char const* response));
+
+/// Flush the revocation queue.void auth_flush_app_revocation_queue(Authenticator const* auth, void* user_data, void (*o_cb)(void* user_data, FfiResult const* result));
+
+/// Revoke the apps whose authorisation has expired.////// Should be called periodically while the user is logged in.void auth_revoke_expired_apps(Authenticator const* auth, void* user_data, void (*o_cb)(void* user_data, FfiResult const* result));
+
+/// Encodes a response to unregistered client authentication request.////// The response is encoded in the legacy format, as unregistered requests are decoded without an/// authenticator to remember their format.void encode_unregistered_resp(uint32_t req_id, bool is_granted, void* user_data, void (*o_cb)(void* user_data, FfiResult const* result, char const* response));
+
+/// Provides and encodes an Authenticator response.void encode_auth_resp(Authenticator const* auth, AuthReq const* req, uint32_t req_id, bool is_granted, void* user_data, void (*o_cb)(void* user_data, FfiResult const* result, char const* response));
+
+/// Update containers permissions for an App.void encode_containers_resp(Authenticator const* auth, ContainersReq const* req, uint32_t req_id, bool is_granted, void* user_data, void (*o_cb)(void* user_data, FfiResult const* result, char const* response));
+
+/// Modify the permissions of an app.void encode_permissions_resp(Authenticator const* auth, PermissionsReq const* req, uint32_t req_id, bool is_granted, void* user_data, void (*o_cb)(void* user_data, FfiResult const* result, char const* response));
+
+/// Encode share mutable data response.void encode_share_mdata_resp(Authenticator const* auth, ShareMDataRequest const* req, uint32_t req_id, bool is_granted, void* user_data, void (*o_cb)(void* user_data, FfiResult const* result, char const* response));
+
+/// Encode share append-only data response.void encode_share_adata_resp(Authenticator const* auth, ShareADataRequest const* req, uint32_t req_id, bool is_granted, void* user_data, void (*o_cb)(void* user_data, FfiResult const* result, char const* response));
+
+/// Encode transfer ownership response.void encode_trans_ownership_resp(Authenticator const* auth, TransOwnershipRequest const* req, uint32_t req_id, bool is_granted, void* user_data, void (*o_cb)(void* user_data, FfiResult const* result, char const* response));
+
+
+
+#ifdef __cplusplus
+}
+#endif
+
+
+#endif
@@ -0,0 +1,67 @@
+// Copyright 2019 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT> or the Modified
+// BSD license <LICENSE-BSD or https://opensource.org/licenses/BSD-3-Clause>,
+// at your option. This file may not be copied, modified, or distributed
+// except according to those terms. Please review the Licences for the
+// specific language governing permissions and limitations relating to use
+// of the SAFE Network Software.
+
+#ifndef bindgen_safe_authenticatorsafe_authenticatorh
+#define bindgen_safe_authenticatorsafe_authenticatorh
+
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+#include <stdint.h>
+#include <stdbool.h>
+
+/// Create a registered client. This or any one of the other companion/// functions to get an authenticator instance must be called before initiating any/// operation allowed by this module. The `user_data` parameter corresponds to the/// first parameter of the `o_cb` and `o_disconnect_notifier_cb` callbacks.////// No recovery phrase is set up for the account; `create_acc_with_recovery` sets one up as part/// of the account creation.void create_acc(char const* account_locator, char const* account_password, void* user_data, void (*o_disconnect_notifier_cb)(void* user_data), void (*o_cb)(void* user_data, FfiResult const* result, Authenticator* authenticator));
+
+/// Create a registered client and set up a recovery phrase for it, which should be shown to the/// user to be written down. The `user_data` parameter corresponds to the first parameter of the/// `o_cb` and `o_disconnect_notifier_cb` callbacks.////// If the phrase can't be set up, the account is created nonetheless and the phrase is null, in/// which case `auth_set_up_recovery` should be retried.////// Callback parameters: user data, error code, authenticator, recovery phrasevoid create_acc_with_recovery(char const* account_locator, char const* account_password, void* user_data, void (*o_disconnect_notifier_cb)(void* user_data), void (*o_cb)(void* user_data, FfiResult const* result, Authenticator* authenticator, char const* recovery_phrase));
+
+/// Log into a registered account. This or any one of the other companion/// functions to get an authenticator instance must be called before initiating/// any operation allowed for authenticator. The `user_data` parameter corresponds to the/// first parameter of the `o_cb` and `o_disconnect_notifier_cb` callbacks.void login(char const* account_locator, char const* account_password, void* user_data, void (*o_disconnect_notifier_cb)(void* user_data), void (*o_cb)(void* user_data, FfiResult const* result, Authenticator* authenticaor));
+
+/// Log into a registered account whose requests are signed by the caller, e.g. with a hardware/// wallet holding the account's key, instead of with the secret key stored in the account./// `public_id` is the serialised public ID of the account, as returned by/// `auth_account_public_id`. `o_sign` is called, possibly from another thread, with each/// message to sign and the channel to send the signature back on with `auth_send_signature`./// The `user_data` parameter corresponds to the first parameter of the `o_sign`, `o_cb` and/// `o_disconnect_notifier_cb` callbacks, and must stay valid as long as the authenticator.void login_with_signer(char const* account_locator, char const* account_password, uint8_t const* public_id, uintptr_t public_id_len, void* user_data, void (*o_sign)(void* user_data, uint8_t const* msg, uintptr_t msg_len, SignatureTx* signature_tx), void (*o_disconnect_notifier_cb)(void* user_data), void (*o_cb)(void* user_data, FfiResult const* result, Authenticator* authenticaor));
+
+/// Send the signature requested by `o_sign` of `login_with_signer` back on `signature_tx`,/// which can't be used afterwards. Pass a null `signature` to refuse to sign.void auth_send_signature(SignatureTx* signature_tx, Signature const* signature, void* user_data, void (*o_cb)(void* user_data, FfiResult const* result));
+
+/// Log into a registered account from a registered device, using the device key obtained when/// registering the device with `auth_register_device`. Fails if the device was logged out. The/// `user_data` parameter corresponds to the first parameter of the `o_cb` and/// `o_disconnect_notifier_cb` callbacks.void login_device(char const* account_locator, char const* account_password, DeviceKey const* device_key, void* user_data, void (*o_disconnect_notifier_cb)(void* user_data), void (*o_cb)(void* user_data, FfiResult const* result, Authenticator* authenticator));
+
+/// Regain access to a registered account using its recovery phrase, replacing its password with/// `new_password`. This or any one of the other companion functions to get an authenticator/// instance must be called before initiating any operation allowed for authenticator. The/// `user_data` parameter corresponds to the first parameter of the `o_cb` and/// `o_disconnect_notifier_cb` callbacks.void recover_account(char const* recovery_phrase, char const* new_password, void* user_data, void (*o_disconnect_notifier_cb)(void* user_data), void (*o_cb)(void* user_data, FfiResult const* result, Authenticator* authenticator));
+
+/// Regain access to a registered account using a backup exported with `auth_export_backup`,/// replacing its password with `new_password`. The backup is verified against the network first./// This or any one of the other companion functions to get an authenticator instance must be/// called before initiating any operation allowed for authenticator. The `user_data` parameter/// corresponds to the first parameter of the `o_cb` and `o_disconnect_notifier_cb` callbacks.void import_backup(uint8_t const* backup, uintptr_t backup_len, char const* passphrase, char const* new_password, void* user_data, void (*o_disconnect_notifier_cb)(void* user_data), void (*o_cb)(void* user_data, FfiResult const* result, Authenticator* authenticator));
+
+/// Set up a new recovery phrase for the logged in account. A previously set up phrase stops/// working.////// Callback parameters: user data, error code, recovery phrasevoid auth_set_up_recovery(Authenticator const* auth, void* user_data, void (*o_cb)(void* user_data, FfiResult const* result, char const* recovery_phrase));
+
+/// Export a backup of the logged in account, encrypted with `passphrase`. The backup can be used/// with `import_backup` to regain access to the account.////// Callback parameters: user data, error code, backup, backup sizevoid auth_export_backup(Authenticator const* auth, char const* passphrase, void* user_data, void (*o_cb)(void* user_data, FfiResult const* result, uint8_t const* backup, uintptr_t backup_len));
+
+/// Change the password of the logged in account. The current password is required to confirm/// the change.void auth_change_password(Authenticator const* auth, char const* current_password, char const* new_password, void* user_data, void (*o_cb)(void* user_data, FfiResult const* result));
+
+/// Move the logged in account to a new locator. The password is required to confirm the/// change.void auth_change_locator(Authenticator const* auth, char const* new_locator, char const* password, void* user_data, void (*o_cb)(void* user_data, FfiResult const* result));
+
+/// Replace the client keys of the logged in account with new ones. Calling this after an/// interrupted rotation resumes it.void auth_rotate_client_keys(Authenticator const* auth, void* user_data, void (*o_cb)(void* user_data, FfiResult const* result));
+
+/// Try to restore a failed connection with the network.void auth_reconnect(Authenticator* auth, void* user_data, void (*o_cb)(void* user_data, FfiResult const* result));
+
+/// Returns the public key of the user's account, which signs sealed IPC responses.////// Apps given this key out of band can verify the responses with `open_sealed_ipc_msg`.void auth_account_public_key(Authenticator const* auth, void* user_data, void (*o_cb)(void* user_data, FfiResult const* result, BlsPublicKey const* public_key));
+
+/// Returns the serialised public ID of the user's account, which `login_with_signer` needs to/// log in before the account is fetched.void auth_account_public_id(Authenticator const* auth, void* user_data, void (*o_cb)(void* user_data, FfiResult const* result, uint8_t const* public_id, uintptr_t public_id_len));
+
+/// Sets the path from which the `safe_core.config` file will be read.void auth_set_config_dir_path(char const* new_path, void* user_data, void (*o_cb)(void* user_data, FfiResult const* result));
+
+/// Discard and clean up the previously allocated authenticator instance./// Use this only if the authenticator is obtained from one of the auth/// functions in this crate (`create_acc` or `login`)./// Using `auth` after a call to this function is undefined behaviour.void auth_free(Authenticator* auth);
+
+/// Returns true if this crate was compiled against mock-routing.bool auth_is_mock(void);
+
+
+
+#ifdef __cplusplus
+}
+#endif
+
+
+#endif
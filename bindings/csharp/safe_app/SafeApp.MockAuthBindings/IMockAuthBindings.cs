@@ -0,0 +1,23 @@
+// Copyright 2019 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT> or the Modified
+// BSD license <LICENSE-BSD or https://opensource.org/licenses/BSD-3-Clause>,
+// at your option. This file may not be copied, modified, or distributed
+// except according to those terms. Please review the Licences for the
+// specific language governing permissions and limitations relating to use
+// of the SAFE Network Software.
+using SafeApp.Utilities;
+using System;
+using System.Collections.Generic;
+using System.Threading.Tasks;
+
+namespace SafeApp.MockAuthBindings
+{
+    public partial interface IMockAuthBindings
+    {
+        Task<IntPtr> TestCreateAppAsync(string appId);
+
+        Task<IntPtr> TestCreateAppWithAccessAsync(ref AuthReq authReq);
+    }
+}
@@ -0,0 +1,60 @@
+// Copyright 2019 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT> or the Modified
+// BSD license <LICENSE-BSD or https://opensource.org/licenses/BSD-3-Clause>,
+// at your option. This file may not be copied, modified, or distributed
+// except according to those terms. Please review the Licences for the
+// specific language governing permissions and limitations relating to use
+// of the SAFE Network Software.
+using SafeApp.Utilities;
+using System;
+using System.Collections.Generic;
+using System.Linq;
+using System.Runtime.InteropServices;
+using System.Threading.Tasks;
+
+namespace SafeApp.MockAuthBindings
+{
+    internal partial class MockAuthBindings : IMockAuthBindings
+    {
+        #if __IOS__
+        private const string DllName = "__Internal";
+        #else
+        private const string DllName = "safe_app";
+        #endif
+
+        public Task<IntPtr> TestCreateAppAsync(string appId)
+        {
+            var (ret, userData) = BindingUtils.PrepareTask<IntPtr>();
+            TestCreateAppNative(appId, userData, DelegateOnFfiResultAppCb);
+            return ret;
+        }
+
+        [DllImport(DllName, EntryPoint = "test_create_app")]
+        private static extern void TestCreateAppNative([MarshalAs(UnmanagedType.LPStr)] string appId, IntPtr userData, FfiResultAppCb oCb);
+
+        public Task<IntPtr> TestCreateAppWithAccessAsync(ref AuthReq authReq)
+        {
+            var (ret, userData) = BindingUtils.PrepareTask<IntPtr>();
+            TestCreateAppWithAccessNative(ref authReq, userData, DelegateOnFfiResultAppCb);
+            return ret;
+        }
+
+        [DllImport(DllName, EntryPoint = "test_create_app_with_access")]
+        private static extern void TestCreateAppWithAccessNative(ref AuthReq authReq, IntPtr userData, FfiResultAppCb oCb);
+
+        private delegate void FfiResultAppCb(IntPtr userData, IntPtr result, IntPtr app);
+
+        #if __IOS__
+        [MonoPInvokeCallback(typeof(FfiResultAppCb))]
+        #endif
+        private static void OnFfiResultAppCb(IntPtr userData, IntPtr result, IntPtr app)
+        {
+            BindingUtils.CompleteTask(userData, Marshal.PtrToStructure<FfiResult>(result), () => app);
+        }
+
+        private static readonly FfiResultAppCb DelegateOnFfiResultAppCb = OnFfiResultAppCb;
+
+    }
+}
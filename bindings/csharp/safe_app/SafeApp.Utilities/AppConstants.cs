@@ -0,0 +1,42 @@
+using System;
+using JetBrains.Annotations;
+
+namespace SafeApp.Utilities
+{
+    [PublicAPI]
+    public static class AppConstants
+    {
+        public const ulong MaidsafeTag = 5483000;
+        public const ulong DirTag = 15000;
+        public const ulong GetNextVersion = 0;
+        public const int ErrInvalidCipherOptHandle = 1;
+        public const int ErrInvalidEncryptPubKeyHandle = 2;
+        public const int ErrInvalidMDataInfoHandle = 3;
+        public const int ErrInvalidMDataEntriesHandle = 4;
+        public const int ErrInvalidMDataEntryActionsHandle = 5;
+        public const int ErrInvalidMDataPermissionsHandle = 6;
+        public const int ErrInvalidMDataPermissionSetHandle = 7;
+        public const int ErrInvalidSelfEncryptorHandle = 8;
+        public const int ErrInvalidSignPubKeyHandle = 9;
+        public const int ErrInvalidSelfEncryptorReadOffsets = 10;
+        public const int ErrInvalidEncryptSecKeyHandle = 11;
+        public const int ErrInvalidFileContextHandle = 12;
+        public const int ErrInvalidFileMode = 13;
+        public const int ErrInvalidSignSecKeyHandle = 14;
+        public const int ErrUnregisteredClientAccess = 15;
+        public const int ErrInvalidPubKeyHandle = 16;
+        public const int ErrGetBalanceDenied = 17;
+        public const int ErrTransferCoinsDenied = 18;
+        public const uint IpcRespAuth = 0;
+        public const uint IpcRespUnregistered = 1;
+        public const uint IpcRespContainers = 2;
+        public const uint IpcRespShareMData = 3;
+        public const uint IpcRespShareAdata = 4;
+        public const uint IpcRespTransOwnership = 5;
+        public const uint IpcRespPermissions = 6;
+        public const uint IpcRespRevoked = 7;
+        public const ulong NullObjectHandle = 0;
+        public const ulong AsymPublicKeyLen = 48;
+        public const ulong XorNameLen = 32;
+    }
+}
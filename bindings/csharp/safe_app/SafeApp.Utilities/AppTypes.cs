@@ -0,0 +1,786 @@
+using System.Runtime.CompilerServices;
+using System;
+using System.Collections.Generic;
+using System.Runtime.InteropServices;
+using JetBrains.Annotations;
+
+[assembly: InternalsVisibleTo("SafeApp.AppBindings")]
+[assembly: InternalsVisibleTo("SafeApp.MockAuthBindings")]
+
+namespace SafeApp.Utilities
+{
+    [PublicAPI]
+    public struct MDataInfo
+    {
+        [MarshalAs(UnmanagedType.U1)]
+        public bool Seq;
+        [MarshalAs(UnmanagedType.ByValArray, SizeConst = (int)AppConstants.XorNameLen)]
+        public byte[] Name;
+        public ulong TypeTag;
+        [MarshalAs(UnmanagedType.U1)]
+        public bool HasEncInfo;
+        [MarshalAs(UnmanagedType.ByValArray, SizeConst = (int)AppConstants.SymEncKeyLen)]
+        public byte[] EncKey;
+        [MarshalAs(UnmanagedType.ByValArray, SizeConst = (int)AppConstants.SymEncNonceLen)]
+        public byte[] EncNonce;
+        [MarshalAs(UnmanagedType.U1)]
+        public bool HasNewEncInfo;
+        [MarshalAs(UnmanagedType.ByValArray, SizeConst = (int)AppConstants.SymEncKeyLen)]
+        public byte[] NewEncKey;
+        [MarshalAs(UnmanagedType.ByValArray, SizeConst = (int)AppConstants.SymEncNonceLen)]
+        public byte[] NewEncNonce;
+    }
+
+    [PublicAPI]
+    public struct TransactionRecord
+    {
+        public ulong Id;
+        [MarshalAs(UnmanagedType.U1)]
+        public bool Sent;
+        [MarshalAs(UnmanagedType.ByValArray, SizeConst = (int)AppConstants.XorNameLen)]
+        public byte[] Counterparty;
+        public ulong Amount;
+        public long TimestampSec;
+        public uint TimestampNsec;
+    }
+
+    [PublicAPI]
+    public struct PermissionSet
+    {
+        [MarshalAs(UnmanagedType.U1)]
+        public bool Read;
+        [MarshalAs(UnmanagedType.U1)]
+        public bool Insert;
+        [MarshalAs(UnmanagedType.U1)]
+        public bool Update;
+        [MarshalAs(UnmanagedType.U1)]
+        public bool Delete;
+        [MarshalAs(UnmanagedType.U1)]
+        public bool ManagePermissions;
+    }
+
+    [PublicAPI]
+    public struct AppExchangeInfo
+    {
+        [MarshalAs(UnmanagedType.LPStr)]
+        public string Id;
+        [MarshalAs(UnmanagedType.LPStr)]
+        public string Scope;
+        [MarshalAs(UnmanagedType.LPStr)]
+        public string Name;
+        [MarshalAs(UnmanagedType.LPStr)]
+        public string Vendor;
+    }
+
+    [PublicAPI]
+    public struct ContainerPermissions
+    {
+        [MarshalAs(UnmanagedType.LPStr)]
+        public string ContName;
+        public PermissionSet Access;
+    }
+
+    [PublicAPI]
+    public struct AuthReq
+    {
+        public AppExchangeInfo App;
+        public bool AppContainer;
+        public bool AppPermissionTransferCoins;
+        public bool AppPermissionPerformMutations;
+        public bool AppPermissionGetBalance;
+        public List<ContainerPermissions> Containers;
+        public bool SealResponse;
+        public byte[] ResponseKey;
+        public ulong Ttl;
+
+        internal AuthReq(AuthReqNative native)
+        {
+            App = native.App;
+            AppContainer = native.AppContainer;
+            AppPermissionTransferCoins = native.AppPermissionTransferCoins;
+            AppPermissionPerformMutations = native.AppPermissionPerformMutations;
+            AppPermissionGetBalance = native.AppPermissionGetBalance;
+            Containers = BindingUtils.CopyToObjectList<ContainerPermissions>(native.ContainersPtr, (int)native.ContainersLen);
+            SealResponse = native.SealResponse;
+            ResponseKey = native.ResponseKey;
+            Ttl = native.Ttl;
+        }
+
+        internal AuthReqNative ToNative()
+        {
+            return new AuthReqNative
+            {
+                App = App,
+                AppContainer = AppContainer,
+                AppPermissionTransferCoins = AppPermissionTransferCoins,
+                AppPermissionPerformMutations = AppPermissionPerformMutations,
+                AppPermissionGetBalance = AppPermissionGetBalance,
+                ContainersPtr = BindingUtils.CopyFromObjectList(Containers),
+                ContainersLen = (UIntPtr)(Containers?.Count ?? 0),
+                SealResponse = SealResponse,
+                ResponseKey = ResponseKey,
+                Ttl = Ttl
+            };
+        }
+    }
+
+    internal struct AuthReqNative
+    {
+        public AppExchangeInfo App;
+        [MarshalAs(UnmanagedType.U1)]
+        public bool AppContainer;
+        [MarshalAs(UnmanagedType.U1)]
+        public bool AppPermissionTransferCoins;
+        [MarshalAs(UnmanagedType.U1)]
+        public bool AppPermissionPerformMutations;
+        [MarshalAs(UnmanagedType.U1)]
+        public bool AppPermissionGetBalance;
+        public IntPtr ContainersPtr;
+        public UIntPtr ContainersLen;
+        [MarshalAs(UnmanagedType.U1)]
+        public bool SealResponse;
+        [MarshalAs(UnmanagedType.ByValArray, SizeConst = (int)AppConstants.BlsPublicKeyLen)]
+        public byte[] ResponseKey;
+        public ulong Ttl;
+
+        internal void Free()
+        {
+            BindingUtils.FreeList(ref ContainersPtr, ref ContainersLen);
+        }
+    }
+
+    [PublicAPI]
+    public struct ContainersReq
+    {
+        public AppExchangeInfo App;
+        public List<ContainerPermissions> Containers;
+
+        internal ContainersReq(ContainersReqNative native)
+        {
+            App = native.App;
+            Containers = BindingUtils.CopyToObjectList<ContainerPermissions>(native.ContainersPtr, (int)native.ContainersLen);
+        }
+
+        internal ContainersReqNative ToNative()
+        {
+            return new ContainersReqNative
+            {
+                App = App,
+                ContainersPtr = BindingUtils.CopyFromObjectList(Containers),
+                ContainersLen = (UIntPtr)(Containers?.Count ?? 0)
+            };
+        }
+    }
+
+    internal struct ContainersReqNative
+    {
+        public AppExchangeInfo App;
+        public IntPtr ContainersPtr;
+        public UIntPtr ContainersLen;
+
+        internal void Free()
+        {
+            BindingUtils.FreeList(ref ContainersPtr, ref ContainersLen);
+        }
+    }
+
+    [PublicAPI]
+    public struct PermissionsReq
+    {
+        public AppExchangeInfo App;
+        public List<ContainerPermissions> Containers;
+        public bool UpdateAppPermissions;
+        public bool AppPermissionTransferCoins;
+        public bool AppPermissionPerformMutations;
+        public bool AppPermissionGetBalance;
+
+        internal PermissionsReq(PermissionsReqNative native)
+        {
+            App = native.App;
+            Containers = BindingUtils.CopyToObjectList<ContainerPermissions>(native.ContainersPtr, (int)native.ContainersLen);
+            UpdateAppPermissions = native.UpdateAppPermissions;
+            AppPermissionTransferCoins = native.AppPermissionTransferCoins;
+            AppPermissionPerformMutations = native.AppPermissionPerformMutations;
+            AppPermissionGetBalance = native.AppPermissionGetBalance;
+        }
+
+        internal PermissionsReqNative ToNative()
+        {
+            return new PermissionsReqNative
+            {
+                App = App,
+                ContainersPtr = BindingUtils.CopyFromObjectList(Containers),
+                ContainersLen = (UIntPtr)(Containers?.Count ?? 0),
+                UpdateAppPermissions = UpdateAppPermissions,
+                AppPermissionTransferCoins = AppPermissionTransferCoins,
+                AppPermissionPerformMutations = AppPermissionPerformMutations,
+                AppPermissionGetBalance = AppPermissionGetBalance
+            };
+        }
+    }
+
+    internal struct PermissionsReqNative
+    {
+        public AppExchangeInfo App;
+        public IntPtr ContainersPtr;
+        public UIntPtr ContainersLen;
+        [MarshalAs(UnmanagedType.U1)]
+        public bool UpdateAppPermissions;
+        [MarshalAs(UnmanagedType.U1)]
+        public bool AppPermissionTransferCoins;
+        [MarshalAs(UnmanagedType.U1)]
+        public bool AppPermissionPerformMutations;
+        [MarshalAs(UnmanagedType.U1)]
+        public bool AppPermissionGetBalance;
+
+        internal void Free()
+        {
+            BindingUtils.FreeList(ref ContainersPtr, ref ContainersLen);
+        }
+    }
+
+    [PublicAPI]
+    public struct ShareMData
+    {
+        public ulong TypeTag;
+        [MarshalAs(UnmanagedType.ByValArray, SizeConst = (int)AppConstants.XorNameLen)]
+        public byte[] Name;
+        public PermissionSet Perms;
+    }
+
+    [PublicAPI]
+    public struct ShareMDataRequest
+    {
+        public AppExchangeInfo App;
+        public List<ShareMData> MData;
+
+        internal ShareMDataRequest(ShareMDataRequestNative native)
+        {
+            App = native.App;
+            MData = BindingUtils.CopyToObjectList<ShareMData>(native.MDataPtr, (int)native.MDataLen);
+        }
+
+        internal ShareMDataRequestNative ToNative()
+        {
+            return new ShareMDataRequestNative
+            {
+                App = App,
+                MDataPtr = BindingUtils.CopyFromObjectList(MData),
+                MDataLen = (UIntPtr)(MData?.Count ?? 0)
+            };
+        }
+    }
+
+    internal struct ShareMDataRequestNative
+    {
+        public AppExchangeInfo App;
+        public IntPtr MDataPtr;
+        public UIntPtr MDataLen;
+
+        internal void Free()
+        {
+            BindingUtils.FreeList(ref MDataPtr, ref MDataLen);
+        }
+    }
+
+    [PublicAPI]
+    public struct ADataPermissionSet
+    {
+        [MarshalAs(UnmanagedType.U1)]
+        public bool Read;
+        [MarshalAs(UnmanagedType.U1)]
+        public bool Append;
+        [MarshalAs(UnmanagedType.U1)]
+        public bool ManagePermissions;
+    }
+
+    [PublicAPI]
+    public struct ShareAData
+    {
+        [MarshalAs(UnmanagedType.ByValArray, SizeConst = (int)AppConstants.XorNameLen)]
+        public byte[] Name;
+        public ulong TypeTag;
+        [MarshalAs(UnmanagedType.U1)]
+        public bool Published;
+        [MarshalAs(UnmanagedType.U1)]
+        public bool Sequenced;
+        public ADataPermissionSet Perms;
+    }
+
+    [PublicAPI]
+    public struct ShareADataRequest
+    {
+        public AppExchangeInfo App;
+        public List<ShareAData> Adata;
+
+        internal ShareADataRequest(ShareADataRequestNative native)
+        {
+            App = native.App;
+            Adata = BindingUtils.CopyToObjectList<ShareAData>(native.AdataPtr, (int)native.AdataLen);
+        }
+
+        internal ShareADataRequestNative ToNative()
+        {
+            return new ShareADataRequestNative
+            {
+                App = App,
+                AdataPtr = BindingUtils.CopyFromObjectList(Adata),
+                AdataLen = (UIntPtr)(Adata?.Count ?? 0)
+            };
+        }
+    }
+
+    internal struct ShareADataRequestNative
+    {
+        public AppExchangeInfo App;
+        public IntPtr AdataPtr;
+        public UIntPtr AdataLen;
+
+        internal void Free()
+        {
+            BindingUtils.FreeList(ref AdataPtr, ref AdataLen);
+        }
+    }
+
+    [PublicAPI]
+    public struct TransOwnership
+    {
+        [MarshalAs(UnmanagedType.ByValArray, SizeConst = (int)AppConstants.XorNameLen)]
+        public byte[] Name;
+        public ulong TypeTag;
+        [MarshalAs(UnmanagedType.U1)]
+        public bool AppendOnly;
+        [MarshalAs(UnmanagedType.U1)]
+        public bool Published;
+        [MarshalAs(UnmanagedType.U1)]
+        public bool Sequenced;
+        [MarshalAs(UnmanagedType.ByValArray, SizeConst = (int)AppConstants.BlsPublicKeyLen)]
+        public byte[] NewOwner;
+    }
+
+    [PublicAPI]
+    public struct TransOwnershipRequest
+    {
+        public AppExchangeInfo App;
+        public List<TransOwnership> Data;
+
+        internal TransOwnershipRequest(TransOwnershipRequestNative native)
+        {
+            App = native.App;
+            Data = BindingUtils.CopyToObjectList<TransOwnership>(native.DataPtr, (int)native.DataLen);
+        }
+
+        internal TransOwnershipRequestNative ToNative()
+        {
+            return new TransOwnershipRequestNative
+            {
+                App = App,
+                DataPtr = BindingUtils.CopyFromObjectList(Data),
+                DataLen = (UIntPtr)(Data?.Count ?? 0)
+            };
+        }
+    }
+
+    internal struct TransOwnershipRequestNative
+    {
+        public AppExchangeInfo App;
+        public IntPtr DataPtr;
+        public UIntPtr DataLen;
+
+        internal void Free()
+        {
+            BindingUtils.FreeList(ref DataPtr, ref DataLen);
+        }
+    }
+
+    [PublicAPI]
+    public struct AppKeys
+    {
+        public List<byte> FullId;
+        public byte[] EncKey;
+        public byte[] EncPublicKey;
+        public List<byte> EncSecretKey;
+
+        internal AppKeys(AppKeysNative native)
+        {
+            FullId = BindingUtils.CopyToByteList(native.FullIdPtr, (int)native.FullIdLen);
+            EncKey = native.EncKey;
+            EncPublicKey = native.EncPublicKey;
+            EncSecretKey = BindingUtils.CopyToByteList(native.EncSecretKeyPtr, (int)native.EncSecretKeyLen);
+        }
+
+        internal AppKeysNative ToNative()
+        {
+            return new AppKeysNative
+            {
+                FullIdPtr = BindingUtils.CopyFromByteList(FullId),
+                FullIdLen = (UIntPtr)(FullId?.Count ?? 0),
+                EncKey = EncKey,
+                EncPublicKey = EncPublicKey,
+                EncSecretKeyPtr = BindingUtils.CopyFromByteList(EncSecretKey),
+                EncSecretKeyLen = (UIntPtr)(EncSecretKey?.Count ?? 0)
+            };
+        }
+    }
+
+    internal struct AppKeysNative
+    {
+        public IntPtr FullIdPtr;
+        public UIntPtr FullIdLen;
+        [MarshalAs(UnmanagedType.ByValArray, SizeConst = (int)AppConstants.SymEncKeyLen)]
+        public byte[] EncKey;
+        [MarshalAs(UnmanagedType.ByValArray, SizeConst = (int)AppConstants.BlsPublicKeyLen)]
+        public byte[] EncPublicKey;
+        public IntPtr EncSecretKeyPtr;
+        public UIntPtr EncSecretKeyLen;
+
+        internal void Free()
+        {
+            BindingUtils.FreeList(ref FullIdPtr, ref FullIdLen);
+            BindingUtils.FreeList(ref EncSecretKeyPtr, ref EncSecretKeyLen);
+        }
+    }
+
+    [PublicAPI]
+    public struct AccessContInfo
+    {
+        [MarshalAs(UnmanagedType.ByValArray, SizeConst = (int)AppConstants.XorNameLen)]
+        public byte[] Id;
+        public ulong Tag;
+        [MarshalAs(UnmanagedType.ByValArray, SizeConst = (int)AppConstants.SymEncNonceLen)]
+        public byte[] Nonce;
+    }
+
+    [PublicAPI]
+    public struct ContainerInfo
+    {
+        [MarshalAs(UnmanagedType.LPStr)]
+        public string Name;
+        public MDataInfo MDataInfo;
+        public PermissionSet Permissions;
+    }
+
+    [PublicAPI]
+    public struct AccessContainerEntry
+    {
+        public List<ContainerInfo> Containers;
+
+        internal AccessContainerEntry(AccessContainerEntryNative native)
+        {
+            Containers = BindingUtils.CopyToObjectList<ContainerInfo>(native.ContainersPtr, (int)native.ContainersLen);
+        }
+
+        internal AccessContainerEntryNative ToNative()
+        {
+            return new AccessContainerEntryNative
+            {
+                ContainersPtr = BindingUtils.CopyFromObjectList(Containers),
+                ContainersLen = (UIntPtr)(Containers?.Count ?? 0)
+            };
+        }
+    }
+
+    internal struct AccessContainerEntryNative
+    {
+        public IntPtr ContainersPtr;
+        public UIntPtr ContainersLen;
+
+        internal void Free()
+        {
+            BindingUtils.FreeList(ref ContainersPtr, ref ContainersLen);
+        }
+    }
+
+    [PublicAPI]
+    public struct AuthGranted
+    {
+        public AppKeys AppKeys;
+        public AccessContInfo AccessContainerInfo;
+        public AccessContainerEntry AccessContainerEntry;
+        public List<byte> BootstrapConfig;
+
+        internal AuthGranted(AuthGrantedNative native)
+        {
+            AppKeys = new AppKeys(native.AppKeys);
+            AccessContainerInfo = native.AccessContainerInfo;
+            AccessContainerEntry = new AccessContainerEntry(native.AccessContainerEntry);
+            BootstrapConfig = BindingUtils.CopyToByteList(native.BootstrapConfigPtr, (int)native.BootstrapConfigLen);
+        }
+
+        internal AuthGrantedNative ToNative()
+        {
+            return new AuthGrantedNative
+            {
+                AppKeys = AppKeys.ToNative(),
+                AccessContainerInfo = AccessContainerInfo,
+                AccessContainerEntry = AccessContainerEntry.ToNative(),
+                BootstrapConfigPtr = BindingUtils.CopyFromByteList(BootstrapConfig),
+                BootstrapConfigLen = (UIntPtr)(BootstrapConfig?.Count ?? 0)
+            };
+        }
+    }
+
+    internal struct AuthGrantedNative
+    {
+        public AppKeysNative AppKeys;
+        public AccessContInfo AccessContainerInfo;
+        public AccessContainerEntryNative AccessContainerEntry;
+        public IntPtr BootstrapConfigPtr;
+        public UIntPtr BootstrapConfigLen;
+
+        internal void Free()
+        {
+            AppKeys.Free();
+            AccessContainerEntry.Free();
+            BindingUtils.FreeList(ref BootstrapConfigPtr, ref BootstrapConfigLen);
+        }
+    }
+
+    [PublicAPI]
+    public struct AppAccess
+    {
+        [MarshalAs(UnmanagedType.ByValArray, SizeConst = (int)AppConstants.BlsPublicKeyLen)]
+        public byte[] SignKey;
+        public PermissionSet Permissions;
+        [MarshalAs(UnmanagedType.LPStr)]
+        public string Name;
+        [MarshalAs(UnmanagedType.LPStr)]
+        public string AppId;
+    }
+
+    [PublicAPI]
+    public struct MetadataResponse
+    {
+        [MarshalAs(UnmanagedType.LPStr)]
+        public string Name;
+        [MarshalAs(UnmanagedType.LPStr)]
+        public string Description;
+        [MarshalAs(UnmanagedType.ByValArray, SizeConst = (int)AppConstants.XorNameLen)]
+        public byte[] XorName;
+        public ulong TypeTag;
+    }
+
+    [PublicAPI]
+    public struct MDataKey
+    {
+        public List<byte> Key;
+
+        internal MDataKey(MDataKeyNative native)
+        {
+            Key = BindingUtils.CopyToByteList(native.KeyPtr, (int)native.KeyLen);
+        }
+
+        internal MDataKeyNative ToNative()
+        {
+            return new MDataKeyNative
+            {
+                KeyPtr = BindingUtils.CopyFromByteList(Key),
+                KeyLen = (UIntPtr)(Key?.Count ?? 0)
+            };
+        }
+    }
+
+    internal struct MDataKeyNative
+    {
+        public IntPtr KeyPtr;
+        public UIntPtr KeyLen;
+
+        internal void Free()
+        {
+            BindingUtils.FreeList(ref KeyPtr, ref KeyLen);
+        }
+    }
+
+    [PublicAPI]
+    public struct MDataValue
+    {
+        public List<byte> Content;
+        public ulong EntryVersion;
+
+        internal MDataValue(MDataValueNative native)
+        {
+            Content = BindingUtils.CopyToByteList(native.ContentPtr, (int)native.ContentLen);
+            EntryVersion = native.EntryVersion;
+        }
+
+        internal MDataValueNative ToNative()
+        {
+            return new MDataValueNative
+            {
+                ContentPtr = BindingUtils.CopyFromByteList(Content),
+                ContentLen = (UIntPtr)(Content?.Count ?? 0),
+                EntryVersion = EntryVersion
+            };
+        }
+    }
+
+    internal struct MDataValueNative
+    {
+        public IntPtr ContentPtr;
+        public UIntPtr ContentLen;
+        public ulong EntryVersion;
+
+        internal void Free()
+        {
+            BindingUtils.FreeList(ref ContentPtr, ref ContentLen);
+        }
+    }
+
+    [PublicAPI]
+    public struct MDataEntry
+    {
+        public MDataKey Key;
+        public MDataValue Value;
+
+        internal MDataEntry(MDataEntryNative native)
+        {
+            Key = new MDataKey(native.Key);
+            Value = new MDataValue(native.Value);
+        }
+
+        internal MDataEntryNative ToNative()
+        {
+            return new MDataEntryNative
+            {
+                Key = Key.ToNative(),
+                Value = Value.ToNative()
+            };
+        }
+    }
+
+    internal struct MDataEntryNative
+    {
+        public MDataKeyNative Key;
+        public MDataValueNative Value;
+
+        internal void Free()
+        {
+            Key.Free();
+            Value.Free();
+        }
+    }
+
+    [PublicAPI]
+    public struct OwnershipChange
+    {
+        [MarshalAs(UnmanagedType.ByValArray, SizeConst = (int)AppConstants.BlsPublicKeyLen)]
+        public byte[] CurrentOwner;
+        [MarshalAs(UnmanagedType.ByValArray, SizeConst = (int)AppConstants.BlsPublicKeyLen)]
+        public byte[] NewOwner;
+        [MarshalAs(UnmanagedType.LPStr)]
+        public string Description;
+    }
+
+    [PublicAPI]
+    public struct File
+    {
+        public ulong Size;
+        public long CreatedSec;
+        public uint CreatedNsec;
+        public long ModifiedSec;
+        public uint ModifiedNsec;
+        public List<byte> UserMetadata;
+        public byte[] DataMapName;
+        public bool Published;
+
+        internal File(FileNative native)
+        {
+            Size = native.Size;
+            CreatedSec = native.CreatedSec;
+            CreatedNsec = native.CreatedNsec;
+            ModifiedSec = native.ModifiedSec;
+            ModifiedNsec = native.ModifiedNsec;
+            UserMetadata = BindingUtils.CopyToByteList(native.UserMetadataPtr, (int)native.UserMetadataLen);
+            DataMapName = native.DataMapName;
+            Published = native.Published;
+        }
+
+        internal FileNative ToNative()
+        {
+            return new FileNative
+            {
+                Size = Size,
+                CreatedSec = CreatedSec,
+                CreatedNsec = CreatedNsec,
+                ModifiedSec = ModifiedSec,
+                ModifiedNsec = ModifiedNsec,
+                UserMetadataPtr = BindingUtils.CopyFromByteList(UserMetadata),
+                UserMetadataLen = (UIntPtr)(UserMetadata?.Count ?? 0),
+                DataMapName = DataMapName,
+                Published = Published
+            };
+        }
+    }
+
+    internal struct FileNative
+    {
+        public ulong Size;
+        public long CreatedSec;
+        public uint CreatedNsec;
+        public long ModifiedSec;
+        public uint ModifiedNsec;
+        public IntPtr UserMetadataPtr;
+        public UIntPtr UserMetadataLen;
+        [MarshalAs(UnmanagedType.ByValArray, SizeConst = (int)AppConstants.XorNameLen)]
+        public byte[] DataMapName;
+        [MarshalAs(UnmanagedType.U1)]
+        public bool Published;
+
+        internal void Free()
+        {
+            BindingUtils.FreeList(ref UserMetadataPtr, ref UserMetadataLen);
+        }
+    }
+
+    [PublicAPI]
+    public struct DecodedIpcResp
+    {
+        public uint Kind;
+        public uint ReqId;
+        public IntPtr AuthGranted;
+        public List<byte> SerialisedCfg;
+
+        internal DecodedIpcResp(DecodedIpcRespNative native)
+        {
+            Kind = native.Kind;
+            ReqId = native.ReqId;
+            AuthGranted = new IntPtr(native.AuthGranted);
+            SerialisedCfg = BindingUtils.CopyToByteList(native.SerialisedCfgPtr, (int)native.SerialisedCfgLen);
+        }
+
+        internal DecodedIpcRespNative ToNative()
+        {
+            return new DecodedIpcRespNative
+            {
+                Kind = Kind,
+                ReqId = ReqId,
+                AuthGranted = AuthGranted.ToNative(),
+                SerialisedCfgPtr = BindingUtils.CopyFromByteList(SerialisedCfg),
+                SerialisedCfgLen = (UIntPtr)(SerialisedCfg?.Count ?? 0)
+            };
+        }
+    }
+
+    internal struct DecodedIpcRespNative
+    {
+        public uint Kind;
+        public uint ReqId;
+        public IntPtr AuthGranted;
+        public IntPtr SerialisedCfgPtr;
+        public UIntPtr SerialisedCfgLen;
+
+        internal void Free()
+        {
+            AuthGranted.Free();
+            BindingUtils.FreeList(ref SerialisedCfgPtr, ref SerialisedCfgLen);
+        }
+    }
+
+    [PublicAPI]
+    public struct UserPermissionSet
+    {
+        public ulong UserH;
+        public PermissionSet PermSet;
+    }
+
+}
@@ -0,0 +1,662 @@
+// Copyright 2019 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT> or the Modified
+// BSD license <LICENSE-BSD or https://opensource.org/licenses/BSD-3-Clause>,
+// at your option. This file may not be copied, modified, or distributed
+// except according to those terms. Please review the Licences for the
+// specific language governing permissions and limitations relating to use
+// of the SAFE Network Software.
+#if !NETSTANDARD1_2 || __DESKTOP__
+#if __IOS__
+using ObjCRuntime;
+#endif
+using SafeAuth.Utilities;
+using System;
+using System.Collections.Generic;
+using System.Linq;
+using System.Runtime.InteropServices;
+using System.Threading.Tasks;
+
+namespace SafeAuth.AuthBindings
+{
+    internal partial class AuthBindings : IAuthBindings
+    {
+        #if __IOS__
+        private const string DllName = "__Internal";
+        #else
+        private const string DllName = "safe_authenticator";
+        #endif
+
+        [DllImport(DllName, EntryPoint = "create_acc")]
+        private static extern void CreateAccNative([MarshalAs(UnmanagedType.LPStr)] string accountLocator, [MarshalAs(UnmanagedType.LPStr)] string accountPassword, IntPtr userData, NoneCb oDisconnectNotifierCb, FfiResultAuthenticatorCb oCb);
+
+        [DllImport(DllName, EntryPoint = "create_acc_with_recovery")]
+        private static extern void CreateAccWithRecoveryNative([MarshalAs(UnmanagedType.LPStr)] string accountLocator, [MarshalAs(UnmanagedType.LPStr)] string accountPassword, IntPtr userData, NoneCb oDisconnectNotifierCb, FfiResultAuthenticatorStringCb oCb);
+
+        [DllImport(DllName, EntryPoint = "login")]
+        private static extern void LoginNative([MarshalAs(UnmanagedType.LPStr)] string accountLocator, [MarshalAs(UnmanagedType.LPStr)] string accountPassword, IntPtr userData, NoneCb oDisconnectNotifierCb, FfiResultAuthenticatorCb oCb);
+
+        [DllImport(DllName, EntryPoint = "login_with_signer")]
+        private static extern void LoginWithSignerNative([MarshalAs(UnmanagedType.LPStr)] string accountLocator, [MarshalAs(UnmanagedType.LPStr)] string accountPassword, [MarshalAs(UnmanagedType.LPArray, SizeParamIndex = 3)] byte[] publicId, UIntPtr publicIdLen, IntPtr userData, ByteListSignatureTxCb oSign, NoneCb oDisconnectNotifierCb, FfiResultAuthenticatorCb oCb);
+
+        public Task AuthSendSignatureAsync(IntPtr signatureTx, byte[] signature)
+        {
+            var (ret, userData) = BindingUtils.PrepareTask();
+            AuthSendSignatureNative(signatureTx, signature, userData, DelegateOnFfiResultCb);
+            return ret;
+        }
+
+        [DllImport(DllName, EntryPoint = "auth_send_signature")]
+        private static extern void AuthSendSignatureNative(IntPtr signatureTx, [MarshalAs(UnmanagedType.LPArray, SizeConst = (int)AuthConstants.SigSize)] byte[] signature, IntPtr userData, FfiResultCb oCb);
+
+        [DllImport(DllName, EntryPoint = "login_device")]
+        private static extern void LoginDeviceNative([MarshalAs(UnmanagedType.LPStr)] string accountLocator, [MarshalAs(UnmanagedType.LPStr)] string accountPassword, ref DeviceKey deviceKey, IntPtr userData, NoneCb oDisconnectNotifierCb, FfiResultAuthenticatorCb oCb);
+
+        [DllImport(DllName, EntryPoint = "recover_account")]
+        private static extern void RecoverAccountNative([MarshalAs(UnmanagedType.LPStr)] string recoveryPhrase, [MarshalAs(UnmanagedType.LPStr)] string newPassword, IntPtr userData, NoneCb oDisconnectNotifierCb, FfiResultAuthenticatorCb oCb);
+
+        [DllImport(DllName, EntryPoint = "import_backup")]
+        private static extern void ImportBackupNative([MarshalAs(UnmanagedType.LPArray, SizeParamIndex = 1)] byte[] backup, UIntPtr backupLen, [MarshalAs(UnmanagedType.LPStr)] string passphrase, [MarshalAs(UnmanagedType.LPStr)] string newPassword, IntPtr userData, NoneCb oDisconnectNotifierCb, FfiResultAuthenticatorCb oCb);
+
+        public Task<string> AuthSetUpRecoveryAsync(IntPtr auth)
+        {
+            var (ret, userData) = BindingUtils.PrepareTask<string>();
+            AuthSetUpRecoveryNative(auth, userData, DelegateOnFfiResultStringCb);
+            return ret;
+        }
+
+        [DllImport(DllName, EntryPoint = "auth_set_up_recovery")]
+        private static extern void AuthSetUpRecoveryNative(IntPtr auth, IntPtr userData, FfiResultStringCb oCb);
+
+        public Task<List<byte>> AuthExportBackupAsync(IntPtr auth, string passphrase)
+        {
+            var (ret, userData) = BindingUtils.PrepareTask<List<byte>>();
+            AuthExportBackupNative(auth, passphrase, userData, DelegateOnFfiResultByteListCb);
+            return ret;
+        }
+
+        [DllImport(DllName, EntryPoint = "auth_export_backup")]
+        private static extern void AuthExportBackupNative(IntPtr auth, [MarshalAs(UnmanagedType.LPStr)] string passphrase, IntPtr userData, FfiResultByteListCb oCb);
+
+        public Task AuthChangePasswordAsync(IntPtr auth, string currentPassword, string newPassword)
+        {
+            var (ret, userData) = BindingUtils.PrepareTask();
+            AuthChangePasswordNative(auth, currentPassword, newPassword, userData, DelegateOnFfiResultCb);
+            return ret;
+        }
+
+        [DllImport(DllName, EntryPoint = "auth_change_password")]
+        private static extern void AuthChangePasswordNative(IntPtr auth, [MarshalAs(UnmanagedType.LPStr)] string currentPassword, [MarshalAs(UnmanagedType.LPStr)] string newPassword, IntPtr userData, FfiResultCb oCb);
+
+        public Task AuthChangeLocatorAsync(IntPtr auth, string newLocator, string password)
+        {
+            var (ret, userData) = BindingUtils.PrepareTask();
+            AuthChangeLocatorNative(auth, newLocator, password, userData, DelegateOnFfiResultCb);
+            return ret;
+        }
+
+        [DllImport(DllName, EntryPoint = "auth_change_locator")]
+        private static extern void AuthChangeLocatorNative(IntPtr auth, [MarshalAs(UnmanagedType.LPStr)] string newLocator, [MarshalAs(UnmanagedType.LPStr)] string password, IntPtr userData, FfiResultCb oCb);
+
+        public Task AuthRotateClientKeysAsync(IntPtr auth)
+        {
+            var (ret, userData) = BindingUtils.PrepareTask();
+            AuthRotateClientKeysNative(auth, userData, DelegateOnFfiResultCb);
+            return ret;
+        }
+
+        [DllImport(DllName, EntryPoint = "auth_rotate_client_keys")]
+        private static extern void AuthRotateClientKeysNative(IntPtr auth, IntPtr userData, FfiResultCb oCb);
+
+        public Task AuthReconnectAsync(IntPtr auth)
+        {
+            var (ret, userData) = BindingUtils.PrepareTask();
+            AuthReconnectNative(auth, userData, DelegateOnFfiResultCb);
+            return ret;
+        }
+
+        [DllImport(DllName, EntryPoint = "auth_reconnect")]
+        private static extern void AuthReconnectNative(IntPtr auth, IntPtr userData, FfiResultCb oCb);
+
+        public Task<byte[]> AuthAccountPublicKeyAsync(IntPtr auth)
+        {
+            var (ret, userData) = BindingUtils.PrepareTask<byte[]>();
+            AuthAccountPublicKeyNative(auth, userData, DelegateOnFfiResultByteArrayBlsPublicKeyLenCb);
+            return ret;
+        }
+
+        [DllImport(DllName, EntryPoint = "auth_account_public_key")]
+        private static extern void AuthAccountPublicKeyNative(IntPtr auth, IntPtr userData, FfiResultByteArrayBlsPublicKeyLenCb oCb);
+
+        public Task<List<byte>> AuthAccountPublicIdAsync(IntPtr auth)
+        {
+            var (ret, userData) = BindingUtils.PrepareTask<List<byte>>();
+            AuthAccountPublicIdNative(auth, userData, DelegateOnFfiResultByteListCb);
+            return ret;
+        }
+
+        [DllImport(DllName, EntryPoint = "auth_account_public_id")]
+        private static extern void AuthAccountPublicIdNative(IntPtr auth, IntPtr userData, FfiResultByteListCb oCb);
+
+        public Task AuthSetConfigDirPathAsync(string newPath)
+        {
+            var (ret, userData) = BindingUtils.PrepareTask();
+            AuthSetConfigDirPathNative(newPath, userData, DelegateOnFfiResultCb);
+            return ret;
+        }
+
+        [DllImport(DllName, EntryPoint = "auth_set_config_dir_path")]
+        private static extern void AuthSetConfigDirPathNative([MarshalAs(UnmanagedType.LPStr)] string newPath, IntPtr userData, FfiResultCb oCb);
+
+        public void AuthFree(IntPtr auth)
+        {
+            AuthFreeNative(auth);
+        }
+
+        [DllImport(DllName, EntryPoint = "auth_free")]
+        private static extern void AuthFreeNative(IntPtr auth);
+
+        public bool AuthIsMock()
+        {
+            var ret = AuthIsMockNative();
+            return ret;
+        }
+
+        [DllImport(DllName, EntryPoint = "auth_is_mock")]
+        private static extern bool AuthIsMockNative();
+
+        public Task AuthRmRevokedAppAsync(IntPtr auth, string appId)
+        {
+            var (ret, userData) = BindingUtils.PrepareTask();
+            AuthRmRevokedAppNative(auth, appId, userData, DelegateOnFfiResultCb);
+            return ret;
+        }
+
+        [DllImport(DllName, EntryPoint = "auth_rm_revoked_app")]
+        private static extern void AuthRmRevokedAppNative(IntPtr auth, [MarshalAs(UnmanagedType.LPStr)] string appId, IntPtr userData, FfiResultCb oCb);
+
+        public Task<List<AppExchangeInfo>> AuthRevokedAppsAsync(IntPtr auth)
+        {
+            var (ret, userData) = BindingUtils.PrepareTask<List<AppExchangeInfo>>();
+            AuthRevokedAppsNative(auth, userData, DelegateOnFfiResultAppExchangeInfoListCb);
+            return ret;
+        }
+
+        [DllImport(DllName, EntryPoint = "auth_revoked_apps")]
+        private static extern void AuthRevokedAppsNative(IntPtr auth, IntPtr userData, FfiResultAppExchangeInfoListCb oCb);
+
+        public Task<List<RegisteredApp>> AuthRegisteredAppsAsync(IntPtr auth)
+        {
+            var (ret, userData) = BindingUtils.PrepareTask<List<RegisteredApp>>();
+            AuthRegisteredAppsNative(auth, userData, DelegateOnFfiResultRegisteredAppListCb);
+            return ret;
+        }
+
+        [DllImport(DllName, EntryPoint = "auth_registered_apps")]
+        private static extern void AuthRegisteredAppsNative(IntPtr auth, IntPtr userData, FfiResultRegisteredAppListCb oCb);
+
+        public Task<List<AppUsageSummary>> AuthAppsUsageAsync(IntPtr auth)
+        {
+            var (ret, userData) = BindingUtils.PrepareTask<List<AppUsageSummary>>();
+            AuthAppsUsageNative(auth, userData, DelegateOnFfiResultAppUsageSummaryListCb);
+            return ret;
+        }
+
+        [DllImport(DllName, EntryPoint = "auth_apps_usage")]
+        private static extern void AuthAppsUsageNative(IntPtr auth, IntPtr userData, FfiResultAppUsageSummaryListCb oCb);
+
+        public Task<List<AppAccess>> AuthAppsAccessingMutableDataAsync(IntPtr auth, byte[] mdName, ulong mdTypeTag)
+        {
+            var (ret, userData) = BindingUtils.PrepareTask<List<AppAccess>>();
+            AuthAppsAccessingMutableDataNative(auth, mdName, mdTypeTag, userData, DelegateOnFfiResultAppAccessListCb);
+            return ret;
+        }
+
+        [DllImport(DllName, EntryPoint = "auth_apps_accessing_mutable_data")]
+        private static extern void AuthAppsAccessingMutableDataNative(IntPtr auth, [MarshalAs(UnmanagedType.LPArray, SizeConst = (int)AuthConstants.XorNameLen)] byte[] mdName, ulong mdTypeTag, IntPtr userData, FfiResultAppAccessListCb oCb);
+
+        public Task<List<AuditEntry>> AuthAuditLogAsync(IntPtr auth)
+        {
+            var (ret, userData) = BindingUtils.PrepareTask<List<AuditEntry>>();
+            AuthAuditLogNative(auth, userData, DelegateOnFfiResultAuditEntryListCb);
+            return ret;
+        }
+
+        [DllImport(DllName, EntryPoint = "auth_audit_log")]
+        private static extern void AuthAuditLogNative(IntPtr auth, IntPtr userData, FfiResultAuditEntryListCb oCb);
+
+        public Task<string> AuthExportAuditLogAsync(IntPtr auth)
+        {
+            var (ret, userData) = BindingUtils.PrepareTask<string>();
+            AuthExportAuditLogNative(auth, userData, DelegateOnFfiResultStringCb);
+            return ret;
+        }
+
+        [DllImport(DllName, EntryPoint = "auth_export_audit_log")]
+        private static extern void AuthExportAuditLogNative(IntPtr auth, IntPtr userData, FfiResultStringCb oCb);
+
+        public Task AuthSetAppAllowanceAsync(IntPtr auth, string appId, ulong limit, bool daily)
+        {
+            var (ret, userData) = BindingUtils.PrepareTask();
+            AuthSetAppAllowanceNative(auth, appId, limit, daily, userData, DelegateOnFfiResultCb);
+            return ret;
+        }
+
+        [DllImport(DllName, EntryPoint = "auth_set_app_allowance")]
+        private static extern void AuthSetAppAllowanceNative(IntPtr auth, [MarshalAs(UnmanagedType.LPStr)] string appId, ulong limit, [MarshalAs(UnmanagedType.U1)] bool daily, IntPtr userData, FfiResultCb oCb);
+
+        public Task AuthRmAppAllowanceAsync(IntPtr auth, string appId)
+        {
+            var (ret, userData) = BindingUtils.PrepareTask();
+            AuthRmAppAllowanceNative(auth, appId, userData, DelegateOnFfiResultCb);
+            return ret;
+        }
+
+        [DllImport(DllName, EntryPoint = "auth_rm_app_allowance")]
+        private static extern void AuthRmAppAllowanceNative(IntPtr auth, [MarshalAs(UnmanagedType.LPStr)] string appId, IntPtr userData, FfiResultCb oCb);
+
+        public Task<List<AppAllowanceStatus>> AuthAppAllowancesAsync(IntPtr auth)
+        {
+            var (ret, userData) = BindingUtils.PrepareTask<List<AppAllowanceStatus>>();
+            AuthAppAllowancesNative(auth, userData, DelegateOnFfiResultAppAllowanceStatusListCb);
+            return ret;
+        }
+
+        [DllImport(DllName, EntryPoint = "auth_app_allowances")]
+        private static extern void AuthAppAllowancesNative(IntPtr auth, IntPtr userData, FfiResultAppAllowanceStatusListCb oCb);
+
+        [DllImport(DllName, EntryPoint = "auth_set_near_limit_observer")]
+        private static extern void AuthSetNearLimitObserverNative(IntPtr auth, IntPtr userData, AppAllowanceStatusCb oNearLimit, FfiResultCb oCb);
+
+        public Task<List<TransactionRecord>> AuthTransactionHistoryAsync(IntPtr auth)
+        {
+            var (ret, userData) = BindingUtils.PrepareTask<List<TransactionRecord>>();
+            AuthTransactionHistoryNative(auth, userData, DelegateOnFfiResultTransactionRecordListCb);
+            return ret;
+        }
+
+        [DllImport(DllName, EntryPoint = "auth_transaction_history")]
+        private static extern void AuthTransactionHistoryNative(IntPtr auth, IntPtr userData, FfiResultTransactionRecordListCb oCb);
+
+        public Task<DeletionReport> AuthDeleteAccountAsync(IntPtr auth, string password, byte[] destination, bool dryRun)
+        {
+            var (ret, userData) = BindingUtils.PrepareTask<DeletionReport>();
+            AuthDeleteAccountNative(auth, password, destination, dryRun, userData, DelegateOnFfiResultDeletionReportCb);
+            return ret;
+        }
+
+        [DllImport(DllName, EntryPoint = "auth_delete_account")]
+        private static extern void AuthDeleteAccountNative(IntPtr auth, [MarshalAs(UnmanagedType.LPStr)] string password, [MarshalAs(UnmanagedType.LPArray, SizeConst = (int)AuthConstants.XorNameLen)] byte[] destination, [MarshalAs(UnmanagedType.U1)] bool dryRun, IntPtr userData, FfiResultDeletionReportCb oCb);
+
+        public Task<DeviceKey> AuthRegisterDeviceAsync(IntPtr auth, string name)
+        {
+            var (ret, userData) = BindingUtils.PrepareTask<DeviceKey>();
+            AuthRegisterDeviceNative(auth, name, userData, DelegateOnFfiResultDeviceKeyCb);
+            return ret;
+        }
+
+        [DllImport(DllName, EntryPoint = "auth_register_device")]
+        private static extern void AuthRegisterDeviceNative(IntPtr auth, [MarshalAs(UnmanagedType.LPStr)] string name, IntPtr userData, FfiResultDeviceKeyCb oCb);
+
+        public Task<List<Device>> AuthDevicesAsync(IntPtr auth)
+        {
+            var (ret, userData) = BindingUtils.PrepareTask<List<Device>>();
+            AuthDevicesNative(auth, userData, DelegateOnFfiResultDeviceListCb);
+            return ret;
+        }
+
+        [DllImport(DllName, EntryPoint = "auth_devices")]
+        private static extern void AuthDevicesNative(IntPtr auth, IntPtr userData, FfiResultDeviceListCb oCb);
+
+        public Task AuthLogOutDeviceAsync(IntPtr auth, ref DeviceId deviceId)
+        {
+            var (ret, userData) = BindingUtils.PrepareTask();
+            AuthLogOutDeviceNative(auth, ref deviceId, userData, DelegateOnFfiResultCb);
+            return ret;
+        }
+
+        [DllImport(DllName, EntryPoint = "auth_log_out_device")]
+        private static extern void AuthLogOutDeviceNative(IntPtr auth, ref DeviceId deviceId, IntPtr userData, FfiResultCb oCb);
+
+        [DllImport(DllName, EntryPoint = "auth_unregistered_decode_ipc_msg")]
+        private static extern void AuthUnregisteredDecodeIpcMsgNative([MarshalAs(UnmanagedType.LPStr)] string msg, IntPtr userData, UIntByteListCb oUnregistered, FfiResultStringCb oErr);
+
+        [DllImport(DllName, EntryPoint = "auth_decode_ipc_msg")]
+        private static extern void AuthDecodeIpcMsgNative(IntPtr auth, [MarshalAs(UnmanagedType.LPStr)] string msg, IntPtr userData, UIntAuthReqCb oAuth, UIntContainersReqCb oContainers, UIntByteListCb oUnregistered, UIntShareMDataRequestMetadataResponseListCb oShareMData, FfiResultStringCb oErr);
+
+        [DllImport(DllName, EntryPoint = "auth_decode_ipc_req")]
+        private static extern void AuthDecodeIpcReqNative(IntPtr auth, [MarshalAs(UnmanagedType.LPStr)] string msg, IntPtr userData, DecodedIpcReqCb oReq, FfiResultStringCb oErr);
+
+        [DllImport(DllName, EntryPoint = "auth_decode_ipc_req_by_kind")]
+        private static extern void AuthDecodeIpcReqByKindNative(IntPtr auth, [MarshalAs(UnmanagedType.LPStr)] string msg, IntPtr userData, UIntAuthReqCb oAuth, UIntContainersReqCb oContainers, UIntByteListCb oUnregistered, UIntShareMDataRequestMetadataResponseListCb oShareMData, UIntShareADataRequestCb oShareAdata, UIntTransOwnershipRequestOwnershipChangeListCb oTransOwnership, UIntPermissionsReqCb oPermissions, FfiResultStringCb oErr);
+
+        public Task<string> AuthRevokeAppAsync(IntPtr auth, string appId)
+        {
+            var (ret, userData) = BindingUtils.PrepareTask<string>();
+            AuthRevokeAppNative(auth, appId, userData, DelegateOnFfiResultStringCb);
+            return ret;
+        }
+
+        [DllImport(DllName, EntryPoint = "auth_revoke_app")]
+        private static extern void AuthRevokeAppNative(IntPtr auth, [MarshalAs(UnmanagedType.LPStr)] string appId, IntPtr userData, FfiResultStringCb oCb);
+
+        public Task AuthFlushAppRevocationQueueAsync(IntPtr auth)
+        {
+            var (ret, userData) = BindingUtils.PrepareTask();
+            AuthFlushAppRevocationQueueNative(auth, userData, DelegateOnFfiResultCb);
+            return ret;
+        }
+
+        [DllImport(DllName, EntryPoint = "auth_flush_app_revocation_queue")]
+        private static extern void AuthFlushAppRevocationQueueNative(IntPtr auth, IntPtr userData, FfiResultCb oCb);
+
+        public Task AuthRevokeExpiredAppsAsync(IntPtr auth)
+        {
+            var (ret, userData) = BindingUtils.PrepareTask();
+            AuthRevokeExpiredAppsNative(auth, userData, DelegateOnFfiResultCb);
+            return ret;
+        }
+
+        [DllImport(DllName, EntryPoint = "auth_revoke_expired_apps")]
+        private static extern void AuthRevokeExpiredAppsNative(IntPtr auth, IntPtr userData, FfiResultCb oCb);
+
+        public Task<string> EncodeUnregisteredRespAsync(uint reqId, bool isGranted)
+        {
+            var (ret, userData) = BindingUtils.PrepareTask<string>();
+            EncodeUnregisteredRespNative(reqId, isGranted, userData, DelegateOnFfiResultStringCb);
+            return ret;
+        }
+
+        [DllImport(DllName, EntryPoint = "encode_unregistered_resp")]
+        private static extern void EncodeUnregisteredRespNative(uint reqId, [MarshalAs(UnmanagedType.U1)] bool isGranted, IntPtr userData, FfiResultStringCb oCb);
+
+        public Task<string> EncodeAuthRespAsync(IntPtr auth, ref AuthReq req, uint reqId, bool isGranted)
+        {
+            var reqNative = req.ToNative();
+            var (ret, userData) = BindingUtils.PrepareTask<string>();
+            EncodeAuthRespNative(auth, ref reqNative, reqId, isGranted, userData, DelegateOnFfiResultStringCb);
+            reqNative.Free();
+            return ret;
+        }
+
+        [DllImport(DllName, EntryPoint = "encode_auth_resp")]
+        private static extern void EncodeAuthRespNative(IntPtr auth, ref AuthReqNative req, uint reqId, [MarshalAs(UnmanagedType.U1)] bool isGranted, IntPtr userData, FfiResultStringCb oCb);
+
+        public Task<string> EncodeContainersRespAsync(IntPtr auth, ref ContainersReq req, uint reqId, bool isGranted)
+        {
+            var reqNative = req.ToNative();
+            var (ret, userData) = BindingUtils.PrepareTask<string>();
+            EncodeContainersRespNative(auth, ref reqNative, reqId, isGranted, userData, DelegateOnFfiResultStringCb);
+            reqNative.Free();
+            return ret;
+        }
+
+        [DllImport(DllName, EntryPoint = "encode_containers_resp")]
+        private static extern void EncodeContainersRespNative(IntPtr auth, ref ContainersReqNative req, uint reqId, [MarshalAs(UnmanagedType.U1)] bool isGranted, IntPtr userData, FfiResultStringCb oCb);
+
+        public Task<string> EncodePermissionsRespAsync(IntPtr auth, ref PermissionsReq req, uint reqId, bool isGranted)
+        {
+            var reqNative = req.ToNative();
+            var (ret, userData) = BindingUtils.PrepareTask<string>();
+            EncodePermissionsRespNative(auth, ref reqNative, reqId, isGranted, userData, DelegateOnFfiResultStringCb);
+            reqNative.Free();
+            return ret;
+        }
+
+        [DllImport(DllName, EntryPoint = "encode_permissions_resp")]
+        private static extern void EncodePermissionsRespNative(IntPtr auth, ref PermissionsReqNative req, uint reqId, [MarshalAs(UnmanagedType.U1)] bool isGranted, IntPtr userData, FfiResultStringCb oCb);
+
+        public Task<string> EncodeShareMDataRespAsync(IntPtr auth, ref ShareMDataRequest req, uint reqId, bool isGranted)
+        {
+            var reqNative = req.ToNative();
+            var (ret, userData) = BindingUtils.PrepareTask<string>();
+            EncodeShareMDataRespNative(auth, ref reqNative, reqId, isGranted, userData, DelegateOnFfiResultStringCb);
+            reqNative.Free();
+            return ret;
+        }
+
+        [DllImport(DllName, EntryPoint = "encode_share_mdata_resp")]
+        private static extern void EncodeShareMDataRespNative(IntPtr auth, ref ShareMDataRequestNative req, uint reqId, [MarshalAs(UnmanagedType.U1)] bool isGranted, IntPtr userData, FfiResultStringCb oCb);
+
+        public Task<string> EncodeShareAdataRespAsync(IntPtr auth, ref ShareADataRequest req, uint reqId, bool isGranted)
+        {
+            var reqNative = req.ToNative();
+            var (ret, userData) = BindingUtils.PrepareTask<string>();
+            EncodeShareAdataRespNative(auth, ref reqNative, reqId, isGranted, userData, DelegateOnFfiResultStringCb);
+            reqNative.Free();
+            return ret;
+        }
+
+        [DllImport(DllName, EntryPoint = "encode_share_adata_resp")]
+        private static extern void EncodeShareAdataRespNative(IntPtr auth, ref ShareADataRequestNative req, uint reqId, [MarshalAs(UnmanagedType.U1)] bool isGranted, IntPtr userData, FfiResultStringCb oCb);
+
+        public Task<string> EncodeTransOwnershipRespAsync(IntPtr auth, ref TransOwnershipRequest req, uint reqId, bool isGranted)
+        {
+            var reqNative = req.ToNative();
+            var (ret, userData) = BindingUtils.PrepareTask<string>();
+            EncodeTransOwnershipRespNative(auth, ref reqNative, reqId, isGranted, userData, DelegateOnFfiResultStringCb);
+            reqNative.Free();
+            return ret;
+        }
+
+        [DllImport(DllName, EntryPoint = "encode_trans_ownership_resp")]
+        private static extern void EncodeTransOwnershipRespNative(IntPtr auth, ref TransOwnershipRequestNative req, uint reqId, [MarshalAs(UnmanagedType.U1)] bool isGranted, IntPtr userData, FfiResultStringCb oCb);
+
+        public Task AuthInitLoggingAsync(string outputFileNameOverride)
+        {
+            var (ret, userData) = BindingUtils.PrepareTask();
+            AuthInitLoggingNative(outputFileNameOverride, userData, DelegateOnFfiResultCb);
+            return ret;
+        }
+
+        [DllImport(DllName, EntryPoint = "auth_init_logging")]
+        private static extern void AuthInitLoggingNative([MarshalAs(UnmanagedType.LPStr)] string outputFileNameOverride, IntPtr userData, FfiResultCb oCb);
+
+        public Task<string> AuthConfigDirPathAsync()
+        {
+            var (ret, userData) = BindingUtils.PrepareTask<string>();
+            AuthConfigDirPathNative(userData, DelegateOnFfiResultStringCb);
+            return ret;
+        }
+
+        [DllImport(DllName, EntryPoint = "auth_config_dir_path")]
+        private static extern void AuthConfigDirPathNative(IntPtr userData, FfiResultStringCb oCb);
+
+        private delegate void AppAllowanceStatusCb(IntPtr userData, IntPtr status);
+
+        private delegate void ByteListSignatureTxCb(IntPtr userData, IntPtr msgPtr, UIntPtr msgLen, IntPtr signatureTx);
+
+        private delegate void DecodedIpcReqCb(IntPtr userData, IntPtr req);
+
+        private delegate void FfiResultAppAccessListCb(IntPtr userData, IntPtr result, IntPtr appAccessPtr, UIntPtr appAccessLen);
+
+        #if __IOS__
+        [MonoPInvokeCallback(typeof(FfiResultAppAccessListCb))]
+        #endif
+        private static void OnFfiResultAppAccessListCb(IntPtr userData, IntPtr result, IntPtr appAccessPtr, UIntPtr appAccessLen)
+        {
+            BindingUtils.CompleteTask(userData, Marshal.PtrToStructure<FfiResult>(result), () => BindingUtils.CopyToObjectList<AppAccess>(appAccessPtr, (int)appAccessLen));
+        }
+
+        private static readonly FfiResultAppAccessListCb DelegateOnFfiResultAppAccessListCb = OnFfiResultAppAccessListCb;
+
+        private delegate void FfiResultAppAllowanceStatusListCb(IntPtr userData, IntPtr result, IntPtr statusesPtr, UIntPtr statusesLen);
+
+        #if __IOS__
+        [MonoPInvokeCallback(typeof(FfiResultAppAllowanceStatusListCb))]
+        #endif
+        private static void OnFfiResultAppAllowanceStatusListCb(IntPtr userData, IntPtr result, IntPtr statusesPtr, UIntPtr statusesLen)
+        {
+            BindingUtils.CompleteTask(userData, Marshal.PtrToStructure<FfiResult>(result), () => BindingUtils.CopyToObjectList<AppAllowanceStatus>(statusesPtr, (int)statusesLen));
+        }
+
+        private static readonly FfiResultAppAllowanceStatusListCb DelegateOnFfiResultAppAllowanceStatusListCb = OnFfiResultAppAllowanceStatusListCb;
+
+        private delegate void FfiResultAppExchangeInfoListCb(IntPtr userData, IntPtr result, IntPtr appExchangeInfoPtr, UIntPtr appExchangeInfoLen);
+
+        #if __IOS__
+        [MonoPInvokeCallback(typeof(FfiResultAppExchangeInfoListCb))]
+        #endif
+        private static void OnFfiResultAppExchangeInfoListCb(IntPtr userData, IntPtr result, IntPtr appExchangeInfoPtr, UIntPtr appExchangeInfoLen)
+        {
+            BindingUtils.CompleteTask(userData, Marshal.PtrToStructure<FfiResult>(result), () => BindingUtils.CopyToObjectList<AppExchangeInfo>(appExchangeInfoPtr, (int)appExchangeInfoLen));
+        }
+
+        private static readonly FfiResultAppExchangeInfoListCb DelegateOnFfiResultAppExchangeInfoListCb = OnFfiResultAppExchangeInfoListCb;
+
+        private delegate void FfiResultAppUsageSummaryListCb(IntPtr userData, IntPtr result, IntPtr appUsagePtr, UIntPtr appUsageLen);
+
+        #if __IOS__
+        [MonoPInvokeCallback(typeof(FfiResultAppUsageSummaryListCb))]
+        #endif
+        private static void OnFfiResultAppUsageSummaryListCb(IntPtr userData, IntPtr result, IntPtr appUsagePtr, UIntPtr appUsageLen)
+        {
+            BindingUtils.CompleteTask(userData, Marshal.PtrToStructure<FfiResult>(result), () => BindingUtils.CopyToObjectList<AppUsageSummary>(appUsagePtr, (int)appUsageLen));
+        }
+
+        private static readonly FfiResultAppUsageSummaryListCb DelegateOnFfiResultAppUsageSummaryListCb = OnFfiResultAppUsageSummaryListCb;
+
+        private delegate void FfiResultAuditEntryListCb(IntPtr userData, IntPtr result, IntPtr entriesPtr, UIntPtr entriesLen);
+
+        #if __IOS__
+        [MonoPInvokeCallback(typeof(FfiResultAuditEntryListCb))]
+        #endif
+        private static void OnFfiResultAuditEntryListCb(IntPtr userData, IntPtr result, IntPtr entriesPtr, UIntPtr entriesLen)
+        {
+            BindingUtils.CompleteTask(userData, Marshal.PtrToStructure<FfiResult>(result), () => BindingUtils.CopyToObjectList<AuditEntry>(entriesPtr, (int)entriesLen));
+        }
+
+        private static readonly FfiResultAuditEntryListCb DelegateOnFfiResultAuditEntryListCb = OnFfiResultAuditEntryListCb;
+
+        private delegate void FfiResultAuthenticatorCb(IntPtr userData, IntPtr result, IntPtr authenticator);
+
+        private delegate void FfiResultAuthenticatorStringCb(IntPtr userData, IntPtr result, IntPtr authenticator, string recoveryPhrase);
+
+        private delegate void FfiResultByteArrayBlsPublicKeyLenCb(IntPtr userData, IntPtr result, IntPtr publicKey);
+
+        #if __IOS__
+        [MonoPInvokeCallback(typeof(FfiResultByteArrayBlsPublicKeyLenCb))]
+        #endif
+        private static void OnFfiResultByteArrayBlsPublicKeyLenCb(IntPtr userData, IntPtr result, IntPtr publicKey)
+        {
+            BindingUtils.CompleteTask(userData, Marshal.PtrToStructure<FfiResult>(result), () => BindingUtils.CopyToByteArray(publicKey, (int)AuthConstants.BlsPublicKeyLen));
+        }
+
+        private static readonly FfiResultByteArrayBlsPublicKeyLenCb DelegateOnFfiResultByteArrayBlsPublicKeyLenCb = OnFfiResultByteArrayBlsPublicKeyLenCb;
+
+        private delegate void FfiResultByteListCb(IntPtr userData, IntPtr result, IntPtr backupPtr, UIntPtr backupLen);
+
+        #if __IOS__
+        [MonoPInvokeCallback(typeof(FfiResultByteListCb))]
+        #endif
+        private static void OnFfiResultByteListCb(IntPtr userData, IntPtr result, IntPtr backupPtr, UIntPtr backupLen)
+        {
+            BindingUtils.CompleteTask(userData, Marshal.PtrToStructure<FfiResult>(result), () => BindingUtils.CopyToByteList(backupPtr, (int)backupLen));
+        }
+
+        private static readonly FfiResultByteListCb DelegateOnFfiResultByteListCb = OnFfiResultByteListCb;
+
+        private delegate void FfiResultCb(IntPtr userData, IntPtr result);
+
+        #if __IOS__
+        [MonoPInvokeCallback(typeof(FfiResultCb))]
+        #endif
+        private static void OnFfiResultCb(IntPtr userData, IntPtr result)
+        {
+            BindingUtils.CompleteTask(userData, Marshal.PtrToStructure<FfiResult>(result));
+        }
+
+        private static readonly FfiResultCb DelegateOnFfiResultCb = OnFfiResultCb;
+
+        private delegate void FfiResultDeletionReportCb(IntPtr userData, IntPtr result, IntPtr report);
+
+        #if __IOS__
+        [MonoPInvokeCallback(typeof(FfiResultDeletionReportCb))]
+        #endif
+        private static void OnFfiResultDeletionReportCb(IntPtr userData, IntPtr result, IntPtr report)
+        {
+            BindingUtils.CompleteTask(userData, Marshal.PtrToStructure<FfiResult>(result), () => Marshal.PtrToStructure<DeletionReport>(report));
+        }
+
+        private static readonly FfiResultDeletionReportCb DelegateOnFfiResultDeletionReportCb = OnFfiResultDeletionReportCb;
+
+        private delegate void FfiResultDeviceKeyCb(IntPtr userData, IntPtr result, IntPtr deviceKey);
+
+        #if __IOS__
+        [MonoPInvokeCallback(typeof(FfiResultDeviceKeyCb))]
+        #endif
+        private static void OnFfiResultDeviceKeyCb(IntPtr userData, IntPtr result, IntPtr deviceKey)
+        {
+            BindingUtils.CompleteTask(userData, Marshal.PtrToStructure<FfiResult>(result), () => Marshal.PtrToStructure<DeviceKey>(deviceKey));
+        }
+
+        private static readonly FfiResultDeviceKeyCb DelegateOnFfiResultDeviceKeyCb = OnFfiResultDeviceKeyCb;
+
+        private delegate void FfiResultDeviceListCb(IntPtr userData, IntPtr result, IntPtr devicesPtr, UIntPtr devicesLen);
+
+        #if __IOS__
+        [MonoPInvokeCallback(typeof(FfiResultDeviceListCb))]
+        #endif
+        private static void OnFfiResultDeviceListCb(IntPtr userData, IntPtr result, IntPtr devicesPtr, UIntPtr devicesLen)
+        {
+            BindingUtils.CompleteTask(userData, Marshal.PtrToStructure<FfiResult>(result), () => BindingUtils.CopyToObjectList<Device>(devicesPtr, (int)devicesLen));
+        }
+
+        private static readonly FfiResultDeviceListCb DelegateOnFfiResultDeviceListCb = OnFfiResultDeviceListCb;
+
+        private delegate void FfiResultRegisteredAppListCb(IntPtr userData, IntPtr result, IntPtr registeredAppPtr, UIntPtr registeredAppLen);
+
+        #if __IOS__
+        [MonoPInvokeCallback(typeof(FfiResultRegisteredAppListCb))]
+        #endif
+        private static void OnFfiResultRegisteredAppListCb(IntPtr userData, IntPtr result, IntPtr registeredAppPtr, UIntPtr registeredAppLen)
+        {
+            BindingUtils.CompleteTask(userData, Marshal.PtrToStructure<FfiResult>(result), () => BindingUtils.CopyToObjectList<RegisteredAppNative>(registeredAppPtr, (int)registeredAppLen).Select(native => new RegisteredApp(native)).ToList());
+        }
+
+        private static readonly FfiResultRegisteredAppListCb DelegateOnFfiResultRegisteredAppListCb = OnFfiResultRegisteredAppListCb;
+
+        private delegate void FfiResultStringCb(IntPtr userData, IntPtr result, string recoveryPhrase);
+
+        #if __IOS__
+        [MonoPInvokeCallback(typeof(FfiResultStringCb))]
+        #endif
+        private static void OnFfiResultStringCb(IntPtr userData, IntPtr result, string recoveryPhrase)
+        {
+            BindingUtils.CompleteTask(userData, Marshal.PtrToStructure<FfiResult>(result), () => recoveryPhrase);
+        }
+
+        private static readonly FfiResultStringCb DelegateOnFfiResultStringCb = OnFfiResultStringCb;
+
+        private delegate void FfiResultTransactionRecordListCb(IntPtr userData, IntPtr result, IntPtr transactionsPtr, UIntPtr transactionsLen);
+
+        #if __IOS__
+        [MonoPInvokeCallback(typeof(FfiResultTransactionRecordListCb))]
+        #endif
+        private static void OnFfiResultTransactionRecordListCb(IntPtr userData, IntPtr result, IntPtr transactionsPtr, UIntPtr transactionsLen)
+        {
+            BindingUtils.CompleteTask(userData, Marshal.PtrToStructure<FfiResult>(result), () => BindingUtils.CopyToObjectList<TransactionRecord>(transactionsPtr, (int)transactionsLen));
+        }
+
+        private static readonly FfiResultTransactionRecordListCb DelegateOnFfiResultTransactionRecordListCb = OnFfiResultTransactionRecordListCb;
+
+        private delegate void NoneCb(IntPtr userData);
+
+        private delegate void UIntAuthReqCb(IntPtr userData, uint reqId, IntPtr req);
+
+        private delegate void UIntByteListCb(IntPtr userData, uint reqId, IntPtr extraDataPtr, UIntPtr extraDataLen);
+
+        private delegate void UIntContainersReqCb(IntPtr userData, uint reqId, IntPtr req);
+
+        private delegate void UIntPermissionsReqCb(IntPtr userData, uint reqId, IntPtr req);
+
+        private delegate void UIntShareADataRequestCb(IntPtr userData, uint reqId, IntPtr req);
+
+        private delegate void UIntShareMDataRequestMetadataResponseListCb(IntPtr userData, uint reqId, IntPtr req, IntPtr metadataPtr, UIntPtr metadataLen);
+
+        private delegate void UIntTransOwnershipRequestOwnershipChangeListCb(IntPtr userData, uint reqId, IntPtr req, IntPtr changesPtr, UIntPtr changesLen);
+
+    }
+}
+#endif
@@ -0,0 +1,34 @@
+// Copyright 2019 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT> or the Modified
+// BSD license <LICENSE-BSD or https://opensource.org/licenses/BSD-3-Clause>,
+// at your option. This file may not be copied, modified, or distributed
+// except according to those terms. Please review the Licences for the
+// specific language governing permissions and limitations relating to use
+// of the SAFE Network Software.
+using System;
+using JetBrains.Annotations;
+
+namespace SafeAuth.Utilities
+{
+    [PublicAPI]
+    public static class AuthConstants
+    {
+        public const ulong MaidsafeTag = 5483000;
+        public const ulong DirTag = 15000;
+        public const int ErrAccountContainersCreation = 1;
+        public const int ErrPendingRevocation = 2;
+        public const int ErrUnknownDevice = 3;
+        public const int ErrBackupMismatch = 4;
+        public const uint IpcReqAuth = 0;
+        public const uint IpcReqContainers = 1;
+        public const uint IpcReqUnregistered = 2;
+        public const uint IpcReqShareMData = 3;
+        public const uint IpcReqShareAdata = 4;
+        public const uint IpcReqTransOwnership = 5;
+        public const uint IpcReqPermissions = 6;
+        public const ulong AsymPublicKeyLen = 48;
+        public const ulong XorNameLen = 32;
+    }
+}
@@ -0,0 +1,96 @@
+// Copyright 2019 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT> or the Modified
+// BSD license <LICENSE-BSD or https://opensource.org/licenses/BSD-3-Clause>,
+// at your option. This file may not be copied, modified, or distributed
+// except according to those terms. Please review the Licences for the
+// specific language governing permissions and limitations relating to use
+// of the SAFE Network Software.
+using System;
+using System.Collections.Generic;
+using System.Threading.Tasks;
+
+namespace SafeAuth.Utilities
+{
+    public partial interface IAuthBindings
+    {
+        Task AuthSendSignatureAsync(IntPtr signatureTx, byte[] signature);
+
+        Task<string> AuthSetUpRecoveryAsync(IntPtr auth);
+
+        Task<List<byte>> AuthExportBackupAsync(IntPtr auth, string passphrase);
+
+        Task AuthChangePasswordAsync(IntPtr auth, string currentPassword, string newPassword);
+
+        Task AuthChangeLocatorAsync(IntPtr auth, string newLocator, string password);
+
+        Task AuthRotateClientKeysAsync(IntPtr auth);
+
+        Task AuthReconnectAsync(IntPtr auth);
+
+        Task<byte[]> AuthAccountPublicKeyAsync(IntPtr auth);
+
+        Task<List<byte>> AuthAccountPublicIdAsync(IntPtr auth);
+
+        Task AuthSetConfigDirPathAsync(string newPath);
+
+        void AuthFree(IntPtr auth);
+
+        bool AuthIsMock();
+
+        Task AuthRmRevokedAppAsync(IntPtr auth, string appId);
+
+        Task<List<AppExchangeInfo>> AuthRevokedAppsAsync(IntPtr auth);
+
+        Task<List<RegisteredApp>> AuthRegisteredAppsAsync(IntPtr auth);
+
+        Task<List<AppUsageSummary>> AuthAppsUsageAsync(IntPtr auth);
+
+        Task<List<AppAccess>> AuthAppsAccessingMutableDataAsync(IntPtr auth, byte[] mdName, ulong mdTypeTag);
+
+        Task<List<AuditEntry>> AuthAuditLogAsync(IntPtr auth);
+
+        Task<string> AuthExportAuditLogAsync(IntPtr auth);
+
+        Task AuthSetAppAllowanceAsync(IntPtr auth, string appId, ulong limit, bool daily);
+
+        Task AuthRmAppAllowanceAsync(IntPtr auth, string appId);
+
+        Task<List<AppAllowanceStatus>> AuthAppAllowancesAsync(IntPtr auth);
+
+        Task<List<TransactionRecord>> AuthTransactionHistoryAsync(IntPtr auth);
+
+        Task<DeletionReport> AuthDeleteAccountAsync(IntPtr auth, string password, byte[] destination, bool dryRun);
+
+        Task<DeviceKey> AuthRegisterDeviceAsync(IntPtr auth, string name);
+
+        Task<List<Device>> AuthDevicesAsync(IntPtr auth);
+
+        Task AuthLogOutDeviceAsync(IntPtr auth, ref DeviceId deviceId);
+
+        Task<string> AuthRevokeAppAsync(IntPtr auth, string appId);
+
+        Task AuthFlushAppRevocationQueueAsync(IntPtr auth);
+
+        Task AuthRevokeExpiredAppsAsync(IntPtr auth);
+
+        Task<string> EncodeUnregisteredRespAsync(uint reqId, bool isGranted);
+
+        Task<string> EncodeAuthRespAsync(IntPtr auth, ref AuthReq req, uint reqId, bool isGranted);
+
+        Task<string> EncodeContainersRespAsync(IntPtr auth, ref ContainersReq req, uint reqId, bool isGranted);
+
+        Task<string> EncodePermissionsRespAsync(IntPtr auth, ref PermissionsReq req, uint reqId, bool isGranted);
+
+        Task<string> EncodeShareMDataRespAsync(IntPtr auth, ref ShareMDataRequest req, uint reqId, bool isGranted);
+
+        Task<string> EncodeShareAdataRespAsync(IntPtr auth, ref ShareADataRequest req, uint reqId, bool isGranted);
+
+        Task<string> EncodeTransOwnershipRespAsync(IntPtr auth, ref TransOwnershipRequest req, uint reqId, bool isGranted);
+
+        Task AuthInitLoggingAsync(string outputFileNameOverride);
+
+        Task<string> AuthConfigDirPathAsync();
+    }
+}
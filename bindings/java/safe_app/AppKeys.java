@@ -0,0 +1,83 @@
+// Copyright 2019 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT> or the Modified
+// BSD license <LICENSE-BSD or https://opensource.org/licenses/BSD-3-Clause>,
+// at your option. This file may not be copied, modified, or distributed
+// except according to those terms. Please review the Licences for the
+// specific language governing permissions and limitations relating to use
+// of the SAFE Network Software.
+package net.maidsafe.safe_app;
+
+/// Represents the needed keys to work with the data.public class AppKeys {
+	private byte[] fullId;
+	private long fullIdLen;
+	private byte[] encKey;
+	private byte[] encPublicKey;
+	private byte[] encSecretKey;
+	private long encSecretKeyLen;
+
+	public AppKeys() {
+		this.fullId = new byte[] {};
+		this.encKey = new byte[] {};
+		this.encPublicKey = new byte[] {};
+		this.encSecretKey = new byte[] {};
+	}
+	public AppKeys(byte[] fullId, long fullIdLen, byte[] encKey, byte[] encPublicKey, byte[] encSecretKey, long encSecretKeyLen) {
+		this.fullId = fullId;
+		this.fullIdLen = fullIdLen;
+		this.encKey = encKey;
+		this.encPublicKey = encPublicKey;
+		this.encSecretKey = encSecretKey;
+		this.encSecretKeyLen = encSecretKeyLen;
+	}
+	public byte[] getFullId() {
+		return fullId;
+	}
+
+	public void setFullId(final byte[] val) {
+		this.fullId = val;
+	}
+
+	public long getFullIdLen() {
+		return fullIdLen;
+	}
+
+	public void setFullIdLen(final long val) {
+		this.fullIdLen = val;
+	}
+
+	public byte[] getEncKey() {
+		return encKey;
+	}
+
+	public void setEncKey(final byte[] val) {
+		this.encKey = val;
+	}
+
+	public byte[] getEncPublicKey() {
+		return encPublicKey;
+	}
+
+	public void setEncPublicKey(final byte[] val) {
+		this.encPublicKey = val;
+	}
+
+	public byte[] getEncSecretKey() {
+		return encSecretKey;
+	}
+
+	public void setEncSecretKey(final byte[] val) {
+		this.encSecretKey = val;
+	}
+
+	public long getEncSecretKeyLen() {
+		return encSecretKeyLen;
+	}
+
+	public void setEncSecretKeyLen(final long val) {
+		this.encSecretKeyLen = val;
+	}
+
+}
+
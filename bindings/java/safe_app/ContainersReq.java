@@ -0,0 +1,51 @@
+// Copyright 2019 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT> or the Modified
+// BSD license <LICENSE-BSD or https://opensource.org/licenses/BSD-3-Clause>,
+// at your option. This file may not be copied, modified, or distributed
+// except according to those terms. Please review the Licences for the
+// specific language governing permissions and limitations relating to use
+// of the SAFE Network Software.
+package net.maidsafe.safe_app;
+
+/// Containers request.public class ContainersReq {
+	private AppExchangeInfo app;
+	private ContainerPermissions[] containers;
+	private long containersLen;
+
+	public ContainersReq() {
+		this.app = new AppExchangeInfo();
+		this.containers = new ContainerPermissions[] {};
+	}
+	public ContainersReq(AppExchangeInfo app, ContainerPermissions[] containers, long containersLen) {
+		this.app = app;
+		this.containers = containers;
+		this.containersLen = containersLen;
+	}
+	public AppExchangeInfo getApp() {
+		return app;
+	}
+
+	public void setApp(final AppExchangeInfo val) {
+		this.app = val;
+	}
+
+	public ContainerPermissions[] getContainer() {
+		return containers;
+	}
+
+	public void setContainer(final ContainerPermissions[] val) {
+		this.containers = val;
+	}
+
+	public long getContainersLen() {
+		return containersLen;
+	}
+
+	public void setContainersLen(final long val) {
+		this.containersLen = val;
+	}
+
+}
+
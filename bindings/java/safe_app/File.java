@@ -0,0 +1,111 @@
+// Copyright 2019 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT> or the Modified
+// BSD license <LICENSE-BSD or https://opensource.org/licenses/BSD-3-Clause>,
+// at your option. This file may not be copied, modified, or distributed
+// except according to those terms. Please review the Licences for the
+// specific language governing permissions and limitations relating to use
+// of the SAFE Network Software.
+package net.maidsafe.safe_app;
+
+/// FFI-wrapper for `File`.public class File {
+	private long size;
+	private long createdSec;
+	private int createdNsec;
+	private long modifiedSec;
+	private int modifiedNsec;
+	private byte[] userMetadata;
+	private long userMetadataLen;
+	private byte[] dataMapName;
+	private boolean published;
+
+	public File() {
+		this.userMetadata = new byte[] {};
+		this.dataMapName = new byte[] {};
+	}
+	public File(long size, long createdSec, int createdNsec, long modifiedSec, int modifiedNsec, byte[] userMetadata, long userMetadataLen, byte[] dataMapName, boolean published) {
+		this.size = size;
+		this.createdSec = createdSec;
+		this.createdNsec = createdNsec;
+		this.modifiedSec = modifiedSec;
+		this.modifiedNsec = modifiedNsec;
+		this.userMetadata = userMetadata;
+		this.userMetadataLen = userMetadataLen;
+		this.dataMapName = dataMapName;
+		this.published = published;
+	}
+	public long getSize() {
+		return size;
+	}
+
+	public void setSize(final long val) {
+		this.size = val;
+	}
+
+	public long getCreatedSec() {
+		return createdSec;
+	}
+
+	public void setCreatedSec(final long val) {
+		this.createdSec = val;
+	}
+
+	public int getCreatedNsec() {
+		return createdNsec;
+	}
+
+	public void setCreatedNsec(final int val) {
+		this.createdNsec = val;
+	}
+
+	public long getModifiedSec() {
+		return modifiedSec;
+	}
+
+	public void setModifiedSec(final long val) {
+		this.modifiedSec = val;
+	}
+
+	public int getModifiedNsec() {
+		return modifiedNsec;
+	}
+
+	public void setModifiedNsec(final int val) {
+		this.modifiedNsec = val;
+	}
+
+	public byte[] getUserMetadaum() {
+		return userMetadata;
+	}
+
+	public void setUserMetadaum(final byte[] val) {
+		this.userMetadata = val;
+	}
+
+	public long getUserMetadataLen() {
+		return userMetadataLen;
+	}
+
+	public void setUserMetadataLen(final long val) {
+		this.userMetadataLen = val;
+	}
+
+	public byte[] getDataMapName() {
+		return dataMapName;
+	}
+
+	public void setDataMapName(final byte[] val) {
+		this.dataMapName = val;
+	}
+
+	public boolean getPublished() {
+		return published;
+	}
+
+	public void setPublished(final boolean val) {
+		this.published = val;
+	}
+
+}
+
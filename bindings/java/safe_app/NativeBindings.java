@@ -0,0 +1,473 @@
+// Copyright 2019 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT> or the Modified
+// BSD license <LICENSE-BSD or https://opensource.org/licenses/BSD-3-Clause>,
+// at your option. This file may not be copied, modified, or distributed
+// except according to those terms. Please review the Licences for the
+// specific language governing permissions and limitations relating to use
+// of the SAFE Network Software.
+package net.maidsafe.safe_app;
+
+
+                         public class NativeBindings {
+
+                         	/**
+	 * Try to restore a failed connection with the network. */
+	public static native void appReconnect(long app, CallbackResult oCb);
+	
+	/**
+	 * Sets the path from which the `safe_core.config` file will be read. */
+	public static native void appSetConfigDirPath(String newPath, CallbackResult oCb);
+	
+	/**
+	 * Discard and clean up the previously allocated app instance. * Use this only if the app is obtained from one of the auth * functions in this crate. Using `app` after a call to this * function is undefined behaviour. */
+	public static native void appFree(long app);
+	
+	/**
+	 * Resets the object cache. Removes all objects currently in the object cache * and invalidates all existing object handles. */
+	public static native void appResetObjectCache(long app, CallbackResult oCb);
+	
+	/**
+	 * Returns the name of the app's container. */
+	public static native void appContainerName(String appId, CallbackResultString oCb);
+	
+	/**
+	 * Returns true if this crate was compiled against mock-routing. */
+	boolean appIsMock();
+	
+	/**
+	 * Fetch access info from the network. */
+	public static native void accessContainerRefreshAccessInfo(long app, CallbackResult oCb);
+	
+	/**
+	 * Retrieve a list of container names that an app has access to. */
+	public static native void accessContainerFetch(long app, CallbackResultContainerPermissionsArrayLen oCb);
+	
+	/**
+	 * Retrieve `MDataInfo` for the given container name from the access container. */
+	public static native void accessContainerGetContainerMdataInfo(long app, String name, CallbackResultMDataInfo oCb);
+	
+	/**
+	 * Construct `CipherOpt::PlainText` handle. */
+	public static native void cipherOptNewPlaintext(long app, CallbackResultCipherOptHandle oCb);
+	
+	/**
+	 * Construct `CipherOpt::Symmetric` handle. */
+	public static native void cipherOptNewSymmetric(long app, CallbackResultCipherOptHandle oCb);
+	
+	/**
+	 * Construct `CipherOpt::Asymmetric` handle. */
+	public static native void cipherOptNewAsymmetric(long app, long peerEncryptKeyH, CallbackResultCipherOptHandle oCb);
+	
+	/**
+	 * Construct `CipherOpt::AuthenticatedAsymmetric` handle. */
+	public static native void cipherOptNewAuthenticatedAsymmetric(long app, long peerEncryptKeyH, CallbackResultCipherOptHandle oCb);
+	
+	/**
+	 * Free `CipherOpt` handle. */
+	public static native void cipherOptFree(long app, long handle, CallbackResult oCb);
+	
+	/**
+	 * Get the coin balance of the account the app is authorised for. * * Fails with `ERR_GET_BALANCE_DENIED` if the app was not granted the `get_balance` permission. * * Callback parameters: user data, error code, balance */
+	public static native void appGetBalance(long app, CallbackResultLong oCb);
+	
+	/**
+	 * Transfer `amount` coins from the account's balance to the balance at `destination`. * * Fails with `ERR_TRANSFER_COINS_DENIED` if the app was not granted the `transfer_coins` * permission, which is also the case once the app has been given a coin allowance, and with * `ERR_INSUFFICIENT_BALANCE` if the balance can't cover the amount. * * Callback parameters: user data, error code, transaction ID */
+	public static native void appTransferCoins(long app, byte[] destination, long amount, CallbackResultLong oCb);
+	
+	/**
+	 * Get the balance of the app's allowance wallet, the coins left of the allowance the user * granted the app. * * Fails with `ERR_NO_SUCH_BALANCE` if the app hasn't been granted an allowance. * * Callback parameters: user data, error code, balance */
+	public static native void appAllowanceBalance(long app, CallbackResultLong oCb);
+	
+	/**
+	 * Transfer `amount` coins from the app's allowance wallet to the balance at `destination`. * * Fails with `ERR_INSUFFICIENT_BALANCE` if what's left of the allowance can't cover the amount * and with `ERR_NO_SUCH_BALANCE` if the app hasn't been granted an allowance. * * Callback parameters: user data, error code, transaction ID */
+	public static native void appAllowanceTransferCoins(long app, byte[] destination, long amount, CallbackResultLong oCb);
+	
+	/**
+	 * Create a new coin balance owned by the key at `new_balance_owner_h`, preloading it with * `amount` coins from the account's balance. * * Fails with `ERR_TRANSFER_COINS_DENIED` if the app is not permitted to spend from the balance * and with `ERR_INSUFFICIENT_BALANCE` if the balance can't cover the amount and the fee. * * Callback parameters: user data, error code, transaction ID */
+	public static native void appCreateBalance(long app, long newBalanceOwnerH, long amount, CallbackResultLong oCb);
+	
+	/**
+	 * Get the transaction history of the account's coin balance, oldest first. * * Fails with `ERR_GET_BALANCE_DENIED` if the app was not granted the `get_balance` permission. * Only available against the mock vault, as vaults don't keep a queryable transaction history * yet. * * Callback parameters: user data, error code, transaction records vector, vector size */
+	public static native void appTransactionHistory(long app, CallbackResultTransactionRecordArrayLen oCb);
+	
+	/**
+	 * Gets the public signing key of the app. */
+	public static native void appPubSignKey(long app, CallbackResultSignPubKeyHandle oCb);
+	
+	/**
+	 * Generates a new sign key pair (public & private key). */
+	public static native void signGenerateKeyPair(long app, CallbackResultSignPubKeyHandleSignSecKeyHandle oCb);
+	
+	/**
+	 * Creates new public signing key from raw array. */
+	public static native void signPubKeyNew(long app, byte[] data, CallbackResultSignPubKeyHandle oCb);
+	
+	/**
+	 * Retrieves the public signing key as a raw array. */
+	public static native void signPubKeyGet(long app, long handle, CallbackResultByteArrayLen oCb);
+	
+	/**
+	 * Frees public signing key from memory. */
+	public static native void signPubKeyFree(long app, long handle, CallbackResult oCb);
+	
+	/**
+	 * Creates new secret signing key from raw array. */
+	public static native void signSecKeyNew(long app, byte[] data, CallbackResultSignSecKeyHandle oCb);
+	
+	/**
+	 * Retrieves the secret signing key as a raw array. */
+	public static native void signSecKeyGet(long app, long handle, CallbackResultByteArrayLen oCb);
+	
+	/**
+	 * Frees secret signing key from memory. */
+	public static native void signSecKeyFree(long app, long handle, CallbackResult oCb);
+	
+	/**
+	 * Gets the public encryption key of the app. */
+	public static native void appPubEncKey(long app, CallbackResultEncryptPubKeyHandle oCb);
+	
+	/**
+	 * Generates a new encryption key pair (public & private key). */
+	public static native void encGenerateKeyPair(long app, CallbackResultEncryptPubKeyHandleEncryptSecKeyHandle oCb);
+	
+	/**
+	 * Creates new public encryption key from raw array. */
+	public static native void encPubKeyNew(long app, byte[] data, CallbackResultEncryptPubKeyHandle oCb);
+	
+	/**
+	 * Retrieves the public encryption key as a raw array. */
+	public static native void encPubKeyGet(long app, long handle, CallbackResultAsymPublicKey oCb);
+	
+	/**
+	 * Frees public encryption key from memory. */
+	public static native void encPubKeyFree(long app, long handle, CallbackResult oCb);
+	
+	/**
+	 * Creates new private encryption key from raw array. */
+	public static native void encSecretKeyNew(long app, byte[] sk, CallbackResultEncryptSecKeyHandle oCb);
+	
+	/**
+	 * Retrieves the private encryption key as a raw array. */
+	public static native void encSecretKeyGet(long app, long handle, CallbackResultByteArrayLen oCb);
+	
+	/**
+	 * Frees private encryption key from memory. */
+	public static native void encSecretKeyFree(long app, long handle, CallbackResult oCb);
+	
+	/**
+	 * Signs arbitrary data using a given secret sign key. * * If `sign_sk_h` is `SIGN_WITH_APP`, then uses the app's own secret key to sign. */
+	public static native void sign(long app, byte[] data, long signSkH, CallbackResultByteArrayLen oCb);
+	
+	/**
+	 * Verifies signed data using a given public sign key. * * If `sign_pk_h` is `VERIFY_WITH_APP`, then uses the app's own public key to verify. */
+	public static native void verify(long app, byte[] signedData, byte[] signature, long signPkH, CallbackResultInt oCb);
+	
+	/**
+	 * Encrypts arbitrary data for a single recipient. * * You should provide a recipient's public key. */
+	public static native void encryptSealedBox(long app, byte[] data, long publicKeyH, CallbackResultByteArrayLen oCb);
+	
+	/**
+	 * Decrypts arbitrary data for a single recipient. * * You should provide a recipients's private key. */
+	public static native void decryptSealedBox(long app, byte[] data, long secretKeyH, CallbackResultByteArrayLen oCb);
+	
+	/**
+	 * Encrypts arbitrary data for a single recipient, signed by the app so that the recipient can * verify who wrote it. * * You should provide a recipient's public key. */
+	public static native void encryptAuthenticatedSealedBox(long app, byte[] data, long publicKeyH, CallbackResultByteArrayLen oCb);
+	
+	/**
+	 * Decrypts data encrypted with `encrypt_authenticated_sealed_box` and verifies the signature of * its sender. * * Callback parameters: user data, error code, plaintext, plaintext length, sender's public sign * key handle */
+	public static native void decryptAuthenticatedSealedBox(long app, byte[] data, long secretKeyH, CallbackResultByteArrayLenSignPubKeyHandle oCb);
+	
+	/**
+	 * Returns a sha3 hash for a given data. */
+	public static native void sha3Hash(byte[] data, CallbackResultByteArrayLen oCb);
+	
+	/**
+	 * Get a Self Encryptor. */
+	public static native void idataNewSelfEncryptor(long app, boolean published, CallbackResultSEWriterHandle oCb);
+	
+	/**
+	 * Write to Self Encryptor. */
+	public static native void idataWriteToSelfEncryptor(long app, long seH, byte[] data, CallbackResult oCb);
+	
+	/**
+	 * Close Self Encryptor and free the Self Encryptor Writer handle. */
+	public static native void idataCloseSelfEncryptor(long app, long seH, long cipherOptH, boolean published, CallbackResultXorNameArray oCb);
+	
+	/**
+	 * Fetch Self Encryptor. */
+	public static native void idataFetchSelfEncryptor(long app, byte[] name, boolean published, CallbackResultSEReaderHandle oCb);
+	
+	/**
+	 * Get serialised size of `ImmutableData`. */
+	public static native void idataSerialisedSize(long app, byte[] name, boolean published, CallbackResultLong oCb);
+	
+	/**
+	 * Get data size from Self Encryptor. */
+	public static native void idataSize(long app, long seH, CallbackResultLong oCb);
+	
+	/**
+	 * Read from Self Encryptor. */
+	public static native void idataReadFromSelfEncryptor(long app, long seH, long fromPos, long len, CallbackResultByteArrayLen oCb);
+	
+	/**
+	 * Free Self Encryptor Writer handle. */
+	public static native void idataSelfEncryptorWriterFree(long app, long handle, CallbackResult oCb);
+	
+	/**
+	 * Free Self Encryptor Reader handle. */
+	public static native void idataSelfEncryptorReaderFree(long app, long handle, CallbackResult oCb);
+	
+	/**
+	 * Encode `AuthReq`. */
+	public static native void encodeAuthReq(AuthReq req, CallbackResultIntString oCb);
+	
+	/**
+	 * Encode `ContainersReq`. */
+	public static native void encodeContainersReq(ContainersReq req, CallbackResultIntString oCb);
+	
+	/**
+	 * Encode `PermissionsReq`. */
+	public static native void encodePermissionsReq(PermissionsReq req, CallbackResultIntString oCb);
+	
+	/**
+	 * Encode `AuthReq` for an unregistered client. */
+	public static native void encodeUnregisteredReq(byte[] extraData, CallbackResultIntString oCb);
+	
+	/**
+	 * Encode `ShareMDataReq`. */
+	public static native void encodeShareMdataReq(ShareMDataRequest req, CallbackResultIntString oCb);
+	
+	/**
+	 * Encode `ShareADataReq`. */
+	public static native void encodeShareAdataReq(ShareADataRequest req, CallbackResultIntString oCb);
+	
+	/**
+	 * Encode `TransOwnershipReq`. */
+	public static native void encodeTransOwnershipReq(TransOwnershipRequest req, CallbackResultIntString oCb);
+	
+	/**
+	 * Decode IPC message. * * Only the responses this function has a callback for are decoded. Any other response is * reported through `o_err`; use `decode_ipc_resp` to decode every kind of response. */
+	public static native void decodeIpcMsg(String msg, CallbackIntAuthGranted oAuth, CallbackIntByteArrayLen oUnregistered, CallbackInt oContainers, CallbackInt oShareMdata, CallbackVoid oRevoked, CallbackResultInt oErr);
+	
+	/**
+	 * Generate an ephemeral key pair for sealed IPC responses. * * The public key goes into `AuthReq::response_key`. The serialised secret key is needed to open * the response with `open_sealed_ipc_msg`. */
+	public static native void genResponseKeyPair(CallbackResultBlsPublicKeyByteArrayLen oCb);
+	
+	/**
+	 * Open a sealed IPC response to the request with the given ID. * * The response is decrypted with the serialised secret key obtained from * `gen_response_key_pair`, and must be signed by `signer`: the public key of the user's account, * pinned from an earlier response or given to the app out of band by the authenticator's * `auth_account_public_key`. Apps which don't know the key yet pass a null `signer` to trust the * signer of their first response, and should pin the key passed to the callback for the later * ones. Responses to other requests are rejected, and so are unsealed responses. On success the * callback receives the response in the legacy unsealed encoding, ready to be passed to * `decode_ipc_resp`, along with the verified signer. Messages which aren't responses, like * revocation notices, are passed through unchanged, with a null signer. */
+	public static native void openSealedIpcMsg(String msg, byte[] secretKey, BlsPublicKey signer, int reqId, CallbackResultStringBlsPublicKey oCb);
+	
+	/**
+	 * This function should be called to enable logging to a file. * If `output_file_name_override` is provided, then this path will be used for * the log output file. */
+	public static native void appInitLogging(String outputFileNameOverride, CallbackResult oCb);
+	
+	/**
+	 * Returns the path at which the the configuration files are expected. */
+	public static native void appConfigDirPath(CallbackResultString oCb);
+	
+	/**
+	 * Create encrypted mdata info with explicit data name and a * provided private key. */
+	public static native void mdataInfoNewPrivate(boolean mdSeq, byte[] name, long typeTag, byte[] secretKey, byte[] nonce, CallbackResultMDataInfo oCb);
+	
+	/**
+	 * Create random, non-encrypted mdata info. */
+	public static native void mdataInfoRandomPublic(boolean mdSeq, long typeTag, CallbackResultMDataInfo oCb);
+	
+	/**
+	 * Create random, encrypted mdata info. */
+	public static native void mdataInfoRandomPrivate(boolean mdSeq, long typeTag, CallbackResultMDataInfo oCb);
+	
+	/**
+	 * Encrypt mdata entry key using the corresponding mdata info. */
+	public static native void mdataInfoEncryptEntryKey(MDataInfo info, byte[] input, CallbackResultByteArrayLen oCb);
+	
+	/**
+	 * Encrypt mdata entry value using the corresponding mdata info. */
+	public static native void mdataInfoEncryptEntryValue(MDataInfo info, byte[] input, CallbackResultByteArrayLen oCb);
+	
+	/**
+	 * Decrypt mdata entry value or a key using the corresponding mdata info. */
+	public static native void mdataInfoDecrypt(MDataInfo info, byte[] input, CallbackResultByteArrayLen oCb);
+	
+	/**
+	 * Serialise `MDataInfo`. */
+	public static native void mdataInfoSerialise(MDataInfo info, CallbackResultByteArrayLen oCb);
+	
+	/**
+	 * Deserialise `MDataInfo`. */
+	public static native void mdataInfoDeserialise(byte[] encodedPtr, CallbackResultMDataInfo oCb);
+	
+	/**
+	 * Create new mutable data and put it on the network. * * `permissions_h` is a handle to permissions to be set on the mutable data. * If `PERMISSIONS_EMPTY`, the permissions will be empty. * * `entries_h` is a handle to entries for the mutable data. * If `ENTRIES_EMPTY`, the entries will be empty. */
+	public static native void mdataPut(long app, MDataInfo info, long permissionsH, long entriesH, CallbackResult oCb);
+	
+	/**
+	 * Get version of the mutable data. */
+	public static native void mdataGetVersion(long app, MDataInfo info, CallbackResultLong oCb);
+	
+	/**
+	 * Get size of serialised mutable data. */
+	public static native void mdataSerialisedSize(long app, MDataInfo info, CallbackResultLong oCb);
+	
+	/**
+	 * Get value at the given key from the mutable data. * * Please notice that if a value is fetched from a private `MutableData`, * it's not automatically decrypted. */
+	public static native void mdataGetValue(long app, MDataInfo info, byte[] key, CallbackResultByteArrayLenLong oCb);
+	
+	/**
+	 * Get a handle to the complete list of entries in the mutable data. */
+	public static native void mdataEntries(long app, MDataInfo info, CallbackResultMDataEntriesHandle oCb);
+	
+	/**
+	 * Get list of all keys in the mutable data. */
+	public static native void mdataListKeys(long app, MDataInfo info, CallbackResultMDataKeyArrayLen oCb);
+	
+	/**
+	 * Get list of all values in the mutable data. */
+	public static native void seqMdataListValues(long app, MDataInfo info, CallbackResultMDataValueArrayLen oCb);
+	
+	/**
+	 * Mutate entries of the mutable data. */
+	public static native void mdataMutateEntries(long app, MDataInfo info, long actionsH, CallbackResult oCb);
+	
+	/**
+	 * Get list of all permissions set on the mutable data. */
+	public static native void mdataListPermissions(long app, MDataInfo info, CallbackResultMDataPermissionsHandle oCb);
+	
+	/**
+	 * Get list of permissions set on the mutable data for the given user. * * User is either handle to a signing key or `USER_ANYONE`. */
+	public static native void mdataListUserPermissions(long app, MDataInfo info, long userH, CallbackResultPermissionSet oCb);
+	
+	/**
+	 * Set permissions set on the mutable data for the given user. * * User is either handle to a signing key or `USER_ANYONE`. */
+	public static native void mdataSetUserPermissions(long app, MDataInfo info, long userH, PermissionSet permissionSet, long version, CallbackResult oCb);
+	
+	/**
+	 * Delete permissions set on the mutable data for the given user. * * User is either handle to a signing key or `USER_ANYONE`. */
+	public static native void mdataDelUserPermissions(long app, MDataInfo info, long userH, long version, CallbackResult oCb);
+	
+	/**
+	 * Create new empty entries. */
+	public static native void seqMdataEntriesNew(long app, CallbackResultMDataEntriesHandle oCb);
+	
+	/**
+	 * Insert an entry to the entries. */
+	public static native void seqMdataEntriesInsert(long app, long entriesH, byte[] key, byte[] value, CallbackResult oCb);
+	
+	/**
+	 * Returns the number of entries. */
+	public static native void seqMdataEntriesLen(long app, long entriesH, CallbackResultSize oCb);
+	
+	/**
+	 * Get the entry value at the given key. * The caller must NOT free the content pointer. */
+	public static native void seqMdataEntriesGet(long app, long entriesH, byte[] key, CallbackResultByteArrayLenLong oCb);
+	
+	/**
+	 * Return a list of the entries. */
+	public static native void seqMdataListEntries(long app, long entriesH, CallbackResultMDataEntryArrayLen oCb);
+	
+	/**
+	 * Free the entries from memory. */
+	public static native void seqMdataEntriesFree(long app, long entriesH, CallbackResult oCb);
+	
+	/**
+	 * Create new entry actions. */
+	public static native void mdataEntryActionsNew(long app, CallbackResultMDataEntryActionsHandle oCb);
+	
+	/**
+	 * Add action to insert new entry. */
+	public static native void mdataEntryActionsInsert(long app, long actionsH, byte[] key, byte[] value, CallbackResult oCb);
+	
+	/**
+	 * Add action to update existing entry. * * If `version` is `GET_NEXT_VERSION`, the correct version for the entry at `key` is obtained * automatically when calling `mdata_mutate_entries`. */
+	public static native void mdataEntryActionsUpdate(long app, long actionsH, byte[] key, byte[] value, long version, CallbackResult oCb);
+	
+	/**
+	 * Add action to delete existing entry. * * If `version` is `GET_NEXT_VERSION`, the correct version for the entry at `key` is obtained * automatically when calling `mdata_mutate_entries`. */
+	public static native void mdataEntryActionsDelete(long app, long actionsH, byte[] key, long version, CallbackResult oCb);
+	
+	/**
+	 * Free the entry actions from memory. */
+	public static native void mdataEntryActionsFree(long app, long actionsH, CallbackResult oCb);
+	
+	/**
+	 * Serialize metadata. */
+	public static native void mdataEncodeMetadata(MetadataResponse metadata, CallbackResultByteArrayLen oCb);
+	
+	/**
+	 * Create new permissions. */
+	public static native void mdataPermissionsNew(long app, CallbackResultMDataPermissionsHandle oCb);
+	
+	/**
+	 * Get the number of entries in the permissions. */
+	public static native void mdataPermissionsLen(long app, long permissionsH, CallbackResultSize oCb);
+	
+	/**
+	 * Get the permission set corresponding to the given user. * * User is either handle to a signing key or `USER_ANYONE`. */
+	public static native void mdataPermissionsGet(long app, long permissionsH, long userH, CallbackResultPermissionSet oCb);
+	
+	/**
+	 * Return each (user, permission set) pair in the permissions. */
+	public static native void mdataListPermissionSets(long app, long permissionsH, CallbackResultUserPermissionSetArrayLen oCb);
+	
+	/**
+	 * Insert permission set for the given user to the permissions. * * User is either handle to a signing key or `USER_ANYONE`. */
+	public static native void mdataPermissionsInsert(long app, long permissionsH, long userH, PermissionSet permissionSet, CallbackResult oCb);
+	
+	/**
+	 * Free the permissions from memory. */
+	public static native void mdataPermissionsFree(long app, long permissionsH, CallbackResult oCb);
+	
+	/**
+	 * Retrieve file with the given name, and its version, from the directory. */
+	public static native void dirFetchFile(long app, MDataInfo parentInfo, String fileName, CallbackResultFileLong oCb);
+	
+	/**
+	 * Insert the file into the parent directory. */
+	public static native void dirInsertFile(long app, MDataInfo parentInfo, String fileName, File file, CallbackResult oCb);
+	
+	/**
+	 * Replace the file in the parent directory. * * If `version` is `GET_NEXT_VERSION`, the correct version is obtained automatically. */
+	public static native void dirUpdateFile(long app, MDataInfo parentInfo, String fileName, File file, long version, CallbackResultLong oCb);
+	
+	/**
+	 * Delete the file in the parent directory. * * If `version` is `GET_NEXT_VERSION`, the correct version is obtained automatically. * If `published` is false then the file is deleted from the network. Else, only the file's entry * is removed from the container. */
+	public static native void dirDeleteFile(long app, MDataInfo parentInfo, String fileName, boolean published, long version, CallbackResultLong oCb);
+	
+	/**
+	 * Open the file to read or write its contents. */
+	public static native void fileOpen(long app, MDataInfo parentInfo, File file, long openMode, CallbackResultFileContextHandle oCb);
+	
+	/**
+	 * Get a size of file opened for read. */
+	public static native void fileSize(long app, long fileH, CallbackResultLong oCb);
+	
+	/**
+	 * Read data from file. */
+	public static native void fileRead(long app, long fileH, long position, long len, CallbackResultByteArrayLen oCb);
+	
+	/**
+	 * Write data to file in smaller chunks. */
+	public static native void fileWrite(long app, long fileH, byte[] data, CallbackResult oCb);
+	
+	/**
+	 * Close is invoked only after all the data is completely written. The * file is saved only when `close` is invoked. * * If the file was opened in any of the write modes, returns the modified * file structure as a result. If the file was opened in the read mode, * returns the original file structure that was passed as an argument to * `file_open`. * * Frees the file context handle. */
+	public static native void fileClose(long app, long fileH, CallbackResultFile oCb);
+	
+	/**
+	 * Creates a random app instance for testing. */
+	public static native void testCreateApp(String appId, CallbackResultApp oCb);
+	
+	/**
+	 * Create a random app instance for testing, with access to containers. */
+	public static native void testCreateAppWithAccess(AuthReq authReq, CallbackResultApp oCb);
+	
+	/**
+	 * Simulate a network disconnect when testing. */
+	public static native void testSimulateNetworkDisconnect(long app, CallbackResult oCb);
+	
+
+
+                         }
\ No newline at end of file
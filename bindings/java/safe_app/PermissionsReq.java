@@ -0,0 +1,91 @@
+// Copyright 2019 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT> or the Modified
+// BSD license <LICENSE-BSD or https://opensource.org/licenses/BSD-3-Clause>,
+// at your option. This file may not be copied, modified, or distributed
+// except according to those terms. Please review the Licences for the
+// specific language governing permissions and limitations relating to use
+// of the SAFE Network Software.
+package net.maidsafe.safe_app;
+
+/// Request to modify the permissions of an authorised app.public class PermissionsReq {
+	private AppExchangeInfo app;
+	private ContainerPermissions[] containers;
+	private long containersLen;
+	private boolean updateAppPermissions;
+	private boolean appPermissionTransferCoins;
+	private boolean appPermissionPerformMutations;
+	private boolean appPermissionGetBalance;
+
+	public PermissionsReq() {
+		this.app = new AppExchangeInfo();
+		this.containers = new ContainerPermissions[] {};
+	}
+	public PermissionsReq(AppExchangeInfo app, ContainerPermissions[] containers, long containersLen, boolean updateAppPermissions, boolean appPermissionTransferCoins, boolean appPermissionPerformMutations, boolean appPermissionGetBalance) {
+		this.app = app;
+		this.containers = containers;
+		this.containersLen = containersLen;
+		this.updateAppPermissions = updateAppPermissions;
+		this.appPermissionTransferCoins = appPermissionTransferCoins;
+		this.appPermissionPerformMutations = appPermissionPerformMutations;
+		this.appPermissionGetBalance = appPermissionGetBalance;
+	}
+	public AppExchangeInfo getApp() {
+		return app;
+	}
+
+	public void setApp(final AppExchangeInfo val) {
+		this.app = val;
+	}
+
+	public ContainerPermissions[] getContainer() {
+		return containers;
+	}
+
+	public void setContainer(final ContainerPermissions[] val) {
+		this.containers = val;
+	}
+
+	public long getContainersLen() {
+		return containersLen;
+	}
+
+	public void setContainersLen(final long val) {
+		this.containersLen = val;
+	}
+
+	public boolean getUpdateAppPermission() {
+		return updateAppPermissions;
+	}
+
+	public void setUpdateAppPermission(final boolean val) {
+		this.updateAppPermissions = val;
+	}
+
+	public boolean getAppPermissionTransferCoin() {
+		return appPermissionTransferCoins;
+	}
+
+	public void setAppPermissionTransferCoin(final boolean val) {
+		this.appPermissionTransferCoins = val;
+	}
+
+	public boolean getAppPermissionPerformMutation() {
+		return appPermissionPerformMutations;
+	}
+
+	public void setAppPermissionPerformMutation(final boolean val) {
+		this.appPermissionPerformMutations = val;
+	}
+
+	public boolean getAppPermissionGetBalance() {
+		return appPermissionGetBalance;
+	}
+
+	public void setAppPermissionGetBalance(final boolean val) {
+		this.appPermissionGetBalance = val;
+	}
+
+}
+
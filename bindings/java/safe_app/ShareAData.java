@@ -0,0 +1,71 @@
+// Copyright 2019 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT> or the Modified
+// BSD license <LICENSE-BSD or https://opensource.org/licenses/BSD-3-Clause>,
+// at your option. This file may not be copied, modified, or distributed
+// except according to those terms. Please review the Licences for the
+// specific language governing permissions and limitations relating to use
+// of the SAFE Network Software.
+package net.maidsafe.safe_app;
+
+/// For use in `ShareADataReq`. Represents a specific `AppendOnlyData` that is being shared.public class ShareAData {
+	private byte[] name;
+	private long typeTag;
+	private boolean published;
+	private boolean sequenced;
+	private ADataPermissionSet perms;
+
+	public ShareAData() {
+		this.name = new byte[] {};
+		this.perms = new ADataPermissionSet();
+	}
+	public ShareAData(byte[] name, long typeTag, boolean published, boolean sequenced, ADataPermissionSet perms) {
+		this.name = name;
+		this.typeTag = typeTag;
+		this.published = published;
+		this.sequenced = sequenced;
+		this.perms = perms;
+	}
+	public byte[] getName() {
+		return name;
+	}
+
+	public void setName(final byte[] val) {
+		this.name = val;
+	}
+
+	public long getTypeTag() {
+		return typeTag;
+	}
+
+	public void setTypeTag(final long val) {
+		this.typeTag = val;
+	}
+
+	public boolean getPublished() {
+		return published;
+	}
+
+	public void setPublished(final boolean val) {
+		this.published = val;
+	}
+
+	public boolean getSequenced() {
+		return sequenced;
+	}
+
+	public void setSequenced(final boolean val) {
+		this.sequenced = val;
+	}
+
+	public ADataPermissionSet getPerm() {
+		return perms;
+	}
+
+	public void setPerm(final ADataPermissionSet val) {
+		this.perms = val;
+	}
+
+}
+
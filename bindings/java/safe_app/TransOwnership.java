@@ -0,0 +1,81 @@
+// Copyright 2019 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT> or the Modified
+// BSD license <LICENSE-BSD or https://opensource.org/licenses/BSD-3-Clause>,
+// at your option. This file may not be copied, modified, or distributed
+// except according to those terms. Please review the Licences for the
+// specific language governing permissions and limitations relating to use
+// of the SAFE Network Software.
+package net.maidsafe.safe_app;
+
+/// For use in `TransOwnershipReq`. Represents a specific data object whose owner should change.public class TransOwnership {
+	private byte[] name;
+	private long typeTag;
+	private boolean appendOnly;
+	private boolean published;
+	private boolean sequenced;
+	private BlsPublicKey newOwner;
+
+	public TransOwnership() {
+		this.name = new byte[] {};
+		this.newOwner = new BlsPublicKey();
+	}
+	public TransOwnership(byte[] name, long typeTag, boolean appendOnly, boolean published, boolean sequenced, BlsPublicKey newOwner) {
+		this.name = name;
+		this.typeTag = typeTag;
+		this.appendOnly = appendOnly;
+		this.published = published;
+		this.sequenced = sequenced;
+		this.newOwner = newOwner;
+	}
+	public byte[] getName() {
+		return name;
+	}
+
+	public void setName(final byte[] val) {
+		this.name = val;
+	}
+
+	public long getTypeTag() {
+		return typeTag;
+	}
+
+	public void setTypeTag(final long val) {
+		this.typeTag = val;
+	}
+
+	public boolean getAppendOnly() {
+		return appendOnly;
+	}
+
+	public void setAppendOnly(final boolean val) {
+		this.appendOnly = val;
+	}
+
+	public boolean getPublished() {
+		return published;
+	}
+
+	public void setPublished(final boolean val) {
+		this.published = val;
+	}
+
+	public boolean getSequenced() {
+		return sequenced;
+	}
+
+	public void setSequenced(final boolean val) {
+		this.sequenced = val;
+	}
+
+	public BlsPublicKey getNewOwner() {
+		return newOwner;
+	}
+
+	public void setNewOwner(final BlsPublicKey val) {
+		this.newOwner = val;
+	}
+
+}
+
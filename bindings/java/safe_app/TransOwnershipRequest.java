@@ -0,0 +1,51 @@
+// Copyright 2019 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT> or the Modified
+// BSD license <LICENSE-BSD or https://opensource.org/licenses/BSD-3-Clause>,
+// at your option. This file may not be copied, modified, or distributed
+// except according to those terms. Please review the Licences for the
+// specific language governing permissions and limitations relating to use
+// of the SAFE Network Software.
+package net.maidsafe.safe_app;
+
+/// Represents a request to transfer the ownership of datapublic class TransOwnershipRequest {
+	private AppExchangeInfo app;
+	private TransOwnership[] data;
+	private long dataLen;
+
+	public TransOwnershipRequest() {
+		this.app = new AppExchangeInfo();
+		this.data = new TransOwnership[] {};
+	}
+	public TransOwnershipRequest(AppExchangeInfo app, TransOwnership[] data, long dataLen) {
+		this.app = app;
+		this.data = data;
+		this.dataLen = dataLen;
+	}
+	public AppExchangeInfo getApp() {
+		return app;
+	}
+
+	public void setApp(final AppExchangeInfo val) {
+		this.app = val;
+	}
+
+	public TransOwnership[] getDaum() {
+		return data;
+	}
+
+	public void setDaum(final TransOwnership[] val) {
+		this.data = val;
+	}
+
+	public long getDataLen() {
+		return dataLen;
+	}
+
+	public void setDataLen(final long val) {
+		this.dataLen = val;
+	}
+
+}
+
@@ -0,0 +1,5847 @@
+// Copyright 2019 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT> or the Modified
+// BSD license <LICENSE-BSD or https://opensource.org/licenses/BSD-3-Clause>,
+// at your option. This file may not be copied, modified, or distributed
+// except according to those terms. Please review the Licences for the
+// specific language governing permissions and limitations relating to use
+// of the SAFE Network Software.
+impl<'a> FromJava<JObject<'a>> for MDataInfo {
+    fn from_java(env: &JNIEnv, input: JObject) -> Result<Self, JniError> {
+        let seq = env.get_field(input, "seq", "Z")?.z()? as bool;
+        let name = env.get_field(input, "name", "[B")?.l()?;
+        let name = XorNameArray::from_java(env, name)?;
+        let type_tag = env.get_field(input, "typeTag", "J")?.j()? as u64;
+        let has_enc_info = env.get_field(input, "hasEncInfo", "Z")?.z()? as bool;
+        let enc_key = env.get_field(input, "encKey", "[B")?.l()?;
+        let enc_key = SymSecretKey::from_java(env, enc_key)?;
+        let enc_nonce = env.get_field(input, "encNonce", "[B")?.l()?;
+        let enc_nonce = SymNonce::from_java(env, enc_nonce)?;
+        let has_new_enc_info = env.get_field(input, "hasNewEncInfo", "Z")?.z()? as bool;
+        let new_enc_key = env.get_field(input, "newEncKey", "[B")?.l()?;
+        let new_enc_key = SymSecretKey::from_java(env, new_enc_key)?;
+        let new_enc_nonce = env.get_field(input, "newEncNonce", "[B")?.l()?;
+        let new_enc_nonce = SymNonce::from_java(env, new_enc_nonce)?;
+        Ok(MDataInfo {
+            seq,
+            name,
+            type_tag,
+            has_enc_info,
+            enc_key,
+            enc_nonce,
+            has_new_enc_info,
+            new_enc_key,
+            new_enc_nonce,
+        })
+    }
+}
+impl<'a> ToJava<'a, JObject<'a>> for MDataInfo {
+    fn to_java(&self, env: &'a JNIEnv) -> Result<JObject<'a>, JniError> {
+        let cls = unsafe { find_class(env, "net/maidsafe/safe_app/MDataInfo")? };
+        let output = env.new_object(&cls, "()V", &[])?;
+        let jobj = self.seq.to_java(env)?;
+        env.set_field(output, "seq", "Z", jobj.into())?;
+        let jobj = self.name.to_java(env)?;
+        env.set_field(output, "name", "[B", jobj.into())?;
+        let jobj = self.type_tag.to_java(env)?;
+        env.set_field(output, "typeTag", "J", jobj.into())?;
+        let jobj = self.has_enc_info.to_java(env)?;
+        env.set_field(output, "hasEncInfo", "Z", jobj.into())?;
+        let jobj = self.enc_key.to_java(env)?;
+        env.set_field(output, "encKey", "[B", jobj.into())?;
+        let jobj = self.enc_nonce.to_java(env)?;
+        env.set_field(output, "encNonce", "[B", jobj.into())?;
+        let jobj = self.has_new_enc_info.to_java(env)?;
+        env.set_field(output, "hasNewEncInfo", "Z", jobj.into())?;
+        let jobj = self.new_enc_key.to_java(env)?;
+        env.set_field(output, "newEncKey", "[B", jobj.into())?;
+        let jobj = self.new_enc_nonce.to_java(env)?;
+        env.set_field(output, "newEncNonce", "[B", jobj.into())?;
+        Ok(output)
+    }
+}
+impl<'a> FromJava<JObject<'a>> for TransactionRecord {
+    fn from_java(env: &JNIEnv, input: JObject) -> Result<Self, JniError> {
+        let id = env.get_field(input, "id", "J")?.j()? as u64;
+        let sent = env.get_field(input, "sent", "Z")?.z()? as bool;
+        let counterparty = env.get_field(input, "counterparty", "[B")?.l()?;
+        let counterparty = XorNameArray::from_java(env, counterparty)?;
+        let amount = env.get_field(input, "amount", "J")?.j()? as u64;
+        let timestamp_sec = env.get_field(input, "timestampSec", "J")?.j()? as i64;
+        let timestamp_nsec = env.get_field(input, "timestampNsec", "I")?.i()? as u32;
+        Ok(TransactionRecord {
+            id,
+            sent,
+            counterparty,
+            amount,
+            timestamp_sec,
+            timestamp_nsec,
+        })
+    }
+}
+impl<'a> ToJava<'a, JObject<'a>> for TransactionRecord {
+    fn to_java(&self, env: &'a JNIEnv) -> Result<JObject<'a>, JniError> {
+        let cls = unsafe { find_class(env, "net/maidsafe/safe_app/TransactionRecord")? };
+        let output = env.new_object(&cls, "()V", &[])?;
+        let jobj = self.id.to_java(env)?;
+        env.set_field(output, "id", "J", jobj.into())?;
+        let jobj = self.sent.to_java(env)?;
+        env.set_field(output, "sent", "Z", jobj.into())?;
+        let jobj = self.counterparty.to_java(env)?;
+        env.set_field(output, "counterparty", "[B", jobj.into())?;
+        let jobj = self.amount.to_java(env)?;
+        env.set_field(output, "amount", "J", jobj.into())?;
+        let jobj = self.timestamp_sec.to_java(env)?;
+        env.set_field(output, "timestampSec", "J", jobj.into())?;
+        let jobj = self.timestamp_nsec.to_java(env)?;
+        env.set_field(output, "timestampNsec", "I", jobj.into())?;
+        Ok(output)
+    }
+}
+impl<'a> FromJava<JObject<'a>> for PermissionSet {
+    fn from_java(env: &JNIEnv, input: JObject) -> Result<Self, JniError> {
+        let read = env.get_field(input, "read", "Z")?.z()? as bool;
+        let insert = env.get_field(input, "insert", "Z")?.z()? as bool;
+        let update = env.get_field(input, "update", "Z")?.z()? as bool;
+        let delete = env.get_field(input, "delete", "Z")?.z()? as bool;
+        let manage_permissions = env.get_field(input, "managePermissions", "Z")?.z()? as bool;
+        Ok(PermissionSet {
+            read,
+            insert,
+            update,
+            delete,
+            manage_permissions,
+        })
+    }
+}
+impl<'a> ToJava<'a, JObject<'a>> for PermissionSet {
+    fn to_java(&self, env: &'a JNIEnv) -> Result<JObject<'a>, JniError> {
+        let cls = unsafe { find_class(env, "net/maidsafe/safe_app/PermissionSet")? };
+        let output = env.new_object(&cls, "()V", &[])?;
+        let jobj = self.read.to_java(env)?;
+        env.set_field(output, "read", "Z", jobj.into())?;
+        let jobj = self.insert.to_java(env)?;
+        env.set_field(output, "insert", "Z", jobj.into())?;
+        let jobj = self.update.to_java(env)?;
+        env.set_field(output, "update", "Z", jobj.into())?;
+        let jobj = self.delete.to_java(env)?;
+        env.set_field(output, "delete", "Z", jobj.into())?;
+        let jobj = self.manage_permissions.to_java(env)?;
+        env.set_field(output, "managePermissions", "Z", jobj.into())?;
+        Ok(output)
+    }
+}
+impl<'a> FromJava<JObject<'a>> for AppExchangeInfo {
+    fn from_java(env: &JNIEnv, input: JObject) -> Result<Self, JniError> {
+        let id = env.get_field(input, "id", "Ljava/lang/String;")?
+            .l()?
+            .into();
+        let id = <*mut _>::from_java(env, id)?;
+        let scope = env.get_field(input, "scope", "Ljava/lang/String;")?
+            .l()?
+            .into();
+        let scope = <*mut _>::from_java(env, scope)?;
+        let name = env.get_field(input, "name", "Ljava/lang/String;")?
+            .l()?
+            .into();
+        let name = <*mut _>::from_java(env, name)?;
+        let vendor = env.get_field(input, "vendor", "Ljava/lang/String;")?
+            .l()?
+            .into();
+        let vendor = <*mut _>::from_java(env, vendor)?;
+        Ok(AppExchangeInfo {
+            id,
+            scope,
+            name,
+            vendor,
+        })
+    }
+}
+impl<'a> ToJava<'a, JObject<'a>> for AppExchangeInfo {
+    fn to_java(&self, env: &'a JNIEnv) -> Result<JObject<'a>, JniError> {
+        let cls = unsafe { find_class(env, "net/maidsafe/safe_app/AppExchangeInfo")? };
+        let output = env.new_object(&cls, "()V", &[])?;
+        if !self.id.is_null() {
+            let id: JObject = self.id.to_java(env)?.into();
+            env.set_field(output, "id", "Ljava/lang/String;", id.into())?;
+            env.delete_local_ref(id)?;
+        }
+        if !self.scope.is_null() {
+            let scope: JObject = self.scope.to_java(env)?.into();
+            env.set_field(
+                output,
+                "scope",
+                "Ljava/lang/String;",
+                scope.into(),
+            )?;
+            env.delete_local_ref(scope)?;
+        }
+        if !self.name.is_null() {
+            let name: JObject = self.name.to_java(env)?.into();
+            env.set_field(
+                output,
+                "name",
+                "Ljava/lang/String;",
+                name.into(),
+            )?;
+            env.delete_local_ref(name)?;
+        }
+        if !self.vendor.is_null() {
+            let vendor: JObject = self.vendor.to_java(env)?.into();
+            env.set_field(
+                output,
+                "vendor",
+                "Ljava/lang/String;",
+                vendor.into(),
+            )?;
+            env.delete_local_ref(vendor)?;
+        }
+        Ok(output)
+    }
+}
+impl<'a> FromJava<JObject<'a>> for ContainerPermissions {
+    fn from_java(env: &JNIEnv, input: JObject) -> Result<Self, JniError> {
+        let cont_name = env.get_field(input, "contName", "Ljava/lang/String;")?
+            .l()?
+            .into();
+        let cont_name = <*mut _>::from_java(env, cont_name)?;
+        let access = env.get_field(input, "access", "Lnet/maidsafe/safe_app/PermissionSet;")?
+            .l()?;
+        let access = PermissionSet::from_java(env, access)?;
+        Ok(ContainerPermissions { cont_name, access })
+    }
+}
+impl<'a> ToJava<'a, JObject<'a>> for ContainerPermissions {
+    fn to_java(&self, env: &'a JNIEnv) -> Result<JObject<'a>, JniError> {
+        let cls = unsafe { find_class(env, "net/maidsafe/safe_app/ContainerPermissions")? };
+        let output = env.new_object(&cls, "()V", &[])?;
+        if !self.cont_name.is_null() {
+            let cont_name: JObject = self.cont_name.to_java(env)?.into();
+            env.set_field(
+                output,
+                "contName",
+                "Ljava/lang/String;",
+                cont_name.into(),
+            )?;
+            env.delete_local_ref(cont_name)?;
+        }
+        let jobj = self.access.to_java(env)?;
+        env.set_field(
+            output,
+            "access",
+            "Lnet/maidsafe/safe_app/PermissionSet;",
+            jobj.into(),
+        )?;
+        env.delete_local_ref(jobj)?;
+        Ok(output)
+    }
+}
+impl<'a> FromJava<JObject<'a>> for AuthReq {
+    fn from_java(env: &JNIEnv, input: JObject) -> Result<Self, JniError> {
+        let app = env.get_field(input, "app", "Lnet/maidsafe/safe_app/AppExchangeInfo;")?
+            .l()?;
+        let app = AppExchangeInfo::from_java(env, app)?;
+        let app_container = env.get_field(input, "appContainer", "Z")?.z()? as bool;
+        let app_permission_transfer_coins =
+            env.get_field(input, "appPermissionTransferCoins", "Z")?.z()? as bool;
+        let app_permission_perform_mutations =
+            env.get_field(input, "appPermissionPerformMutations", "Z")?
+                .z()? as bool;
+        let app_permission_get_balance = env.get_field(input, "appPermissionGetBalance", "Z")?
+            .z()? as bool;
+        let arr = env.get_field(
+            input,
+            "containers",
+            "[Lnet/maidsafe/safe_app/ContainerPermissions;",
+        )?
+            .l()?
+            .into_inner() as jni::sys::jarray;
+        let containers_len = env.get_array_length(arr)? as usize;
+        let mut vec = Vec::with_capacity(containers_len);
+        for idx in 0..containers_len {
+            let item = env.get_object_array_element(arr, idx as jni::sys::jsize);
+            let item = ContainerPermissions::from_java(env, item?)?;
+            vec.push(item);
+        }
+        let containers = vec.as_ptr();
+        ::std::mem::forget(vec);
+        let seal_response = env.get_field(input, "sealResponse", "Z")?.z()? as bool;
+        let response_key =
+            env.get_field(input, "responseKey", "Lnet/maidsafe/safe_app/BlsPublicKey;")?
+                .l()?;
+        let response_key = BlsPublicKey::from_java(env, response_key)?;
+        let ttl = env.get_field(input, "ttl", "J")?.j()? as u64;
+        Ok(AuthReq {
+            app,
+            app_container,
+            app_permission_transfer_coins,
+            app_permission_perform_mutations,
+            app_permission_get_balance,
+            containers,
+            containers_len,
+            seal_response,
+            response_key,
+            ttl,
+        })
+    }
+}
+impl<'a> ToJava<'a, JObject<'a>> for AuthReq {
+    fn to_java(&self, env: &'a JNIEnv) -> Result<JObject<'a>, JniError> {
+        let cls = unsafe { find_class(env, "net/maidsafe/safe_app/AuthReq")? };
+        let output = env.new_object(&cls, "()V", &[])?;
+        let jobj = self.app.to_java(env)?;
+        env.set_field(
+            output,
+            "app",
+            "Lnet/maidsafe/safe_app/AppExchangeInfo;",
+            jobj.into(),
+        )?;
+        env.delete_local_ref(jobj)?;
+        let jobj = self.app_container.to_java(env)?;
+        env.set_field(output, "appContainer", "Z", jobj.into())?;
+        let jobj = self.app_permission_transfer_coins.to_java(env)?;
+        env.set_field(
+            output,
+            "appPermissionTransferCoins",
+            "Z",
+            jobj.into(),
+        )?;
+        let jobj = self.app_permission_perform_mutations.to_java(env)?;
+        env.set_field(
+            output,
+            "appPermissionPerformMutations",
+            "Z",
+            jobj.into(),
+        )?;
+        let jobj = self.app_permission_get_balance.to_java(env)?;
+        env.set_field(
+            output,
+            "appPermissionGetBalance",
+            "Z",
+            jobj.into(),
+        )?;
+        let cls = unsafe { find_class(env, "net/maidsafe/safe_app/ContainerPermissions")? };
+        let arr = env.new_object_array(
+            self.containers_len as jni::sys::jsize,
+            &cls,
+            JObject::null(),
+        )?;
+        let items = unsafe { slice::from_raw_parts(self.containers, self.containers_len) };
+        for (idx, item) in items.iter().enumerate() {
+            let jobj = item.to_java(env)?;
+            env.set_object_array_element(
+                arr,
+                idx as jni::sys::jsize,
+                jobj,
+            )?;
+            env.delete_local_ref(jobj)?;
+        }
+        let jobj = JObject::from(arr);
+        env.set_field(
+            output,
+            "containers",
+            "[Lnet/maidsafe/safe_app/ContainerPermissions;",
+            jobj.into(),
+        )?;
+        env.delete_local_ref(jobj)?;
+        env.set_field(
+            output,
+            "containersLen",
+            "J",
+            self.containers_len.to_java(env)?.into(),
+        )?;
+        let jobj = self.seal_response.to_java(env)?;
+        env.set_field(output, "sealResponse", "Z", jobj.into())?;
+        let jobj = self.response_key.to_java(env)?;
+        env.set_field(
+            output,
+            "responseKey",
+            "Lnet/maidsafe/safe_app/BlsPublicKey;",
+            jobj.into(),
+        )?;
+        env.delete_local_ref(jobj)?;
+        let jobj = self.ttl.to_java(env)?;
+        env.set_field(output, "ttl", "J", jobj.into())?;
+        Ok(output)
+    }
+}
+impl<'a> FromJava<JObject<'a>> for ContainersReq {
+    fn from_java(env: &JNIEnv, input: JObject) -> Result<Self, JniError> {
+        let app = env.get_field(input, "app", "Lnet/maidsafe/safe_app/AppExchangeInfo;")?
+            .l()?;
+        let app = AppExchangeInfo::from_java(env, app)?;
+        let arr = env.get_field(
+            input,
+            "containers",
+            "[Lnet/maidsafe/safe_app/ContainerPermissions;",
+        )?
+            .l()?
+            .into_inner() as jni::sys::jarray;
+        let containers_len = env.get_array_length(arr)? as usize;
+        let mut vec = Vec::with_capacity(containers_len);
+        for idx in 0..containers_len {
+            let item = env.get_object_array_element(arr, idx as jni::sys::jsize);
+            let item = ContainerPermissions::from_java(env, item?)?;
+            vec.push(item);
+        }
+        let containers = vec.as_ptr();
+        ::std::mem::forget(vec);
+        Ok(ContainersReq {
+            app,
+            containers,
+            containers_len,
+        })
+    }
+}
+impl<'a> ToJava<'a, JObject<'a>> for ContainersReq {
+    fn to_java(&self, env: &'a JNIEnv) -> Result<JObject<'a>, JniError> {
+        let cls = unsafe { find_class(env, "net/maidsafe/safe_app/ContainersReq")? };
+        let output = env.new_object(&cls, "()V", &[])?;
+        let jobj = self.app.to_java(env)?;
+        env.set_field(
+            output,
+            "app",
+            "Lnet/maidsafe/safe_app/AppExchangeInfo;",
+            jobj.into(),
+        )?;
+        env.delete_local_ref(jobj)?;
+        let cls = unsafe { find_class(env, "net/maidsafe/safe_app/ContainerPermissions")? };
+        let arr = env.new_object_array(
+            self.containers_len as jni::sys::jsize,
+            &cls,
+            JObject::null(),
+        )?;
+        let items = unsafe { slice::from_raw_parts(self.containers, self.containers_len) };
+        for (idx, item) in items.iter().enumerate() {
+            let jobj = item.to_java(env)?;
+            env.set_object_array_element(
+                arr,
+                idx as jni::sys::jsize,
+                jobj,
+            )?;
+            env.delete_local_ref(jobj)?;
+        }
+        let jobj = JObject::from(arr);
+        env.set_field(
+            output,
+            "containers",
+            "[Lnet/maidsafe/safe_app/ContainerPermissions;",
+            jobj.into(),
+        )?;
+        env.delete_local_ref(jobj)?;
+        env.set_field(
+            output,
+            "containersLen",
+            "J",
+            self.containers_len.to_java(env)?.into(),
+        )?;
+        Ok(output)
+    }
+}
+impl<'a> FromJava<JObject<'a>> for PermissionsReq {
+    fn from_java(env: &JNIEnv, input: JObject) -> Result<Self, JniError> {
+        let app = env.get_field(input, "app", "Lnet/maidsafe/safe_app/AppExchangeInfo;")?
+            .l()?;
+        let app = AppExchangeInfo::from_java(env, app)?;
+        let arr = env.get_field(
+            input,
+            "containers",
+            "[Lnet/maidsafe/safe_app/ContainerPermissions;",
+        )?
+            .l()?
+            .into_inner() as jni::sys::jarray;
+        let containers_len = env.get_array_length(arr)? as usize;
+        let mut vec = Vec::with_capacity(containers_len);
+        for idx in 0..containers_len {
+            let item = env.get_object_array_element(arr, idx as jni::sys::jsize);
+            let item = ContainerPermissions::from_java(env, item?)?;
+            vec.push(item);
+        }
+        let containers = vec.as_ptr();
+        ::std::mem::forget(vec);
+        let update_app_permissions = env.get_field(input, "updateAppPermissions", "Z")?.z()? as
+            bool;
+        let app_permission_transfer_coins =
+            env.get_field(input, "appPermissionTransferCoins", "Z")?.z()? as bool;
+        let app_permission_perform_mutations =
+            env.get_field(input, "appPermissionPerformMutations", "Z")?
+                .z()? as bool;
+        let app_permission_get_balance = env.get_field(input, "appPermissionGetBalance", "Z")?
+            .z()? as bool;
+        Ok(PermissionsReq {
+            app,
+            containers,
+            containers_len,
+            update_app_permissions,
+            app_permission_transfer_coins,
+            app_permission_perform_mutations,
+            app_permission_get_balance,
+        })
+    }
+}
+impl<'a> ToJava<'a, JObject<'a>> for PermissionsReq {
+    fn to_java(&self, env: &'a JNIEnv) -> Result<JObject<'a>, JniError> {
+        let cls = unsafe { find_class(env, "net/maidsafe/safe_app/PermissionsReq")? };
+        let output = env.new_object(&cls, "()V", &[])?;
+        let jobj = self.app.to_java(env)?;
+        env.set_field(
+            output,
+            "app",
+            "Lnet/maidsafe/safe_app/AppExchangeInfo;",
+            jobj.into(),
+        )?;
+        env.delete_local_ref(jobj)?;
+        let cls = unsafe { find_class(env, "net/maidsafe/safe_app/ContainerPermissions")? };
+        let arr = env.new_object_array(
+            self.containers_len as jni::sys::jsize,
+            &cls,
+            JObject::null(),
+        )?;
+        let items = unsafe { slice::from_raw_parts(self.containers, self.containers_len) };
+        for (idx, item) in items.iter().enumerate() {
+            let jobj = item.to_java(env)?;
+            env.set_object_array_element(
+                arr,
+                idx as jni::sys::jsize,
+                jobj,
+            )?;
+            env.delete_local_ref(jobj)?;
+        }
+        let jobj = JObject::from(arr);
+        env.set_field(
+            output,
+            "containers",
+            "[Lnet/maidsafe/safe_app/ContainerPermissions;",
+            jobj.into(),
+        )?;
+        env.delete_local_ref(jobj)?;
+        env.set_field(
+            output,
+            "containersLen",
+            "J",
+            self.containers_len.to_java(env)?.into(),
+        )?;
+        let jobj = self.update_app_permissions.to_java(env)?;
+        env.set_field(
+            output,
+            "updateAppPermissions",
+            "Z",
+            jobj.into(),
+        )?;
+        let jobj = self.app_permission_transfer_coins.to_java(env)?;
+        env.set_field(
+            output,
+            "appPermissionTransferCoins",
+            "Z",
+            jobj.into(),
+        )?;
+        let jobj = self.app_permission_perform_mutations.to_java(env)?;
+        env.set_field(
+            output,
+            "appPermissionPerformMutations",
+            "Z",
+            jobj.into(),
+        )?;
+        let jobj = self.app_permission_get_balance.to_java(env)?;
+        env.set_field(
+            output,
+            "appPermissionGetBalance",
+            "Z",
+            jobj.into(),
+        )?;
+        Ok(output)
+    }
+}
+impl<'a> FromJava<JObject<'a>> for ShareMData {
+    fn from_java(env: &JNIEnv, input: JObject) -> Result<Self, JniError> {
+        let type_tag = env.get_field(input, "typeTag", "J")?.j()? as u64;
+        let name = env.get_field(input, "name", "[B")?.l()?;
+        let name = XorNameArray::from_java(env, name)?;
+        let perms = env.get_field(input, "perms", "Lnet/maidsafe/safe_app/PermissionSet;")?
+            .l()?;
+        let perms = PermissionSet::from_java(env, perms)?;
+        Ok(ShareMData {
+            type_tag,
+            name,
+            perms,
+        })
+    }
+}
+impl<'a> ToJava<'a, JObject<'a>> for ShareMData {
+    fn to_java(&self, env: &'a JNIEnv) -> Result<JObject<'a>, JniError> {
+        let cls = unsafe { find_class(env, "net/maidsafe/safe_app/ShareMData")? };
+        let output = env.new_object(&cls, "()V", &[])?;
+        let jobj = self.type_tag.to_java(env)?;
+        env.set_field(output, "typeTag", "J", jobj.into())?;
+        let jobj = self.name.to_java(env)?;
+        env.set_field(output, "name", "[B", jobj.into())?;
+        let jobj = self.perms.to_java(env)?;
+        env.set_field(
+            output,
+            "perms",
+            "Lnet/maidsafe/safe_app/PermissionSet;",
+            jobj.into(),
+        )?;
+        env.delete_local_ref(jobj)?;
+        Ok(output)
+    }
+}
+impl<'a> FromJava<JObject<'a>> for ShareMDataRequest {
+    fn from_java(env: &JNIEnv, input: JObject) -> Result<Self, JniError> {
+        let app = env.get_field(input, "app", "Lnet/maidsafe/safe_app/AppExchangeInfo;")?
+            .l()?;
+        let app = AppExchangeInfo::from_java(env, app)?;
+        let arr = env.get_field(input, "mdata", "[Lnet/maidsafe/safe_app/ShareMData;")?
+            .l()?
+            .into_inner() as jni::sys::jarray;
+        let mdata_len = env.get_array_length(arr)? as usize;
+        let mut vec = Vec::with_capacity(mdata_len);
+        for idx in 0..mdata_len {
+            let item = env.get_object_array_element(arr, idx as jni::sys::jsize);
+            let item = ShareMData::from_java(env, item?)?;
+            vec.push(item);
+        }
+        let mdata = vec.as_ptr();
+        ::std::mem::forget(vec);
+        Ok(ShareMDataRequest {
+            app,
+            mdata,
+            mdata_len,
+        })
+    }
+}
+impl<'a> ToJava<'a, JObject<'a>> for ShareMDataRequest {
+    fn to_java(&self, env: &'a JNIEnv) -> Result<JObject<'a>, JniError> {
+        let cls = unsafe { find_class(env, "net/maidsafe/safe_app/ShareMDataRequest")? };
+        let output = env.new_object(&cls, "()V", &[])?;
+        let jobj = self.app.to_java(env)?;
+        env.set_field(
+            output,
+            "app",
+            "Lnet/maidsafe/safe_app/AppExchangeInfo;",
+            jobj.into(),
+        )?;
+        env.delete_local_ref(jobj)?;
+        let cls = unsafe { find_class(env, "net/maidsafe/safe_app/ShareMData")? };
+        let arr = env.new_object_array(
+            self.mdata_len as jni::sys::jsize,
+            &cls,
+            JObject::null(),
+        )?;
+        let items = unsafe { slice::from_raw_parts(self.mdata, self.mdata_len) };
+        for (idx, item) in items.iter().enumerate() {
+            let jobj = item.to_java(env)?;
+            env.set_object_array_element(
+                arr,
+                idx as jni::sys::jsize,
+                jobj,
+            )?;
+            env.delete_local_ref(jobj)?;
+        }
+        let jobj = JObject::from(arr);
+        env.set_field(
+            output,
+            "mdata",
+            "[Lnet/maidsafe/safe_app/ShareMData;",
+            jobj.into(),
+        )?;
+        env.delete_local_ref(jobj)?;
+        env.set_field(
+            output,
+            "mdataLen",
+            "J",
+            self.mdata_len.to_java(env)?.into(),
+        )?;
+        Ok(output)
+    }
+}
+impl<'a> FromJava<JObject<'a>> for ADataPermissionSet {
+    fn from_java(env: &JNIEnv, input: JObject) -> Result<Self, JniError> {
+        let read = env.get_field(input, "read", "Z")?.z()? as bool;
+        let append = env.get_field(input, "append", "Z")?.z()? as bool;
+        let manage_permissions = env.get_field(input, "managePermissions", "Z")?.z()? as bool;
+        Ok(ADataPermissionSet {
+            read,
+            append,
+            manage_permissions,
+        })
+    }
+}
+impl<'a> ToJava<'a, JObject<'a>> for ADataPermissionSet {
+    fn to_java(&self, env: &'a JNIEnv) -> Result<JObject<'a>, JniError> {
+        let cls = unsafe { find_class(env, "net/maidsafe/safe_app/ADataPermissionSet")? };
+        let output = env.new_object(&cls, "()V", &[])?;
+        let jobj = self.read.to_java(env)?;
+        env.set_field(output, "read", "Z", jobj.into())?;
+        let jobj = self.append.to_java(env)?;
+        env.set_field(output, "append", "Z", jobj.into())?;
+        let jobj = self.manage_permissions.to_java(env)?;
+        env.set_field(output, "managePermissions", "Z", jobj.into())?;
+        Ok(output)
+    }
+}
+impl<'a> FromJava<JObject<'a>> for ShareAData {
+    fn from_java(env: &JNIEnv, input: JObject) -> Result<Self, JniError> {
+        let name = env.get_field(input, "name", "[B")?.l()?;
+        let name = XorNameArray::from_java(env, name)?;
+        let type_tag = env.get_field(input, "typeTag", "J")?.j()? as u64;
+        let published = env.get_field(input, "published", "Z")?.z()? as bool;
+        let sequenced = env.get_field(input, "sequenced", "Z")?.z()? as bool;
+        let perms = env.get_field(input, "perms", "Lnet/maidsafe/safe_app/ADataPermissionSet;")?
+            .l()?;
+        let perms = ADataPermissionSet::from_java(env, perms)?;
+        Ok(ShareAData {
+            name,
+            type_tag,
+            published,
+            sequenced,
+            perms,
+        })
+    }
+}
+impl<'a> ToJava<'a, JObject<'a>> for ShareAData {
+    fn to_java(&self, env: &'a JNIEnv) -> Result<JObject<'a>, JniError> {
+        let cls = unsafe { find_class(env, "net/maidsafe/safe_app/ShareAData")? };
+        let output = env.new_object(&cls, "()V", &[])?;
+        let jobj = self.name.to_java(env)?;
+        env.set_field(output, "name", "[B", jobj.into())?;
+        let jobj = self.type_tag.to_java(env)?;
+        env.set_field(output, "typeTag", "J", jobj.into())?;
+        let jobj = self.published.to_java(env)?;
+        env.set_field(output, "published", "Z", jobj.into())?;
+        let jobj = self.sequenced.to_java(env)?;
+        env.set_field(output, "sequenced", "Z", jobj.into())?;
+        let jobj = self.perms.to_java(env)?;
+        env.set_field(
+            output,
+            "perms",
+            "Lnet/maidsafe/safe_app/ADataPermissionSet;",
+            jobj.into(),
+        )?;
+        env.delete_local_ref(jobj)?;
+        Ok(output)
+    }
+}
+impl<'a> FromJava<JObject<'a>> for ShareADataRequest {
+    fn from_java(env: &JNIEnv, input: JObject) -> Result<Self, JniError> {
+        let app = env.get_field(input, "app", "Lnet/maidsafe/safe_app/AppExchangeInfo;")?
+            .l()?;
+        let app = AppExchangeInfo::from_java(env, app)?;
+        let arr = env.get_field(input, "adata", "[Lnet/maidsafe/safe_app/ShareAData;")?
+            .l()?
+            .into_inner() as jni::sys::jarray;
+        let adata_len = env.get_array_length(arr)? as usize;
+        let mut vec = Vec::with_capacity(adata_len);
+        for idx in 0..adata_len {
+            let item = env.get_object_array_element(arr, idx as jni::sys::jsize);
+            let item = ShareAData::from_java(env, item?)?;
+            vec.push(item);
+        }
+        let adata = vec.as_ptr();
+        ::std::mem::forget(vec);
+        Ok(ShareADataRequest {
+            app,
+            adata,
+            adata_len,
+        })
+    }
+}
+impl<'a> ToJava<'a, JObject<'a>> for ShareADataRequest {
+    fn to_java(&self, env: &'a JNIEnv) -> Result<JObject<'a>, JniError> {
+        let cls = unsafe { find_class(env, "net/maidsafe/safe_app/ShareADataRequest")? };
+        let output = env.new_object(&cls, "()V", &[])?;
+        let jobj = self.app.to_java(env)?;
+        env.set_field(
+            output,
+            "app",
+            "Lnet/maidsafe/safe_app/AppExchangeInfo;",
+            jobj.into(),
+        )?;
+        env.delete_local_ref(jobj)?;
+        let cls = unsafe { find_class(env, "net/maidsafe/safe_app/ShareAData")? };
+        let arr = env.new_object_array(
+            self.adata_len as jni::sys::jsize,
+            &cls,
+            JObject::null(),
+        )?;
+        let items = unsafe { slice::from_raw_parts(self.adata, self.adata_len) };
+        for (idx, item) in items.iter().enumerate() {
+            let jobj = item.to_java(env)?;
+            env.set_object_array_element(
+                arr,
+                idx as jni::sys::jsize,
+                jobj,
+            )?;
+            env.delete_local_ref(jobj)?;
+        }
+        let jobj = JObject::from(arr);
+        env.set_field(
+            output,
+            "adata",
+            "[Lnet/maidsafe/safe_app/ShareAData;",
+            jobj.into(),
+        )?;
+        env.delete_local_ref(jobj)?;
+        env.set_field(
+            output,
+            "adataLen",
+            "J",
+            self.adata_len.to_java(env)?.into(),
+        )?;
+        Ok(output)
+    }
+}
+impl<'a> FromJava<JObject<'a>> for TransOwnership {
+    fn from_java(env: &JNIEnv, input: JObject) -> Result<Self, JniError> {
+        let name = env.get_field(input, "name", "[B")?.l()?;
+        let name = XorNameArray::from_java(env, name)?;
+        let type_tag = env.get_field(input, "typeTag", "J")?.j()? as u64;
+        let append_only = env.get_field(input, "appendOnly", "Z")?.z()? as bool;
+        let published = env.get_field(input, "published", "Z")?.z()? as bool;
+        let sequenced = env.get_field(input, "sequenced", "Z")?.z()? as bool;
+        let new_owner = env.get_field(input, "newOwner", "Lnet/maidsafe/safe_app/BlsPublicKey;")?
+            .l()?;
+        let new_owner = BlsPublicKey::from_java(env, new_owner)?;
+        Ok(TransOwnership {
+            name,
+            type_tag,
+            append_only,
+            published,
+            sequenced,
+            new_owner,
+        })
+    }
+}
+impl<'a> ToJava<'a, JObject<'a>> for TransOwnership {
+    fn to_java(&self, env: &'a JNIEnv) -> Result<JObject<'a>, JniError> {
+        let cls = unsafe { find_class(env, "net/maidsafe/safe_app/TransOwnership")? };
+        let output = env.new_object(&cls, "()V", &[])?;
+        let jobj = self.name.to_java(env)?;
+        env.set_field(output, "name", "[B", jobj.into())?;
+        let jobj = self.type_tag.to_java(env)?;
+        env.set_field(output, "typeTag", "J", jobj.into())?;
+        let jobj = self.append_only.to_java(env)?;
+        env.set_field(output, "appendOnly", "Z", jobj.into())?;
+        let jobj = self.published.to_java(env)?;
+        env.set_field(output, "published", "Z", jobj.into())?;
+        let jobj = self.sequenced.to_java(env)?;
+        env.set_field(output, "sequenced", "Z", jobj.into())?;
+        let jobj = self.new_owner.to_java(env)?;
+        env.set_field(
+            output,
+            "newOwner",
+            "Lnet/maidsafe/safe_app/BlsPublicKey;",
+            jobj.into(),
+        )?;
+        env.delete_local_ref(jobj)?;
+        Ok(output)
+    }
+}
+impl<'a> FromJava<JObject<'a>> for TransOwnershipRequest {
+    fn from_java(env: &JNIEnv, input: JObject) -> Result<Self, JniError> {
+        let app = env.get_field(input, "app", "Lnet/maidsafe/safe_app/AppExchangeInfo;")?
+            .l()?;
+        let app = AppExchangeInfo::from_java(env, app)?;
+        let arr = env.get_field(input, "data", "[Lnet/maidsafe/safe_app/TransOwnership;")?
+            .l()?
+            .into_inner() as jni::sys::jarray;
+        let data_len = env.get_array_length(arr)? as usize;
+        let mut vec = Vec::with_capacity(data_len);
+        for idx in 0..data_len {
+            let item = env.get_object_array_element(arr, idx as jni::sys::jsize);
+            let item = TransOwnership::from_java(env, item?)?;
+            vec.push(item);
+        }
+        let data = vec.as_ptr();
+        ::std::mem::forget(vec);
+        Ok(TransOwnershipRequest {
+            app,
+            data,
+            data_len,
+        })
+    }
+}
+impl<'a> ToJava<'a, JObject<'a>> for TransOwnershipRequest {
+    fn to_java(&self, env: &'a JNIEnv) -> Result<JObject<'a>, JniError> {
+        let cls = unsafe { find_class(env, "net/maidsafe/safe_app/TransOwnershipRequest")? };
+        let output = env.new_object(&cls, "()V", &[])?;
+        let jobj = self.app.to_java(env)?;
+        env.set_field(
+            output,
+            "app",
+            "Lnet/maidsafe/safe_app/AppExchangeInfo;",
+            jobj.into(),
+        )?;
+        env.delete_local_ref(jobj)?;
+        let cls = unsafe { find_class(env, "net/maidsafe/safe_app/TransOwnership")? };
+        let arr = env.new_object_array(
+            self.data_len as jni::sys::jsize,
+            &cls,
+            JObject::null(),
+        )?;
+        let items = unsafe { slice::from_raw_parts(self.data, self.data_len) };
+        for (idx, item) in items.iter().enumerate() {
+            let jobj = item.to_java(env)?;
+            env.set_object_array_element(
+                arr,
+                idx as jni::sys::jsize,
+                jobj,
+            )?;
+            env.delete_local_ref(jobj)?;
+        }
+        let jobj = JObject::from(arr);
+        env.set_field(
+            output,
+            "data",
+            "[Lnet/maidsafe/safe_app/TransOwnership;",
+            jobj.into(),
+        )?;
+        env.delete_local_ref(jobj)?;
+        env.set_field(
+            output,
+            "dataLen",
+            "J",
+            self.data_len.to_java(env)?.into(),
+        )?;
+        Ok(output)
+    }
+}
+impl<'a> FromJava<JObject<'a>> for AppKeys {
+    fn from_java(env: &JNIEnv, input: JObject) -> Result<Self, JniError> {
+        let arr = env.get_field(input, "fullId", "[B")?.l()?.into_inner() as jni::sys::jbyteArray;
+        let mut vec = env.convert_byte_array(arr)?;
+        let full_id_len = vec.len();
+        let full_id = vec.as_ptr();
+        ::std::mem::forget(vec);
+        let enc_key = env.get_field(input, "encKey", "[B")?.l()?;
+        let enc_key = SymSecretKey::from_java(env, enc_key)?;
+        let enc_public_key = env.get_field(input, "encPublicKey", "[B")?.l()?;
+        let enc_public_key = AsymPublicKey::from_java(env, enc_public_key)?;
+        let arr = env.get_field(input, "encSecretKey", "[B")?
+            .l()?
+            .into_inner() as jni::sys::jbyteArray;
+        let mut vec = env.convert_byte_array(arr)?;
+        let enc_secret_key_len = vec.len();
+        let enc_secret_key = vec.as_ptr();
+        ::std::mem::forget(vec);
+        Ok(AppKeys {
+            full_id,
+            full_id_len,
+            enc_key,
+            enc_public_key,
+            enc_secret_key,
+            enc_secret_key_len,
+        })
+    }
+}
+impl<'a> ToJava<'a, JObject<'a>> for AppKeys {
+    fn to_java(&self, env: &'a JNIEnv) -> Result<JObject<'a>, JniError> {
+        let cls = unsafe { find_class(env, "net/maidsafe/safe_app/AppKeys")? };
+        let output = env.new_object(&cls, "()V", &[])?;
+        let arr = env.new_byte_array(self.full_id_len as jni::sys::jsize)?;
+        let slice = unsafe { slice::from_raw_parts(self.full_id as *const i8, self.full_id_len) };
+        env.set_byte_array_region(arr, 0, slice)?;
+        let jobj = JObject::from(arr);
+        env.set_field(output, "fullId", "[B", jobj.into())?;
+        env.delete_local_ref(jobj)?;
+        env.set_field(
+            output,
+            "fullIdLen",
+            "J",
+            self.full_id_len.to_java(env)?.into(),
+        )?;
+        let jobj = self.enc_key.to_java(env)?;
+        env.set_field(output, "encKey", "[B", jobj.into())?;
+        let jobj = self.enc_public_key.to_java(env)?;
+        env.set_field(output, "encPublicKey", "[B", jobj.into())?;
+        let arr = env.new_byte_array(
+            self.enc_secret_key_len as jni::sys::jsize,
+        )?;
+        let slice = unsafe {
+            slice::from_raw_parts(self.enc_secret_key as *const i8, self.enc_secret_key_len)
+        };
+        env.set_byte_array_region(arr, 0, slice)?;
+        let jobj = JObject::from(arr);
+        env.set_field(output, "encSecretKey", "[B", jobj.into())?;
+        env.delete_local_ref(jobj)?;
+        env.set_field(
+            output,
+            "encSecretKeyLen",
+            "J",
+            self.enc_secret_key_len.to_java(env)?.into(),
+        )?;
+        Ok(output)
+    }
+}
+impl<'a> FromJava<JObject<'a>> for AccessContInfo {
+    fn from_java(env: &JNIEnv, input: JObject) -> Result<Self, JniError> {
+        let id = env.get_field(input, "id", "[B")?.l()?;
+        let id = XorNameArray::from_java(env, id)?;
+        let tag = env.get_field(input, "tag", "J")?.j()? as u64;
+        let nonce = env.get_field(input, "nonce", "[B")?.l()?;
+        let nonce = SymNonce::from_java(env, nonce)?;
+        Ok(AccessContInfo { id, tag, nonce })
+    }
+}
+impl<'a> ToJava<'a, JObject<'a>> for AccessContInfo {
+    fn to_java(&self, env: &'a JNIEnv) -> Result<JObject<'a>, JniError> {
+        let cls = unsafe { find_class(env, "net/maidsafe/safe_app/AccessContInfo")? };
+        let output = env.new_object(&cls, "()V", &[])?;
+        let jobj = self.id.to_java(env)?;
+        env.set_field(output, "id", "[B", jobj.into())?;
+        let jobj = self.tag.to_java(env)?;
+        env.set_field(output, "tag", "J", jobj.into())?;
+        let jobj = self.nonce.to_java(env)?;
+        env.set_field(output, "nonce", "[B", jobj.into())?;
+        Ok(output)
+    }
+}
+impl<'a> FromJava<JObject<'a>> for ContainerInfo {
+    fn from_java(env: &JNIEnv, input: JObject) -> Result<Self, JniError> {
+        let name = env.get_field(input, "name", "Ljava/lang/String;")?
+            .l()?
+            .into();
+        let name = <*mut _>::from_java(env, name)?;
+        let mdata_info = env.get_field(input, "mdataInfo", "Lnet/maidsafe/safe_app/MDataInfo;")?
+            .l()?;
+        let mdata_info = MDataInfo::from_java(env, mdata_info)?;
+        let permissions = env.get_field(
+            input,
+            "permissions",
+            "Lnet/maidsafe/safe_app/PermissionSet;",
+        )?
+            .l()?;
+        let permissions = PermissionSet::from_java(env, permissions)?;
+        Ok(ContainerInfo {
+            name,
+            mdata_info,
+            permissions,
+        })
+    }
+}
+impl<'a> ToJava<'a, JObject<'a>> for ContainerInfo {
+    fn to_java(&self, env: &'a JNIEnv) -> Result<JObject<'a>, JniError> {
+        let cls = unsafe { find_class(env, "net/maidsafe/safe_app/ContainerInfo")? };
+        let output = env.new_object(&cls, "()V", &[])?;
+        if !self.name.is_null() {
+            let name: JObject = self.name.to_java(env)?.into();
+            env.set_field(
+                output,
+                "name",
+                "Ljava/lang/String;",
+                name.into(),
+            )?;
+            env.delete_local_ref(name)?;
+        }
+        let jobj = self.mdata_info.to_java(env)?;
+        env.set_field(
+            output,
+            "mdataInfo",
+            "Lnet/maidsafe/safe_app/MDataInfo;",
+            jobj.into(),
+        )?;
+        env.delete_local_ref(jobj)?;
+        let jobj = self.permissions.to_java(env)?;
+        env.set_field(
+            output,
+            "permissions",
+            "Lnet/maidsafe/safe_app/PermissionSet;",
+            jobj.into(),
+        )?;
+        env.delete_local_ref(jobj)?;
+        Ok(output)
+    }
+}
+impl<'a> FromJava<JObject<'a>> for AccessContainerEntry {
+    fn from_java(env: &JNIEnv, input: JObject) -> Result<Self, JniError> {
+        let arr = env.get_field(
+            input,
+            "containers",
+            "[Lnet/maidsafe/safe_app/ContainerInfo;",
+        )?
+            .l()?
+            .into_inner() as jni::sys::jarray;
+        let containers_len = env.get_array_length(arr)? as usize;
+        let mut vec = Vec::with_capacity(containers_len);
+        for idx in 0..containers_len {
+            let item = env.get_object_array_element(arr, idx as jni::sys::jsize);
+            let item = ContainerInfo::from_java(env, item?)?;
+            vec.push(item);
+        }
+        let containers = vec.as_ptr();
+        ::std::mem::forget(vec);
+        Ok(AccessContainerEntry {
+            containers,
+            containers_len,
+        })
+    }
+}
+impl<'a> ToJava<'a, JObject<'a>> for AccessContainerEntry {
+    fn to_java(&self, env: &'a JNIEnv) -> Result<JObject<'a>, JniError> {
+        let cls = unsafe { find_class(env, "net/maidsafe/safe_app/AccessContainerEntry")? };
+        let output = env.new_object(&cls, "()V", &[])?;
+        let cls = unsafe { find_class(env, "net/maidsafe/safe_app/ContainerInfo")? };
+        let arr = env.new_object_array(
+            self.containers_len as jni::sys::jsize,
+            &cls,
+            JObject::null(),
+        )?;
+        let items = unsafe { slice::from_raw_parts(self.containers, self.containers_len) };
+        for (idx, item) in items.iter().enumerate() {
+            let jobj = item.to_java(env)?;
+            env.set_object_array_element(
+                arr,
+                idx as jni::sys::jsize,
+                jobj,
+            )?;
+            env.delete_local_ref(jobj)?;
+        }
+        let jobj = JObject::from(arr);
+        env.set_field(
+            output,
+            "containers",
+            "[Lnet/maidsafe/safe_app/ContainerInfo;",
+            jobj.into(),
+        )?;
+        env.delete_local_ref(jobj)?;
+        env.set_field(
+            output,
+            "containersLen",
+            "J",
+            self.containers_len.to_java(env)?.into(),
+        )?;
+        Ok(output)
+    }
+}
+impl<'a> FromJava<JObject<'a>> for AuthGranted {
+    fn from_java(env: &JNIEnv, input: JObject) -> Result<Self, JniError> {
+        let app_keys = env.get_field(input, "appKeys", "Lnet/maidsafe/safe_app/AppKeys;")?
+            .l()?;
+        let app_keys = AppKeys::from_java(env, app_keys)?;
+        let access_container_info = env.get_field(
+            input,
+            "accessContainerInfo",
+            "Lnet/maidsafe/safe_app/AccessContInfo;",
+        )?
+            .l()?;
+        let access_container_info = AccessContInfo::from_java(env, access_container_info)?;
+        let access_container_entry = env.get_field(
+            input,
+            "accessContainerEntry",
+            "Lnet/maidsafe/safe_app/AccessContainerEntry;",
+        )?
+            .l()?;
+        let access_container_entry = AccessContainerEntry::from_java(env, access_container_entry)?;
+        let arr = env.get_field(input, "bootstrapConfig", "[B")?
+            .l()?
+            .into_inner() as jni::sys::jbyteArray;
+        let mut vec = env.convert_byte_array(arr)?;
+        let bootstrap_config_len = vec.len();
+        let bootstrap_config = vec.as_ptr();
+        ::std::mem::forget(vec);
+        Ok(AuthGranted {
+            app_keys,
+            access_container_info,
+            access_container_entry,
+            bootstrap_config,
+            bootstrap_config_len,
+        })
+    }
+}
+impl<'a> ToJava<'a, JObject<'a>> for AuthGranted {
+    fn to_java(&self, env: &'a JNIEnv) -> Result<JObject<'a>, JniError> {
+        let cls = unsafe { find_class(env, "net/maidsafe/safe_app/AuthGranted")? };
+        let output = env.new_object(&cls, "()V", &[])?;
+        let jobj = self.app_keys.to_java(env)?;
+        env.set_field(
+            output,
+            "appKeys",
+            "Lnet/maidsafe/safe_app/AppKeys;",
+            jobj.into(),
+        )?;
+        env.delete_local_ref(jobj)?;
+        let jobj = self.access_container_info.to_java(env)?;
+        env.set_field(
+            output,
+            "accessContainerInfo",
+            "Lnet/maidsafe/safe_app/AccessContInfo;",
+            jobj.into(),
+        )?;
+        env.delete_local_ref(jobj)?;
+        let jobj = self.access_container_entry.to_java(env)?;
+        env.set_field(
+            output,
+            "accessContainerEntry",
+            "Lnet/maidsafe/safe_app/AccessContainerEntry;",
+            jobj.into(),
+        )?;
+        env.delete_local_ref(jobj)?;
+        let arr = env.new_byte_array(
+            self.bootstrap_config_len as jni::sys::jsize,
+        )?;
+        let slice = unsafe {
+            slice::from_raw_parts(
+                self.bootstrap_config as *const i8,
+                self.bootstrap_config_len,
+            )
+        };
+        env.set_byte_array_region(arr, 0, slice)?;
+        let jobj = JObject::from(arr);
+        env.set_field(output, "bootstrapConfig", "[B", jobj.into())?;
+        env.delete_local_ref(jobj)?;
+        env.set_field(
+            output,
+            "bootstrapConfigLen",
+            "J",
+            self.bootstrap_config_len.to_java(env)?.into(),
+        )?;
+        Ok(output)
+    }
+}
+impl<'a> FromJava<JObject<'a>> for AppAccess {
+    fn from_java(env: &JNIEnv, input: JObject) -> Result<Self, JniError> {
+        let sign_key = env.get_field(input, "signKey", "Lnet/maidsafe/safe_app/BlsPublicKey;")?
+            .l()?;
+        let sign_key = BlsPublicKey::from_java(env, sign_key)?;
+        let permissions = env.get_field(
+            input,
+            "permissions",
+            "Lnet/maidsafe/safe_app/PermissionSet;",
+        )?
+            .l()?;
+        let permissions = PermissionSet::from_java(env, permissions)?;
+        let name = env.get_field(input, "name", "Ljava/lang/String;")?
+            .l()?
+            .into();
+        let name = <*mut _>::from_java(env, name)?;
+        let app_id = env.get_field(input, "appId", "Ljava/lang/String;")?
+            .l()?
+            .into();
+        let app_id = <*mut _>::from_java(env, app_id)?;
+        Ok(AppAccess {
+            sign_key,
+            permissions,
+            name,
+            app_id,
+        })
+    }
+}
+impl<'a> ToJava<'a, JObject<'a>> for AppAccess {
+    fn to_java(&self, env: &'a JNIEnv) -> Result<JObject<'a>, JniError> {
+        let cls = unsafe { find_class(env, "net/maidsafe/safe_app/AppAccess")? };
+        let output = env.new_object(&cls, "()V", &[])?;
+        let jobj = self.sign_key.to_java(env)?;
+        env.set_field(
+            output,
+            "signKey",
+            "Lnet/maidsafe/safe_app/BlsPublicKey;",
+            jobj.into(),
+        )?;
+        env.delete_local_ref(jobj)?;
+        let jobj = self.permissions.to_java(env)?;
+        env.set_field(
+            output,
+            "permissions",
+            "Lnet/maidsafe/safe_app/PermissionSet;",
+            jobj.into(),
+        )?;
+        env.delete_local_ref(jobj)?;
+        if !self.name.is_null() {
+            let name: JObject = self.name.to_java(env)?.into();
+            env.set_field(
+                output,
+                "name",
+                "Ljava/lang/String;",
+                name.into(),
+            )?;
+            env.delete_local_ref(name)?;
+        }
+        if !self.app_id.is_null() {
+            let app_id: JObject = self.app_id.to_java(env)?.into();
+            env.set_field(
+                output,
+                "appId",
+                "Ljava/lang/String;",
+                app_id.into(),
+            )?;
+            env.delete_local_ref(app_id)?;
+        }
+        Ok(output)
+    }
+}
+impl<'a> FromJava<JObject<'a>> for MetadataResponse {
+    fn from_java(env: &JNIEnv, input: JObject) -> Result<Self, JniError> {
+        let name = env.get_field(input, "name", "Ljava/lang/String;")?
+            .l()?
+            .into();
+        let name = <*mut _>::from_java(env, name)?;
+        let description = env.get_field(input, "description", "Ljava/lang/String;")?
+            .l()?
+            .into();
+        let description = <*mut _>::from_java(env, description)?;
+        let xor_name = env.get_field(input, "xorName", "[B")?.l()?;
+        let xor_name = XorNameArray::from_java(env, xor_name)?;
+        let type_tag = env.get_field(input, "typeTag", "J")?.j()? as u64;
+        Ok(MetadataResponse {
+            name,
+            description,
+            xor_name,
+            type_tag,
+        })
+    }
+}
+impl<'a> ToJava<'a, JObject<'a>> for MetadataResponse {
+    fn to_java(&self, env: &'a JNIEnv) -> Result<JObject<'a>, JniError> {
+        let cls = unsafe { find_class(env, "net/maidsafe/safe_app/MetadataResponse")? };
+        let output = env.new_object(&cls, "()V", &[])?;
+        if !self.name.is_null() {
+            let name: JObject = self.name.to_java(env)?.into();
+            env.set_field(
+                output,
+                "name",
+                "Ljava/lang/String;",
+                name.into(),
+            )?;
+            env.delete_local_ref(name)?;
+        }
+        if !self.description.is_null() {
+            let description: JObject = self.description.to_java(env)?.into();
+            env.set_field(
+                output,
+                "description",
+                "Ljava/lang/String;",
+                description.into(),
+            )?;
+            env.delete_local_ref(description)?;
+        }
+        let jobj = self.xor_name.to_java(env)?;
+        env.set_field(output, "xorName", "[B", jobj.into())?;
+        let jobj = self.type_tag.to_java(env)?;
+        env.set_field(output, "typeTag", "J", jobj.into())?;
+        Ok(output)
+    }
+}
+impl<'a> FromJava<JObject<'a>> for MDataKey {
+    fn from_java(env: &JNIEnv, input: JObject) -> Result<Self, JniError> {
+        let arr = env.get_field(input, "key", "[B")?.l()?.into_inner() as jni::sys::jbyteArray;
+        let mut vec = env.convert_byte_array(arr)?;
+        let key_len = vec.len();
+        let key = vec.as_ptr();
+        ::std::mem::forget(vec);
+        Ok(MDataKey { key, key_len })
+    }
+}
+impl<'a> ToJava<'a, JObject<'a>> for MDataKey {
+    fn to_java(&self, env: &'a JNIEnv) -> Result<JObject<'a>, JniError> {
+        let cls = unsafe { find_class(env, "net/maidsafe/safe_app/MDataKey")? };
+        let output = env.new_object(&cls, "()V", &[])?;
+        let arr = env.new_byte_array(self.key_len as jni::sys::jsize)?;
+        let slice = unsafe { slice::from_raw_parts(self.key as *const i8, self.key_len) };
+        env.set_byte_array_region(arr, 0, slice)?;
+        let jobj = JObject::from(arr);
+        env.set_field(output, "key", "[B", jobj.into())?;
+        env.delete_local_ref(jobj)?;
+        env.set_field(
+            output,
+            "keyLen",
+            "J",
+            self.key_len.to_java(env)?.into(),
+        )?;
+        Ok(output)
+    }
+}
+impl<'a> FromJava<JObject<'a>> for MDataValue {
+    fn from_java(env: &JNIEnv, input: JObject) -> Result<Self, JniError> {
+        let arr = env.get_field(input, "content", "[B")?.l()?.into_inner() as jni::sys::jbyteArray;
+        let mut vec = env.convert_byte_array(arr)?;
+        let content_len = vec.len();
+        let content = vec.as_ptr();
+        ::std::mem::forget(vec);
+        let entry_version = env.get_field(input, "entryVersion", "J")?.j()? as u64;
+        Ok(MDataValue {
+            content,
+            content_len,
+            entry_version,
+        })
+    }
+}
+impl<'a> ToJava<'a, JObject<'a>> for MDataValue {
+    fn to_java(&self, env: &'a JNIEnv) -> Result<JObject<'a>, JniError> {
+        let cls = unsafe { find_class(env, "net/maidsafe/safe_app/MDataValue")? };
+        let output = env.new_object(&cls, "()V", &[])?;
+        let arr = env.new_byte_array(self.content_len as jni::sys::jsize)?;
+        let slice = unsafe { slice::from_raw_parts(self.content as *const i8, self.content_len) };
+        env.set_byte_array_region(arr, 0, slice)?;
+        let jobj = JObject::from(arr);
+        env.set_field(output, "content", "[B", jobj.into())?;
+        env.delete_local_ref(jobj)?;
+        env.set_field(
+            output,
+            "contentLen",
+            "J",
+            self.content_len.to_java(env)?.into(),
+        )?;
+        let jobj = self.entry_version.to_java(env)?;
+        env.set_field(output, "entryVersion", "J", jobj.into())?;
+        Ok(output)
+    }
+}
+impl<'a> FromJava<JObject<'a>> for MDataEntry {
+    fn from_java(env: &JNIEnv, input: JObject) -> Result<Self, JniError> {
+        let key = env.get_field(input, "key", "Lnet/maidsafe/safe_app/MDataKey;")?
+            .l()?;
+        let key = MDataKey::from_java(env, key)?;
+        let value = env.get_field(input, "value", "Lnet/maidsafe/safe_app/MDataValue;")?
+            .l()?;
+        let value = MDataValue::from_java(env, value)?;
+        Ok(MDataEntry { key, value })
+    }
+}
+impl<'a> ToJava<'a, JObject<'a>> for MDataEntry {
+    fn to_java(&self, env: &'a JNIEnv) -> Result<JObject<'a>, JniError> {
+        let cls = unsafe { find_class(env, "net/maidsafe/safe_app/MDataEntry")? };
+        let output = env.new_object(&cls, "()V", &[])?;
+        let jobj = self.key.to_java(env)?;
+        env.set_field(
+            output,
+            "key",
+            "Lnet/maidsafe/safe_app/MDataKey;",
+            jobj.into(),
+        )?;
+        env.delete_local_ref(jobj)?;
+        let jobj = self.value.to_java(env)?;
+        env.set_field(
+            output,
+            "value",
+            "Lnet/maidsafe/safe_app/MDataValue;",
+            jobj.into(),
+        )?;
+        env.delete_local_ref(jobj)?;
+        Ok(output)
+    }
+}
+impl<'a> FromJava<JObject<'a>> for OwnershipChange {
+    fn from_java(env: &JNIEnv, input: JObject) -> Result<Self, JniError> {
+        let current_owner = env.get_field(
+            input,
+            "currentOwner",
+            "Lnet/maidsafe/safe_app/BlsPublicKey;",
+        )?
+            .l()?;
+        let current_owner = BlsPublicKey::from_java(env, current_owner)?;
+        let new_owner = env.get_field(input, "newOwner", "Lnet/maidsafe/safe_app/BlsPublicKey;")?
+            .l()?;
+        let new_owner = BlsPublicKey::from_java(env, new_owner)?;
+        let description = env.get_field(input, "description", "Ljava/lang/String;")?
+            .l()?
+            .into();
+        let description = <*mut _>::from_java(env, description)?;
+        Ok(OwnershipChange {
+            current_owner,
+            new_owner,
+            description,
+        })
+    }
+}
+impl<'a> ToJava<'a, JObject<'a>> for OwnershipChange {
+    fn to_java(&self, env: &'a JNIEnv) -> Result<JObject<'a>, JniError> {
+        let cls = unsafe { find_class(env, "net/maidsafe/safe_app/OwnershipChange")? };
+        let output = env.new_object(&cls, "()V", &[])?;
+        let jobj = self.current_owner.to_java(env)?;
+        env.set_field(
+            output,
+            "currentOwner",
+            "Lnet/maidsafe/safe_app/BlsPublicKey;",
+            jobj.into(),
+        )?;
+        env.delete_local_ref(jobj)?;
+        let jobj = self.new_owner.to_java(env)?;
+        env.set_field(
+            output,
+            "newOwner",
+            "Lnet/maidsafe/safe_app/BlsPublicKey;",
+            jobj.into(),
+        )?;
+        env.delete_local_ref(jobj)?;
+        if !self.description.is_null() {
+            let description: JObject = self.description.to_java(env)?.into();
+            env.set_field(
+                output,
+                "description",
+                "Ljava/lang/String;",
+                description.into(),
+            )?;
+            env.delete_local_ref(description)?;
+        }
+        Ok(output)
+    }
+}
+impl<'a> FromJava<JObject<'a>> for File {
+    fn from_java(env: &JNIEnv, input: JObject) -> Result<Self, JniError> {
+        let size = env.get_field(input, "size", "J")?.j()? as u64;
+        let created_sec = env.get_field(input, "createdSec", "J")?.j()? as i64;
+        let created_nsec = env.get_field(input, "createdNsec", "I")?.i()? as u32;
+        let modified_sec = env.get_field(input, "modifiedSec", "J")?.j()? as i64;
+        let modified_nsec = env.get_field(input, "modifiedNsec", "I")?.i()? as u32;
+        let arr = env.get_field(input, "userMetadata", "[B")?
+            .l()?
+            .into_inner() as jni::sys::jbyteArray;
+        let mut vec = env.convert_byte_array(arr)?;
+        let user_metadata_len = vec.len();
+        let user_metadata = vec.as_ptr();
+        ::std::mem::forget(vec);
+        let data_map_name = env.get_field(input, "dataMapName", "[B")?.l()?;
+        let data_map_name = XorNameArray::from_java(env, data_map_name)?;
+        let published = env.get_field(input, "published", "Z")?.z()? as bool;
+        Ok(File {
+            size,
+            created_sec,
+            created_nsec,
+            modified_sec,
+            modified_nsec,
+            user_metadata,
+            user_metadata_len,
+            data_map_name,
+            published,
+        })
+    }
+}
+impl<'a> ToJava<'a, JObject<'a>> for File {
+    fn to_java(&self, env: &'a JNIEnv) -> Result<JObject<'a>, JniError> {
+        let cls = unsafe { find_class(env, "net/maidsafe/safe_app/File")? };
+        let output = env.new_object(&cls, "()V", &[])?;
+        let jobj = self.size.to_java(env)?;
+        env.set_field(output, "size", "J", jobj.into())?;
+        let jobj = self.created_sec.to_java(env)?;
+        env.set_field(output, "createdSec", "J", jobj.into())?;
+        let jobj = self.created_nsec.to_java(env)?;
+        env.set_field(output, "createdNsec", "I", jobj.into())?;
+        let jobj = self.modified_sec.to_java(env)?;
+        env.set_field(output, "modifiedSec", "J", jobj.into())?;
+        let jobj = self.modified_nsec.to_java(env)?;
+        env.set_field(output, "modifiedNsec", "I", jobj.into())?;
+        let arr = env.new_byte_array(
+            self.user_metadata_len as jni::sys::jsize,
+        )?;
+        let slice = unsafe {
+            slice::from_raw_parts(self.user_metadata as *const i8, self.user_metadata_len)
+        };
+        env.set_byte_array_region(arr, 0, slice)?;
+        let jobj = JObject::from(arr);
+        env.set_field(output, "userMetadata", "[B", jobj.into())?;
+        env.delete_local_ref(jobj)?;
+        env.set_field(
+            output,
+            "userMetadataLen",
+            "J",
+            self.user_metadata_len.to_java(env)?.into(),
+        )?;
+        let jobj = self.data_map_name.to_java(env)?;
+        env.set_field(output, "dataMapName", "[B", jobj.into())?;
+        let jobj = self.published.to_java(env)?;
+        env.set_field(output, "published", "Z", jobj.into())?;
+        Ok(output)
+    }
+}
+impl<'a> FromJava<JObject<'a>> for FfiResult {
+    fn from_java(env: &JNIEnv, input: JObject) -> Result<Self, JniError> {
+        let error_code = env.get_field(input, "errorCode", "I")?.i()? as i32;
+        let description = env.get_field(input, "description", "Ljava/lang/String;")?
+            .l()?
+            .into();
+        let description = <*mut _>::from_java(env, description)?;
+        Ok(FfiResult {
+            error_code,
+            description,
+        })
+    }
+}
+impl<'a> ToJava<'a, JObject<'a>> for FfiResult {
+    fn to_java(&self, env: &'a JNIEnv) -> Result<JObject<'a>, JniError> {
+        let cls = unsafe { find_class(env, "net/maidsafe/safe_app/FfiResult")? };
+        let output = env.new_object(&cls, "()V", &[])?;
+        let jobj = self.error_code.to_java(env)?;
+        env.set_field(output, "errorCode", "I", jobj.into())?;
+        if !self.description.is_null() {
+            let description: JObject = self.description.to_java(env)?.into();
+            env.set_field(
+                output,
+                "description",
+                "Ljava/lang/String;",
+                description.into(),
+            )?;
+            env.delete_local_ref(description)?;
+        }
+        Ok(output)
+    }
+}
+extern "C" fn call_CallbackResult(ctx: *mut c_void, result: *const FfiResult) {
+    unsafe {
+        let guard = jni_unwrap!(EnvGuard::new(JVM.as_ref()));
+        let env = guard.env();
+        let cb = jni_unwrap!(convert_cb_from_java(&env, ctx));
+        let result = if result.is_null() {
+            JObject::null()
+        } else {
+            jni_unwrap!((*result).to_java(&env))
+        };
+        jni_unwrap!(env.call_method(
+            cb.as_obj(),
+            "call",
+            "(Lnet/maidsafe/safe_app/FfiResult;)V",
+            &[result.into()],
+        ));
+    }
+}
+
+#[link(name = "safe_app")]
+extern "C" {
+    fn app_reconnect(
+        app: *mut App,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void, result: *const FfiResult),
+    );
+}
+#[no_mangle]
+pub unsafe extern "system" fn Java_net_maidsafe_safe_1app_NativeBindings_appReconnect(
+    env: JNIEnv,
+    _class: JClass,
+    app: jlong,
+    o_cb: JObject,
+) {
+    let app = app as *mut App;
+    let ctx = gen_ctx!(env, o_cb);
+    app_reconnect(app, ctx, call_CallbackResult);
+}
+
+#[link(name = "safe_app")]
+extern "C" {
+    fn app_set_config_dir_path(
+        new_path: *const c_char,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void, result: *const FfiResult),
+    );
+}
+#[no_mangle]
+pub unsafe extern "system" fn Java_net_maidsafe_safe_1app_NativeBindings_appSetConfigDirPath(
+    env: JNIEnv,
+    _class: JClass,
+    new_path: JString,
+    o_cb: JObject,
+) {
+    let new_path = jni_unwrap!(CString::from_java(&env, new_path));
+    let ctx = gen_ctx!(env, o_cb);
+    app_set_config_dir_path(new_path.as_ptr(), ctx, call_CallbackResult);
+}
+
+#[link(name = "safe_app")]
+extern "C" {
+    fn app_free(app: *mut App);
+}
+#[no_mangle]
+pub unsafe extern "system" fn Java_net_maidsafe_safe_1app_NativeBindings_appFree(
+    env: JNIEnv,
+    _class: JClass,
+    app: jlong,
+) {
+    let app = app as *mut App;
+    app_free(app);
+}
+
+#[link(name = "safe_app")]
+extern "C" {
+    fn app_reset_object_cache(
+        app: *mut App,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void, result: *const FfiResult),
+    );
+}
+#[no_mangle]
+pub unsafe extern "system" fn Java_net_maidsafe_safe_1app_NativeBindings_appResetObjectCache(
+    env: JNIEnv,
+    _class: JClass,
+    app: jlong,
+    o_cb: JObject,
+) {
+    let app = app as *mut App;
+    let ctx = gen_ctx!(env, o_cb);
+    app_reset_object_cache(app, ctx, call_CallbackResult);
+}
+extern "C" fn call_CallbackResultString(
+    ctx: *mut c_void,
+    result: *const FfiResult,
+    container_name: *const c_char,
+) {
+    unsafe {
+        let guard = jni_unwrap!(EnvGuard::new(JVM.as_ref()));
+        let env = guard.env();
+        let cb = jni_unwrap!(convert_cb_from_java(&env, ctx));
+        let result = if result.is_null() {
+            JObject::null()
+        } else {
+            jni_unwrap!((*result).to_java(&env))
+        };
+        let container_name: JObject = if container_name.is_null() {
+            JObject::null()
+        } else {
+            jni_unwrap!(container_name.to_java(&env)).into()
+        };
+        jni_unwrap!(env.call_method(
+            cb.as_obj(),
+            "call",
+            "(Lnet/maidsafe/safe_app/FfiResult;Ljava/lang/String;)V",
+            &[result.into(), container_name.into()],
+        ));
+    }
+}
+
+#[link(name = "safe_app")]
+extern "C" {
+    fn app_container_name(
+        app_id: *const c_char,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void,
+                            result: *const FfiResult,
+                            container_name: *const c_char),
+    );
+}
+#[no_mangle]
+pub unsafe extern "system" fn Java_net_maidsafe_safe_1app_NativeBindings_appContainerName(
+    env: JNIEnv,
+    _class: JClass,
+    app_id: JString,
+    o_cb: JObject,
+) {
+    let app_id = jni_unwrap!(CString::from_java(&env, app_id));
+    let ctx = gen_ctx!(env, o_cb);
+    app_container_name(app_id.as_ptr(), ctx, call_CallbackResultString);
+}
+
+#[link(name = "safe_app")]
+extern "C" {
+    fn app_is_mock();
+}
+#[no_mangle]
+pub unsafe extern "system" fn Java_net_maidsafe_safe_1app_NativeBindings_appIsMock(
+    env: JNIEnv,
+    _class: JClass,
+) {
+    app_is_mock();
+}
+
+#[link(name = "safe_app")]
+extern "C" {
+    fn access_container_refresh_access_info(
+        app: *const App,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void, result: *const FfiResult),
+    );
+}
+#[no_mangle]pub unsafe extern "system" fn Java_net_maidsafe_safe_1app_NativeBindings_accessContainerRefreshAccessInfo ( env : JNIEnv , _class : JClass , app : jlong , o_cb : JObject ){
+    let app = app as *mut App;
+    let ctx = gen_ctx!(env, o_cb);
+    access_container_refresh_access_info(app, ctx, call_CallbackResult);
+}
+extern "C" fn call_CallbackResultContainerPermissionsArrayLen(
+    ctx: *mut c_void,
+    result: *const FfiResult,
+    container_perms: *const ContainerPermissions,
+    container_perms_len: usize,
+) {
+    unsafe {
+        let guard = jni_unwrap!(EnvGuard::new(JVM.as_ref()));
+        let env = guard.env();
+        let cb = jni_unwrap!(convert_cb_from_java(&env, ctx));
+        let result = if result.is_null() {
+            JObject::null()
+        } else {
+            jni_unwrap!((*result).to_java(&env))
+        };
+        let container_perms =
+            jni_unwrap!(slice::from_raw_parts(container_perms, container_perms_len).to_java(&env));
+        jni_unwrap!(env.call_method(
+            cb.as_obj(),
+            "call",
+            "(Lnet/maidsafe/safe_app/FfiResult;[Lnet/maidsafe/safe_app/ContainerPermissions;)V",
+            &[result.into(), container_perms.into()],
+        ));
+    }
+}
+
+#[link(name = "safe_app")]
+extern "C" {
+    fn access_container_fetch(
+        app: *const App,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void,
+                            result: *const FfiResult,
+                            container_perms: *const ContainerPermissions,
+                            container_perms_len: usize),
+    );
+}
+#[no_mangle]
+pub unsafe extern "system" fn Java_net_maidsafe_safe_1app_NativeBindings_accessContainerFetch(
+    env: JNIEnv,
+    _class: JClass,
+    app: jlong,
+    o_cb: JObject,
+) {
+    let app = app as *mut App;
+    let ctx = gen_ctx!(env, o_cb);
+    access_container_fetch(app, ctx, call_CallbackResultContainerPermissionsArrayLen);
+}
+extern "C" fn call_CallbackResultMDataInfo(
+    ctx: *mut c_void,
+    result: *const FfiResult,
+    mdata_info: *const MDataInfo,
+) {
+    unsafe {
+        let guard = jni_unwrap!(EnvGuard::new(JVM.as_ref()));
+        let env = guard.env();
+        let cb = jni_unwrap!(convert_cb_from_java(&env, ctx));
+        let result = if result.is_null() {
+            JObject::null()
+        } else {
+            jni_unwrap!((*result).to_java(&env))
+        };
+        let mdata_info = if mdata_info.is_null() {
+            JObject::null()
+        } else {
+            jni_unwrap!((*mdata_info).to_java(&env))
+        };
+        jni_unwrap!(env.call_method(
+            cb.as_obj(),
+            "call",
+            "(Lnet/maidsafe/safe_app/FfiResult;Lnet/maidsafe/safe_app/MDataInfo;)V",
+            &[result.into(), mdata_info.into()],
+        ));
+    }
+}
+
+#[link(name = "safe_app")]
+extern "C" {
+    fn access_container_get_container_mdata_info(
+        app: *const App,
+        name: *const c_char,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void,
+                            result: *const FfiResult,
+                            mdata_info: *const MDataInfo),
+    );
+}
+#[no_mangle]pub unsafe extern "system" fn Java_net_maidsafe_safe_1app_NativeBindings_accessContainerGetContainerMdataInfo ( env : JNIEnv , _class : JClass , app : jlong , name : JString , o_cb : JObject ){
+    let app = app as *mut App;
+    let name = jni_unwrap!(CString::from_java(&env, name));
+    let ctx = gen_ctx!(env, o_cb);
+    access_container_get_container_mdata_info(
+        app,
+        name.as_ptr(),
+        ctx,
+        call_CallbackResultMDataInfo,
+    );
+}
+extern "C" fn call_CallbackResultCipherOptHandle(
+    ctx: *mut c_void,
+    result: *const FfiResult,
+    handle: CipherOptHandle,
+) {
+    unsafe {
+        let guard = jni_unwrap!(EnvGuard::new(JVM.as_ref()));
+        let env = guard.env();
+        let cb = jni_unwrap!(convert_cb_from_java(&env, ctx));
+        let result = if result.is_null() {
+            JObject::null()
+        } else {
+            jni_unwrap!((*result).to_java(&env))
+        };
+        let handle = jni_unwrap!(handle.to_java(&env));
+        jni_unwrap!(env.call_method(
+            cb.as_obj(),
+            "call",
+            "(Lnet/maidsafe/safe_app/FfiResult;J)V",
+            &[result.into(), handle.into()],
+        ));
+    }
+}
+
+#[link(name = "safe_app")]
+extern "C" {
+    fn cipher_opt_new_plaintext(
+        app: *const App,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void,
+                            result: *const FfiResult,
+                            handle: CipherOptHandle),
+    );
+}
+#[no_mangle]
+pub unsafe extern "system" fn Java_net_maidsafe_safe_1app_NativeBindings_cipherOptNewPlaintext(
+    env: JNIEnv,
+    _class: JClass,
+    app: jlong,
+    o_cb: JObject,
+) {
+    let app = app as *mut App;
+    let ctx = gen_ctx!(env, o_cb);
+    cipher_opt_new_plaintext(app, ctx, call_CallbackResultCipherOptHandle);
+}
+
+#[link(name = "safe_app")]
+extern "C" {
+    fn cipher_opt_new_symmetric(
+        app: *const App,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void,
+                            result: *const FfiResult,
+                            handle: CipherOptHandle),
+    );
+}
+#[no_mangle]
+pub unsafe extern "system" fn Java_net_maidsafe_safe_1app_NativeBindings_cipherOptNewSymmetric(
+    env: JNIEnv,
+    _class: JClass,
+    app: jlong,
+    o_cb: JObject,
+) {
+    let app = app as *mut App;
+    let ctx = gen_ctx!(env, o_cb);
+    cipher_opt_new_symmetric(app, ctx, call_CallbackResultCipherOptHandle);
+}
+
+#[link(name = "safe_app")]
+extern "C" {
+    fn cipher_opt_new_asymmetric(
+        app: *const App,
+        peer_encrypt_key_h: EncryptPubKeyHandle,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void,
+                            result: *const FfiResult,
+                            handle: CipherOptHandle),
+    );
+}
+#[no_mangle]
+pub unsafe extern "system" fn Java_net_maidsafe_safe_1app_NativeBindings_cipherOptNewAsymmetric(
+    env: JNIEnv,
+    _class: JClass,
+    app: jlong,
+    peer_encrypt_key_h: EncryptPubKeyHandle,
+    o_cb: JObject,
+) {
+    let app = app as *mut App;
+    let ctx = gen_ctx!(env, o_cb);
+    cipher_opt_new_asymmetric(
+        app,
+        peer_encrypt_key_h as EncryptPubKeyHandle,
+        ctx,
+        call_CallbackResultCipherOptHandle,
+    );
+}
+
+#[link(name = "safe_app")]
+extern "C" {
+    fn cipher_opt_new_authenticated_asymmetric(
+        app: *const App,
+        peer_encrypt_key_h: EncryptPubKeyHandle,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void,
+                            result: *const FfiResult,
+                            handle: CipherOptHandle),
+    );
+}
+#[no_mangle]pub unsafe extern "system" fn Java_net_maidsafe_safe_1app_NativeBindings_cipherOptNewAuthenticatedAsymmetric ( env : JNIEnv , _class : JClass , app : jlong , peer_encrypt_key_h : EncryptPubKeyHandle , o_cb : JObject ){
+    let app = app as *mut App;
+    let ctx = gen_ctx!(env, o_cb);
+    cipher_opt_new_authenticated_asymmetric(
+        app,
+        peer_encrypt_key_h as EncryptPubKeyHandle,
+        ctx,
+        call_CallbackResultCipherOptHandle,
+    );
+}
+
+#[link(name = "safe_app")]
+extern "C" {
+    fn cipher_opt_free(
+        app: *const App,
+        handle: CipherOptHandle,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void, result: *const FfiResult),
+    );
+}
+#[no_mangle]
+pub unsafe extern "system" fn Java_net_maidsafe_safe_1app_NativeBindings_cipherOptFree(
+    env: JNIEnv,
+    _class: JClass,
+    app: jlong,
+    handle: CipherOptHandle,
+    o_cb: JObject,
+) {
+    let app = app as *mut App;
+    let ctx = gen_ctx!(env, o_cb);
+    cipher_opt_free(app, handle as CipherOptHandle, ctx, call_CallbackResult);
+}
+extern "C" fn call_CallbackResultLong(ctx: *mut c_void, result: *const FfiResult, balance: u64) {
+    unsafe {
+        let guard = jni_unwrap!(EnvGuard::new(JVM.as_ref()));
+        let env = guard.env();
+        let cb = jni_unwrap!(convert_cb_from_java(&env, ctx));
+        let result = if result.is_null() {
+            JObject::null()
+        } else {
+            jni_unwrap!((*result).to_java(&env))
+        };
+        let balance = jni_unwrap!(balance.to_java(&env));
+        jni_unwrap!(env.call_method(
+            cb.as_obj(),
+            "call",
+            "(Lnet/maidsafe/safe_app/FfiResult;J)V",
+            &[result.into(), balance.into()],
+        ));
+    }
+}
+
+#[link(name = "safe_app")]
+extern "C" {
+    fn app_get_balance(
+        app: *const App,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void,
+                            result: *const FfiResult,
+                            balance: u64),
+    );
+}
+#[no_mangle]
+pub unsafe extern "system" fn Java_net_maidsafe_safe_1app_NativeBindings_appGetBalance(
+    env: JNIEnv,
+    _class: JClass,
+    app: jlong,
+    o_cb: JObject,
+) {
+    let app = app as *mut App;
+    let ctx = gen_ctx!(env, o_cb);
+    app_get_balance(app, ctx, call_CallbackResultLong);
+}
+
+#[link(name = "safe_app")]
+extern "C" {
+    fn app_transfer_coins(
+        app: *const App,
+        destination: *const XorNameArray,
+        amount: u64,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void,
+                            result: *const FfiResult,
+                            transaction_id: u64),
+    );
+}
+#[no_mangle]
+pub unsafe extern "system" fn Java_net_maidsafe_safe_1app_NativeBindings_appTransferCoins(
+    env: JNIEnv,
+    _class: JClass,
+    app: jlong,
+    destination: JObject,
+    amount: jlong,
+    o_cb: JObject,
+) {
+    let app = app as *mut App;
+    let destination = jni_unwrap!(XorNameArray::from_java(&env, destination));
+    let ctx = gen_ctx!(env, o_cb);
+    app_transfer_coins(
+        app,
+        &destination,
+        amount as u64,
+        ctx,
+        call_CallbackResultLong,
+    );
+}
+
+#[link(name = "safe_app")]
+extern "C" {
+    fn app_allowance_balance(
+        app: *const App,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void,
+                            result: *const FfiResult,
+                            balance: u64),
+    );
+}
+#[no_mangle]
+pub unsafe extern "system" fn Java_net_maidsafe_safe_1app_NativeBindings_appAllowanceBalance(
+    env: JNIEnv,
+    _class: JClass,
+    app: jlong,
+    o_cb: JObject,
+) {
+    let app = app as *mut App;
+    let ctx = gen_ctx!(env, o_cb);
+    app_allowance_balance(app, ctx, call_CallbackResultLong);
+}
+
+#[link(name = "safe_app")]
+extern "C" {
+    fn app_allowance_transfer_coins(
+        app: *const App,
+        destination: *const XorNameArray,
+        amount: u64,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void,
+                            result: *const FfiResult,
+                            transaction_id: u64),
+    );
+}
+#[no_mangle]pub unsafe extern "system" fn Java_net_maidsafe_safe_1app_NativeBindings_appAllowanceTransferCoins ( env : JNIEnv , _class : JClass , app : jlong , destination : JObject , amount : jlong , o_cb : JObject ){
+    let app = app as *mut App;
+    let destination = jni_unwrap!(XorNameArray::from_java(&env, destination));
+    let ctx = gen_ctx!(env, o_cb);
+    app_allowance_transfer_coins(
+        app,
+        &destination,
+        amount as u64,
+        ctx,
+        call_CallbackResultLong,
+    );
+}
+
+#[link(name = "safe_app")]
+extern "C" {
+    fn app_create_balance(
+        app: *const App,
+        new_balance_owner_h: SignPubKeyHandle,
+        amount: u64,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void,
+                            result: *const FfiResult,
+                            transaction_id: u64),
+    );
+}
+#[no_mangle]
+pub unsafe extern "system" fn Java_net_maidsafe_safe_1app_NativeBindings_appCreateBalance(
+    env: JNIEnv,
+    _class: JClass,
+    app: jlong,
+    new_balance_owner_h: SignPubKeyHandle,
+    amount: jlong,
+    o_cb: JObject,
+) {
+    let app = app as *mut App;
+    let ctx = gen_ctx!(env, o_cb);
+    app_create_balance(
+        app,
+        new_balance_owner_h as SignPubKeyHandle,
+        amount as u64,
+        ctx,
+        call_CallbackResultLong,
+    );
+}
+extern "C" fn call_CallbackResultTransactionRecordArrayLen(
+    ctx: *mut c_void,
+    result: *const FfiResult,
+    transactions: *const TransactionRecord,
+    transactions_len: usize,
+) {
+    unsafe {
+        let guard = jni_unwrap!(EnvGuard::new(JVM.as_ref()));
+        let env = guard.env();
+        let cb = jni_unwrap!(convert_cb_from_java(&env, ctx));
+        let result = if result.is_null() {
+            JObject::null()
+        } else {
+            jni_unwrap!((*result).to_java(&env))
+        };
+        let transactions =
+            jni_unwrap!(slice::from_raw_parts(transactions, transactions_len).to_java(&env));
+        jni_unwrap!(env.call_method(
+            cb.as_obj(),
+            "call",
+            "(Lnet/maidsafe/safe_app/FfiResult;[Lnet/maidsafe/safe_app/TransactionRecord;)V",
+            &[result.into(), transactions.into()],
+        ));
+    }
+}
+
+#[link(name = "safe_app")]
+extern "C" {
+    fn app_transaction_history(
+        app: *const App,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void,
+                            result: *const FfiResult,
+                            transactions: *const TransactionRecord,
+                            transactions_len: usize),
+    );
+}
+#[cfg(feature = "mock-network")]
+#[no_mangle]
+pub unsafe extern "system" fn Java_net_maidsafe_safe_1app_NativeBindings_appTransactionHistory(
+    env: JNIEnv,
+    _class: JClass,
+    app: jlong,
+    o_cb: JObject,
+) {
+    let app = app as *mut App;
+    let ctx = gen_ctx!(env, o_cb);
+    app_transaction_history(app, ctx, call_CallbackResultTransactionRecordArrayLen);
+}
+extern "C" fn call_CallbackResultSignPubKeyHandle(
+    ctx: *mut c_void,
+    result: *const FfiResult,
+    handle: SignPubKeyHandle,
+) {
+    unsafe {
+        let guard = jni_unwrap!(EnvGuard::new(JVM.as_ref()));
+        let env = guard.env();
+        let cb = jni_unwrap!(convert_cb_from_java(&env, ctx));
+        let result = if result.is_null() {
+            JObject::null()
+        } else {
+            jni_unwrap!((*result).to_java(&env))
+        };
+        let handle = jni_unwrap!(handle.to_java(&env));
+        jni_unwrap!(env.call_method(
+            cb.as_obj(),
+            "call",
+            "(Lnet/maidsafe/safe_app/FfiResult;J)V",
+            &[result.into(), handle.into()],
+        ));
+    }
+}
+
+#[link(name = "safe_app")]
+extern "C" {
+    fn app_pub_sign_key(
+        app: *const App,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void,
+                            result: *const FfiResult,
+                            handle: SignPubKeyHandle),
+    );
+}
+#[no_mangle]
+pub unsafe extern "system" fn Java_net_maidsafe_safe_1app_NativeBindings_appPubSignKey(
+    env: JNIEnv,
+    _class: JClass,
+    app: jlong,
+    o_cb: JObject,
+) {
+    let app = app as *mut App;
+    let ctx = gen_ctx!(env, o_cb);
+    app_pub_sign_key(app, ctx, call_CallbackResultSignPubKeyHandle);
+}
+extern "C" fn call_CallbackResultSignPubKeyHandleSignSecKeyHandle(
+    ctx: *mut c_void,
+    result: *const FfiResult,
+    public_key_h: SignPubKeyHandle,
+    secret_key_h: SignSecKeyHandle,
+) {
+    unsafe {
+        let guard = jni_unwrap!(EnvGuard::new(JVM.as_ref()));
+        let env = guard.env();
+        let cb = jni_unwrap!(convert_cb_from_java(&env, ctx));
+        let result = if result.is_null() {
+            JObject::null()
+        } else {
+            jni_unwrap!((*result).to_java(&env))
+        };
+        let public_key_h = jni_unwrap!(public_key_h.to_java(&env));
+        let secret_key_h = jni_unwrap!(secret_key_h.to_java(&env));
+        jni_unwrap!(env.call_method(
+            cb.as_obj(),
+            "call",
+            "(Lnet/maidsafe/safe_app/FfiResult;JJ)V",
+            &[
+                result.into(),
+                public_key_h.into(),
+                secret_key_h.into(),
+            ],
+        ));
+    }
+}
+
+#[link(name = "safe_app")]
+extern "C" {
+    fn sign_generate_key_pair(
+        app: *const App,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void,
+                            result: *const FfiResult,
+                            public_key_h: SignPubKeyHandle,
+                            secret_key_h: SignSecKeyHandle),
+    );
+}
+#[no_mangle]
+pub unsafe extern "system" fn Java_net_maidsafe_safe_1app_NativeBindings_signGenerateKeyPair(
+    env: JNIEnv,
+    _class: JClass,
+    app: jlong,
+    o_cb: JObject,
+) {
+    let app = app as *mut App;
+    let ctx = gen_ctx!(env, o_cb);
+    sign_generate_key_pair(
+        app,
+        ctx,
+        call_CallbackResultSignPubKeyHandleSignSecKeyHandle,
+    );
+}
+
+#[link(name = "safe_app")]
+extern "C" {
+    fn sign_pub_key_new(
+        app: *const App,
+        data: *const u8,
+        data_len: usize,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void,
+                            result: *const FfiResult,
+                            handle: SignPubKeyHandle),
+    );
+}
+#[no_mangle]
+pub unsafe extern "system" fn Java_net_maidsafe_safe_1app_NativeBindings_signPubKeyNew(
+    env: JNIEnv,
+    _class: JClass,
+    app: jlong,
+    data: JObject,
+    o_cb: JObject,
+) {
+    let app = app as *mut App;
+    let data = jni_unwrap!(Vec::from_java(&env, data));
+    let ctx = gen_ctx!(env, o_cb);
+    sign_pub_key_new(
+        app,
+        data.as_ptr(),
+        data.len(),
+        ctx,
+        call_CallbackResultSignPubKeyHandle,
+    );
+}
+extern "C" fn call_CallbackResultByteArrayLen(
+    ctx: *mut c_void,
+    result: *const FfiResult,
+    pub_sign_key: *const u8,
+    pub_sign_key_len: usize,
+) {
+    unsafe {
+        let guard = jni_unwrap!(EnvGuard::new(JVM.as_ref()));
+        let env = guard.env();
+        let cb = jni_unwrap!(convert_cb_from_java(&env, ctx));
+        let result = if result.is_null() {
+            JObject::null()
+        } else {
+            jni_unwrap!((*result).to_java(&env))
+        };
+        let pub_sign_key =
+            jni_unwrap!(slice::from_raw_parts(pub_sign_key, pub_sign_key_len).to_java(&env));
+        jni_unwrap!(env.call_method(
+            cb.as_obj(),
+            "call",
+            "(Lnet/maidsafe/safe_app/FfiResult;[B)V",
+            &[result.into(), pub_sign_key.into()],
+        ));
+    }
+}
+
+#[link(name = "safe_app")]
+extern "C" {
+    fn sign_pub_key_get(
+        app: *const App,
+        handle: SignPubKeyHandle,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void,
+                            result: *const FfiResult,
+                            pub_sign_key: *const u8,
+                            pub_sign_key_len: usize),
+    );
+}
+#[no_mangle]
+pub unsafe extern "system" fn Java_net_maidsafe_safe_1app_NativeBindings_signPubKeyGet(
+    env: JNIEnv,
+    _class: JClass,
+    app: jlong,
+    handle: SignPubKeyHandle,
+    o_cb: JObject,
+) {
+    let app = app as *mut App;
+    let ctx = gen_ctx!(env, o_cb);
+    sign_pub_key_get(
+        app,
+        handle as SignPubKeyHandle,
+        ctx,
+        call_CallbackResultByteArrayLen,
+    );
+}
+
+#[link(name = "safe_app")]
+extern "C" {
+    fn sign_pub_key_free(
+        app: *const App,
+        handle: SignPubKeyHandle,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void, result: *const FfiResult),
+    );
+}
+#[no_mangle]
+pub unsafe extern "system" fn Java_net_maidsafe_safe_1app_NativeBindings_signPubKeyFree(
+    env: JNIEnv,
+    _class: JClass,
+    app: jlong,
+    handle: SignPubKeyHandle,
+    o_cb: JObject,
+) {
+    let app = app as *mut App;
+    let ctx = gen_ctx!(env, o_cb);
+    sign_pub_key_free(app, handle as SignPubKeyHandle, ctx, call_CallbackResult);
+}
+extern "C" fn call_CallbackResultSignSecKeyHandle(
+    ctx: *mut c_void,
+    result: *const FfiResult,
+    handle: SignSecKeyHandle,
+) {
+    unsafe {
+        let guard = jni_unwrap!(EnvGuard::new(JVM.as_ref()));
+        let env = guard.env();
+        let cb = jni_unwrap!(convert_cb_from_java(&env, ctx));
+        let result = if result.is_null() {
+            JObject::null()
+        } else {
+            jni_unwrap!((*result).to_java(&env))
+        };
+        let handle = jni_unwrap!(handle.to_java(&env));
+        jni_unwrap!(env.call_method(
+            cb.as_obj(),
+            "call",
+            "(Lnet/maidsafe/safe_app/FfiResult;J)V",
+            &[result.into(), handle.into()],
+        ));
+    }
+}
+
+#[link(name = "safe_app")]
+extern "C" {
+    fn sign_sec_key_new(
+        app: *const App,
+        data: *const u8,
+        data_len: usize,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void,
+                            result: *const FfiResult,
+                            handle: SignSecKeyHandle),
+    );
+}
+#[no_mangle]
+pub unsafe extern "system" fn Java_net_maidsafe_safe_1app_NativeBindings_signSecKeyNew(
+    env: JNIEnv,
+    _class: JClass,
+    app: jlong,
+    data: JObject,
+    o_cb: JObject,
+) {
+    let app = app as *mut App;
+    let data = jni_unwrap!(Vec::from_java(&env, data));
+    let ctx = gen_ctx!(env, o_cb);
+    sign_sec_key_new(
+        app,
+        data.as_ptr(),
+        data.len(),
+        ctx,
+        call_CallbackResultSignSecKeyHandle,
+    );
+}
+
+#[link(name = "safe_app")]
+extern "C" {
+    fn sign_sec_key_get(
+        app: *const App,
+        handle: SignSecKeyHandle,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void,
+                            result: *const FfiResult,
+                            sec_sign_key: *const u8,
+                            sec_sign_key_len: usize),
+    );
+}
+#[no_mangle]
+pub unsafe extern "system" fn Java_net_maidsafe_safe_1app_NativeBindings_signSecKeyGet(
+    env: JNIEnv,
+    _class: JClass,
+    app: jlong,
+    handle: SignSecKeyHandle,
+    o_cb: JObject,
+) {
+    let app = app as *mut App;
+    let ctx = gen_ctx!(env, o_cb);
+    sign_sec_key_get(
+        app,
+        handle as SignSecKeyHandle,
+        ctx,
+        call_CallbackResultByteArrayLen,
+    );
+}
+
+#[link(name = "safe_app")]
+extern "C" {
+    fn sign_sec_key_free(
+        app: *const App,
+        handle: SignSecKeyHandle,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void, result: *const FfiResult),
+    );
+}
+#[no_mangle]
+pub unsafe extern "system" fn Java_net_maidsafe_safe_1app_NativeBindings_signSecKeyFree(
+    env: JNIEnv,
+    _class: JClass,
+    app: jlong,
+    handle: SignSecKeyHandle,
+    o_cb: JObject,
+) {
+    let app = app as *mut App;
+    let ctx = gen_ctx!(env, o_cb);
+    sign_sec_key_free(app, handle as SignSecKeyHandle, ctx, call_CallbackResult);
+}
+extern "C" fn call_CallbackResultEncryptPubKeyHandle(
+    ctx: *mut c_void,
+    result: *const FfiResult,
+    public_key_h: EncryptPubKeyHandle,
+) {
+    unsafe {
+        let guard = jni_unwrap!(EnvGuard::new(JVM.as_ref()));
+        let env = guard.env();
+        let cb = jni_unwrap!(convert_cb_from_java(&env, ctx));
+        let result = if result.is_null() {
+            JObject::null()
+        } else {
+            jni_unwrap!((*result).to_java(&env))
+        };
+        let public_key_h = jni_unwrap!(public_key_h.to_java(&env));
+        jni_unwrap!(env.call_method(
+            cb.as_obj(),
+            "call",
+            "(Lnet/maidsafe/safe_app/FfiResult;J)V",
+            &[result.into(), public_key_h.into()],
+        ));
+    }
+}
+
+#[link(name = "safe_app")]
+extern "C" {
+    fn app_pub_enc_key(
+        app: *const App,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void,
+                            result: *const FfiResult,
+                            public_key_h: EncryptPubKeyHandle),
+    );
+}
+#[no_mangle]
+pub unsafe extern "system" fn Java_net_maidsafe_safe_1app_NativeBindings_appPubEncKey(
+    env: JNIEnv,
+    _class: JClass,
+    app: jlong,
+    o_cb: JObject,
+) {
+    let app = app as *mut App;
+    let ctx = gen_ctx!(env, o_cb);
+    app_pub_enc_key(app, ctx, call_CallbackResultEncryptPubKeyHandle);
+}
+extern "C" fn call_CallbackResultEncryptPubKeyHandleEncryptSecKeyHandle(
+    ctx: *mut c_void,
+    result: *const FfiResult,
+    public_key_h: EncryptPubKeyHandle,
+    secret_key_h: EncryptSecKeyHandle,
+) {
+    unsafe {
+        let guard = jni_unwrap!(EnvGuard::new(JVM.as_ref()));
+        let env = guard.env();
+        let cb = jni_unwrap!(convert_cb_from_java(&env, ctx));
+        let result = if result.is_null() {
+            JObject::null()
+        } else {
+            jni_unwrap!((*result).to_java(&env))
+        };
+        let public_key_h = jni_unwrap!(public_key_h.to_java(&env));
+        let secret_key_h = jni_unwrap!(secret_key_h.to_java(&env));
+        jni_unwrap!(env.call_method(
+            cb.as_obj(),
+            "call",
+            "(Lnet/maidsafe/safe_app/FfiResult;JJ)V",
+            &[
+                result.into(),
+                public_key_h.into(),
+                secret_key_h.into(),
+            ],
+        ));
+    }
+}
+
+#[link(name = "safe_app")]
+extern "C" {
+    fn enc_generate_key_pair(
+        app: *const App,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void,
+                            result: *const FfiResult,
+                            public_key_h: EncryptPubKeyHandle,
+                            secret_key_h: EncryptSecKeyHandle),
+    );
+}
+#[no_mangle]
+pub unsafe extern "system" fn Java_net_maidsafe_safe_1app_NativeBindings_encGenerateKeyPair(
+    env: JNIEnv,
+    _class: JClass,
+    app: jlong,
+    o_cb: JObject,
+) {
+    let app = app as *mut App;
+    let ctx = gen_ctx!(env, o_cb);
+    enc_generate_key_pair(
+        app,
+        ctx,
+        call_CallbackResultEncryptPubKeyHandleEncryptSecKeyHandle,
+    );
+}
+
+#[link(name = "safe_app")]
+extern "C" {
+    fn enc_pub_key_new(
+        app: *const App,
+        data: *const AsymPublicKey,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void,
+                            result: *const FfiResult,
+                            public_key_h: EncryptPubKeyHandle),
+    );
+}
+#[no_mangle]
+pub unsafe extern "system" fn Java_net_maidsafe_safe_1app_NativeBindings_encPubKeyNew(
+    env: JNIEnv,
+    _class: JClass,
+    app: jlong,
+    data: JObject,
+    o_cb: JObject,
+) {
+    let app = app as *mut App;
+    let data = jni_unwrap!(AsymPublicKey::from_java(&env, data));
+    let ctx = gen_ctx!(env, o_cb);
+    enc_pub_key_new(app, &data, ctx, call_CallbackResultEncryptPubKeyHandle);
+}
+extern "C" fn call_CallbackResultAsymPublicKey(
+    ctx: *mut c_void,
+    result: *const FfiResult,
+    pub_enc_key: *const AsymPublicKey,
+) {
+    unsafe {
+        let guard = jni_unwrap!(EnvGuard::new(JVM.as_ref()));
+        let env = guard.env();
+        let cb = jni_unwrap!(convert_cb_from_java(&env, ctx));
+        let result = if result.is_null() {
+            JObject::null()
+        } else {
+            jni_unwrap!((*result).to_java(&env))
+        };
+        let pub_enc_key = if pub_enc_key.is_null() {
+            JObject::null()
+        } else {
+            jni_unwrap!((*pub_enc_key).to_java(&env))
+        };
+        jni_unwrap!(env.call_method(
+            cb.as_obj(),
+            "call",
+            "(Lnet/maidsafe/safe_app/FfiResult;[B)V",
+            &[result.into(), pub_enc_key.into()],
+        ));
+    }
+}
+
+#[link(name = "safe_app")]
+extern "C" {
+    fn enc_pub_key_get(
+        app: *const App,
+        handle: EncryptPubKeyHandle,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void,
+                            result: *const FfiResult,
+                            pub_enc_key: *const AsymPublicKey),
+    );
+}
+#[no_mangle]
+pub unsafe extern "system" fn Java_net_maidsafe_safe_1app_NativeBindings_encPubKeyGet(
+    env: JNIEnv,
+    _class: JClass,
+    app: jlong,
+    handle: EncryptPubKeyHandle,
+    o_cb: JObject,
+) {
+    let app = app as *mut App;
+    let ctx = gen_ctx!(env, o_cb);
+    enc_pub_key_get(
+        app,
+        handle as EncryptPubKeyHandle,
+        ctx,
+        call_CallbackResultAsymPublicKey,
+    );
+}
+
+#[link(name = "safe_app")]
+extern "C" {
+    fn enc_pub_key_free(
+        app: *const App,
+        handle: EncryptPubKeyHandle,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void, result: *const FfiResult),
+    );
+}
+#[no_mangle]
+pub unsafe extern "system" fn Java_net_maidsafe_safe_1app_NativeBindings_encPubKeyFree(
+    env: JNIEnv,
+    _class: JClass,
+    app: jlong,
+    handle: EncryptPubKeyHandle,
+    o_cb: JObject,
+) {
+    let app = app as *mut App;
+    let ctx = gen_ctx!(env, o_cb);
+    enc_pub_key_free(app, handle as EncryptPubKeyHandle, ctx, call_CallbackResult);
+}
+extern "C" fn call_CallbackResultEncryptSecKeyHandle(
+    ctx: *mut c_void,
+    result: *const FfiResult,
+    sk_h: EncryptSecKeyHandle,
+) {
+    unsafe {
+        let guard = jni_unwrap!(EnvGuard::new(JVM.as_ref()));
+        let env = guard.env();
+        let cb = jni_unwrap!(convert_cb_from_java(&env, ctx));
+        let result = if result.is_null() {
+            JObject::null()
+        } else {
+            jni_unwrap!((*result).to_java(&env))
+        };
+        let sk_h = jni_unwrap!(sk_h.to_java(&env));
+        jni_unwrap!(env.call_method(
+            cb.as_obj(),
+            "call",
+            "(Lnet/maidsafe/safe_app/FfiResult;J)V",
+            &[result.into(), sk_h.into()],
+        ));
+    }
+}
+
+#[link(name = "safe_app")]
+extern "C" {
+    fn enc_secret_key_new(
+        app: *const App,
+        sk: *const u8,
+        sk_len: usize,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void,
+                            result: *const FfiResult,
+                            sk_h: EncryptSecKeyHandle),
+    );
+}
+#[no_mangle]
+pub unsafe extern "system" fn Java_net_maidsafe_safe_1app_NativeBindings_encSecretKeyNew(
+    env: JNIEnv,
+    _class: JClass,
+    app: jlong,
+    sk: JObject,
+    o_cb: JObject,
+) {
+    let app = app as *mut App;
+    let sk = jni_unwrap!(Vec::from_java(&env, sk));
+    let ctx = gen_ctx!(env, o_cb);
+    enc_secret_key_new(
+        app,
+        sk.as_ptr(),
+        sk.len(),
+        ctx,
+        call_CallbackResultEncryptSecKeyHandle,
+    );
+}
+
+#[link(name = "safe_app")]
+extern "C" {
+    fn enc_secret_key_get(
+        app: *const App,
+        handle: EncryptSecKeyHandle,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void,
+                            result: *const FfiResult,
+                            sec_enc_key: *const u8,
+                            sec_enc_key_len: usize),
+    );
+}
+#[no_mangle]
+pub unsafe extern "system" fn Java_net_maidsafe_safe_1app_NativeBindings_encSecretKeyGet(
+    env: JNIEnv,
+    _class: JClass,
+    app: jlong,
+    handle: EncryptSecKeyHandle,
+    o_cb: JObject,
+) {
+    let app = app as *mut App;
+    let ctx = gen_ctx!(env, o_cb);
+    enc_secret_key_get(
+        app,
+        handle as EncryptSecKeyHandle,
+        ctx,
+        call_CallbackResultByteArrayLen,
+    );
+}
+
+#[link(name = "safe_app")]
+extern "C" {
+    fn enc_secret_key_free(
+        app: *const App,
+        handle: EncryptSecKeyHandle,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void, result: *const FfiResult),
+    );
+}
+#[no_mangle]
+pub unsafe extern "system" fn Java_net_maidsafe_safe_1app_NativeBindings_encSecretKeyFree(
+    env: JNIEnv,
+    _class: JClass,
+    app: jlong,
+    handle: EncryptSecKeyHandle,
+    o_cb: JObject,
+) {
+    let app = app as *mut App;
+    let ctx = gen_ctx!(env, o_cb);
+    enc_secret_key_free(app, handle as EncryptSecKeyHandle, ctx, call_CallbackResult);
+}
+
+#[link(name = "safe_app")]
+extern "C" {
+    fn sign(
+        app: *const App,
+        data: *const u8,
+        data_len: usize,
+        sign_sk_h: SignSecKeyHandle,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void,
+                            result: *const FfiResult,
+                            signature: *const u8,
+                            signature_len: usize),
+    );
+}
+#[no_mangle]
+pub unsafe extern "system" fn Java_net_maidsafe_safe_1app_NativeBindings_sign(
+    env: JNIEnv,
+    _class: JClass,
+    app: jlong,
+    data: JObject,
+    sign_sk_h: SignSecKeyHandle,
+    o_cb: JObject,
+) {
+    let app = app as *mut App;
+    let data = jni_unwrap!(Vec::from_java(&env, data));
+    let ctx = gen_ctx!(env, o_cb);
+    sign(
+        app,
+        data.as_ptr(),
+        data.len(),
+        sign_sk_h as SignSecKeyHandle,
+        ctx,
+        call_CallbackResultByteArrayLen,
+    );
+}
+extern "C" fn call_CallbackResultInt(ctx: *mut c_void, result: *const FfiResult, valid: u32) {
+    unsafe {
+        let guard = jni_unwrap!(EnvGuard::new(JVM.as_ref()));
+        let env = guard.env();
+        let cb = jni_unwrap!(convert_cb_from_java(&env, ctx));
+        let result = if result.is_null() {
+            JObject::null()
+        } else {
+            jni_unwrap!((*result).to_java(&env))
+        };
+        let valid = jni_unwrap!(valid.to_java(&env));
+        jni_unwrap!(env.call_method(
+            cb.as_obj(),
+            "call",
+            "(Lnet/maidsafe/safe_app/FfiResult;I)V",
+            &[result.into(), valid.into()],
+        ));
+    }
+}
+
+#[link(name = "safe_app")]
+extern "C" {
+    fn verify(
+        app: *const App,
+        signed_data: *const u8,
+        signed_data_len: usize,
+        signature: *const u8,
+        signature_len: usize,
+        sign_pk_h: SignPubKeyHandle,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void, result: *const FfiResult, valid: u32),
+    );
+}
+#[no_mangle]
+pub unsafe extern "system" fn Java_net_maidsafe_safe_1app_NativeBindings_verify(
+    env: JNIEnv,
+    _class: JClass,
+    app: jlong,
+    signed_data: JObject,
+    signature: JObject,
+    sign_pk_h: SignPubKeyHandle,
+    o_cb: JObject,
+) {
+    let app = app as *mut App;
+    let signed_data = jni_unwrap!(Vec::from_java(&env, signed_data));
+    let signature = jni_unwrap!(Vec::from_java(&env, signature));
+    let ctx = gen_ctx!(env, o_cb);
+    verify(
+        app,
+        signed_data.as_ptr(),
+        signed_data.len(),
+        signature.as_ptr(),
+        signature.len(),
+        sign_pk_h as SignPubKeyHandle,
+        ctx,
+        call_CallbackResultInt,
+    );
+}
+
+#[link(name = "safe_app")]
+extern "C" {
+    fn encrypt_sealed_box(
+        app: *const App,
+        data: *const u8,
+        data_len: usize,
+        public_key_h: EncryptPubKeyHandle,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void,
+                            result: *const FfiResult,
+                            ciphertext: *const u8,
+                            ciphertext_len: usize),
+    );
+}
+#[no_mangle]
+pub unsafe extern "system" fn Java_net_maidsafe_safe_1app_NativeBindings_encryptSealedBox(
+    env: JNIEnv,
+    _class: JClass,
+    app: jlong,
+    data: JObject,
+    public_key_h: EncryptPubKeyHandle,
+    o_cb: JObject,
+) {
+    let app = app as *mut App;
+    let data = jni_unwrap!(Vec::from_java(&env, data));
+    let ctx = gen_ctx!(env, o_cb);
+    encrypt_sealed_box(
+        app,
+        data.as_ptr(),
+        data.len(),
+        public_key_h as EncryptPubKeyHandle,
+        ctx,
+        call_CallbackResultByteArrayLen,
+    );
+}
+
+#[link(name = "safe_app")]
+extern "C" {
+    fn decrypt_sealed_box(
+        app: *const App,
+        data: *const u8,
+        data_len: usize,
+        secret_key_h: EncryptSecKeyHandle,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void,
+                            result: *const FfiResult,
+                            plaintext: *const u8,
+                            plaintext_len: usize),
+    );
+}
+#[no_mangle]
+pub unsafe extern "system" fn Java_net_maidsafe_safe_1app_NativeBindings_decryptSealedBox(
+    env: JNIEnv,
+    _class: JClass,
+    app: jlong,
+    data: JObject,
+    secret_key_h: EncryptSecKeyHandle,
+    o_cb: JObject,
+) {
+    let app = app as *mut App;
+    let data = jni_unwrap!(Vec::from_java(&env, data));
+    let ctx = gen_ctx!(env, o_cb);
+    decrypt_sealed_box(
+        app,
+        data.as_ptr(),
+        data.len(),
+        secret_key_h as EncryptSecKeyHandle,
+        ctx,
+        call_CallbackResultByteArrayLen,
+    );
+}
+
+#[link(name = "safe_app")]
+extern "C" {
+    fn encrypt_authenticated_sealed_box(
+        app: *const App,
+        data: *const u8,
+        data_len: usize,
+        public_key_h: EncryptPubKeyHandle,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void,
+                            result: *const FfiResult,
+                            ciphertext: *const u8,
+                            ciphertext_len: usize),
+    );
+}
+#[no_mangle]pub unsafe extern "system" fn Java_net_maidsafe_safe_1app_NativeBindings_encryptAuthenticatedSealedBox ( env : JNIEnv , _class : JClass , app : jlong , data : JObject , public_key_h : EncryptPubKeyHandle , o_cb : JObject ){
+    let app = app as *mut App;
+    let data = jni_unwrap!(Vec::from_java(&env, data));
+    let ctx = gen_ctx!(env, o_cb);
+    encrypt_authenticated_sealed_box(
+        app,
+        data.as_ptr(),
+        data.len(),
+        public_key_h as EncryptPubKeyHandle,
+        ctx,
+        call_CallbackResultByteArrayLen,
+    );
+}
+extern "C" fn call_CallbackResultByteArrayLenSignPubKeyHandle(
+    ctx: *mut c_void,
+    result: *const FfiResult,
+    plaintext: *const u8,
+    plaintext_len: usize,
+    sender_h: SignPubKeyHandle,
+) {
+    unsafe {
+        let guard = jni_unwrap!(EnvGuard::new(JVM.as_ref()));
+        let env = guard.env();
+        let cb = jni_unwrap!(convert_cb_from_java(&env, ctx));
+        let result = if result.is_null() {
+            JObject::null()
+        } else {
+            jni_unwrap!((*result).to_java(&env))
+        };
+        let plaintext = jni_unwrap!(slice::from_raw_parts(plaintext, plaintext_len).to_java(
+            &env,
+        ));
+        let sender_h = jni_unwrap!(sender_h.to_java(&env));
+        jni_unwrap!(env.call_method(
+            cb.as_obj(),
+            "call",
+            "(Lnet/maidsafe/safe_app/FfiResult;[BJ)V",
+            &[result.into(), plaintext.into(), sender_h.into()],
+        ));
+    }
+}
+
+#[link(name = "safe_app")]
+extern "C" {
+    fn decrypt_authenticated_sealed_box(
+        app: *const App,
+        data: *const u8,
+        data_len: usize,
+        secret_key_h: EncryptSecKeyHandle,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void,
+                            result: *const FfiResult,
+                            plaintext: *const u8,
+                            plaintext_len: usize,
+                            sender_h: SignPubKeyHandle),
+    );
+}
+#[no_mangle]pub unsafe extern "system" fn Java_net_maidsafe_safe_1app_NativeBindings_decryptAuthenticatedSealedBox ( env : JNIEnv , _class : JClass , app : jlong , data : JObject , secret_key_h : EncryptSecKeyHandle , o_cb : JObject ){
+    let app = app as *mut App;
+    let data = jni_unwrap!(Vec::from_java(&env, data));
+    let ctx = gen_ctx!(env, o_cb);
+    decrypt_authenticated_sealed_box(
+        app,
+        data.as_ptr(),
+        data.len(),
+        secret_key_h as EncryptSecKeyHandle,
+        ctx,
+        call_CallbackResultByteArrayLenSignPubKeyHandle,
+    );
+}
+
+#[link(name = "safe_app")]
+extern "C" {
+    fn sha3_hash(
+        data: *const u8,
+        data_len: usize,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void,
+                            result: *const FfiResult,
+                            hash: *const u8,
+                            hash_len: usize),
+    );
+}
+#[no_mangle]
+pub unsafe extern "system" fn Java_net_maidsafe_safe_1app_NativeBindings_sha3Hash(
+    env: JNIEnv,
+    _class: JClass,
+    data: JObject,
+    o_cb: JObject,
+) {
+    let data = jni_unwrap!(Vec::from_java(&env, data));
+    let ctx = gen_ctx!(env, o_cb);
+    sha3_hash(
+        data.as_ptr(),
+        data.len(),
+        ctx,
+        call_CallbackResultByteArrayLen,
+    );
+}
+extern "C" fn call_CallbackResultSEWriterHandle(
+    ctx: *mut c_void,
+    result: *const FfiResult,
+    se_h: SEWriterHandle,
+) {
+    unsafe {
+        let guard = jni_unwrap!(EnvGuard::new(JVM.as_ref()));
+        let env = guard.env();
+        let cb = jni_unwrap!(convert_cb_from_java(&env, ctx));
+        let result = if result.is_null() {
+            JObject::null()
+        } else {
+            jni_unwrap!((*result).to_java(&env))
+        };
+        let se_h = jni_unwrap!(se_h.to_java(&env));
+        jni_unwrap!(env.call_method(
+            cb.as_obj(),
+            "call",
+            "(Lnet/maidsafe/safe_app/FfiResult;J)V",
+            &[result.into(), se_h.into()],
+        ));
+    }
+}
+
+#[link(name = "safe_app")]
+extern "C" {
+    fn idata_new_self_encryptor(
+        app: *const App,
+        published: bool,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void,
+                            result: *const FfiResult,
+                            se_h: SEWriterHandle),
+    );
+}
+#[no_mangle]
+pub unsafe extern "system" fn Java_net_maidsafe_safe_1app_NativeBindings_idataNewSelfEncryptor(
+    env: JNIEnv,
+    _class: JClass,
+    app: jlong,
+    published: bool,
+    o_cb: JObject,
+) {
+    let app = app as *mut App;
+    let ctx = gen_ctx!(env, o_cb);
+    idata_new_self_encryptor(
+        app,
+        published as bool,
+        ctx,
+        call_CallbackResultSEWriterHandle,
+    );
+}
+
+#[link(name = "safe_app")]
+extern "C" {
+    fn idata_write_to_self_encryptor(
+        app: *const App,
+        se_h: SEWriterHandle,
+        data: *const u8,
+        data_len: usize,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void, result: *const FfiResult),
+    );
+}
+#[no_mangle]pub unsafe extern "system" fn Java_net_maidsafe_safe_1app_NativeBindings_idataWriteToSelfEncryptor ( env : JNIEnv , _class : JClass , app : jlong , se_h : SEWriterHandle , data : JObject , o_cb : JObject ){
+    let app = app as *mut App;
+    let data = jni_unwrap!(Vec::from_java(&env, data));
+    let ctx = gen_ctx!(env, o_cb);
+    idata_write_to_self_encryptor(
+        app,
+        se_h as SEWriterHandle,
+        data.as_ptr(),
+        data.len(),
+        ctx,
+        call_CallbackResult,
+    );
+}
+extern "C" fn call_CallbackResultXorNameArray(
+    ctx: *mut c_void,
+    result: *const FfiResult,
+    name: *const XorNameArray,
+) {
+    unsafe {
+        let guard = jni_unwrap!(EnvGuard::new(JVM.as_ref()));
+        let env = guard.env();
+        let cb = jni_unwrap!(convert_cb_from_java(&env, ctx));
+        let result = if result.is_null() {
+            JObject::null()
+        } else {
+            jni_unwrap!((*result).to_java(&env))
+        };
+        let name = if name.is_null() {
+            JObject::null()
+        } else {
+            jni_unwrap!((*name).to_java(&env))
+        };
+        jni_unwrap!(env.call_method(
+            cb.as_obj(),
+            "call",
+            "(Lnet/maidsafe/safe_app/FfiResult;[B)V",
+            &[result.into(), name.into()],
+        ));
+    }
+}
+
+#[link(name = "safe_app")]
+extern "C" {
+    fn idata_close_self_encryptor(
+        app: *const App,
+        se_h: SEWriterHandle,
+        cipher_opt_h: CipherOptHandle,
+        published: bool,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void,
+                            result: *const FfiResult,
+                            name: *const XorNameArray),
+    );
+}
+#[no_mangle]
+pub unsafe extern "system" fn Java_net_maidsafe_safe_1app_NativeBindings_idataCloseSelfEncryptor(
+    env: JNIEnv,
+    _class: JClass,
+    app: jlong,
+    se_h: SEWriterHandle,
+    cipher_opt_h: CipherOptHandle,
+    published: bool,
+    o_cb: JObject,
+) {
+    let app = app as *mut App;
+    let ctx = gen_ctx!(env, o_cb);
+    idata_close_self_encryptor(
+        app,
+        se_h as SEWriterHandle,
+        cipher_opt_h as CipherOptHandle,
+        published as bool,
+        ctx,
+        call_CallbackResultXorNameArray,
+    );
+}
+extern "C" fn call_CallbackResultSEReaderHandle(
+    ctx: *mut c_void,
+    result: *const FfiResult,
+    se_h: SEReaderHandle,
+) {
+    unsafe {
+        let guard = jni_unwrap!(EnvGuard::new(JVM.as_ref()));
+        let env = guard.env();
+        let cb = jni_unwrap!(convert_cb_from_java(&env, ctx));
+        let result = if result.is_null() {
+            JObject::null()
+        } else {
+            jni_unwrap!((*result).to_java(&env))
+        };
+        let se_h = jni_unwrap!(se_h.to_java(&env));
+        jni_unwrap!(env.call_method(
+            cb.as_obj(),
+            "call",
+            "(Lnet/maidsafe/safe_app/FfiResult;J)V",
+            &[result.into(), se_h.into()],
+        ));
+    }
+}
+
+#[link(name = "safe_app")]
+extern "C" {
+    fn idata_fetch_self_encryptor(
+        app: *const App,
+        name: *const XorNameArray,
+        published: bool,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void,
+                            result: *const FfiResult,
+                            se_h: SEReaderHandle),
+    );
+}
+#[no_mangle]
+pub unsafe extern "system" fn Java_net_maidsafe_safe_1app_NativeBindings_idataFetchSelfEncryptor(
+    env: JNIEnv,
+    _class: JClass,
+    app: jlong,
+    name: JObject,
+    published: bool,
+    o_cb: JObject,
+) {
+    let app = app as *mut App;
+    let name = jni_unwrap!(XorNameArray::from_java(&env, name));
+    let ctx = gen_ctx!(env, o_cb);
+    idata_fetch_self_encryptor(
+        app,
+        &name,
+        published as bool,
+        ctx,
+        call_CallbackResultSEReaderHandle,
+    );
+}
+
+#[link(name = "safe_app")]
+extern "C" {
+    fn idata_serialised_size(
+        app: *const App,
+        name: *const XorNameArray,
+        published: bool,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void,
+                            result: *const FfiResult,
+                            serialised_size: u64),
+    );
+}
+#[no_mangle]
+pub unsafe extern "system" fn Java_net_maidsafe_safe_1app_NativeBindings_idataSerialisedSize(
+    env: JNIEnv,
+    _class: JClass,
+    app: jlong,
+    name: JObject,
+    published: bool,
+    o_cb: JObject,
+) {
+    let app = app as *mut App;
+    let name = jni_unwrap!(XorNameArray::from_java(&env, name));
+    let ctx = gen_ctx!(env, o_cb);
+    idata_serialised_size(app, &name, published as bool, ctx, call_CallbackResultLong);
+}
+
+#[link(name = "safe_app")]
+extern "C" {
+    fn idata_size(
+        app: *const App,
+        se_h: SEReaderHandle,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void, result: *const FfiResult, size: u64),
+    );
+}
+#[no_mangle]
+pub unsafe extern "system" fn Java_net_maidsafe_safe_1app_NativeBindings_idataSize(
+    env: JNIEnv,
+    _class: JClass,
+    app: jlong,
+    se_h: SEReaderHandle,
+    o_cb: JObject,
+) {
+    let app = app as *mut App;
+    let ctx = gen_ctx!(env, o_cb);
+    idata_size(app, se_h as SEReaderHandle, ctx, call_CallbackResultLong);
+}
+
+#[link(name = "safe_app")]
+extern "C" {
+    fn idata_read_from_self_encryptor(
+        app: *const App,
+        se_h: SEReaderHandle,
+        from_pos: u64,
+        len: u64,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void,
+                            result: *const FfiResult,
+                            data: *const u8,
+                            data_len: usize),
+    );
+}
+#[no_mangle]pub unsafe extern "system" fn Java_net_maidsafe_safe_1app_NativeBindings_idataReadFromSelfEncryptor ( env : JNIEnv , _class : JClass , app : jlong , se_h : SEReaderHandle , from_pos : jlong , len : jlong , o_cb : JObject ){
+    let app = app as *mut App;
+    let ctx = gen_ctx!(env, o_cb);
+    idata_read_from_self_encryptor(
+        app,
+        se_h as SEReaderHandle,
+        from_pos as u64,
+        len as u64,
+        ctx,
+        call_CallbackResultByteArrayLen,
+    );
+}
+
+#[link(name = "safe_app")]
+extern "C" {
+    fn idata_self_encryptor_writer_free(
+        app: *const App,
+        handle: SEWriterHandle,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void, result: *const FfiResult),
+    );
+}
+#[no_mangle]pub unsafe extern "system" fn Java_net_maidsafe_safe_1app_NativeBindings_idataSelfEncryptorWriterFree ( env : JNIEnv , _class : JClass , app : jlong , handle : SEWriterHandle , o_cb : JObject ){
+    let app = app as *mut App;
+    let ctx = gen_ctx!(env, o_cb);
+    idata_self_encryptor_writer_free(app, handle as SEWriterHandle, ctx, call_CallbackResult);
+}
+
+#[link(name = "safe_app")]
+extern "C" {
+    fn idata_self_encryptor_reader_free(
+        app: *const App,
+        handle: SEReaderHandle,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void, result: *const FfiResult),
+    );
+}
+#[no_mangle]pub unsafe extern "system" fn Java_net_maidsafe_safe_1app_NativeBindings_idataSelfEncryptorReaderFree ( env : JNIEnv , _class : JClass , app : jlong , handle : SEReaderHandle , o_cb : JObject ){
+    let app = app as *mut App;
+    let ctx = gen_ctx!(env, o_cb);
+    idata_self_encryptor_reader_free(app, handle as SEReaderHandle, ctx, call_CallbackResult);
+}
+extern "C" fn call_CallbackResultIntString(
+    ctx: *mut c_void,
+    result: *const FfiResult,
+    req_id: u32,
+    encoded: *const c_char,
+) {
+    unsafe {
+        let guard = jni_unwrap!(EnvGuard::new(JVM.as_ref()));
+        let env = guard.env();
+        let cb = jni_unwrap!(convert_cb_from_java(&env, ctx));
+        let result = if result.is_null() {
+            JObject::null()
+        } else {
+            jni_unwrap!((*result).to_java(&env))
+        };
+        let req_id = jni_unwrap!(req_id.to_java(&env));
+        let encoded: JObject = if encoded.is_null() {
+            JObject::null()
+        } else {
+            jni_unwrap!(encoded.to_java(&env)).into()
+        };
+        jni_unwrap!(env.call_method(
+            cb.as_obj(),
+            "call",
+            "(Lnet/maidsafe/safe_app/FfiResult;ILjava/lang/String;)V",
+            &[result.into(), req_id.into(), encoded.into()],
+        ));
+    }
+}
+
+#[link(name = "safe_app")]
+extern "C" {
+    fn encode_auth_req(
+        req: *const AuthReq,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void,
+                            result: *const FfiResult,
+                            req_id: u32,
+                            encoded: *const c_char),
+    );
+}
+#[no_mangle]
+pub unsafe extern "system" fn Java_net_maidsafe_safe_1app_NativeBindings_encodeAuthReq(
+    env: JNIEnv,
+    _class: JClass,
+    req: JObject,
+    o_cb: JObject,
+) {
+    let req = jni_unwrap!(AuthReq::from_java(&env, req));
+    let ctx = gen_ctx!(env, o_cb);
+    encode_auth_req(&req, ctx, call_CallbackResultIntString);
+}
+
+#[link(name = "safe_app")]
+extern "C" {
+    fn encode_containers_req(
+        req: *const ContainersReq,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void,
+                            result: *const FfiResult,
+                            req_id: u32,
+                            encoded: *const c_char),
+    );
+}
+#[no_mangle]
+pub unsafe extern "system" fn Java_net_maidsafe_safe_1app_NativeBindings_encodeContainersReq(
+    env: JNIEnv,
+    _class: JClass,
+    req: JObject,
+    o_cb: JObject,
+) {
+    let req = jni_unwrap!(ContainersReq::from_java(&env, req));
+    let ctx = gen_ctx!(env, o_cb);
+    encode_containers_req(&req, ctx, call_CallbackResultIntString);
+}
+
+#[link(name = "safe_app")]
+extern "C" {
+    fn encode_permissions_req(
+        req: *const PermissionsReq,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void,
+                            result: *const FfiResult,
+                            req_id: u32,
+                            encoded: *const c_char),
+    );
+}
+#[no_mangle]
+pub unsafe extern "system" fn Java_net_maidsafe_safe_1app_NativeBindings_encodePermissionsReq(
+    env: JNIEnv,
+    _class: JClass,
+    req: JObject,
+    o_cb: JObject,
+) {
+    let req = jni_unwrap!(PermissionsReq::from_java(&env, req));
+    let ctx = gen_ctx!(env, o_cb);
+    encode_permissions_req(&req, ctx, call_CallbackResultIntString);
+}
+
+#[link(name = "safe_app")]
+extern "C" {
+    fn encode_unregistered_req(
+        extra_data: *const u8,
+        extra_data_len: usize,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void,
+                            result: *const FfiResult,
+                            req_id: u32,
+                            encoded: *const c_char),
+    );
+}
+#[no_mangle]
+pub unsafe extern "system" fn Java_net_maidsafe_safe_1app_NativeBindings_encodeUnregisteredReq(
+    env: JNIEnv,
+    _class: JClass,
+    extra_data: JObject,
+    o_cb: JObject,
+) {
+    let extra_data = jni_unwrap!(Vec::from_java(&env, extra_data));
+    let ctx = gen_ctx!(env, o_cb);
+    encode_unregistered_req(
+        extra_data.as_ptr(),
+        extra_data.len(),
+        ctx,
+        call_CallbackResultIntString,
+    );
+}
+
+#[link(name = "safe_app")]
+extern "C" {
+    fn encode_share_mdata_req(
+        req: *const ShareMDataRequest,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void,
+                            result: *const FfiResult,
+                            req_id: u32,
+                            encoded: *const c_char),
+    );
+}
+#[no_mangle]
+pub unsafe extern "system" fn Java_net_maidsafe_safe_1app_NativeBindings_encodeShareMdataReq(
+    env: JNIEnv,
+    _class: JClass,
+    req: JObject,
+    o_cb: JObject,
+) {
+    let req = jni_unwrap!(ShareMDataRequest::from_java(&env, req));
+    let ctx = gen_ctx!(env, o_cb);
+    encode_share_mdata_req(&req, ctx, call_CallbackResultIntString);
+}
+
+#[link(name = "safe_app")]
+extern "C" {
+    fn encode_share_adata_req(
+        req: *const ShareADataRequest,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void,
+                            result: *const FfiResult,
+                            req_id: u32,
+                            encoded: *const c_char),
+    );
+}
+#[no_mangle]
+pub unsafe extern "system" fn Java_net_maidsafe_safe_1app_NativeBindings_encodeShareAdataReq(
+    env: JNIEnv,
+    _class: JClass,
+    req: JObject,
+    o_cb: JObject,
+) {
+    let req = jni_unwrap!(ShareADataRequest::from_java(&env, req));
+    let ctx = gen_ctx!(env, o_cb);
+    encode_share_adata_req(&req, ctx, call_CallbackResultIntString);
+}
+
+#[link(name = "safe_app")]
+extern "C" {
+    fn encode_trans_ownership_req(
+        req: *const TransOwnershipRequest,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void,
+                            result: *const FfiResult,
+                            req_id: u32,
+                            encoded: *const c_char),
+    );
+}
+#[no_mangle]
+pub unsafe extern "system" fn Java_net_maidsafe_safe_1app_NativeBindings_encodeTransOwnershipReq(
+    env: JNIEnv,
+    _class: JClass,
+    req: JObject,
+    o_cb: JObject,
+) {
+    let req = jni_unwrap!(TransOwnershipRequest::from_java(&env, req));
+    let ctx = gen_ctx!(env, o_cb);
+    encode_trans_ownership_req(&req, ctx, call_CallbackResultIntString);
+}
+extern "C" fn call_CallbackIntAuthGranted(
+    ctx: *mut c_void,
+    req_id: u32,
+    auth_granted: *const AuthGranted,
+) {
+    unsafe {
+        let guard = jni_unwrap!(EnvGuard::new(JVM.as_ref()));
+        let env = guard.env();
+        let cb = jni_unwrap!(convert_cb_from_java(&env, ctx));
+        let req_id = jni_unwrap!(req_id.to_java(&env));
+        let auth_granted = if auth_granted.is_null() {
+            JObject::null()
+        } else {
+            jni_unwrap!((*auth_granted).to_java(&env))
+        };
+        jni_unwrap!(env.call_method(
+            cb.as_obj(),
+            "call",
+            "(ILnet/maidsafe/safe_app/AuthGranted;)V",
+            &[req_id.into(), auth_granted.into()],
+        ));
+    }
+}
+extern "C" fn call_CallbackIntByteArrayLen(
+    ctx: *mut c_void,
+    req_id: u32,
+    serialised_cfg: *const u8,
+    serialised_cfg_len: usize,
+) {
+    unsafe {
+        let guard = jni_unwrap!(EnvGuard::new(JVM.as_ref()));
+        let env = guard.env();
+        let cb = jni_unwrap!(convert_cb_from_java(&env, ctx));
+        let req_id = jni_unwrap!(req_id.to_java(&env));
+        let serialised_cfg =
+            jni_unwrap!(slice::from_raw_parts(serialised_cfg, serialised_cfg_len).to_java(&env));
+        jni_unwrap!(env.call_method(
+            cb.as_obj(),
+            "call",
+            "(I[B)V",
+            &[req_id.into(), serialised_cfg.into()],
+        ));
+    }
+}
+extern "C" fn call_CallbackInt(ctx: *mut c_void, req_id: u32) {
+    unsafe {
+        let guard = jni_unwrap!(EnvGuard::new(JVM.as_ref()));
+        let env = guard.env();
+        let cb = jni_unwrap!(convert_cb_from_java(&env, ctx));
+        let req_id = jni_unwrap!(req_id.to_java(&env));
+        jni_unwrap!(env.call_method(
+            cb.as_obj(),
+            "call",
+            "(I)V",
+            &[req_id.into()],
+        ));
+    }
+}
+extern "C" fn call_CallbackVoid(ctx: *mut c_void) {
+    unsafe {
+        let guard = jni_unwrap!(EnvGuard::new(JVM.as_ref()));
+        let env = guard.env();
+        let cb = jni_unwrap!(convert_cb_from_java(&env, ctx));
+        jni_unwrap!(env.call_method(cb.as_obj(), "call", "()V", &[]));
+    }
+}
+extern "C" fn call_decode_ipc_msg_0(
+    ctx: *mut c_void,
+    req_id: u32,
+    auth_granted: *const AuthGranted,
+) {
+    unsafe {
+        let guard = jni_unwrap!(EnvGuard::new(JVM.as_ref()));
+        let env = guard.env();
+        let mut cbs = Box::from_raw(ctx as *mut [Option<GlobalRef>; 6usize]);
+        if let Some(cb) = cbs[0usize].take() {
+            let req_id = jni_unwrap!(req_id.to_java(&env));
+            let auth_granted = if auth_granted.is_null() {
+                JObject::null()
+            } else {
+                jni_unwrap!((*auth_granted).to_java(&env))
+            };
+            jni_unwrap!(env.call_method(
+                cb.as_obj(),
+                "call",
+                "(ILnet/maidsafe/safe_app/AuthGranted;)V",
+                &[req_id.into(), auth_granted.into()],
+            ));
+        }
+    }
+}
+extern "C" fn call_decode_ipc_msg_1(
+    ctx: *mut c_void,
+    req_id: u32,
+    serialised_cfg: *const u8,
+    serialised_cfg_len: usize,
+) {
+    unsafe {
+        let guard = jni_unwrap!(EnvGuard::new(JVM.as_ref()));
+        let env = guard.env();
+        let mut cbs = Box::from_raw(ctx as *mut [Option<GlobalRef>; 6usize]);
+        if let Some(cb) = cbs[1usize].take() {
+            let req_id = jni_unwrap!(req_id.to_java(&env));
+            let serialised_cfg = jni_unwrap!(
+                slice::from_raw_parts(serialised_cfg, serialised_cfg_len)
+                    .to_java(&env)
+            );
+            jni_unwrap!(env.call_method(
+                cb.as_obj(),
+                "call",
+                "(I[B)V",
+                &[req_id.into(), serialised_cfg.into()],
+            ));
+        }
+    }
+}
+extern "C" fn call_decode_ipc_msg_2(ctx: *mut c_void, req_id: u32) {
+    unsafe {
+        let guard = jni_unwrap!(EnvGuard::new(JVM.as_ref()));
+        let env = guard.env();
+        let mut cbs = Box::from_raw(ctx as *mut [Option<GlobalRef>; 6usize]);
+        if let Some(cb) = cbs[2usize].take() {
+            let req_id = jni_unwrap!(req_id.to_java(&env));
+            jni_unwrap!(env.call_method(
+                cb.as_obj(),
+                "call",
+                "(I)V",
+                &[req_id.into()],
+            ));
+        }
+    }
+}
+extern "C" fn call_decode_ipc_msg_3(ctx: *mut c_void, req_id: u32) {
+    unsafe {
+        let guard = jni_unwrap!(EnvGuard::new(JVM.as_ref()));
+        let env = guard.env();
+        let mut cbs = Box::from_raw(ctx as *mut [Option<GlobalRef>; 6usize]);
+        if let Some(cb) = cbs[3usize].take() {
+            let req_id = jni_unwrap!(req_id.to_java(&env));
+            jni_unwrap!(env.call_method(
+                cb.as_obj(),
+                "call",
+                "(I)V",
+                &[req_id.into()],
+            ));
+        }
+    }
+}
+extern "C" fn call_decode_ipc_msg_4(ctx: *mut c_void) {
+    unsafe {
+        let guard = jni_unwrap!(EnvGuard::new(JVM.as_ref()));
+        let env = guard.env();
+        let mut cbs = Box::from_raw(ctx as *mut [Option<GlobalRef>; 6usize]);
+        if let Some(cb) = cbs[4usize].take() {
+            jni_unwrap!(env.call_method(cb.as_obj(), "call", "()V", &[]));
+        }
+    }
+}
+extern "C" fn call_decode_ipc_msg_5(ctx: *mut c_void, result: *const FfiResult, req_id: u32) {
+    unsafe {
+        let guard = jni_unwrap!(EnvGuard::new(JVM.as_ref()));
+        let env = guard.env();
+        let mut cbs = Box::from_raw(ctx as *mut [Option<GlobalRef>; 6usize]);
+        if let Some(cb) = cbs[5usize].take() {
+            let result = if result.is_null() {
+                JObject::null()
+            } else {
+                jni_unwrap!((*result).to_java(&env))
+            };
+            let req_id = jni_unwrap!(req_id.to_java(&env));
+            jni_unwrap!(env.call_method(
+                cb.as_obj(),
+                "call",
+                "(Lnet/maidsafe/safe_app/FfiResult;I)V",
+                &[result.into(), req_id.into()],
+            ));
+        }
+    }
+}
+
+#[link(name = "safe_app")]
+extern "C" {
+    fn decode_ipc_msg(
+        msg: *const c_char,
+        user_data: *mut c_void,
+        o_auth: extern "C" fn(user_data: *mut c_void,
+                              req_id: u32,
+                              auth_granted: *const AuthGranted),
+        o_unregistered: extern "C" fn(user_data: *mut c_void,
+                                      req_id: u32,
+                                      serialised_cfg: *const u8,
+                                      serialised_cfg_len: usize),
+        o_containers: extern "C" fn(user_data: *mut c_void, req_id: u32),
+        o_share_mdata: extern "C" fn(user_data: *mut c_void, req_id: u32),
+        o_revoked: extern "C" fn(user_data: *mut c_void),
+        o_err: extern "C" fn(user_data: *mut c_void,
+                             result: *const FfiResult,
+                             req_id: u32),
+    );
+}
+#[no_mangle]
+pub unsafe extern "system" fn Java_net_maidsafe_safe_1app_NativeBindings_decodeIpcMsg(
+    env: JNIEnv,
+    _class: JClass,
+    msg: JString,
+    o_auth: JObject,
+    o_unregistered: JObject,
+    o_containers: JObject,
+    o_share_mdata: JObject,
+    o_revoked: JObject,
+    o_err: JObject,
+) {
+    let msg = jni_unwrap!(CString::from_java(&env, msg));
+    let ctx = gen_ctx!(
+        env,
+        o_auth,
+        o_unregistered,
+        o_containers,
+        o_share_mdata,
+        o_revoked,
+        o_err
+    );
+    decode_ipc_msg(
+        msg.as_ptr(),
+        ctx,
+        call_decode_ipc_msg_0,
+        call_decode_ipc_msg_1,
+        call_decode_ipc_msg_2,
+        call_decode_ipc_msg_3,
+        call_decode_ipc_msg_4,
+        call_decode_ipc_msg_5,
+    );
+}
+extern "C" fn call_CallbackResultBlsPublicKeyByteArrayLen(
+    ctx: *mut c_void,
+    result: *const FfiResult,
+    public_key: *const BlsPublicKey,
+    secret_key: *const u8,
+    secret_key_len: usize,
+) {
+    unsafe {
+        let guard = jni_unwrap!(EnvGuard::new(JVM.as_ref()));
+        let env = guard.env();
+        let cb = jni_unwrap!(convert_cb_from_java(&env, ctx));
+        let result = if result.is_null() {
+            JObject::null()
+        } else {
+            jni_unwrap!((*result).to_java(&env))
+        };
+        let public_key = if public_key.is_null() {
+            JObject::null()
+        } else {
+            jni_unwrap!((*public_key).to_java(&env))
+        };
+        let secret_key = jni_unwrap!(slice::from_raw_parts(secret_key, secret_key_len).to_java(
+            &env,
+        ));
+        jni_unwrap!(env.call_method(
+            cb.as_obj(),
+            "call",
+            "(Lnet/maidsafe/safe_app/FfiResult;Lnet/maidsafe/safe_app/BlsPublicKey;[B)V",
+            &[result.into(), public_key.into(), secret_key.into()],
+        ));
+    }
+}
+
+#[link(name = "safe_app")]
+extern "C" {
+    fn gen_response_key_pair(
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void,
+                            result: *const FfiResult,
+                            public_key: *const BlsPublicKey,
+                            secret_key: *const u8,
+                            secret_key_len: usize),
+    );
+}
+#[no_mangle]
+pub unsafe extern "system" fn Java_net_maidsafe_safe_1app_NativeBindings_genResponseKeyPair(
+    env: JNIEnv,
+    _class: JClass,
+    o_cb: JObject,
+) {
+    let ctx = gen_ctx!(env, o_cb);
+    gen_response_key_pair(ctx, call_CallbackResultBlsPublicKeyByteArrayLen);
+}
+extern "C" fn call_CallbackResultStringBlsPublicKey(
+    ctx: *mut c_void,
+    result: *const FfiResult,
+    msg: *const c_char,
+    signer: *const BlsPublicKey,
+) {
+    unsafe {
+        let guard = jni_unwrap!(EnvGuard::new(JVM.as_ref()));
+        let env = guard.env();
+        let cb = jni_unwrap!(convert_cb_from_java(&env, ctx));
+        let result = if result.is_null() {
+            JObject::null()
+        } else {
+            jni_unwrap!((*result).to_java(&env))
+        };
+        let msg: JObject = if msg.is_null() {
+            JObject::null()
+        } else {
+            jni_unwrap!(msg.to_java(&env)).into()
+        };
+        let signer = if signer.is_null() {
+            JObject::null()
+        } else {
+            jni_unwrap!((*signer).to_java(&env))
+        };
+        jni_unwrap ! ( env . call_method ( cb . as_obj ( ) , "call" , "(Lnet/maidsafe/safe_app/FfiResult;Ljava/lang/String;Lnet/maidsafe/safe_app/BlsPublicKey;)V" , & [ result . into ( ) , msg . into ( ) , signer . into ( ) ] , ) );
+    }
+}
+
+#[link(name = "safe_app")]
+extern "C" {
+    fn open_sealed_ipc_msg(
+        msg: *const c_char,
+        secret_key: *const u8,
+        secret_key_len: usize,
+        signer: *const BlsPublicKey,
+        req_id: u32,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void,
+                            result: *const FfiResult,
+                            msg: *const c_char,
+                            signer: *const BlsPublicKey),
+    );
+}
+#[no_mangle]
+pub unsafe extern "system" fn Java_net_maidsafe_safe_1app_NativeBindings_openSealedIpcMsg(
+    env: JNIEnv,
+    _class: JClass,
+    msg: JString,
+    secret_key: JObject,
+    signer: JObject,
+    req_id: jint,
+    o_cb: JObject,
+) {
+    let msg = jni_unwrap!(CString::from_java(&env, msg));
+    let secret_key = jni_unwrap!(Vec::from_java(&env, secret_key));
+    let signer = jni_unwrap!(BlsPublicKey::from_java(&env, signer));
+    let ctx = gen_ctx!(env, o_cb);
+    open_sealed_ipc_msg(
+        msg.as_ptr(),
+        secret_key.as_ptr(),
+        secret_key.len(),
+        &signer,
+        req_id as u32,
+        ctx,
+        call_CallbackResultStringBlsPublicKey,
+    );
+}
+
+#[link(name = "safe_app")]
+extern "C" {
+    fn app_init_logging(
+        output_file_name_override: *const c_char,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void, result: *const FfiResult),
+    );
+}
+#[no_mangle]
+pub unsafe extern "system" fn Java_net_maidsafe_safe_1app_NativeBindings_appInitLogging(
+    env: JNIEnv,
+    _class: JClass,
+    output_file_name_override: JString,
+    o_cb: JObject,
+) {
+    let output_file_name_override =
+        jni_unwrap!(CString::from_java(&env, output_file_name_override));
+    let ctx = gen_ctx!(env, o_cb);
+    app_init_logging(output_file_name_override.as_ptr(), ctx, call_CallbackResult);
+}
+
+#[link(name = "safe_app")]
+extern "C" {
+    fn app_config_dir_path(
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void,
+                            result: *const FfiResult,
+                            log_path: *const c_char),
+    );
+}
+#[no_mangle]
+pub unsafe extern "system" fn Java_net_maidsafe_safe_1app_NativeBindings_appConfigDirPath(
+    env: JNIEnv,
+    _class: JClass,
+    o_cb: JObject,
+) {
+    let ctx = gen_ctx!(env, o_cb);
+    app_config_dir_path(ctx, call_CallbackResultString);
+}
+
+#[link(name = "safe_app")]
+extern "C" {
+    fn mdata_info_new_private(
+        md_seq: bool,
+        name: *const XorNameArray,
+        type_tag: u64,
+        secret_key: *const SymSecretKey,
+        nonce: *const SymNonce,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void,
+                            result: *const FfiResult,
+                            mdata_info: *const MDataInfo),
+    );
+}
+#[no_mangle]
+pub unsafe extern "system" fn Java_net_maidsafe_safe_1app_NativeBindings_mdataInfoNewPrivate(
+    env: JNIEnv,
+    _class: JClass,
+    md_seq: bool,
+    name: JObject,
+    type_tag: jlong,
+    secret_key: JObject,
+    nonce: JObject,
+    o_cb: JObject,
+) {
+    let name = jni_unwrap!(XorNameArray::from_java(&env, name));
+    let secret_key = jni_unwrap!(SymSecretKey::from_java(&env, secret_key));
+    let nonce = jni_unwrap!(SymNonce::from_java(&env, nonce));
+    let ctx = gen_ctx!(env, o_cb);
+    mdata_info_new_private(
+        md_seq as bool,
+        &name,
+        type_tag as u64,
+        &secret_key,
+        &nonce,
+        ctx,
+        call_CallbackResultMDataInfo,
+    );
+}
+
+#[link(name = "safe_app")]
+extern "C" {
+    fn mdata_info_random_public(
+        md_seq: bool,
+        type_tag: u64,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void,
+                            result: *const FfiResult,
+                            mdata_info: *const MDataInfo),
+    );
+}
+#[no_mangle]
+pub unsafe extern "system" fn Java_net_maidsafe_safe_1app_NativeBindings_mdataInfoRandomPublic(
+    env: JNIEnv,
+    _class: JClass,
+    md_seq: bool,
+    type_tag: jlong,
+    o_cb: JObject,
+) {
+    let ctx = gen_ctx!(env, o_cb);
+    mdata_info_random_public(
+        md_seq as bool,
+        type_tag as u64,
+        ctx,
+        call_CallbackResultMDataInfo,
+    );
+}
+
+#[link(name = "safe_app")]
+extern "C" {
+    fn mdata_info_random_private(
+        md_seq: bool,
+        type_tag: u64,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void,
+                            result: *const FfiResult,
+                            mdata_info: *const MDataInfo),
+    );
+}
+#[no_mangle]
+pub unsafe extern "system" fn Java_net_maidsafe_safe_1app_NativeBindings_mdataInfoRandomPrivate(
+    env: JNIEnv,
+    _class: JClass,
+    md_seq: bool,
+    type_tag: jlong,
+    o_cb: JObject,
+) {
+    let ctx = gen_ctx!(env, o_cb);
+    mdata_info_random_private(
+        md_seq as bool,
+        type_tag as u64,
+        ctx,
+        call_CallbackResultMDataInfo,
+    );
+}
+
+#[link(name = "safe_app")]
+extern "C" {
+    fn mdata_info_encrypt_entry_key(
+        info: *const MDataInfo,
+        input: *const u8,
+        input_len: usize,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void,
+                            result: *const FfiResult,
+                            enc_entry_key: *const u8,
+                            enc_entry_key_len: usize),
+    );
+}
+#[no_mangle]pub unsafe extern "system" fn Java_net_maidsafe_safe_1app_NativeBindings_mdataInfoEncryptEntryKey ( env : JNIEnv , _class : JClass , info : JObject , input : JObject , o_cb : JObject ){
+    let info = jni_unwrap!(MDataInfo::from_java(&env, info));
+    let input = jni_unwrap!(Vec::from_java(&env, input));
+    let ctx = gen_ctx!(env, o_cb);
+    mdata_info_encrypt_entry_key(
+        &info,
+        input.as_ptr(),
+        input.len(),
+        ctx,
+        call_CallbackResultByteArrayLen,
+    );
+}
+
+#[link(name = "safe_app")]
+extern "C" {
+    fn mdata_info_encrypt_entry_value(
+        info: *const MDataInfo,
+        input: *const u8,
+        input_len: usize,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void,
+                            result: *const FfiResult,
+                            enc_entry_value: *const u8,
+                            enc_entry_value_len: usize),
+    );
+}
+#[no_mangle]pub unsafe extern "system" fn Java_net_maidsafe_safe_1app_NativeBindings_mdataInfoEncryptEntryValue ( env : JNIEnv , _class : JClass , info : JObject , input : JObject , o_cb : JObject ){
+    let info = jni_unwrap!(MDataInfo::from_java(&env, info));
+    let input = jni_unwrap!(Vec::from_java(&env, input));
+    let ctx = gen_ctx!(env, o_cb);
+    mdata_info_encrypt_entry_value(
+        &info,
+        input.as_ptr(),
+        input.len(),
+        ctx,
+        call_CallbackResultByteArrayLen,
+    );
+}
+
+#[link(name = "safe_app")]
+extern "C" {
+    fn mdata_info_decrypt(
+        info: *const MDataInfo,
+        input: *const u8,
+        input_len: usize,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void,
+                            result: *const FfiResult,
+                            mdata_info_decrypt: *const u8,
+                            mdata_info_decrypt_len: usize),
+    );
+}
+#[no_mangle]
+pub unsafe extern "system" fn Java_net_maidsafe_safe_1app_NativeBindings_mdataInfoDecrypt(
+    env: JNIEnv,
+    _class: JClass,
+    info: JObject,
+    input: JObject,
+    o_cb: JObject,
+) {
+    let info = jni_unwrap!(MDataInfo::from_java(&env, info));
+    let input = jni_unwrap!(Vec::from_java(&env, input));
+    let ctx = gen_ctx!(env, o_cb);
+    mdata_info_decrypt(
+        &info,
+        input.as_ptr(),
+        input.len(),
+        ctx,
+        call_CallbackResultByteArrayLen,
+    );
+}
+
+#[link(name = "safe_app")]
+extern "C" {
+    fn mdata_info_serialise(
+        info: *const MDataInfo,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void,
+                            result: *const FfiResult,
+                            encoded: *const u8,
+                            encoded_len: usize),
+    );
+}
+#[no_mangle]
+pub unsafe extern "system" fn Java_net_maidsafe_safe_1app_NativeBindings_mdataInfoSerialise(
+    env: JNIEnv,
+    _class: JClass,
+    info: JObject,
+    o_cb: JObject,
+) {
+    let info = jni_unwrap!(MDataInfo::from_java(&env, info));
+    let ctx = gen_ctx!(env, o_cb);
+    mdata_info_serialise(&info, ctx, call_CallbackResultByteArrayLen);
+}
+
+#[link(name = "safe_app")]
+extern "C" {
+    fn mdata_info_deserialise(
+        encoded_ptr: *const u8,
+        encoded_len: usize,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void,
+                            result: *const FfiResult,
+                            mdata_info: *const MDataInfo),
+    );
+}
+#[no_mangle]
+pub unsafe extern "system" fn Java_net_maidsafe_safe_1app_NativeBindings_mdataInfoDeserialise(
+    env: JNIEnv,
+    _class: JClass,
+    encoded_ptr: JObject,
+    o_cb: JObject,
+) {
+    let encoded_ptr = jni_unwrap!(Vec::from_java(&env, encoded_ptr));
+    let ctx = gen_ctx!(env, o_cb);
+    mdata_info_deserialise(
+        encoded_ptr.as_ptr(),
+        encoded_ptr.len(),
+        ctx,
+        call_CallbackResultMDataInfo,
+    );
+}
+
+#[link(name = "safe_app")]
+extern "C" {
+    fn mdata_put(
+        app: *const App,
+        info: *const MDataInfo,
+        permissions_h: MDataPermissionsHandle,
+        entries_h: MDataEntriesHandle,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void, result: *const FfiResult),
+    );
+}
+#[no_mangle]
+pub unsafe extern "system" fn Java_net_maidsafe_safe_1app_NativeBindings_mdataPut(
+    env: JNIEnv,
+    _class: JClass,
+    app: jlong,
+    info: JObject,
+    permissions_h: MDataPermissionsHandle,
+    entries_h: MDataEntriesHandle,
+    o_cb: JObject,
+) {
+    let app = app as *mut App;
+    let info = jni_unwrap!(MDataInfo::from_java(&env, info));
+    let ctx = gen_ctx!(env, o_cb);
+    mdata_put(
+        app,
+        &info,
+        permissions_h as MDataPermissionsHandle,
+        entries_h as MDataEntriesHandle,
+        ctx,
+        call_CallbackResult,
+    );
+}
+
+#[link(name = "safe_app")]
+extern "C" {
+    fn mdata_get_version(
+        app: *const App,
+        info: *const MDataInfo,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void,
+                            result: *const FfiResult,
+                            version: u64),
+    );
+}
+#[no_mangle]
+pub unsafe extern "system" fn Java_net_maidsafe_safe_1app_NativeBindings_mdataGetVersion(
+    env: JNIEnv,
+    _class: JClass,
+    app: jlong,
+    info: JObject,
+    o_cb: JObject,
+) {
+    let app = app as *mut App;
+    let info = jni_unwrap!(MDataInfo::from_java(&env, info));
+    let ctx = gen_ctx!(env, o_cb);
+    mdata_get_version(app, &info, ctx, call_CallbackResultLong);
+}
+
+#[link(name = "safe_app")]
+extern "C" {
+    fn mdata_serialised_size(
+        app: *const App,
+        info: *const MDataInfo,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void,
+                            result: *const FfiResult,
+                            serialised_size: u64),
+    );
+}
+#[no_mangle]
+pub unsafe extern "system" fn Java_net_maidsafe_safe_1app_NativeBindings_mdataSerialisedSize(
+    env: JNIEnv,
+    _class: JClass,
+    app: jlong,
+    info: JObject,
+    o_cb: JObject,
+) {
+    let app = app as *mut App;
+    let info = jni_unwrap!(MDataInfo::from_java(&env, info));
+    let ctx = gen_ctx!(env, o_cb);
+    mdata_serialised_size(app, &info, ctx, call_CallbackResultLong);
+}
+extern "C" fn call_CallbackResultByteArrayLenLong(
+    ctx: *mut c_void,
+    result: *const FfiResult,
+    content: *const u8,
+    content_len: usize,
+    version: u64,
+) {
+    unsafe {
+        let guard = jni_unwrap!(EnvGuard::new(JVM.as_ref()));
+        let env = guard.env();
+        let cb = jni_unwrap!(convert_cb_from_java(&env, ctx));
+        let result = if result.is_null() {
+            JObject::null()
+        } else {
+            jni_unwrap!((*result).to_java(&env))
+        };
+        let content = jni_unwrap!(slice::from_raw_parts(content, content_len).to_java(&env));
+        let version = jni_unwrap!(version.to_java(&env));
+        jni_unwrap!(env.call_method(
+            cb.as_obj(),
+            "call",
+            "(Lnet/maidsafe/safe_app/FfiResult;[BJ)V",
+            &[result.into(), content.into(), version.into()],
+        ));
+    }
+}
+
+#[link(name = "safe_app")]
+extern "C" {
+    fn mdata_get_value(
+        app: *const App,
+        info: *const MDataInfo,
+        key: *const u8,
+        key_len: usize,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void,
+                            result: *const FfiResult,
+                            content: *const u8,
+                            content_len: usize,
+                            version: u64),
+    );
+}
+#[no_mangle]
+pub unsafe extern "system" fn Java_net_maidsafe_safe_1app_NativeBindings_mdataGetValue(
+    env: JNIEnv,
+    _class: JClass,
+    app: jlong,
+    info: JObject,
+    key: JObject,
+    o_cb: JObject,
+) {
+    let app = app as *mut App;
+    let info = jni_unwrap!(MDataInfo::from_java(&env, info));
+    let key = jni_unwrap!(Vec::from_java(&env, key));
+    let ctx = gen_ctx!(env, o_cb);
+    mdata_get_value(
+        app,
+        &info,
+        key.as_ptr(),
+        key.len(),
+        ctx,
+        call_CallbackResultByteArrayLenLong,
+    );
+}
+extern "C" fn call_CallbackResultMDataEntriesHandle(
+    ctx: *mut c_void,
+    result: *const FfiResult,
+    entries_h: MDataEntriesHandle,
+) {
+    unsafe {
+        let guard = jni_unwrap!(EnvGuard::new(JVM.as_ref()));
+        let env = guard.env();
+        let cb = jni_unwrap!(convert_cb_from_java(&env, ctx));
+        let result = if result.is_null() {
+            JObject::null()
+        } else {
+            jni_unwrap!((*result).to_java(&env))
+        };
+        let entries_h = jni_unwrap!(entries_h.to_java(&env));
+        jni_unwrap!(env.call_method(
+            cb.as_obj(),
+            "call",
+            "(Lnet/maidsafe/safe_app/FfiResult;J)V",
+            &[result.into(), entries_h.into()],
+        ));
+    }
+}
+
+#[link(name = "safe_app")]
+extern "C" {
+    fn mdata_entries(
+        app: *const App,
+        info: *const MDataInfo,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void,
+                            result: *const FfiResult,
+                            entries_h: MDataEntriesHandle),
+    );
+}
+#[no_mangle]
+pub unsafe extern "system" fn Java_net_maidsafe_safe_1app_NativeBindings_mdataEntries(
+    env: JNIEnv,
+    _class: JClass,
+    app: jlong,
+    info: JObject,
+    o_cb: JObject,
+) {
+    let app = app as *mut App;
+    let info = jni_unwrap!(MDataInfo::from_java(&env, info));
+    let ctx = gen_ctx!(env, o_cb);
+    mdata_entries(app, &info, ctx, call_CallbackResultMDataEntriesHandle);
+}
+extern "C" fn call_CallbackResultMDataKeyArrayLen(
+    ctx: *mut c_void,
+    result: *const FfiResult,
+    keys: *const MDataKey,
+    keys_len: usize,
+) {
+    unsafe {
+        let guard = jni_unwrap!(EnvGuard::new(JVM.as_ref()));
+        let env = guard.env();
+        let cb = jni_unwrap!(convert_cb_from_java(&env, ctx));
+        let result = if result.is_null() {
+            JObject::null()
+        } else {
+            jni_unwrap!((*result).to_java(&env))
+        };
+        let keys = jni_unwrap!(slice::from_raw_parts(keys, keys_len).to_java(&env));
+        jni_unwrap!(env.call_method(
+            cb.as_obj(),
+            "call",
+            "(Lnet/maidsafe/safe_app/FfiResult;[Lnet/maidsafe/safe_app/MDataKey;)V",
+            &[result.into(), keys.into()],
+        ));
+    }
+}
+
+#[link(name = "safe_app")]
+extern "C" {
+    fn mdata_list_keys(
+        app: *const App,
+        info: *const MDataInfo,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void,
+                            result: *const FfiResult,
+                            keys: *const MDataKey,
+                            keys_len: usize),
+    );
+}
+#[no_mangle]
+pub unsafe extern "system" fn Java_net_maidsafe_safe_1app_NativeBindings_mdataListKeys(
+    env: JNIEnv,
+    _class: JClass,
+    app: jlong,
+    info: JObject,
+    o_cb: JObject,
+) {
+    let app = app as *mut App;
+    let info = jni_unwrap!(MDataInfo::from_java(&env, info));
+    let ctx = gen_ctx!(env, o_cb);
+    mdata_list_keys(app, &info, ctx, call_CallbackResultMDataKeyArrayLen);
+}
+extern "C" fn call_CallbackResultMDataValueArrayLen(
+    ctx: *mut c_void,
+    result: *const FfiResult,
+    values: *const MDataValue,
+    values_len: usize,
+) {
+    unsafe {
+        let guard = jni_unwrap!(EnvGuard::new(JVM.as_ref()));
+        let env = guard.env();
+        let cb = jni_unwrap!(convert_cb_from_java(&env, ctx));
+        let result = if result.is_null() {
+            JObject::null()
+        } else {
+            jni_unwrap!((*result).to_java(&env))
+        };
+        let values = jni_unwrap!(slice::from_raw_parts(values, values_len).to_java(&env));
+        jni_unwrap!(env.call_method(
+            cb.as_obj(),
+            "call",
+            "(Lnet/maidsafe/safe_app/FfiResult;[Lnet/maidsafe/safe_app/MDataValue;)V",
+            &[result.into(), values.into()],
+        ));
+    }
+}
+
+#[link(name = "safe_app")]
+extern "C" {
+    fn seq_mdata_list_values(
+        app: *const App,
+        info: *const MDataInfo,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void,
+                            result: *const FfiResult,
+                            values: *const MDataValue,
+                            values_len: usize),
+    );
+}
+#[no_mangle]
+pub unsafe extern "system" fn Java_net_maidsafe_safe_1app_NativeBindings_seqMdataListValues(
+    env: JNIEnv,
+    _class: JClass,
+    app: jlong,
+    info: JObject,
+    o_cb: JObject,
+) {
+    let app = app as *mut App;
+    let info = jni_unwrap!(MDataInfo::from_java(&env, info));
+    let ctx = gen_ctx!(env, o_cb);
+    seq_mdata_list_values(app, &info, ctx, call_CallbackResultMDataValueArrayLen);
+}
+
+#[link(name = "safe_app")]
+extern "C" {
+    fn mdata_mutate_entries(
+        app: *const App,
+        info: *const MDataInfo,
+        actions_h: MDataEntryActionsHandle,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void, result: *const FfiResult),
+    );
+}
+#[no_mangle]
+pub unsafe extern "system" fn Java_net_maidsafe_safe_1app_NativeBindings_mdataMutateEntries(
+    env: JNIEnv,
+    _class: JClass,
+    app: jlong,
+    info: JObject,
+    actions_h: MDataEntryActionsHandle,
+    o_cb: JObject,
+) {
+    let app = app as *mut App;
+    let info = jni_unwrap!(MDataInfo::from_java(&env, info));
+    let ctx = gen_ctx!(env, o_cb);
+    mdata_mutate_entries(
+        app,
+        &info,
+        actions_h as MDataEntryActionsHandle,
+        ctx,
+        call_CallbackResult,
+    );
+}
+extern "C" fn call_CallbackResultMDataPermissionsHandle(
+    ctx: *mut c_void,
+    result: *const FfiResult,
+    perm_h: MDataPermissionsHandle,
+) {
+    unsafe {
+        let guard = jni_unwrap!(EnvGuard::new(JVM.as_ref()));
+        let env = guard.env();
+        let cb = jni_unwrap!(convert_cb_from_java(&env, ctx));
+        let result = if result.is_null() {
+            JObject::null()
+        } else {
+            jni_unwrap!((*result).to_java(&env))
+        };
+        let perm_h = jni_unwrap!(perm_h.to_java(&env));
+        jni_unwrap!(env.call_method(
+            cb.as_obj(),
+            "call",
+            "(Lnet/maidsafe/safe_app/FfiResult;J)V",
+            &[result.into(), perm_h.into()],
+        ));
+    }
+}
+
+#[link(name = "safe_app")]
+extern "C" {
+    fn mdata_list_permissions(
+        app: *const App,
+        info: *const MDataInfo,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void,
+                            result: *const FfiResult,
+                            perm_h: MDataPermissionsHandle),
+    );
+}
+#[no_mangle]
+pub unsafe extern "system" fn Java_net_maidsafe_safe_1app_NativeBindings_mdataListPermissions(
+    env: JNIEnv,
+    _class: JClass,
+    app: jlong,
+    info: JObject,
+    o_cb: JObject,
+) {
+    let app = app as *mut App;
+    let info = jni_unwrap!(MDataInfo::from_java(&env, info));
+    let ctx = gen_ctx!(env, o_cb);
+    mdata_list_permissions(app, &info, ctx, call_CallbackResultMDataPermissionsHandle);
+}
+extern "C" fn call_CallbackResultPermissionSet(
+    ctx: *mut c_void,
+    result: *const FfiResult,
+    perm_set: *const PermissionSet,
+) {
+    unsafe {
+        let guard = jni_unwrap!(EnvGuard::new(JVM.as_ref()));
+        let env = guard.env();
+        let cb = jni_unwrap!(convert_cb_from_java(&env, ctx));
+        let result = if result.is_null() {
+            JObject::null()
+        } else {
+            jni_unwrap!((*result).to_java(&env))
+        };
+        let perm_set = if perm_set.is_null() {
+            JObject::null()
+        } else {
+            jni_unwrap!((*perm_set).to_java(&env))
+        };
+        jni_unwrap!(env.call_method(
+            cb.as_obj(),
+            "call",
+            "(Lnet/maidsafe/safe_app/FfiResult;Lnet/maidsafe/safe_app/PermissionSet;)V",
+            &[result.into(), perm_set.into()],
+        ));
+    }
+}
+
+#[link(name = "safe_app")]
+extern "C" {
+    fn mdata_list_user_permissions(
+        app: *const App,
+        info: *const MDataInfo,
+        user_h: SignPubKeyHandle,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void,
+                            result: *const FfiResult,
+                            perm_set: *const PermissionSet),
+    );
+}
+#[no_mangle]pub unsafe extern "system" fn Java_net_maidsafe_safe_1app_NativeBindings_mdataListUserPermissions ( env : JNIEnv , _class : JClass , app : jlong , info : JObject , user_h : SignPubKeyHandle , o_cb : JObject ){
+    let app = app as *mut App;
+    let info = jni_unwrap!(MDataInfo::from_java(&env, info));
+    let ctx = gen_ctx!(env, o_cb);
+    mdata_list_user_permissions(
+        app,
+        &info,
+        user_h as SignPubKeyHandle,
+        ctx,
+        call_CallbackResultPermissionSet,
+    );
+}
+
+#[link(name = "safe_app")]
+extern "C" {
+    fn mdata_set_user_permissions(
+        app: *const App,
+        info: *const MDataInfo,
+        user_h: SignPubKeyHandle,
+        permission_set: *const PermissionSet,
+        version: u64,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void, result: *const FfiResult),
+    );
+}
+#[no_mangle]
+pub unsafe extern "system" fn Java_net_maidsafe_safe_1app_NativeBindings_mdataSetUserPermissions(
+    env: JNIEnv,
+    _class: JClass,
+    app: jlong,
+    info: JObject,
+    user_h: SignPubKeyHandle,
+    permission_set: JObject,
+    version: jlong,
+    o_cb: JObject,
+) {
+    let app = app as *mut App;
+    let info = jni_unwrap!(MDataInfo::from_java(&env, info));
+    let permission_set = jni_unwrap!(PermissionSet::from_java(&env, permission_set));
+    let ctx = gen_ctx!(env, o_cb);
+    mdata_set_user_permissions(
+        app,
+        &info,
+        user_h as SignPubKeyHandle,
+        &permission_set,
+        version as u64,
+        ctx,
+        call_CallbackResult,
+    );
+}
+
+#[link(name = "safe_app")]
+extern "C" {
+    fn mdata_del_user_permissions(
+        app: *const App,
+        info: *const MDataInfo,
+        user_h: SignPubKeyHandle,
+        version: u64,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void, result: *const FfiResult),
+    );
+}
+#[no_mangle]
+pub unsafe extern "system" fn Java_net_maidsafe_safe_1app_NativeBindings_mdataDelUserPermissions(
+    env: JNIEnv,
+    _class: JClass,
+    app: jlong,
+    info: JObject,
+    user_h: SignPubKeyHandle,
+    version: jlong,
+    o_cb: JObject,
+) {
+    let app = app as *mut App;
+    let info = jni_unwrap!(MDataInfo::from_java(&env, info));
+    let ctx = gen_ctx!(env, o_cb);
+    mdata_del_user_permissions(
+        app,
+        &info,
+        user_h as SignPubKeyHandle,
+        version as u64,
+        ctx,
+        call_CallbackResult,
+    );
+}
+
+#[link(name = "safe_app")]
+extern "C" {
+    fn seq_mdata_entries_new(
+        app: *const App,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void,
+                            result: *const FfiResult,
+                            entries_h: MDataEntriesHandle),
+    );
+}
+#[no_mangle]
+pub unsafe extern "system" fn Java_net_maidsafe_safe_1app_NativeBindings_seqMdataEntriesNew(
+    env: JNIEnv,
+    _class: JClass,
+    app: jlong,
+    o_cb: JObject,
+) {
+    let app = app as *mut App;
+    let ctx = gen_ctx!(env, o_cb);
+    seq_mdata_entries_new(app, ctx, call_CallbackResultMDataEntriesHandle);
+}
+
+#[link(name = "safe_app")]
+extern "C" {
+    fn seq_mdata_entries_insert(
+        app: *const App,
+        entries_h: MDataEntriesHandle,
+        key: *const u8,
+        key_len: usize,
+        value: *const u8,
+        value_len: usize,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void, result: *const FfiResult),
+    );
+}
+#[no_mangle]
+pub unsafe extern "system" fn Java_net_maidsafe_safe_1app_NativeBindings_seqMdataEntriesInsert(
+    env: JNIEnv,
+    _class: JClass,
+    app: jlong,
+    entries_h: MDataEntriesHandle,
+    key: JObject,
+    value: JObject,
+    o_cb: JObject,
+) {
+    let app = app as *mut App;
+    let key = jni_unwrap!(Vec::from_java(&env, key));
+    let value = jni_unwrap!(Vec::from_java(&env, value));
+    let ctx = gen_ctx!(env, o_cb);
+    seq_mdata_entries_insert(
+        app,
+        entries_h as MDataEntriesHandle,
+        key.as_ptr(),
+        key.len(),
+        value.as_ptr(),
+        value.len(),
+        ctx,
+        call_CallbackResult,
+    );
+}
+extern "C" fn call_CallbackResultSize(ctx: *mut c_void, result: *const FfiResult, len: usize) {
+    unsafe {
+        let guard = jni_unwrap!(EnvGuard::new(JVM.as_ref()));
+        let env = guard.env();
+        let cb = jni_unwrap!(convert_cb_from_java(&env, ctx));
+        let result = if result.is_null() {
+            JObject::null()
+        } else {
+            jni_unwrap!((*result).to_java(&env))
+        };
+        let len = jni_unwrap!(len.to_java(&env));
+        jni_unwrap!(env.call_method(
+            cb.as_obj(),
+            "call",
+            "(Lnet/maidsafe/safe_app/FfiResult;J)V",
+            &[result.into(), len.into()],
+        ));
+    }
+}
+
+#[link(name = "safe_app")]
+extern "C" {
+    fn seq_mdata_entries_len(
+        app: *const App,
+        entries_h: MDataEntriesHandle,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void, result: *const FfiResult, len: usize),
+    );
+}
+#[no_mangle]
+pub unsafe extern "system" fn Java_net_maidsafe_safe_1app_NativeBindings_seqMdataEntriesLen(
+    env: JNIEnv,
+    _class: JClass,
+    app: jlong,
+    entries_h: MDataEntriesHandle,
+    o_cb: JObject,
+) {
+    let app = app as *mut App;
+    let ctx = gen_ctx!(env, o_cb);
+    seq_mdata_entries_len(
+        app,
+        entries_h as MDataEntriesHandle,
+        ctx,
+        call_CallbackResultSize,
+    );
+}
+
+#[link(name = "safe_app")]
+extern "C" {
+    fn seq_mdata_entries_get(
+        app: *const App,
+        entries_h: MDataEntriesHandle,
+        key: *const u8,
+        key_len: usize,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void,
+                            result: *const FfiResult,
+                            content: *const u8,
+                            content_len: usize,
+                            version: u64),
+    );
+}
+#[no_mangle]
+pub unsafe extern "system" fn Java_net_maidsafe_safe_1app_NativeBindings_seqMdataEntriesGet(
+    env: JNIEnv,
+    _class: JClass,
+    app: jlong,
+    entries_h: MDataEntriesHandle,
+    key: JObject,
+    o_cb: JObject,
+) {
+    let app = app as *mut App;
+    let key = jni_unwrap!(Vec::from_java(&env, key));
+    let ctx = gen_ctx!(env, o_cb);
+    seq_mdata_entries_get(
+        app,
+        entries_h as MDataEntriesHandle,
+        key.as_ptr(),
+        key.len(),
+        ctx,
+        call_CallbackResultByteArrayLenLong,
+    );
+}
+extern "C" fn call_CallbackResultMDataEntryArrayLen(
+    ctx: *mut c_void,
+    result: *const FfiResult,
+    entries: *const MDataEntry,
+    entries_len: usize,
+) {
+    unsafe {
+        let guard = jni_unwrap!(EnvGuard::new(JVM.as_ref()));
+        let env = guard.env();
+        let cb = jni_unwrap!(convert_cb_from_java(&env, ctx));
+        let result = if result.is_null() {
+            JObject::null()
+        } else {
+            jni_unwrap!((*result).to_java(&env))
+        };
+        let entries = jni_unwrap!(slice::from_raw_parts(entries, entries_len).to_java(&env));
+        jni_unwrap!(env.call_method(
+            cb.as_obj(),
+            "call",
+            "(Lnet/maidsafe/safe_app/FfiResult;[Lnet/maidsafe/safe_app/MDataEntry;)V",
+            &[result.into(), entries.into()],
+        ));
+    }
+}
+
+#[link(name = "safe_app")]
+extern "C" {
+    fn seq_mdata_list_entries(
+        app: *const App,
+        entries_h: MDataEntriesHandle,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void,
+                            result: *const FfiResult,
+                            entries: *const MDataEntry,
+                            entries_len: usize),
+    );
+}
+#[no_mangle]
+pub unsafe extern "system" fn Java_net_maidsafe_safe_1app_NativeBindings_seqMdataListEntries(
+    env: JNIEnv,
+    _class: JClass,
+    app: jlong,
+    entries_h: MDataEntriesHandle,
+    o_cb: JObject,
+) {
+    let app = app as *mut App;
+    let ctx = gen_ctx!(env, o_cb);
+    seq_mdata_list_entries(
+        app,
+        entries_h as MDataEntriesHandle,
+        ctx,
+        call_CallbackResultMDataEntryArrayLen,
+    );
+}
+
+#[link(name = "safe_app")]
+extern "C" {
+    fn seq_mdata_entries_free(
+        app: *const App,
+        entries_h: MDataEntriesHandle,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void, result: *const FfiResult),
+    );
+}
+#[no_mangle]
+pub unsafe extern "system" fn Java_net_maidsafe_safe_1app_NativeBindings_seqMdataEntriesFree(
+    env: JNIEnv,
+    _class: JClass,
+    app: jlong,
+    entries_h: MDataEntriesHandle,
+    o_cb: JObject,
+) {
+    let app = app as *mut App;
+    let ctx = gen_ctx!(env, o_cb);
+    seq_mdata_entries_free(
+        app,
+        entries_h as MDataEntriesHandle,
+        ctx,
+        call_CallbackResult,
+    );
+}
+extern "C" fn call_CallbackResultMDataEntryActionsHandle(
+    ctx: *mut c_void,
+    result: *const FfiResult,
+    entry_actions_h: MDataEntryActionsHandle,
+) {
+    unsafe {
+        let guard = jni_unwrap!(EnvGuard::new(JVM.as_ref()));
+        let env = guard.env();
+        let cb = jni_unwrap!(convert_cb_from_java(&env, ctx));
+        let result = if result.is_null() {
+            JObject::null()
+        } else {
+            jni_unwrap!((*result).to_java(&env))
+        };
+        let entry_actions_h = jni_unwrap!(entry_actions_h.to_java(&env));
+        jni_unwrap!(env.call_method(
+            cb.as_obj(),
+            "call",
+            "(Lnet/maidsafe/safe_app/FfiResult;J)V",
+            &[result.into(), entry_actions_h.into()],
+        ));
+    }
+}
+
+#[link(name = "safe_app")]
+extern "C" {
+    fn mdata_entry_actions_new(
+        app: *const App,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void,
+                            result: *const FfiResult,
+                            entry_actions_h: MDataEntryActionsHandle),
+    );
+}
+#[no_mangle]
+pub unsafe extern "system" fn Java_net_maidsafe_safe_1app_NativeBindings_mdataEntryActionsNew(
+    env: JNIEnv,
+    _class: JClass,
+    app: jlong,
+    o_cb: JObject,
+) {
+    let app = app as *mut App;
+    let ctx = gen_ctx!(env, o_cb);
+    mdata_entry_actions_new(app, ctx, call_CallbackResultMDataEntryActionsHandle);
+}
+
+#[link(name = "safe_app")]
+extern "C" {
+    fn mdata_entry_actions_insert(
+        app: *const App,
+        actions_h: MDataEntryActionsHandle,
+        key: *const u8,
+        key_len: usize,
+        value: *const u8,
+        value_len: usize,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void, result: *const FfiResult),
+    );
+}
+#[no_mangle]
+pub unsafe extern "system" fn Java_net_maidsafe_safe_1app_NativeBindings_mdataEntryActionsInsert(
+    env: JNIEnv,
+    _class: JClass,
+    app: jlong,
+    actions_h: MDataEntryActionsHandle,
+    key: JObject,
+    value: JObject,
+    o_cb: JObject,
+) {
+    let app = app as *mut App;
+    let key = jni_unwrap!(Vec::from_java(&env, key));
+    let value = jni_unwrap!(Vec::from_java(&env, value));
+    let ctx = gen_ctx!(env, o_cb);
+    mdata_entry_actions_insert(
+        app,
+        actions_h as MDataEntryActionsHandle,
+        key.as_ptr(),
+        key.len(),
+        value.as_ptr(),
+        value.len(),
+        ctx,
+        call_CallbackResult,
+    );
+}
+
+#[link(name = "safe_app")]
+extern "C" {
+    fn mdata_entry_actions_update(
+        app: *const App,
+        actions_h: MDataEntryActionsHandle,
+        key: *const u8,
+        key_len: usize,
+        value: *const u8,
+        value_len: usize,
+        version: u64,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void, result: *const FfiResult),
+    );
+}
+#[no_mangle]
+pub unsafe extern "system" fn Java_net_maidsafe_safe_1app_NativeBindings_mdataEntryActionsUpdate(
+    env: JNIEnv,
+    _class: JClass,
+    app: jlong,
+    actions_h: MDataEntryActionsHandle,
+    key: JObject,
+    value: JObject,
+    version: jlong,
+    o_cb: JObject,
+) {
+    let app = app as *mut App;
+    let key = jni_unwrap!(Vec::from_java(&env, key));
+    let value = jni_unwrap!(Vec::from_java(&env, value));
+    let ctx = gen_ctx!(env, o_cb);
+    mdata_entry_actions_update(
+        app,
+        actions_h as MDataEntryActionsHandle,
+        key.as_ptr(),
+        key.len(),
+        value.as_ptr(),
+        value.len(),
+        version as u64,
+        ctx,
+        call_CallbackResult,
+    );
+}
+
+#[link(name = "safe_app")]
+extern "C" {
+    fn mdata_entry_actions_delete(
+        app: *const App,
+        actions_h: MDataEntryActionsHandle,
+        key: *const u8,
+        key_len: usize,
+        version: u64,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void, result: *const FfiResult),
+    );
+}
+#[no_mangle]
+pub unsafe extern "system" fn Java_net_maidsafe_safe_1app_NativeBindings_mdataEntryActionsDelete(
+    env: JNIEnv,
+    _class: JClass,
+    app: jlong,
+    actions_h: MDataEntryActionsHandle,
+    key: JObject,
+    version: jlong,
+    o_cb: JObject,
+) {
+    let app = app as *mut App;
+    let key = jni_unwrap!(Vec::from_java(&env, key));
+    let ctx = gen_ctx!(env, o_cb);
+    mdata_entry_actions_delete(
+        app,
+        actions_h as MDataEntryActionsHandle,
+        key.as_ptr(),
+        key.len(),
+        version as u64,
+        ctx,
+        call_CallbackResult,
+    );
+}
+
+#[link(name = "safe_app")]
+extern "C" {
+    fn mdata_entry_actions_free(
+        app: *const App,
+        actions_h: MDataEntryActionsHandle,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void, result: *const FfiResult),
+    );
+}
+#[no_mangle]
+pub unsafe extern "system" fn Java_net_maidsafe_safe_1app_NativeBindings_mdataEntryActionsFree(
+    env: JNIEnv,
+    _class: JClass,
+    app: jlong,
+    actions_h: MDataEntryActionsHandle,
+    o_cb: JObject,
+) {
+    let app = app as *mut App;
+    let ctx = gen_ctx!(env, o_cb);
+    mdata_entry_actions_free(
+        app,
+        actions_h as MDataEntryActionsHandle,
+        ctx,
+        call_CallbackResult,
+    );
+}
+
+#[link(name = "safe_app")]
+extern "C" {
+    fn mdata_encode_metadata(
+        metadata: *const MetadataResponse,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void,
+                            result: *const FfiResult,
+                            encoded: *const u8,
+                            encoded_len: usize),
+    );
+}
+#[no_mangle]
+pub unsafe extern "system" fn Java_net_maidsafe_safe_1app_NativeBindings_mdataEncodeMetadata(
+    env: JNIEnv,
+    _class: JClass,
+    metadata: JObject,
+    o_cb: JObject,
+) {
+    let metadata = jni_unwrap!(MetadataResponse::from_java(&env, metadata));
+    let ctx = gen_ctx!(env, o_cb);
+    mdata_encode_metadata(&metadata, ctx, call_CallbackResultByteArrayLen);
+}
+impl<'a> FromJava<JObject<'a>> for UserPermissionSet {
+    fn from_java(env: &JNIEnv, input: JObject) -> Result<Self, JniError> {
+        let user_h = env.get_field(input, "userH", "J")?.j()? as u64;
+        let perm_set = env.get_field(input, "permSet", "Lnet/maidsafe/safe_app/PermissionSet;")?
+            .l()?;
+        let perm_set = PermissionSet::from_java(env, perm_set)?;
+        Ok(UserPermissionSet { user_h, perm_set })
+    }
+}
+impl<'a> ToJava<'a, JObject<'a>> for UserPermissionSet {
+    fn to_java(&self, env: &'a JNIEnv) -> Result<JObject<'a>, JniError> {
+        let cls = unsafe { find_class(env, "net/maidsafe/safe_app/UserPermissionSet")? };
+        let output = env.new_object(&cls, "()V", &[])?;
+        let jobj = self.user_h.to_java(env)?;
+        env.set_field(output, "userH", "J", jobj.into())?;
+        let jobj = self.perm_set.to_java(env)?;
+        env.set_field(
+            output,
+            "permSet",
+            "Lnet/maidsafe/safe_app/PermissionSet;",
+            jobj.into(),
+        )?;
+        env.delete_local_ref(jobj)?;
+        Ok(output)
+    }
+}
+#[link(name = "safe_app")]
+extern "C" {
+    fn mdata_permissions_new(
+        app: *const App,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void,
+                            result: *const FfiResult,
+                            perm_h: MDataPermissionsHandle),
+    );
+}
+#[no_mangle]
+pub unsafe extern "system" fn Java_net_maidsafe_safe_1app_NativeBindings_mdataPermissionsNew(
+    env: JNIEnv,
+    _class: JClass,
+    app: jlong,
+    o_cb: JObject,
+) {
+    let app = app as *mut App;
+    let ctx = gen_ctx!(env, o_cb);
+    mdata_permissions_new(app, ctx, call_CallbackResultMDataPermissionsHandle);
+}
+
+#[link(name = "safe_app")]
+extern "C" {
+    fn mdata_permissions_len(
+        app: *const App,
+        permissions_h: MDataPermissionsHandle,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void,
+                            result: *const FfiResult,
+                            size: usize),
+    );
+}
+#[no_mangle]
+pub unsafe extern "system" fn Java_net_maidsafe_safe_1app_NativeBindings_mdataPermissionsLen(
+    env: JNIEnv,
+    _class: JClass,
+    app: jlong,
+    permissions_h: MDataPermissionsHandle,
+    o_cb: JObject,
+) {
+    let app = app as *mut App;
+    let ctx = gen_ctx!(env, o_cb);
+    mdata_permissions_len(
+        app,
+        permissions_h as MDataPermissionsHandle,
+        ctx,
+        call_CallbackResultSize,
+    );
+}
+
+#[link(name = "safe_app")]
+extern "C" {
+    fn mdata_permissions_get(
+        app: *const App,
+        permissions_h: MDataPermissionsHandle,
+        user_h: SignPubKeyHandle,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void,
+                            result: *const FfiResult,
+                            perm_set: *const PermissionSet),
+    );
+}
+#[no_mangle]
+pub unsafe extern "system" fn Java_net_maidsafe_safe_1app_NativeBindings_mdataPermissionsGet(
+    env: JNIEnv,
+    _class: JClass,
+    app: jlong,
+    permissions_h: MDataPermissionsHandle,
+    user_h: SignPubKeyHandle,
+    o_cb: JObject,
+) {
+    let app = app as *mut App;
+    let ctx = gen_ctx!(env, o_cb);
+    mdata_permissions_get(
+        app,
+        permissions_h as MDataPermissionsHandle,
+        user_h as SignPubKeyHandle,
+        ctx,
+        call_CallbackResultPermissionSet,
+    );
+}
+extern "C" fn call_CallbackResultUserPermissionSetArrayLen(
+    ctx: *mut c_void,
+    result: *const FfiResult,
+    user_perm_sets: *const UserPermissionSet,
+    user_perm_sets_len: usize,
+) {
+    unsafe {
+        let guard = jni_unwrap!(EnvGuard::new(JVM.as_ref()));
+        let env = guard.env();
+        let cb = jni_unwrap!(convert_cb_from_java(&env, ctx));
+        let result = if result.is_null() {
+            JObject::null()
+        } else {
+            jni_unwrap!((*result).to_java(&env))
+        };
+        let user_perm_sets =
+            jni_unwrap!(slice::from_raw_parts(user_perm_sets, user_perm_sets_len).to_java(&env));
+        jni_unwrap!(env.call_method(
+            cb.as_obj(),
+            "call",
+            "(Lnet/maidsafe/safe_app/FfiResult;[Lnet/maidsafe/safe_app/UserPermissionSet;)V",
+            &[result.into(), user_perm_sets.into()],
+        ));
+    }
+}
+
+#[link(name = "safe_app")]
+extern "C" {
+    fn mdata_list_permission_sets(
+        app: *const App,
+        permissions_h: MDataPermissionsHandle,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void,
+                            result: *const FfiResult,
+                            user_perm_sets: *const UserPermissionSet,
+                            user_perm_sets_len: usize),
+    );
+}
+#[no_mangle]
+pub unsafe extern "system" fn Java_net_maidsafe_safe_1app_NativeBindings_mdataListPermissionSets(
+    env: JNIEnv,
+    _class: JClass,
+    app: jlong,
+    permissions_h: MDataPermissionsHandle,
+    o_cb: JObject,
+) {
+    let app = app as *mut App;
+    let ctx = gen_ctx!(env, o_cb);
+    mdata_list_permission_sets(
+        app,
+        permissions_h as MDataPermissionsHandle,
+        ctx,
+        call_CallbackResultUserPermissionSetArrayLen,
+    );
+}
+
+#[link(name = "safe_app")]
+extern "C" {
+    fn mdata_permissions_insert(
+        app: *const App,
+        permissions_h: MDataPermissionsHandle,
+        user_h: SignPubKeyHandle,
+        permission_set: *const PermissionSet,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void, result: *const FfiResult),
+    );
+}
+#[no_mangle]
+pub unsafe extern "system" fn Java_net_maidsafe_safe_1app_NativeBindings_mdataPermissionsInsert(
+    env: JNIEnv,
+    _class: JClass,
+    app: jlong,
+    permissions_h: MDataPermissionsHandle,
+    user_h: SignPubKeyHandle,
+    permission_set: JObject,
+    o_cb: JObject,
+) {
+    let app = app as *mut App;
+    let permission_set = jni_unwrap!(PermissionSet::from_java(&env, permission_set));
+    let ctx = gen_ctx!(env, o_cb);
+    mdata_permissions_insert(
+        app,
+        permissions_h as MDataPermissionsHandle,
+        user_h as SignPubKeyHandle,
+        &permission_set,
+        ctx,
+        call_CallbackResult,
+    );
+}
+
+#[link(name = "safe_app")]
+extern "C" {
+    fn mdata_permissions_free(
+        app: *const App,
+        permissions_h: MDataPermissionsHandle,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void, result: *const FfiResult),
+    );
+}
+#[no_mangle]
+pub unsafe extern "system" fn Java_net_maidsafe_safe_1app_NativeBindings_mdataPermissionsFree(
+    env: JNIEnv,
+    _class: JClass,
+    app: jlong,
+    permissions_h: MDataPermissionsHandle,
+    o_cb: JObject,
+) {
+    let app = app as *mut App;
+    let ctx = gen_ctx!(env, o_cb);
+    mdata_permissions_free(
+        app,
+        permissions_h as MDataPermissionsHandle,
+        ctx,
+        call_CallbackResult,
+    );
+}
+extern "C" fn call_CallbackResultFileLong(
+    ctx: *mut c_void,
+    result: *const FfiResult,
+    file: *const File,
+    version: u64,
+) {
+    unsafe {
+        let guard = jni_unwrap!(EnvGuard::new(JVM.as_ref()));
+        let env = guard.env();
+        let cb = jni_unwrap!(convert_cb_from_java(&env, ctx));
+        let result = if result.is_null() {
+            JObject::null()
+        } else {
+            jni_unwrap!((*result).to_java(&env))
+        };
+        let file = if file.is_null() {
+            JObject::null()
+        } else {
+            jni_unwrap!((*file).to_java(&env))
+        };
+        let version = jni_unwrap!(version.to_java(&env));
+        jni_unwrap!(env.call_method(
+            cb.as_obj(),
+            "call",
+            "(Lnet/maidsafe/safe_app/FfiResult;Lnet/maidsafe/safe_app/File;J)V",
+            &[result.into(), file.into(), version.into()],
+        ));
+    }
+}
+
+#[link(name = "safe_app")]
+extern "C" {
+    fn dir_fetch_file(
+        app: *const App,
+        parent_info: *const MDataInfo,
+        file_name: *const c_char,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void,
+                            result: *const FfiResult,
+                            file: *const File,
+                            version: u64),
+    );
+}
+#[no_mangle]
+pub unsafe extern "system" fn Java_net_maidsafe_safe_1app_NativeBindings_dirFetchFile(
+    env: JNIEnv,
+    _class: JClass,
+    app: jlong,
+    parent_info: JObject,
+    file_name: JString,
+    o_cb: JObject,
+) {
+    let app = app as *mut App;
+    let parent_info = jni_unwrap!(MDataInfo::from_java(&env, parent_info));
+    let file_name = jni_unwrap!(CString::from_java(&env, file_name));
+    let ctx = gen_ctx!(env, o_cb);
+    dir_fetch_file(
+        app,
+        &parent_info,
+        file_name.as_ptr(),
+        ctx,
+        call_CallbackResultFileLong,
+    );
+}
+
+#[link(name = "safe_app")]
+extern "C" {
+    fn dir_insert_file(
+        app: *const App,
+        parent_info: *const MDataInfo,
+        file_name: *const c_char,
+        file: *const File,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void, result: *const FfiResult),
+    );
+}
+#[no_mangle]
+pub unsafe extern "system" fn Java_net_maidsafe_safe_1app_NativeBindings_dirInsertFile(
+    env: JNIEnv,
+    _class: JClass,
+    app: jlong,
+    parent_info: JObject,
+    file_name: JString,
+    file: JObject,
+    o_cb: JObject,
+) {
+    let app = app as *mut App;
+    let parent_info = jni_unwrap!(MDataInfo::from_java(&env, parent_info));
+    let file_name = jni_unwrap!(CString::from_java(&env, file_name));
+    let file = jni_unwrap!(File::from_java(&env, file));
+    let ctx = gen_ctx!(env, o_cb);
+    dir_insert_file(
+        app,
+        &parent_info,
+        file_name.as_ptr(),
+        &file,
+        ctx,
+        call_CallbackResult,
+    );
+}
+
+#[link(name = "safe_app")]
+extern "C" {
+    fn dir_update_file(
+        app: *const App,
+        parent_info: *const MDataInfo,
+        file_name: *const c_char,
+        file: *const File,
+        version: u64,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void,
+                            result: *const FfiResult,
+                            new_version: u64),
+    );
+}
+#[no_mangle]
+pub unsafe extern "system" fn Java_net_maidsafe_safe_1app_NativeBindings_dirUpdateFile(
+    env: JNIEnv,
+    _class: JClass,
+    app: jlong,
+    parent_info: JObject,
+    file_name: JString,
+    file: JObject,
+    version: jlong,
+    o_cb: JObject,
+) {
+    let app = app as *mut App;
+    let parent_info = jni_unwrap!(MDataInfo::from_java(&env, parent_info));
+    let file_name = jni_unwrap!(CString::from_java(&env, file_name));
+    let file = jni_unwrap!(File::from_java(&env, file));
+    let ctx = gen_ctx!(env, o_cb);
+    dir_update_file(
+        app,
+        &parent_info,
+        file_name.as_ptr(),
+        &file,
+        version as u64,
+        ctx,
+        call_CallbackResultLong,
+    );
+}
+
+#[link(name = "safe_app")]
+extern "C" {
+    fn dir_delete_file(
+        app: *const App,
+        parent_info: *const MDataInfo,
+        file_name: *const c_char,
+        published: bool,
+        version: u64,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void,
+                            result: *const FfiResult,
+                            new_version: u64),
+    );
+}
+#[no_mangle]
+pub unsafe extern "system" fn Java_net_maidsafe_safe_1app_NativeBindings_dirDeleteFile(
+    env: JNIEnv,
+    _class: JClass,
+    app: jlong,
+    parent_info: JObject,
+    file_name: JString,
+    published: bool,
+    version: jlong,
+    o_cb: JObject,
+) {
+    let app = app as *mut App;
+    let parent_info = jni_unwrap!(MDataInfo::from_java(&env, parent_info));
+    let file_name = jni_unwrap!(CString::from_java(&env, file_name));
+    let ctx = gen_ctx!(env, o_cb);
+    dir_delete_file(
+        app,
+        &parent_info,
+        file_name.as_ptr(),
+        published as bool,
+        version as u64,
+        ctx,
+        call_CallbackResultLong,
+    );
+}
+extern "C" fn call_CallbackResultFileContextHandle(
+    ctx: *mut c_void,
+    result: *const FfiResult,
+    file_h: FileContextHandle,
+) {
+    unsafe {
+        let guard = jni_unwrap!(EnvGuard::new(JVM.as_ref()));
+        let env = guard.env();
+        let cb = jni_unwrap!(convert_cb_from_java(&env, ctx));
+        let result = if result.is_null() {
+            JObject::null()
+        } else {
+            jni_unwrap!((*result).to_java(&env))
+        };
+        let file_h = jni_unwrap!(file_h.to_java(&env));
+        jni_unwrap!(env.call_method(
+            cb.as_obj(),
+            "call",
+            "(Lnet/maidsafe/safe_app/FfiResult;J)V",
+            &[result.into(), file_h.into()],
+        ));
+    }
+}
+
+#[link(name = "safe_app")]
+extern "C" {
+    fn file_open(
+        app: *const App,
+        parent_info: *const MDataInfo,
+        file: *const File,
+        open_mode: u64,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void,
+                            result: *const FfiResult,
+                            file_h: FileContextHandle),
+    );
+}
+#[no_mangle]
+pub unsafe extern "system" fn Java_net_maidsafe_safe_1app_NativeBindings_fileOpen(
+    env: JNIEnv,
+    _class: JClass,
+    app: jlong,
+    parent_info: JObject,
+    file: JObject,
+    open_mode: jlong,
+    o_cb: JObject,
+) {
+    let app = app as *mut App;
+    let parent_info = jni_unwrap!(MDataInfo::from_java(&env, parent_info));
+    let file = jni_unwrap!(File::from_java(&env, file));
+    let ctx = gen_ctx!(env, o_cb);
+    file_open(
+        app,
+        &parent_info,
+        &file,
+        open_mode as u64,
+        ctx,
+        call_CallbackResultFileContextHandle,
+    );
+}
+
+#[link(name = "safe_app")]
+extern "C" {
+    fn file_size(
+        app: *const App,
+        file_h: FileContextHandle,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void, result: *const FfiResult, size: u64),
+    );
+}
+#[no_mangle]
+pub unsafe extern "system" fn Java_net_maidsafe_safe_1app_NativeBindings_fileSize(
+    env: JNIEnv,
+    _class: JClass,
+    app: jlong,
+    file_h: FileContextHandle,
+    o_cb: JObject,
+) {
+    let app = app as *mut App;
+    let ctx = gen_ctx!(env, o_cb);
+    file_size(
+        app,
+        file_h as FileContextHandle,
+        ctx,
+        call_CallbackResultLong,
+    );
+}
+
+#[link(name = "safe_app")]
+extern "C" {
+    fn file_read(
+        app: *const App,
+        file_h: FileContextHandle,
+        position: u64,
+        len: u64,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void,
+                            result: *const FfiResult,
+                            data: *const u8,
+                            data_len: usize),
+    );
+}
+#[no_mangle]
+pub unsafe extern "system" fn Java_net_maidsafe_safe_1app_NativeBindings_fileRead(
+    env: JNIEnv,
+    _class: JClass,
+    app: jlong,
+    file_h: FileContextHandle,
+    position: jlong,
+    len: jlong,
+    o_cb: JObject,
+) {
+    let app = app as *mut App;
+    let ctx = gen_ctx!(env, o_cb);
+    file_read(
+        app,
+        file_h as FileContextHandle,
+        position as u64,
+        len as u64,
+        ctx,
+        call_CallbackResultByteArrayLen,
+    );
+}
+
+#[link(name = "safe_app")]
+extern "C" {
+    fn file_write(
+        app: *const App,
+        file_h: FileContextHandle,
+        data: *const u8,
+        data_len: usize,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void, result: *const FfiResult),
+    );
+}
+#[no_mangle]
+pub unsafe extern "system" fn Java_net_maidsafe_safe_1app_NativeBindings_fileWrite(
+    env: JNIEnv,
+    _class: JClass,
+    app: jlong,
+    file_h: FileContextHandle,
+    data: JObject,
+    o_cb: JObject,
+) {
+    let app = app as *mut App;
+    let data = jni_unwrap!(Vec::from_java(&env, data));
+    let ctx = gen_ctx!(env, o_cb);
+    file_write(
+        app,
+        file_h as FileContextHandle,
+        data.as_ptr(),
+        data.len(),
+        ctx,
+        call_CallbackResult,
+    );
+}
+extern "C" fn call_CallbackResultFile(
+    ctx: *mut c_void,
+    result: *const FfiResult,
+    file: *const File,
+) {
+    unsafe {
+        let guard = jni_unwrap!(EnvGuard::new(JVM.as_ref()));
+        let env = guard.env();
+        let cb = jni_unwrap!(convert_cb_from_java(&env, ctx));
+        let result = if result.is_null() {
+            JObject::null()
+        } else {
+            jni_unwrap!((*result).to_java(&env))
+        };
+        let file = if file.is_null() {
+            JObject::null()
+        } else {
+            jni_unwrap!((*file).to_java(&env))
+        };
+        jni_unwrap!(env.call_method(
+            cb.as_obj(),
+            "call",
+            "(Lnet/maidsafe/safe_app/FfiResult;Lnet/maidsafe/safe_app/File;)V",
+            &[result.into(), file.into()],
+        ));
+    }
+}
+
+#[link(name = "safe_app")]
+extern "C" {
+    fn file_close(
+        app: *const App,
+        file_h: FileContextHandle,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void,
+                            result: *const FfiResult,
+                            file: *const File),
+    );
+}
+#[no_mangle]
+pub unsafe extern "system" fn Java_net_maidsafe_safe_1app_NativeBindings_fileClose(
+    env: JNIEnv,
+    _class: JClass,
+    app: jlong,
+    file_h: FileContextHandle,
+    o_cb: JObject,
+) {
+    let app = app as *mut App;
+    let ctx = gen_ctx!(env, o_cb);
+    file_close(
+        app,
+        file_h as FileContextHandle,
+        ctx,
+        call_CallbackResultFile,
+    );
+}
+extern "C" fn call_CallbackResultApp(ctx: *mut c_void, result: *const FfiResult, app: *mut App) {
+    unsafe {
+        let guard = jni_unwrap!(EnvGuard::new(JVM.as_ref()));
+        let env = guard.env();
+        let cb = jni_unwrap!(convert_cb_from_java(&env, ctx));
+        let result = if result.is_null() {
+            JObject::null()
+        } else {
+            jni_unwrap!((*result).to_java(&env))
+        };
+        let app = app as jlong;
+        jni_unwrap!(env.call_method(
+            cb.as_obj(),
+            "call",
+            "(Lnet/maidsafe/safe_app/FfiResult;J)V",
+            &[result.into(), app.into()],
+        ));
+    }
+}
+
+#[link(name = "safe_app")]
+extern "C" {
+    fn test_create_app(
+        app_id: *const c_char,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void,
+                            result: *const FfiResult,
+                            app: *mut App),
+    );
+}
+#[no_mangle]
+pub unsafe extern "system" fn Java_net_maidsafe_safe_1app_NativeBindings_testCreateApp(
+    env: JNIEnv,
+    _class: JClass,
+    app_id: JString,
+    o_cb: JObject,
+) {
+    let app_id = jni_unwrap!(CString::from_java(&env, app_id));
+    let ctx = gen_ctx!(env, o_cb);
+    test_create_app(app_id.as_ptr(), ctx, call_CallbackResultApp);
+}
+
+#[link(name = "safe_app")]
+extern "C" {
+    fn test_create_app_with_access(
+        auth_req: *const AuthReq,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void,
+                            result: *const FfiResult,
+                            o_app: *mut App),
+    );
+}
+#[no_mangle]
+pub unsafe extern "system" fn Java_net_maidsafe_safe_1app_NativeBindings_testCreateAppWithAccess(
+    env: JNIEnv,
+    _class: JClass,
+    auth_req: JObject,
+    o_cb: JObject,
+) {
+    let auth_req = jni_unwrap!(AuthReq::from_java(&env, auth_req));
+    let ctx = gen_ctx!(env, o_cb);
+    test_create_app_with_access(&auth_req, ctx, call_CallbackResultApp);
+}
+
+#[link(name = "safe_app")]
+extern "C" {
+    fn test_simulate_network_disconnect(
+        app: *mut App,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void, result: *const FfiResult),
+    );
+}
+#[cfg(feature = "mock-network")]
+#[no_mangle]pub unsafe extern "system" fn Java_net_maidsafe_safe_1app_NativeBindings_testSimulateNetworkDisconnect ( env : JNIEnv , _class : JClass , app : jlong , o_cb : JObject ){
+    let app = app as *mut App;
+    let ctx = gen_ctx!(env, o_cb);
+    test_simulate_network_disconnect(app, ctx, call_CallbackResult);
+}
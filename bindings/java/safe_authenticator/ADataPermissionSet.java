@@ -0,0 +1,50 @@
+// Copyright 2019 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT> or the Modified
+// BSD license <LICENSE-BSD or https://opensource.org/licenses/BSD-3-Clause>,
+// at your option. This file may not be copied, modified, or distributed
+// except according to those terms. Please review the Licences for the
+// specific language governing permissions and limitations relating to use
+// of the SAFE Network Software.
+package net.maidsafe.safe_authenticator;
+
+/// Represents a requested set of permissions for an append-only data.public class ADataPermissionSet {
+	private boolean read;
+	private boolean append;
+	private boolean managePermissions;
+
+	public ADataPermissionSet() {
+
+	}
+	public ADataPermissionSet(boolean read, boolean append, boolean managePermissions) {
+		this.read = read;
+		this.append = append;
+		this.managePermissions = managePermissions;
+	}
+	public boolean getRead() {
+		return read;
+	}
+
+	public void setRead(final boolean val) {
+		this.read = val;
+	}
+
+	public boolean getAppend() {
+		return append;
+	}
+
+	public void setAppend(final boolean val) {
+		this.append = val;
+	}
+
+	public boolean getManagePermission() {
+		return managePermissions;
+	}
+
+	public void setManagePermission(final boolean val) {
+		this.managePermissions = val;
+	}
+
+}
+
@@ -0,0 +1,90 @@
+// Copyright 2019 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT> or the Modified
+// BSD license <LICENSE-BSD or https://opensource.org/licenses/BSD-3-Clause>,
+// at your option. This file may not be copied, modified, or distributed
+// except according to those terms. Please review the Licences for the
+// specific language governing permissions and limitations relating to use
+// of the SAFE Network Software.
+package net.maidsafe.safe_authenticator;
+
+/// Spending of an app measured against its coin allowance. Amounts are in nano coins.public class AppAllowanceStatus {
+	private String appId;
+	private long limit;
+	private boolean daily;
+	private long spent;
+	private long remaining;
+	private boolean exhausted;
+	private boolean nearLimit;
+
+	public AppAllowanceStatus() {
+		this.appId = new String();
+	}
+	public AppAllowanceStatus(String appId, long limit, boolean daily, long spent, long remaining, boolean exhausted, boolean nearLimit) {
+		this.appId = appId;
+		this.limit = limit;
+		this.daily = daily;
+		this.spent = spent;
+		this.remaining = remaining;
+		this.exhausted = exhausted;
+		this.nearLimit = nearLimit;
+	}
+	public String getAppId() {
+		return appId;
+	}
+
+	public void setAppId(final String val) {
+		this.appId = val;
+	}
+
+	public long getLimit() {
+		return limit;
+	}
+
+	public void setLimit(final long val) {
+		this.limit = val;
+	}
+
+	public boolean getDaily() {
+		return daily;
+	}
+
+	public void setDaily(final boolean val) {
+		this.daily = val;
+	}
+
+	public long getSpent() {
+		return spent;
+	}
+
+	public void setSpent(final long val) {
+		this.spent = val;
+	}
+
+	public long getRemaining() {
+		return remaining;
+	}
+
+	public void setRemaining(final long val) {
+		this.remaining = val;
+	}
+
+	public boolean getExhausted() {
+		return exhausted;
+	}
+
+	public void setExhausted(final boolean val) {
+		this.exhausted = val;
+	}
+
+	public boolean getNearLimit() {
+		return nearLimit;
+	}
+
+	public void setNearLimit(final boolean val) {
+		this.nearLimit = val;
+	}
+
+}
+
@@ -0,0 +1,50 @@
+// Copyright 2019 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT> or the Modified
+// BSD license <LICENSE-BSD or https://opensource.org/licenses/BSD-3-Clause>,
+// at your option. This file may not be copied, modified, or distributed
+// except according to those terms. Please review the Licences for the
+// specific language governing permissions and limitations relating to use
+// of the SAFE Network Software.
+package net.maidsafe.safe_authenticator;
+
+/// Permission for Appspublic class AppPermissions {
+	private boolean transferCoins;
+	private boolean performMutations;
+	private boolean getBalance;
+
+	public AppPermissions() {
+
+	}
+	public AppPermissions(boolean transferCoins, boolean performMutations, boolean getBalance) {
+		this.transferCoins = transferCoins;
+		this.performMutations = performMutations;
+		this.getBalance = getBalance;
+	}
+	public boolean getTransferCoin() {
+		return transferCoins;
+	}
+
+	public void setTransferCoin(final boolean val) {
+		this.transferCoins = val;
+	}
+
+	public boolean getPerformMutation() {
+		return performMutations;
+	}
+
+	public void setPerformMutation(final boolean val) {
+		this.performMutations = val;
+	}
+
+	public boolean getGetBalance() {
+		return getBalance;
+	}
+
+	public void setGetBalance(final boolean val) {
+		this.getBalance = val;
+	}
+
+}
+
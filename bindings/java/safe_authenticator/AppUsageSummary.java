@@ -0,0 +1,60 @@
+// Copyright 2019 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT> or the Modified
+// BSD license <LICENSE-BSD or https://opensource.org/licenses/BSD-3-Clause>,
+// at your option. This file may not be copied, modified, or distributed
+// except according to those terms. Please review the Licences for the
+// specific language governing permissions and limitations relating to use
+// of the SAFE Network Software.
+package net.maidsafe.safe_authenticator;
+
+/// Resources consumed by an application registered in the authenticator.public class AppUsageSummary {
+	private AppExchangeInfo appInfo;
+	private long mutations;
+	private long bytes;
+	private long spent;
+
+	public AppUsageSummary() {
+		this.appInfo = new AppExchangeInfo();
+	}
+	public AppUsageSummary(AppExchangeInfo appInfo, long mutations, long bytes, long spent) {
+		this.appInfo = appInfo;
+		this.mutations = mutations;
+		this.bytes = bytes;
+		this.spent = spent;
+	}
+	public AppExchangeInfo getAppInfo() {
+		return appInfo;
+	}
+
+	public void setAppInfo(final AppExchangeInfo val) {
+		this.appInfo = val;
+	}
+
+	public long getMutation() {
+		return mutations;
+	}
+
+	public void setMutation(final long val) {
+		this.mutations = val;
+	}
+
+	public long getByte() {
+		return bytes;
+	}
+
+	public void setByte(final long val) {
+		this.bytes = val;
+	}
+
+	public long getSpent() {
+		return spent;
+	}
+
+	public void setSpent(final long val) {
+		this.spent = val;
+	}
+
+}
+
@@ -0,0 +1,61 @@
+// Copyright 2019 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT> or the Modified
+// BSD license <LICENSE-BSD or https://opensource.org/licenses/BSD-3-Clause>,
+// at your option. This file may not be copied, modified, or distributed
+// except according to those terms. Please review the Licences for the
+// specific language governing permissions and limitations relating to use
+// of the SAFE Network Software.
+package net.maidsafe.safe_authenticator;
+
+/// Entry of the audit log, recording a decision of the authenticator about an app.public class AuditEntry {
+	private long timestampSec;
+	private int timestampNsec;
+	private String appId;
+	private String decision;
+
+	public AuditEntry() {
+		this.appId = new String();
+		this.decision = new String();
+	}
+	public AuditEntry(long timestampSec, int timestampNsec, String appId, String decision) {
+		this.timestampSec = timestampSec;
+		this.timestampNsec = timestampNsec;
+		this.appId = appId;
+		this.decision = decision;
+	}
+	public long getTimestampSec() {
+		return timestampSec;
+	}
+
+	public void setTimestampSec(final long val) {
+		this.timestampSec = val;
+	}
+
+	public int getTimestampNsec() {
+		return timestampNsec;
+	}
+
+	public void setTimestampNsec(final int val) {
+		this.timestampNsec = val;
+	}
+
+	public String getAppId() {
+		return appId;
+	}
+
+	public void setAppId(final String val) {
+		this.appId = val;
+	}
+
+	public String getDecision() {
+		return decision;
+	}
+
+	public void setDecision(final String val) {
+		this.decision = val;
+	}
+
+}
+
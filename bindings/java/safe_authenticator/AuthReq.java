@@ -0,0 +1,122 @@
+// Copyright 2019 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT> or the Modified
+// BSD license <LICENSE-BSD or https://opensource.org/licenses/BSD-3-Clause>,
+// at your option. This file may not be copied, modified, or distributed
+// except according to those terms. Please review the Licences for the
+// specific language governing permissions and limitations relating to use
+// of the SAFE Network Software.
+package net.maidsafe.safe_authenticator;
+
+/// Represents an authorisation request.public class AuthReq {
+	private AppExchangeInfo app;
+	private boolean appContainer;
+	private boolean appPermissionTransferCoins;
+	private boolean appPermissionPerformMutations;
+	private boolean appPermissionGetBalance;
+	private ContainerPermissions[] containers;
+	private long containersLen;
+	private boolean sealResponse;
+	private BlsPublicKey responseKey;
+	private long ttl;
+
+	public AuthReq() {
+		this.app = new AppExchangeInfo();
+		this.containers = new ContainerPermissions[] {};
+		this.responseKey = new BlsPublicKey();
+	}
+	public AuthReq(AppExchangeInfo app, boolean appContainer, boolean appPermissionTransferCoins, boolean appPermissionPerformMutations, boolean appPermissionGetBalance, ContainerPermissions[] containers, long containersLen, boolean sealResponse, BlsPublicKey responseKey, long ttl) {
+		this.app = app;
+		this.appContainer = appContainer;
+		this.appPermissionTransferCoins = appPermissionTransferCoins;
+		this.appPermissionPerformMutations = appPermissionPerformMutations;
+		this.appPermissionGetBalance = appPermissionGetBalance;
+		this.containers = containers;
+		this.containersLen = containersLen;
+		this.sealResponse = sealResponse;
+		this.responseKey = responseKey;
+		this.ttl = ttl;
+	}
+	public AppExchangeInfo getApp() {
+		return app;
+	}
+
+	public void setApp(final AppExchangeInfo val) {
+		this.app = val;
+	}
+
+	public boolean getAppContainer() {
+		return appContainer;
+	}
+
+	public void setAppContainer(final boolean val) {
+		this.appContainer = val;
+	}
+
+	public boolean getAppPermissionTransferCoin() {
+		return appPermissionTransferCoins;
+	}
+
+	public void setAppPermissionTransferCoin(final boolean val) {
+		this.appPermissionTransferCoins = val;
+	}
+
+	public boolean getAppPermissionPerformMutation() {
+		return appPermissionPerformMutations;
+	}
+
+	public void setAppPermissionPerformMutation(final boolean val) {
+		this.appPermissionPerformMutations = val;
+	}
+
+	public boolean getAppPermissionGetBalance() {
+		return appPermissionGetBalance;
+	}
+
+	public void setAppPermissionGetBalance(final boolean val) {
+		this.appPermissionGetBalance = val;
+	}
+
+	public ContainerPermissions[] getContainer() {
+		return containers;
+	}
+
+	public void setContainer(final ContainerPermissions[] val) {
+		this.containers = val;
+	}
+
+	public long getContainersLen() {
+		return containersLen;
+	}
+
+	public void setContainersLen(final long val) {
+		this.containersLen = val;
+	}
+
+	public boolean getSealResponse() {
+		return sealResponse;
+	}
+
+	public void setSealResponse(final boolean val) {
+		this.sealResponse = val;
+	}
+
+	public BlsPublicKey getResponseKey() {
+		return responseKey;
+	}
+
+	public void setResponseKey(final BlsPublicKey val) {
+		this.responseKey = val;
+	}
+
+	public long getTtl() {
+		return ttl;
+	}
+
+	public void setTtl(final long val) {
+		this.ttl = val;
+	}
+
+}
+
@@ -0,0 +1,110 @@
+// Copyright 2019 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT> or the Modified
+// BSD license <LICENSE-BSD or https://opensource.org/licenses/BSD-3-Clause>,
+// at your option. This file may not be copied, modified, or distributed
+// except according to those terms. Please review the Licences for the
+// specific language governing permissions and limitations relating to use
+// of the SAFE Network Software.
+package net.maidsafe.safe_authenticator;
+
+/// Summary of what deleting the account removes, or would remove in case of a dry run.public class DeletionReport {
+	private long mdataCount;
+	private long idataCount;
+	private long keptMdataCount;
+	private long keptFilesCount;
+	private long authKeysCount;
+	private long balance;
+	private long loginPacketsCount;
+	private long adataCount;
+	private long keptAdataCount;
+
+	public DeletionReport() {
+
+	}
+	public DeletionReport(long mdataCount, long idataCount, long keptMdataCount, long keptFilesCount, long authKeysCount, long balance, long loginPacketsCount, long adataCount, long keptAdataCount) {
+		this.mdataCount = mdataCount;
+		this.idataCount = idataCount;
+		this.keptMdataCount = keptMdataCount;
+		this.keptFilesCount = keptFilesCount;
+		this.authKeysCount = authKeysCount;
+		this.balance = balance;
+		this.loginPacketsCount = loginPacketsCount;
+		this.adataCount = adataCount;
+		this.keptAdataCount = keptAdataCount;
+	}
+	public long getMdataCount() {
+		return mdataCount;
+	}
+
+	public void setMdataCount(final long val) {
+		this.mdataCount = val;
+	}
+
+	public long getIdataCount() {
+		return idataCount;
+	}
+
+	public void setIdataCount(final long val) {
+		this.idataCount = val;
+	}
+
+	public long getKeptMdataCount() {
+		return keptMdataCount;
+	}
+
+	public void setKeptMdataCount(final long val) {
+		this.keptMdataCount = val;
+	}
+
+	public long getKeptFilesCount() {
+		return keptFilesCount;
+	}
+
+	public void setKeptFilesCount(final long val) {
+		this.keptFilesCount = val;
+	}
+
+	public long getAuthKeysCount() {
+		return authKeysCount;
+	}
+
+	public void setAuthKeysCount(final long val) {
+		this.authKeysCount = val;
+	}
+
+	public long getBalance() {
+		return balance;
+	}
+
+	public void setBalance(final long val) {
+		this.balance = val;
+	}
+
+	public long getLoginPacketsCount() {
+		return loginPacketsCount;
+	}
+
+	public void setLoginPacketsCount(final long val) {
+		this.loginPacketsCount = val;
+	}
+
+	public long getAdataCount() {
+		return adataCount;
+	}
+
+	public void setAdataCount(final long val) {
+		this.adataCount = val;
+	}
+
+	public long getKeptAdataCount() {
+		return keptAdataCount;
+	}
+
+	public void setKeptAdataCount(final long val) {
+		this.keptAdataCount = val;
+	}
+
+}
+
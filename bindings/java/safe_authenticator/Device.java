@@ -0,0 +1,61 @@
+// Copyright 2019 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT> or the Modified
+// BSD license <LICENSE-BSD or https://opensource.org/licenses/BSD-3-Clause>,
+// at your option. This file may not be copied, modified, or distributed
+// except according to those terms. Please review the Licences for the
+// specific language governing permissions and limitations relating to use
+// of the SAFE Network Software.
+package net.maidsafe.safe_authenticator;
+
+/// Device registered to use the account.public class Device {
+	private byte[] id;
+	private String name;
+	private long registeredAtSec;
+	private long lastLoginSec;
+
+	public Device() {
+		this.id = new byte[] {};
+		this.name = new String();
+	}
+	public Device(byte[] id, String name, long registeredAtSec, long lastLoginSec) {
+		this.id = id;
+		this.name = name;
+		this.registeredAtSec = registeredAtSec;
+		this.lastLoginSec = lastLoginSec;
+	}
+	public byte[] getId() {
+		return id;
+	}
+
+	public void setId(final byte[] val) {
+		this.id = val;
+	}
+
+	public String getName() {
+		return name;
+	}
+
+	public void setName(final String val) {
+		this.name = val;
+	}
+
+	public long getRegisteredAtSec() {
+		return registeredAtSec;
+	}
+
+	public void setRegisteredAtSec(final long val) {
+		this.registeredAtSec = val;
+	}
+
+	public long getLastLoginSec() {
+		return lastLoginSec;
+	}
+
+	public void setLastLoginSec(final long val) {
+		this.lastLoginSec = val;
+	}
+
+}
+
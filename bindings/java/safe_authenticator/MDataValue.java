@@ -0,0 +1,50 @@
+// Copyright 2019 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT> or the Modified
+// BSD license <LICENSE-BSD or https://opensource.org/licenses/BSD-3-Clause>,
+// at your option. This file may not be copied, modified, or distributed
+// except according to those terms. Please review the Licences for the
+// specific language governing permissions and limitations relating to use
+// of the SAFE Network Software.
+package net.maidsafe.safe_authenticator;
+
+/// Represents the FFI-safe mutable data value.public class MDataValue {
+	private byte[] content;
+	private long contentLen;
+	private long entryVersion;
+
+	public MDataValue() {
+		this.content = new byte[] {};
+	}
+	public MDataValue(byte[] content, long contentLen, long entryVersion) {
+		this.content = content;
+		this.contentLen = contentLen;
+		this.entryVersion = entryVersion;
+	}
+	public byte[] getContent() {
+		return content;
+	}
+
+	public void setContent(final byte[] val) {
+		this.content = val;
+	}
+
+	public long getContentLen() {
+		return contentLen;
+	}
+
+	public void setContentLen(final long val) {
+		this.contentLen = val;
+	}
+
+	public long getEntryVersion() {
+		return entryVersion;
+	}
+
+	public void setEntryVersion(final long val) {
+		this.entryVersion = val;
+	}
+
+}
+
@@ -0,0 +1,181 @@
+// Copyright 2019 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT> or the Modified
+// BSD license <LICENSE-BSD or https://opensource.org/licenses/BSD-3-Clause>,
+// at your option. This file may not be copied, modified, or distributed
+// except according to those terms. Please review the Licences for the
+// specific language governing permissions and limitations relating to use
+// of the SAFE Network Software.
+package net.maidsafe.safe_authenticator;
+
+
+                         public class NativeBindings {
+
+                         	/**
+	 * Set up a new recovery phrase for the logged in account. A previously set up phrase stops * working. * * Callback parameters: user data, error code, recovery phrase */
+	public static native void authSetUpRecovery(long auth, CallbackResultString oCb);
+	
+	/**
+	 * Export a backup of the logged in account, encrypted with `passphrase`. The backup can be used * with `import_backup` to regain access to the account. * * Callback parameters: user data, error code, backup, backup size */
+	public static native void authExportBackup(long auth, String passphrase, CallbackResultByteArrayLen oCb);
+	
+	/**
+	 * Change the password of the logged in account. The current password is required to confirm * the change. */
+	public static native void authChangePassword(long auth, String currentPassword, String newPassword, CallbackResult oCb);
+	
+	/**
+	 * Move the logged in account to a new locator. The password is required to confirm the * change. */
+	public static native void authChangeLocator(long auth, String newLocator, String password, CallbackResult oCb);
+	
+	/**
+	 * Replace the client keys of the logged in account with new ones. Calling this after an * interrupted rotation resumes it. */
+	public static native void authRotateClientKeys(long auth, CallbackResult oCb);
+	
+	/**
+	 * Try to restore a failed connection with the network. */
+	public static native void authReconnect(long auth, CallbackResult oCb);
+	
+	/**
+	 * Returns the public key of the user's account, which signs sealed IPC responses. * * Apps given this key out of band can verify the responses with `open_sealed_ipc_msg`. */
+	public static native void authAccountPublicKey(long auth, CallbackResultBlsPublicKey oCb);
+	
+	/**
+	 * Returns the serialised public ID of the user's account, which `login_with_signer` needs to * log in before the account is fetched. */
+	public static native void authAccountPublicId(long auth, CallbackResultByteArrayLen oCb);
+	
+	/**
+	 * Sets the path from which the `safe_core.config` file will be read. */
+	public static native void authSetConfigDirPath(String newPath, CallbackResult oCb);
+	
+	/**
+	 * Discard and clean up the previously allocated authenticator instance. * Use this only if the authenticator is obtained from one of the auth * functions in this crate (`create_acc` or `login`). * Using `auth` after a call to this function is undefined behaviour. */
+	public static native void authFree(long auth);
+	
+	/**
+	 * Returns true if this crate was compiled against mock-routing. */
+	boolean authIsMock();
+	
+	/**
+	 * Remove a revoked app from the authenticator config. */
+	public static native void authRmRevokedApp(long auth, String appId, CallbackResult oCb);
+	
+	/**
+	 * Get a list of apps revoked from authenticator. */
+	public static native void authRevokedApps(long auth, CallbackResultAppExchangeInfoArrayLen oCb);
+	
+	/**
+	 * Get a list of apps registered in authenticator. */
+	public static native void authRegisteredApps(long auth, CallbackResultRegisteredAppArrayLen oCb);
+	
+	/**
+	 * Get the resources consumed by each app registered in the authenticator, including revoked * apps. * * Only available against the mock vault, as vaults don't attribute mutations to app keys yet. * * Callback parameters: user data, error code, app usage vector, vector size */
+	public static native void authAppsUsage(long auth, CallbackResultAppUsageSummaryArrayLen oCb);
+	
+	/**
+	 * Return a list of apps having access to an arbitrary MD object. * `md_name` and `md_type_tag` together correspond to a single MD. */
+	public static native void authAppsAccessingMutableData(long auth, byte[] mdName, long mdTypeTag, CallbackResultAppAccessArrayLen oCb);
+	
+	/**
+	 * Get the audit log of the decisions made by the authenticator, oldest first. * * Callback parameters: user data, error code, audit entries vector, vector size */
+	public static native void authAuditLog(long auth, CallbackResultAuditEntryArrayLen oCb);
+	
+	/**
+	 * Export the audit log of the decisions made by the authenticator as a JSON array, oldest * first. * * Callback parameters: user data, error code, JSON string */
+	public static native void authExportAuditLog(long auth, CallbackResultString oCb);
+	
+	/**
+	 * Grant a coin allowance of `limit` nano coins to a registered app, or change the limit of its * existing allowance. If `daily` is `true` the limit applies to each day, otherwise it applies * to everything the app spends from now on. The allowance is moved from the account's balance * to the app's allowance wallet, which is the only balance the app can spend from afterwards. */
+	public static native void authSetAppAllowance(long auth, String appId, long limit, boolean daily, CallbackResult oCb);
+	
+	/**
+	 * Remove the coin allowance of an app. Coins left in its allowance wallet are moved back to the * account's balance. */
+	public static native void authRmAppAllowance(long auth, String appId, CallbackResult oCb);
+	
+	/**
+	 * Get the spending of every app with a coin allowance, refilling any daily allowances that are * due first. Apps that are `near_limit` should be brought to the user's attention. * * Callback parameters: user data, error code, allowance statuses vector, vector size */
+	public static native void authAppAllowances(long auth, CallbackResultAppAllowanceStatusArrayLen oCb);
+	
+	/**
+	 * Get the transaction history of the account's coin balance, oldest first. * * Callback parameters: user data, error code, transaction records vector, vector size */
+	public static native void authTransactionHistory(long auth, CallbackResultTransactionRecordArrayLen oCb);
+	
+	/**
+	 * Delete the logged in account, transferring its coin balance to the balance at `destination`. * `password` must match the password the account is currently encrypted with. If `dry_run` is * `true`, nothing is changed and the report lists what would be deleted. The account can't be * logged into anymore once the deletion succeeded. * * Callback parameters: user data, error code, deletion report */
+	public static native void authDeleteAccount(long auth, String password, byte[] destination, boolean dryRun, CallbackResultDeletionReport oCb);
+	
+	/**
+	 * Register the device the authenticator runs on with the given name. The device key passed to * the callback has to be stored by the device, as it's needed to log in with `login_device`. * * Callback parameters: user data, error code, device key */
+	public static native void authRegisterDevice(long auth, String name, CallbackResultDeviceKey oCb);
+	
+	/**
+	 * Get the devices registered to use the account. * * Callback parameters: user data, error code, devices vector, vector size */
+	public static native void authDevices(long auth, CallbackResultDeviceArrayLen oCb);
+	
+	/**
+	 * Log out the device with the given ID, so that it can't log in with its device key anymore. * The client keys are rotated as well, ending the sessions the device has open. Other sessions * of the account, including other authenticators, have to log in again too. */
+	public static native void authLogOutDevice(long auth, byte[] deviceId, CallbackResult oCb);
+	
+	/**
+	 * Decodes a given encoded IPC message without requiring an authorised account. */
+	public static native void authUnregisteredDecodeIpcMsg(String msg, CallbackIntByteArrayLen oUnregistered, CallbackResultString oErr);
+	
+	/**
+	 * Decodes a given encoded IPC message and calls a corresponding callback. * * Only the requests this function has a callback for are decoded. Any other request is reported * through `o_err`; use `auth_decode_ipc_req` or `auth_decode_ipc_req_by_kind` to decode every kind * of request. */
+	public static native void authDecodeIpcMsg(long auth, String msg, CallbackIntAuthReq oAuth, CallbackIntContainersReq oContainers, CallbackIntByteArrayLen oUnregistered, CallbackIntShareMDataRequestMetadataResponseArrayLen oShareMdata, CallbackResultString oErr);
+	
+	/**
+	 * Decodes a given encoded IPC request and calls the callback of its kind. Decodes the same * requests as `auth_decode_ipc_req`, for bindings that can't read `DecodedIpcReq`. * * A callback is added here with every new kind of request; until then the request is reported * through `o_err`, as `auth_decode_ipc_msg` does. */
+	public static native void authDecodeIpcReqByKind(long auth, String msg, CallbackIntAuthReq oAuth, CallbackIntContainersReq oContainers, CallbackIntByteArrayLen oUnregistered, CallbackIntShareMDataRequestMetadataResponseArrayLen oShareMdata, CallbackIntShareADataRequest oShareAdata, CallbackIntTransOwnershipRequestOwnershipChangeArrayLen oTransOwnership, CallbackIntPermissionsReq oPermissions, CallbackResultString oErr);
+	
+	/**
+	 * Revoke app access. */
+	public static native void authRevokeApp(long auth, String appId, CallbackResultString oCb);
+	
+	/**
+	 * Flush the revocation queue. */
+	public static native void authFlushAppRevocationQueue(long auth, CallbackResult oCb);
+	
+	/**
+	 * Revoke the apps whose authorisation has expired. * * Should be called periodically while the user is logged in. */
+	public static native void authRevokeExpiredApps(long auth, CallbackResult oCb);
+	
+	/**
+	 * Encodes a response to unregistered client authentication request. * * The response is encoded in the legacy format, as unregistered requests are decoded without an * authenticator to remember their format. */
+	public static native void encodeUnregisteredResp(int reqId, boolean isGranted, CallbackResultString oCb);
+	
+	/**
+	 * Provides and encodes an Authenticator response. */
+	public static native void encodeAuthResp(long auth, AuthReq req, int reqId, boolean isGranted, CallbackResultString oCb);
+	
+	/**
+	 * Update containers permissions for an App. */
+	public static native void encodeContainersResp(long auth, ContainersReq req, int reqId, boolean isGranted, CallbackResultString oCb);
+	
+	/**
+	 * Modify the permissions of an app. */
+	public static native void encodePermissionsResp(long auth, PermissionsReq req, int reqId, boolean isGranted, CallbackResultString oCb);
+	
+	/**
+	 * Encode share mutable data response. */
+	public static native void encodeShareMdataResp(long auth, ShareMDataRequest req, int reqId, boolean isGranted, CallbackResultString oCb);
+	
+	/**
+	 * Encode share append-only data response. */
+	public static native void encodeShareAdataResp(long auth, ShareADataRequest req, int reqId, boolean isGranted, CallbackResultString oCb);
+	
+	/**
+	 * Encode transfer ownership response. */
+	public static native void encodeTransOwnershipResp(long auth, TransOwnershipRequest req, int reqId, boolean isGranted, CallbackResultString oCb);
+	
+	/**
+	 * This function should be called to enable logging to a file. * If `output_file_name_override` is provided, then this path will be used for * the log output file. */
+	public static native void authInitLogging(String outputFileNameOverride, CallbackResult oCb);
+	
+	/**
+	 * Returns the path at which the the configuration files are expected. */
+	public static native void authConfigDirPath(CallbackResultString oCb);
+	
+
+
+                         }
\ No newline at end of file
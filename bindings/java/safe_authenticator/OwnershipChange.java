@@ -0,0 +1,52 @@
+// Copyright 2019 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT> or the Modified
+// BSD license <LICENSE-BSD or https://opensource.org/licenses/BSD-3-Clause>,
+// at your option. This file may not be copied, modified, or distributed
+// except according to those terms. Please review the Licences for the
+// specific language governing permissions and limitations relating to use
+// of the SAFE Network Software.
+package net.maidsafe.safe_authenticator;
+
+/// Description of an ownership change, shown to the user before granting a transfer.public class OwnershipChange {
+	private BlsPublicKey currentOwner;
+	private BlsPublicKey newOwner;
+	private String description;
+
+	public OwnershipChange() {
+		this.currentOwner = new BlsPublicKey();
+		this.newOwner = new BlsPublicKey();
+		this.description = new String();
+	}
+	public OwnershipChange(BlsPublicKey currentOwner, BlsPublicKey newOwner, String description) {
+		this.currentOwner = currentOwner;
+		this.newOwner = newOwner;
+		this.description = description;
+	}
+	public BlsPublicKey getCurrentOwner() {
+		return currentOwner;
+	}
+
+	public void setCurrentOwner(final BlsPublicKey val) {
+		this.currentOwner = val;
+	}
+
+	public BlsPublicKey getNewOwner() {
+		return newOwner;
+	}
+
+	public void setNewOwner(final BlsPublicKey val) {
+		this.newOwner = val;
+	}
+
+	public String getDescription() {
+		return description;
+	}
+
+	public void setDescription(final String val) {
+		this.description = val;
+	}
+
+}
+
@@ -0,0 +1,51 @@
+// Copyright 2019 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT> or the Modified
+// BSD license <LICENSE-BSD or https://opensource.org/licenses/BSD-3-Clause>,
+// at your option. This file may not be copied, modified, or distributed
+// except according to those terms. Please review the Licences for the
+// specific language governing permissions and limitations relating to use
+// of the SAFE Network Software.
+package net.maidsafe.safe_authenticator;
+
+/// Represents a request to share append-only datapublic class ShareADataRequest {
+	private AppExchangeInfo app;
+	private ShareAData[] adata;
+	private long adataLen;
+
+	public ShareADataRequest() {
+		this.app = new AppExchangeInfo();
+		this.adata = new ShareAData[] {};
+	}
+	public ShareADataRequest(AppExchangeInfo app, ShareAData[] adata, long adataLen) {
+		this.app = app;
+		this.adata = adata;
+		this.adataLen = adataLen;
+	}
+	public AppExchangeInfo getApp() {
+		return app;
+	}
+
+	public void setApp(final AppExchangeInfo val) {
+		this.app = val;
+	}
+
+	public ShareAData[] getAdaum() {
+		return adata;
+	}
+
+	public void setAdaum(final ShareAData[] val) {
+		this.adata = val;
+	}
+
+	public long getAdataLen() {
+		return adataLen;
+	}
+
+	public void setAdataLen(final long val) {
+		this.adataLen = val;
+	}
+
+}
+
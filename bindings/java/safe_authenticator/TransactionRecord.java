@@ -0,0 +1,80 @@
+// Copyright 2019 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT> or the Modified
+// BSD license <LICENSE-BSD or https://opensource.org/licenses/BSD-3-Clause>,
+// at your option. This file may not be copied, modified, or distributed
+// except according to those terms. Please review the Licences for the
+// specific language governing permissions and limitations relating to use
+// of the SAFE Network Software.
+package net.maidsafe.safe_authenticator;
+
+/// FFI-wrapper for `TransactionRecord`.public class TransactionRecord {
+	private long id;
+	private boolean sent;
+	private byte[] counterparty;
+	private long amount;
+	private long timestampSec;
+	private int timestampNsec;
+
+	public TransactionRecord() {
+		this.counterparty = new byte[] {};
+	}
+	public TransactionRecord(long id, boolean sent, byte[] counterparty, long amount, long timestampSec, int timestampNsec) {
+		this.id = id;
+		this.sent = sent;
+		this.counterparty = counterparty;
+		this.amount = amount;
+		this.timestampSec = timestampSec;
+		this.timestampNsec = timestampNsec;
+	}
+	public long getId() {
+		return id;
+	}
+
+	public void setId(final long val) {
+		this.id = val;
+	}
+
+	public boolean getSent() {
+		return sent;
+	}
+
+	public void setSent(final boolean val) {
+		this.sent = val;
+	}
+
+	public byte[] getCounterparty() {
+		return counterparty;
+	}
+
+	public void setCounterparty(final byte[] val) {
+		this.counterparty = val;
+	}
+
+	public long getAmount() {
+		return amount;
+	}
+
+	public void setAmount(final long val) {
+		this.amount = val;
+	}
+
+	public long getTimestampSec() {
+		return timestampSec;
+	}
+
+	public void setTimestampSec(final long val) {
+		this.timestampSec = val;
+	}
+
+	public int getTimestampNsec() {
+		return timestampNsec;
+	}
+
+	public void setTimestampNsec(final int val) {
+		this.timestampNsec = val;
+	}
+
+}
+
@@ -0,0 +1,3732 @@
+// Copyright 2019 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT> or the Modified
+// BSD license <LICENSE-BSD or https://opensource.org/licenses/BSD-3-Clause>,
+// at your option. This file may not be copied, modified, or distributed
+// except according to those terms. Please review the Licences for the
+// specific language governing permissions and limitations relating to use
+// of the SAFE Network Software.
+impl<'a> FromJava<JObject<'a>> for MDataInfo {
+    fn from_java(env: &JNIEnv, input: JObject) -> Result<Self, JniError> {
+        let seq = env.get_field(input, "seq", "Z")?.z()? as bool;
+        let name = env.get_field(input, "name", "[B")?.l()?;
+        let name = XorNameArray::from_java(env, name)?;
+        let type_tag = env.get_field(input, "typeTag", "J")?.j()? as u64;
+        let has_enc_info = env.get_field(input, "hasEncInfo", "Z")?.z()? as bool;
+        let enc_key = env.get_field(input, "encKey", "[B")?.l()?;
+        let enc_key = SymSecretKey::from_java(env, enc_key)?;
+        let enc_nonce = env.get_field(input, "encNonce", "[B")?.l()?;
+        let enc_nonce = SymNonce::from_java(env, enc_nonce)?;
+        let has_new_enc_info = env.get_field(input, "hasNewEncInfo", "Z")?.z()? as bool;
+        let new_enc_key = env.get_field(input, "newEncKey", "[B")?.l()?;
+        let new_enc_key = SymSecretKey::from_java(env, new_enc_key)?;
+        let new_enc_nonce = env.get_field(input, "newEncNonce", "[B")?.l()?;
+        let new_enc_nonce = SymNonce::from_java(env, new_enc_nonce)?;
+        Ok(MDataInfo {
+            seq,
+            name,
+            type_tag,
+            has_enc_info,
+            enc_key,
+            enc_nonce,
+            has_new_enc_info,
+            new_enc_key,
+            new_enc_nonce,
+        })
+    }
+}
+impl<'a> ToJava<'a, JObject<'a>> for MDataInfo {
+    fn to_java(&self, env: &'a JNIEnv) -> Result<JObject<'a>, JniError> {
+        let cls = unsafe { find_class(env, "net/maidsafe/safe_authenticator/MDataInfo")? };
+        let output = env.new_object(&cls, "()V", &[])?;
+        let jobj = self.seq.to_java(env)?;
+        env.set_field(output, "seq", "Z", jobj.into())?;
+        let jobj = self.name.to_java(env)?;
+        env.set_field(output, "name", "[B", jobj.into())?;
+        let jobj = self.type_tag.to_java(env)?;
+        env.set_field(output, "typeTag", "J", jobj.into())?;
+        let jobj = self.has_enc_info.to_java(env)?;
+        env.set_field(output, "hasEncInfo", "Z", jobj.into())?;
+        let jobj = self.enc_key.to_java(env)?;
+        env.set_field(output, "encKey", "[B", jobj.into())?;
+        let jobj = self.enc_nonce.to_java(env)?;
+        env.set_field(output, "encNonce", "[B", jobj.into())?;
+        let jobj = self.has_new_enc_info.to_java(env)?;
+        env.set_field(output, "hasNewEncInfo", "Z", jobj.into())?;
+        let jobj = self.new_enc_key.to_java(env)?;
+        env.set_field(output, "newEncKey", "[B", jobj.into())?;
+        let jobj = self.new_enc_nonce.to_java(env)?;
+        env.set_field(output, "newEncNonce", "[B", jobj.into())?;
+        Ok(output)
+    }
+}
+impl<'a> FromJava<JObject<'a>> for TransactionRecord {
+    fn from_java(env: &JNIEnv, input: JObject) -> Result<Self, JniError> {
+        let id = env.get_field(input, "id", "J")?.j()? as u64;
+        let sent = env.get_field(input, "sent", "Z")?.z()? as bool;
+        let counterparty = env.get_field(input, "counterparty", "[B")?.l()?;
+        let counterparty = XorNameArray::from_java(env, counterparty)?;
+        let amount = env.get_field(input, "amount", "J")?.j()? as u64;
+        let timestamp_sec = env.get_field(input, "timestampSec", "J")?.j()? as i64;
+        let timestamp_nsec = env.get_field(input, "timestampNsec", "I")?.i()? as u32;
+        Ok(TransactionRecord {
+            id,
+            sent,
+            counterparty,
+            amount,
+            timestamp_sec,
+            timestamp_nsec,
+        })
+    }
+}
+impl<'a> ToJava<'a, JObject<'a>> for TransactionRecord {
+    fn to_java(&self, env: &'a JNIEnv) -> Result<JObject<'a>, JniError> {
+        let cls = unsafe { find_class(env, "net/maidsafe/safe_authenticator/TransactionRecord")? };
+        let output = env.new_object(&cls, "()V", &[])?;
+        let jobj = self.id.to_java(env)?;
+        env.set_field(output, "id", "J", jobj.into())?;
+        let jobj = self.sent.to_java(env)?;
+        env.set_field(output, "sent", "Z", jobj.into())?;
+        let jobj = self.counterparty.to_java(env)?;
+        env.set_field(output, "counterparty", "[B", jobj.into())?;
+        let jobj = self.amount.to_java(env)?;
+        env.set_field(output, "amount", "J", jobj.into())?;
+        let jobj = self.timestamp_sec.to_java(env)?;
+        env.set_field(output, "timestampSec", "J", jobj.into())?;
+        let jobj = self.timestamp_nsec.to_java(env)?;
+        env.set_field(output, "timestampNsec", "I", jobj.into())?;
+        Ok(output)
+    }
+}
+impl<'a> FromJava<JObject<'a>> for PermissionSet {
+    fn from_java(env: &JNIEnv, input: JObject) -> Result<Self, JniError> {
+        let read = env.get_field(input, "read", "Z")?.z()? as bool;
+        let insert = env.get_field(input, "insert", "Z")?.z()? as bool;
+        let update = env.get_field(input, "update", "Z")?.z()? as bool;
+        let delete = env.get_field(input, "delete", "Z")?.z()? as bool;
+        let manage_permissions = env.get_field(input, "managePermissions", "Z")?.z()? as bool;
+        Ok(PermissionSet {
+            read,
+            insert,
+            update,
+            delete,
+            manage_permissions,
+        })
+    }
+}
+impl<'a> ToJava<'a, JObject<'a>> for PermissionSet {
+    fn to_java(&self, env: &'a JNIEnv) -> Result<JObject<'a>, JniError> {
+        let cls = unsafe { find_class(env, "net/maidsafe/safe_authenticator/PermissionSet")? };
+        let output = env.new_object(&cls, "()V", &[])?;
+        let jobj = self.read.to_java(env)?;
+        env.set_field(output, "read", "Z", jobj.into())?;
+        let jobj = self.insert.to_java(env)?;
+        env.set_field(output, "insert", "Z", jobj.into())?;
+        let jobj = self.update.to_java(env)?;
+        env.set_field(output, "update", "Z", jobj.into())?;
+        let jobj = self.delete.to_java(env)?;
+        env.set_field(output, "delete", "Z", jobj.into())?;
+        let jobj = self.manage_permissions.to_java(env)?;
+        env.set_field(output, "managePermissions", "Z", jobj.into())?;
+        Ok(output)
+    }
+}
+impl<'a> FromJava<JObject<'a>> for AppExchangeInfo {
+    fn from_java(env: &JNIEnv, input: JObject) -> Result<Self, JniError> {
+        let id = env.get_field(input, "id", "Ljava/lang/String;")?
+            .l()?
+            .into();
+        let id = <*mut _>::from_java(env, id)?;
+        let scope = env.get_field(input, "scope", "Ljava/lang/String;")?
+            .l()?
+            .into();
+        let scope = <*mut _>::from_java(env, scope)?;
+        let name = env.get_field(input, "name", "Ljava/lang/String;")?
+            .l()?
+            .into();
+        let name = <*mut _>::from_java(env, name)?;
+        let vendor = env.get_field(input, "vendor", "Ljava/lang/String;")?
+            .l()?
+            .into();
+        let vendor = <*mut _>::from_java(env, vendor)?;
+        Ok(AppExchangeInfo {
+            id,
+            scope,
+            name,
+            vendor,
+        })
+    }
+}
+impl<'a> ToJava<'a, JObject<'a>> for AppExchangeInfo {
+    fn to_java(&self, env: &'a JNIEnv) -> Result<JObject<'a>, JniError> {
+        let cls = unsafe { find_class(env, "net/maidsafe/safe_authenticator/AppExchangeInfo")? };
+        let output = env.new_object(&cls, "()V", &[])?;
+        if !self.id.is_null() {
+            let id: JObject = self.id.to_java(env)?.into();
+            env.set_field(output, "id", "Ljava/lang/String;", id.into())?;
+            env.delete_local_ref(id)?;
+        }
+        if !self.scope.is_null() {
+            let scope: JObject = self.scope.to_java(env)?.into();
+            env.set_field(
+                output,
+                "scope",
+                "Ljava/lang/String;",
+                scope.into(),
+            )?;
+            env.delete_local_ref(scope)?;
+        }
+        if !self.name.is_null() {
+            let name: JObject = self.name.to_java(env)?.into();
+            env.set_field(
+                output,
+                "name",
+                "Ljava/lang/String;",
+                name.into(),
+            )?;
+            env.delete_local_ref(name)?;
+        }
+        if !self.vendor.is_null() {
+            let vendor: JObject = self.vendor.to_java(env)?.into();
+            env.set_field(
+                output,
+                "vendor",
+                "Ljava/lang/String;",
+                vendor.into(),
+            )?;
+            env.delete_local_ref(vendor)?;
+        }
+        Ok(output)
+    }
+}
+impl<'a> FromJava<JObject<'a>> for ContainerPermissions {
+    fn from_java(env: &JNIEnv, input: JObject) -> Result<Self, JniError> {
+        let cont_name = env.get_field(input, "contName", "Ljava/lang/String;")?
+            .l()?
+            .into();
+        let cont_name = <*mut _>::from_java(env, cont_name)?;
+        let access = env.get_field(
+            input,
+            "access",
+            "Lnet/maidsafe/safe_authenticator/PermissionSet;",
+        )?
+            .l()?;
+        let access = PermissionSet::from_java(env, access)?;
+        Ok(ContainerPermissions { cont_name, access })
+    }
+}
+impl<'a> ToJava<'a, JObject<'a>> for ContainerPermissions {
+    fn to_java(&self, env: &'a JNIEnv) -> Result<JObject<'a>, JniError> {
+        let cls =
+            unsafe { find_class(env, "net/maidsafe/safe_authenticator/ContainerPermissions")? };
+        let output = env.new_object(&cls, "()V", &[])?;
+        if !self.cont_name.is_null() {
+            let cont_name: JObject = self.cont_name.to_java(env)?.into();
+            env.set_field(
+                output,
+                "contName",
+                "Ljava/lang/String;",
+                cont_name.into(),
+            )?;
+            env.delete_local_ref(cont_name)?;
+        }
+        let jobj = self.access.to_java(env)?;
+        env.set_field(
+            output,
+            "access",
+            "Lnet/maidsafe/safe_authenticator/PermissionSet;",
+            jobj.into(),
+        )?;
+        env.delete_local_ref(jobj)?;
+        Ok(output)
+    }
+}
+impl<'a> FromJava<JObject<'a>> for AuthReq {
+    fn from_java(env: &JNIEnv, input: JObject) -> Result<Self, JniError> {
+        let app = env.get_field(
+            input,
+            "app",
+            "Lnet/maidsafe/safe_authenticator/AppExchangeInfo;",
+        )?
+            .l()?;
+        let app = AppExchangeInfo::from_java(env, app)?;
+        let app_container = env.get_field(input, "appContainer", "Z")?.z()? as bool;
+        let app_permission_transfer_coins =
+            env.get_field(input, "appPermissionTransferCoins", "Z")?.z()? as bool;
+        let app_permission_perform_mutations =
+            env.get_field(input, "appPermissionPerformMutations", "Z")?
+                .z()? as bool;
+        let app_permission_get_balance = env.get_field(input, "appPermissionGetBalance", "Z")?
+            .z()? as bool;
+        let arr = env.get_field(
+            input,
+            "containers",
+            "[Lnet/maidsafe/safe_authenticator/ContainerPermissions;",
+        )?
+            .l()?
+            .into_inner() as jni::sys::jarray;
+        let containers_len = env.get_array_length(arr)? as usize;
+        let mut vec = Vec::with_capacity(containers_len);
+        for idx in 0..containers_len {
+            let item = env.get_object_array_element(arr, idx as jni::sys::jsize);
+            let item = ContainerPermissions::from_java(env, item?)?;
+            vec.push(item);
+        }
+        let containers = vec.as_ptr();
+        ::std::mem::forget(vec);
+        let seal_response = env.get_field(input, "sealResponse", "Z")?.z()? as bool;
+        let response_key = env.get_field(
+            input,
+            "responseKey",
+            "Lnet/maidsafe/safe_authenticator/BlsPublicKey;",
+        )?
+            .l()?;
+        let response_key = BlsPublicKey::from_java(env, response_key)?;
+        let ttl = env.get_field(input, "ttl", "J")?.j()? as u64;
+        Ok(AuthReq {
+            app,
+            app_container,
+            app_permission_transfer_coins,
+            app_permission_perform_mutations,
+            app_permission_get_balance,
+            containers,
+            containers_len,
+            seal_response,
+            response_key,
+            ttl,
+        })
+    }
+}
+impl<'a> ToJava<'a, JObject<'a>> for AuthReq {
+    fn to_java(&self, env: &'a JNIEnv) -> Result<JObject<'a>, JniError> {
+        let cls = unsafe { find_class(env, "net/maidsafe/safe_authenticator/AuthReq")? };
+        let output = env.new_object(&cls, "()V", &[])?;
+        let jobj = self.app.to_java(env)?;
+        env.set_field(
+            output,
+            "app",
+            "Lnet/maidsafe/safe_authenticator/AppExchangeInfo;",
+            jobj.into(),
+        )?;
+        env.delete_local_ref(jobj)?;
+        let jobj = self.app_container.to_java(env)?;
+        env.set_field(output, "appContainer", "Z", jobj.into())?;
+        let jobj = self.app_permission_transfer_coins.to_java(env)?;
+        env.set_field(
+            output,
+            "appPermissionTransferCoins",
+            "Z",
+            jobj.into(),
+        )?;
+        let jobj = self.app_permission_perform_mutations.to_java(env)?;
+        env.set_field(
+            output,
+            "appPermissionPerformMutations",
+            "Z",
+            jobj.into(),
+        )?;
+        let jobj = self.app_permission_get_balance.to_java(env)?;
+        env.set_field(
+            output,
+            "appPermissionGetBalance",
+            "Z",
+            jobj.into(),
+        )?;
+        let cls =
+            unsafe { find_class(env, "net/maidsafe/safe_authenticator/ContainerPermissions")? };
+        let arr = env.new_object_array(
+            self.containers_len as jni::sys::jsize,
+            &cls,
+            JObject::null(),
+        )?;
+        let items = unsafe { slice::from_raw_parts(self.containers, self.containers_len) };
+        for (idx, item) in items.iter().enumerate() {
+            let jobj = item.to_java(env)?;
+            env.set_object_array_element(
+                arr,
+                idx as jni::sys::jsize,
+                jobj,
+            )?;
+            env.delete_local_ref(jobj)?;
+        }
+        let jobj = JObject::from(arr);
+        env.set_field(
+            output,
+            "containers",
+            "[Lnet/maidsafe/safe_authenticator/ContainerPermissions;",
+            jobj.into(),
+        )?;
+        env.delete_local_ref(jobj)?;
+        env.set_field(
+            output,
+            "containersLen",
+            "J",
+            self.containers_len.to_java(env)?.into(),
+        )?;
+        let jobj = self.seal_response.to_java(env)?;
+        env.set_field(output, "sealResponse", "Z", jobj.into())?;
+        let jobj = self.response_key.to_java(env)?;
+        env.set_field(
+            output,
+            "responseKey",
+            "Lnet/maidsafe/safe_authenticator/BlsPublicKey;",
+            jobj.into(),
+        )?;
+        env.delete_local_ref(jobj)?;
+        let jobj = self.ttl.to_java(env)?;
+        env.set_field(output, "ttl", "J", jobj.into())?;
+        Ok(output)
+    }
+}
+impl<'a> FromJava<JObject<'a>> for ContainersReq {
+    fn from_java(env: &JNIEnv, input: JObject) -> Result<Self, JniError> {
+        let app = env.get_field(
+            input,
+            "app",
+            "Lnet/maidsafe/safe_authenticator/AppExchangeInfo;",
+        )?
+            .l()?;
+        let app = AppExchangeInfo::from_java(env, app)?;
+        let arr = env.get_field(
+            input,
+            "containers",
+            "[Lnet/maidsafe/safe_authenticator/ContainerPermissions;",
+        )?
+            .l()?
+            .into_inner() as jni::sys::jarray;
+        let containers_len = env.get_array_length(arr)? as usize;
+        let mut vec = Vec::with_capacity(containers_len);
+        for idx in 0..containers_len {
+            let item = env.get_object_array_element(arr, idx as jni::sys::jsize);
+            let item = ContainerPermissions::from_java(env, item?)?;
+            vec.push(item);
+        }
+        let containers = vec.as_ptr();
+        ::std::mem::forget(vec);
+        Ok(ContainersReq {
+            app,
+            containers,
+            containers_len,
+        })
+    }
+}
+impl<'a> ToJava<'a, JObject<'a>> for ContainersReq {
+    fn to_java(&self, env: &'a JNIEnv) -> Result<JObject<'a>, JniError> {
+        let cls = unsafe { find_class(env, "net/maidsafe/safe_authenticator/ContainersReq")? };
+        let output = env.new_object(&cls, "()V", &[])?;
+        let jobj = self.app.to_java(env)?;
+        env.set_field(
+            output,
+            "app",
+            "Lnet/maidsafe/safe_authenticator/AppExchangeInfo;",
+            jobj.into(),
+        )?;
+        env.delete_local_ref(jobj)?;
+        let cls =
+            unsafe { find_class(env, "net/maidsafe/safe_authenticator/ContainerPermissions")? };
+        let arr = env.new_object_array(
+            self.containers_len as jni::sys::jsize,
+            &cls,
+            JObject::null(),
+        )?;
+        let items = unsafe { slice::from_raw_parts(self.containers, self.containers_len) };
+        for (idx, item) in items.iter().enumerate() {
+            let jobj = item.to_java(env)?;
+            env.set_object_array_element(
+                arr,
+                idx as jni::sys::jsize,
+                jobj,
+            )?;
+            env.delete_local_ref(jobj)?;
+        }
+        let jobj = JObject::from(arr);
+        env.set_field(
+            output,
+            "containers",
+            "[Lnet/maidsafe/safe_authenticator/ContainerPermissions;",
+            jobj.into(),
+        )?;
+        env.delete_local_ref(jobj)?;
+        env.set_field(
+            output,
+            "containersLen",
+            "J",
+            self.containers_len.to_java(env)?.into(),
+        )?;
+        Ok(output)
+    }
+}
+impl<'a> FromJava<JObject<'a>> for PermissionsReq {
+    fn from_java(env: &JNIEnv, input: JObject) -> Result<Self, JniError> {
+        let app = env.get_field(
+            input,
+            "app",
+            "Lnet/maidsafe/safe_authenticator/AppExchangeInfo;",
+        )?
+            .l()?;
+        let app = AppExchangeInfo::from_java(env, app)?;
+        let arr = env.get_field(
+            input,
+            "containers",
+            "[Lnet/maidsafe/safe_authenticator/ContainerPermissions;",
+        )?
+            .l()?
+            .into_inner() as jni::sys::jarray;
+        let containers_len = env.get_array_length(arr)? as usize;
+        let mut vec = Vec::with_capacity(containers_len);
+        for idx in 0..containers_len {
+            let item = env.get_object_array_element(arr, idx as jni::sys::jsize);
+            let item = ContainerPermissions::from_java(env, item?)?;
+            vec.push(item);
+        }
+        let containers = vec.as_ptr();
+        ::std::mem::forget(vec);
+        let update_app_permissions = env.get_field(input, "updateAppPermissions", "Z")?.z()? as
+            bool;
+        let app_permission_transfer_coins =
+            env.get_field(input, "appPermissionTransferCoins", "Z")?.z()? as bool;
+        let app_permission_perform_mutations =
+            env.get_field(input, "appPermissionPerformMutations", "Z")?
+                .z()? as bool;
+        let app_permission_get_balance = env.get_field(input, "appPermissionGetBalance", "Z")?
+            .z()? as bool;
+        Ok(PermissionsReq {
+            app,
+            containers,
+            containers_len,
+            update_app_permissions,
+            app_permission_transfer_coins,
+            app_permission_perform_mutations,
+            app_permission_get_balance,
+        })
+    }
+}
+impl<'a> ToJava<'a, JObject<'a>> for PermissionsReq {
+    fn to_java(&self, env: &'a JNIEnv) -> Result<JObject<'a>, JniError> {
+        let cls = unsafe { find_class(env, "net/maidsafe/safe_authenticator/PermissionsReq")? };
+        let output = env.new_object(&cls, "()V", &[])?;
+        let jobj = self.app.to_java(env)?;
+        env.set_field(
+            output,
+            "app",
+            "Lnet/maidsafe/safe_authenticator/AppExchangeInfo;",
+            jobj.into(),
+        )?;
+        env.delete_local_ref(jobj)?;
+        let cls =
+            unsafe { find_class(env, "net/maidsafe/safe_authenticator/ContainerPermissions")? };
+        let arr = env.new_object_array(
+            self.containers_len as jni::sys::jsize,
+            &cls,
+            JObject::null(),
+        )?;
+        let items = unsafe { slice::from_raw_parts(self.containers, self.containers_len) };
+        for (idx, item) in items.iter().enumerate() {
+            let jobj = item.to_java(env)?;
+            env.set_object_array_element(
+                arr,
+                idx as jni::sys::jsize,
+                jobj,
+            )?;
+            env.delete_local_ref(jobj)?;
+        }
+        let jobj = JObject::from(arr);
+        env.set_field(
+            output,
+            "containers",
+            "[Lnet/maidsafe/safe_authenticator/ContainerPermissions;",
+            jobj.into(),
+        )?;
+        env.delete_local_ref(jobj)?;
+        env.set_field(
+            output,
+            "containersLen",
+            "J",
+            self.containers_len.to_java(env)?.into(),
+        )?;
+        let jobj = self.update_app_permissions.to_java(env)?;
+        env.set_field(
+            output,
+            "updateAppPermissions",
+            "Z",
+            jobj.into(),
+        )?;
+        let jobj = self.app_permission_transfer_coins.to_java(env)?;
+        env.set_field(
+            output,
+            "appPermissionTransferCoins",
+            "Z",
+            jobj.into(),
+        )?;
+        let jobj = self.app_permission_perform_mutations.to_java(env)?;
+        env.set_field(
+            output,
+            "appPermissionPerformMutations",
+            "Z",
+            jobj.into(),
+        )?;
+        let jobj = self.app_permission_get_balance.to_java(env)?;
+        env.set_field(
+            output,
+            "appPermissionGetBalance",
+            "Z",
+            jobj.into(),
+        )?;
+        Ok(output)
+    }
+}
+impl<'a> FromJava<JObject<'a>> for ShareMData {
+    fn from_java(env: &JNIEnv, input: JObject) -> Result<Self, JniError> {
+        let type_tag = env.get_field(input, "typeTag", "J")?.j()? as u64;
+        let name = env.get_field(input, "name", "[B")?.l()?;
+        let name = XorNameArray::from_java(env, name)?;
+        let perms = env.get_field(
+            input,
+            "perms",
+            "Lnet/maidsafe/safe_authenticator/PermissionSet;",
+        )?
+            .l()?;
+        let perms = PermissionSet::from_java(env, perms)?;
+        Ok(ShareMData {
+            type_tag,
+            name,
+            perms,
+        })
+    }
+}
+impl<'a> ToJava<'a, JObject<'a>> for ShareMData {
+    fn to_java(&self, env: &'a JNIEnv) -> Result<JObject<'a>, JniError> {
+        let cls = unsafe { find_class(env, "net/maidsafe/safe_authenticator/ShareMData")? };
+        let output = env.new_object(&cls, "()V", &[])?;
+        let jobj = self.type_tag.to_java(env)?;
+        env.set_field(output, "typeTag", "J", jobj.into())?;
+        let jobj = self.name.to_java(env)?;
+        env.set_field(output, "name", "[B", jobj.into())?;
+        let jobj = self.perms.to_java(env)?;
+        env.set_field(
+            output,
+            "perms",
+            "Lnet/maidsafe/safe_authenticator/PermissionSet;",
+            jobj.into(),
+        )?;
+        env.delete_local_ref(jobj)?;
+        Ok(output)
+    }
+}
+impl<'a> FromJava<JObject<'a>> for ShareMDataRequest {
+    fn from_java(env: &JNIEnv, input: JObject) -> Result<Self, JniError> {
+        let app = env.get_field(
+            input,
+            "app",
+            "Lnet/maidsafe/safe_authenticator/AppExchangeInfo;",
+        )?
+            .l()?;
+        let app = AppExchangeInfo::from_java(env, app)?;
+        let arr = env.get_field(
+            input,
+            "mdata",
+            "[Lnet/maidsafe/safe_authenticator/ShareMData;",
+        )?
+            .l()?
+            .into_inner() as jni::sys::jarray;
+        let mdata_len = env.get_array_length(arr)? as usize;
+        let mut vec = Vec::with_capacity(mdata_len);
+        for idx in 0..mdata_len {
+            let item = env.get_object_array_element(arr, idx as jni::sys::jsize);
+            let item = ShareMData::from_java(env, item?)?;
+            vec.push(item);
+        }
+        let mdata = vec.as_ptr();
+        ::std::mem::forget(vec);
+        Ok(ShareMDataRequest {
+            app,
+            mdata,
+            mdata_len,
+        })
+    }
+}
+impl<'a> ToJava<'a, JObject<'a>> for ShareMDataRequest {
+    fn to_java(&self, env: &'a JNIEnv) -> Result<JObject<'a>, JniError> {
+        let cls = unsafe { find_class(env, "net/maidsafe/safe_authenticator/ShareMDataRequest")? };
+        let output = env.new_object(&cls, "()V", &[])?;
+        let jobj = self.app.to_java(env)?;
+        env.set_field(
+            output,
+            "app",
+            "Lnet/maidsafe/safe_authenticator/AppExchangeInfo;",
+            jobj.into(),
+        )?;
+        env.delete_local_ref(jobj)?;
+        let cls = unsafe { find_class(env, "net/maidsafe/safe_authenticator/ShareMData")? };
+        let arr = env.new_object_array(
+            self.mdata_len as jni::sys::jsize,
+            &cls,
+            JObject::null(),
+        )?;
+        let items = unsafe { slice::from_raw_parts(self.mdata, self.mdata_len) };
+        for (idx, item) in items.iter().enumerate() {
+            let jobj = item.to_java(env)?;
+            env.set_object_array_element(
+                arr,
+                idx as jni::sys::jsize,
+                jobj,
+            )?;
+            env.delete_local_ref(jobj)?;
+        }
+        let jobj = JObject::from(arr);
+        env.set_field(
+            output,
+            "mdata",
+            "[Lnet/maidsafe/safe_authenticator/ShareMData;",
+            jobj.into(),
+        )?;
+        env.delete_local_ref(jobj)?;
+        env.set_field(
+            output,
+            "mdataLen",
+            "J",
+            self.mdata_len.to_java(env)?.into(),
+        )?;
+        Ok(output)
+    }
+}
+impl<'a> FromJava<JObject<'a>> for ADataPermissionSet {
+    fn from_java(env: &JNIEnv, input: JObject) -> Result<Self, JniError> {
+        let read = env.get_field(input, "read", "Z")?.z()? as bool;
+        let append = env.get_field(input, "append", "Z")?.z()? as bool;
+        let manage_permissions = env.get_field(input, "managePermissions", "Z")?.z()? as bool;
+        Ok(ADataPermissionSet {
+            read,
+            append,
+            manage_permissions,
+        })
+    }
+}
+impl<'a> ToJava<'a, JObject<'a>> for ADataPermissionSet {
+    fn to_java(&self, env: &'a JNIEnv) -> Result<JObject<'a>, JniError> {
+        let cls = unsafe { find_class(env, "net/maidsafe/safe_authenticator/ADataPermissionSet")? };
+        let output = env.new_object(&cls, "()V", &[])?;
+        let jobj = self.read.to_java(env)?;
+        env.set_field(output, "read", "Z", jobj.into())?;
+        let jobj = self.append.to_java(env)?;
+        env.set_field(output, "append", "Z", jobj.into())?;
+        let jobj = self.manage_permissions.to_java(env)?;
+        env.set_field(output, "managePermissions", "Z", jobj.into())?;
+        Ok(output)
+    }
+}
+impl<'a> FromJava<JObject<'a>> for ShareAData {
+    fn from_java(env: &JNIEnv, input: JObject) -> Result<Self, JniError> {
+        let name = env.get_field(input, "name", "[B")?.l()?;
+        let name = XorNameArray::from_java(env, name)?;
+        let type_tag = env.get_field(input, "typeTag", "J")?.j()? as u64;
+        let published = env.get_field(input, "published", "Z")?.z()? as bool;
+        let sequenced = env.get_field(input, "sequenced", "Z")?.z()? as bool;
+        let perms = env.get_field(
+            input,
+            "perms",
+            "Lnet/maidsafe/safe_authenticator/ADataPermissionSet;",
+        )?
+            .l()?;
+        let perms = ADataPermissionSet::from_java(env, perms)?;
+        Ok(ShareAData {
+            name,
+            type_tag,
+            published,
+            sequenced,
+            perms,
+        })
+    }
+}
+impl<'a> ToJava<'a, JObject<'a>> for ShareAData {
+    fn to_java(&self, env: &'a JNIEnv) -> Result<JObject<'a>, JniError> {
+        let cls = unsafe { find_class(env, "net/maidsafe/safe_authenticator/ShareAData")? };
+        let output = env.new_object(&cls, "()V", &[])?;
+        let jobj = self.name.to_java(env)?;
+        env.set_field(output, "name", "[B", jobj.into())?;
+        let jobj = self.type_tag.to_java(env)?;
+        env.set_field(output, "typeTag", "J", jobj.into())?;
+        let jobj = self.published.to_java(env)?;
+        env.set_field(output, "published", "Z", jobj.into())?;
+        let jobj = self.sequenced.to_java(env)?;
+        env.set_field(output, "sequenced", "Z", jobj.into())?;
+        let jobj = self.perms.to_java(env)?;
+        env.set_field(
+            output,
+            "perms",
+            "Lnet/maidsafe/safe_authenticator/ADataPermissionSet;",
+            jobj.into(),
+        )?;
+        env.delete_local_ref(jobj)?;
+        Ok(output)
+    }
+}
+impl<'a> FromJava<JObject<'a>> for ShareADataRequest {
+    fn from_java(env: &JNIEnv, input: JObject) -> Result<Self, JniError> {
+        let app = env.get_field(
+            input,
+            "app",
+            "Lnet/maidsafe/safe_authenticator/AppExchangeInfo;",
+        )?
+            .l()?;
+        let app = AppExchangeInfo::from_java(env, app)?;
+        let arr = env.get_field(
+            input,
+            "adata",
+            "[Lnet/maidsafe/safe_authenticator/ShareAData;",
+        )?
+            .l()?
+            .into_inner() as jni::sys::jarray;
+        let adata_len = env.get_array_length(arr)? as usize;
+        let mut vec = Vec::with_capacity(adata_len);
+        for idx in 0..adata_len {
+            let item = env.get_object_array_element(arr, idx as jni::sys::jsize);
+            let item = ShareAData::from_java(env, item?)?;
+            vec.push(item);
+        }
+        let adata = vec.as_ptr();
+        ::std::mem::forget(vec);
+        Ok(ShareADataRequest {
+            app,
+            adata,
+            adata_len,
+        })
+    }
+}
+impl<'a> ToJava<'a, JObject<'a>> for ShareADataRequest {
+    fn to_java(&self, env: &'a JNIEnv) -> Result<JObject<'a>, JniError> {
+        let cls = unsafe { find_class(env, "net/maidsafe/safe_authenticator/ShareADataRequest")? };
+        let output = env.new_object(&cls, "()V", &[])?;
+        let jobj = self.app.to_java(env)?;
+        env.set_field(
+            output,
+            "app",
+            "Lnet/maidsafe/safe_authenticator/AppExchangeInfo;",
+            jobj.into(),
+        )?;
+        env.delete_local_ref(jobj)?;
+        let cls = unsafe { find_class(env, "net/maidsafe/safe_authenticator/ShareAData")? };
+        let arr = env.new_object_array(
+            self.adata_len as jni::sys::jsize,
+            &cls,
+            JObject::null(),
+        )?;
+        let items = unsafe { slice::from_raw_parts(self.adata, self.adata_len) };
+        for (idx, item) in items.iter().enumerate() {
+            let jobj = item.to_java(env)?;
+            env.set_object_array_element(
+                arr,
+                idx as jni::sys::jsize,
+                jobj,
+            )?;
+            env.delete_local_ref(jobj)?;
+        }
+        let jobj = JObject::from(arr);
+        env.set_field(
+            output,
+            "adata",
+            "[Lnet/maidsafe/safe_authenticator/ShareAData;",
+            jobj.into(),
+        )?;
+        env.delete_local_ref(jobj)?;
+        env.set_field(
+            output,
+            "adataLen",
+            "J",
+            self.adata_len.to_java(env)?.into(),
+        )?;
+        Ok(output)
+    }
+}
+impl<'a> FromJava<JObject<'a>> for TransOwnership {
+    fn from_java(env: &JNIEnv, input: JObject) -> Result<Self, JniError> {
+        let name = env.get_field(input, "name", "[B")?.l()?;
+        let name = XorNameArray::from_java(env, name)?;
+        let type_tag = env.get_field(input, "typeTag", "J")?.j()? as u64;
+        let append_only = env.get_field(input, "appendOnly", "Z")?.z()? as bool;
+        let published = env.get_field(input, "published", "Z")?.z()? as bool;
+        let sequenced = env.get_field(input, "sequenced", "Z")?.z()? as bool;
+        let new_owner = env.get_field(
+            input,
+            "newOwner",
+            "Lnet/maidsafe/safe_authenticator/BlsPublicKey;",
+        )?
+            .l()?;
+        let new_owner = BlsPublicKey::from_java(env, new_owner)?;
+        Ok(TransOwnership {
+            name,
+            type_tag,
+            append_only,
+            published,
+            sequenced,
+            new_owner,
+        })
+    }
+}
+impl<'a> ToJava<'a, JObject<'a>> for TransOwnership {
+    fn to_java(&self, env: &'a JNIEnv) -> Result<JObject<'a>, JniError> {
+        let cls = unsafe { find_class(env, "net/maidsafe/safe_authenticator/TransOwnership")? };
+        let output = env.new_object(&cls, "()V", &[])?;
+        let jobj = self.name.to_java(env)?;
+        env.set_field(output, "name", "[B", jobj.into())?;
+        let jobj = self.type_tag.to_java(env)?;
+        env.set_field(output, "typeTag", "J", jobj.into())?;
+        let jobj = self.append_only.to_java(env)?;
+        env.set_field(output, "appendOnly", "Z", jobj.into())?;
+        let jobj = self.published.to_java(env)?;
+        env.set_field(output, "published", "Z", jobj.into())?;
+        let jobj = self.sequenced.to_java(env)?;
+        env.set_field(output, "sequenced", "Z", jobj.into())?;
+        let jobj = self.new_owner.to_java(env)?;
+        env.set_field(
+            output,
+            "newOwner",
+            "Lnet/maidsafe/safe_authenticator/BlsPublicKey;",
+            jobj.into(),
+        )?;
+        env.delete_local_ref(jobj)?;
+        Ok(output)
+    }
+}
+impl<'a> FromJava<JObject<'a>> for TransOwnershipRequest {
+    fn from_java(env: &JNIEnv, input: JObject) -> Result<Self, JniError> {
+        let app = env.get_field(
+            input,
+            "app",
+            "Lnet/maidsafe/safe_authenticator/AppExchangeInfo;",
+        )?
+            .l()?;
+        let app = AppExchangeInfo::from_java(env, app)?;
+        let arr = env.get_field(
+            input,
+            "data",
+            "[Lnet/maidsafe/safe_authenticator/TransOwnership;",
+        )?
+            .l()?
+            .into_inner() as jni::sys::jarray;
+        let data_len = env.get_array_length(arr)? as usize;
+        let mut vec = Vec::with_capacity(data_len);
+        for idx in 0..data_len {
+            let item = env.get_object_array_element(arr, idx as jni::sys::jsize);
+            let item = TransOwnership::from_java(env, item?)?;
+            vec.push(item);
+        }
+        let data = vec.as_ptr();
+        ::std::mem::forget(vec);
+        Ok(TransOwnershipRequest {
+            app,
+            data,
+            data_len,
+        })
+    }
+}
+impl<'a> ToJava<'a, JObject<'a>> for TransOwnershipRequest {
+    fn to_java(&self, env: &'a JNIEnv) -> Result<JObject<'a>, JniError> {
+        let cls =
+            unsafe { find_class(env, "net/maidsafe/safe_authenticator/TransOwnershipRequest")? };
+        let output = env.new_object(&cls, "()V", &[])?;
+        let jobj = self.app.to_java(env)?;
+        env.set_field(
+            output,
+            "app",
+            "Lnet/maidsafe/safe_authenticator/AppExchangeInfo;",
+            jobj.into(),
+        )?;
+        env.delete_local_ref(jobj)?;
+        let cls = unsafe { find_class(env, "net/maidsafe/safe_authenticator/TransOwnership")? };
+        let arr = env.new_object_array(
+            self.data_len as jni::sys::jsize,
+            &cls,
+            JObject::null(),
+        )?;
+        let items = unsafe { slice::from_raw_parts(self.data, self.data_len) };
+        for (idx, item) in items.iter().enumerate() {
+            let jobj = item.to_java(env)?;
+            env.set_object_array_element(
+                arr,
+                idx as jni::sys::jsize,
+                jobj,
+            )?;
+            env.delete_local_ref(jobj)?;
+        }
+        let jobj = JObject::from(arr);
+        env.set_field(
+            output,
+            "data",
+            "[Lnet/maidsafe/safe_authenticator/TransOwnership;",
+            jobj.into(),
+        )?;
+        env.delete_local_ref(jobj)?;
+        env.set_field(
+            output,
+            "dataLen",
+            "J",
+            self.data_len.to_java(env)?.into(),
+        )?;
+        Ok(output)
+    }
+}
+impl<'a> FromJava<JObject<'a>> for AppKeys {
+    fn from_java(env: &JNIEnv, input: JObject) -> Result<Self, JniError> {
+        let arr = env.get_field(input, "fullId", "[B")?.l()?.into_inner() as jni::sys::jbyteArray;
+        let mut vec = env.convert_byte_array(arr)?;
+        let full_id_len = vec.len();
+        let full_id = vec.as_ptr();
+        ::std::mem::forget(vec);
+        let enc_key = env.get_field(input, "encKey", "[B")?.l()?;
+        let enc_key = SymSecretKey::from_java(env, enc_key)?;
+        let enc_public_key = env.get_field(input, "encPublicKey", "[B")?.l()?;
+        let enc_public_key = AsymPublicKey::from_java(env, enc_public_key)?;
+        let arr = env.get_field(input, "encSecretKey", "[B")?
+            .l()?
+            .into_inner() as jni::sys::jbyteArray;
+        let mut vec = env.convert_byte_array(arr)?;
+        let enc_secret_key_len = vec.len();
+        let enc_secret_key = vec.as_ptr();
+        ::std::mem::forget(vec);
+        Ok(AppKeys {
+            full_id,
+            full_id_len,
+            enc_key,
+            enc_public_key,
+            enc_secret_key,
+            enc_secret_key_len,
+        })
+    }
+}
+impl<'a> ToJava<'a, JObject<'a>> for AppKeys {
+    fn to_java(&self, env: &'a JNIEnv) -> Result<JObject<'a>, JniError> {
+        let cls = unsafe { find_class(env, "net/maidsafe/safe_authenticator/AppKeys")? };
+        let output = env.new_object(&cls, "()V", &[])?;
+        let arr = env.new_byte_array(self.full_id_len as jni::sys::jsize)?;
+        let slice = unsafe { slice::from_raw_parts(self.full_id as *const i8, self.full_id_len) };
+        env.set_byte_array_region(arr, 0, slice)?;
+        let jobj = JObject::from(arr);
+        env.set_field(output, "fullId", "[B", jobj.into())?;
+        env.delete_local_ref(jobj)?;
+        env.set_field(
+            output,
+            "fullIdLen",
+            "J",
+            self.full_id_len.to_java(env)?.into(),
+        )?;
+        let jobj = self.enc_key.to_java(env)?;
+        env.set_field(output, "encKey", "[B", jobj.into())?;
+        let jobj = self.enc_public_key.to_java(env)?;
+        env.set_field(output, "encPublicKey", "[B", jobj.into())?;
+        let arr = env.new_byte_array(
+            self.enc_secret_key_len as jni::sys::jsize,
+        )?;
+        let slice = unsafe {
+            slice::from_raw_parts(self.enc_secret_key as *const i8, self.enc_secret_key_len)
+        };
+        env.set_byte_array_region(arr, 0, slice)?;
+        let jobj = JObject::from(arr);
+        env.set_field(output, "encSecretKey", "[B", jobj.into())?;
+        env.delete_local_ref(jobj)?;
+        env.set_field(
+            output,
+            "encSecretKeyLen",
+            "J",
+            self.enc_secret_key_len.to_java(env)?.into(),
+        )?;
+        Ok(output)
+    }
+}
+impl<'a> FromJava<JObject<'a>> for AccessContInfo {
+    fn from_java(env: &JNIEnv, input: JObject) -> Result<Self, JniError> {
+        let id = env.get_field(input, "id", "[B")?.l()?;
+        let id = XorNameArray::from_java(env, id)?;
+        let tag = env.get_field(input, "tag", "J")?.j()? as u64;
+        let nonce = env.get_field(input, "nonce", "[B")?.l()?;
+        let nonce = SymNonce::from_java(env, nonce)?;
+        Ok(AccessContInfo { id, tag, nonce })
+    }
+}
+impl<'a> ToJava<'a, JObject<'a>> for AccessContInfo {
+    fn to_java(&self, env: &'a JNIEnv) -> Result<JObject<'a>, JniError> {
+        let cls = unsafe { find_class(env, "net/maidsafe/safe_authenticator/AccessContInfo")? };
+        let output = env.new_object(&cls, "()V", &[])?;
+        let jobj = self.id.to_java(env)?;
+        env.set_field(output, "id", "[B", jobj.into())?;
+        let jobj = self.tag.to_java(env)?;
+        env.set_field(output, "tag", "J", jobj.into())?;
+        let jobj = self.nonce.to_java(env)?;
+        env.set_field(output, "nonce", "[B", jobj.into())?;
+        Ok(output)
+    }
+}
+impl<'a> FromJava<JObject<'a>> for ContainerInfo {
+    fn from_java(env: &JNIEnv, input: JObject) -> Result<Self, JniError> {
+        let name = env.get_field(input, "name", "Ljava/lang/String;")?
+            .l()?
+            .into();
+        let name = <*mut _>::from_java(env, name)?;
+        let mdata_info = env.get_field(
+            input,
+            "mdataInfo",
+            "Lnet/maidsafe/safe_authenticator/MDataInfo;",
+        )?
+            .l()?;
+        let mdata_info = MDataInfo::from_java(env, mdata_info)?;
+        let permissions = env.get_field(
+            input,
+            "permissions",
+            "Lnet/maidsafe/safe_authenticator/PermissionSet;",
+        )?
+            .l()?;
+        let permissions = PermissionSet::from_java(env, permissions)?;
+        Ok(ContainerInfo {
+            name,
+            mdata_info,
+            permissions,
+        })
+    }
+}
+impl<'a> ToJava<'a, JObject<'a>> for ContainerInfo {
+    fn to_java(&self, env: &'a JNIEnv) -> Result<JObject<'a>, JniError> {
+        let cls = unsafe { find_class(env, "net/maidsafe/safe_authenticator/ContainerInfo")? };
+        let output = env.new_object(&cls, "()V", &[])?;
+        if !self.name.is_null() {
+            let name: JObject = self.name.to_java(env)?.into();
+            env.set_field(
+                output,
+                "name",
+                "Ljava/lang/String;",
+                name.into(),
+            )?;
+            env.delete_local_ref(name)?;
+        }
+        let jobj = self.mdata_info.to_java(env)?;
+        env.set_field(
+            output,
+            "mdataInfo",
+            "Lnet/maidsafe/safe_authenticator/MDataInfo;",
+            jobj.into(),
+        )?;
+        env.delete_local_ref(jobj)?;
+        let jobj = self.permissions.to_java(env)?;
+        env.set_field(
+            output,
+            "permissions",
+            "Lnet/maidsafe/safe_authenticator/PermissionSet;",
+            jobj.into(),
+        )?;
+        env.delete_local_ref(jobj)?;
+        Ok(output)
+    }
+}
+impl<'a> FromJava<JObject<'a>> for AccessContainerEntry {
+    fn from_java(env: &JNIEnv, input: JObject) -> Result<Self, JniError> {
+        let arr = env.get_field(
+            input,
+            "containers",
+            "[Lnet/maidsafe/safe_authenticator/ContainerInfo;",
+        )?
+            .l()?
+            .into_inner() as jni::sys::jarray;
+        let containers_len = env.get_array_length(arr)? as usize;
+        let mut vec = Vec::with_capacity(containers_len);
+        for idx in 0..containers_len {
+            let item = env.get_object_array_element(arr, idx as jni::sys::jsize);
+            let item = ContainerInfo::from_java(env, item?)?;
+            vec.push(item);
+        }
+        let containers = vec.as_ptr();
+        ::std::mem::forget(vec);
+        Ok(AccessContainerEntry {
+            containers,
+            containers_len,
+        })
+    }
+}
+impl<'a> ToJava<'a, JObject<'a>> for AccessContainerEntry {
+    fn to_java(&self, env: &'a JNIEnv) -> Result<JObject<'a>, JniError> {
+        let cls =
+            unsafe { find_class(env, "net/maidsafe/safe_authenticator/AccessContainerEntry")? };
+        let output = env.new_object(&cls, "()V", &[])?;
+        let cls = unsafe { find_class(env, "net/maidsafe/safe_authenticator/ContainerInfo")? };
+        let arr = env.new_object_array(
+            self.containers_len as jni::sys::jsize,
+            &cls,
+            JObject::null(),
+        )?;
+        let items = unsafe { slice::from_raw_parts(self.containers, self.containers_len) };
+        for (idx, item) in items.iter().enumerate() {
+            let jobj = item.to_java(env)?;
+            env.set_object_array_element(
+                arr,
+                idx as jni::sys::jsize,
+                jobj,
+            )?;
+            env.delete_local_ref(jobj)?;
+        }
+        let jobj = JObject::from(arr);
+        env.set_field(
+            output,
+            "containers",
+            "[Lnet/maidsafe/safe_authenticator/ContainerInfo;",
+            jobj.into(),
+        )?;
+        env.delete_local_ref(jobj)?;
+        env.set_field(
+            output,
+            "containersLen",
+            "J",
+            self.containers_len.to_java(env)?.into(),
+        )?;
+        Ok(output)
+    }
+}
+impl<'a> FromJava<JObject<'a>> for AuthGranted {
+    fn from_java(env: &JNIEnv, input: JObject) -> Result<Self, JniError> {
+        let app_keys = env.get_field(
+            input,
+            "appKeys",
+            "Lnet/maidsafe/safe_authenticator/AppKeys;",
+        )?
+            .l()?;
+        let app_keys = AppKeys::from_java(env, app_keys)?;
+        let access_container_info = env.get_field(
+            input,
+            "accessContainerInfo",
+            "Lnet/maidsafe/safe_authenticator/AccessContInfo;",
+        )?
+            .l()?;
+        let access_container_info = AccessContInfo::from_java(env, access_container_info)?;
+        let access_container_entry = env.get_field(
+            input,
+            "accessContainerEntry",
+            "Lnet/maidsafe/safe_authenticator/AccessContainerEntry;",
+        )?
+            .l()?;
+        let access_container_entry = AccessContainerEntry::from_java(env, access_container_entry)?;
+        let arr = env.get_field(input, "bootstrapConfig", "[B")?
+            .l()?
+            .into_inner() as jni::sys::jbyteArray;
+        let mut vec = env.convert_byte_array(arr)?;
+        let bootstrap_config_len = vec.len();
+        let bootstrap_config = vec.as_ptr();
+        ::std::mem::forget(vec);
+        Ok(AuthGranted {
+            app_keys,
+            access_container_info,
+            access_container_entry,
+            bootstrap_config,
+            bootstrap_config_len,
+        })
+    }
+}
+impl<'a> ToJava<'a, JObject<'a>> for AuthGranted {
+    fn to_java(&self, env: &'a JNIEnv) -> Result<JObject<'a>, JniError> {
+        let cls = unsafe { find_class(env, "net/maidsafe/safe_authenticator/AuthGranted")? };
+        let output = env.new_object(&cls, "()V", &[])?;
+        let jobj = self.app_keys.to_java(env)?;
+        env.set_field(
+            output,
+            "appKeys",
+            "Lnet/maidsafe/safe_authenticator/AppKeys;",
+            jobj.into(),
+        )?;
+        env.delete_local_ref(jobj)?;
+        let jobj = self.access_container_info.to_java(env)?;
+        env.set_field(
+            output,
+            "accessContainerInfo",
+            "Lnet/maidsafe/safe_authenticator/AccessContInfo;",
+            jobj.into(),
+        )?;
+        env.delete_local_ref(jobj)?;
+        let jobj = self.access_container_entry.to_java(env)?;
+        env.set_field(
+            output,
+            "accessContainerEntry",
+            "Lnet/maidsafe/safe_authenticator/AccessContainerEntry;",
+            jobj.into(),
+        )?;
+        env.delete_local_ref(jobj)?;
+        let arr = env.new_byte_array(
+            self.bootstrap_config_len as jni::sys::jsize,
+        )?;
+        let slice = unsafe {
+            slice::from_raw_parts(
+                self.bootstrap_config as *const i8,
+                self.bootstrap_config_len,
+            )
+        };
+        env.set_byte_array_region(arr, 0, slice)?;
+        let jobj = JObject::from(arr);
+        env.set_field(output, "bootstrapConfig", "[B", jobj.into())?;
+        env.delete_local_ref(jobj)?;
+        env.set_field(
+            output,
+            "bootstrapConfigLen",
+            "J",
+            self.bootstrap_config_len.to_java(env)?.into(),
+        )?;
+        Ok(output)
+    }
+}
+impl<'a> FromJava<JObject<'a>> for AppAccess {
+    fn from_java(env: &JNIEnv, input: JObject) -> Result<Self, JniError> {
+        let sign_key = env.get_field(
+            input,
+            "signKey",
+            "Lnet/maidsafe/safe_authenticator/BlsPublicKey;",
+        )?
+            .l()?;
+        let sign_key = BlsPublicKey::from_java(env, sign_key)?;
+        let permissions = env.get_field(
+            input,
+            "permissions",
+            "Lnet/maidsafe/safe_authenticator/PermissionSet;",
+        )?
+            .l()?;
+        let permissions = PermissionSet::from_java(env, permissions)?;
+        let name = env.get_field(input, "name", "Ljava/lang/String;")?
+            .l()?
+            .into();
+        let name = <*mut _>::from_java(env, name)?;
+        let app_id = env.get_field(input, "appId", "Ljava/lang/String;")?
+            .l()?
+            .into();
+        let app_id = <*mut _>::from_java(env, app_id)?;
+        Ok(AppAccess {
+            sign_key,
+            permissions,
+            name,
+            app_id,
+        })
+    }
+}
+impl<'a> ToJava<'a, JObject<'a>> for AppAccess {
+    fn to_java(&self, env: &'a JNIEnv) -> Result<JObject<'a>, JniError> {
+        let cls = unsafe { find_class(env, "net/maidsafe/safe_authenticator/AppAccess")? };
+        let output = env.new_object(&cls, "()V", &[])?;
+        let jobj = self.sign_key.to_java(env)?;
+        env.set_field(
+            output,
+            "signKey",
+            "Lnet/maidsafe/safe_authenticator/BlsPublicKey;",
+            jobj.into(),
+        )?;
+        env.delete_local_ref(jobj)?;
+        let jobj = self.permissions.to_java(env)?;
+        env.set_field(
+            output,
+            "permissions",
+            "Lnet/maidsafe/safe_authenticator/PermissionSet;",
+            jobj.into(),
+        )?;
+        env.delete_local_ref(jobj)?;
+        if !self.name.is_null() {
+            let name: JObject = self.name.to_java(env)?.into();
+            env.set_field(
+                output,
+                "name",
+                "Ljava/lang/String;",
+                name.into(),
+            )?;
+            env.delete_local_ref(name)?;
+        }
+        if !self.app_id.is_null() {
+            let app_id: JObject = self.app_id.to_java(env)?.into();
+            env.set_field(
+                output,
+                "appId",
+                "Ljava/lang/String;",
+                app_id.into(),
+            )?;
+            env.delete_local_ref(app_id)?;
+        }
+        Ok(output)
+    }
+}
+impl<'a> FromJava<JObject<'a>> for MetadataResponse {
+    fn from_java(env: &JNIEnv, input: JObject) -> Result<Self, JniError> {
+        let name = env.get_field(input, "name", "Ljava/lang/String;")?
+            .l()?
+            .into();
+        let name = <*mut _>::from_java(env, name)?;
+        let description = env.get_field(input, "description", "Ljava/lang/String;")?
+            .l()?
+            .into();
+        let description = <*mut _>::from_java(env, description)?;
+        let xor_name = env.get_field(input, "xorName", "[B")?.l()?;
+        let xor_name = XorNameArray::from_java(env, xor_name)?;
+        let type_tag = env.get_field(input, "typeTag", "J")?.j()? as u64;
+        Ok(MetadataResponse {
+            name,
+            description,
+            xor_name,
+            type_tag,
+        })
+    }
+}
+impl<'a> ToJava<'a, JObject<'a>> for MetadataResponse {
+    fn to_java(&self, env: &'a JNIEnv) -> Result<JObject<'a>, JniError> {
+        let cls = unsafe { find_class(env, "net/maidsafe/safe_authenticator/MetadataResponse")? };
+        let output = env.new_object(&cls, "()V", &[])?;
+        if !self.name.is_null() {
+            let name: JObject = self.name.to_java(env)?.into();
+            env.set_field(
+                output,
+                "name",
+                "Ljava/lang/String;",
+                name.into(),
+            )?;
+            env.delete_local_ref(name)?;
+        }
+        if !self.description.is_null() {
+            let description: JObject = self.description.to_java(env)?.into();
+            env.set_field(
+                output,
+                "description",
+                "Ljava/lang/String;",
+                description.into(),
+            )?;
+            env.delete_local_ref(description)?;
+        }
+        let jobj = self.xor_name.to_java(env)?;
+        env.set_field(output, "xorName", "[B", jobj.into())?;
+        let jobj = self.type_tag.to_java(env)?;
+        env.set_field(output, "typeTag", "J", jobj.into())?;
+        Ok(output)
+    }
+}
+impl<'a> FromJava<JObject<'a>> for MDataKey {
+    fn from_java(env: &JNIEnv, input: JObject) -> Result<Self, JniError> {
+        let arr = env.get_field(input, "key", "[B")?.l()?.into_inner() as jni::sys::jbyteArray;
+        let mut vec = env.convert_byte_array(arr)?;
+        let key_len = vec.len();
+        let key = vec.as_ptr();
+        ::std::mem::forget(vec);
+        Ok(MDataKey { key, key_len })
+    }
+}
+impl<'a> ToJava<'a, JObject<'a>> for MDataKey {
+    fn to_java(&self, env: &'a JNIEnv) -> Result<JObject<'a>, JniError> {
+        let cls = unsafe { find_class(env, "net/maidsafe/safe_authenticator/MDataKey")? };
+        let output = env.new_object(&cls, "()V", &[])?;
+        let arr = env.new_byte_array(self.key_len as jni::sys::jsize)?;
+        let slice = unsafe { slice::from_raw_parts(self.key as *const i8, self.key_len) };
+        env.set_byte_array_region(arr, 0, slice)?;
+        let jobj = JObject::from(arr);
+        env.set_field(output, "key", "[B", jobj.into())?;
+        env.delete_local_ref(jobj)?;
+        env.set_field(
+            output,
+            "keyLen",
+            "J",
+            self.key_len.to_java(env)?.into(),
+        )?;
+        Ok(output)
+    }
+}
+impl<'a> FromJava<JObject<'a>> for MDataValue {
+    fn from_java(env: &JNIEnv, input: JObject) -> Result<Self, JniError> {
+        let arr = env.get_field(input, "content", "[B")?.l()?.into_inner() as jni::sys::jbyteArray;
+        let mut vec = env.convert_byte_array(arr)?;
+        let content_len = vec.len();
+        let content = vec.as_ptr();
+        ::std::mem::forget(vec);
+        let entry_version = env.get_field(input, "entryVersion", "J")?.j()? as u64;
+        Ok(MDataValue {
+            content,
+            content_len,
+            entry_version,
+        })
+    }
+}
+impl<'a> ToJava<'a, JObject<'a>> for MDataValue {
+    fn to_java(&self, env: &'a JNIEnv) -> Result<JObject<'a>, JniError> {
+        let cls = unsafe { find_class(env, "net/maidsafe/safe_authenticator/MDataValue")? };
+        let output = env.new_object(&cls, "()V", &[])?;
+        let arr = env.new_byte_array(self.content_len as jni::sys::jsize)?;
+        let slice = unsafe { slice::from_raw_parts(self.content as *const i8, self.content_len) };
+        env.set_byte_array_region(arr, 0, slice)?;
+        let jobj = JObject::from(arr);
+        env.set_field(output, "content", "[B", jobj.into())?;
+        env.delete_local_ref(jobj)?;
+        env.set_field(
+            output,
+            "contentLen",
+            "J",
+            self.content_len.to_java(env)?.into(),
+        )?;
+        let jobj = self.entry_version.to_java(env)?;
+        env.set_field(output, "entryVersion", "J", jobj.into())?;
+        Ok(output)
+    }
+}
+impl<'a> FromJava<JObject<'a>> for MDataEntry {
+    fn from_java(env: &JNIEnv, input: JObject) -> Result<Self, JniError> {
+        let key = env.get_field(input, "key", "Lnet/maidsafe/safe_authenticator/MDataKey;")?
+            .l()?;
+        let key = MDataKey::from_java(env, key)?;
+        let value = env.get_field(
+            input,
+            "value",
+            "Lnet/maidsafe/safe_authenticator/MDataValue;",
+        )?
+            .l()?;
+        let value = MDataValue::from_java(env, value)?;
+        Ok(MDataEntry { key, value })
+    }
+}
+impl<'a> ToJava<'a, JObject<'a>> for MDataEntry {
+    fn to_java(&self, env: &'a JNIEnv) -> Result<JObject<'a>, JniError> {
+        let cls = unsafe { find_class(env, "net/maidsafe/safe_authenticator/MDataEntry")? };
+        let output = env.new_object(&cls, "()V", &[])?;
+        let jobj = self.key.to_java(env)?;
+        env.set_field(
+            output,
+            "key",
+            "Lnet/maidsafe/safe_authenticator/MDataKey;",
+            jobj.into(),
+        )?;
+        env.delete_local_ref(jobj)?;
+        let jobj = self.value.to_java(env)?;
+        env.set_field(
+            output,
+            "value",
+            "Lnet/maidsafe/safe_authenticator/MDataValue;",
+            jobj.into(),
+        )?;
+        env.delete_local_ref(jobj)?;
+        Ok(output)
+    }
+}
+impl<'a> FromJava<JObject<'a>> for OwnershipChange {
+    fn from_java(env: &JNIEnv, input: JObject) -> Result<Self, JniError> {
+        let current_owner = env.get_field(
+            input,
+            "currentOwner",
+            "Lnet/maidsafe/safe_authenticator/BlsPublicKey;",
+        )?
+            .l()?;
+        let current_owner = BlsPublicKey::from_java(env, current_owner)?;
+        let new_owner = env.get_field(
+            input,
+            "newOwner",
+            "Lnet/maidsafe/safe_authenticator/BlsPublicKey;",
+        )?
+            .l()?;
+        let new_owner = BlsPublicKey::from_java(env, new_owner)?;
+        let description = env.get_field(input, "description", "Ljava/lang/String;")?
+            .l()?
+            .into();
+        let description = <*mut _>::from_java(env, description)?;
+        Ok(OwnershipChange {
+            current_owner,
+            new_owner,
+            description,
+        })
+    }
+}
+impl<'a> ToJava<'a, JObject<'a>> for OwnershipChange {
+    fn to_java(&self, env: &'a JNIEnv) -> Result<JObject<'a>, JniError> {
+        let cls = unsafe { find_class(env, "net/maidsafe/safe_authenticator/OwnershipChange")? };
+        let output = env.new_object(&cls, "()V", &[])?;
+        let jobj = self.current_owner.to_java(env)?;
+        env.set_field(
+            output,
+            "currentOwner",
+            "Lnet/maidsafe/safe_authenticator/BlsPublicKey;",
+            jobj.into(),
+        )?;
+        env.delete_local_ref(jobj)?;
+        let jobj = self.new_owner.to_java(env)?;
+        env.set_field(
+            output,
+            "newOwner",
+            "Lnet/maidsafe/safe_authenticator/BlsPublicKey;",
+            jobj.into(),
+        )?;
+        env.delete_local_ref(jobj)?;
+        if !self.description.is_null() {
+            let description: JObject = self.description.to_java(env)?.into();
+            env.set_field(
+                output,
+                "description",
+                "Ljava/lang/String;",
+                description.into(),
+            )?;
+            env.delete_local_ref(description)?;
+        }
+        Ok(output)
+    }
+}
+impl<'a> FromJava<JObject<'a>> for File {
+    fn from_java(env: &JNIEnv, input: JObject) -> Result<Self, JniError> {
+        let size = env.get_field(input, "size", "J")?.j()? as u64;
+        let created_sec = env.get_field(input, "createdSec", "J")?.j()? as i64;
+        let created_nsec = env.get_field(input, "createdNsec", "I")?.i()? as u32;
+        let modified_sec = env.get_field(input, "modifiedSec", "J")?.j()? as i64;
+        let modified_nsec = env.get_field(input, "modifiedNsec", "I")?.i()? as u32;
+        let arr = env.get_field(input, "userMetadata", "[B")?
+            .l()?
+            .into_inner() as jni::sys::jbyteArray;
+        let mut vec = env.convert_byte_array(arr)?;
+        let user_metadata_len = vec.len();
+        let user_metadata = vec.as_ptr();
+        ::std::mem::forget(vec);
+        let data_map_name = env.get_field(input, "dataMapName", "[B")?.l()?;
+        let data_map_name = XorNameArray::from_java(env, data_map_name)?;
+        let published = env.get_field(input, "published", "Z")?.z()? as bool;
+        Ok(File {
+            size,
+            created_sec,
+            created_nsec,
+            modified_sec,
+            modified_nsec,
+            user_metadata,
+            user_metadata_len,
+            data_map_name,
+            published,
+        })
+    }
+}
+impl<'a> ToJava<'a, JObject<'a>> for File {
+    fn to_java(&self, env: &'a JNIEnv) -> Result<JObject<'a>, JniError> {
+        let cls = unsafe { find_class(env, "net/maidsafe/safe_authenticator/File")? };
+        let output = env.new_object(&cls, "()V", &[])?;
+        let jobj = self.size.to_java(env)?;
+        env.set_field(output, "size", "J", jobj.into())?;
+        let jobj = self.created_sec.to_java(env)?;
+        env.set_field(output, "createdSec", "J", jobj.into())?;
+        let jobj = self.created_nsec.to_java(env)?;
+        env.set_field(output, "createdNsec", "I", jobj.into())?;
+        let jobj = self.modified_sec.to_java(env)?;
+        env.set_field(output, "modifiedSec", "J", jobj.into())?;
+        let jobj = self.modified_nsec.to_java(env)?;
+        env.set_field(output, "modifiedNsec", "I", jobj.into())?;
+        let arr = env.new_byte_array(
+            self.user_metadata_len as jni::sys::jsize,
+        )?;
+        let slice = unsafe {
+            slice::from_raw_parts(self.user_metadata as *const i8, self.user_metadata_len)
+        };
+        env.set_byte_array_region(arr, 0, slice)?;
+        let jobj = JObject::from(arr);
+        env.set_field(output, "userMetadata", "[B", jobj.into())?;
+        env.delete_local_ref(jobj)?;
+        env.set_field(
+            output,
+            "userMetadataLen",
+            "J",
+            self.user_metadata_len.to_java(env)?.into(),
+        )?;
+        let jobj = self.data_map_name.to_java(env)?;
+        env.set_field(output, "dataMapName", "[B", jobj.into())?;
+        let jobj = self.published.to_java(env)?;
+        env.set_field(output, "published", "Z", jobj.into())?;
+        Ok(output)
+    }
+}
+impl<'a> FromJava<JObject<'a>> for FfiResult {
+    fn from_java(env: &JNIEnv, input: JObject) -> Result<Self, JniError> {
+        let error_code = env.get_field(input, "errorCode", "I")?.i()? as i32;
+        let description = env.get_field(input, "description", "Ljava/lang/String;")?
+            .l()?
+            .into();
+        let description = <*mut _>::from_java(env, description)?;
+        Ok(FfiResult {
+            error_code,
+            description,
+        })
+    }
+}
+impl<'a> ToJava<'a, JObject<'a>> for FfiResult {
+    fn to_java(&self, env: &'a JNIEnv) -> Result<JObject<'a>, JniError> {
+        let cls = unsafe { find_class(env, "net/maidsafe/safe_authenticator/FfiResult")? };
+        let output = env.new_object(&cls, "()V", &[])?;
+        let jobj = self.error_code.to_java(env)?;
+        env.set_field(output, "errorCode", "I", jobj.into())?;
+        if !self.description.is_null() {
+            let description: JObject = self.description.to_java(env)?.into();
+            env.set_field(
+                output,
+                "description",
+                "Ljava/lang/String;",
+                description.into(),
+            )?;
+            env.delete_local_ref(description)?;
+        }
+        Ok(output)
+    }
+}
+extern "C" fn call_CallbackResultString(
+    ctx: *mut c_void,
+    result: *const FfiResult,
+    recovery_phrase: *const c_char,
+) {
+    unsafe {
+        let guard = jni_unwrap!(EnvGuard::new(JVM.as_ref()));
+        let env = guard.env();
+        let cb = jni_unwrap!(convert_cb_from_java(&env, ctx));
+        let result = if result.is_null() {
+            JObject::null()
+        } else {
+            jni_unwrap!((*result).to_java(&env))
+        };
+        let recovery_phrase: JObject = if recovery_phrase.is_null() {
+            JObject::null()
+        } else {
+            jni_unwrap!(recovery_phrase.to_java(&env)).into()
+        };
+        jni_unwrap!(env.call_method(
+            cb.as_obj(),
+            "call",
+            "(Lnet/maidsafe/safe_authenticator/FfiResult;Ljava/lang/String;)V",
+            &[result.into(), recovery_phrase.into()],
+        ));
+    }
+}
+
+#[link(name = "safe_authenticator")]
+extern "C" {
+    fn auth_set_up_recovery(
+        auth: *const Authenticator,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void,
+                            result: *const FfiResult,
+                            recovery_phrase: *const c_char),
+    );
+}
+#[no_mangle]pub unsafe extern "system" fn Java_net_maidsafe_safe_1authenticator_NativeBindings_authSetUpRecovery ( env : JNIEnv , _class : JClass , auth : jlong , o_cb : JObject ){
+    let auth = auth as *mut Authenticator;
+    let ctx = gen_ctx!(env, o_cb);
+    auth_set_up_recovery(auth, ctx, call_CallbackResultString);
+}
+extern "C" fn call_CallbackResultByteArrayLen(
+    ctx: *mut c_void,
+    result: *const FfiResult,
+    backup: *const u8,
+    backup_len: usize,
+) {
+    unsafe {
+        let guard = jni_unwrap!(EnvGuard::new(JVM.as_ref()));
+        let env = guard.env();
+        let cb = jni_unwrap!(convert_cb_from_java(&env, ctx));
+        let result = if result.is_null() {
+            JObject::null()
+        } else {
+            jni_unwrap!((*result).to_java(&env))
+        };
+        let backup = jni_unwrap!(slice::from_raw_parts(backup, backup_len).to_java(&env));
+        jni_unwrap!(env.call_method(
+            cb.as_obj(),
+            "call",
+            "(Lnet/maidsafe/safe_authenticator/FfiResult;[B)V",
+            &[result.into(), backup.into()],
+        ));
+    }
+}
+
+#[link(name = "safe_authenticator")]
+extern "C" {
+    fn auth_export_backup(
+        auth: *const Authenticator,
+        passphrase: *const c_char,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void,
+                            result: *const FfiResult,
+                            backup: *const u8,
+                            backup_len: usize),
+    );
+}
+#[no_mangle]pub unsafe extern "system" fn Java_net_maidsafe_safe_1authenticator_NativeBindings_authExportBackup ( env : JNIEnv , _class : JClass , auth : jlong , passphrase : JString , o_cb : JObject ){
+    let auth = auth as *mut Authenticator;
+    let passphrase = jni_unwrap!(CString::from_java(&env, passphrase));
+    let ctx = gen_ctx!(env, o_cb);
+    auth_export_backup(
+        auth,
+        passphrase.as_ptr(),
+        ctx,
+        call_CallbackResultByteArrayLen,
+    );
+}
+extern "C" fn call_CallbackResult(ctx: *mut c_void, result: *const FfiResult) {
+    unsafe {
+        let guard = jni_unwrap!(EnvGuard::new(JVM.as_ref()));
+        let env = guard.env();
+        let cb = jni_unwrap!(convert_cb_from_java(&env, ctx));
+        let result = if result.is_null() {
+            JObject::null()
+        } else {
+            jni_unwrap!((*result).to_java(&env))
+        };
+        jni_unwrap!(env.call_method(
+            cb.as_obj(),
+            "call",
+            "(Lnet/maidsafe/safe_authenticator/FfiResult;)V",
+            &[result.into()],
+        ));
+    }
+}
+
+#[link(name = "safe_authenticator")]
+extern "C" {
+    fn auth_change_password(
+        auth: *const Authenticator,
+        current_password: *const c_char,
+        new_password: *const c_char,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void, result: *const FfiResult),
+    );
+}
+#[no_mangle]pub unsafe extern "system" fn Java_net_maidsafe_safe_1authenticator_NativeBindings_authChangePassword ( env : JNIEnv , _class : JClass , auth : jlong , current_password : JString , new_password : JString , o_cb : JObject ){
+    let auth = auth as *mut Authenticator;
+    let current_password = jni_unwrap!(CString::from_java(&env, current_password));
+    let new_password = jni_unwrap!(CString::from_java(&env, new_password));
+    let ctx = gen_ctx!(env, o_cb);
+    auth_change_password(
+        auth,
+        current_password.as_ptr(),
+        new_password.as_ptr(),
+        ctx,
+        call_CallbackResult,
+    );
+}
+
+#[link(name = "safe_authenticator")]
+extern "C" {
+    fn auth_change_locator(
+        auth: *const Authenticator,
+        new_locator: *const c_char,
+        password: *const c_char,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void, result: *const FfiResult),
+    );
+}
+#[no_mangle]pub unsafe extern "system" fn Java_net_maidsafe_safe_1authenticator_NativeBindings_authChangeLocator ( env : JNIEnv , _class : JClass , auth : jlong , new_locator : JString , password : JString , o_cb : JObject ){
+    let auth = auth as *mut Authenticator;
+    let new_locator = jni_unwrap!(CString::from_java(&env, new_locator));
+    let password = jni_unwrap!(CString::from_java(&env, password));
+    let ctx = gen_ctx!(env, o_cb);
+    auth_change_locator(
+        auth,
+        new_locator.as_ptr(),
+        password.as_ptr(),
+        ctx,
+        call_CallbackResult,
+    );
+}
+
+#[link(name = "safe_authenticator")]
+extern "C" {
+    fn auth_rotate_client_keys(
+        auth: *const Authenticator,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void, result: *const FfiResult),
+    );
+}
+#[no_mangle]pub unsafe extern "system" fn Java_net_maidsafe_safe_1authenticator_NativeBindings_authRotateClientKeys ( env : JNIEnv , _class : JClass , auth : jlong , o_cb : JObject ){
+    let auth = auth as *mut Authenticator;
+    let ctx = gen_ctx!(env, o_cb);
+    auth_rotate_client_keys(auth, ctx, call_CallbackResult);
+}
+
+#[link(name = "safe_authenticator")]
+extern "C" {
+    fn auth_reconnect(
+        auth: *mut Authenticator,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void, result: *const FfiResult),
+    );
+}
+#[no_mangle]
+pub unsafe extern "system" fn Java_net_maidsafe_safe_1authenticator_NativeBindings_authReconnect(
+    env: JNIEnv,
+    _class: JClass,
+    auth: jlong,
+    o_cb: JObject,
+) {
+    let auth = auth as *mut Authenticator;
+    let ctx = gen_ctx!(env, o_cb);
+    auth_reconnect(auth, ctx, call_CallbackResult);
+}
+extern "C" fn call_CallbackResultBlsPublicKey(
+    ctx: *mut c_void,
+    result: *const FfiResult,
+    public_key: *const BlsPublicKey,
+) {
+    unsafe {
+        let guard = jni_unwrap!(EnvGuard::new(JVM.as_ref()));
+        let env = guard.env();
+        let cb = jni_unwrap!(convert_cb_from_java(&env, ctx));
+        let result = if result.is_null() {
+            JObject::null()
+        } else {
+            jni_unwrap!((*result).to_java(&env))
+        };
+        let public_key = if public_key.is_null() {
+            JObject::null()
+        } else {
+            jni_unwrap!((*public_key).to_java(&env))
+        };
+        jni_unwrap ! ( env . call_method ( cb . as_obj ( ) , "call" , "(Lnet/maidsafe/safe_authenticator/FfiResult;Lnet/maidsafe/safe_authenticator/BlsPublicKey;)V" , & [ result . into ( ) , public_key . into ( ) ] , ) );
+    }
+}
+
+#[link(name = "safe_authenticator")]
+extern "C" {
+    fn auth_account_public_key(
+        auth: *const Authenticator,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void,
+                            result: *const FfiResult,
+                            public_key: *const BlsPublicKey),
+    );
+}
+#[no_mangle]pub unsafe extern "system" fn Java_net_maidsafe_safe_1authenticator_NativeBindings_authAccountPublicKey ( env : JNIEnv , _class : JClass , auth : jlong , o_cb : JObject ){
+    let auth = auth as *mut Authenticator;
+    let ctx = gen_ctx!(env, o_cb);
+    auth_account_public_key(auth, ctx, call_CallbackResultBlsPublicKey);
+}
+
+#[link(name = "safe_authenticator")]
+extern "C" {
+    fn auth_account_public_id(
+        auth: *const Authenticator,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void,
+                            result: *const FfiResult,
+                            public_id: *const u8,
+                            public_id_len: usize),
+    );
+}
+#[no_mangle]pub unsafe extern "system" fn Java_net_maidsafe_safe_1authenticator_NativeBindings_authAccountPublicId ( env : JNIEnv , _class : JClass , auth : jlong , o_cb : JObject ){
+    let auth = auth as *mut Authenticator;
+    let ctx = gen_ctx!(env, o_cb);
+    auth_account_public_id(auth, ctx, call_CallbackResultByteArrayLen);
+}
+
+#[link(name = "safe_authenticator")]
+extern "C" {
+    fn auth_set_config_dir_path(
+        new_path: *const c_char,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void, result: *const FfiResult),
+    );
+}
+#[no_mangle]pub unsafe extern "system" fn Java_net_maidsafe_safe_1authenticator_NativeBindings_authSetConfigDirPath ( env : JNIEnv , _class : JClass , new_path : JString , o_cb : JObject ){
+    let new_path = jni_unwrap!(CString::from_java(&env, new_path));
+    let ctx = gen_ctx!(env, o_cb);
+    auth_set_config_dir_path(new_path.as_ptr(), ctx, call_CallbackResult);
+}
+
+#[link(name = "safe_authenticator")]
+extern "C" {
+    fn auth_free(auth: *mut Authenticator);
+}
+#[no_mangle]
+pub unsafe extern "system" fn Java_net_maidsafe_safe_1authenticator_NativeBindings_authFree(
+    env: JNIEnv,
+    _class: JClass,
+    auth: jlong,
+) {
+    let auth = auth as *mut Authenticator;
+    auth_free(auth);
+}
+
+#[link(name = "safe_authenticator")]
+extern "C" {
+    fn auth_is_mock();
+}
+#[no_mangle]
+pub unsafe extern "system" fn Java_net_maidsafe_safe_1authenticator_NativeBindings_authIsMock(
+    env: JNIEnv,
+    _class: JClass,
+) {
+    auth_is_mock();
+}
+impl<'a> FromJava<JObject<'a>> for RegisteredApp {
+    fn from_java(env: &JNIEnv, input: JObject) -> Result<Self, JniError> {
+        let app_info = env.get_field(
+            input,
+            "appInfo",
+            "Lnet/maidsafe/safe_authenticator/AppExchangeInfo;",
+        )?
+            .l()?;
+        let app_info = AppExchangeInfo::from_java(env, app_info)?;
+        let arr = env.get_field(
+            input,
+            "containers",
+            "[Lnet/maidsafe/safe_authenticator/ContainerPermissions;",
+        )?
+            .l()?
+            .into_inner() as jni::sys::jarray;
+        let containers_len = env.get_array_length(arr)? as usize;
+        let mut vec = Vec::with_capacity(containers_len);
+        for idx in 0..containers_len {
+            let item = env.get_object_array_element(arr, idx as jni::sys::jsize);
+            let item = ContainerPermissions::from_java(env, item?)?;
+            vec.push(item);
+        }
+        let containers = vec.as_ptr();
+        ::std::mem::forget(vec);
+        let app_permissions = env.get_field(
+            input,
+            "appPermissions",
+            "Lnet/maidsafe/safe_authenticator/AppPermissions;",
+        )?
+            .l()?;
+        let app_permissions = AppPermissions::from_java(env, app_permissions)?;
+        let expires_at_sec = env.get_field(input, "expiresAtSec", "J")?.j()? as i64;
+        Ok(RegisteredApp {
+            app_info,
+            containers,
+            containers_len,
+            app_permissions,
+            expires_at_sec,
+        })
+    }
+}
+impl<'a> ToJava<'a, JObject<'a>> for RegisteredApp {
+    fn to_java(&self, env: &'a JNIEnv) -> Result<JObject<'a>, JniError> {
+        let cls = unsafe { find_class(env, "net/maidsafe/safe_authenticator/RegisteredApp")? };
+        let output = env.new_object(&cls, "()V", &[])?;
+        let jobj = self.app_info.to_java(env)?;
+        env.set_field(
+            output,
+            "appInfo",
+            "Lnet/maidsafe/safe_authenticator/AppExchangeInfo;",
+            jobj.into(),
+        )?;
+        env.delete_local_ref(jobj)?;
+        let cls =
+            unsafe { find_class(env, "net/maidsafe/safe_authenticator/ContainerPermissions")? };
+        let arr = env.new_object_array(
+            self.containers_len as jni::sys::jsize,
+            &cls,
+            JObject::null(),
+        )?;
+        let items = unsafe { slice::from_raw_parts(self.containers, self.containers_len) };
+        for (idx, item) in items.iter().enumerate() {
+            let jobj = item.to_java(env)?;
+            env.set_object_array_element(
+                arr,
+                idx as jni::sys::jsize,
+                jobj,
+            )?;
+            env.delete_local_ref(jobj)?;
+        }
+        let jobj = JObject::from(arr);
+        env.set_field(
+            output,
+            "containers",
+            "[Lnet/maidsafe/safe_authenticator/ContainerPermissions;",
+            jobj.into(),
+        )?;
+        env.delete_local_ref(jobj)?;
+        env.set_field(
+            output,
+            "containersLen",
+            "J",
+            self.containers_len.to_java(env)?.into(),
+        )?;
+        let jobj = self.app_permissions.to_java(env)?;
+        env.set_field(
+            output,
+            "appPermissions",
+            "Lnet/maidsafe/safe_authenticator/AppPermissions;",
+            jobj.into(),
+        )?;
+        env.delete_local_ref(jobj)?;
+        let jobj = self.expires_at_sec.to_java(env)?;
+        env.set_field(output, "expiresAtSec", "J", jobj.into())?;
+        Ok(output)
+    }
+}
+impl<'a> FromJava<JObject<'a>> for AppUsageSummary {
+    fn from_java(env: &JNIEnv, input: JObject) -> Result<Self, JniError> {
+        let app_info = env.get_field(
+            input,
+            "appInfo",
+            "Lnet/maidsafe/safe_authenticator/AppExchangeInfo;",
+        )?
+            .l()?;
+        let app_info = AppExchangeInfo::from_java(env, app_info)?;
+        let mutations = env.get_field(input, "mutations", "J")?.j()? as u64;
+        let bytes = env.get_field(input, "bytes", "J")?.j()? as u64;
+        let spent = env.get_field(input, "spent", "J")?.j()? as u64;
+        Ok(AppUsageSummary {
+            app_info,
+            mutations,
+            bytes,
+            spent,
+        })
+    }
+}
+impl<'a> ToJava<'a, JObject<'a>> for AppUsageSummary {
+    fn to_java(&self, env: &'a JNIEnv) -> Result<JObject<'a>, JniError> {
+        let cls = unsafe { find_class(env, "net/maidsafe/safe_authenticator/AppUsageSummary")? };
+        let output = env.new_object(&cls, "()V", &[])?;
+        let jobj = self.app_info.to_java(env)?;
+        env.set_field(
+            output,
+            "appInfo",
+            "Lnet/maidsafe/safe_authenticator/AppExchangeInfo;",
+            jobj.into(),
+        )?;
+        env.delete_local_ref(jobj)?;
+        let jobj = self.mutations.to_java(env)?;
+        env.set_field(output, "mutations", "J", jobj.into())?;
+        let jobj = self.bytes.to_java(env)?;
+        env.set_field(output, "bytes", "J", jobj.into())?;
+        let jobj = self.spent.to_java(env)?;
+        env.set_field(output, "spent", "J", jobj.into())?;
+        Ok(output)
+    }
+}
+impl<'a> FromJava<JObject<'a>> for AppPermissions {
+    fn from_java(env: &JNIEnv, input: JObject) -> Result<Self, JniError> {
+        let transfer_coins = env.get_field(input, "transferCoins", "Z")?.z()? as bool;
+        let perform_mutations = env.get_field(input, "performMutations", "Z")?.z()? as bool;
+        let get_balance = env.get_field(input, "getBalance", "Z")?.z()? as bool;
+        Ok(AppPermissions {
+            transfer_coins,
+            perform_mutations,
+            get_balance,
+        })
+    }
+}
+impl<'a> ToJava<'a, JObject<'a>> for AppPermissions {
+    fn to_java(&self, env: &'a JNIEnv) -> Result<JObject<'a>, JniError> {
+        let cls = unsafe { find_class(env, "net/maidsafe/safe_authenticator/AppPermissions")? };
+        let output = env.new_object(&cls, "()V", &[])?;
+        let jobj = self.transfer_coins.to_java(env)?;
+        env.set_field(output, "transferCoins", "Z", jobj.into())?;
+        let jobj = self.perform_mutations.to_java(env)?;
+        env.set_field(output, "performMutations", "Z", jobj.into())?;
+        let jobj = self.get_balance.to_java(env)?;
+        env.set_field(output, "getBalance", "Z", jobj.into())?;
+        Ok(output)
+    }
+}
+#[link(name = "safe_authenticator")]
+extern "C" {
+    fn auth_rm_revoked_app(
+        auth: *const Authenticator,
+        app_id: *const c_char,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void, result: *const FfiResult),
+    );
+}
+#[no_mangle]pub unsafe extern "system" fn Java_net_maidsafe_safe_1authenticator_NativeBindings_authRmRevokedApp ( env : JNIEnv , _class : JClass , auth : jlong , app_id : JString , o_cb : JObject ){
+    let auth = auth as *mut Authenticator;
+    let app_id = jni_unwrap!(CString::from_java(&env, app_id));
+    let ctx = gen_ctx!(env, o_cb);
+    auth_rm_revoked_app(auth, app_id.as_ptr(), ctx, call_CallbackResult);
+}
+extern "C" fn call_CallbackResultAppExchangeInfoArrayLen(
+    ctx: *mut c_void,
+    result: *const FfiResult,
+    app_exchange_info: *const AppExchangeInfo,
+    app_exchange_info_len: usize,
+) {
+    unsafe {
+        let guard = jni_unwrap!(EnvGuard::new(JVM.as_ref()));
+        let env = guard.env();
+        let cb = jni_unwrap!(convert_cb_from_java(&env, ctx));
+        let result = if result.is_null() {
+            JObject::null()
+        } else {
+            jni_unwrap!((*result).to_java(&env))
+        };
+        let app_exchange_info = jni_unwrap!(
+            slice::from_raw_parts(app_exchange_info, app_exchange_info_len)
+                .to_java(&env)
+        );
+        jni_unwrap ! ( env . call_method ( cb . as_obj ( ) , "call" , "(Lnet/maidsafe/safe_authenticator/FfiResult;[Lnet/maidsafe/safe_authenticator/AppExchangeInfo;)V" , & [ result . into ( ) , app_exchange_info . into ( ) ] , ) );
+    }
+}
+
+#[link(name = "safe_authenticator")]
+extern "C" {
+    fn auth_revoked_apps(
+        auth: *const Authenticator,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void,
+                            result: *const FfiResult,
+                            app_exchange_info: *const AppExchangeInfo,
+                            app_exchange_info_len: usize),
+    );
+}
+#[no_mangle]pub unsafe extern "system" fn Java_net_maidsafe_safe_1authenticator_NativeBindings_authRevokedApps ( env : JNIEnv , _class : JClass , auth : jlong , o_cb : JObject ){
+    let auth = auth as *mut Authenticator;
+    let ctx = gen_ctx!(env, o_cb);
+    auth_revoked_apps(auth, ctx, call_CallbackResultAppExchangeInfoArrayLen);
+}
+extern "C" fn call_CallbackResultRegisteredAppArrayLen(
+    ctx: *mut c_void,
+    result: *const FfiResult,
+    registered_app: *const RegisteredApp,
+    registered_app_len: usize,
+) {
+    unsafe {
+        let guard = jni_unwrap!(EnvGuard::new(JVM.as_ref()));
+        let env = guard.env();
+        let cb = jni_unwrap!(convert_cb_from_java(&env, ctx));
+        let result = if result.is_null() {
+            JObject::null()
+        } else {
+            jni_unwrap!((*result).to_java(&env))
+        };
+        let registered_app =
+            jni_unwrap!(slice::from_raw_parts(registered_app, registered_app_len).to_java(&env));
+        jni_unwrap ! ( env . call_method ( cb . as_obj ( ) , "call" , "(Lnet/maidsafe/safe_authenticator/FfiResult;[Lnet/maidsafe/safe_authenticator/RegisteredApp;)V" , & [ result . into ( ) , registered_app . into ( ) ] , ) );
+    }
+}
+
+#[link(name = "safe_authenticator")]
+extern "C" {
+    fn auth_registered_apps(
+        auth: *const Authenticator,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void,
+                            result: *const FfiResult,
+                            registered_app: *const RegisteredApp,
+                            registered_app_len: usize),
+    );
+}
+#[no_mangle]pub unsafe extern "system" fn Java_net_maidsafe_safe_1authenticator_NativeBindings_authRegisteredApps ( env : JNIEnv , _class : JClass , auth : jlong , o_cb : JObject ){
+    let auth = auth as *mut Authenticator;
+    let ctx = gen_ctx!(env, o_cb);
+    auth_registered_apps(auth, ctx, call_CallbackResultRegisteredAppArrayLen);
+}
+extern "C" fn call_CallbackResultAppUsageSummaryArrayLen(
+    ctx: *mut c_void,
+    result: *const FfiResult,
+    app_usage: *const AppUsageSummary,
+    app_usage_len: usize,
+) {
+    unsafe {
+        let guard = jni_unwrap!(EnvGuard::new(JVM.as_ref()));
+        let env = guard.env();
+        let cb = jni_unwrap!(convert_cb_from_java(&env, ctx));
+        let result = if result.is_null() {
+            JObject::null()
+        } else {
+            jni_unwrap!((*result).to_java(&env))
+        };
+        let app_usage = jni_unwrap!(slice::from_raw_parts(app_usage, app_usage_len).to_java(
+            &env,
+        ));
+        jni_unwrap ! ( env . call_method ( cb . as_obj ( ) , "call" , "(Lnet/maidsafe/safe_authenticator/FfiResult;[Lnet/maidsafe/safe_authenticator/AppUsageSummary;)V" , & [ result . into ( ) , app_usage . into ( ) ] , ) );
+    }
+}
+
+#[link(name = "safe_authenticator")]
+extern "C" {
+    fn auth_apps_usage(
+        auth: *const Authenticator,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void,
+                            result: *const FfiResult,
+                            app_usage: *const AppUsageSummary,
+                            app_usage_len: usize),
+    );
+}
+#[cfg(feature = "mock-network")]
+#[no_mangle]
+pub unsafe extern "system" fn Java_net_maidsafe_safe_1authenticator_NativeBindings_authAppsUsage(
+    env: JNIEnv,
+    _class: JClass,
+    auth: jlong,
+    o_cb: JObject,
+) {
+    let auth = auth as *mut Authenticator;
+    let ctx = gen_ctx!(env, o_cb);
+    auth_apps_usage(auth, ctx, call_CallbackResultAppUsageSummaryArrayLen);
+}
+extern "C" fn call_CallbackResultAppAccessArrayLen(
+    ctx: *mut c_void,
+    result: *const FfiResult,
+    app_access: *const AppAccess,
+    app_access_len: usize,
+) {
+    unsafe {
+        let guard = jni_unwrap!(EnvGuard::new(JVM.as_ref()));
+        let env = guard.env();
+        let cb = jni_unwrap!(convert_cb_from_java(&env, ctx));
+        let result = if result.is_null() {
+            JObject::null()
+        } else {
+            jni_unwrap!((*result).to_java(&env))
+        };
+        let app_access = jni_unwrap!(slice::from_raw_parts(app_access, app_access_len).to_java(
+            &env,
+        ));
+        jni_unwrap ! ( env . call_method ( cb . as_obj ( ) , "call" , "(Lnet/maidsafe/safe_authenticator/FfiResult;[Lnet/maidsafe/safe_authenticator/AppAccess;)V" , & [ result . into ( ) , app_access . into ( ) ] , ) );
+    }
+}
+
+#[link(name = "safe_authenticator")]
+extern "C" {
+    fn auth_apps_accessing_mutable_data(
+        auth: *const Authenticator,
+        md_name: *const XorNameArray,
+        md_type_tag: u64,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void,
+                            result: *const FfiResult,
+                            app_access: *const AppAccess,
+                            app_access_len: usize),
+    );
+}
+#[no_mangle]pub unsafe extern "system" fn Java_net_maidsafe_safe_1authenticator_NativeBindings_authAppsAccessingMutableData ( env : JNIEnv , _class : JClass , auth : jlong , md_name : JObject , md_type_tag : jlong , o_cb : JObject ){
+    let auth = auth as *mut Authenticator;
+    let md_name = jni_unwrap!(XorNameArray::from_java(&env, md_name));
+    let ctx = gen_ctx!(env, o_cb);
+    auth_apps_accessing_mutable_data(
+        auth,
+        &md_name,
+        md_type_tag as u64,
+        ctx,
+        call_CallbackResultAppAccessArrayLen,
+    );
+}
+impl<'a> FromJava<JObject<'a>> for AuditEntry {
+    fn from_java(env: &JNIEnv, input: JObject) -> Result<Self, JniError> {
+        let timestamp_sec = env.get_field(input, "timestampSec", "J")?.j()? as i64;
+        let timestamp_nsec = env.get_field(input, "timestampNsec", "I")?.i()? as u32;
+        let app_id = env.get_field(input, "appId", "Ljava/lang/String;")?
+            .l()?
+            .into();
+        let app_id = <*mut _>::from_java(env, app_id)?;
+        let decision = env.get_field(input, "decision", "Ljava/lang/String;")?
+            .l()?
+            .into();
+        let decision = <*mut _>::from_java(env, decision)?;
+        Ok(AuditEntry {
+            timestamp_sec,
+            timestamp_nsec,
+            app_id,
+            decision,
+        })
+    }
+}
+impl<'a> ToJava<'a, JObject<'a>> for AuditEntry {
+    fn to_java(&self, env: &'a JNIEnv) -> Result<JObject<'a>, JniError> {
+        let cls = unsafe { find_class(env, "net/maidsafe/safe_authenticator/AuditEntry")? };
+        let output = env.new_object(&cls, "()V", &[])?;
+        let jobj = self.timestamp_sec.to_java(env)?;
+        env.set_field(output, "timestampSec", "J", jobj.into())?;
+        let jobj = self.timestamp_nsec.to_java(env)?;
+        env.set_field(output, "timestampNsec", "I", jobj.into())?;
+        if !self.app_id.is_null() {
+            let app_id: JObject = self.app_id.to_java(env)?.into();
+            env.set_field(
+                output,
+                "appId",
+                "Ljava/lang/String;",
+                app_id.into(),
+            )?;
+            env.delete_local_ref(app_id)?;
+        }
+        if !self.decision.is_null() {
+            let decision: JObject = self.decision.to_java(env)?.into();
+            env.set_field(
+                output,
+                "decision",
+                "Ljava/lang/String;",
+                decision.into(),
+            )?;
+            env.delete_local_ref(decision)?;
+        }
+        Ok(output)
+    }
+}
+extern "C" fn call_CallbackResultAuditEntryArrayLen(
+    ctx: *mut c_void,
+    result: *const FfiResult,
+    entries: *const AuditEntry,
+    entries_len: usize,
+) {
+    unsafe {
+        let guard = jni_unwrap!(EnvGuard::new(JVM.as_ref()));
+        let env = guard.env();
+        let cb = jni_unwrap!(convert_cb_from_java(&env, ctx));
+        let result = if result.is_null() {
+            JObject::null()
+        } else {
+            jni_unwrap!((*result).to_java(&env))
+        };
+        let entries = jni_unwrap!(slice::from_raw_parts(entries, entries_len).to_java(&env));
+        jni_unwrap ! ( env . call_method ( cb . as_obj ( ) , "call" , "(Lnet/maidsafe/safe_authenticator/FfiResult;[Lnet/maidsafe/safe_authenticator/AuditEntry;)V" , & [ result . into ( ) , entries . into ( ) ] , ) );
+    }
+}
+
+#[link(name = "safe_authenticator")]
+extern "C" {
+    fn auth_audit_log(
+        auth: *const Authenticator,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void,
+                            result: *const FfiResult,
+                            entries: *const AuditEntry,
+                            entries_len: usize),
+    );
+}
+#[no_mangle]
+pub unsafe extern "system" fn Java_net_maidsafe_safe_1authenticator_NativeBindings_authAuditLog(
+    env: JNIEnv,
+    _class: JClass,
+    auth: jlong,
+    o_cb: JObject,
+) {
+    let auth = auth as *mut Authenticator;
+    let ctx = gen_ctx!(env, o_cb);
+    auth_audit_log(auth, ctx, call_CallbackResultAuditEntryArrayLen);
+}
+
+#[link(name = "safe_authenticator")]
+extern "C" {
+    fn auth_export_audit_log(
+        auth: *const Authenticator,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void,
+                            result: *const FfiResult,
+                            json: *const c_char),
+    );
+}
+#[no_mangle]pub unsafe extern "system" fn Java_net_maidsafe_safe_1authenticator_NativeBindings_authExportAuditLog ( env : JNIEnv , _class : JClass , auth : jlong , o_cb : JObject ){
+    let auth = auth as *mut Authenticator;
+    let ctx = gen_ctx!(env, o_cb);
+    auth_export_audit_log(auth, ctx, call_CallbackResultString);
+}
+impl<'a> FromJava<JObject<'a>> for AppAllowanceStatus {
+    fn from_java(env: &JNIEnv, input: JObject) -> Result<Self, JniError> {
+        let app_id = env.get_field(input, "appId", "Ljava/lang/String;")?
+            .l()?
+            .into();
+        let app_id = <*mut _>::from_java(env, app_id)?;
+        let limit = env.get_field(input, "limit", "J")?.j()? as u64;
+        let daily = env.get_field(input, "daily", "Z")?.z()? as bool;
+        let spent = env.get_field(input, "spent", "J")?.j()? as u64;
+        let remaining = env.get_field(input, "remaining", "J")?.j()? as u64;
+        let exhausted = env.get_field(input, "exhausted", "Z")?.z()? as bool;
+        let near_limit = env.get_field(input, "nearLimit", "Z")?.z()? as bool;
+        Ok(AppAllowanceStatus {
+            app_id,
+            limit,
+            daily,
+            spent,
+            remaining,
+            exhausted,
+            near_limit,
+        })
+    }
+}
+impl<'a> ToJava<'a, JObject<'a>> for AppAllowanceStatus {
+    fn to_java(&self, env: &'a JNIEnv) -> Result<JObject<'a>, JniError> {
+        let cls = unsafe { find_class(env, "net/maidsafe/safe_authenticator/AppAllowanceStatus")? };
+        let output = env.new_object(&cls, "()V", &[])?;
+        if !self.app_id.is_null() {
+            let app_id: JObject = self.app_id.to_java(env)?.into();
+            env.set_field(
+                output,
+                "appId",
+                "Ljava/lang/String;",
+                app_id.into(),
+            )?;
+            env.delete_local_ref(app_id)?;
+        }
+        let jobj = self.limit.to_java(env)?;
+        env.set_field(output, "limit", "J", jobj.into())?;
+        let jobj = self.daily.to_java(env)?;
+        env.set_field(output, "daily", "Z", jobj.into())?;
+        let jobj = self.spent.to_java(env)?;
+        env.set_field(output, "spent", "J", jobj.into())?;
+        let jobj = self.remaining.to_java(env)?;
+        env.set_field(output, "remaining", "J", jobj.into())?;
+        let jobj = self.exhausted.to_java(env)?;
+        env.set_field(output, "exhausted", "Z", jobj.into())?;
+        let jobj = self.near_limit.to_java(env)?;
+        env.set_field(output, "nearLimit", "Z", jobj.into())?;
+        Ok(output)
+    }
+}
+#[link(name = "safe_authenticator")]
+extern "C" {
+    fn auth_set_app_allowance(
+        auth: *const Authenticator,
+        app_id: *const c_char,
+        limit: u64,
+        daily: bool,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void, result: *const FfiResult),
+    );
+}
+#[no_mangle]pub unsafe extern "system" fn Java_net_maidsafe_safe_1authenticator_NativeBindings_authSetAppAllowance ( env : JNIEnv , _class : JClass , auth : jlong , app_id : JString , limit : jlong , daily : bool , o_cb : JObject ){
+    let auth = auth as *mut Authenticator;
+    let app_id = jni_unwrap!(CString::from_java(&env, app_id));
+    let ctx = gen_ctx!(env, o_cb);
+    auth_set_app_allowance(
+        auth,
+        app_id.as_ptr(),
+        limit as u64,
+        daily as bool,
+        ctx,
+        call_CallbackResult,
+    );
+}
+
+#[link(name = "safe_authenticator")]
+extern "C" {
+    fn auth_rm_app_allowance(
+        auth: *const Authenticator,
+        app_id: *const c_char,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void, result: *const FfiResult),
+    );
+}
+#[no_mangle]pub unsafe extern "system" fn Java_net_maidsafe_safe_1authenticator_NativeBindings_authRmAppAllowance ( env : JNIEnv , _class : JClass , auth : jlong , app_id : JString , o_cb : JObject ){
+    let auth = auth as *mut Authenticator;
+    let app_id = jni_unwrap!(CString::from_java(&env, app_id));
+    let ctx = gen_ctx!(env, o_cb);
+    auth_rm_app_allowance(auth, app_id.as_ptr(), ctx, call_CallbackResult);
+}
+extern "C" fn call_CallbackResultAppAllowanceStatusArrayLen(
+    ctx: *mut c_void,
+    result: *const FfiResult,
+    statuses: *const AppAllowanceStatus,
+    statuses_len: usize,
+) {
+    unsafe {
+        let guard = jni_unwrap!(EnvGuard::new(JVM.as_ref()));
+        let env = guard.env();
+        let cb = jni_unwrap!(convert_cb_from_java(&env, ctx));
+        let result = if result.is_null() {
+            JObject::null()
+        } else {
+            jni_unwrap!((*result).to_java(&env))
+        };
+        let statuses = jni_unwrap!(slice::from_raw_parts(statuses, statuses_len).to_java(&env));
+        jni_unwrap ! ( env . call_method ( cb . as_obj ( ) , "call" , "(Lnet/maidsafe/safe_authenticator/FfiResult;[Lnet/maidsafe/safe_authenticator/AppAllowanceStatus;)V" , & [ result . into ( ) , statuses . into ( ) ] , ) );
+    }
+}
+
+#[link(name = "safe_authenticator")]
+extern "C" {
+    fn auth_app_allowances(
+        auth: *const Authenticator,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void,
+                            result: *const FfiResult,
+                            statuses: *const AppAllowanceStatus,
+                            statuses_len: usize),
+    );
+}
+#[no_mangle]pub unsafe extern "system" fn Java_net_maidsafe_safe_1authenticator_NativeBindings_authAppAllowances ( env : JNIEnv , _class : JClass , auth : jlong , o_cb : JObject ){
+    let auth = auth as *mut Authenticator;
+    let ctx = gen_ctx!(env, o_cb);
+    auth_app_allowances(auth, ctx, call_CallbackResultAppAllowanceStatusArrayLen);
+}
+extern "C" fn call_CallbackResultTransactionRecordArrayLen(
+    ctx: *mut c_void,
+    result: *const FfiResult,
+    transactions: *const TransactionRecord,
+    transactions_len: usize,
+) {
+    unsafe {
+        let guard = jni_unwrap!(EnvGuard::new(JVM.as_ref()));
+        let env = guard.env();
+        let cb = jni_unwrap!(convert_cb_from_java(&env, ctx));
+        let result = if result.is_null() {
+            JObject::null()
+        } else {
+            jni_unwrap!((*result).to_java(&env))
+        };
+        let transactions =
+            jni_unwrap!(slice::from_raw_parts(transactions, transactions_len).to_java(&env));
+        jni_unwrap ! ( env . call_method ( cb . as_obj ( ) , "call" , "(Lnet/maidsafe/safe_authenticator/FfiResult;[Lnet/maidsafe/safe_authenticator/TransactionRecord;)V" , & [ result . into ( ) , transactions . into ( ) ] , ) );
+    }
+}
+
+#[link(name = "safe_authenticator")]
+extern "C" {
+    fn auth_transaction_history(
+        auth: *const Authenticator,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void,
+                            result: *const FfiResult,
+                            transactions: *const TransactionRecord,
+                            transactions_len: usize),
+    );
+}
+#[no_mangle]pub unsafe extern "system" fn Java_net_maidsafe_safe_1authenticator_NativeBindings_authTransactionHistory ( env : JNIEnv , _class : JClass , auth : jlong , o_cb : JObject ){
+    let auth = auth as *mut Authenticator;
+    let ctx = gen_ctx!(env, o_cb);
+    auth_transaction_history(auth, ctx, call_CallbackResultTransactionRecordArrayLen);
+}
+impl<'a> FromJava<JObject<'a>> for DeletionReport {
+    fn from_java(env: &JNIEnv, input: JObject) -> Result<Self, JniError> {
+        let mdata_count = env.get_field(input, "mdataCount", "J")?.j()? as usize;
+        let idata_count = env.get_field(input, "idataCount", "J")?.j()? as usize;
+        let kept_mdata_count = env.get_field(input, "keptMdataCount", "J")?.j()? as usize;
+        let kept_files_count = env.get_field(input, "keptFilesCount", "J")?.j()? as usize;
+        let auth_keys_count = env.get_field(input, "authKeysCount", "J")?.j()? as usize;
+        let balance = env.get_field(input, "balance", "J")?.j()? as u64;
+        let login_packets_count = env.get_field(input, "loginPacketsCount", "J")?.j()? as usize;
+        let adata_count = env.get_field(input, "adataCount", "J")?.j()? as usize;
+        let kept_adata_count = env.get_field(input, "keptAdataCount", "J")?.j()? as usize;
+        Ok(DeletionReport {
+            mdata_count,
+            idata_count,
+            kept_mdata_count,
+            kept_files_count,
+            auth_keys_count,
+            balance,
+            login_packets_count,
+            adata_count,
+            kept_adata_count,
+        })
+    }
+}
+impl<'a> ToJava<'a, JObject<'a>> for DeletionReport {
+    fn to_java(&self, env: &'a JNIEnv) -> Result<JObject<'a>, JniError> {
+        let cls = unsafe { find_class(env, "net/maidsafe/safe_authenticator/DeletionReport")? };
+        let output = env.new_object(&cls, "()V", &[])?;
+        let jobj = self.mdata_count.to_java(env)?;
+        env.set_field(output, "mdataCount", "J", jobj.into())?;
+        let jobj = self.idata_count.to_java(env)?;
+        env.set_field(output, "idataCount", "J", jobj.into())?;
+        let jobj = self.kept_mdata_count.to_java(env)?;
+        env.set_field(output, "keptMdataCount", "J", jobj.into())?;
+        let jobj = self.kept_files_count.to_java(env)?;
+        env.set_field(output, "keptFilesCount", "J", jobj.into())?;
+        let jobj = self.auth_keys_count.to_java(env)?;
+        env.set_field(output, "authKeysCount", "J", jobj.into())?;
+        let jobj = self.balance.to_java(env)?;
+        env.set_field(output, "balance", "J", jobj.into())?;
+        let jobj = self.login_packets_count.to_java(env)?;
+        env.set_field(output, "loginPacketsCount", "J", jobj.into())?;
+        let jobj = self.adata_count.to_java(env)?;
+        env.set_field(output, "adataCount", "J", jobj.into())?;
+        let jobj = self.kept_adata_count.to_java(env)?;
+        env.set_field(output, "keptAdataCount", "J", jobj.into())?;
+        Ok(output)
+    }
+}
+extern "C" fn call_CallbackResultDeletionReport(
+    ctx: *mut c_void,
+    result: *const FfiResult,
+    report: *const DeletionReport,
+) {
+    unsafe {
+        let guard = jni_unwrap!(EnvGuard::new(JVM.as_ref()));
+        let env = guard.env();
+        let cb = jni_unwrap!(convert_cb_from_java(&env, ctx));
+        let result = if result.is_null() {
+            JObject::null()
+        } else {
+            jni_unwrap!((*result).to_java(&env))
+        };
+        let report = if report.is_null() {
+            JObject::null()
+        } else {
+            jni_unwrap!((*report).to_java(&env))
+        };
+        jni_unwrap ! ( env . call_method ( cb . as_obj ( ) , "call" , "(Lnet/maidsafe/safe_authenticator/FfiResult;Lnet/maidsafe/safe_authenticator/DeletionReport;)V" , & [ result . into ( ) , report . into ( ) ] , ) );
+    }
+}
+
+#[link(name = "safe_authenticator")]
+extern "C" {
+    fn auth_delete_account(
+        auth: *const Authenticator,
+        password: *const c_char,
+        destination: *const XorNameArray,
+        dry_run: bool,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void,
+                            result: *const FfiResult,
+                            report: *const DeletionReport),
+    );
+}
+#[no_mangle]pub unsafe extern "system" fn Java_net_maidsafe_safe_1authenticator_NativeBindings_authDeleteAccount ( env : JNIEnv , _class : JClass , auth : jlong , password : JString , destination : JObject , dry_run : bool , o_cb : JObject ){
+    let auth = auth as *mut Authenticator;
+    let password = jni_unwrap!(CString::from_java(&env, password));
+    let destination = jni_unwrap!(XorNameArray::from_java(&env, destination));
+    let ctx = gen_ctx!(env, o_cb);
+    auth_delete_account(
+        auth,
+        password.as_ptr(),
+        &destination,
+        dry_run as bool,
+        ctx,
+        call_CallbackResultDeletionReport,
+    );
+}
+impl<'a> FromJava<JObject<'a>> for Device {
+    fn from_java(env: &JNIEnv, input: JObject) -> Result<Self, JniError> {
+        let id = env.get_field(input, "id", "[B")?.l()?;
+        let id = DeviceId::from_java(env, id)?;
+        let name = env.get_field(input, "name", "Ljava/lang/String;")?
+            .l()?
+            .into();
+        let name = <*mut _>::from_java(env, name)?;
+        let registered_at_sec = env.get_field(input, "registeredAtSec", "J")?.j()? as i64;
+        let last_login_sec = env.get_field(input, "lastLoginSec", "J")?.j()? as i64;
+        Ok(Device {
+            id,
+            name,
+            registered_at_sec,
+            last_login_sec,
+        })
+    }
+}
+impl<'a> ToJava<'a, JObject<'a>> for Device {
+    fn to_java(&self, env: &'a JNIEnv) -> Result<JObject<'a>, JniError> {
+        let cls = unsafe { find_class(env, "net/maidsafe/safe_authenticator/Device")? };
+        let output = env.new_object(&cls, "()V", &[])?;
+        let jobj = self.id.to_java(env)?;
+        env.set_field(output, "id", "[B", jobj.into())?;
+        if !self.name.is_null() {
+            let name: JObject = self.name.to_java(env)?.into();
+            env.set_field(
+                output,
+                "name",
+                "Ljava/lang/String;",
+                name.into(),
+            )?;
+            env.delete_local_ref(name)?;
+        }
+        let jobj = self.registered_at_sec.to_java(env)?;
+        env.set_field(output, "registeredAtSec", "J", jobj.into())?;
+        let jobj = self.last_login_sec.to_java(env)?;
+        env.set_field(output, "lastLoginSec", "J", jobj.into())?;
+        Ok(output)
+    }
+}
+extern "C" fn call_CallbackResultDeviceKey(
+    ctx: *mut c_void,
+    result: *const FfiResult,
+    device_key: *const DeviceKey,
+) {
+    unsafe {
+        let guard = jni_unwrap!(EnvGuard::new(JVM.as_ref()));
+        let env = guard.env();
+        let cb = jni_unwrap!(convert_cb_from_java(&env, ctx));
+        let result = if result.is_null() {
+            JObject::null()
+        } else {
+            jni_unwrap!((*result).to_java(&env))
+        };
+        let device_key = if device_key.is_null() {
+            JObject::null()
+        } else {
+            jni_unwrap!((*device_key).to_java(&env))
+        };
+        jni_unwrap!(env.call_method(
+            cb.as_obj(),
+            "call",
+            "(Lnet/maidsafe/safe_authenticator/FfiResult;[B)V",
+            &[result.into(), device_key.into()],
+        ));
+    }
+}
+
+#[link(name = "safe_authenticator")]
+extern "C" {
+    fn auth_register_device(
+        auth: *const Authenticator,
+        name: *const c_char,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void,
+                            result: *const FfiResult,
+                            device_key: *const DeviceKey),
+    );
+}
+#[no_mangle]pub unsafe extern "system" fn Java_net_maidsafe_safe_1authenticator_NativeBindings_authRegisterDevice ( env : JNIEnv , _class : JClass , auth : jlong , name : JString , o_cb : JObject ){
+    let auth = auth as *mut Authenticator;
+    let name = jni_unwrap!(CString::from_java(&env, name));
+    let ctx = gen_ctx!(env, o_cb);
+    auth_register_device(auth, name.as_ptr(), ctx, call_CallbackResultDeviceKey);
+}
+extern "C" fn call_CallbackResultDeviceArrayLen(
+    ctx: *mut c_void,
+    result: *const FfiResult,
+    devices: *const Device,
+    devices_len: usize,
+) {
+    unsafe {
+        let guard = jni_unwrap!(EnvGuard::new(JVM.as_ref()));
+        let env = guard.env();
+        let cb = jni_unwrap!(convert_cb_from_java(&env, ctx));
+        let result = if result.is_null() {
+            JObject::null()
+        } else {
+            jni_unwrap!((*result).to_java(&env))
+        };
+        let devices = jni_unwrap!(slice::from_raw_parts(devices, devices_len).to_java(&env));
+        jni_unwrap ! ( env . call_method ( cb . as_obj ( ) , "call" , "(Lnet/maidsafe/safe_authenticator/FfiResult;[Lnet/maidsafe/safe_authenticator/Device;)V" , & [ result . into ( ) , devices . into ( ) ] , ) );
+    }
+}
+
+#[link(name = "safe_authenticator")]
+extern "C" {
+    fn auth_devices(
+        auth: *const Authenticator,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void,
+                            result: *const FfiResult,
+                            devices: *const Device,
+                            devices_len: usize),
+    );
+}
+#[no_mangle]
+pub unsafe extern "system" fn Java_net_maidsafe_safe_1authenticator_NativeBindings_authDevices(
+    env: JNIEnv,
+    _class: JClass,
+    auth: jlong,
+    o_cb: JObject,
+) {
+    let auth = auth as *mut Authenticator;
+    let ctx = gen_ctx!(env, o_cb);
+    auth_devices(auth, ctx, call_CallbackResultDeviceArrayLen);
+}
+
+#[link(name = "safe_authenticator")]
+extern "C" {
+    fn auth_log_out_device(
+        auth: *const Authenticator,
+        device_id: *const DeviceId,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void, result: *const FfiResult),
+    );
+}
+#[no_mangle]pub unsafe extern "system" fn Java_net_maidsafe_safe_1authenticator_NativeBindings_authLogOutDevice ( env : JNIEnv , _class : JClass , auth : jlong , device_id : JObject , o_cb : JObject ){
+    let auth = auth as *mut Authenticator;
+    let device_id = jni_unwrap!(DeviceId::from_java(&env, device_id));
+    let ctx = gen_ctx!(env, o_cb);
+    auth_log_out_device(auth, &device_id, ctx, call_CallbackResult);
+}
+extern "C" fn call_CallbackIntByteArrayLen(
+    ctx: *mut c_void,
+    req_id: u32,
+    extra_data: *const u8,
+    extra_data_len: usize,
+) {
+    unsafe {
+        let guard = jni_unwrap!(EnvGuard::new(JVM.as_ref()));
+        let env = guard.env();
+        let cb = jni_unwrap!(convert_cb_from_java(&env, ctx));
+        let req_id = jni_unwrap!(req_id.to_java(&env));
+        let extra_data = jni_unwrap!(slice::from_raw_parts(extra_data, extra_data_len).to_java(
+            &env,
+        ));
+        jni_unwrap!(env.call_method(
+            cb.as_obj(),
+            "call",
+            "(I[B)V",
+            &[req_id.into(), extra_data.into()],
+        ));
+    }
+}
+extern "C" fn call_auth_unregistered_decode_ipc_msg_0(
+    ctx: *mut c_void,
+    req_id: u32,
+    extra_data: *const u8,
+    extra_data_len: usize,
+) {
+    unsafe {
+        let guard = jni_unwrap!(EnvGuard::new(JVM.as_ref()));
+        let env = guard.env();
+        let mut cbs = Box::from_raw(ctx as *mut [Option<GlobalRef>; 2usize]);
+        if let Some(cb) = cbs[0usize].take() {
+            let req_id = jni_unwrap!(req_id.to_java(&env));
+            let extra_data = jni_unwrap!(
+                slice::from_raw_parts(extra_data, extra_data_len).to_java(&env)
+            );
+            jni_unwrap!(env.call_method(
+                cb.as_obj(),
+                "call",
+                "(I[B)V",
+                &[req_id.into(), extra_data.into()],
+            ));
+        }
+    }
+}
+extern "C" fn call_auth_unregistered_decode_ipc_msg_1(
+    ctx: *mut c_void,
+    result: *const FfiResult,
+    response: *const c_char,
+) {
+    unsafe {
+        let guard = jni_unwrap!(EnvGuard::new(JVM.as_ref()));
+        let env = guard.env();
+        let mut cbs = Box::from_raw(ctx as *mut [Option<GlobalRef>; 2usize]);
+        if let Some(cb) = cbs[1usize].take() {
+            let result = if result.is_null() {
+                JObject::null()
+            } else {
+                jni_unwrap!((*result).to_java(&env))
+            };
+            let response: JObject = if response.is_null() {
+                JObject::null()
+            } else {
+                jni_unwrap!(response.to_java(&env)).into()
+            };
+            jni_unwrap!(env.call_method(
+                cb.as_obj(),
+                "call",
+                "(Lnet/maidsafe/safe_authenticator/FfiResult;Ljava/lang/String;)V",
+                &[result.into(), response.into()],
+            ));
+        }
+    }
+}
+
+#[link(name = "safe_authenticator")]
+extern "C" {
+    fn auth_unregistered_decode_ipc_msg(
+        msg: *const c_char,
+        user_data: *mut c_void,
+        o_unregistered: extern "C" fn(user_data: *mut c_void,
+                                      req_id: u32,
+                                      extra_data: *const u8,
+                                      extra_data_len: usize),
+        o_err: extern "C" fn(user_data: *mut c_void,
+                             result: *const FfiResult,
+                             response: *const c_char),
+    );
+}
+#[no_mangle]pub unsafe extern "system" fn Java_net_maidsafe_safe_1authenticator_NativeBindings_authUnregisteredDecodeIpcMsg ( env : JNIEnv , _class : JClass , msg : JString , o_unregistered : JObject , o_err : JObject ){
+    let msg = jni_unwrap!(CString::from_java(&env, msg));
+    let ctx = gen_ctx!(env, o_unregistered, o_err);
+    auth_unregistered_decode_ipc_msg(
+        msg.as_ptr(),
+        ctx,
+        call_auth_unregistered_decode_ipc_msg_0,
+        call_auth_unregistered_decode_ipc_msg_1,
+    );
+}
+extern "C" fn call_CallbackIntAuthReq(ctx: *mut c_void, req_id: u32, req: *const AuthReq) {
+    unsafe {
+        let guard = jni_unwrap!(EnvGuard::new(JVM.as_ref()));
+        let env = guard.env();
+        let cb = jni_unwrap!(convert_cb_from_java(&env, ctx));
+        let req_id = jni_unwrap!(req_id.to_java(&env));
+        let req = if req.is_null() {
+            JObject::null()
+        } else {
+            jni_unwrap!((*req).to_java(&env))
+        };
+        jni_unwrap!(env.call_method(
+            cb.as_obj(),
+            "call",
+            "(ILnet/maidsafe/safe_authenticator/AuthReq;)V",
+            &[req_id.into(), req.into()],
+        ));
+    }
+}
+extern "C" fn call_CallbackIntContainersReq(
+    ctx: *mut c_void,
+    req_id: u32,
+    req: *const ContainersReq,
+) {
+    unsafe {
+        let guard = jni_unwrap!(EnvGuard::new(JVM.as_ref()));
+        let env = guard.env();
+        let cb = jni_unwrap!(convert_cb_from_java(&env, ctx));
+        let req_id = jni_unwrap!(req_id.to_java(&env));
+        let req = if req.is_null() {
+            JObject::null()
+        } else {
+            jni_unwrap!((*req).to_java(&env))
+        };
+        jni_unwrap!(env.call_method(
+            cb.as_obj(),
+            "call",
+            "(ILnet/maidsafe/safe_authenticator/ContainersReq;)V",
+            &[req_id.into(), req.into()],
+        ));
+    }
+}
+extern "C" fn call_CallbackIntShareMDataRequestMetadataResponseArrayLen(
+    ctx: *mut c_void,
+    req_id: u32,
+    req: *const ShareMDataRequest,
+    metadata: *const MetadataResponse,
+    metadata_len: usize,
+) {
+    unsafe {
+        let guard = jni_unwrap!(EnvGuard::new(JVM.as_ref()));
+        let env = guard.env();
+        let cb = jni_unwrap!(convert_cb_from_java(&env, ctx));
+        let req_id = jni_unwrap!(req_id.to_java(&env));
+        let req = if req.is_null() {
+            JObject::null()
+        } else {
+            jni_unwrap!((*req).to_java(&env))
+        };
+        let metadata = jni_unwrap!(slice::from_raw_parts(metadata, metadata_len).to_java(&env));
+        jni_unwrap ! ( env . call_method ( cb . as_obj ( ) , "call" , "(ILnet/maidsafe/safe_authenticator/ShareMDataRequest;[Lnet/maidsafe/safe_authenticator/MetadataResponse;)V" , & [ req_id . into ( ) , req . into ( ) , metadata . into ( ) ] , ) );
+    }
+}
+extern "C" fn call_auth_decode_ipc_msg_0(ctx: *mut c_void, req_id: u32, req: *const AuthReq) {
+    unsafe {
+        let guard = jni_unwrap!(EnvGuard::new(JVM.as_ref()));
+        let env = guard.env();
+        let mut cbs = Box::from_raw(ctx as *mut [Option<GlobalRef>; 5usize]);
+        if let Some(cb) = cbs[0usize].take() {
+            let req_id = jni_unwrap!(req_id.to_java(&env));
+            let req = if req.is_null() {
+                JObject::null()
+            } else {
+                jni_unwrap!((*req).to_java(&env))
+            };
+            jni_unwrap!(env.call_method(
+                cb.as_obj(),
+                "call",
+                "(ILnet/maidsafe/safe_authenticator/AuthReq;)V",
+                &[req_id.into(), req.into()],
+            ));
+        }
+    }
+}
+extern "C" fn call_auth_decode_ipc_msg_1(ctx: *mut c_void, req_id: u32, req: *const ContainersReq) {
+    unsafe {
+        let guard = jni_unwrap!(EnvGuard::new(JVM.as_ref()));
+        let env = guard.env();
+        let mut cbs = Box::from_raw(ctx as *mut [Option<GlobalRef>; 5usize]);
+        if let Some(cb) = cbs[1usize].take() {
+            let req_id = jni_unwrap!(req_id.to_java(&env));
+            let req = if req.is_null() {
+                JObject::null()
+            } else {
+                jni_unwrap!((*req).to_java(&env))
+            };
+            jni_unwrap!(env.call_method(
+                cb.as_obj(),
+                "call",
+                "(ILnet/maidsafe/safe_authenticator/ContainersReq;)V",
+                &[req_id.into(), req.into()],
+            ));
+        }
+    }
+}
+extern "C" fn call_auth_decode_ipc_msg_2(
+    ctx: *mut c_void,
+    req_id: u32,
+    extra_data: *const u8,
+    extra_data_len: usize,
+) {
+    unsafe {
+        let guard = jni_unwrap!(EnvGuard::new(JVM.as_ref()));
+        let env = guard.env();
+        let mut cbs = Box::from_raw(ctx as *mut [Option<GlobalRef>; 5usize]);
+        if let Some(cb) = cbs[2usize].take() {
+            let req_id = jni_unwrap!(req_id.to_java(&env));
+            let extra_data = jni_unwrap!(
+                slice::from_raw_parts(extra_data, extra_data_len).to_java(&env)
+            );
+            jni_unwrap!(env.call_method(
+                cb.as_obj(),
+                "call",
+                "(I[B)V",
+                &[req_id.into(), extra_data.into()],
+            ));
+        }
+    }
+}
+extern "C" fn call_auth_decode_ipc_msg_3(
+    ctx: *mut c_void,
+    req_id: u32,
+    req: *const ShareMDataRequest,
+    metadata: *const MetadataResponse,
+    metadata_len: usize,
+) {
+    unsafe {
+        let guard = jni_unwrap!(EnvGuard::new(JVM.as_ref()));
+        let env = guard.env();
+        let mut cbs = Box::from_raw(ctx as *mut [Option<GlobalRef>; 5usize]);
+        if let Some(cb) = cbs[3usize].take() {
+            let req_id = jni_unwrap!(req_id.to_java(&env));
+            let req = if req.is_null() {
+                JObject::null()
+            } else {
+                jni_unwrap!((*req).to_java(&env))
+            };
+            let metadata = jni_unwrap!(slice::from_raw_parts(metadata, metadata_len).to_java(&env));
+            jni_unwrap ! ( env . call_method ( cb . as_obj ( ) , "call" , "(ILnet/maidsafe/safe_authenticator/ShareMDataRequest;[Lnet/maidsafe/safe_authenticator/MetadataResponse;)V" , & [ req_id . into ( ) , req . into ( ) , metadata . into ( ) ] , ) );
+        }
+    }
+}
+extern "C" fn call_auth_decode_ipc_msg_4(
+    ctx: *mut c_void,
+    result: *const FfiResult,
+    response: *const c_char,
+) {
+    unsafe {
+        let guard = jni_unwrap!(EnvGuard::new(JVM.as_ref()));
+        let env = guard.env();
+        let mut cbs = Box::from_raw(ctx as *mut [Option<GlobalRef>; 5usize]);
+        if let Some(cb) = cbs[4usize].take() {
+            let result = if result.is_null() {
+                JObject::null()
+            } else {
+                jni_unwrap!((*result).to_java(&env))
+            };
+            let response: JObject = if response.is_null() {
+                JObject::null()
+            } else {
+                jni_unwrap!(response.to_java(&env)).into()
+            };
+            jni_unwrap!(env.call_method(
+                cb.as_obj(),
+                "call",
+                "(Lnet/maidsafe/safe_authenticator/FfiResult;Ljava/lang/String;)V",
+                &[result.into(), response.into()],
+            ));
+        }
+    }
+}
+
+#[link(name = "safe_authenticator")]
+extern "C" {
+    fn auth_decode_ipc_msg(
+        auth: *const Authenticator,
+        msg: *const c_char,
+        user_data: *mut c_void,
+        o_auth: extern "C" fn(user_data: *mut c_void, req_id: u32, req: *const AuthReq),
+        o_containers: extern "C" fn(user_data: *mut c_void,
+                                    req_id: u32,
+                                    req: *const ContainersReq),
+        o_unregistered: extern "C" fn(user_data: *mut c_void,
+                                      req_id: u32,
+                                      extra_data: *const u8,
+                                      extra_data_len: usize),
+        o_share_mdata: extern "C" fn(user_data: *mut c_void,
+                                     req_id: u32,
+                                     req: *const ShareMDataRequest,
+                                     metadata: *const MetadataResponse,
+                                     metadata_len: usize),
+        o_err: extern "C" fn(user_data: *mut c_void,
+                             result: *const FfiResult,
+                             response: *const c_char),
+    );
+}
+#[no_mangle]pub unsafe extern "system" fn Java_net_maidsafe_safe_1authenticator_NativeBindings_authDecodeIpcMsg ( env : JNIEnv , _class : JClass , auth : jlong , msg : JString , o_auth : JObject , o_containers : JObject , o_unregistered : JObject , o_share_mdata : JObject , o_err : JObject ){
+    let auth = auth as *mut Authenticator;
+    let msg = jni_unwrap!(CString::from_java(&env, msg));
+    let ctx = gen_ctx!(
+        env,
+        o_auth,
+        o_containers,
+        o_unregistered,
+        o_share_mdata,
+        o_err
+    );
+    auth_decode_ipc_msg(
+        auth,
+        msg.as_ptr(),
+        ctx,
+        call_auth_decode_ipc_msg_0,
+        call_auth_decode_ipc_msg_1,
+        call_auth_decode_ipc_msg_2,
+        call_auth_decode_ipc_msg_3,
+        call_auth_decode_ipc_msg_4,
+    );
+}
+extern "C" fn call_CallbackIntShareADataRequest(
+    ctx: *mut c_void,
+    req_id: u32,
+    req: *const ShareADataRequest,
+) {
+    unsafe {
+        let guard = jni_unwrap!(EnvGuard::new(JVM.as_ref()));
+        let env = guard.env();
+        let cb = jni_unwrap!(convert_cb_from_java(&env, ctx));
+        let req_id = jni_unwrap!(req_id.to_java(&env));
+        let req = if req.is_null() {
+            JObject::null()
+        } else {
+            jni_unwrap!((*req).to_java(&env))
+        };
+        jni_unwrap!(env.call_method(
+            cb.as_obj(),
+            "call",
+            "(ILnet/maidsafe/safe_authenticator/ShareADataRequest;)V",
+            &[req_id.into(), req.into()],
+        ));
+    }
+}
+extern "C" fn call_CallbackIntTransOwnershipRequestOwnershipChangeArrayLen(
+    ctx: *mut c_void,
+    req_id: u32,
+    req: *const TransOwnershipRequest,
+    changes: *const OwnershipChange,
+    changes_len: usize,
+) {
+    unsafe {
+        let guard = jni_unwrap!(EnvGuard::new(JVM.as_ref()));
+        let env = guard.env();
+        let cb = jni_unwrap!(convert_cb_from_java(&env, ctx));
+        let req_id = jni_unwrap!(req_id.to_java(&env));
+        let req = if req.is_null() {
+            JObject::null()
+        } else {
+            jni_unwrap!((*req).to_java(&env))
+        };
+        let changes = jni_unwrap!(slice::from_raw_parts(changes, changes_len).to_java(&env));
+        jni_unwrap ! ( env . call_method ( cb . as_obj ( ) , "call" , "(ILnet/maidsafe/safe_authenticator/TransOwnershipRequest;[Lnet/maidsafe/safe_authenticator/OwnershipChange;)V" , & [ req_id . into ( ) , req . into ( ) , changes . into ( ) ] , ) );
+    }
+}
+extern "C" fn call_CallbackIntPermissionsReq(
+    ctx: *mut c_void,
+    req_id: u32,
+    req: *const PermissionsReq,
+) {
+    unsafe {
+        let guard = jni_unwrap!(EnvGuard::new(JVM.as_ref()));
+        let env = guard.env();
+        let cb = jni_unwrap!(convert_cb_from_java(&env, ctx));
+        let req_id = jni_unwrap!(req_id.to_java(&env));
+        let req = if req.is_null() {
+            JObject::null()
+        } else {
+            jni_unwrap!((*req).to_java(&env))
+        };
+        jni_unwrap!(env.call_method(
+            cb.as_obj(),
+            "call",
+            "(ILnet/maidsafe/safe_authenticator/PermissionsReq;)V",
+            &[req_id.into(), req.into()],
+        ));
+    }
+}
+extern "C" fn call_auth_decode_ipc_req_by_kind_0(
+    ctx: *mut c_void,
+    req_id: u32,
+    req: *const AuthReq,
+) {
+    unsafe {
+        let guard = jni_unwrap!(EnvGuard::new(JVM.as_ref()));
+        let env = guard.env();
+        let mut cbs = Box::from_raw(ctx as *mut [Option<GlobalRef>; 8usize]);
+        if let Some(cb) = cbs[0usize].take() {
+            let req_id = jni_unwrap!(req_id.to_java(&env));
+            let req = if req.is_null() {
+                JObject::null()
+            } else {
+                jni_unwrap!((*req).to_java(&env))
+            };
+            jni_unwrap!(env.call_method(
+                cb.as_obj(),
+                "call",
+                "(ILnet/maidsafe/safe_authenticator/AuthReq;)V",
+                &[req_id.into(), req.into()],
+            ));
+        }
+    }
+}
+extern "C" fn call_auth_decode_ipc_req_by_kind_1(
+    ctx: *mut c_void,
+    req_id: u32,
+    req: *const ContainersReq,
+) {
+    unsafe {
+        let guard = jni_unwrap!(EnvGuard::new(JVM.as_ref()));
+        let env = guard.env();
+        let mut cbs = Box::from_raw(ctx as *mut [Option<GlobalRef>; 8usize]);
+        if let Some(cb) = cbs[1usize].take() {
+            let req_id = jni_unwrap!(req_id.to_java(&env));
+            let req = if req.is_null() {
+                JObject::null()
+            } else {
+                jni_unwrap!((*req).to_java(&env))
+            };
+            jni_unwrap!(env.call_method(
+                cb.as_obj(),
+                "call",
+                "(ILnet/maidsafe/safe_authenticator/ContainersReq;)V",
+                &[req_id.into(), req.into()],
+            ));
+        }
+    }
+}
+extern "C" fn call_auth_decode_ipc_req_by_kind_2(
+    ctx: *mut c_void,
+    req_id: u32,
+    extra_data: *const u8,
+    extra_data_len: usize,
+) {
+    unsafe {
+        let guard = jni_unwrap!(EnvGuard::new(JVM.as_ref()));
+        let env = guard.env();
+        let mut cbs = Box::from_raw(ctx as *mut [Option<GlobalRef>; 8usize]);
+        if let Some(cb) = cbs[2usize].take() {
+            let req_id = jni_unwrap!(req_id.to_java(&env));
+            let extra_data = jni_unwrap!(
+                slice::from_raw_parts(extra_data, extra_data_len).to_java(&env)
+            );
+            jni_unwrap!(env.call_method(
+                cb.as_obj(),
+                "call",
+                "(I[B)V",
+                &[req_id.into(), extra_data.into()],
+            ));
+        }
+    }
+}
+extern "C" fn call_auth_decode_ipc_req_by_kind_3(
+    ctx: *mut c_void,
+    req_id: u32,
+    req: *const ShareMDataRequest,
+    metadata: *const MetadataResponse,
+    metadata_len: usize,
+) {
+    unsafe {
+        let guard = jni_unwrap!(EnvGuard::new(JVM.as_ref()));
+        let env = guard.env();
+        let mut cbs = Box::from_raw(ctx as *mut [Option<GlobalRef>; 8usize]);
+        if let Some(cb) = cbs[3usize].take() {
+            let req_id = jni_unwrap!(req_id.to_java(&env));
+            let req = if req.is_null() {
+                JObject::null()
+            } else {
+                jni_unwrap!((*req).to_java(&env))
+            };
+            let metadata = jni_unwrap!(slice::from_raw_parts(metadata, metadata_len).to_java(&env));
+            jni_unwrap ! ( env . call_method ( cb . as_obj ( ) , "call" , "(ILnet/maidsafe/safe_authenticator/ShareMDataRequest;[Lnet/maidsafe/safe_authenticator/MetadataResponse;)V" , & [ req_id . into ( ) , req . into ( ) , metadata . into ( ) ] , ) );
+        }
+    }
+}
+extern "C" fn call_auth_decode_ipc_req_by_kind_4(
+    ctx: *mut c_void,
+    req_id: u32,
+    req: *const ShareADataRequest,
+) {
+    unsafe {
+        let guard = jni_unwrap!(EnvGuard::new(JVM.as_ref()));
+        let env = guard.env();
+        let mut cbs = Box::from_raw(ctx as *mut [Option<GlobalRef>; 8usize]);
+        if let Some(cb) = cbs[4usize].take() {
+            let req_id = jni_unwrap!(req_id.to_java(&env));
+            let req = if req.is_null() {
+                JObject::null()
+            } else {
+                jni_unwrap!((*req).to_java(&env))
+            };
+            jni_unwrap!(env.call_method(
+                cb.as_obj(),
+                "call",
+                "(ILnet/maidsafe/safe_authenticator/ShareADataRequest;)V",
+                &[req_id.into(), req.into()],
+            ));
+        }
+    }
+}
+extern "C" fn call_auth_decode_ipc_req_by_kind_5(
+    ctx: *mut c_void,
+    req_id: u32,
+    req: *const TransOwnershipRequest,
+    changes: *const OwnershipChange,
+    changes_len: usize,
+) {
+    unsafe {
+        let guard = jni_unwrap!(EnvGuard::new(JVM.as_ref()));
+        let env = guard.env();
+        let mut cbs = Box::from_raw(ctx as *mut [Option<GlobalRef>; 8usize]);
+        if let Some(cb) = cbs[5usize].take() {
+            let req_id = jni_unwrap!(req_id.to_java(&env));
+            let req = if req.is_null() {
+                JObject::null()
+            } else {
+                jni_unwrap!((*req).to_java(&env))
+            };
+            let changes = jni_unwrap!(slice::from_raw_parts(changes, changes_len).to_java(&env));
+            jni_unwrap ! ( env . call_method ( cb . as_obj ( ) , "call" , "(ILnet/maidsafe/safe_authenticator/TransOwnershipRequest;[Lnet/maidsafe/safe_authenticator/OwnershipChange;)V" , & [ req_id . into ( ) , req . into ( ) , changes . into ( ) ] , ) );
+        }
+    }
+}
+extern "C" fn call_auth_decode_ipc_req_by_kind_6(
+    ctx: *mut c_void,
+    req_id: u32,
+    req: *const PermissionsReq,
+) {
+    unsafe {
+        let guard = jni_unwrap!(EnvGuard::new(JVM.as_ref()));
+        let env = guard.env();
+        let mut cbs = Box::from_raw(ctx as *mut [Option<GlobalRef>; 8usize]);
+        if let Some(cb) = cbs[6usize].take() {
+            let req_id = jni_unwrap!(req_id.to_java(&env));
+            let req = if req.is_null() {
+                JObject::null()
+            } else {
+                jni_unwrap!((*req).to_java(&env))
+            };
+            jni_unwrap!(env.call_method(
+                cb.as_obj(),
+                "call",
+                "(ILnet/maidsafe/safe_authenticator/PermissionsReq;)V",
+                &[req_id.into(), req.into()],
+            ));
+        }
+    }
+}
+extern "C" fn call_auth_decode_ipc_req_by_kind_7(
+    ctx: *mut c_void,
+    result: *const FfiResult,
+    response: *const c_char,
+) {
+    unsafe {
+        let guard = jni_unwrap!(EnvGuard::new(JVM.as_ref()));
+        let env = guard.env();
+        let mut cbs = Box::from_raw(ctx as *mut [Option<GlobalRef>; 8usize]);
+        if let Some(cb) = cbs[7usize].take() {
+            let result = if result.is_null() {
+                JObject::null()
+            } else {
+                jni_unwrap!((*result).to_java(&env))
+            };
+            let response: JObject = if response.is_null() {
+                JObject::null()
+            } else {
+                jni_unwrap!(response.to_java(&env)).into()
+            };
+            jni_unwrap!(env.call_method(
+                cb.as_obj(),
+                "call",
+                "(Lnet/maidsafe/safe_authenticator/FfiResult;Ljava/lang/String;)V",
+                &[result.into(), response.into()],
+            ));
+        }
+    }
+}
+
+#[link(name = "safe_authenticator")]
+extern "C" {
+    fn auth_decode_ipc_req_by_kind(
+        auth: *const Authenticator,
+        msg: *const c_char,
+        user_data: *mut c_void,
+        o_auth: extern "C" fn(user_data: *mut c_void, req_id: u32, req: *const AuthReq),
+        o_containers: extern "C" fn(user_data: *mut c_void,
+                                    req_id: u32,
+                                    req: *const ContainersReq),
+        o_unregistered: extern "C" fn(user_data: *mut c_void,
+                                      req_id: u32,
+                                      extra_data: *const u8,
+                                      extra_data_len: usize),
+        o_share_mdata: extern "C" fn(user_data: *mut c_void,
+                                     req_id: u32,
+                                     req: *const ShareMDataRequest,
+                                     metadata: *const MetadataResponse,
+                                     metadata_len: usize),
+        o_share_adata: extern "C" fn(user_data: *mut c_void,
+                                     req_id: u32,
+                                     req: *const ShareADataRequest),
+        o_trans_ownership: extern "C" fn(user_data: *mut c_void,
+                                         req_id: u32,
+                                         req: *const TransOwnershipRequest,
+                                         changes: *const OwnershipChange,
+                                         changes_len: usize),
+        o_permissions: extern "C" fn(user_data: *mut c_void,
+                                     req_id: u32,
+                                     req: *const PermissionsReq),
+        o_err: extern "C" fn(user_data: *mut c_void,
+                             result: *const FfiResult,
+                             response: *const c_char),
+    );
+}
+#[no_mangle]pub unsafe extern "system" fn Java_net_maidsafe_safe_1authenticator_NativeBindings_authDecodeIpcReqByKind ( env : JNIEnv , _class : JClass , auth : jlong , msg : JString , o_auth : JObject , o_containers : JObject , o_unregistered : JObject , o_share_mdata : JObject , o_share_adata : JObject , o_trans_ownership : JObject , o_permissions : JObject , o_err : JObject ){
+    let auth = auth as *mut Authenticator;
+    let msg = jni_unwrap!(CString::from_java(&env, msg));
+    let ctx = gen_ctx!(
+        env,
+        o_auth,
+        o_containers,
+        o_unregistered,
+        o_share_mdata,
+        o_share_adata,
+        o_trans_ownership,
+        o_permissions,
+        o_err
+    );
+    auth_decode_ipc_req_by_kind(
+        auth,
+        msg.as_ptr(),
+        ctx,
+        call_auth_decode_ipc_req_by_kind_0,
+        call_auth_decode_ipc_req_by_kind_1,
+        call_auth_decode_ipc_req_by_kind_2,
+        call_auth_decode_ipc_req_by_kind_3,
+        call_auth_decode_ipc_req_by_kind_4,
+        call_auth_decode_ipc_req_by_kind_5,
+        call_auth_decode_ipc_req_by_kind_6,
+        call_auth_decode_ipc_req_by_kind_7,
+    );
+}
+
+#[link(name = "safe_authenticator")]
+extern "C" {
+    fn auth_revoke_app(
+        auth: *const Authenticator,
+        app_id: *const c_char,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void,
+                            result: *const FfiResult,
+                            response: *const c_char),
+    );
+}
+#[no_mangle]
+pub unsafe extern "system" fn Java_net_maidsafe_safe_1authenticator_NativeBindings_authRevokeApp(
+    env: JNIEnv,
+    _class: JClass,
+    auth: jlong,
+    app_id: JString,
+    o_cb: JObject,
+) {
+    let auth = auth as *mut Authenticator;
+    let app_id = jni_unwrap!(CString::from_java(&env, app_id));
+    let ctx = gen_ctx!(env, o_cb);
+    auth_revoke_app(auth, app_id.as_ptr(), ctx, call_CallbackResultString);
+}
+
+#[link(name = "safe_authenticator")]
+extern "C" {
+    fn auth_flush_app_revocation_queue(
+        auth: *const Authenticator,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void, result: *const FfiResult),
+    );
+}
+#[no_mangle]pub unsafe extern "system" fn Java_net_maidsafe_safe_1authenticator_NativeBindings_authFlushAppRevocationQueue ( env : JNIEnv , _class : JClass , auth : jlong , o_cb : JObject ){
+    let auth = auth as *mut Authenticator;
+    let ctx = gen_ctx!(env, o_cb);
+    auth_flush_app_revocation_queue(auth, ctx, call_CallbackResult);
+}
+
+#[link(name = "safe_authenticator")]
+extern "C" {
+    fn auth_revoke_expired_apps(
+        auth: *const Authenticator,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void, result: *const FfiResult),
+    );
+}
+#[no_mangle]pub unsafe extern "system" fn Java_net_maidsafe_safe_1authenticator_NativeBindings_authRevokeExpiredApps ( env : JNIEnv , _class : JClass , auth : jlong , o_cb : JObject ){
+    let auth = auth as *mut Authenticator;
+    let ctx = gen_ctx!(env, o_cb);
+    auth_revoke_expired_apps(auth, ctx, call_CallbackResult);
+}
+
+#[link(name = "safe_authenticator")]
+extern "C" {
+    fn encode_unregistered_resp(
+        req_id: u32,
+        is_granted: bool,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void,
+                            result: *const FfiResult,
+                            response: *const c_char),
+    );
+}
+#[no_mangle]pub unsafe extern "system" fn Java_net_maidsafe_safe_1authenticator_NativeBindings_encodeUnregisteredResp ( env : JNIEnv , _class : JClass , req_id : jint , is_granted : bool , o_cb : JObject ){
+    let ctx = gen_ctx!(env, o_cb);
+    encode_unregistered_resp(
+        req_id as u32,
+        is_granted as bool,
+        ctx,
+        call_CallbackResultString,
+    );
+}
+
+#[link(name = "safe_authenticator")]
+extern "C" {
+    fn encode_auth_resp(
+        auth: *const Authenticator,
+        req: *const AuthReq,
+        req_id: u32,
+        is_granted: bool,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void,
+                            result: *const FfiResult,
+                            response: *const c_char),
+    );
+}
+#[no_mangle]pub unsafe extern "system" fn Java_net_maidsafe_safe_1authenticator_NativeBindings_encodeAuthResp ( env : JNIEnv , _class : JClass , auth : jlong , req : JObject , req_id : jint , is_granted : bool , o_cb : JObject ){
+    let auth = auth as *mut Authenticator;
+    let req = jni_unwrap!(AuthReq::from_java(&env, req));
+    let ctx = gen_ctx!(env, o_cb);
+    encode_auth_resp(
+        auth,
+        &req,
+        req_id as u32,
+        is_granted as bool,
+        ctx,
+        call_CallbackResultString,
+    );
+}
+
+#[link(name = "safe_authenticator")]
+extern "C" {
+    fn encode_containers_resp(
+        auth: *const Authenticator,
+        req: *const ContainersReq,
+        req_id: u32,
+        is_granted: bool,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void,
+                            result: *const FfiResult,
+                            response: *const c_char),
+    );
+}
+#[no_mangle]pub unsafe extern "system" fn Java_net_maidsafe_safe_1authenticator_NativeBindings_encodeContainersResp ( env : JNIEnv , _class : JClass , auth : jlong , req : JObject , req_id : jint , is_granted : bool , o_cb : JObject ){
+    let auth = auth as *mut Authenticator;
+    let req = jni_unwrap!(ContainersReq::from_java(&env, req));
+    let ctx = gen_ctx!(env, o_cb);
+    encode_containers_resp(
+        auth,
+        &req,
+        req_id as u32,
+        is_granted as bool,
+        ctx,
+        call_CallbackResultString,
+    );
+}
+
+#[link(name = "safe_authenticator")]
+extern "C" {
+    fn encode_permissions_resp(
+        auth: *const Authenticator,
+        req: *const PermissionsReq,
+        req_id: u32,
+        is_granted: bool,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void,
+                            result: *const FfiResult,
+                            response: *const c_char),
+    );
+}
+#[no_mangle]pub unsafe extern "system" fn Java_net_maidsafe_safe_1authenticator_NativeBindings_encodePermissionsResp ( env : JNIEnv , _class : JClass , auth : jlong , req : JObject , req_id : jint , is_granted : bool , o_cb : JObject ){
+    let auth = auth as *mut Authenticator;
+    let req = jni_unwrap!(PermissionsReq::from_java(&env, req));
+    let ctx = gen_ctx!(env, o_cb);
+    encode_permissions_resp(
+        auth,
+        &req,
+        req_id as u32,
+        is_granted as bool,
+        ctx,
+        call_CallbackResultString,
+    );
+}
+
+#[link(name = "safe_authenticator")]
+extern "C" {
+    fn encode_share_mdata_resp(
+        auth: *const Authenticator,
+        req: *const ShareMDataRequest,
+        req_id: u32,
+        is_granted: bool,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void,
+                            result: *const FfiResult,
+                            response: *const c_char),
+    );
+}
+#[no_mangle]pub unsafe extern "system" fn Java_net_maidsafe_safe_1authenticator_NativeBindings_encodeShareMdataResp ( env : JNIEnv , _class : JClass , auth : jlong , req : JObject , req_id : jint , is_granted : bool , o_cb : JObject ){
+    let auth = auth as *mut Authenticator;
+    let req = jni_unwrap!(ShareMDataRequest::from_java(&env, req));
+    let ctx = gen_ctx!(env, o_cb);
+    encode_share_mdata_resp(
+        auth,
+        &req,
+        req_id as u32,
+        is_granted as bool,
+        ctx,
+        call_CallbackResultString,
+    );
+}
+
+#[link(name = "safe_authenticator")]
+extern "C" {
+    fn encode_share_adata_resp(
+        auth: *const Authenticator,
+        req: *const ShareADataRequest,
+        req_id: u32,
+        is_granted: bool,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void,
+                            result: *const FfiResult,
+                            response: *const c_char),
+    );
+}
+#[no_mangle]pub unsafe extern "system" fn Java_net_maidsafe_safe_1authenticator_NativeBindings_encodeShareAdataResp ( env : JNIEnv , _class : JClass , auth : jlong , req : JObject , req_id : jint , is_granted : bool , o_cb : JObject ){
+    let auth = auth as *mut Authenticator;
+    let req = jni_unwrap!(ShareADataRequest::from_java(&env, req));
+    let ctx = gen_ctx!(env, o_cb);
+    encode_share_adata_resp(
+        auth,
+        &req,
+        req_id as u32,
+        is_granted as bool,
+        ctx,
+        call_CallbackResultString,
+    );
+}
+
+#[link(name = "safe_authenticator")]
+extern "C" {
+    fn encode_trans_ownership_resp(
+        auth: *const Authenticator,
+        req: *const TransOwnershipRequest,
+        req_id: u32,
+        is_granted: bool,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void,
+                            result: *const FfiResult,
+                            response: *const c_char),
+    );
+}
+#[no_mangle]pub unsafe extern "system" fn Java_net_maidsafe_safe_1authenticator_NativeBindings_encodeTransOwnershipResp ( env : JNIEnv , _class : JClass , auth : jlong , req : JObject , req_id : jint , is_granted : bool , o_cb : JObject ){
+    let auth = auth as *mut Authenticator;
+    let req = jni_unwrap!(TransOwnershipRequest::from_java(&env, req));
+    let ctx = gen_ctx!(env, o_cb);
+    encode_trans_ownership_resp(
+        auth,
+        &req,
+        req_id as u32,
+        is_granted as bool,
+        ctx,
+        call_CallbackResultString,
+    );
+}
+
+#[link(name = "safe_authenticator")]
+extern "C" {
+    fn auth_init_logging(
+        output_file_name_override: *const c_char,
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void, result: *const FfiResult),
+    );
+}
+#[no_mangle]pub unsafe extern "system" fn Java_net_maidsafe_safe_1authenticator_NativeBindings_authInitLogging ( env : JNIEnv , _class : JClass , output_file_name_override : JString , o_cb : JObject ){
+    let output_file_name_override =
+        jni_unwrap!(CString::from_java(&env, output_file_name_override));
+    let ctx = gen_ctx!(env, o_cb);
+    auth_init_logging(output_file_name_override.as_ptr(), ctx, call_CallbackResult);
+}
+
+#[link(name = "safe_authenticator")]
+extern "C" {
+    fn auth_config_dir_path(
+        user_data: *mut c_void,
+        o_cb: extern "C" fn(user_data: *mut c_void,
+                            result: *const FfiResult,
+                            log_path: *const c_char),
+    );
+}
+#[no_mangle]pub unsafe extern "system" fn Java_net_maidsafe_safe_1authenticator_NativeBindings_authConfigDirPath ( env : JNIEnv , _class : JClass , o_cb : JObject ){
+    let ctx = gen_ctx!(env, o_cb);
+    auth_config_dir_path(ctx, call_CallbackResultString);
+}
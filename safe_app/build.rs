@@ -70,6 +70,7 @@ mod bindings {
         unwrap!(bindgen.compile(&mut lang, &mut outputs, false));
 
         lang.add_custom_code("typedef void* App;\n");
+        lang.add_custom_code("typedef void* SignatureTx;\n");
         lang.set_lib_name(unwrap!(env::var("CARGO_PKG_NAME")));
         bindgen.source_file("../safe_app/src/lib.rs");
         unwrap!(bindgen.compile(&mut lang, &mut outputs, true));
@@ -147,6 +148,14 @@ mod bindings {
 
         lang.filter("app_registered");
         lang.filter("app_unregistered");
+        // The Java generator can't pass the opaque `SignatureTx` channel, so Java apps can only
+        // sign with the keys in `AuthGranted`.
+        lang.filter("app_registered_with_signer");
+        lang.filter("app_send_signature");
+        // The Java generator can't convert the nullable struct pointers of `DecodedIpcResp`, so
+        // Java apps decode responses with `decode_ipc_msg`.
+        lang.filter("decode_ipc_resp");
+        lang.filter("DecodedIpcResp");
 
         lang.set_namespace("net.maidsafe.safe_app");
         lang.set_model_namespace("net.maidsafe.safe_app");
@@ -203,6 +212,7 @@ mod bindings {
         lang.add_const("ulong", "ASYM_PUBLIC_KEY_LEN", PK_SIZE);
         lang.add_const("ulong", "XOR_NAME_LEN", XOR_NAME_LEN);
         lang.add_opaque_type("App");
+        lang.add_opaque_type("SignatureTx");
 
         lang.reset_filter(FilterMode::Blacklist);
         for &ident in &test_idents {
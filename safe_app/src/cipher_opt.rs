@@ -11,13 +11,16 @@
 
 use crate::client::AppClient;
 use crate::errors::AppError;
-use crate::AppContext;
+use crate::{AppContext, AppFuture};
 use bincode::{deserialize, serialize};
+use futures::{future, Future};
 use miscreant::aead::Aead;
 use miscreant::aead::Aes128SivAead;
-use safe_core::{utils, Client, CoreError};
+use safe_core::client::SafeKey;
+use safe_core::{fry, utils, Client, CoreError, FutureExt};
+use safe_nd::{Error as SndError, PublicKey as SignPublicKey, Signature};
 use serde_derive::{Deserialize, Serialize};
-use threshold_crypto::{Ciphertext, PublicKey};
+use threshold_crypto::{Ciphertext, PublicKey, SecretKey};
 
 /// Cipher Options
 #[derive(Debug)]
@@ -31,6 +34,12 @@ pub enum CipherOpt {
         /// PublicKey of the peer to whom we want to encrypt
         peer_encrypt_key: PublicKey,
     },
+    /// Sign using the app's key, then encrypt using asymmetric encryption, so that the peer can
+    /// verify who wrote the data
+    AuthenticatedAsymmetric {
+        /// PublicKey of the peer to whom we want to encrypt
+        peer_encrypt_key: PublicKey,
+    },
 }
 
 #[allow(clippy::large_enum_variant)]
@@ -42,29 +51,110 @@ enum WireFormat {
         cipher_text: Vec<u8>,
     },
     Asymmetric(Ciphertext),
+    AuthenticatedAsymmetric(Ciphertext),
+}
+
+// Plain text along with its sender's signature, encrypted as a whole for the recipient.
+#[derive(Serialize, Deserialize)]
+struct SignedPayload {
+    sender: SignPublicKey,
+    signature: Signature,
+    plain_text: Vec<u8>,
+}
+
+// Bytes signed by the sender. They include the recipient's key, so that the recipient can't
+// re-encrypt the payload for someone else and pass it off as written to them.
+fn signed_bytes(peer_encrypt_key: &PublicKey, plain_text: &[u8]) -> Result<Vec<u8>, AppError> {
+    Ok(serialize(&(peer_encrypt_key, plain_text))?)
+}
+
+/// Signs the plain text with the sender's key, then encrypts it along with the signature and the
+/// sender's public key for the peer. The result can be opened with `open_authenticated`.
+pub fn seal_authenticated(
+    plain_text: &[u8],
+    peer_encrypt_key: &PublicKey,
+    sender: &SafeKey,
+) -> Box<AppFuture<Ciphertext>> {
+    let signed_bytes = fry!(signed_bytes(peer_encrypt_key, plain_text));
+    let sender_key = sender.public_key();
+    let plain_text = plain_text.to_vec();
+    let peer_encrypt_key = *peer_encrypt_key;
+
+    sender
+        .sign(&signed_bytes)
+        .map_err(AppError::from)
+        .and_then(move |signature| {
+            let payload = SignedPayload {
+                sender: sender_key,
+                signature,
+                plain_text,
+            };
+
+            Ok(peer_encrypt_key.encrypt(serialize(&payload)?))
+        })
+        .into_box()
+}
+
+/// Decrypts data sealed by `seal_authenticated` and verifies the sender's signature. Returns the
+/// plain text along with the public key of the sender.
+pub fn open_authenticated(
+    cipher_text: &Ciphertext,
+    secret_key: &SecretKey,
+) -> Result<(Vec<u8>, SignPublicKey), AppError> {
+    let payload = secret_key
+        .decrypt(cipher_text)
+        .ok_or_else(|| AppError::from(CoreError::AsymmetricDecipherFailure))?;
+    let SignedPayload {
+        sender,
+        signature,
+        plain_text,
+    } = deserialize(&payload)?;
+
+    sender
+        .verify(
+            &signature,
+            &signed_bytes(&secret_key.public_key(), &plain_text)?,
+        )
+        .map_err(|_| AppError::from(SndError::InvalidSignature))?;
+
+    Ok((plain_text, sender))
 }
 
 impl CipherOpt {
-    /// Encrypt plain text
-    pub fn encrypt(&self, plain_text: &[u8], app_ctx: &AppContext) -> Result<Vec<u8>, AppError> {
-        match *self {
-            Self::PlainText => Ok(serialize(&WireFormat::Plain(plain_text.to_owned()))?),
+    /// Encrypt plain text. Authenticated encryption waits for the app's signer, so the result is
+    /// returned as a future.
+    pub fn encrypt(
+        &self,
+        plain_text: &[u8],
+        app_ctx: &AppContext,
+        client: &AppClient,
+    ) -> Box<AppFuture<Vec<u8>>> {
+        let wire_format = match *self {
+            Self::PlainText => WireFormat::Plain(plain_text.to_owned()),
             Self::Symmetric => {
                 let nonce = utils::generate_nonce().to_vec();
-                let sym_enc_key = app_ctx.sym_enc_key()?;
+                let sym_enc_key = fry!(app_ctx.sym_enc_key());
                 let mut cipher = Aes128SivAead::new(&**sym_enc_key);
                 let cipher_text = cipher.seal(&nonce, &[], plain_text);
-                let wire_format = WireFormat::Symmetric { nonce, cipher_text };
 
-                Ok(serialize(&wire_format)?)
+                WireFormat::Symmetric { nonce, cipher_text }
             }
             Self::Asymmetric {
                 ref peer_encrypt_key,
+            } => WireFormat::Asymmetric(peer_encrypt_key.encrypt(plain_text)),
+            Self::AuthenticatedAsymmetric {
+                ref peer_encrypt_key,
             } => {
-                let cipher_text = peer_encrypt_key.encrypt(plain_text);
-                Ok(serialize(&WireFormat::Asymmetric(cipher_text))?)
+                return seal_authenticated(plain_text, peer_encrypt_key, &client.full_id())
+                    .and_then(|cipher_text| {
+                        let wire_format = WireFormat::AuthenticatedAsymmetric(cipher_text);
+                        Ok(serialize(&wire_format)?)
+                    })
+                    .into_box();
             }
-        }
+        };
+
+        future::result(serialize(&wire_format).map_err(AppError::from)).into_box()
     }
 
     /// Decrypt something encrypted by CipherOpt::encrypt()
@@ -73,24 +163,42 @@ impl CipherOpt {
         app_ctx: &AppContext,
         client: &AppClient,
     ) -> Result<Vec<u8>, AppError> {
+        Self::decrypt_with_sender(cipher_text, app_ctx, client).map(|(plain_text, _)| plain_text)
+    }
+
+    /// Decrypt something encrypted by CipherOpt::encrypt(), also returning the public key of the
+    /// sender if it was encrypted with `CipherOpt::AuthenticatedAsymmetric`. Fails if the sender's
+    /// signature doesn't verify.
+    pub fn decrypt_with_sender(
+        cipher_text: &[u8],
+        app_ctx: &AppContext,
+        client: &AppClient,
+    ) -> Result<(Vec<u8>, Option<SignPublicKey>), AppError> {
         if cipher_text.is_empty() {
-            return Ok(Vec::new());
+            return Ok((Vec::new(), None));
         }
 
         match deserialize::<WireFormat>(cipher_text)? {
-            WireFormat::Plain(plain_text) => Ok(plain_text),
+            WireFormat::Plain(plain_text) => Ok((plain_text, None)),
             WireFormat::Symmetric { nonce, cipher_text } => {
                 let sym_enc_key = app_ctx.sym_enc_key()?;
                 let mut cipher = Aes128SivAead::new(&**sym_enc_key);
-                Ok(cipher
+                let plain_text = cipher
                     .open(&nonce, &[], &cipher_text)
-                    .map_err(|_| CoreError::SymmetricDecipherFailure)?)
+                    .map_err(|_| CoreError::SymmetricDecipherFailure)?;
+                Ok((plain_text, None))
             }
             WireFormat::Asymmetric(cipher_text) => {
                 let asym_sk = client.secret_encryption_key();
-                asym_sk
+                let plain_text = asym_sk
                     .decrypt(&cipher_text)
-                    .ok_or_else(|| AppError::from(CoreError::AsymmetricDecipherFailure))
+                    .ok_or_else(|| AppError::from(CoreError::AsymmetricDecipherFailure))?;
+                Ok((plain_text, None))
+            }
+            WireFormat::AuthenticatedAsymmetric(cipher_text) => {
+                let asym_sk = client.secret_encryption_key();
+                let (plain_text, sender) = open_authenticated(&cipher_text, &asym_sk)?;
+                Ok((plain_text, Some(sender)))
             }
         }
     }
@@ -12,7 +12,7 @@ use crate::{AppContext, AppMsgTx};
 use log::trace;
 use lru_cache::LruCache;
 use rand::thread_rng;
-use safe_core::client::{Inner, SafeKey, IMMUT_DATA_CACHE_SIZE};
+use safe_core::client::{Inner, SafeKey, Signer, IMMUT_DATA_CACHE_SIZE};
 use safe_core::config_handler::Config;
 use safe_core::core_structs::AppKeys;
 use safe_core::crypto::{shared_box, shared_secretbox};
@@ -22,6 +22,7 @@ use safe_nd::{ClientFullId, PublicKey};
 use std::cell::RefCell;
 use std::fmt;
 use std::rc::Rc;
+use std::sync::Arc;
 use std::time::Duration;
 use tokio::runtime::current_thread::{block_on_all, Handle};
 
@@ -69,7 +70,7 @@ impl AppClient {
                 core_tx,
                 net_tx,
             ))),
-            app_inner: Rc::new(RefCell::new(AppInner::new(app_keys, pk, config))),
+            app_inner: Rc::new(RefCell::new(AppInner::new(app_keys, pk, None, config))),
         })
     }
 
@@ -83,9 +84,45 @@ impl AppClient {
         net_tx: NetworkTx,
         config: BootstrapConfig,
     ) -> Result<Self, AppError> {
-        Self::from_keys_impl(keys, owner, el_handle, core_tx, net_tx, config, |routing| {
-            routing
-        })
+        Self::from_keys_impl(
+            keys,
+            owner,
+            None,
+            el_handle,
+            core_tx,
+            net_tx,
+            config,
+            |routing| routing,
+        )
+    }
+
+    /// Like `from_keys`, but the app's requests are signed by `signer` instead of the secret key
+    /// in `keys`.
+    pub(crate) fn from_keys_with_signer(
+        keys: AppKeys,
+        owner: PublicKey,
+        signer: Arc<dyn Signer>,
+        el_handle: Handle,
+        core_tx: AppMsgTx,
+        net_tx: NetworkTx,
+        config: BootstrapConfig,
+    ) -> Result<Self, AppError> {
+        if signer.public_key() != *keys.app_full_id.public_id().public_key() {
+            return Err(AppError::Unexpected(
+                "The signer doesn't hold the app's key".to_string(),
+            ));
+        }
+
+        Self::from_keys_impl(
+            keys,
+            owner,
+            Some(signer),
+            el_handle,
+            core_tx,
+            net_tx,
+            config,
+            |routing| routing,
+        )
     }
 
     /// Allows customising the mock Routing client before logging in using client keys.
@@ -108,6 +145,7 @@ impl AppClient {
         Self::from_keys_impl(
             keys,
             owner,
+            None,
             el_handle,
             core_tx,
             net_tx,
@@ -116,9 +154,11 @@ impl AppClient {
         )
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn from_keys_impl<F>(
         keys: AppKeys,
         owner: PublicKey,
+        signer: Option<Arc<dyn Signer>>,
         el_handle: Handle,
         core_tx: AppMsgTx,
         net_tx: NetworkTx,
@@ -137,8 +177,10 @@ impl AppClient {
             .cloned()
             .collect();
 
+        let app_inner = AppInner::new(keys, owner, signer, Some(config));
+
         let mut connection_manager = ConnectionManager::new(qp2p_config, &net_tx.clone())?;
-        let _ = block_on_all(connection_manager.bootstrap(keys.app_safe_key()));
+        let _ = block_on_all(connection_manager.bootstrap(app_inner.safe_key()));
 
         connection_manager = connection_manager_wrapper_fn(connection_manager);
 
@@ -151,17 +193,23 @@ impl AppClient {
                 core_tx,
                 net_tx,
             ))),
-            app_inner: Rc::new(RefCell::new(AppInner::new(keys, owner, Some(config)))),
+            app_inner: Rc::new(RefCell::new(app_inner)),
         })
     }
+
+    /// Returns the identity owning the app's allowance wallet. The authenticator funds this
+    /// balance when it grants the app a coin allowance; pass the identity to the coin operations
+    /// to spend from it.
+    pub fn allowance_wallet_id(&self) -> ClientFullId {
+        self.app_inner.borrow().keys.allowance_wallet_id()
+    }
 }
 
 impl Client for AppClient {
     type Context = AppContext;
 
     fn full_id(&self) -> SafeKey {
-        let app_inner = self.app_inner.borrow();
-        app_inner.keys.app_safe_key()
+        self.app_inner.borrow().safe_key()
     }
 
     fn owner_key(&self) -> PublicKey {
@@ -211,15 +259,30 @@ impl fmt::Debug for AppClient {
 struct AppInner {
     keys: AppKeys,
     owner_key: PublicKey,
+    // Signs the app's requests instead of the secret key in `keys`, if set.
+    signer: Option<Arc<dyn Signer>>,
     config: Option<BootstrapConfig>,
 }
 
 impl AppInner {
-    pub fn new(keys: AppKeys, owner_key: PublicKey, config: Option<BootstrapConfig>) -> Self {
+    pub fn new(
+        keys: AppKeys,
+        owner_key: PublicKey,
+        signer: Option<Arc<dyn Signer>>,
+        config: Option<BootstrapConfig>,
+    ) -> Self {
         Self {
             keys,
             owner_key,
+            signer,
             config,
         }
     }
+
+    fn safe_key(&self) -> SafeKey {
+        match self.signer {
+            Some(ref signer) => SafeKey::external(Arc::clone(signer)),
+            None => self.keys.app_safe_key(),
+        }
+    }
 }
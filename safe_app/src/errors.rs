@@ -47,6 +47,10 @@ pub enum AppError {
     InvalidFileMode,
     /// Tried to access a client key from an unregistered client.
     UnregisteredClientAccess,
+    /// The app is not permitted to read the coin balance.
+    GetBalanceDenied,
+    /// The app is not permitted to transfer coins.
+    TransferCoinsDenied,
 
     /// Invalid CipherOpt handle.
     InvalidCipherOptHandle,
@@ -103,6 +107,12 @@ impl Display for AppError {
                 formatter,
                 "Tried to access a client key from an unregistered client",
             ),
+            Self::GetBalanceDenied => {
+                write!(formatter, "App is not permitted to read the coin balance")
+            }
+            Self::TransferCoinsDenied => {
+                write!(formatter, "App is not permitted to transfer coins")
+            }
             Self::InvalidEncryptPubKeyHandle => {
                 write!(formatter, "Invalid encrypt (threshold_crypto) key handle")
             }
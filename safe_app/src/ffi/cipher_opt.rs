@@ -85,6 +85,36 @@ pub unsafe extern "C" fn cipher_opt_new_asymmetric(
     });
 }
 
+/// Construct `CipherOpt::AuthenticatedAsymmetric` handle.
+#[no_mangle]
+pub unsafe extern "C" fn cipher_opt_new_authenticated_asymmetric(
+    app: *const App,
+    peer_encrypt_key_h: EncryptPubKeyHandle,
+    user_data: *mut c_void,
+    o_cb: extern "C" fn(user_data: *mut c_void, result: *const FfiResult, handle: CipherOptHandle),
+) {
+    let user_data = OpaqueCtx(user_data);
+
+    catch_unwind_cb(user_data, o_cb, || {
+        (*app).send(move |_, context| {
+            let pk = try_cb!(
+                context
+                    .object_cache()
+                    .get_encrypt_key(peer_encrypt_key_h)
+                    .map_err(Error::from),
+                user_data,
+                o_cb
+            );
+            let cipher_opt = CipherOpt::AuthenticatedAsymmetric {
+                peer_encrypt_key: *pk,
+            };
+            let handle = context.object_cache().insert_cipher_opt(cipher_opt);
+            o_cb(user_data.0, FFI_RESULT_OK, handle);
+            None
+        })
+    });
+}
+
 /// Free `CipherOpt` handle.
 #[no_mangle]
 pub unsafe extern "C" fn cipher_opt_free(
@@ -111,14 +141,18 @@ pub unsafe extern "C" fn cipher_opt_free(
 mod tests {
     use super::*;
     use crate::client::AppClient;
+    use crate::errors::AppError;
     use crate::ffi::errors::codes::{
         ERR_INVALID_CIPHER_OPT_HANDLE, ERR_INVALID_ENCRYPT_PUB_KEY_HANDLE,
     };
     use crate::ffi::object_cache::CipherOptHandle;
     use crate::test_utils::create_app;
     use crate::{run, App, AppContext};
+    use bincode::serialize;
     use ffi_utils::test_utils::{call_0, call_1};
+    use futures::Future;
     use safe_core::{utils, Client};
+    use safe_nd::Error as SndError;
     use unwrap::unwrap;
 
     // Test plaintext "encryption" and decryption.
@@ -130,10 +164,11 @@ mod tests {
         let cipher_opt_handle: CipherOptHandle =
             unsafe { unwrap!(call_1(|ud, cb| cipher_opt_new_plaintext(&app_0, ud, cb))) };
 
-        let (plain_text, cipher_text) = unwrap!(run(&app_0, move |_, context| {
+        let (plain_text, cipher_text) = unwrap!(run(&app_0, move |client, context| {
             let cipher_opt = unwrap!(context.object_cache().get_cipher_opt(cipher_opt_handle));
-            let cipher_text = unwrap!(cipher_opt.encrypt(&plain_text, context));
-            Ok((plain_text, cipher_text))
+            cipher_opt
+                .encrypt(&plain_text, context, client)
+                .map(move |cipher_text| (plain_text, cipher_text))
         }));
         assert_ne!(cipher_text, plain_text);
         assert_free(&app_0, cipher_opt_handle, 0);
@@ -162,10 +197,11 @@ mod tests {
         let cipher_opt_handle: CipherOptHandle =
             unsafe { unwrap!(call_1(|ud, cb| cipher_opt_new_symmetric(&app_0, ud, cb))) };
 
-        let (plain_text, cipher_text) = unwrap!(run(&app_0, move |_, context| {
+        let (plain_text, cipher_text) = unwrap!(run(&app_0, move |client, context| {
             let cipher_opt = unwrap!(context.object_cache().get_cipher_opt(cipher_opt_handle));
-            let cipher_text = unwrap!(cipher_opt.encrypt(&plain_text, context));
-            Ok((plain_text, cipher_text))
+            cipher_opt
+                .encrypt(&plain_text, context, client)
+                .map(move |cipher_text| (plain_text, cipher_text))
         }));
         assert_ne!(cipher_text, plain_text);
         assert_free(&app_0, cipher_opt_handle, 0);
@@ -211,10 +247,11 @@ mod tests {
 
         // Encrypt the plaintext on App 0's end.
         let plain_text = unwrap!(utils::generate_random_vector::<u8>(10));
-        let (plain_text, cipher_text) = unwrap!(run(&app_0, move |_, context| {
+        let (plain_text, cipher_text) = unwrap!(run(&app_0, move |client, context| {
             let cipher_opt = unwrap!(context.object_cache().get_cipher_opt(cipher_opt_h));
-            let cipher_text = unwrap!(cipher_opt.encrypt(&plain_text, context));
-            Ok((plain_text, cipher_text))
+            cipher_opt
+                .encrypt(&plain_text, context, client)
+                .map(move |cipher_text| (plain_text, cipher_text))
         }));
 
         assert_ne!(cipher_text, plain_text);
@@ -249,6 +286,85 @@ mod tests {
         }));
     }
 
+    // Test authenticated asymmetric encryption and decryption.
+    // 1. Encrypt a message from App 0 to App 1 and verify that App 1 can decrypt it and learns
+    //    that App 0 wrote it, while App 0 can't decrypt it.
+    // 2. Replace the sender's signature with one made by App 2 and verify that decryption fails.
+    #[test]
+    fn app_0_to_app_1_authenticated_asym() {
+        let app_0 = create_app();
+        let app_1 = create_app();
+        let app_2 = create_app();
+
+        let enc_pk = unwrap!(run(&app_1, move |client, _| Ok(
+            client.public_encryption_key()
+        )));
+        let enc_pk_h = unwrap!(run(&app_0, move |_, context| {
+            Ok(context.object_cache().insert_encrypt_key(enc_pk))
+        }));
+
+        let cipher_opt_h = unsafe {
+            unwrap!(call_1(|ud, cb| cipher_opt_new_authenticated_asymmetric(
+                &app_0, enc_pk_h, ud, cb
+            )))
+        };
+
+        let plain_text = unwrap!(utils::generate_random_vector::<u8>(10));
+        let (plain_text, cipher_text, sender) = unwrap!(run(&app_0, move |client, context| {
+            let cipher_opt = unwrap!(context.object_cache().get_cipher_opt(cipher_opt_h));
+            let sender = client.public_key();
+            cipher_opt
+                .encrypt(&plain_text, context, client)
+                .map(move |cipher_text| (plain_text, cipher_text, sender))
+        }));
+        assert_ne!(cipher_text, plain_text);
+        assert_free(&app_0, cipher_opt_h, 0);
+
+        let (plain_text, cipher_text) = unwrap!(run(&app_0, move |client, context| {
+            assert!(!decrypt_and_check(
+                client,
+                context,
+                &cipher_text,
+                &plain_text
+            ));
+            Ok((plain_text, cipher_text))
+        }));
+
+        let plain_text = unwrap!(run(&app_1, move |client, context| {
+            let (decrypted, decrypted_sender) = unwrap!(CipherOpt::decrypt_with_sender(
+                &cipher_text,
+                context,
+                client
+            ));
+            assert_eq!(decrypted, plain_text);
+            assert_eq!(decrypted_sender, Some(sender));
+            Ok(plain_text)
+        }));
+
+        // A payload signed by App 2 but claiming to be from App 0 is rejected.
+        let forged = unwrap!(run(&app_2, move |client, _| {
+            client
+                .full_id()
+                .sign(&unwrap!(serialize(&(enc_pk, &plain_text))))
+                .map(move |signature| {
+                    // Serialised like a `SignedPayload` wrapped in
+                    // `WireFormat::AuthenticatedAsymmetric`.
+                    let payload = (sender, signature, plain_text);
+                    let cipher_text = enc_pk.encrypt(unwrap!(serialize(&payload)));
+                    unwrap!(serialize(&(3u32, cipher_text)))
+                })
+                .map_err(AppError::from)
+        }));
+
+        unwrap!(run(&app_1, move |client, context| {
+            match CipherOpt::decrypt(&forged, context, client) {
+                Err(AppError::SndError(SndError::InvalidSignature)) => (),
+                x => panic!("Unexpected {:?}", x),
+            }
+            Ok(())
+        }));
+    }
+
     // Test creating and freeing the different possible cipher option handles.
     #[test]
     fn create_and_free() {
@@ -0,0 +1,330 @@
+// Copyright 2019 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under the MIT license <LICENSE-MIT
+// https://opensource.org/licenses/MIT> or the Modified BSD license <LICENSE-BSD
+// https://opensource.org/licenses/BSD-3-Clause>, at your option. This file may not be copied,
+// modified, or distributed except according to those terms. Please review the Licences for the
+// specific language governing permissions and limitations relating to use of the SAFE Network
+// Software.
+
+//! Coin balance operations. All amounts are expressed in nano coins.
+
+use crate::errors::AppError;
+use crate::ffi::errors::Error;
+use crate::ffi::helper::send;
+use crate::ffi::object_cache::SignPubKeyHandle;
+use crate::App;
+use ffi_utils::{call_result_cb, catch_unwind_cb, FfiResult, OpaqueCtx, SafePtr, FFI_RESULT_OK};
+use futures::Future;
+use safe_core::core_structs::TransactionRecord as NativeTransactionRecord;
+use safe_core::ffi::arrays::XorNameArray;
+use safe_core::ffi::coins::TransactionRecord;
+use safe_core::{fry, Client, CoreError, FutureExt};
+use safe_nd::{Coins, Error as SndError, XorName};
+use std::os::raw::c_void;
+
+/// Get the coin balance of the account the app is authorised for.
+///
+/// Fails with `ERR_GET_BALANCE_DENIED` if the app was not granted the `get_balance` permission.
+///
+/// Callback parameters: user data, error code, balance
+#[no_mangle]
+pub unsafe extern "C" fn app_get_balance(
+    app: *const App,
+    user_data: *mut c_void,
+    o_cb: extern "C" fn(user_data: *mut c_void, result: *const FfiResult, balance: u64),
+) {
+    catch_unwind_cb(user_data, o_cb, || {
+        send(app, user_data, o_cb, move |client, _| {
+            client
+                .get_balance(None)
+                .map(|balance| balance.as_nano())
+                .map_err(|err| coin_error(err, AppError::GetBalanceDenied))
+        })
+    })
+}
+
+/// Transfer `amount` coins from the account's balance to the balance at `destination`.
+///
+/// Fails with `ERR_TRANSFER_COINS_DENIED` if the app was not granted the `transfer_coins`
+/// permission, which is also the case once the app has been given a coin allowance, and with
+/// `ERR_INSUFFICIENT_BALANCE` if the balance can't cover the amount.
+///
+/// Callback parameters: user data, error code, transaction ID
+#[no_mangle]
+pub unsafe extern "C" fn app_transfer_coins(
+    app: *const App,
+    destination: *const XorNameArray,
+    amount: u64,
+    user_data: *mut c_void,
+    o_cb: extern "C" fn(user_data: *mut c_void, result: *const FfiResult, transaction_id: u64),
+) {
+    catch_unwind_cb(user_data, o_cb, || {
+        let destination = XorName(*destination);
+        let amount = Coins::from_nano(amount).map_err(AppError::from)?;
+
+        send(app, user_data, o_cb, move |client, _| {
+            client
+                .transfer_coins(None, destination, amount, None)
+                .map(|transaction| transaction.id)
+                .map_err(|err| coin_error(err, AppError::TransferCoinsDenied))
+        })
+    })
+}
+
+/// Get the balance of the app's allowance wallet, the coins left of the allowance the user
+/// granted the app.
+///
+/// Fails with `ERR_NO_SUCH_BALANCE` if the app hasn't been granted an allowance.
+///
+/// Callback parameters: user data, error code, balance
+#[no_mangle]
+pub unsafe extern "C" fn app_allowance_balance(
+    app: *const App,
+    user_data: *mut c_void,
+    o_cb: extern "C" fn(user_data: *mut c_void, result: *const FfiResult, balance: u64),
+) {
+    catch_unwind_cb(user_data, o_cb, || {
+        send(app, user_data, o_cb, move |client, _| {
+            client
+                .get_balance(Some(&client.allowance_wallet_id()))
+                .map(|balance| balance.as_nano())
+                .map_err(AppError::from)
+        })
+    })
+}
+
+/// Transfer `amount` coins from the app's allowance wallet to the balance at `destination`.
+///
+/// Fails with `ERR_INSUFFICIENT_BALANCE` if what's left of the allowance can't cover the amount
+/// and with `ERR_NO_SUCH_BALANCE` if the app hasn't been granted an allowance.
+///
+/// Callback parameters: user data, error code, transaction ID
+#[no_mangle]
+pub unsafe extern "C" fn app_allowance_transfer_coins(
+    app: *const App,
+    destination: *const XorNameArray,
+    amount: u64,
+    user_data: *mut c_void,
+    o_cb: extern "C" fn(user_data: *mut c_void, result: *const FfiResult, transaction_id: u64),
+) {
+    catch_unwind_cb(user_data, o_cb, || {
+        let destination = XorName(*destination);
+        let amount = Coins::from_nano(amount).map_err(AppError::from)?;
+
+        send(app, user_data, o_cb, move |client, _| {
+            client
+                .transfer_coins(
+                    Some(&client.allowance_wallet_id()),
+                    destination,
+                    amount,
+                    None,
+                )
+                .map(|transaction| transaction.id)
+                .map_err(AppError::from)
+        })
+    })
+}
+
+/// Create a new coin balance owned by the key at `new_balance_owner_h`, preloading it with
+/// `amount` coins from the account's balance.
+///
+/// Fails with `ERR_TRANSFER_COINS_DENIED` if the app is not permitted to spend from the balance
+/// and with `ERR_INSUFFICIENT_BALANCE` if the balance can't cover the amount and the fee.
+///
+/// Callback parameters: user data, error code, transaction ID
+#[no_mangle]
+pub unsafe extern "C" fn app_create_balance(
+    app: *const App,
+    new_balance_owner_h: SignPubKeyHandle,
+    amount: u64,
+    user_data: *mut c_void,
+    o_cb: extern "C" fn(user_data: *mut c_void, result: *const FfiResult, transaction_id: u64),
+) {
+    catch_unwind_cb(user_data, o_cb, || {
+        let amount = Coins::from_nano(amount).map_err(AppError::from)?;
+
+        send(app, user_data, o_cb, move |client, context| {
+            let new_balance_owner =
+                *fry!(context.object_cache().get_pub_sign_key(new_balance_owner_h));
+
+            client
+                .create_balance(None, new_balance_owner, amount, None)
+                .map(|transaction| transaction.id)
+                .map_err(|err| coin_error(err, AppError::TransferCoinsDenied))
+                .into_box()
+        })
+    })
+}
+
+/// Get the transaction history of the account's coin balance, oldest first.
+///
+/// Fails with `ERR_GET_BALANCE_DENIED` if the app was not granted the `get_balance` permission.
+///
+/// Callback parameters: user data, error code, transaction records vector, vector size
+#[no_mangle]
+pub unsafe extern "C" fn app_transaction_history(
+    app: *const App,
+    user_data: *mut c_void,
+    o_cb: extern "C" fn(
+        user_data: *mut c_void,
+        result: *const FfiResult,
+        transactions: *const TransactionRecord,
+        transactions_len: usize,
+    ),
+) {
+    let user_data = OpaqueCtx(user_data);
+
+    catch_unwind_cb(user_data, o_cb, || {
+        (*app).send(move |client, _| {
+            client
+                .get_transaction_history(None)
+                .map(move |history| {
+                    let history: Vec<_> = history
+                        .into_iter()
+                        .map(NativeTransactionRecord::into_repr_c)
+                        .collect();
+
+                    o_cb(
+                        user_data.0,
+                        FFI_RESULT_OK,
+                        history.as_safe_ptr(),
+                        history.len(),
+                    );
+                })
+                .map_err(move |err| {
+                    let err = Error::from(coin_error(err, AppError::GetBalanceDenied));
+                    call_result_cb!(Err::<(), _>(err), user_data, o_cb);
+                })
+                .into_box()
+                .into()
+        })
+    })
+}
+
+// Vaults reject a coin operation the app has no permission for with a generic `AccessDenied`,
+// so replace it with the error naming the missing permission.
+fn coin_error(err: CoreError, denied: AppError) -> AppError {
+    match err {
+        CoreError::DataError(SndError::AccessDenied) => denied,
+        err => AppError::from(err),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ffi::errors::{
+        ERR_GET_BALANCE_DENIED, ERR_INSUFFICIENT_BALANCE, ERR_TRANSFER_COINS_DENIED,
+    };
+    use crate::run;
+    use crate::test_utils::{create_app_by_req, create_random_auth_req};
+    use ffi_utils::test_utils::call_1;
+    use rand::thread_rng;
+    use safe_nd::{AppPermissions, ClientFullId, Request};
+    use std::str::FromStr;
+    use unwrap::unwrap;
+
+    // Coin operations from an app without coin permissions must fail with the typed error codes.
+    #[test]
+    fn coins_denied() {
+        let mut auth_req = create_random_auth_req();
+        auth_req.app_permissions = AppPermissions {
+            transfer_coins: false,
+            perform_mutations: false,
+            get_balance: false,
+        };
+        let app = unwrap!(create_app_by_req(&auth_req));
+        let destination: XorNameArray = rand::random();
+
+        let res: Result<u64, i32> = unsafe { call_1(|ud, cb| app_get_balance(&app, ud, cb)) };
+        match res {
+            Err(ERR_GET_BALANCE_DENIED) => (),
+            res => panic!("Unexpected result: {:?}", res),
+        }
+
+        let res: Result<u64, i32> =
+            unsafe { call_1(|ud, cb| app_transfer_coins(&app, &destination, 1, ud, cb)) };
+        match res {
+            Err(ERR_TRANSFER_COINS_DENIED) => (),
+            res => panic!("Unexpected result: {:?}", res),
+        }
+    }
+
+    // 1. Authorise an app with the `get_balance` and `transfer_coins` permissions.
+    // 2. Create a balance owned by another key, preloading it with some coins. Verify the
+    //    account's balance has been debited these coins plus the mock vault's price for creating
+    //    a balance.
+    // 3. Transfer some coins to the created balance and verify they were moved from the account's
+    //    balance to it.
+    // 4. Try to transfer more coins than the balance holds. This must fail with
+    //    `ERR_INSUFFICIENT_BALANCE`.
+    #[test]
+    fn coins_allowed() {
+        let mut auth_req = create_random_auth_req();
+        auth_req.app_permissions = AppPermissions {
+            transfer_coins: true,
+            perform_mutations: true,
+            get_balance: true,
+        };
+        let app = unwrap!(create_app_by_req(&auth_req));
+        let amount = unwrap!(Coins::from_str("1.0"));
+
+        let owner_id = ClientFullId::new_bls(&mut thread_rng());
+        let owner_pk = *owner_id.public_id().public_key();
+        let owner_h = unwrap!(run(&app, move |_, context| {
+            Ok(context.object_cache().insert_pub_sign_key(owner_pk))
+        }));
+        let fee = unwrap!(run(&app, move |client, _| {
+            let request = Request::CreateBalance {
+                new_balance_owner: owner_pk,
+                amount,
+                transaction_id: 0,
+            };
+            client.estimate_cost(&[request]).map_err(AppError::from)
+        }));
+
+        let balance: u64 = unsafe { unwrap!(call_1(|ud, cb| app_get_balance(&app, ud, cb))) };
+        let _: u64 = unsafe {
+            unwrap!(call_1(|ud, cb| app_create_balance(
+                &app,
+                owner_h,
+                amount.as_nano(),
+                ud,
+                cb
+            )))
+        };
+
+        let new_balance: u64 = unsafe { unwrap!(call_1(|ud, cb| app_get_balance(&app, ud, cb))) };
+        assert_eq!(new_balance, balance - amount.as_nano() - fee.as_nano());
+
+        let destination = XorName::from(owner_pk).0;
+        let transferred = unwrap!(Coins::from_str("0.5"));
+        let _: u64 = unsafe {
+            unwrap!(call_1(|ud, cb| app_transfer_coins(
+                &app,
+                &destination,
+                transferred.as_nano(),
+                ud,
+                cb
+            )))
+        };
+
+        let balance = new_balance;
+        let new_balance: u64 = unsafe { unwrap!(call_1(|ud, cb| app_get_balance(&app, ud, cb))) };
+        assert_eq!(new_balance, balance - transferred.as_nano());
+
+        let owner_balance = unwrap!(run(&app, move |client, _| {
+            client.get_balance(Some(&owner_id)).map_err(AppError::from)
+        }));
+        assert_eq!(owner_balance, unwrap!(amount.checked_add(transferred)));
+
+        let res: Result<u64, i32> = unsafe {
+            call_1(|ud, cb| app_transfer_coins(&app, &destination, new_balance + 1, ud, cb))
+        };
+        match res {
+            Err(ERR_INSUFFICIENT_BALANCE) => (),
+            res => panic!("Unexpected result: {:?}", res),
+        }
+    }
+}
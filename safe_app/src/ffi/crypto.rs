@@ -7,6 +7,7 @@
 // specific language governing permissions and limitations relating to use of the SAFE Network
 // Software.
 
+use crate::cipher_opt;
 use crate::errors::AppError;
 use crate::ffi::errors::{Error, Result};
 use crate::ffi::helper::send_sync;
@@ -18,9 +19,10 @@ use crate::App;
 use bincode::{deserialize, serialize};
 use ffi_utils::{call_result_cb, try_cb};
 use ffi_utils::{catch_unwind_cb, vec_clone_from_raw_parts, FfiResult, OpaqueCtx, FFI_RESULT_OK};
+use futures::Future;
 use rand::thread_rng;
 use safe_core::crypto::shared_box;
-use safe_core::{AsymPublicKey, Client};
+use safe_core::{ok, AsymPublicKey, Client, FutureExt};
 use safe_nd::{ClientFullId, PublicKey, Signature};
 use std::os::raw::c_void;
 use std::slice;
@@ -438,8 +440,11 @@ pub unsafe extern "C" fn sign(
         (*app)
             .send(move |client, context| {
                 let signature = if sign_sk_h == SIGN_WITH_APP {
-                    let safe_key = client.full_id();
-                    safe_key.sign(&plaintext)
+                    client
+                        .full_id()
+                        .sign(&plaintext)
+                        .map_err(Error::from)
+                        .into_box()
                 } else {
                     let sign_sk = try_cb!(
                         context
@@ -449,15 +454,24 @@ pub unsafe extern "C" fn sign(
                         user_data,
                         o_cb
                     );
-                    sign_sk.sign(&plaintext)
+                    ok!(sign_sk.sign(&plaintext))
                 };
-                match serialize(&signature) {
-                    Ok(result) => o_cb(user_data.0, FFI_RESULT_OK, result.as_ptr(), result.len()),
-                    res @ Err(..) => {
-                        call_result_cb!(res.map_err(Error::from), user_data, o_cb);
-                    }
-                }
-                None
+
+                signature
+                    .and_then(|signature| Ok(serialize(&signature)?))
+                    .then(move |result| {
+                        match result {
+                            Ok(result) => {
+                                o_cb(user_data.0, FFI_RESULT_OK, result.as_ptr(), result.len())
+                            }
+                            res @ Err(..) => {
+                                call_result_cb!(res, user_data, o_cb);
+                            }
+                        }
+                        Ok(())
+                    })
+                    .into_box()
+                    .into()
             })
             .map_err(Error::from)
     })
@@ -703,6 +717,120 @@ pub unsafe extern "C" fn decrypt_sealed_box(
     })
 }
 
+/// Encrypts arbitrary data for a single recipient, signed by the app so that the recipient can
+/// verify who wrote it.
+///
+/// You should provide a recipient's public key.
+#[no_mangle]
+pub unsafe extern "C" fn encrypt_authenticated_sealed_box(
+    app: *const App,
+    data: *const u8,
+    data_len: usize,
+    public_key_h: EncryptPubKeyHandle,
+    user_data: *mut c_void,
+    o_cb: extern "C" fn(
+        user_data: *mut c_void,
+        result: *const FfiResult,
+        ciphertext: *const u8,
+        ciphertext_len: usize,
+    ),
+) {
+    catch_unwind_cb(user_data, o_cb, || {
+        let plaintext = vec_clone_from_raw_parts(data, data_len);
+        let user_data = OpaqueCtx(user_data);
+
+        (*app)
+            .send(move |client, context| {
+                let pk: AsymEncryptKey = *try_cb!(
+                    context
+                        .object_cache()
+                        .get_encrypt_key(public_key_h)
+                        .map_err(Error::from),
+                    user_data,
+                    o_cb
+                );
+
+                cipher_opt::seal_authenticated(&plaintext, &pk, &client.full_id())
+                    .and_then(|ciphertext| Ok(serialize(&ciphertext)?))
+                    .map_err(Error::from)
+                    .then(move |result| {
+                        match result {
+                            Ok(ciphertext) => o_cb(
+                                user_data.0,
+                                FFI_RESULT_OK,
+                                ciphertext.as_ptr(),
+                                ciphertext.len(),
+                            ),
+                            res @ Err(..) => {
+                                call_result_cb!(res, user_data, o_cb);
+                            }
+                        }
+                        Ok(())
+                    })
+                    .into_box()
+                    .into()
+            })
+            .map_err(Error::from)
+    })
+}
+
+/// Decrypts data encrypted with `encrypt_authenticated_sealed_box` and verifies the signature of
+/// its sender.
+///
+/// Callback parameters: user data, error code, plaintext, plaintext length, sender's public sign
+/// key handle
+#[no_mangle]
+pub unsafe extern "C" fn decrypt_authenticated_sealed_box(
+    app: *const App,
+    data: *const u8,
+    data_len: usize,
+    secret_key_h: EncryptSecKeyHandle,
+    user_data: *mut c_void,
+    o_cb: extern "C" fn(
+        user_data: *mut c_void,
+        result: *const FfiResult,
+        plaintext: *const u8,
+        plaintext_len: usize,
+        sender_h: SignPubKeyHandle,
+    ),
+) {
+    catch_unwind_cb(user_data, o_cb, || {
+        let user_data = OpaqueCtx(user_data);
+        let ciphertext = vec_clone_from_raw_parts(data, data_len);
+        let deserialized: Ciphertext =
+            deserialize(ciphertext.as_slice()).map_err(AppError::from)?;
+
+        (*app)
+            .send(move |_, context| {
+                let sk = try_cb!(
+                    context
+                        .object_cache()
+                        .get_secret_key(secret_key_h)
+                        .map_err(Error::from),
+                    user_data,
+                    o_cb
+                );
+
+                let (plaintext, sender) = try_cb!(
+                    cipher_opt::open_authenticated(&deserialized, &sk).map_err(Error::from),
+                    user_data,
+                    o_cb
+                );
+                let sender_h = context.object_cache().insert_pub_sign_key(sender);
+
+                o_cb(
+                    user_data.0,
+                    FFI_RESULT_OK,
+                    plaintext.as_ptr(),
+                    plaintext.len(),
+                    sender_h,
+                );
+                None
+            })
+            .map_err(Error::from)
+    })
+}
+
 /// Returns a sha3 hash for a given data.
 #[no_mangle]
 pub unsafe extern "C" fn sha3_hash(
@@ -733,8 +861,11 @@ mod tests {
     use crate::ffi::mutable_data::permissions::USER_ANYONE;
     use crate::run;
     use crate::test_utils::create_app;
-    use ffi_utils::test_utils::{call_0, call_1, call_2, call_vec_u8};
+    use ffi_utils::test_utils::{
+        call_0, call_1, call_2, call_vec_u8, send_via_user_data, sender_as_user_data,
+    };
     use ffi_utils::vec_into_raw_parts;
+    use std::sync::mpsc;
 
     // Test signing and verifying messages between apps.
     #[test]
@@ -920,6 +1051,90 @@ mod tests {
         assert_eq!(&decrypted, data);
     }
 
+    // Test encrypting and decrypting authenticated sealed box messages between apps.
+    // 1. Encrypt a message for app2 from app1.
+    // 2. Decrypt it in app2 and verify that the returned sender key is app1's.
+    // 3. Verify that it can't be decrypted as a plain sealed box.
+    #[test]
+    fn encrypt_decrypt_authenticated_sealed() {
+        let app1 = create_app();
+        let app2 = create_app();
+
+        let (app2_pk2_h, app2_sk2_h): (EncryptPubKeyHandle, EncryptSecKeyHandle) =
+            unsafe { unwrap!(call_2(|ud, cb| enc_generate_key_pair(&app2, ud, cb))) };
+
+        let pk2_raw: AsymPublicKey =
+            unsafe { unwrap!(call_1(|ud, cb| enc_pub_key_get(&app2, app2_pk2_h, ud, cb))) };
+        let app1_pk2_h =
+            unsafe { unwrap!(call_1(|ud, cb| enc_pub_key_new(&app1, &pk2_raw, ud, cb))) };
+
+        let data = b"authenticated sealed box message";
+        let encrypted = unsafe {
+            unwrap!(call_vec_u8(|ud, cb| encrypt_authenticated_sealed_box(
+                &app1,
+                data.as_ptr(),
+                data.len(),
+                app1_pk2_h,
+                ud,
+                cb
+            )))
+        };
+
+        let (tx, rx) = mpsc::channel::<::std::result::Result<(Vec<u8>, SignPubKeyHandle), i32>>();
+        let mut ud = Default::default();
+        unsafe {
+            decrypt_authenticated_sealed_box(
+                &app2,
+                encrypted.as_ptr(),
+                encrypted.len(),
+                app2_sk2_h,
+                sender_as_user_data(&tx, &mut ud),
+                decrypt_cb,
+            )
+        };
+        let (decrypted, sender_h) = unwrap!(unwrap!(rx.recv()));
+        assert_eq!(&decrypted, data);
+
+        let app1_sign_key = unwrap!(run(&app1, |client, _| Ok(client.public_key())));
+        unwrap!(run(&app2, move |_, context| {
+            let sender = unwrap!(context.object_cache().get_pub_sign_key(sender_h));
+            assert_eq!(*sender, app1_sign_key);
+            Ok(())
+        }));
+
+        let res = unsafe {
+            call_vec_u8(|ud, cb| {
+                decrypt_sealed_box(
+                    &app2,
+                    encrypted.as_ptr(),
+                    encrypted.len(),
+                    app2_sk2_h,
+                    ud,
+                    cb,
+                )
+            })
+        };
+        assert!(res.is_err());
+
+        extern "C" fn decrypt_cb(
+            user_data: *mut c_void,
+            res: *const FfiResult,
+            plaintext: *const u8,
+            plaintext_len: usize,
+            sender_h: SignPubKeyHandle,
+        ) {
+            unsafe {
+                let result = if (*res).error_code == 0 {
+                    Ok((vec_clone_from_raw_parts(plaintext, plaintext_len), sender_h))
+                } else {
+                    Err((*res).error_code)
+                };
+
+                send_via_user_data(user_data, result);
+            }
+        }
+    }
+
     // Test creating and fetching public sign keys.
     #[test]
     fn sign_public_key_basics() {
@@ -27,3 +27,5 @@ pub const ERR_INVALID_FILE_MODE: i32 = 13;
 pub const ERR_INVALID_SIGN_SEC_KEY_HANDLE: i32 = 14;
 pub const ERR_UNREGISTERED_CLIENT_ACCESS: i32 = 15;
 pub const ERR_INVALID_PUB_KEY_HANDLE: i32 = 16;
+pub const ERR_GET_BALANCE_DENIED: i32 = 17;
+pub const ERR_TRANSFER_COINS_DENIED: i32 = 18;
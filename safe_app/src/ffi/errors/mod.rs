@@ -159,8 +159,15 @@ impl ErrorCode for Error {
                 IpcError::Unexpected(_) => ERR_UNEXPECTED,
                 IpcError::StringError(_) => ERR_STRING_ERROR,
                 IpcError::ShareMDataDenied => ERR_SHARE_MDATA_DENIED,
+                IpcError::ShareADataDenied => ERR_SHARE_ADATA_DENIED,
+                IpcError::TransOwnershipDenied => ERR_TRANS_OWNERSHIP_DENIED,
+                IpcError::PermissionsDenied => ERR_PERMISSIONS_DENIED,
                 IpcError::InvalidOwner(..) => ERR_INVALID_OWNER,
                 IpcError::IncompatibleMockStatus => ERR_INCOMPATIBLE_MOCK_STATUS,
+                IpcError::ReqIdMismatch => ERR_REQ_ID_MISMATCH,
+                IpcError::InvalidSignature => ERR_IPC_INVALID_SIGNATURE,
+                IpcError::UnsupportedVersion(_) => ERR_UNSUPPORTED_VERSION,
+                IpcError::TransOwnershipFailed(..) => ERR_TRANS_OWNERSHIP_FAILED,
             },
             AppError::NfsError(ref err) => match *err {
                 NfsError::CoreError(ref err) => core_error_code(err),
@@ -187,6 +194,8 @@ impl ErrorCode for Error {
             AppError::InvalidFileContextHandle => ERR_INVALID_FILE_CONTEXT_HANDLE,
             AppError::InvalidFileMode => ERR_INVALID_FILE_MODE,
             AppError::UnregisteredClientAccess => ERR_UNREGISTERED_CLIENT_ACCESS,
+            AppError::GetBalanceDenied => ERR_GET_BALANCE_DENIED,
+            AppError::TransferCoinsDenied => ERR_TRANSFER_COINS_DENIED,
             AppError::SelfEncryption(_) => ERR_SELF_ENCRYPTION,
             AppError::InvalidSelfEncryptorReadOffsets => ERR_INVALID_SELF_ENCRYPTOR_READ_OFFSETS,
             AppError::IoError(_) => ERR_IO_ERROR,
@@ -277,6 +286,9 @@ fn core_error_code(err: &CoreError) -> i32 {
         CoreError::RequestTimeout => ERR_REQUEST_TIMEOUT,
         CoreError::ConfigError(_) => ERR_CONFIG_FILE,
         CoreError::IoError(_) => ERR_IO,
+        CoreError::InsufficientSignatureShares => ERR_INSUFFICIENT_SIGNATURE_SHARES,
+        CoreError::CostEstimateUnsupported => ERR_COST_ESTIMATE_UNSUPPORTED,
+        CoreError::TransactionHistoryUnsupported => ERR_TRANSACTION_HISTORY_UNSUPPORTED,
         CoreError::Unexpected(_) => ERR_UNEXPECTED,
     }
 }
@@ -19,7 +19,7 @@ use ffi_utils::{call_result_cb, try_cb};
 use ffi_utils::{catch_unwind_cb, vec_clone_from_raw_parts, FfiResult, OpaqueCtx, FFI_RESULT_OK};
 use futures::Future;
 use safe_core::ffi::arrays::XorNameArray;
-use safe_core::{immutable_data, Client, FutureExt, SelfEncryptionStorage};
+use safe_core::{fry, immutable_data, Client, FutureExt, SelfEncryptionStorage};
 use safe_nd::{IDataAddress, IDataKind, XorName};
 use self_encryption::{SelfEncryptor, SequentialEncryptor};
 use std::os::raw::c_void;
@@ -117,6 +117,7 @@ pub unsafe extern "C" fn idata_close_self_encryptor(
         (*app).send(move |client, context| {
             let client2 = client.clone();
             let client3 = client.clone();
+            let client4 = client.clone();
             let context2 = context.clone();
 
             let se_writer = try_cb!(
@@ -132,22 +133,22 @@ pub unsafe extern "C" fn idata_close_self_encryptor(
                 .close()
                 .map_err(Error::from)
                 .and_then(move |(data_map, _)| {
-                    let ser_data_map = serialize(&data_map)?;
-                    let enc_data_map = {
-                        let cipher_opt = context2.object_cache().get_cipher_opt(cipher_opt_h)?;
-                        cipher_opt.encrypt(&ser_data_map, &context2)?
-                    };
+                    let ser_data_map = fry!(serialize(&data_map));
+                    let cipher_opt = fry!(context2.object_cache().get_cipher_opt(cipher_opt_h));
 
-                    Ok(enc_data_map)
+                    cipher_opt
+                        .encrypt(&ser_data_map, &context2, &client2)
+                        .map_err(Error::from)
+                        .into_box()
                 })
                 .and_then(move |enc_data_map| {
-                    immutable_data::create(&client2, &enc_data_map, published, None)
+                    immutable_data::create(&client3, &enc_data_map, published, None)
                         .map_err(Error::from)
                 })
                 .and_then(move |data| {
                     let name = *data.name();
 
-                    client3
+                    client4
                         .put_idata(data)
                         .map_err(Error::from)
                         .map(move |_| name)
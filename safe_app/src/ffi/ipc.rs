@@ -9,20 +9,30 @@
 
 //! App-related IPC utilities.
 
+use crate::errors::AppError;
 use crate::ffi::errors::{Error, Result};
 use bincode::serialize;
 use ffi_utils::ffi_error;
 use ffi_utils::{
     catch_unwind_cb, vec_clone_from_raw_parts, FfiResult, NativeResult, ReprC, FFI_RESULT_OK,
 };
-use safe_core::ffi::ipc::req::{AuthReq, ContainersReq, ShareMDataRequest};
+use safe_core::crypto::shared_box;
+use safe_core::ffi::arrays::BlsPublicKey;
+use safe_core::ffi::ipc::req::{
+    AuthReq, ContainersReq, PermissionsReq, ShareADataRequest, ShareMDataRequest,
+    TransOwnershipRequest,
+};
 use safe_core::ffi::ipc::resp::AuthGranted;
 use safe_core::ipc::{
-    self, AuthReq as NativeAuthReq, ContainersReq as NativeContainersReq, IpcError, IpcMsg, IpcReq,
-    IpcResp, ShareMDataReq as NativeShareMDataReq,
+    self, AuthReq as NativeAuthReq, ContainersReq as NativeContainersReq, IpcEncoding, IpcError,
+    IpcFormat, IpcMsg, IpcReq, IpcResp, PermissionsReq as NativePermissionsReq,
+    ShareADataReq as NativeShareADataReq, ShareMDataReq as NativeShareMDataReq,
+    TransOwnershipReq as NativeTransOwnershipReq,
 };
+use safe_nd::PublicKey;
 use std::ffi::CString;
 use std::os::raw::{c_char, c_void};
+use std::ptr;
 
 /// Encode `AuthReq`.
 #[no_mangle]
@@ -91,6 +101,28 @@ pub unsafe extern "C" fn encode_containers_req(
     })
 }
 
+/// Encode `PermissionsReq`.
+#[no_mangle]
+pub unsafe extern "C" fn encode_permissions_req(
+    req: *const PermissionsReq,
+    user_data: *mut c_void,
+    o_cb: extern "C" fn(
+        user_data: *mut c_void,
+        result: *const FfiResult,
+        req_id: u32,
+        encoded: *const c_char,
+    ),
+) {
+    catch_unwind_cb(user_data, o_cb, || -> Result<_> {
+        let req_id = ipc::gen_req_id();
+        let req = NativePermissionsReq::clone_from_repr_c(req)?;
+
+        let encoded = encode_ipc(req_id, IpcReq::Permissions(req))?;
+        o_cb(user_data, FFI_RESULT_OK, req_id, encoded.as_ptr());
+        Ok(())
+    })
+}
+
 /// Encode `AuthReq` for an unregistered client.
 #[no_mangle]
 pub unsafe extern "C" fn encode_unregistered_req(
@@ -136,11 +168,66 @@ pub unsafe extern "C" fn encode_share_mdata_req(
     })
 }
 
+/// Encode `ShareADataReq`.
+#[no_mangle]
+pub unsafe extern "C" fn encode_share_adata_req(
+    req: *const ShareADataRequest,
+    user_data: *mut c_void,
+    o_cb: extern "C" fn(
+        user_data: *mut c_void,
+        result: *const FfiResult,
+        req_id: u32,
+        encoded: *const c_char,
+    ),
+) {
+    catch_unwind_cb(user_data, o_cb, || -> Result<_> {
+        let req_id = ipc::gen_req_id();
+        let req = NativeShareADataReq::clone_from_repr_c(req)?;
+
+        let encoded = encode_ipc(req_id, IpcReq::ShareAData(req))?;
+        o_cb(user_data, FFI_RESULT_OK, req_id, encoded.as_ptr());
+        Ok(())
+    })
+}
+
+/// Encode `TransOwnershipReq`.
+#[no_mangle]
+pub unsafe extern "C" fn encode_trans_ownership_req(
+    req: *const TransOwnershipRequest,
+    user_data: *mut c_void,
+    o_cb: extern "C" fn(
+        user_data: *mut c_void,
+        result: *const FfiResult,
+        req_id: u32,
+        encoded: *const c_char,
+    ),
+) {
+    catch_unwind_cb(user_data, o_cb, || -> Result<_> {
+        let req_id = ipc::gen_req_id();
+        let req = NativeTransOwnershipReq::clone_from_repr_c(req)?;
+
+        let encoded = encode_ipc(req_id, IpcReq::TransOwnership(req))?;
+        o_cb(user_data, FFI_RESULT_OK, req_id, encoded.as_ptr());
+        Ok(())
+    })
+}
+
 fn encode_ipc(req_id: u32, req: IpcReq) -> Result<CString> {
-    let encoded = ipc::encode_msg(&IpcMsg::Req {
-        req_id,
-        request: req,
-    })?;
+    // Authenticators only seal responses for apps that advertise they can open them, which needs
+    // the versioned format.
+    let format = match req {
+        IpcReq::Auth(ref auth_req) if auth_req.response_key.is_some() => {
+            IpcFormat::current(IpcEncoding::Bincode)
+        }
+        _ => IpcFormat::legacy(),
+    };
+    let encoded = ipc::encode_msg_as(
+        &IpcMsg::Req {
+            req_id,
+            request: req,
+        },
+        format,
+    )?;
     Ok(CString::new(encoded)?)
 }
 
@@ -153,7 +240,57 @@ fn encode_ipc_64(req_id: u32, req: IpcReq) -> Result<CString> {
     Ok(CString::new(encoded)?)
 }
 
+/// Decoded IPC response answers an `AuthReq`, with `DecodedIpcResp::auth_granted` set.
+pub const IPC_RESP_AUTH: u32 = 0;
+/// Decoded IPC response answers an unregistered request, with the serialised bootstrap config
+/// set in `DecodedIpcResp::serialised_cfg`.
+pub const IPC_RESP_UNREGISTERED: u32 = 1;
+/// Decoded IPC response grants a `ContainersReq`.
+pub const IPC_RESP_CONTAINERS: u32 = 2;
+/// Decoded IPC response grants a `ShareMDataReq`.
+pub const IPC_RESP_SHARE_MDATA: u32 = 3;
+/// Decoded IPC response grants a `ShareADataReq`.
+pub const IPC_RESP_SHARE_ADATA: u32 = 4;
+/// Decoded IPC response grants a `TransOwnershipReq`.
+pub const IPC_RESP_TRANS_OWNERSHIP: u32 = 5;
+/// Decoded IPC response grants a `PermissionsReq`.
+pub const IPC_RESP_PERMISSIONS: u32 = 6;
+/// Decoded IPC message notifies the app that it has been revoked. `DecodedIpcResp::req_id` is 0.
+pub const IPC_RESP_REVOKED: u32 = 7;
+
+/// IPC response decoded by `decode_ipc_resp`. Only the fields of the response's `kind` are set,
+/// the others are null. Fields for new kinds of responses are appended, so callers built against
+/// an older version keep working.
+#[repr(C)]
+pub struct DecodedIpcResp {
+    /// Kind of the response, one of the `IPC_RESP_*` constants.
+    pub kind: u32,
+    /// ID of the request the response answers.
+    pub req_id: u32,
+    /// Set for `IPC_RESP_AUTH`.
+    pub auth_granted: *const AuthGranted,
+    /// Serialised bootstrap config, set for `IPC_RESP_UNREGISTERED`.
+    pub serialised_cfg: *const u8,
+    /// Length of `serialised_cfg`.
+    pub serialised_cfg_len: usize,
+}
+
+impl DecodedIpcResp {
+    fn new(kind: u32, req_id: u32) -> Self {
+        Self {
+            kind,
+            req_id,
+            auth_granted: ptr::null(),
+            serialised_cfg: ptr::null(),
+            serialised_cfg_len: 0,
+        }
+    }
+}
+
 /// Decode IPC message.
+///
+/// Only the responses this function has a callback for are decoded. Any other response is
+/// reported through `o_err`; use `decode_ipc_resp` to decode every kind of response.
 #[no_mangle]
 pub unsafe extern "C" fn decode_ipc_msg(
     msg: *const c_char,
@@ -183,9 +320,7 @@ pub unsafe extern "C" fn decode_ipc_msg(
             o_share_mdata,
             o_revoked,
             o_err,
-        )?;
-
-        Ok(())
+        )
     })
 }
 
@@ -220,8 +355,121 @@ unsafe extern "C" fn decode_ipc_msg_64(
             o_share_mdata,
             o_revoked,
             o_err,
-        )?;
+        )
+    })
+}
+
+/// Decode an IPC response of any kind and pass it to `o_resp`. The decoded response is only
+/// valid for the duration of the callback. Responses denying a request are reported through
+/// `o_err`.
+///
+/// New kinds of responses are passed to the same callback, so unlike `decode_ipc_msg` this
+/// function keeps its signature as requests are added.
+#[no_mangle]
+pub unsafe extern "C" fn decode_ipc_resp(
+    msg: *const c_char,
+    user_data: *mut c_void,
+    o_resp: extern "C" fn(user_data: *mut c_void, resp: *const DecodedIpcResp),
+    o_err: extern "C" fn(user_data: *mut c_void, result: *const FfiResult, req_id: u32),
+) {
+    catch_unwind_cb(user_data, o_err, || -> Result<_> {
+        let msg = String::clone_from_repr_c(msg)?;
+        let msg = ipc::decode_msg(&msg)?;
 
+        decode_ipc_resp_impl(msg, user_data, o_err, |resp| {
+            o_resp(user_data, resp);
+            Ok(())
+        })
+    })
+}
+
+/// Generate an ephemeral key pair for sealed IPC responses.
+///
+/// The public key goes into `AuthReq::response_key`. The serialised secret key is needed to open
+/// the response with `open_sealed_ipc_msg`.
+#[no_mangle]
+pub unsafe extern "C" fn gen_response_key_pair(
+    user_data: *mut c_void,
+    o_cb: extern "C" fn(
+        user_data: *mut c_void,
+        result: *const FfiResult,
+        public_key: *const BlsPublicKey,
+        secret_key: *const u8,
+        secret_key_len: usize,
+    ),
+) {
+    catch_unwind_cb(user_data, o_cb, || -> Result<_> {
+        let (public_key, secret_key) = shared_box::gen_keypair();
+        let secret_key = serialize(&secret_key)?;
+
+        o_cb(
+            user_data,
+            FFI_RESULT_OK,
+            &public_key.to_bytes(),
+            secret_key.as_ptr(),
+            secret_key.len(),
+        );
+        Ok(())
+    })
+}
+
+/// Open a sealed IPC response to the request with the given ID.
+///
+/// The response is decrypted with the serialised secret key obtained from
+/// `gen_response_key_pair`, and must be signed by `signer`: the public key of the user's account,
+/// pinned from an earlier response or given to the app out of band by the authenticator's
+/// `auth_account_public_key`. Apps which don't know the key yet pass a null `signer` to trust the
+/// signer of their first response, and should pin the key passed to the callback for the later
+/// ones. Responses to other requests are rejected, and so are unsealed responses. On success the
+/// callback receives the response in the legacy unsealed encoding, ready to be passed to
+/// `decode_ipc_resp`, along with the verified signer. Messages which aren't responses, like
+/// revocation notices, are passed through unchanged, with a null signer.
+#[no_mangle]
+pub unsafe extern "C" fn open_sealed_ipc_msg(
+    msg: *const c_char,
+    secret_key: *const u8,
+    secret_key_len: usize,
+    signer: *const BlsPublicKey,
+    req_id: u32,
+    user_data: *mut c_void,
+    o_cb: extern "C" fn(
+        user_data: *mut c_void,
+        result: *const FfiResult,
+        msg: *const c_char,
+        signer: *const BlsPublicKey,
+    ),
+) {
+    catch_unwind_cb(user_data, o_cb, || -> Result<_> {
+        let msg = String::clone_from_repr_c(msg)?;
+        let raw = vec_clone_from_raw_parts(secret_key, secret_key_len);
+        let secret_key =
+            shared_box::SecretKey::from_raw(&raw).map_err(|_| AppError::EncodeDecodeError)?;
+        let signer = if signer.is_null() {
+            None
+        } else {
+            let signer = threshold_crypto::PublicKey::from_bytes(*signer)
+                .map_err(|_| AppError::EncodeDecodeError)?;
+            Some(PublicKey::Bls(signer))
+        };
+
+        let (msg, signer) = ipc::decode_sealed_msg(&msg, &secret_key, req_id, signer.as_ref())?;
+        let signer = match signer {
+            Some(PublicKey::Bls(signer)) => Some(signer.to_bytes()),
+            Some(_) => {
+                return Err(Error::from(AppError::Unexpected(
+                    "The signer key isn't a BLS key".to_owned(),
+                )))
+            }
+            None => None,
+        };
+        let encoded = CString::new(ipc::encode_msg(&msg)?)?;
+
+        o_cb(
+            user_data,
+            FFI_RESULT_OK,
+            encoded.as_ptr(),
+            signer.as_ref().map_or(ptr::null(), |signer| signer),
+        );
         Ok(())
     })
 }
@@ -242,105 +490,93 @@ fn decode_ipc_msg_impl(
     o_revoked: extern "C" fn(user_data: *mut c_void),
     o_err: extern "C" fn(user_data: *mut c_void, result: *const FfiResult, req_id: u32),
 ) -> Result<()> {
-    match msg {
-        IpcMsg::Resp {
-            response: IpcResp::Auth(res),
-            req_id,
-        } => match res {
-            Ok(auth_granted) => match auth_granted.into_repr_c() {
-                Ok(auth_granted) => {
-                    o_auth(user_data, req_id, &auth_granted);
-                }
-                Err(err) => {
-                    let e = Error::from(err);
-                    let (error_code, description) = ffi_error!(e);
-                    let res = NativeResult {
-                        error_code,
-                        description: Some(description),
-                    }
-                    .into_repr_c()?;
-                    o_err(user_data, &res, req_id);
-                }
-            },
-            Err(err) => {
-                let e = Error::from(err);
-                let (error_code, description) = ffi_error!(e);
-                let res = NativeResult {
-                    error_code,
-                    description: Some(description),
-                }
-                .into_repr_c()?;
-                o_err(user_data, &res, req_id);
-            }
-        },
-        IpcMsg::Resp {
-            response: IpcResp::Containers(res),
-            req_id,
-        } => match res {
-            Ok(()) => o_containers(user_data, req_id),
-            Err(err) => {
-                let e = Error::from(err);
-                let (error_code, description) = ffi_error!(e);
-                let res = NativeResult {
-                    error_code,
-                    description: Some(description),
-                }
-                .into_repr_c()?;
-                o_err(user_data, &res, req_id);
-            }
-        },
-        IpcMsg::Resp {
-            response: IpcResp::Unregistered(res),
-            req_id,
-        } => match res {
-            Ok(bootstrap_cfg) => {
-                let serialised_cfg = serialize(&bootstrap_cfg)?;
-                o_unregistered(
-                    user_data,
-                    req_id,
-                    serialised_cfg.as_ptr(),
-                    serialised_cfg.len(),
-                );
-            }
-            Err(err) => {
-                let e = Error::from(err);
-                let (error_code, description) = ffi_error!(e);
-                let res = NativeResult {
-                    error_code,
-                    description: Some(description),
-                }
-                .into_repr_c()?;
-                o_err(user_data, &res, req_id);
-            }
-        },
-        IpcMsg::Resp {
-            response: IpcResp::ShareMData(res),
-            req_id,
-        } => match res {
-            Ok(()) => o_share_mdata(user_data, req_id),
-            Err(err) => {
-                let e = Error::from(err);
-                let (error_code, description) = ffi_error!(e);
-                let res = NativeResult {
-                    error_code,
-                    description: Some(description),
-                }
-                .into_repr_c()?;
-                o_err(user_data, &res, req_id);
-            }
-        },
-        IpcMsg::Revoked { .. } => o_revoked(user_data),
-        _ => {
-            return Err(IpcError::InvalidMsg.into());
+    decode_ipc_resp_impl(msg, user_data, o_err, |resp| {
+        match resp.kind {
+            IPC_RESP_AUTH => o_auth(user_data, resp.req_id, resp.auth_granted),
+            IPC_RESP_UNREGISTERED => o_unregistered(
+                user_data,
+                resp.req_id,
+                resp.serialised_cfg,
+                resp.serialised_cfg_len,
+            ),
+            IPC_RESP_CONTAINERS => o_containers(user_data, resp.req_id),
+            IPC_RESP_SHARE_MDATA => o_share_mdata(user_data, resp.req_id),
+            IPC_RESP_REVOKED => o_revoked(user_data),
+            _ => call_err_cb(user_data, o_err, resp.req_id, IpcError::InvalidMsg.into())?,
         }
+        Ok(())
+    })
+}
+
+fn decode_ipc_resp_impl<F>(
+    msg: IpcMsg,
+    user_data: *mut c_void,
+    o_err: extern "C" fn(user_data: *mut c_void, result: *const FfiResult, req_id: u32),
+    o_resp: F,
+) -> Result<()>
+where
+    F: FnOnce(&DecodedIpcResp) -> Result<()>,
+{
+    let (req_id, response) = match msg {
+        IpcMsg::Resp { req_id, response } => (req_id, response),
+        IpcMsg::Revoked { .. } => return o_resp(&DecodedIpcResp::new(IPC_RESP_REVOKED, 0)),
+        _ => return Err(IpcError::InvalidMsg.into()),
     };
 
+    match response {
+        IpcResp::Auth(Ok(auth_granted)) => match auth_granted.into_repr_c() {
+            Ok(auth_granted) => o_resp(&DecodedIpcResp {
+                auth_granted: &auth_granted,
+                ..DecodedIpcResp::new(IPC_RESP_AUTH, req_id)
+            }),
+            Err(err) => call_err_cb(user_data, o_err, req_id, Error::from(err)),
+        },
+        IpcResp::Unregistered(Ok(bootstrap_cfg)) => {
+            let serialised_cfg = serialize(&bootstrap_cfg)?;
+            o_resp(&DecodedIpcResp {
+                serialised_cfg: serialised_cfg.as_ptr(),
+                serialised_cfg_len: serialised_cfg.len(),
+                ..DecodedIpcResp::new(IPC_RESP_UNREGISTERED, req_id)
+            })
+        }
+        IpcResp::Containers(Ok(())) => o_resp(&DecodedIpcResp::new(IPC_RESP_CONTAINERS, req_id)),
+        IpcResp::ShareMData(Ok(())) => o_resp(&DecodedIpcResp::new(IPC_RESP_SHARE_MDATA, req_id)),
+        IpcResp::ShareAData(Ok(())) => o_resp(&DecodedIpcResp::new(IPC_RESP_SHARE_ADATA, req_id)),
+        IpcResp::TransOwnership(Ok(())) => {
+            o_resp(&DecodedIpcResp::new(IPC_RESP_TRANS_OWNERSHIP, req_id))
+        }
+        IpcResp::Permissions(Ok(())) => o_resp(&DecodedIpcResp::new(IPC_RESP_PERMISSIONS, req_id)),
+        IpcResp::Auth(Err(err))
+        | IpcResp::Unregistered(Err(err))
+        | IpcResp::Containers(Err(err))
+        | IpcResp::ShareMData(Err(err))
+        | IpcResp::ShareAData(Err(err))
+        | IpcResp::TransOwnership(Err(err))
+        | IpcResp::Permissions(Err(err)) => call_err_cb(user_data, o_err, req_id, Error::from(err)),
+    }
+}
+
+// Reports an error to the app in the format of the decode callbacks.
+fn call_err_cb(
+    user_data: *mut c_void,
+    o_err: extern "C" fn(user_data: *mut c_void, result: *const FfiResult, req_id: u32),
+    req_id: u32,
+    err: Error,
+) -> Result<()> {
+    let (error_code, description) = ffi_error!(err);
+    let res = NativeResult {
+        error_code,
+        description: Some(description),
+    }
+    .into_repr_c()?;
+    o_err(user_data, &res, req_id);
     Ok(())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::ffi::errors::{ERR_INVALID_MSG, ERR_IPC_INVALID_SIGNATURE, ERR_REQ_ID_MISMATCH};
     use crate::test_utils;
     use ffi_utils::test_utils::{call_1, call_2};
     use ffi_utils::ReprC;
@@ -350,13 +586,16 @@ mod tests {
     use safe_core::core_structs::{AccessContInfo, AccessContainerEntry, AppKeys};
     use safe_core::crypto::{shared_box, shared_secretbox};
     use safe_core::ffi::ipc::resp::AuthGranted as FfiAuthGranted;
+    use safe_core::ipc::req::{OwnedData, ShareADataPermissions};
     use safe_core::ipc::{
         self, AuthGranted, BootstrapConfig, ContainersReq, IpcMsg, IpcReq, IpcResp, Permission,
-        ShareMData, ShareMDataReq,
+        PermissionsReq, ShareAData, ShareADataReq, ShareMData, ShareMDataReq, TransOwnership,
+        TransOwnershipReq,
     };
     use safe_core::utils;
-    use safe_core::utils::test_utils::{gen_app_id, gen_client_id};
-    use safe_nd::{MDataAction, MDataPermissionSet};
+    use safe_core::utils::test_utils::{gen_app_id, gen_bls_keypair, gen_client_id};
+    use safe_core::{ok, Client};
+    use safe_nd::{ADataAddress, AppPermissions, MDataAction, MDataAddress, MDataPermissionSet};
     use std::collections::HashMap;
     use std::ffi::CString;
     use std::os::raw::c_void;
@@ -618,6 +857,231 @@ mod tests {
         assert_eq!(decoded_req, req);
     }
 
+    // Test encoding and decoding requests to share append-only data
+    #[test]
+    fn encode_share_adata_basics() {
+        let req = ShareADataReq {
+            app: test_utils::gen_app_exchange_info(),
+            adata: vec![ShareAData {
+                address: ADataAddress::UnpubSeq {
+                    name: rand::random(),
+                    tag: rand::random(),
+                },
+                perms: ShareADataPermissions {
+                    read: true,
+                    append: true,
+                    manage_permissions: false,
+                },
+            }],
+        };
+
+        let req_c = unwrap!(req.clone().into_repr_c());
+
+        let (req_id, encoded): (u32, String) =
+            unsafe { unwrap!(call_2(|ud, cb| encode_share_adata_req(&req_c, ud, cb))) };
+
+        // Decode it and verify it's the same we encoded.
+        let msg = unwrap!(ipc::decode_msg(&encoded));
+
+        let (decoded_req_id, decoded_req) = match msg {
+            IpcMsg::Req {
+                req_id,
+                request: IpcReq::ShareAData(req),
+            } => (req_id, req),
+            x => panic!("Unexpected {:?}", x),
+        };
+
+        assert_eq!(decoded_req_id, req_id);
+        assert_eq!(decoded_req, req);
+    }
+
+    // Test opening a sealed authentication response.
+    #[test]
+    fn open_sealed_auth_resp() {
+        let auth = auth_utils::create_account_and_login();
+
+        let (response_key, secret_key) = shared_box::gen_keypair();
+        let secret_key = unwrap!(serialize(&secret_key));
+
+        let mut auth_req = test_utils::create_random_auth_req();
+        auth_req.response_key = Some(response_key);
+        let req_id = ipc::gen_req_id();
+
+        let encoded_auth_resp: String = unsafe {
+            unwrap!(call_1(|ud, cb| {
+                let auth_req = unwrap!(auth_req.into_repr_c());
+                encode_auth_resp(
+                    &auth, &auth_req, req_id, true, // is_granted
+                    ud, cb,
+                )
+            }))
+        };
+        let encoded_auth_resp = unwrap!(CString::new(encoded_auth_resp));
+
+        // The sealed response is rejected by the legacy decoder.
+        match ipc::decode_msg(unwrap!(encoded_auth_resp.to_str())) {
+            Ok(IpcMsg::Sealed(..)) => (),
+            x => panic!("Unexpected {:?}", x),
+        }
+
+        let signer = match unwrap!(safe_authenticator::run(&auth, |client| ok!(
+            client.public_key()
+        ))) {
+            PublicKey::Bls(key) => key.to_bytes(),
+            x => panic!("Unexpected {:?}", x),
+        };
+        let open = |req_id,
+                    signer: *const BlsPublicKey|
+         -> ::std::result::Result<(String, BlsPublicKey), i32> {
+            unsafe {
+                call_2(|ud, cb| {
+                    open_sealed_ipc_msg(
+                        encoded_auth_resp.as_ptr(),
+                        secret_key.as_ptr(),
+                        secret_key.len(),
+                        signer,
+                        req_id,
+                        ud,
+                        cb,
+                    )
+                })
+            }
+        };
+
+        // Opening the response to a different request must fail.
+        match open(req_id.wrapping_add(1), &signer) {
+            Err(ERR_REQ_ID_MISMATCH) => (),
+            x => panic!("Unexpected {:?}", x),
+        }
+
+        // So must opening a response signed by another account than the expected one.
+        let other_signer = threshold_crypto::SecretKey::random()
+            .public_key()
+            .to_bytes();
+        match open(req_id, &other_signer) {
+            Err(ERR_IPC_INVALID_SIGNATURE) => (),
+            x => panic!("Unexpected {:?}", x),
+        }
+
+        let (opened, received_signer) = unwrap!(open(req_id, &signer));
+        assert_eq!(received_signer, signer);
+        match unwrap!(ipc::decode_msg(&opened)) {
+            IpcMsg::Resp {
+                req_id: received_req_id,
+                response: IpcResp::Auth(Ok(_)),
+            } => assert_eq!(received_req_id, req_id),
+            x => panic!("Unexpected {:?}", x),
+        }
+
+        // Without a pinned signer, the response is opened and its signer returned to be pinned.
+        let (opened_unpinned, received_signer) = unwrap!(open(req_id, ptr::null()));
+        assert_eq!(received_signer, signer);
+        assert_eq!(opened_unpinned, opened);
+
+        // Unsealed responses are rejected, as anyone could have sent them.
+        let unsealed = unwrap!(CString::new(unwrap!(ipc::encode_msg(&IpcMsg::Resp {
+            req_id,
+            response: IpcResp::Containers(Ok(())),
+        }))));
+        let res: ::std::result::Result<(String, BlsPublicKey), i32> = unsafe {
+            call_2(|ud, cb| {
+                open_sealed_ipc_msg(
+                    unsealed.as_ptr(),
+                    secret_key.as_ptr(),
+                    secret_key.len(),
+                    &signer,
+                    req_id,
+                    ud,
+                    cb,
+                )
+            })
+        };
+        match res {
+            Err(ERR_INVALID_MSG) => (),
+            x => panic!("Unexpected {:?}", x),
+        }
+    }
+
+    // Test encoding a request to modify app permissions.
+    #[test]
+    fn encode_permissions_basics() {
+        let mut containers = HashMap::new();
+        let _ = containers.insert("_videos".to_string(), btree_set![Permission::Read]);
+        let _ = containers.insert("_documents".to_string(), Default::default());
+
+        let req = PermissionsReq {
+            app: test_utils::gen_app_exchange_info(),
+            containers,
+            app_permissions: Some(AppPermissions {
+                transfer_coins: false,
+                perform_mutations: true,
+                get_balance: true,
+            }),
+        };
+
+        let req_c = unwrap!(req.clone().into_repr_c());
+
+        let (req_id, encoded): (u32, String) =
+            unsafe { unwrap!(call_2(|ud, cb| encode_permissions_req(&req_c, ud, cb))) };
+
+        // Decode it and verify it's the same we encoded.
+        let msg = unwrap!(ipc::decode_msg(&encoded));
+
+        let (decoded_req_id, decoded_req) = match msg {
+            IpcMsg::Req {
+                req_id,
+                request: IpcReq::Permissions(req),
+            } => (req_id, req),
+            x => panic!("Unexpected {:?}", x),
+        };
+
+        assert_eq!(decoded_req_id, req_id);
+        assert_eq!(decoded_req, req);
+    }
+
+    // Test encoding a transfer ownership request.
+    #[test]
+    fn encode_trans_ownership_basics() {
+        let req = TransOwnershipReq {
+            app: test_utils::gen_app_exchange_info(),
+            data: vec![
+                TransOwnership {
+                    address: OwnedData::AData(ADataAddress::PubSeq {
+                        name: rand::random(),
+                        tag: rand::random(),
+                    }),
+                    new_owner: gen_bls_keypair().public_key(),
+                },
+                TransOwnership {
+                    address: OwnedData::MData(MDataAddress::Unseq {
+                        name: rand::random(),
+                        tag: rand::random(),
+                    }),
+                    new_owner: gen_bls_keypair().public_key(),
+                },
+            ],
+        };
+
+        let req_c = unwrap!(req.clone().into_repr_c());
+
+        let (req_id, encoded): (u32, String) =
+            unsafe { unwrap!(call_2(|ud, cb| encode_trans_ownership_req(&req_c, ud, cb))) };
+
+        // Decode it and verify it's the same we encoded.
+        let msg = unwrap!(ipc::decode_msg(&encoded));
+
+        let (decoded_req_id, decoded_req) = match msg {
+            IpcMsg::Req {
+                req_id,
+                request: IpcReq::TransOwnership(req),
+            } => (req_id, req),
+            x => panic!("Unexpected {:?}", x),
+        };
+
+        assert_eq!(decoded_req_id, req_id);
+        assert_eq!(decoded_req, req);
+    }
+
     // Test that `decode_ipc_msg` calls the `o_auth` callback.
     #[test]
     #[ignore] // FIXME: Remove this after ReprC is properly implemented for PublicKey
@@ -650,6 +1114,7 @@ mod tests {
                 unexpected_cb: false,
                 req_id: 0,
                 auth_granted: None,
+                kind: None,
             };
 
             extern "C" fn auth_cb(
@@ -736,6 +1201,7 @@ mod tests {
             unexpected_cb: false,
             req_id: 0,
             auth_granted: None,
+            kind: None,
         };
 
         unsafe {
@@ -817,6 +1283,7 @@ mod tests {
             unexpected_cb: false,
             req_id: 0,
             auth_granted: None,
+            kind: None,
         };
 
         unsafe {
@@ -898,6 +1365,7 @@ mod tests {
             unexpected_cb: false,
             req_id: 0,
             auth_granted: None,
+            kind: None,
         };
 
         unsafe {
@@ -962,6 +1430,73 @@ mod tests {
         assert_eq!(context.req_id, req_id);
     }
 
+    // Test that `decode_ipc_resp` passes every kind of response to the single callback, and that
+    // `decode_ipc_msg` reports the kinds it has no callback for as errors.
+    #[test]
+    fn decode_ipc_resp_kinds() {
+        extern "C" fn resp_cb(ctx: *mut c_void, resp: *const DecodedIpcResp) {
+            unsafe {
+                let ctx = ctx as *mut Context;
+                (*ctx).req_id = (*resp).req_id;
+                (*ctx).kind = Some((*resp).kind);
+            }
+        }
+
+        let responses = vec![
+            (IpcResp::Containers(Ok(())), IPC_RESP_CONTAINERS, true),
+            (IpcResp::ShareMData(Ok(())), IPC_RESP_SHARE_MDATA, true),
+            (IpcResp::ShareAData(Ok(())), IPC_RESP_SHARE_ADATA, false),
+            (
+                IpcResp::TransOwnership(Ok(())),
+                IPC_RESP_TRANS_OWNERSHIP,
+                false,
+            ),
+            (IpcResp::Permissions(Ok(())), IPC_RESP_PERMISSIONS, false),
+        ];
+
+        for (response, kind, legacy) in responses {
+            let req_id = ipc::gen_req_id();
+            let msg = IpcMsg::Resp { req_id, response };
+
+            let encoded = unwrap!(ipc::encode_msg(&msg));
+            let encoded = unwrap!(CString::new(encoded));
+
+            let mut context = Context::default();
+            let context_ptr: *mut Context = &mut context;
+            unsafe {
+                decode_ipc_resp(
+                    encoded.as_ptr(),
+                    context_ptr as *mut c_void,
+                    resp_cb,
+                    err_cb,
+                );
+            }
+
+            assert!(!context.unexpected_cb);
+            assert_eq!(context.req_id, req_id);
+            assert_eq!(context.kind, Some(kind));
+
+            // The legacy entry point only knows the kinds it has a callback for.
+            let mut context = Context::default();
+            let context_ptr: *mut Context = &mut context;
+            unsafe {
+                decode_ipc_msg(
+                    encoded.as_ptr(),
+                    context_ptr as *mut c_void,
+                    unexpected_auth_cb,
+                    unexpected_unregistered_cb,
+                    legacy_req_cb,
+                    legacy_req_cb,
+                    unexpected_revoked_cb,
+                    err_cb,
+                );
+            }
+
+            assert_eq!(context.unexpected_cb, !legacy);
+            assert_eq!(context.req_id, if legacy { req_id } else { 0 });
+        }
+    }
+
     fn gen_app_keys() -> AppKeys {
         let client_id = gen_client_id();
         let app_full_id = gen_app_id(client_id.public_id().clone());
@@ -976,10 +1511,12 @@ mod tests {
         }
     }
 
+    #[derive(Default)]
     struct Context {
         unexpected_cb: bool,
         req_id: u32,
         auth_granted: Option<AuthGranted>,
+        kind: Option<u32>,
     }
 
     extern "C" fn err_cb(ctx: *mut c_void, _res: *const FfiResult, _req_id: u32) {
@@ -988,4 +1525,41 @@ mod tests {
             (*ctx).unexpected_cb = true;
         }
     }
+
+    extern "C" fn legacy_req_cb(ctx: *mut c_void, req_id: u32) {
+        unsafe {
+            let ctx = ctx as *mut Context;
+            (*ctx).req_id = req_id;
+        }
+    }
+
+    extern "C" fn unexpected_auth_cb(
+        ctx: *mut c_void,
+        _req_id: u32,
+        _auth_granted: *const FfiAuthGranted,
+    ) {
+        unsafe {
+            let ctx = ctx as *mut Context;
+            (*ctx).unexpected_cb = true;
+        }
+    }
+
+    extern "C" fn unexpected_unregistered_cb(
+        ctx: *mut c_void,
+        _req_id: u32,
+        _bootstrap_cfg: *const u8,
+        _bootstrap_cfg_len: usize,
+    ) {
+        unsafe {
+            let ctx = ctx as *mut Context;
+            (*ctx).unexpected_cb = true;
+        }
+    }
+
+    extern "C" fn unexpected_revoked_cb(ctx: *mut c_void) {
+        unsafe {
+            let ctx = ctx as *mut Context;
+            (*ctx).unexpected_cb = true;
+        }
+    }
 }
@@ -15,6 +15,8 @@
 pub mod access_container;
 /// Cipher options operations.
 pub mod cipher_opt;
+/// Coin balance operations.
+pub mod coins;
 /// Crypto-related routines.
 pub mod crypto;
 /// Errors
@@ -41,17 +43,21 @@ mod helper;
 #[cfg(test)]
 mod tests;
 
-use super::App;
+use super::{App, AppError};
 use bincode::deserialize;
 use errors::{Error, Result};
 use ffi_utils::try_cb;
 use ffi_utils::{catch_unwind_cb, FfiResult, OpaqueCtx, ReprC, FFI_RESULT_OK};
+use safe_core::ffi::arrays::Signature;
 use safe_core::ffi::ipc::resp::AuthGranted;
+use safe_core::ffi::signer::{send_signature, FfiSigner, SignatureTx};
 use safe_core::ipc::{AuthGranted as NativeAuthGranted, BootstrapConfig};
 use safe_core::{self, config_handler, Client};
+use safe_nd::PublicId;
 use std::ffi::{CStr, CString, OsStr};
 use std::os::raw::{c_char, c_void};
 use std::slice;
+use std::sync::Arc;
 
 /// Constant to pass to certain functions that take a version when the next version should be
 /// retrieved and used automatically.
@@ -112,6 +118,61 @@ pub unsafe extern "C" fn app_registered(
     })
 }
 
+/// Create a registered app whose requests are signed by the caller, e.g. with a hardware wallet
+/// holding the app's key, instead of with the secret key in `auth_granted`.
+/// `o_sign` is called, possibly from another thread, with each message to sign and the channel
+/// to send the signature back on with `app_send_signature`.
+/// The `user_data` parameter corresponds to the first parameter of the
+/// `o_sign`, `o_cb` and `o_disconnect_notifier_cb` callbacks, and must stay valid as long as
+/// the app.
+#[no_mangle]
+pub unsafe extern "C" fn app_registered_with_signer(
+    app_id: *const c_char,
+    auth_granted: *const AuthGranted,
+    user_data: *mut c_void,
+    o_sign: extern "C" fn(
+        user_data: *mut c_void,
+        msg: *const u8,
+        msg_len: usize,
+        signature_tx: *mut SignatureTx,
+    ),
+    o_disconnect_notifier_cb: extern "C" fn(user_data: *mut c_void),
+    o_cb: extern "C" fn(user_data: *mut c_void, result: *const FfiResult, app: *mut App),
+) {
+    catch_unwind_cb(user_data, o_cb, || -> Result<_> {
+        let user_data = OpaqueCtx(user_data);
+        let app_id = String::clone_from_repr_c(app_id)?;
+        let auth_granted = NativeAuthGranted::clone_from_repr_c(auth_granted)?;
+
+        let public_id = PublicId::App(auth_granted.app_keys.app_full_id.public_id().clone());
+        let signer = Arc::new(FfiSigner::new(public_id, user_data.0, o_sign));
+
+        let app = App::registered_with_signer(app_id, auth_granted, signer, move || {
+            o_disconnect_notifier_cb(user_data.0)
+        })?;
+
+        o_cb(user_data.0, FFI_RESULT_OK, Box::into_raw(Box::new(app)));
+
+        Ok(())
+    })
+}
+
+/// Send the signature requested by `o_sign` of `app_registered_with_signer` back on
+/// `signature_tx`, which can't be used afterwards. Pass a null `signature` to refuse to sign.
+#[no_mangle]
+pub unsafe extern "C" fn app_send_signature(
+    signature_tx: *mut SignatureTx,
+    signature: *const Signature,
+    user_data: *mut c_void,
+    o_cb: extern "C" fn(user_data: *mut c_void, result: *const FfiResult),
+) {
+    catch_unwind_cb(user_data, o_cb, || -> Result<_> {
+        send_signature(signature_tx, signature).map_err(AppError::from)?;
+        o_cb(user_data, FFI_RESULT_OK);
+        Ok(())
+    })
+}
+
 /// Try to restore a failed connection with the network.
 #[no_mangle]
 pub unsafe extern "C" fn app_reconnect(
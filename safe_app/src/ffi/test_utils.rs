@@ -101,6 +101,8 @@ mod tests {
             app_container: false,
             app_permissions: Default::default(),
             containers,
+            response_key: None,
+            ttl: None,
         };
         let auth_req = unwrap!(auth_req.into_repr_c());
 
@@ -150,6 +150,8 @@ fn test_app_container_name() {
             app_permissions: Default::default(),
             app_container: true,
             containers: HashMap::new(),
+            response_key: None,
+            ttl: None,
         },
     ));
 
@@ -179,6 +181,8 @@ fn app_authentication() {
         app_permissions: Default::default(),
         app_container: true,
         containers,
+        response_key: None,
+        ttl: None,
     };
     let auth_req = unwrap!(auth_req.into_repr_c());
 
@@ -47,6 +47,7 @@ pub use safe_nd::PubImmutableData;
 
 pub use crate::ffi::access_container::*;
 pub use crate::ffi::cipher_opt::*;
+pub use crate::ffi::coins::*;
 pub use crate::ffi::crypto::*;
 pub use crate::ffi::errors::codes::*;
 pub use crate::ffi::immutable_data::*;
@@ -90,6 +91,7 @@ use futures::stream::Stream;
 use futures::sync::mpsc as futures_mpsc;
 use futures::{future, Future, IntoFuture};
 use log::info;
+use safe_core::client::Signer;
 use safe_core::core_structs::{access_container_enc_key, AccessContInfo, AccessContainerEntry};
 use safe_core::crypto::shared_secretbox;
 use safe_core::ipc::{AuthGranted, BootstrapConfig};
@@ -100,7 +102,7 @@ use std::cell::RefCell;
 use std::collections::HashMap;
 use std::rc::Rc;
 use std::sync::mpsc;
-use std::sync::Mutex;
+use std::sync::{Arc, Mutex};
 use std::thread::{self, JoinHandle};
 use tokio::runtime::current_thread::{Handle, Runtime};
 use unwrap::unwrap;
@@ -163,6 +165,42 @@ impl App {
         Self::registered_impl(app_id, auth_granted, disconnect_notifier)
     }
 
+    /// Create registered app whose requests are signed by `signer`, e.g. a hardware wallet
+    /// holding the app's key, instead of the secret key in `auth_granted`. Fails if the signer
+    /// doesn't hold the key the app was authorised with.
+    pub fn registered_with_signer<N>(
+        app_id: String,
+        auth_granted: AuthGranted,
+        signer: Arc<dyn Signer>,
+        disconnect_notifier: N,
+    ) -> Result<Self, AppError>
+    where
+        N: FnMut() + Send + 'static,
+    {
+        let AuthGranted {
+            app_keys,
+            access_container_info,
+            bootstrap_config,
+            ..
+        } = auth_granted;
+        let enc_key = app_keys.enc_key.clone();
+        let owner_key = *app_keys.app_full_id.public_id().owner().public_key();
+
+        Self::new(disconnect_notifier, move |el_h, core_tx, net_tx| {
+            let client = AppClient::from_keys_with_signer(
+                app_keys,
+                owner_key,
+                signer,
+                el_h,
+                core_tx,
+                net_tx,
+                bootstrap_config,
+            )?;
+            let context = AppContext::registered(app_id, enc_key, access_container_info);
+            Ok((client, context))
+        })
+    }
+
     fn registered_impl<N>(
         app_id: String,
         auth_granted: AuthGranted,
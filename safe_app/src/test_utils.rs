@@ -94,6 +94,8 @@ pub fn create_auth_req(
             get_balance: true,
         },
         containers,
+        response_key: None,
+        ttl: None,
     }
 }
 
@@ -9,12 +9,17 @@
 
 //! Testing Safecoin operations from the apps point of view.
 
+use crate::ffi::errors::Error as FfiError;
 use crate::test_utils::{create_app, create_app_by_req, create_random_auth_req};
-use crate::{run, AppError};
+use crate::{run, App, AppError};
 use futures::Future;
+use safe_authenticator::config::AllowancePeriod;
+use safe_authenticator::test_utils as authenticator;
+use safe_authenticator::{allowances, run as auth_run};
 use safe_core::{Client, CoreError};
 use safe_nd::{AppPermissions, Coins, Error, XorName};
 use std::str::FromStr;
+use std::sync::mpsc;
 use unwrap::unwrap;
 
 // Apps should not be able to request the coin balance if they don't have
@@ -113,3 +118,175 @@ fn coin_app_allow_permissions() {
             })
     }));
 }
+
+// Apps with an allowance spend from their allowance wallet, never beyond the allowance.
+// 1. Create a user account and authorise an app with the `transfer_coins` permission.
+// 2. Grant the app an allowance of 2 coins in the authenticator.
+// 3. Try to transfer coins from the account's balance. This request must fail, as the app's
+//    `transfer_coins` permission has been withdrawn.
+// 4. Transfer 1 coin from the allowance wallet. The allowance must not be exhausted yet.
+// 5. Try to transfer 2 coins from the allowance wallet. The vault must refuse it.
+// 6. Transfer the last coin. The allowance must now be reported as exhausted.
+// 7. Raise the allowance. The app must be able to spend from its wallet again.
+// 8. Remove the allowance. The app must get its `transfer_coins` permission back.
+#[test]
+fn coin_app_allowance() {
+    let auth = authenticator::create_account_and_login();
+    let mut auth_req = create_random_auth_req();
+    auth_req.app_permissions = AppPermissions {
+        transfer_coins: true,
+        perform_mutations: true,
+        get_balance: true,
+    };
+    let app_id = auth_req.app.id.clone();
+    let auth_granted = unwrap!(authenticator::register_app(&auth, &auth_req));
+    let app = unwrap!(App::registered(app_id.clone(), auth_granted, || ()));
+
+    let set_allowance = |limit: &str| {
+        let app_id = app_id.clone();
+        let limit = unwrap!(Coins::from_str(limit));
+        unwrap!(auth_run(&auth, move |client| {
+            allowances::set_app_allowance(client, &app_id, limit, AllowancePeriod::Total)
+        }));
+    };
+    // Transfers back to the account's balance, which is sure to exist.
+    let transfer = |app: &App, from_wallet: bool, amount: &str| {
+        let amount = unwrap!(Coins::from_str(amount));
+        run(app, move |client, _app_context| {
+            let wallet_id = client.allowance_wallet_id();
+            let source = if from_wallet { Some(&wallet_id) } else { None };
+            client
+                .transfer_coins(source, XorName::from(client.owner_key()), amount, None)
+                .map(|_| ())
+                .map_err(AppError::from)
+        })
+    };
+    let status = |auth| {
+        let mut statuses = unwrap!(auth_run(auth, allowances::list_app_allowances));
+        assert_eq!(statuses.len(), 1);
+        statuses.remove(0)
+    };
+
+    set_allowance("2.0");
+
+    match transfer(&app, false, "1.0") {
+        Err(FfiError(AppError::CoreError(CoreError::DataError(Error::AccessDenied)))) => (),
+        res => panic!("Unexpected result: {:?}", res),
+    }
+
+    unwrap!(transfer(&app, true, "1.0"));
+    let status1 = status(&auth);
+    assert_eq!(status1.app_id, app_id);
+    assert_eq!(status1.spent(), unwrap!(Coins::from_str("1.0")));
+    assert_eq!(status1.remaining, unwrap!(Coins::from_str("1.0")));
+    assert!(!status1.exhausted());
+    assert!(!status1.near_limit());
+
+    match transfer(&app, true, "2.0") {
+        Err(FfiError(AppError::CoreError(CoreError::DataError(Error::InsufficientBalance)))) => (),
+        res => panic!("Unexpected result: {:?}", res),
+    }
+
+    unwrap!(transfer(&app, true, "1.0"));
+    let status2 = status(&auth);
+    assert!(status2.exhausted());
+    assert!(status2.near_limit());
+
+    // Only the difference is added: the app has 3 coins more to spend, not 5.
+    set_allowance("5.0");
+    let status3 = status(&auth);
+    assert!(!status3.exhausted());
+    assert_eq!(status3.remaining, unwrap!(Coins::from_str("3.0")));
+    unwrap!(transfer(&app, true, "1.0"));
+
+    let app_id2 = app_id.clone();
+    unwrap!(auth_run(&auth, move |client| {
+        allowances::remove_app_allowance(client, &app_id2)
+    }));
+    let statuses = unwrap!(auth_run(&auth, allowances::list_app_allowances));
+    assert!(statuses.is_empty());
+
+    match transfer(&app, true, "1.0") {
+        Err(FfiError(AppError::CoreError(CoreError::DataError(Error::InsufficientBalance)))) => (),
+        res => panic!("Unexpected result: {:?}", res),
+    }
+    unwrap!(transfer(&app, false, "1.0"));
+}
+
+// The authenticator reports apps that come near the limit of their allowance to the observer.
+// 1. Grant an app an allowance of 10 coins and register an observer. Nothing must be reported.
+// 2. Spend 8 coins from the allowance wallet. The app must not be reported, as it's still below
+//    the threshold.
+// 3. Spend another coin. The app must be reported when the allowances are next listed, and not
+//    again on the following listing.
+// 4. Raise the limit to 20 coins and spend up to the threshold again. The app must be reported
+//    again.
+#[test]
+fn coin_app_allowance_near_limit() {
+    let auth = authenticator::create_account_and_login();
+    let mut auth_req = create_random_auth_req();
+    auth_req.app_permissions = AppPermissions {
+        transfer_coins: true,
+        perform_mutations: true,
+        get_balance: true,
+    };
+    let app_id = auth_req.app.id.clone();
+    let auth_granted = unwrap!(authenticator::register_app(&auth, &auth_req));
+    let app = unwrap!(App::registered(app_id.clone(), auth_granted, || ()));
+
+    let set_allowance = |limit: &str| {
+        let app_id = app_id.clone();
+        let limit = unwrap!(Coins::from_str(limit));
+        unwrap!(auth_run(&auth, move |client| {
+            allowances::set_app_allowance(client, &app_id, limit, AllowancePeriod::Total)
+        }));
+    };
+    let spend = |amount: &str| {
+        let amount = unwrap!(Coins::from_str(amount));
+        unwrap!(run(&app, move |client, _app_context| {
+            let wallet_id = client.allowance_wallet_id();
+            client
+                .transfer_coins(
+                    Some(&wallet_id),
+                    XorName::from(client.owner_key()),
+                    amount,
+                    None,
+                )
+                .map(|_| ())
+                .map_err(AppError::from)
+        }));
+    };
+    let list = || {
+        let _ = unwrap!(auth_run(&auth, allowances::list_app_allowances));
+    };
+
+    set_allowance("10.0");
+
+    let (tx, rx) = mpsc::channel();
+    unwrap!(auth_run(&auth, move |client| {
+        allowances::set_near_limit_observer(client, move |status| unwrap!(tx.send(status.clone())))
+    }));
+    assert!(rx.try_recv().is_err());
+
+    spend("8.0");
+    list();
+    assert!(rx.try_recv().is_err());
+
+    spend("1.0");
+    list();
+    let status = unwrap!(rx.try_recv());
+    assert_eq!(status.app_id, app_id);
+    assert_eq!(status.spent(), unwrap!(Coins::from_str("9.0")));
+    assert!(status.near_limit());
+
+    list();
+    assert!(rx.try_recv().is_err());
+
+    set_allowance("20.0");
+    assert!(rx.try_recv().is_err());
+
+    spend("9.0");
+    list();
+    let status = unwrap!(rx.try_recv());
+    assert_eq!(status.spent(), unwrap!(Coins::from_str("18.0")));
+}
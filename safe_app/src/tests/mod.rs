@@ -10,6 +10,8 @@
 mod append_only_data;
 mod coins;
 mod unpublished_mutable_data;
+#[cfg(feature = "mock-network")]
+mod usage;
 
 use crate::ffi::test_utils::test_create_app_with_access;
 use crate::test_utils::{create_app, create_random_auth_req, gen_app_exchange_info};
@@ -18,10 +20,12 @@ use crate::{run, App, AppError};
 use ffi_utils::test_utils::call_1;
 use futures::Future;
 use log::trace;
+use rand::thread_rng;
 use safe_authenticator::test_utils as authenticator;
 use safe_authenticator::test_utils::revoke;
 use safe_authenticator::{run as auth_run, AuthError, Authenticator};
 use safe_core::btree_set;
+use safe_core::client::{LocalSigner, SafeKey};
 use safe_core::ipc::req::{AppExchangeInfo, AuthReq};
 use safe_core::ipc::Permission;
 use safe_core::utils;
@@ -30,14 +34,15 @@ use safe_core::utils::test_utils::random_client;
 use safe_core::ConnectionManager;
 use safe_core::{Client, CoreError};
 use safe_nd::{
-    ADataAddress, ADataOwner, AppPermissions, AppendOnlyData, Coins, Error as SndError,
-    PubImmutableData, PubSeqAppendOnlyData, PubUnseqAppendOnlyData, UnpubUnseqAppendOnlyData,
-    XorName,
+    ADataAddress, ADataOwner, AppPermissions, AppendOnlyData, ClientFullId, Coins,
+    Error as SndError, PubImmutableData, PubSeqAppendOnlyData, PubUnseqAppendOnlyData,
+    UnpubUnseqAppendOnlyData, XorName,
 };
 #[cfg(feature = "mock-network")]
 use safe_nd::{RequestType, Response};
 use std::collections::HashMap;
 use std::rc::Rc;
+use std::sync::Arc;
 use unwrap::unwrap;
 
 // Test refreshing access info by fetching it from the network.
@@ -139,6 +144,8 @@ pub fn login_registered_with_low_balance() {
             app_container: false,
             app_permissions: Default::default(),
             containers: HashMap::new(),
+            response_key: None,
+            ttl: None,
         },
     ));
 
@@ -168,6 +175,8 @@ fn authorise_app(
                 get_balance: true,
             },
             containers: HashMap::new(),
+            response_key: None,
+            ttl: None,
         },
     ));
 
@@ -470,3 +479,41 @@ fn account_info() {
         unwrap!(orig_balance.checked_sub(unwrap!(Coins::from_nano(1))))
     );
 }
+
+// Test an app whose requests are signed by an external signer.
+// 1. Verify that creating the app fails with a signer which doesn't hold the app's keys.
+// 2. Create the app with a signer holding its keys and verify that it signs the requests.
+#[test]
+fn registered_with_signer() {
+    let auth = authenticator::create_account_and_login();
+    let auth_req = create_random_auth_req();
+    let auth_granted = unwrap!(authenticator::register_app(&auth, &auth_req));
+    let app_id = auth_req.app.id;
+
+    let other_signer = Arc::new(LocalSigner::new(SafeKey::client(ClientFullId::new_bls(
+        &mut thread_rng(),
+    ))));
+    match App::registered_with_signer(app_id.clone(), auth_granted.clone(), other_signer, || ()) {
+        Err(AppError::Unexpected(_)) => (),
+        Err(err) => panic!("Unexpected {:?}", err),
+        Ok(_) => panic!("Unexpected success"),
+    }
+
+    let signer = Arc::new(LocalSigner::new(SafeKey::app(
+        auth_granted.app_keys.app_full_id.clone(),
+    )));
+    let app = unwrap!(App::registered_with_signer(
+        app_id,
+        auth_granted,
+        signer.clone(),
+        || ()
+    ));
+
+    let signatures = signer.signatures();
+    unwrap!(run(&app, |client, _| {
+        client
+            .put_idata(PubImmutableData::new(vec![1, 2, 3]))
+            .map_err(AppError::from)
+    }));
+    assert!(signer.signatures() > signatures);
+}
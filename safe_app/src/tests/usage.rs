@@ -0,0 +1,122 @@
+// Copyright 2019 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under the MIT license <LICENSE-MIT
+// https://opensource.org/licenses/MIT> or the Modified BSD license <LICENSE-BSD
+// https://opensource.org/licenses/BSD-3-Clause>, at your option. This file may not be copied,
+// modified, or distributed except according to those terms. Please review the Licences for the
+// specific language governing permissions and limitations relating to use of the SAFE Network
+// Software.
+
+//! Testing the accounting of the resources consumed by apps.
+
+use crate::test_utils::create_random_auth_req;
+use crate::{run, App, AppError};
+use bincode::serialized_size;
+use ffi_utils::test_utils::call_vec;
+use futures::{future, Future};
+use safe_authenticator::apps::{self, AppUsageSummary};
+use safe_authenticator::ffi::apps::auth_apps_usage;
+use safe_authenticator::run as auth_run;
+use safe_authenticator::test_utils as authenticator;
+use safe_core::core_structs::AppUsage;
+use safe_core::{utils, Client, DIR_TAG};
+use safe_nd::{
+    AppPermissions, IData, MData, MDataAction, MDataPermissionSet, PubImmutableData, PublicKey,
+    UnseqMutableData,
+};
+use std::collections::BTreeMap;
+use unwrap::unwrap;
+
+// Test that the resources consumed by apps are reported per app.
+// 1. Register two apps and make one of them store some data, then delete a user's permissions
+//    on the mutable data it stored.
+// 2. Verify that the mutations are attributed to that app only, and that only the stored data
+//    is counted, not the deletion.
+// 3. Verify that the usage of revoked apps is still reported.
+// 4. Fetch the usage through FFI.
+#[test]
+#[allow(unsafe_code)]
+fn app_usage() {
+    let auth = authenticator::create_account_and_login();
+    let mut auth_req = create_random_auth_req();
+    auth_req.app_permissions = AppPermissions {
+        transfer_coins: false,
+        perform_mutations: true,
+        get_balance: false,
+    };
+    let app_id = auth_req.app.id.clone();
+    let auth_granted = unwrap!(authenticator::register_app(&auth, &auth_req));
+    let app = unwrap!(App::registered(app_id.clone(), auth_granted, || ()));
+    let (idle_app_id, _) = unwrap!(authenticator::register_rand_app(
+        &auth,
+        false,
+        Default::default()
+    ));
+
+    let bytes = unwrap!(run(&app, |client, _app_context| {
+        let c2 = client.clone();
+        let c3 = client.clone();
+        let c4 = client.clone();
+
+        let idata: Vec<IData> = (0..2)
+            .map(|_| PubImmutableData::new(unwrap!(utils::generate_random_vector(100))).into())
+            .collect();
+
+        let other_key = PublicKey::from(threshold_crypto::SecretKey::random().public_key());
+        let mut permissions = BTreeMap::new();
+        let _ = permissions.insert(
+            client.public_key(),
+            MDataPermissionSet::new().allow(MDataAction::ManagePermissions),
+        );
+        let _ = permissions.insert(
+            other_key,
+            MDataPermissionSet::new().allow(MDataAction::Read),
+        );
+        let mdata = UnseqMutableData::new_with_data(
+            rand::random(),
+            DIR_TAG,
+            BTreeMap::new(),
+            permissions,
+            client.owner_key(),
+        );
+        let address = *mdata.address();
+
+        let bytes = idata
+            .iter()
+            .map(|data| unwrap!(serialized_size(data)))
+            .sum::<u64>()
+            + unwrap!(serialized_size(&MData::Unseq(mdata.clone())));
+
+        future::join_all(idata.into_iter().map(move |data| c2.put_idata(data)))
+            .and_then(move |_| c3.put_unseq_mutable_data(mdata))
+            .and_then(move |()| c4.del_mdata_user_permissions(address, other_key, 1))
+            .map(move |()| bytes)
+            .map_err(AppError::from)
+    }));
+
+    authenticator::revoke(&auth, &app_id);
+
+    let usage = unwrap!(auth_run(&auth, apps::list_usage));
+    assert_eq!(usage.len(), 2);
+
+    for summary in &usage {
+        if summary.app_info.id == app_id {
+            assert_eq!(summary.usage.mutations, 4);
+            assert_eq!(summary.usage.bytes, bytes);
+        } else {
+            assert_eq!(summary.app_info.id, idle_app_id);
+            assert_eq!(summary.usage, AppUsage::default());
+        }
+    }
+
+    let ffi_usage: Vec<AppUsageSummary> =
+        unsafe { unwrap!(call_vec(|ud, cb| auth_apps_usage(&auth, ud, cb))) };
+    assert_eq!(ffi_usage.len(), usage.len());
+
+    for summary in ffi_usage {
+        let expected = unwrap!(usage
+            .iter()
+            .find(|expected| expected.app_info.id == summary.app_info.id));
+        assert_eq!(summary.usage, expected.usage);
+    }
+}
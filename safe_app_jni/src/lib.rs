@@ -29,13 +29,15 @@ use log::error;
 use safe_app::ffi::object_cache::*;
 use safe_app::UserPermissionSet;
 use safe_core::arrays::*;
+use safe_core::ffi::coins::TransactionRecord;
 use safe_core::ffi::ipc::req::{
-    AppExchangeInfo, AuthReq, ContainerPermissions, ContainersReq, PermissionSet, ShareMData,
-    ShareMDataRequest,
+    ADataPermissionSet, AppExchangeInfo, AuthReq, ContainerPermissions, ContainersReq,
+    PermissionSet, PermissionsReq, ShareAData, ShareADataRequest, ShareMData, ShareMDataRequest,
+    TransOwnership, TransOwnershipRequest,
 };
 use safe_core::ffi::ipc::resp::{
     AccessContInfo, AccessContainerEntry, AppAccess, AppKeys, AuthGranted, ContainerInfo,
-    MDataEntry, MDataKey, MDataValue, MetadataResponse,
+    MDataEntry, MDataKey, MDataValue, MetadataResponse, OwnershipChange,
 };
 use safe_core::ffi::nfs::File;
 use safe_core::ffi::*;
@@ -268,6 +270,11 @@ gen_object_array_converter!(
     ContainerPermissions,
     "net/maidsafe/safe_app/ContainerPermissions"
 );
+gen_object_array_converter!(
+    find_class,
+    TransactionRecord,
+    "net/maidsafe/safe_app/TransactionRecord"
+);
 
 extern "C" fn call_app_disconnect_cb(ctx: *mut c_void) {
     unsafe {
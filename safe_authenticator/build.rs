@@ -69,6 +69,7 @@ mod bindings {
         unwrap!(bindgen.compile(&mut lang, &mut outputs, false));
 
         lang.add_custom_code("typedef void* Authenticator;\n");
+        lang.add_custom_code("typedef void* SignatureTx;\n");
         lang.set_lib_name(unwrap!(env::var("CARGO_PKG_NAME")));
         bindgen.source_file("../safe_authenticator/src/lib.rs");
         unwrap!(bindgen.compile(&mut lang, &mut outputs, true));
@@ -85,6 +86,14 @@ mod bindings {
             "XorNameArray",
             JavaType::Array(Box::new(JavaType::Primitive(Primitive::Byte))),
         );
+        type_map.insert(
+            "DeviceKey",
+            JavaType::Array(Box::new(JavaType::Primitive(Primitive::Byte))),
+        );
+        type_map.insert(
+            "DeviceId",
+            JavaType::Array(Box::new(JavaType::Primitive(Primitive::Byte))),
+        );
         type_map.insert(
             "SignSecretKey",
             JavaType::Array(Box::new(JavaType::Primitive(Primitive::Byte))),
@@ -147,6 +156,23 @@ mod bindings {
         lang.reset_filter(FilterMode::Blacklist);
         lang.filter("login");
         lang.filter("create_acc");
+        // The generated code releases the callbacks once the first of them is called, so the
+        // other functions taking a disconnect notifier are bound by hand too.
+        lang.filter("create_acc_with_recovery");
+        lang.filter("recover_account");
+        lang.filter("login_device");
+        lang.filter("import_backup");
+        // The Java generator can't pass the opaque `SignatureTx` channel, so Java authenticators
+        // can only sign with the keys stored in the account.
+        lang.filter("login_with_signer");
+        lang.filter("auth_send_signature");
+        // The Java generator can't convert the nullable struct pointers of `DecodedIpcReq`, so
+        // Java authenticators decode requests with `auth_decode_ipc_req_by_kind`.
+        lang.filter("auth_decode_ipc_req");
+        lang.filter("DecodedIpcReq");
+        // The generated code releases the callbacks once the first of them is called, so the
+        // near-limit observer, which is called repeatedly, is bound by hand like `login`.
+        lang.filter("auth_set_near_limit_observer");
 
         lang.set_namespace("net.maidsafe.safe_authenticator");
         lang.set_model_namespace("net.maidsafe.safe_authenticator");
@@ -202,6 +228,7 @@ mod bindings {
         lang.add_const("ulong", "ASYM_PUBLIC_KEY_LEN", PK_SIZE);
         lang.add_const("ulong", "XOR_NAME_LEN", XOR_NAME_LEN);
         lang.add_opaque_type("Authenticator");
+        lang.add_opaque_type("SignatureTx");
 
         lang.reset_filter(FilterMode::Blacklist);
         lang.filter("AuthFuture");
@@ -79,6 +79,49 @@ pub fn update_container_perms(
         .into_box()
 }
 
+/// Drops the permissions of the given key from the given root containers.
+pub fn revoke_container_perms(
+    client: &AuthClient,
+    containers: Vec<String>,
+    app_pk: PublicKey,
+) -> Box<AuthFuture<()>> {
+    let c2 = client.clone();
+
+    fetch_authenticator_entry(client)
+        .and_then(move |(_, mut root_containers)| {
+            let mut reqs = Vec::new();
+
+            for container_key in containers {
+                let c3 = c2.clone();
+                let mdata_info = fry!(root_containers
+                    .remove(&container_key)
+                    .ok_or_else(|| AuthError::NoSuchContainer(container_key.clone())));
+
+                let fut = c2
+                    .get_mdata_version(*mdata_info.address())
+                    .and_then(move |version| {
+                        recoverable_apis::del_mdata_user_permissions(
+                            &c3,
+                            *mdata_info.address(),
+                            app_pk,
+                            version + 1,
+                        )
+                    })
+                    .or_else(|error| match error {
+                        // The app had no access to this container.
+                        CoreError::DataError(SndError::NoSuchKey) => Ok(()),
+                        error => Err(error),
+                    })
+                    .map_err(AuthError::from);
+
+                reqs.push(fut);
+            }
+
+            future::join_all(reqs).map(|_| ()).into_box()
+        })
+        .into_box()
+}
+
 /// Gets access container entry key corresponding to the given app.
 pub fn enc_key(
     access_container: &MDataInfo,
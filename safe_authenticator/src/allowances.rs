@@ -0,0 +1,645 @@
+// Copyright 2019 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+//! Coin allowances granted to apps.
+//!
+//! An app with an allowance doesn't spend from the account's balance: the authenticator
+//! withdraws its `transfer_coins` permission and funds a separate coin balance, the app's
+//! allowance wallet, with the allowance instead. Vaults refuse to transfer more than the wallet
+//! holds, so the limit is enforced at the time the app spends. The wallet is owned by a key
+//! derived from the app's keys (see `AppKeys::allowance_wallet_id`), so the app can spend from
+//! it and the authenticator can move coins back when the limit is lowered or the allowance is
+//! removed.
+//!
+//! `Daily` allowances are topped back up to their limit once a day has passed since the current
+//! period started. This happens on login and whenever the allowances are listed.
+//!
+//! Apps that come near their limit are reported to the observer registered with
+//! `set_near_limit_observer`. Apps spend from their wallets without going through the
+//! authenticator, so their spending is only noticed when the allowances are next checked: on
+//! login, whenever the allowances are listed and after an allowance is changed.
+
+use super::{config, AuthError, AuthFuture};
+use crate::client::AuthClient;
+use crate::config::{AllowancePeriod, AppAllowance};
+use crate::ffi::coins::AppAllowanceStatus as FfiAllowanceStatus;
+use chrono::{DateTime, Duration, Utc};
+use futures::future::{self, Either};
+use futures::{stream, Future, Stream};
+use log::{trace, warn};
+use safe_core::client::AuthActions;
+use safe_core::core_structs::AppKeys;
+use safe_core::{ok, recoverable_apis, Client, CoreError, FutureExt};
+use safe_nd::{AppPermissions, ClientFullId, Coins, Error as SndError, PublicKey, XorName};
+use std::cmp;
+use std::collections::HashSet;
+use std::ffi::{CString, NulError};
+use std::rc::Rc;
+use tiny_keccak::sha3_256;
+use unwrap::unwrap;
+
+/// Percentage of its allowance an app has to spend before it is reported as near its limit.
+pub const NEAR_LIMIT_PERCENT: u64 = 90;
+
+/// Function called with the status of an app that came near the limit of its allowance.
+pub type NearLimitObserver = dyn Fn(&AllowanceStatus);
+
+/// Apps reported as near their limit to the registered observer.
+#[derive(Default)]
+pub(crate) struct NearLimitReports {
+    observer: Option<Rc<NearLimitObserver>>,
+    // Apps reported since their spending was last below the threshold.
+    reported: HashSet<String>,
+}
+
+/// Spending of an app measured against its coin allowance.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AllowanceStatus {
+    /// App ID.
+    pub app_id: String,
+    /// Allowance granted to the app.
+    pub allowance: AppAllowance,
+    /// Amount of coins left in the app's allowance wallet.
+    pub remaining: Coins,
+}
+
+impl AllowanceStatus {
+    /// Returns the amount of coins the app has spent within the current period.
+    pub fn spent(&self) -> Coins {
+        let spent = self
+            .allowance
+            .funded
+            .as_nano()
+            .saturating_sub(self.remaining.as_nano());
+        unwrap!(Coins::from_nano(spent))
+    }
+
+    /// Returns `true` if the app has spent its whole allowance.
+    pub fn exhausted(&self) -> bool {
+        self.remaining.as_nano() == 0
+    }
+
+    /// Returns `true` if the app has spent at least `NEAR_LIMIT_PERCENT` of its allowance.
+    pub fn near_limit(&self) -> bool {
+        u128::from(self.spent().as_nano()) * 100
+            >= u128::from(self.allowance.limit.as_nano()) * u128::from(NEAR_LIMIT_PERCENT)
+    }
+
+    /// Construct FFI wrapper for the native Rust object, consuming self.
+    pub fn into_repr_c(self) -> Result<FfiAllowanceStatus, NulError> {
+        let spent = self.spent();
+        let exhausted = self.exhausted();
+        let near_limit = self.near_limit();
+        let Self {
+            app_id,
+            allowance,
+            remaining,
+        } = self;
+
+        Ok(FfiAllowanceStatus {
+            app_id: CString::new(app_id)?.into_raw(),
+            limit: allowance.limit.as_nano(),
+            daily: allowance.period == AllowancePeriod::Daily,
+            spent: spent.as_nano(),
+            remaining: remaining.as_nano(),
+            exhausted,
+            near_limit,
+        })
+    }
+}
+
+/// Grant a coin allowance to a registered app, or change the limit of its existing allowance.
+/// The app's `transfer_coins` permission on the account's balance is withdrawn and its
+/// allowance wallet is funded up to the new limit, or drained if the limit was lowered. The app
+/// doesn't need to be revoked for the new limit to take effect.
+pub fn set_app_allowance(
+    client: &AuthClient,
+    app_id: &str,
+    limit: Coins,
+    period: AllowancePeriod,
+) -> Box<AuthFuture<()>> {
+    trace!("Setting coin allowance for app {}...", app_id);
+
+    let c2 = client.clone();
+    let c3 = client.clone();
+    let c4 = client.clone();
+    let app_id = app_id.to_string();
+
+    config::get_app(client, &app_id)
+        .join(config::list_allowances(client))
+        .and_then(move |(app, (_, allowances))| {
+            let existing = allowances.get(&app.info.id).cloned();
+
+            // Stop the app from spending the account's coins before funding its wallet.
+            set_transfer_permission(&c2, app.keys.public_key(), false)
+                .map(move |withdrawn| (app, existing, withdrawn))
+        })
+        .and_then(move |(app, existing, withdrawn)| {
+            let allowance = match existing {
+                Some(existing) => AppAllowance {
+                    limit,
+                    period,
+                    withdrew_transfer_coins: existing.withdrew_transfer_coins || withdrawn,
+                    ..existing
+                },
+                None => AppAllowance {
+                    limit,
+                    period,
+                    period_start: Utc::now(),
+                    funded: unwrap!(Coins::from_nano(0)),
+                    withdrew_transfer_coins: withdrawn,
+                },
+            };
+            apply(&c3, app_id, &app.keys, allowance)
+        })
+        .and_then(move |_| {
+            // The allowance is set, so failing to check it doesn't fail the call.
+            check_near_limit(&c4).then(|res| {
+                if let Err(e) = res {
+                    warn!("Failed to check the coin allowances: {:?}", e);
+                }
+                Ok(())
+            })
+        })
+        .into_box()
+}
+
+/// Remove the coin allowance of an app. The coins left in its allowance wallet are moved back to
+/// the account's balance and the app gets its `transfer_coins` permission back if it had it when
+/// the allowance was granted.
+pub fn remove_app_allowance(client: &AuthClient, app_id: &str) -> Box<AuthFuture<()>> {
+    trace!("Removing coin allowance of app {}...", app_id);
+
+    let c2 = client.clone();
+    let c3 = client.clone();
+    let c4 = client.clone();
+    let app_id = app_id.to_string();
+
+    config::list_allowances(client)
+        .and_then(move |(_, allowances)| {
+            let allowance = match allowances.get(&app_id) {
+                Some(allowance) => allowance.clone(),
+                None => return Either::A(future::ok(())),
+            };
+
+            let f = config::get_app(&c2, &app_id)
+                .and_then(move |app| {
+                    drain_wallet(&c3, &app.keys.allowance_wallet_id()).map(move |_| app)
+                })
+                .and_then(move |app| {
+                    save(&c4, &app_id, None).and_then(move |_| {
+                        if allowance.withdrew_transfer_coins {
+                            set_transfer_permission(&c4, app.keys.public_key(), true)
+                                .map(|_| ())
+                                .into_box()
+                        } else {
+                            ok!(())
+                        }
+                    })
+                });
+            Either::B(f)
+        })
+        .into_box()
+}
+
+/// Refill any `Daily` allowances whose period has passed and report the spending of every app
+/// that has been granted an allowance. Apps that are `near_limit` should be brought to the
+/// user's attention, and those that came near their limit since the last check are reported to
+/// the near-limit observer.
+pub fn list_app_allowances(client: &AuthClient) -> Box<AuthFuture<Vec<AllowanceStatus>>> {
+    let c2 = client.clone();
+    let c3 = client.clone();
+
+    refill_due_allowances(client)
+        .and_then(move |_| statuses(&c2))
+        .map(move |statuses| {
+            report_near_limit(&c3, &statuses);
+            statuses
+        })
+        .into_box()
+}
+
+/// Top the allowance wallets of apps with `Daily` allowances back up to their limit if a day has
+/// passed since their current period started, then report the apps that came near their limit
+/// to the near-limit observer.
+pub fn refill_allowances(client: &AuthClient) -> Box<AuthFuture<()>> {
+    list_app_allowances(client).map(|_| ()).into_box()
+}
+
+/// Register `observer` to be called with the status of each app that comes near the limit of
+/// its allowance, replacing any observer registered before. The apps already near their limit
+/// are reported right away. An app is reported once, and again only after its spending has gone
+/// back below the threshold, e.g. because its daily allowance was refilled or its limit raised.
+pub fn set_near_limit_observer<F>(client: &AuthClient, observer: F) -> Box<AuthFuture<()>>
+where
+    F: Fn(&AllowanceStatus) + 'static,
+{
+    {
+        let mut reports = client.near_limit_reports();
+        reports.observer = Some(Rc::new(observer));
+        reports.reported.clear();
+    }
+
+    refill_allowances(client)
+}
+
+// Reports the apps that came near their limit, if an observer is registered.
+fn check_near_limit(client: &AuthClient) -> Box<AuthFuture<()>> {
+    if client.near_limit_reports().observer.is_none() {
+        return ok!(());
+    }
+
+    let c2 = client.clone();
+    statuses(client)
+        .map(move |statuses| report_near_limit(&c2, &statuses))
+        .into_box()
+}
+
+fn report_near_limit(client: &AuthClient, statuses: &[AllowanceStatus]) {
+    let (observer, due) = {
+        let mut reports = client.near_limit_reports();
+        let observer = match reports.observer {
+            Some(ref observer) => Rc::clone(observer),
+            None => return,
+        };
+
+        reports
+            .reported
+            .retain(|app_id| statuses.iter().any(|status| status.app_id == *app_id));
+        let mut due = Vec::new();
+        for status in statuses {
+            if !status.near_limit() {
+                let _ = reports.reported.remove(&status.app_id);
+            } else if reports.reported.insert(status.app_id.clone()) {
+                due.push(status);
+            }
+        }
+        (observer, due)
+    };
+
+    // The observer is called once the reports are released, so that it can use the client.
+    for status in due {
+        observer(status);
+    }
+}
+
+fn statuses(client: &AuthClient) -> Box<AuthFuture<Vec<AllowanceStatus>>> {
+    let c2 = client.clone();
+
+    config::list_allowances(client)
+        .join(config::list_apps(client))
+        .and_then(move |((_, allowances), (_, apps))| {
+            let statuses: Vec<_> = allowances
+                .into_iter()
+                .filter_map(|(app_id, allowance)| {
+                    // Allowances of apps that are no longer registered are ignored.
+                    let app = apps.get(&sha3_256(app_id.as_bytes()))?;
+                    let f =
+                        wallet_balance(&c2, &app.keys.allowance_wallet_id()).map(move |balance| {
+                            AllowanceStatus {
+                                app_id,
+                                allowance,
+                                remaining: balance.unwrap_or_else(|| unwrap!(Coins::from_nano(0))),
+                            }
+                        });
+                    Some(f)
+                })
+                .collect();
+
+            future::join_all(statuses).map(|mut statuses| {
+                statuses.sort_by(|a, b| a.app_id.cmp(&b.app_id));
+                statuses
+            })
+        })
+        .into_box()
+}
+
+fn refill_due_allowances(client: &AuthClient) -> Box<AuthFuture<()>> {
+    let client = client.clone();
+    let now = Utc::now();
+
+    config::list_allowances(&client)
+        .join(config::list_apps(&client))
+        .and_then(move |((_, allowances), (_, apps))| {
+            let due: Vec<_> = allowances
+                .into_iter()
+                .filter(|(_, allowance)| refill_due(allowance, now))
+                .filter_map(|(app_id, allowance)| {
+                    let app = apps.get(&sha3_256(app_id.as_bytes()))?;
+                    Some((app_id, app.keys.clone(), allowance))
+                })
+                .collect();
+
+            stream::iter_ok::<_, AuthError>(due).for_each(move |(app_id, keys, allowance)| {
+                trace!("Refilling coin allowance of app {}...", app_id);
+                apply(&client, app_id, &keys, allowance).map(|_| ())
+            })
+        })
+        .into_box()
+}
+
+/// Returns the permissions to issue to an app being authorised, with `transfer_coins` withdrawn
+/// if the app has an allowance: such an app spends from its allowance wallet only.
+pub fn restrict_permissions(
+    client: &AuthClient,
+    app_id: &str,
+    permissions: AppPermissions,
+) -> Box<AuthFuture<AppPermissions>> {
+    let app_id = app_id.to_string();
+
+    config::list_allowances(client)
+        .map(move |(_, allowances)| {
+            if allowances.contains_key(&app_id) {
+                AppPermissions {
+                    transfer_coins: false,
+                    ..permissions
+                }
+            } else {
+                permissions
+            }
+        })
+        .into_box()
+}
+
+/// Remove the allowance of an app that is being removed from the authenticator, moving the coins
+/// left in its allowance wallet back to the account's balance. Must be called while the app is
+/// still registered.
+pub fn forget_app(client: &AuthClient, app_id: &str) -> Box<AuthFuture<()>> {
+    // The app is revoked, so there is no key to give the `transfer_coins` permission back to.
+    remove_app_allowance(client, app_id)
+}
+
+// Coins to move between the account's balance and an app's allowance wallet.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Movement {
+    None,
+    ToWallet(Coins),
+    FromWallet(Coins),
+}
+
+impl Movement {
+    fn reverse(self) -> Self {
+        match self {
+            Movement::None => Movement::None,
+            Movement::ToWallet(amount) => Movement::FromWallet(amount),
+            Movement::FromWallet(amount) => Movement::ToWallet(amount),
+        }
+    }
+}
+
+fn refill_due(allowance: &AppAllowance, now: DateTime<Utc>) -> bool {
+    allowance.period == AllowancePeriod::Daily && now - allowance.period_start >= Duration::days(1)
+}
+
+// Works out the coins to move so that the wallet, holding `balance`, matches the allowance at
+// `now`. Returns the allowance as it is once the coins have been moved.
+fn plan(
+    allowance: &AppAllowance,
+    balance: Coins,
+    now: DateTime<Utc>,
+) -> Result<(AppAllowance, Movement), AuthError> {
+    let mut allowance = allowance.clone();
+
+    if refill_due(&allowance, now) {
+        // What the app didn't spend counts towards the new period.
+        allowance.period_start = now;
+        allowance.funded = balance;
+    }
+
+    let limit = allowance.limit.as_nano();
+    let funded = allowance.funded.as_nano();
+
+    let (movement, funded) = if limit > funded {
+        (Movement::ToWallet(Coins::from_nano(limit - funded)?), limit)
+    } else {
+        // Coins the app has already spent can't be taken back.
+        let excess = cmp::min(funded - limit, balance.as_nano());
+        if excess == 0 {
+            (Movement::None, funded)
+        } else {
+            (
+                Movement::FromWallet(Coins::from_nano(excess)?),
+                funded - excess,
+            )
+        }
+    };
+    allowance.funded = Coins::from_nano(funded)?;
+
+    Ok((allowance, movement))
+}
+
+// Brings the app's allowance wallet in line with the allowance and stores it. If storing the
+// allowance fails, the coins are moved back so that the stored `funded` amount stays accurate.
+fn apply(
+    client: &AuthClient,
+    app_id: String,
+    keys: &AppKeys,
+    allowance: AppAllowance,
+) -> Box<AuthFuture<AppAllowance>> {
+    let c2 = client.clone();
+    let c3 = client.clone();
+    let wallet_id = keys.allowance_wallet_id();
+    let wallet_id2 = wallet_id.clone();
+
+    wallet_balance(client, &wallet_id)
+        .and_then(move |balance| {
+            let zero = unwrap!(Coins::from_nano(0));
+            let (allowance, movement) = plan(&allowance, balance.unwrap_or(zero), Utc::now())?;
+            Ok((allowance, movement, balance.is_some()))
+        })
+        .and_then(move |(allowance, movement, exists)| {
+            move_coins(&c2, &wallet_id, exists, movement).map(move |_| (allowance, movement))
+        })
+        .and_then(move |(allowance, movement)| {
+            save(&c3, &app_id, Some(allowance.clone()))
+                .map(move |_| allowance)
+                .or_else(move |error| {
+                    move_coins(&c3, &wallet_id2, true, movement.reverse()).then(move |res| {
+                        if let Err(e) = res {
+                            warn!(
+                                "Failed to move coins back after failing to store the \
+                                 allowance of app {}: {:?}",
+                                app_id, e
+                            );
+                        }
+                        Err(error)
+                    })
+                })
+        })
+        .into_box()
+}
+
+fn save(client: &AuthClient, app_id: &str, allowance: Option<AppAllowance>) -> Box<AuthFuture<()>> {
+    let c2 = client.clone();
+    let app_id = app_id.to_string();
+
+    config::list_allowances(client)
+        .and_then(move |(version, allowances)| {
+            config::set_allowance(
+                &c2,
+                allowances,
+                config::next_version(version),
+                &app_id,
+                allowance,
+            )
+        })
+        .map(|_| ())
+        .into_box()
+}
+
+// Returns the balance of the allowance wallet, or `None` if it hasn't been created yet.
+fn wallet_balance(client: &AuthClient, wallet_id: &ClientFullId) -> Box<AuthFuture<Option<Coins>>> {
+    client
+        .get_balance(Some(wallet_id))
+        .then(|res| match res {
+            Ok(balance) => Ok(Some(balance)),
+            Err(CoreError::DataError(SndError::NoSuchBalance)) => Ok(None),
+            Err(error) => Err(AuthError::from(error)),
+        })
+        .into_box()
+}
+
+fn drain_wallet(client: &AuthClient, wallet_id: &ClientFullId) -> Box<AuthFuture<()>> {
+    let c2 = client.clone();
+    let wallet_id2 = wallet_id.clone();
+
+    wallet_balance(client, wallet_id)
+        .and_then(move |balance| match balance {
+            Some(balance) if balance.as_nano() > 0 => {
+                move_coins(&c2, &wallet_id2, true, Movement::FromWallet(balance))
+            }
+            _ => ok!(()),
+        })
+        .into_box()
+}
+
+fn move_coins(
+    client: &AuthClient,
+    wallet_id: &ClientFullId,
+    wallet_exists: bool,
+    movement: Movement,
+) -> Box<AuthFuture<()>> {
+    let wallet_pk = *wallet_id.public_id().public_key();
+
+    let f = match movement {
+        Movement::None => return ok!(()),
+        Movement::ToWallet(amount) if wallet_exists => {
+            client.transfer_coins(None, XorName::from(wallet_pk), amount, None)
+        }
+        Movement::ToWallet(amount) => client.create_balance(None, wallet_pk, amount, None),
+        Movement::FromWallet(amount) => {
+            client.transfer_coins(Some(wallet_id), client.owner_key().into(), amount, None)
+        }
+    };
+
+    f.map(|_| ()).map_err(AuthError::from).into_box()
+}
+
+// Sets the `transfer_coins` permission of the app's key. Returns `false` if there was nothing
+// to change, including when the app is revoked and has no key at the moment.
+fn set_transfer_permission(
+    client: &AuthClient,
+    app_pk: PublicKey,
+    transfer_coins: bool,
+) -> Box<AuthFuture<bool>> {
+    let c2 = client.clone();
+
+    client
+        .list_auth_keys_and_version()
+        .map_err(AuthError::from)
+        .and_then(move |(keys, version)| match keys.get(&app_pk) {
+            Some(permissions) if permissions.transfer_coins != transfer_coins => {
+                let permissions = AppPermissions {
+                    transfer_coins,
+                    ..*permissions
+                };
+                let f = recoverable_apis::ins_auth_key_to_client_h(
+                    &c2,
+                    app_pk,
+                    permissions,
+                    version + 1,
+                )
+                .map(|_| true)
+                .map_err(AuthError::from);
+                Either::A(f)
+            }
+            _ => Either::B(future::ok(false)),
+        })
+        .into_box()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn coins(nano: u64) -> Coins {
+        unwrap!(Coins::from_nano(nano))
+    }
+
+    fn allowance(limit: u64, funded: u64, period: AllowancePeriod) -> AppAllowance {
+        AppAllowance {
+            limit: coins(limit),
+            period,
+            period_start: Utc::now(),
+            funded: coins(funded),
+            withdrew_transfer_coins: true,
+        }
+    }
+
+    // Test planning the coins to move for an allowance.
+    #[test]
+    fn plan_movements() {
+        let now = Utc::now();
+
+        // A new allowance gets its wallet funded with the full limit.
+        let (planned, movement) = unwrap!(plan(
+            &allowance(10, 0, AllowancePeriod::Total),
+            coins(0),
+            now
+        ));
+        assert_eq!(movement, Movement::ToWallet(coins(10)));
+        assert_eq!(planned.funded, coins(10));
+
+        // Raising the limit tops the wallet up by the difference only, whatever was spent.
+        let (planned, movement) = unwrap!(plan(
+            &allowance(15, 10, AllowancePeriod::Total),
+            coins(2),
+            now
+        ));
+        assert_eq!(movement, Movement::ToWallet(coins(5)));
+        assert_eq!(planned.funded, coins(15));
+
+        // Lowering the limit takes back what's left of the excess.
+        let (planned, movement) = unwrap!(plan(
+            &allowance(4, 10, AllowancePeriod::Total),
+            coins(3),
+            now
+        ));
+        assert_eq!(movement, Movement::FromWallet(coins(3)));
+        assert_eq!(planned.funded, coins(7));
+
+        // A total allowance that has been spent isn't refilled.
+        let mut spent = allowance(10, 10, AllowancePeriod::Total);
+        spent.period_start = now - Duration::days(2);
+        let (planned, movement) = unwrap!(plan(&spent, coins(0), now));
+        assert_eq!(movement, Movement::None);
+        assert_eq!(planned, spent);
+
+        // A daily allowance is topped back up to the limit once a day has passed.
+        let mut daily = allowance(10, 10, AllowancePeriod::Daily);
+        daily.period_start = now - Duration::hours(23);
+        let (_, movement) = unwrap!(plan(&daily, coins(4), now));
+        assert_eq!(movement, Movement::None);
+
+        daily.period_start = now - Duration::hours(25);
+        let (planned, movement) = unwrap!(plan(&daily, coins(4), now));
+        assert_eq!(movement, Movement::ToWallet(coins(6)));
+        assert_eq!(planned.funded, coins(10));
+        assert_eq!(planned.period_start, now);
+    }
+}
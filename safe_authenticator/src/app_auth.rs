@@ -11,15 +11,18 @@
 use super::{AuthError, AuthFuture};
 use crate::access_container;
 use crate::access_container::update_container_perms;
+use crate::allowances;
 use crate::app_container;
+use crate::audit;
 use crate::client::AuthClient;
-use crate::config::{self, AppInfo, Apps};
+use crate::config::{self, AppInfo, Apps, AuditDecision};
+use chrono::{DateTime, Duration, Utc};
 use futures::future::{self, Either};
 use futures::Future;
 use log::trace;
 use safe_core::client;
 use safe_core::core_structs::{AccessContInfo, AccessContainerEntry, AppKeys};
-use safe_core::ipc::req::{AuthReq, ContainerPermissions, Permission};
+use safe_core::ipc::req::{AuthReq, ContainerPermissions, Permission, PermissionsReq};
 use safe_core::ipc::resp::AuthGranted;
 use safe_core::{
     app_container_name, client::AuthActions, recoverable_apis, Client, FutureExt, MDataInfo,
@@ -27,6 +30,7 @@ use safe_core::{
 use safe_core::{btree_set, err, fry, ok};
 use safe_nd::AppPermissions;
 use std::collections::HashMap;
+use std::time::Duration as StdDuration;
 use tiny_keccak::sha3_256;
 use unwrap::unwrap;
 
@@ -143,12 +147,21 @@ pub fn authenticate(client: &AuthClient, auth_req: AuthReq) -> Box<AuthFuture<Au
     let AuthReq {
         app_container,
         app_permissions,
+        ttl,
         ..
     } = auth_req;
+    let expires_at = fry!(expiry_time(ttl));
+    let decision = AuditDecision::Authorised {
+        app_permissions,
+        containers: permissions.clone(),
+        expires_at,
+    };
 
     let c2 = client.clone();
     let c3 = client.clone();
     let c4 = client.clone();
+    let c5 = client.clone();
+    let c6 = client.clone();
 
     config::list_apps(client)
         .join(check_revocation(client, app_id.clone()))
@@ -156,10 +169,16 @@ pub fn authenticate(client: &AuthClient, auth_req: AuthReq) -> Box<AuthFuture<Au
             app_state(&c2, &apps, &app_id)
                 .map(move |app_state| (apps_version, apps, app_state, app_id))
         })
-        .and_then(move |(apps_version, mut apps, app_state, app_id)| {
+        .and_then(move |(apps_version, apps, app_state, app_id)| {
+            // Access is only granted once the decision to grant it is recorded.
+            audit::record(&c5, &app_id, decision)
+                .map(move |()| (apps_version, apps, app_state, app_id))
+        })
+        .and_then(move |(apps_version, apps, app_state, app_id)| {
             // Determine an app state. If it's revoked we can reuse existing
             // keys stored in the config. And if it is authorised, we just
-            // return the app info from the config.
+            // return the app info from the config. Either way the expiry is
+            // replaced with the one from the new request.
             match app_state {
                 AppState::NotAuthenticated => {
                     let public_id = c3.public_id();
@@ -175,7 +194,7 @@ pub fn authenticate(client: &AuthClient, auth_req: AuthReq) -> Box<AuthFuture<Au
                 }
                 AppState::Authenticated | AppState::Revoked => {
                     let app_entry_name = sha3_256(app_id.as_bytes());
-                    if let Some(app) = apps.remove(&app_entry_name) {
+                    if let Some(app) = apps.get(&app_entry_name).cloned() {
                         ok!((app, app_state, app_id))
                     } else {
                         err!(AuthError::from(
@@ -185,6 +204,19 @@ pub fn authenticate(client: &AuthClient, auth_req: AuthReq) -> Box<AuthFuture<Au
                 }
             }
         })
+        .and_then(move |(app, app_state, app_id)| {
+            let c7 = c6.clone();
+            config::list_app_expiries(&c6).and_then(move |(version, expiries)| {
+                config::set_app_expiry(
+                    &c7,
+                    expiries,
+                    config::next_version(version),
+                    &app_id,
+                    expires_at,
+                )
+                .map(move |_| (app, app_state, app_id))
+            })
+        })
         .and_then(move |(app, app_state, app_id)| {
             match app_state {
                 AppState::Authenticated => {
@@ -251,7 +283,7 @@ fn authenticated_app(
 
 /// Register a new or revoked app in Maid Managers and in the access container.
 ///
-/// 1. Insert app's key to Maid Managers
+/// 1. Insert app's key to Maid Managers, without `transfer_coins` if its allowance is exhausted
 /// 2. Update container permissions for requested containers
 /// 3. Create the app container (if it's been requested)
 /// 4. Insert or update the access container entry for an app
@@ -268,23 +300,26 @@ fn authenticate_new_app(
     let c4 = client.clone();
     let c5 = client.clone();
     let c6 = client.clone();
+    let c7 = client.clone();
 
     let app_pk = app.keys.public_key();
     let app_keys = app.keys.clone();
     let app_keys_auth = app.keys.clone();
     let app_id = app.info.id.clone();
 
-    client
-        .list_auth_keys_and_version()
-        .and_then(move |(_, version)| {
-            recoverable_apis::ins_auth_key_to_client_h(
-                &c2,
-                app_keys.public_key(),
-                app_permissions,
-                version + 1,
-            )
+    allowances::restrict_permissions(client, &app_id, app_permissions)
+        .and_then(move |app_permissions| {
+            c7.list_auth_keys_and_version()
+                .and_then(move |(_, version)| {
+                    recoverable_apis::ins_auth_key_to_client_h(
+                        &c2,
+                        app_keys.public_key(),
+                        app_permissions,
+                        version + 1,
+                    )
+                })
+                .map_err(AuthError::from)
         })
-        .map_err(AuthError::from)
         .and_then(move |_| {
             if permissions.is_empty() {
                 ok!((Default::default(), app_pk))
@@ -323,6 +358,124 @@ fn authenticate_new_app(
         .into_box()
 }
 
+/// Modify the permissions of an authenticated app without re-authenticating it.
+///
+/// 1. Record the modification in the audit log
+/// 2. Replace the app's key permissions in Maid Managers (if requested), withholding
+///    `transfer_coins` if its allowance is exhausted
+/// 3. Set the requested permissions on the containers and drop the permissions on the
+///    containers requested with an empty permission set
+/// 4. Update the access container entry for the app
+pub fn modify_permissions(client: &AuthClient, req: PermissionsReq) -> Box<AuthFuture<()>> {
+    let PermissionsReq {
+        app,
+        containers,
+        app_permissions,
+    } = req;
+    let decision = AuditDecision::PermissionsModified {
+        app_permissions,
+        containers: containers.clone(),
+    };
+    let (granted, dropped): (HashMap<_, _>, HashMap<_, _>) = containers
+        .into_iter()
+        .partition(|(_, access)| !access.is_empty());
+    let dropped: Vec<_> = dropped
+        .into_iter()
+        .map(|(container, _)| container)
+        .collect();
+
+    let c2 = client.clone();
+    let c3 = client.clone();
+    let c4 = client.clone();
+    let c5 = client.clone();
+    let c6 = client.clone();
+    let c7 = client.clone();
+    let c8 = client.clone();
+    let c9 = client.clone();
+
+    config::get_app(client, &app.id)
+        .and_then(move |app| audit::record(&c9, &app.info.id, decision).map(move |()| app))
+        .and_then(move |app| {
+            let app_pk = app.keys.public_key();
+
+            match app_permissions {
+                Some(app_permissions) => {
+                    allowances::restrict_permissions(&c2, &app.info.id, app_permissions)
+                        .and_then(move |app_permissions| {
+                            c3.list_auth_keys_and_version()
+                                .and_then(move |(_, version)| {
+                                    recoverable_apis::ins_auth_key_to_client_h(
+                                        &c7,
+                                        app_pk,
+                                        app_permissions,
+                                        version + 1,
+                                    )
+                                })
+                                .map_err(AuthError::from)
+                        })
+                        .map(move |_| app)
+                        .into_box()
+                }
+                None => ok!(app),
+            }
+        })
+        .and_then(move |app| {
+            let app_pk = app.keys.public_key();
+
+            if granted.is_empty() {
+                ok!((app, AccessContainerEntry::default()))
+            } else {
+                update_container_perms(&c4, granted, app_pk)
+                    .map(move |granted| (app, granted))
+                    .into_box()
+            }
+        })
+        .and_then(move |(app, granted)| {
+            let app_pk = app.keys.public_key();
+
+            if dropped.is_empty() {
+                ok!((app, granted, dropped))
+            } else {
+                access_container::revoke_container_perms(&c5, dropped.clone(), app_pk)
+                    .map(move |()| (app, granted, dropped))
+                    .into_box()
+            }
+        })
+        .and_then(move |(app, granted, dropped)| {
+            let app_id = app.info.id.clone();
+            let app_keys = app.keys;
+
+            access_container::fetch_entry(&c6, &app_id, app_keys.clone()).and_then(
+                move |(version, existing)| {
+                    let version = if existing.is_some() { version + 1 } else { 0 };
+                    let mut entry = existing.unwrap_or_default();
+
+                    for (container, access) in granted {
+                        let _ = entry.insert(container, access);
+                    }
+                    for container in dropped {
+                        let _ = entry.remove(&container);
+                    }
+
+                    access_container::put_entry(&c8, &app_id, &app_keys, &entry, version)
+                },
+            )
+        })
+        .into_box()
+}
+
+/// Returns the time an authorisation granted now with the given TTL expires at.
+fn expiry_time(ttl: Option<u64>) -> Result<Option<DateTime<Utc>>, AuthError> {
+    match ttl {
+        Some(ttl) => Duration::from_std(StdDuration::from_secs(ttl))
+            .ok()
+            .and_then(|ttl| Utc::now().checked_add_signed(ttl))
+            .map(Some)
+            .ok_or_else(|| AuthError::from("Authorisation TTL is out of range")),
+        None => Ok(None),
+    }
+}
+
 fn check_revocation(client: &AuthClient, app_id: String) -> Box<AuthFuture<()>> {
     config::get_app_revocation_queue(client)
         .and_then(move |(_, queue)| {
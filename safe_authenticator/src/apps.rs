@@ -13,18 +13,22 @@ use crate::app_auth::{app_state, AppState};
 use crate::client::AuthClient;
 use crate::ffi::apps as ffi;
 use crate::ffi::apps::AppPermissions as FfiAppPermissions;
+use crate::ffi::apps::AppUsageSummary as FfiAppUsageSummary;
 use crate::ffi::apps::RegisteredApp as FfiRegisteredApp;
-use crate::{app_container, AuthError};
+use crate::{allowances, app_container, AuthError};
 use bincode::deserialize;
+use chrono::{DateTime, NaiveDateTime, Utc};
 use ffi_utils::{vec_into_raw_parts, ReprC};
 use futures::future::Future;
 use safe_core::client::{AuthActions, Client};
-use safe_core::core_structs::{access_container_enc_key, AccessContainerEntry, AppAccess};
+use safe_core::core_structs::{
+    access_container_enc_key, AccessContainerEntry, AppAccess, AppUsage,
+};
 use safe_core::ipc::req::{containers_from_repr_c, containers_into_vec, ContainerPermissions};
 use safe_core::ipc::{AppExchangeInfo, IpcError};
 use safe_core::utils::symmetric_decrypt;
 use safe_core::FutureExt;
-use safe_nd::{AppPermissions, MDataAddress, XorName};
+use safe_nd::{AppPermissions, Coins, MDataAddress, XorName};
 use std::collections::HashMap;
 
 /// Represents an application that is registered with the Authenticator.
@@ -37,6 +41,8 @@ pub struct RegisteredApp {
     pub containers: HashMap<String, ContainerPermissions>,
     /// Permissions allowed for the app
     pub app_perms: AppPermissions,
+    /// Time the authorisation of the app expires at. `None` if it never expires.
+    pub expires_at: Option<DateTime<Utc>>,
 }
 
 impl RegisteredApp {
@@ -46,6 +52,7 @@ impl RegisteredApp {
             app_info,
             containers,
             app_perms,
+            expires_at,
         } = self;
 
         let container_permissions_vec = containers_into_vec(containers.into_iter())?;
@@ -63,6 +70,7 @@ impl RegisteredApp {
             containers: containers_ptr,
             containers_len,
             app_permissions: ffi_app_perms,
+            expires_at_sec: expires_at.map_or(0, |expires_at| expires_at.timestamp()),
         })
     }
 }
@@ -83,6 +91,56 @@ impl ReprC for RegisteredApp {
             app_info: AppExchangeInfo::clone_from_repr_c(&(*repr_c).app_info)?,
             containers: containers_from_repr_c((*repr_c).containers, (*repr_c).containers_len)?,
             app_perms: native_app_perms,
+            expires_at: match (*repr_c).expires_at_sec {
+                0 => None,
+                sec => Some(DateTime::from_utc(
+                    NaiveDateTime::from_timestamp_opt(sec, 0)
+                        .ok_or_else(|| IpcError::from("Invalid expiry time"))?,
+                    Utc,
+                )),
+            },
+        })
+    }
+}
+
+/// Resources an application registered with the Authenticator has consumed on behalf of the
+/// user.
+#[derive(Clone, Debug)]
+pub struct AppUsageSummary {
+    /// Unique application identifier.
+    pub app_info: AppExchangeInfo,
+    /// Mutations, bytes and coins attributed to the app's key.
+    pub usage: AppUsage,
+}
+
+impl AppUsageSummary {
+    /// Construct FFI wrapper for the native Rust object, consuming self.
+    pub fn into_repr_c(self) -> Result<FfiAppUsageSummary, IpcError> {
+        let Self { app_info, usage } = self;
+
+        Ok(FfiAppUsageSummary {
+            app_info: app_info.into_repr_c()?,
+            mutations: usage.mutations,
+            bytes: usage.bytes,
+            spent: usage.spent.as_nano(),
+        })
+    }
+}
+
+impl ReprC for AppUsageSummary {
+    type C = *const ffi::AppUsageSummary;
+    type Error = IpcError;
+
+    #[allow(unsafe_code)]
+    unsafe fn clone_from_repr_c(repr_c: Self::C) -> Result<Self, Self::Error> {
+        Ok(Self {
+            app_info: AppExchangeInfo::clone_from_repr_c(&(*repr_c).app_info)?,
+            usage: AppUsage {
+                mutations: (*repr_c).mutations,
+                bytes: (*repr_c).bytes,
+                spent: Coins::from_nano((*repr_c).spent)
+                    .map_err(|_| IpcError::from("Invalid coin amount"))?,
+            },
         })
     }
 }
@@ -93,9 +151,11 @@ pub fn remove_revoked_app(client: &AuthClient, app_id: String) -> Box<AuthFuture
     let c2 = client.clone();
     let c3 = client.clone();
     let c4 = client.clone();
+    let c5 = client.clone();
 
     let app_id2 = app_id.clone();
     let app_id3 = app_id.clone();
+    let app_id4 = app_id.clone();
 
     config::list_apps(&client)
         .and_then(move |(apps_version, apps)| {
@@ -106,6 +166,10 @@ pub fn remove_revoked_app(client: &AuthClient, app_id: String) -> Box<AuthFuture
             AppState::Authenticated => Err(AuthError::from("App is not revoked")),
             AppState::NotAuthenticated => Err(AuthError::IpcError(IpcError::UnknownApp)),
         })
+        .and_then(move |(apps, apps_version)| {
+            // The app's keys are needed to drain its allowance wallet, so do it first.
+            allowances::forget_app(&c5, &app_id4).map(move |_| (apps, apps_version))
+        })
         .and_then(move |(apps, apps_version)| {
             config::remove_app(&c3, apps, config::next_version(apps_version), &app_id2)
         })
@@ -156,27 +220,34 @@ pub fn list_registered(client: &AuthClient) -> Box<AuthFuture<Vec<RegisteredApp>
     let c4 = client.clone();
 
     config::list_apps(client)
-        .map(move |(_, auth_cfg)| (c2.access_container(), auth_cfg))
-        .and_then(move |(access_container, auth_cfg)| {
+        .join(config::list_app_expiries(client))
+        .map(move |((_, auth_cfg), (_, expiries))| (c2.access_container(), auth_cfg, expiries))
+        .and_then(move |(access_container, auth_cfg, expiries)| {
             c3.list_seq_mdata_entries(access_container.name(), access_container.type_tag())
                 .map_err(From::from)
-                .map(move |entries| (access_container, entries, auth_cfg))
+                .map(move |entries| (access_container, entries, auth_cfg, expiries))
         })
-        .and_then(move |(access_container, entries, auth_cfg)| {
+        .and_then(move |(access_container, entries, auth_cfg, expiries)| {
             c4.list_auth_keys_and_version().map_err(From::from).map(
                 move |(authorised_keys, _version)| {
-                    (authorised_keys, access_container, entries, auth_cfg)
+                    (
+                        authorised_keys,
+                        access_container,
+                        entries,
+                        auth_cfg,
+                        expiries,
+                    )
                 },
             )
         })
         .and_then(
-            move |(mut authorised_keys, access_container, entries, auth_cfg)| {
+            move |(mut authorised_keys, access_container, entries, auth_cfg, expiries)| {
                 let mut apps = Vec::new();
                 let nonce = access_container
                     .nonce()
                     .ok_or_else(|| AuthError::from("No nonce on access container's MDataInfo"))?;
 
-                for app in auth_cfg.values() {
+                for (hash, app) in &auth_cfg {
                     let key = access_container_enc_key(&app.info.id, &app.keys.enc_key, nonce)?;
 
                     // Empty entry means it has been deleted
@@ -202,6 +273,7 @@ pub fn list_registered(client: &AuthClient) -> Box<AuthFuture<Vec<RegisteredApp>
                             app_info: app.info.clone(),
                             containers,
                             app_perms,
+                            expires_at: expiries.get(hash).cloned(),
                         };
 
                         apps.push(registered_app);
@@ -263,3 +335,24 @@ pub fn apps_accessing_mutable_data(
         })
         .into_box()
 }
+
+/// Returns the resources consumed by each application known to the Authenticator, including
+/// revoked ones. Apps that haven't performed any mutations are reported with zero usage.
+///
+/// Only available against the mock vault, as vaults don't attribute mutations to app keys yet.
+#[cfg(feature = "mock-network")]
+pub fn list_usage(client: &AuthClient) -> Box<AuthFuture<Vec<AppUsageSummary>>> {
+    client
+        .list_app_usage()
+        .map_err(AuthError::from)
+        .join(config::list_apps(client))
+        .map(|(mut usage, (_, apps))| {
+            apps.into_iter()
+                .map(|(_, app)| AppUsageSummary {
+                    usage: usage.remove(&app.keys.public_key()).unwrap_or_default(),
+                    app_info: app.info,
+                })
+                .collect()
+        })
+        .into_box()
+}
@@ -0,0 +1,212 @@
+// Copyright 2019 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+//! Audit log of the decisions made by the authenticator.
+//!
+//! Every time app access is granted, modified or revoked, an entry is appended to the log. The
+//! log is an unpublished append-only data owned by the account, whose location is stored in the
+//! config root next to the rest of the authenticator configuration. Each entry is encrypted
+//! separately, so recording a decision doesn't grow with the size of the log. Entries are never
+//! modified or removed.
+//!
+//! Access is only granted once the decision to grant it has been recorded, so a failure to record
+//! it fails the request. Revocations are recorded once done, and a failure to record one doesn't
+//! undo the revocation.
+
+use super::{config, AuthError, AuthFuture};
+use crate::client::AuthClient;
+use crate::config::{AuditDecision, AuditEntry, AuditLogInfo};
+use crate::ffi::audit::AuditEntry as FfiAuditEntry;
+use bincode::{deserialize, serialize};
+use chrono::Utc;
+use futures::future::{self, Either};
+use futures::Future;
+use log::{trace, warn};
+use safe_core::crypto::shared_secretbox;
+use safe_core::utils::{symmetric_decrypt, symmetric_encrypt};
+use safe_core::{fry, ok, AuthActions, Client, FutureExt, DIR_TAG};
+use safe_nd::{
+    AData, ADataAddress, ADataAppendOperation, ADataEntry, ADataIndex, ADataOwner,
+    ADataUnpubPermissionSet, ADataUnpubPermissions, AppendOnlyData, UnpubUnseqAppendOnlyData,
+    XorName,
+};
+use std::collections::BTreeMap;
+use std::ffi::CString;
+
+impl AuditEntry {
+    /// Construct FFI wrapper for the native Rust object, consuming self.
+    pub fn into_repr_c(self) -> Result<FfiAuditEntry, AuthError> {
+        let Self {
+            time,
+            app_id,
+            decision,
+        } = self;
+
+        Ok(FfiAuditEntry {
+            timestamp_sec: time.timestamp(),
+            timestamp_nsec: time.timestamp_subsec_nanos(),
+            app_id: CString::new(app_id)?.into_raw(),
+            decision: CString::new(to_json(&decision)?)?.into_raw(),
+        })
+    }
+}
+
+/// Record a decision about the given app in the audit log.
+pub fn record(client: &AuthClient, app_id: &str, decision: AuditDecision) -> Box<AuthFuture<()>> {
+    trace!("Recording decision about app with ID {}...", app_id);
+
+    let client = client.clone();
+    let entry = AuditEntry {
+        time: Utc::now(),
+        app_id: app_id.to_string(),
+        decision,
+    };
+    let encoded = fry!(serialize(&entry));
+
+    get_or_create_log(&client)
+        .and_then(move |log| {
+            let value = symmetric_encrypt(&encoded, &log.enc_key, None)?;
+            // Entries are told apart by their key, which reveals nothing about them.
+            let key = rand::random::<[u8; 32]>().to_vec();
+
+            Ok(ADataAppendOperation {
+                address: log.address,
+                values: vec![ADataEntry::new(key, value)],
+            })
+        })
+        .and_then(move |append| client.append_unseq_adata(append).map_err(AuthError::from))
+        .into_box()
+}
+
+/// Record a decision about the given app that was already carried out, only logging a failure to
+/// record it.
+pub fn record_done(
+    client: &AuthClient,
+    app_id: &str,
+    decision: AuditDecision,
+) -> Box<AuthFuture<()>> {
+    let app_id = app_id.to_string();
+
+    record(client, &app_id, decision)
+        .or_else(move |error| {
+            warn!(
+                "Failed to record decision about app with ID {} in the audit log: {}",
+                app_id, error
+            );
+            Ok(())
+        })
+        .into_box()
+}
+
+/// Returns the audit log, oldest entry first.
+pub fn list(client: &AuthClient) -> Box<AuthFuture<Vec<AuditEntry>>> {
+    let client = client.clone();
+
+    config::get_audit_log(&client)
+        .and_then(move |(_, log)| match log {
+            Some(log) => Either::A(
+                client
+                    .get_adata_range(
+                        log.address,
+                        (ADataIndex::FromStart(0), ADataIndex::FromEnd(0)),
+                    )
+                    .map_err(AuthError::from)
+                    .and_then(move |entries| {
+                        entries
+                            .iter()
+                            .map(|entry| -> Result<AuditEntry, AuthError> {
+                                let plaintext = symmetric_decrypt(&entry.value, &log.enc_key)?;
+                                Ok(deserialize(&plaintext)?)
+                            })
+                            .collect::<Result<Vec<_>, _>>()
+                    }),
+            ),
+            None => Either::B(future::ok(Vec::new())),
+        })
+        .into_box()
+}
+
+/// Returns the audit log as a JSON array, oldest entry first.
+pub fn export(client: &AuthClient) -> Box<AuthFuture<String>> {
+    list(client).and_then(|log| to_json(&log)).into_box()
+}
+
+// Returns the location of the audit log, creating the log if it doesn't exist yet.
+fn get_or_create_log(client: &AuthClient) -> Box<AuthFuture<AuditLogInfo>> {
+    let client = client.clone();
+
+    config::get_audit_log(&client)
+        .and_then(move |(version, log)| {
+            if let Some(log) = log {
+                return ok!(log);
+            }
+
+            let new_log = AuditLogInfo {
+                address: ADataAddress::UnpubUnseq {
+                    name: XorName(rand::random()),
+                    tag: DIR_TAG,
+                },
+                enc_key: shared_secretbox::gen_key(),
+            };
+            let c2 = client.clone();
+            let c3 = client.clone();
+
+            client
+                .put_adata(fry!(new_log_data(&client, &new_log)))
+                .map_err(AuthError::from)
+                .and_then(move |()| {
+                    config::set_audit_log(&c2, log, config::next_version(version), new_log.clone())
+                        .map(move |(_, log)| (log, new_log))
+                })
+                .and_then(move |(log, new_log)| {
+                    let log = fry!(log.ok_or_else(|| AuthError::from("Audit log was not stored")));
+                    if log == new_log {
+                        return ok!(log);
+                    }
+
+                    // Another authenticator created the log first, so ours is not needed.
+                    c3.delete_adata(new_log.address)
+                        .then(move |_| Ok(log))
+                        .into_box()
+                })
+                .into_box()
+        })
+        .into_box()
+}
+
+fn new_log_data(client: &AuthClient, log: &AuditLogInfo) -> Result<AData, AuthError> {
+    let mut data = UnpubUnseqAppendOnlyData::new(*log.address.name(), log.address.tag());
+
+    let mut permissions = BTreeMap::new();
+    let _ = permissions.insert(
+        client.public_key(),
+        ADataUnpubPermissionSet::new(true, true, true),
+    );
+    data.append_permissions(
+        ADataUnpubPermissions {
+            permissions,
+            entries_index: 0,
+            owners_index: 0,
+        },
+        0,
+    )?;
+    data.append_owner(
+        ADataOwner {
+            public_key: client.public_key(),
+            entries_index: 0,
+            permissions_index: 1,
+        },
+        0,
+    )?;
+
+    Ok(AData::UnpubUnseq(data))
+}
+
+fn to_json<T: serde::Serialize>(value: &T) -> Result<String, AuthError> {
+    serde_json::to_string(value).map_err(|error| AuthError::Unexpected(error.to_string()))
+}
@@ -0,0 +1,423 @@
+// Copyright 2019 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+//! Offline backups of an account.
+//!
+//! A backup holds the account itself (the client keys and the `MDataInfo`s of the access
+//! container and the config root), the location of its account packet, the registered apps with
+//! the expiry times of their authorisations and a snapshot of the access container. It is
+//! encrypted with a key derived from a passphrase and versioned, so that the format can evolve
+//! without breaking older backups.
+//!
+//! Importing a backup verifies it against the network before anything is changed: the
+//! authenticator entry has to be readable with the keys from the backup and still list the
+//! containers of the snapshot, and the config root has to exist. The account packet is then
+//! re-encrypted with a new password, like it is when recovering the account with its recovery
+//! phrase. Finally the apps from the backup the account doesn't know about anymore are
+//! registered again.
+
+use crate::access_container::{self, AUTHENTICATOR_ENTRY};
+use crate::client::AuthClient;
+use crate::config::{self, AppExpiries, AppInfo, Apps};
+use crate::recovery::RecoveryData;
+use crate::{AuthError, AuthFuture};
+use bincode::{deserialize, serialize};
+use futures::{future, stream, Future, Stream};
+use log::trace;
+use rand::{thread_rng, Rng};
+use safe_core::client::account::{Account, Kdf};
+use safe_core::client::{req, SafeKey};
+use safe_core::core_structs::AccessContainerEntry;
+use safe_core::crypto::SecretBytes;
+use safe_core::utils::{symmetric_decrypt, symmetric_encrypt, SymEncKey, SYM_ENC_KEY_LEN};
+use safe_core::{ok, AuthActions, ConnectionManager, CoreError, FutureExt, MDataInfo};
+use safe_nd::{Error as SndError, MDataValue, Request, Response, XorName};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::mem;
+use tiny_keccak::sha3_256;
+
+/// Prefix marking an account backup.
+const BACKUP_MAGIC: &[u8] = b"SAFEBAK\0";
+
+const BACKUP_SALT_LEN: usize = 32;
+
+/// Contents of an account backup.
+#[derive(Deserialize, Serialize)]
+pub struct Backup {
+    /// Location of the account packet.
+    pub acc_loc: XorName,
+    /// PIN derived from the account locator.
+    pub pin: SecretBytes,
+    /// The account itself.
+    pub account: Account,
+    /// Apps registered when the backup was exported, including revoked ones.
+    pub apps: Apps,
+    /// Containers listed in the authenticator entry of the access container.
+    pub authenticator_entry: HashMap<String, MDataInfo>,
+    /// Access container entries of the apps, by app ID. Revoked apps have none.
+    pub app_entries: HashMap<String, AccessContainerEntry>,
+    /// Expiry times of the app authorisations.
+    pub app_expiries: AppExpiries,
+}
+
+impl Backup {
+    /// Takes the registered apps, their access container entries and the expiry times of their
+    /// authorisations out of the backup, to be registered again with `restore_apps` once the
+    /// account has been restored.
+    pub(crate) fn take_apps(
+        &mut self,
+    ) -> (Apps, HashMap<String, AccessContainerEntry>, AppExpiries) {
+        (
+            mem::replace(&mut self.apps, Default::default()),
+            mem::replace(&mut self.app_entries, Default::default()),
+            mem::replace(&mut self.app_expiries, Default::default()),
+        )
+    }
+}
+
+// Contents of backups exported before the expiry times of app authorisations were included.
+#[derive(Deserialize)]
+struct BackupV1 {
+    acc_loc: XorName,
+    pin: SecretBytes,
+    account: Account,
+    apps: Apps,
+    authenticator_entry: HashMap<String, MDataInfo>,
+    app_entries: HashMap<String, AccessContainerEntry>,
+}
+
+impl From<BackupV1> for Backup {
+    fn from(backup: BackupV1) -> Self {
+        let BackupV1 {
+            acc_loc,
+            pin,
+            account,
+            apps,
+            authenticator_entry,
+            app_entries,
+        } = backup;
+
+        Self {
+            acc_loc,
+            pin,
+            account,
+            apps,
+            authenticator_entry,
+            app_entries,
+            app_expiries: Default::default(),
+        }
+    }
+}
+
+// Encrypted backup along with what is needed to derive its encryption key from the passphrase.
+// `V1` holds a `BackupV1`, `V2` a `Backup`.
+#[derive(Deserialize, Serialize)]
+enum BackupEnvelope {
+    V1 {
+        kdf: Kdf,
+        salt: [u8; BACKUP_SALT_LEN],
+        ciphertext: Vec<u8>,
+    },
+    V2 {
+        kdf: Kdf,
+        salt: [u8; BACKUP_SALT_LEN],
+        ciphertext: Vec<u8>,
+    },
+}
+
+/// Exports a backup of the account, encrypted with the given passphrase.
+pub fn export(client: &AuthClient, passphrase: &str) -> Box<AuthFuture<Vec<u8>>> {
+    trace!("Exporting account backup...");
+
+    let c2 = client.clone();
+    let passphrase = passphrase.to_string();
+    let RecoveryData {
+        acc_loc,
+        pin,
+        account,
+    } = client.recovery_data();
+
+    config::list_apps(client)
+        .join3(
+            config::list_app_expiries(client),
+            access_container::fetch_authenticator_entry(client),
+        )
+        .and_then(
+            move |((_, apps), (_, app_expiries), (_, authenticator_entry))| {
+                let reqs: Vec<_> = apps
+                    .values()
+                    .map(|app| {
+                        let app_id = app.info.id.clone();
+
+                        access_container::fetch_entry(&c2, &app_id, app.keys.clone())
+                            .map(move |(_, entry)| entry.map(|entry| (app_id, entry)))
+                    })
+                    .collect();
+
+                future::join_all(reqs).map(move |app_entries| {
+                    (
+                        apps,
+                        app_expiries,
+                        authenticator_entry,
+                        app_entries.into_iter().flatten().collect(),
+                    )
+                })
+            },
+        )
+        .and_then(
+            move |(apps, app_expiries, authenticator_entry, app_entries)| {
+                seal(
+                    &Backup {
+                        acc_loc,
+                        pin,
+                        account,
+                        apps,
+                        authenticator_entry,
+                        app_entries,
+                        app_expiries,
+                    },
+                    &passphrase,
+                )
+            },
+        )
+        .into_box()
+}
+
+/// Encrypts the backup with a key derived from the passphrase.
+pub fn seal(backup: &Backup, passphrase: &str) -> Result<Vec<u8>, AuthError> {
+    let kdf = Kdf::default();
+    let salt: [u8; BACKUP_SALT_LEN] = thread_rng().gen();
+    let key = derive_key(kdf, passphrase, &salt)?;
+
+    let envelope = BackupEnvelope::V2 {
+        kdf,
+        salt,
+        ciphertext: symmetric_encrypt(&serialize(backup)?, &key, None)?,
+    };
+
+    let mut sealed = BACKUP_MAGIC.to_vec();
+    sealed.extend(serialize(&envelope)?);
+
+    Ok(sealed)
+}
+
+/// Decrypts a backup using the passphrase it was exported with. Fails with
+/// `CoreError::SymmetricDecipherFailure` if the passphrase is wrong.
+pub fn open(sealed: &[u8], passphrase: &str) -> Result<Backup, AuthError> {
+    if !sealed.starts_with(BACKUP_MAGIC) {
+        return Err(AuthError::from("Not an account backup"));
+    }
+
+    match deserialize(&sealed[BACKUP_MAGIC.len()..])? {
+        BackupEnvelope::V1 {
+            kdf,
+            salt,
+            ciphertext,
+        } => {
+            let plaintext = decrypt(kdf, passphrase, &salt, &ciphertext)?;
+            Ok(deserialize::<BackupV1>(&plaintext)?.into())
+        }
+        BackupEnvelope::V2 {
+            kdf,
+            salt,
+            ciphertext,
+        } => {
+            let plaintext = decrypt(kdf, passphrase, &salt, &ciphertext)?;
+            Ok(deserialize(&plaintext)?)
+        }
+    }
+}
+
+fn decrypt(
+    kdf: Kdf,
+    passphrase: &str,
+    salt: &[u8],
+    ciphertext: &[u8],
+) -> Result<Vec<u8>, AuthError> {
+    if !kdf.is_within_bounds() {
+        return Err(AuthError::from("Unsupported key derivation parameters"));
+    }
+
+    Ok(symmetric_decrypt(
+        ciphertext,
+        &derive_key(kdf, passphrase, salt)?,
+    )?)
+}
+
+/// Verifies that the backup matches the account on the network. Fails with
+/// `AuthError::BackupMismatch` if the access container or the config root recorded in the backup
+/// don't belong to the account anymore, e.g. because its client keys were rotated since, or if
+/// containers listed in the backup aren't listed in the authenticator entry anymore. Containers
+/// added since the backup was exported are fine.
+pub(crate) fn verify(
+    connection_manager: &mut ConnectionManager,
+    backup: &Backup,
+    client_id: &SafeKey,
+) -> Result<(), AuthError> {
+    let access_container = &backup.account.access_container;
+    let enc_key = &backup.account.maid_keys.enc_key;
+    let key = access_container::enc_key(access_container, AUTHENTICATOR_ENTRY, enc_key)?;
+
+    let response = req(
+        connection_manager,
+        Request::GetMDataValue {
+            address: *access_container.address(),
+            key,
+        },
+        client_id,
+    )?;
+    let authenticator_entry = match response {
+        Response::GetMDataValue(Ok(MDataValue::Seq(value))) => {
+            access_container::decode_authenticator_entry(&value.data, enc_key)
+                .map_err(|_| AuthError::BackupMismatch)?
+        }
+        Response::GetMDataValue(res) => return Err(mismatch_or(res.err())),
+        _ => return Err(AuthError::from("Unexpected response")),
+    };
+
+    let listed = backup.authenticator_entry.iter().all(|(name, info)| {
+        authenticator_entry
+            .get(name)
+            .map_or(false, |current| current.address() == info.address())
+    });
+    if !listed {
+        return Err(AuthError::BackupMismatch);
+    }
+
+    let config_root = &backup.account.config_root;
+    let response = req(
+        connection_manager,
+        Request::GetMDataVersion(*config_root.address()),
+        client_id,
+    )?;
+
+    match response {
+        Response::GetMDataVersion(Ok(_)) => Ok(()),
+        Response::GetMDataVersion(Err(error)) => Err(mismatch_or(Some(error))),
+        _ => Err(AuthError::from("Unexpected response")),
+    }
+}
+
+/// Registers the apps from a backup that the account doesn't know about anymore, along with the
+/// expiry times of their authorisations. The access container entries of the apps whose keys are
+/// still authorised on the network are restored too; the other apps are registered as revoked,
+/// so they have to be authorised again. Apps the account knows about are left as they are.
+pub(crate) fn restore_apps(
+    client: &AuthClient,
+    apps: Apps,
+    mut app_entries: HashMap<String, AccessContainerEntry>,
+    app_expiries: AppExpiries,
+) -> Box<AuthFuture<()>> {
+    trace!("Restoring apps from a backup...");
+
+    let c2 = client.clone();
+    let c3 = client.clone();
+    let c4 = client.clone();
+    let c5 = client.clone();
+
+    config::list_apps(client)
+        .join(client.list_auth_keys_and_version().map_err(AuthError::from))
+        .and_then(move |((version, registered), (auth_keys, _))| {
+            let missing: Vec<(AppInfo, Option<AccessContainerEntry>)> = apps
+                .into_iter()
+                .filter(|(hash, _)| !registered.contains_key(hash))
+                .map(|(_, app)| {
+                    let entry = if auth_keys.contains_key(&app.keys.public_key()) {
+                        app_entries.remove(&app.info.id)
+                    } else {
+                        None
+                    };
+                    (app, entry)
+                })
+                .collect();
+
+            if missing.is_empty() {
+                return ok!(());
+            }
+
+            let expiries: Vec<_> = missing
+                .iter()
+                .filter_map(|(app, _)| {
+                    let hash = sha3_256(app.info.id.as_bytes());
+                    app_expiries
+                        .get(&hash)
+                        .map(|expires_at| (app.info.id.clone(), *expires_at))
+                })
+                .collect();
+
+            // The apps and their expiry times are each stored under a single config entry, so
+            // they are updated one at a time.
+            let to_register: Vec<_> = missing.iter().map(|(app, _)| app.clone()).collect();
+
+            stream::iter_ok::<_, AuthError>(to_register)
+                .fold((version, registered), move |(version, registered), app| {
+                    config::insert_app(&c2, registered, config::next_version(version), app)
+                        .map(|(version, registered)| (Some(version), registered))
+                })
+                .and_then(move |_| config::list_app_expiries(&c3))
+                .and_then(move |(version, current)| {
+                    stream::iter_ok::<_, AuthError>(expiries).fold(
+                        (version, current),
+                        move |(version, current), (app_id, expires_at)| {
+                            config::set_app_expiry(
+                                &c4,
+                                current,
+                                config::next_version(version),
+                                &app_id,
+                                Some(expires_at),
+                            )
+                            .map(|(version, current)| (Some(version), current))
+                        },
+                    )
+                })
+                .and_then(move |_| {
+                    stream::iter_ok(missing).for_each(move |(app, entry)| match entry {
+                        Some(entry) => restore_app_entry(&c5, app, entry),
+                        None => ok!(()),
+                    })
+                })
+                .into_box()
+        })
+        .into_box()
+}
+
+// Puts the access container entry of the app back, unless it has one already.
+fn restore_app_entry(
+    client: &AuthClient,
+    app: AppInfo,
+    entry: AccessContainerEntry,
+) -> Box<AuthFuture<()>> {
+    let c2 = client.clone();
+
+    access_container::fetch_entry(client, &app.info.id, app.keys.clone())
+        .and_then(move |(_, current)| match current {
+            Some(_) => ok!(()),
+            None => access_container::put_entry(&c2, &app.info.id, &app.keys, &entry, 0),
+        })
+        .into_box()
+}
+
+// Treats data missing from the network, or that the keys from the backup can't access anymore,
+// as a mismatch, and reports any other error as is.
+fn mismatch_or(error: Option<SndError>) -> AuthError {
+    match error {
+        None
+        | Some(SndError::NoSuchData)
+        | Some(SndError::NoSuchEntry)
+        | Some(SndError::AccessDenied) => AuthError::BackupMismatch,
+        Some(error) => AuthError::from(CoreError::from(error)),
+    }
+}
+
+fn derive_key(kdf: Kdf, passphrase: &str, salt: &[u8]) -> Result<SymEncKey, AuthError> {
+    let mut key = [0; SYM_ENC_KEY_LEN];
+    kdf.derive_key(&mut key, passphrase.as_bytes(), salt)?;
+
+    Ok(key)
+}
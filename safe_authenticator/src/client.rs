@@ -6,30 +6,38 @@
 // KIND, either express or implied. Please review the Licences for the specific language governing
 // permissions and limitations relating to use of the SAFE Network Software.
 
+use crate::allowances::NearLimitReports;
+use crate::backup::{self, Backup};
+use crate::config;
+use crate::devices::{self, DeviceData, DeviceKey};
 use crate::errors::AuthError;
+use crate::recovery::{self, RecoveryData};
 #[cfg(any(test, feature = "testing"))]
 use crate::test_utils::divide_seed;
 use crate::AuthFuture;
 use crate::AuthMsgTx;
 use futures::future;
-use futures::Future;
-use log::trace;
+use futures::{stream, Future, Stream};
+use log::{trace, warn};
 use lru_cache::LruCache;
 use rand::rngs::StdRng;
 use rand::{thread_rng, CryptoRng, Rng, SeedableRng};
-use safe_core::client::account::Account;
-use safe_core::client::{req, AuthActions, Inner, SafeKey, IMMUT_DATA_CACHE_SIZE};
+use safe_core::client::account::{Account, Kdf};
+use safe_core::client::{req, AuthActions, Inner, SafeKey, Signer, IMMUT_DATA_CACHE_SIZE};
 use safe_core::config_handler::Config;
-use safe_core::crypto::{shared_box, shared_secretbox};
-use safe_core::fry;
+use safe_core::crypto::{shared_box, shared_secretbox, SecretBytes};
 use safe_core::ipc::BootstrapConfig;
+use safe_core::{err, fry, ok, FutureExt};
 use safe_core::{utils, Client, ClientKeys, ConnectionManager, CoreError, MDataInfo, NetworkTx};
 use safe_nd::{
-    ClientFullId, LoginPacket, Message, MessageId, PublicId, PublicKey, Request, Response, XorName,
+    ClientFullId, Error as SndError, LoginPacket, Message, MessageId, PublicKey, Request, Response,
+    XorName,
 };
-use std::cell::RefCell;
+use std::cell::{RefCell, RefMut};
 use std::fmt;
+use std::mem;
 use std::rc::Rc;
+use std::sync::Arc;
 use std::time::Duration;
 use tiny_keccak::sha3_256;
 use tokio::runtime::current_thread::{block_on_all, Handle};
@@ -43,11 +51,12 @@ pub struct AuthClient {
 
 impl AuthClient {
     /// This is a Gateway function to the Maidsafe network. This will help
-    /// create a fresh acc for the user in the SAFE-network.
+    /// create a fresh acc for the user in the SAFE-network. The account is encrypted using `kdf`.
     pub(crate) fn registered(
         acc_locator: &str,
         acc_password: &str,
         client_id: ClientFullId,
+        kdf: Kdf,
         el_handle: Handle,
         core_tx: AuthMsgTx,
         net_tx: NetworkTx,
@@ -56,6 +65,7 @@ impl AuthClient {
             acc_locator.as_bytes(),
             acc_password.as_bytes(),
             client_id,
+            kdf,
             el_handle,
             core_tx,
             net_tx,
@@ -74,6 +84,7 @@ impl AuthClient {
     pub(crate) fn registered_with_seed(
         seed: &str,
         client_id: ClientFullId,
+        kdf: Kdf,
         el_handle: Handle,
         core_tx: AuthMsgTx,
         net_tx: NetworkTx,
@@ -87,6 +98,7 @@ impl AuthClient {
             arr[0],
             arr[1],
             client_id,
+            kdf,
             el_handle,
             core_tx,
             net_tx,
@@ -101,6 +113,7 @@ impl AuthClient {
         acc_locator: &str,
         acc_password: &str,
         client_id: ClientFullId,
+        kdf: Kdf,
         el_handle: Handle,
         core_tx: AuthMsgTx,
         net_tx: NetworkTx,
@@ -113,6 +126,7 @@ impl AuthClient {
             acc_locator.as_bytes(),
             acc_password.as_bytes(),
             client_id,
+            kdf,
             el_handle,
             core_tx,
             net_tx,
@@ -128,6 +142,7 @@ impl AuthClient {
         acc_locator: &[u8],
         acc_password: &[u8],
         client_id: ClientFullId,
+        kdf: Kdf,
         el_handle: Handle,
         core_tx: AuthMsgTx,
         net_tx: NetworkTx,
@@ -153,7 +168,7 @@ impl AuthClient {
         let client_safe_key = maid_keys.client_safe_key();
 
         let acc = Account::new(maid_keys)?;
-        let acc_ciphertext = acc.encrypt(&user_cred.password, &user_cred.pin)?;
+        let acc_ciphertext = acc.encrypt_with_kdf(&user_cred.password, &user_cred.pin, kdf)?;
 
         let transient_id = create_client_id(&acc_locator.0);
 
@@ -193,15 +208,21 @@ impl AuthClient {
                 acc,
                 acc_loc: acc_locator,
                 user_cred,
+                kdf,
+                kdf_outdated: false,
+                signer: None,
+                near_limit_reports: Default::default(),
             })),
         })
     }
 
     /// This is a Gateway function to the Maidsafe network. This will help login to an already
-    /// existing account of the user in the SAFE-network.
+    /// existing account of the user in the SAFE-network. Accounts encrypted using anything but
+    /// `kdf` are reported as outdated.
     pub(crate) fn login(
         acc_locator: &str,
         acc_password: &str,
+        kdf: Kdf,
         el_handle: Handle,
         core_tx: AuthMsgTx,
         net_tx: NetworkTx,
@@ -209,6 +230,31 @@ impl AuthClient {
         Self::login_impl(
             acc_locator.as_bytes(),
             acc_password.as_bytes(),
+            kdf,
+            None,
+            el_handle,
+            core_tx,
+            net_tx,
+            |routing| routing,
+        )
+    }
+
+    /// Like `login`, but the client's requests are signed by `signer` instead of the secret key
+    /// stored in the account. Fails if the signer doesn't hold the account's key.
+    pub(crate) fn login_with_signer(
+        acc_locator: &str,
+        acc_password: &str,
+        signer: Arc<dyn Signer>,
+        kdf: Kdf,
+        el_handle: Handle,
+        core_tx: AuthMsgTx,
+        net_tx: NetworkTx,
+    ) -> Result<Self, AuthError> {
+        Self::login_impl(
+            acc_locator.as_bytes(),
+            acc_password.as_bytes(),
+            kdf,
+            Some(signer),
             el_handle,
             core_tx,
             net_tx,
@@ -220,14 +266,22 @@ impl AuthClient {
     #[cfg(any(test, feature = "testing"))]
     pub(crate) fn login_with_seed(
         seed: &str,
+        kdf: Kdf,
         el_handle: Handle,
         core_tx: AuthMsgTx,
         net_tx: NetworkTx,
     ) -> Result<Self, AuthError> {
         let arr = divide_seed(seed)?;
-        Self::login_impl(arr[0], arr[1], el_handle, core_tx, net_tx, |routing| {
-            routing
-        })
+        Self::login_impl(
+            arr[0],
+            arr[1],
+            kdf,
+            None,
+            el_handle,
+            core_tx,
+            net_tx,
+            |routing| routing,
+        )
     }
 
     #[cfg(all(feature = "mock-network", any(test, feature = "testing")))]
@@ -235,6 +289,7 @@ impl AuthClient {
     pub fn login_with_hook<F>(
         acc_locator: &str,
         acc_password: &str,
+        kdf: Kdf,
         el_handle: Handle,
         core_tx: AuthMsgTx,
         net_tx: NetworkTx,
@@ -246,6 +301,8 @@ impl AuthClient {
         Self::login_impl(
             acc_locator.as_bytes(),
             acc_password.as_bytes(),
+            kdf,
+            None,
             el_handle,
             core_tx,
             net_tx,
@@ -253,9 +310,12 @@ impl AuthClient {
         )
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn login_impl<F>(
         acc_locator: &[u8],
         acc_password: &[u8],
+        kdf: Kdf,
+        signer: Option<Arc<dyn Signer>>,
         el_handle: Handle,
         core_tx: AuthMsgTx,
         net_tx: NetworkTx,
@@ -270,10 +330,6 @@ impl AuthClient {
 
         let acc_locator = Account::generate_network_id(&keyword, &pin)?;
 
-        let client_full_id = create_client_id(&acc_locator.0);
-        let client_pk = *client_full_id.public_id().public_key();
-        let client_full_id = SafeKey::client(client_full_id);
-
         let user_cred = UserCred::new(password, pin);
 
         // Create the connection manager
@@ -281,31 +337,155 @@ impl AuthClient {
             ConnectionManager::new(Config::new().quic_p2p, &net_tx.clone())?;
         connection_manager = connection_manager_wrapper_fn(connection_manager);
 
-        let (account_buffer, signature) = {
-            trace!("Using throw-away connection group to get a login packet.");
+        let account_buffer = Self::get_login_packet(&mut connection_manager, acc_locator)?;
+        let acc = Account::decrypt(
+            account_buffer.as_slice(),
+            &user_cred.password,
+            &user_cred.pin,
+        )?;
+        let kdf_outdated = Account::kdf(&account_buffer) != kdf;
+
+        let id_packet = match signer {
+            Some(ref signer) => {
+                if signer.public_key() != acc.maid_keys.public_key() {
+                    return Err(AuthError::Unexpected(
+                        "The signer doesn't hold the account's key".to_string(),
+                    ));
+                }
+                SafeKey::external(Arc::clone(signer))
+            }
+            None => acc.maid_keys.client_safe_key(),
+        };
 
-            block_on_all(connection_manager.bootstrap(client_full_id.clone()))?;
+        trace!("Creating an actual client...");
 
-            let response = req(
-                &mut connection_manager,
-                Request::GetLoginPacket(acc_locator),
-                &client_full_id,
-            )?;
+        block_on_all(connection_manager.bootstrap(id_packet))?;
 
-            block_on_all(connection_manager.disconnect(&client_full_id.public_id()))?;
+        Ok(Self {
+            inner: Rc::new(RefCell::new(Inner::new(
+                el_handle,
+                connection_manager,
+                LruCache::new(IMMUT_DATA_CACHE_SIZE),
+                Duration::from_secs(180), // REQUEST_TIMEOUT_SECS), //FIXME
+                core_tx,
+                net_tx,
+            ))),
+            auth_inner: Rc::new(RefCell::new(AuthInner {
+                acc,
+                acc_loc: acc_locator,
+                user_cred,
+                kdf,
+                kdf_outdated,
+                signer,
+                near_limit_reports: Default::default(),
+            })),
+        })
+    }
 
-            match response {
-                Response::GetLoginPacket(res) => res?,
-                _ => return Err(AuthError::from("Unexpected response")),
+    /// Logs in to an account from a registered device, using the copy of the account stored for
+    /// the device instead of the account packet. Fails with `AuthError::UnknownDevice` if there
+    /// is no copy of the account for the device, e.g. because it was logged out. The account
+    /// packet is encrypted using `kdf` when it's updated.
+    pub(crate) fn login_device(
+        acc_locator: &str,
+        acc_password: &str,
+        device_key: &DeviceKey,
+        kdf: Kdf,
+        el_handle: Handle,
+        core_tx: AuthMsgTx,
+        net_tx: NetworkTx,
+    ) -> Result<Self, AuthError> {
+        trace!("Attempting to log into an acc from a device.");
+
+        let (password, keyword, pin) =
+            utils::derive_secrets(acc_locator.as_bytes(), acc_password.as_bytes());
+        let acc_loc = Account::generate_network_id(&keyword, &pin)?;
+        let device_sk = devices::secret_key(device_key);
+        let device_loc = devices::location(&device_sk.public_key());
+
+        // Create the connection manager
+        let mut connection_manager =
+            ConnectionManager::new(Config::new().quic_p2p, &net_tx.clone())?;
+
+        let device_buffer = match Self::get_login_packet(&mut connection_manager, device_loc) {
+            Ok(device_buffer) => device_buffer,
+            Err(AuthError::SndError(SndError::NoSuchLoginPacket)) => {
+                return Err(AuthError::UnknownDevice)
             }
+            Err(error) => return Err(error),
         };
+        // The copy of a logged out device is wiped.
+        let DeviceData {
+            acc_loc: device_acc_loc,
+            password: device_password,
+            account: acc,
+        } = devices::open(&device_sk, &device_buffer).map_err(|_| AuthError::UnknownDevice)?;
+
+        if device_acc_loc != acc_loc {
+            return Err(AuthError::UnknownDevice);
+        }
+        if device_password != password {
+            return Err(AuthError::from(CoreError::SymmetricDecipherFailure));
+        }
 
-        client_pk.verify(&signature, account_buffer.as_slice())?;
-        let acc = Account::decrypt(
-            account_buffer.as_slice(),
-            &user_cred.password,
-            &user_cred.pin,
-        )?;
+        let user_cred = UserCred::new(password, pin);
+        let id_packet = acc.maid_keys.client_safe_key();
+
+        trace!("Creating an actual client...");
+
+        block_on_all(connection_manager.bootstrap(id_packet))?;
+
+        Ok(Self {
+            inner: Rc::new(RefCell::new(Inner::new(
+                el_handle,
+                connection_manager,
+                LruCache::new(IMMUT_DATA_CACHE_SIZE),
+                Duration::from_secs(180), // REQUEST_TIMEOUT_SECS), //FIXME
+                core_tx,
+                net_tx,
+            ))),
+            auth_inner: Rc::new(RefCell::new(AuthInner {
+                acc,
+                acc_loc,
+                user_cred,
+                kdf,
+                kdf_outdated: false,
+                signer: None,
+                near_limit_reports: Default::default(),
+            })),
+        })
+    }
+
+    /// Regains access to an account using its recovery phrase. The account packet is
+    /// re-encrypted with `new_password`, so it can be used to log in together with the
+    /// account's existing locator from then on. The account packet is encrypted using `kdf`.
+    pub(crate) fn recover(
+        recovery_phrase: &str,
+        new_password: &str,
+        kdf: Kdf,
+        el_handle: Handle,
+        core_tx: AuthMsgTx,
+        net_tx: NetworkTx,
+    ) -> Result<Self, AuthError> {
+        trace!("Attempting to recover an acc.");
+
+        let recovery_sk = recovery::secret_key(recovery_phrase)?;
+        let recovery_loc = recovery::location(&recovery_sk.public_key());
+
+        // Create the connection manager
+        let mut connection_manager =
+            ConnectionManager::new(Config::new().quic_p2p, &net_tx.clone())?;
+
+        let recovery_buffer = Self::get_login_packet(&mut connection_manager, recovery_loc)?;
+        let RecoveryData {
+            acc_loc,
+            pin,
+            account: acc,
+        } = recovery::open(&recovery_sk, &recovery_buffer)?;
+
+        let user_cred = UserCred::new(utils::derive_password_secret(new_password.as_bytes()), pin);
+
+        Self::reset_login_packet(&mut connection_manager, acc_loc, &acc, &user_cred, kdf)?;
 
         let id_packet = acc.maid_keys.client_safe_key();
 
@@ -324,12 +504,135 @@ impl AuthClient {
             ))),
             auth_inner: Rc::new(RefCell::new(AuthInner {
                 acc,
-                acc_loc: acc_locator,
+                acc_loc,
+                user_cred,
+                kdf,
+                kdf_outdated: false,
+                signer: None,
+                near_limit_reports: Default::default(),
+            })),
+        })
+    }
+
+    /// Regains access to an account using an exported backup. The backup is verified against
+    /// the network first, then the account packet is re-encrypted with `new_password`, so it can
+    /// be used to log in together with the locator the account had when the backup was exported.
+    /// The account packet is encrypted using `kdf`.
+    pub(crate) fn restore(
+        backup: Backup,
+        new_password: &str,
+        kdf: Kdf,
+        el_handle: Handle,
+        core_tx: AuthMsgTx,
+        net_tx: NetworkTx,
+    ) -> Result<Self, AuthError> {
+        trace!("Attempting to restore an acc from a backup.");
+
+        // Create the connection manager
+        let mut connection_manager =
+            ConnectionManager::new(Config::new().quic_p2p, &net_tx.clone())?;
+
+        let id_packet = backup.account.maid_keys.client_safe_key();
+
+        block_on_all(connection_manager.bootstrap(id_packet.clone()))?;
+        backup::verify(&mut connection_manager, &backup, &id_packet)?;
+
+        let Backup {
+            acc_loc,
+            pin,
+            account: acc,
+            ..
+        } = backup;
+        let user_cred = UserCred::new(utils::derive_password_secret(new_password.as_bytes()), pin);
+
+        Self::reset_login_packet(&mut connection_manager, acc_loc, &acc, &user_cred, kdf)?;
+
+        Ok(Self {
+            inner: Rc::new(RefCell::new(Inner::new(
+                el_handle,
+                connection_manager,
+                LruCache::new(IMMUT_DATA_CACHE_SIZE),
+                Duration::from_secs(180), // REQUEST_TIMEOUT_SECS), //FIXME
+                core_tx,
+                net_tx,
+            ))),
+            auth_inner: Rc::new(RefCell::new(AuthInner {
+                acc,
+                acc_loc,
                 user_cred,
+                kdf,
+                kdf_outdated: false,
+                signer: None,
+                near_limit_reports: Default::default(),
             })),
         })
     }
 
+    // Re-encrypts the account packet with the given credentials, using a throw-away connection.
+    fn reset_login_packet(
+        connection_manager: &mut ConnectionManager,
+        acc_loc: XorName,
+        acc: &Account,
+        user_cred: &UserCred,
+        kdf: Kdf,
+    ) -> Result<(), AuthError> {
+        trace!("Using throw-away connection group to update the login packet.");
+
+        let account_packet_id = create_client_id(&acc_loc.0);
+        let updated_packet =
+            Self::prepare_account_packet_update(acc_loc, acc, user_cred, kdf, &account_packet_id)?;
+        let account_packet_id = SafeKey::client(account_packet_id);
+
+        block_on_all(connection_manager.bootstrap(account_packet_id.clone()))?;
+
+        let response = req(
+            connection_manager,
+            Request::UpdateLoginPacket(updated_packet),
+            &account_packet_id,
+        )?;
+
+        block_on_all(connection_manager.disconnect(&account_packet_id.public_id()))?;
+
+        match response {
+            Response::Mutation(res) => Ok(res?),
+            _ => Err(AuthError::from("Unexpected response")),
+        }
+    }
+
+    // Fetches the login packet stored at the given location using a throw-away connection,
+    // and verifies its signature.
+    fn get_login_packet(
+        connection_manager: &mut ConnectionManager,
+        acc_loc: XorName,
+    ) -> Result<Vec<u8>, AuthError> {
+        let client_full_id = create_client_id(&acc_loc.0);
+        let client_pk = *client_full_id.public_id().public_key();
+        let client_full_id = SafeKey::client(client_full_id);
+
+        let (account_buffer, signature) = {
+            trace!("Using throw-away connection group to get a login packet.");
+
+            block_on_all(connection_manager.bootstrap(client_full_id.clone()))?;
+
+            let response = req(
+                connection_manager,
+                Request::GetLoginPacket(acc_loc),
+                &client_full_id,
+            )?;
+
+            block_on_all(connection_manager.disconnect(&client_full_id.public_id()))?;
+
+            match response {
+                Response::GetLoginPacket(res) => res?,
+                _ => return Err(AuthError::from("Unexpected response")),
+            }
+        };
+
+        client_pk.verify(&signature, account_buffer.as_slice())?;
+
+        Ok(account_buffer)
+    }
+
     /// Get Maidsafe specific configuration's Root Directory ID if available in
     /// account packet used for current login.
     pub fn config_root_dir(&self) -> MDataInfo {
@@ -356,6 +659,25 @@ impl AuthClient {
         }
     }
 
+    /// Returns the keys the account currently uses to access the network.
+    pub(crate) fn client_keys(&self) -> ClientKeys {
+        let auth_inner = self.auth_inner.borrow();
+        auth_inner.acc.maid_keys.clone()
+    }
+
+    /// Returns `true` if the client's requests are signed by an external signer.
+    pub(crate) fn has_external_signer(&self) -> bool {
+        self.auth_inner.borrow().signer.is_some()
+    }
+
+    /// Returns the apps reported as near the limit of their coin allowance, and the observer they
+    /// are reported to.
+    pub(crate) fn near_limit_reports(&self) -> RefMut<'_, NearLimitReports> {
+        RefMut::map(self.auth_inner.borrow_mut(), |auth_inner| {
+            &mut auth_inner.near_limit_reports
+        })
+    }
+
     /// Get User's Access Container if available in account packet used for
     /// current login
     pub fn access_container(&self) -> MDataInfo {
@@ -386,36 +708,394 @@ impl AuthClient {
         acc_loc: XorName,
         account: &Account,
         keys: &UserCred,
-        full_id: &SafeKey,
+        kdf: Kdf,
+        full_id: &ClientFullId,
     ) -> Result<LoginPacket, AuthError> {
-        let encrypted_account = account.encrypt(&keys.password, &keys.pin)?;
+        let encrypted_account = account.encrypt_with_kdf(&keys.password, &keys.pin, kdf)?;
+        Self::sign_login_packet(acc_loc, encrypted_account, full_id)
+    }
 
-        let sig = full_id.sign(&encrypted_account);
-        let client_pk = match full_id.public_id() {
-            PublicId::Client(id) => *id.public_key(),
-            // FIXME
-            x => panic!("Unexpected ID type {:?}", x),
-        };
-        LoginPacket::new(acc_loc, client_pk, encrypted_account, sig).map_err(AuthError::from)
+    fn sign_login_packet(
+        acc_loc: XorName,
+        data: Vec<u8>,
+        full_id: &ClientFullId,
+    ) -> Result<LoginPacket, AuthError> {
+        let sig = full_id.sign(&data);
+        let client_pk = *full_id.public_id().public_key();
+        LoginPacket::new(acc_loc, client_pk, data, sig).map_err(AuthError::from)
     }
 
     /// Updates user's account packet.
     pub fn update_account_packet(&self) -> Box<AuthFuture<()>> {
         trace!("Updating account packet.");
 
+        let client = self.clone();
+        let client2 = self.clone();
+
+        self.replace_account_packet()
+            .and_then(move |()| client.refresh_recovery_packet())
+            .and_then(move |()| client2.refresh_device_packets())
+            .into_box()
+    }
+
+    /// Switches the account over to new client keys and a re-encrypted config root. The client
+    /// connects to the network with the new identity and the account packet is updated. If the
+    /// account packet can't be updated, the client keeps using the current keys.
+    pub(crate) fn switch_client_keys(
+        &self,
+        new_keys: ClientKeys,
+        new_config_root: MDataInfo,
+    ) -> Box<AuthFuture<()>> {
+        trace!("Switching to new client keys.");
+
+        let (old_keys, old_config_root) = {
+            let mut auth_inner = self.auth_inner.borrow_mut();
+            let old_keys = mem::replace(&mut auth_inner.acc.maid_keys, new_keys.clone());
+            let old_config_root = mem::replace(&mut auth_inner.acc.config_root, new_config_root);
+
+            (old_keys, old_config_root)
+        };
+        let old_pub_id = old_keys.client_safe_key().public_id();
+
+        let mut cm = self.inner.borrow_mut().cm().clone();
+        let mut cm2 = cm.clone();
+
+        let c2 = self.clone();
+        let c3 = self.clone();
+        let c4 = self.clone();
+        let c5 = self.clone();
+
+        cm.bootstrap(new_keys.client_safe_key())
+            .map_err(AuthError::from)
+            .and_then(move |()| c2.replace_account_packet())
+            .or_else(move |error| {
+                let mut auth_inner = c3.auth_inner.borrow_mut();
+                auth_inner.acc.maid_keys = old_keys;
+                auth_inner.acc.config_root = old_config_root;
+
+                Err(error)
+            })
+            .and_then(move |()| cm2.disconnect(&old_pub_id).map_err(AuthError::from))
+            .and_then(move |()| c4.refresh_recovery_packet())
+            .and_then(move |()| c5.refresh_device_packets())
+            .into_box()
+    }
+
+    // Stores the account at its current location, encrypted with the current credentials.
+    fn replace_account_packet(&self) -> Box<AuthFuture<()>> {
         let auth_inner = self.auth_inner.borrow();
         let account = &auth_inner.acc;
         let keys = &auth_inner.user_cred;
         let acc_loc = &auth_inner.acc_loc;
-        let account_packet_id = SafeKey::client(create_client_id(&acc_loc.0));
-        let account_pub_id = account_packet_id.public_id();
+        let account_packet_id = create_client_id(&acc_loc.0);
         let updated_packet = fry!(Self::prepare_account_packet_update(
             *acc_loc,
             account,
             keys,
+            auth_inner.kdf,
             &account_packet_id
         ));
 
+        let auth_inner2 = Rc::clone(&self.auth_inner);
+
+        self.replace_login_packet(updated_packet, account_packet_id)
+            .map(move |()| auth_inner2.borrow_mut().kdf_outdated = false)
+            .into_box()
+    }
+
+    /// Sets up a new recovery phrase for the account and returns it. A previously set up phrase
+    /// stops working once this succeeds.
+    pub fn set_up_recovery(&self) -> Box<AuthFuture<String>> {
+        trace!("Setting up account recovery.");
+
+        let recovery_phrase = recovery::generate_phrase();
+        let recovery_pk = fry!(recovery::secret_key(&recovery_phrase)).public_key();
+        let recovery_packet = fry!(self.prepare_recovery_packet(&recovery_pk));
+
+        let c2 = self.clone();
+        let c3 = self.clone();
+
+        // Store the new recovery packet before forgetting the old one, so that there is always a
+        // working recovery phrase.
+        self.insert_login_packet(recovery_packet)
+            .map_err(AuthError::from)
+            .and_then(move |()| config::get_recovery_key(&c2))
+            .and_then(move |(version, old_recovery_pk)| {
+                config::set_recovery_key(
+                    &c3,
+                    old_recovery_pk,
+                    config::next_version(version),
+                    recovery_pk,
+                )
+                .map(move |_| (c3, old_recovery_pk))
+            })
+            .and_then(move |(client, old_recovery_pk)| match old_recovery_pk {
+                Some(old_recovery_pk) if old_recovery_pk != recovery_pk => {
+                    client.wipe_login_packet(recovery::location(&old_recovery_pk))
+                }
+                _ => ok!(()),
+            })
+            .map(move |()| recovery_phrase)
+            .into_box()
+    }
+
+    // Updates the copy of the account stored for recovery, if recovery has been set up.
+    fn refresh_recovery_packet(&self) -> Box<AuthFuture<()>> {
+        let client = self.clone();
+
+        config::get_recovery_key(self)
+            .or_else(|error| match error {
+                // Accounts without a config root can't have recovery set up.
+                AuthError::CoreError(CoreError::DataError(SndError::NoSuchData)) => {
+                    Ok((None, None))
+                }
+                error => Err(error),
+            })
+            .and_then(move |(_, recovery_pk)| match recovery_pk {
+                Some(recovery_pk) => {
+                    let recovery_loc = recovery::location(&recovery_pk);
+                    let recovery_packet = fry!(client.prepare_recovery_packet(&recovery_pk));
+
+                    client.replace_login_packet(recovery_packet, create_client_id(&recovery_loc.0))
+                }
+                None => ok!(()),
+            })
+            .into_box()
+    }
+
+    fn prepare_recovery_packet(
+        &self,
+        recovery_pk: &threshold_crypto::PublicKey,
+    ) -> Result<LoginPacket, AuthError> {
+        let recovery_loc = recovery::location(recovery_pk);
+        let sealed = recovery::seal(recovery_pk, &self.recovery_data())?;
+
+        Self::sign_login_packet(recovery_loc, sealed, &create_client_id(&recovery_loc.0))
+    }
+
+    /// Returns what is needed to regain access to the account without its password.
+    pub(crate) fn recovery_data(&self) -> RecoveryData {
+        let auth_inner = self.auth_inner.borrow();
+
+        RecoveryData {
+            acc_loc: auth_inner.acc_loc,
+            pin: auth_inner.user_cred.pin.clone(),
+            account: auth_inner.acc.clone(),
+        }
+    }
+
+    /// Stores a copy of the account for the device with the given public key, which the device
+    /// logs in with.
+    pub(crate) fn store_device_packet(
+        &self,
+        device_pk: &threshold_crypto::PublicKey,
+    ) -> Box<AuthFuture<()>> {
+        let device_packet = fry!(self.prepare_device_packet(device_pk));
+
+        self.insert_login_packet(device_packet)
+            .map_err(AuthError::from)
+            .into_box()
+    }
+
+    // Updates the copies of the account stored for the registered devices. Devices registered
+    // without a copy are skipped.
+    fn refresh_device_packets(&self) -> Box<AuthFuture<()>> {
+        let client = self.clone();
+
+        config::list_devices(self)
+            .or_else(|error| match error {
+                // Accounts without a config root can't have devices registered.
+                AuthError::CoreError(CoreError::DataError(SndError::NoSuchData)) => {
+                    Ok((None, Default::default()))
+                }
+                error => Err(error),
+            })
+            .and_then(move |(_, registered)| {
+                let device_pks: Vec<_> = registered
+                    .into_iter()
+                    .map(|(_, device)| device.public_key)
+                    .collect();
+
+                // Each packet is replaced over a connection of its own, so one at a time.
+                stream::iter_ok(device_pks).for_each(move |device_pk| {
+                    let device_loc = devices::location(&device_pk);
+                    let device_packet = fry!(client.prepare_device_packet(&device_pk));
+
+                    client
+                        .replace_login_packet(device_packet, create_client_id(&device_loc.0))
+                        .or_else(|error| match error {
+                            AuthError::CoreError(CoreError::DataError(
+                                SndError::NoSuchLoginPacket,
+                            )) => Ok(()),
+                            error => Err(error),
+                        })
+                        .into_box()
+                })
+            })
+            .into_box()
+    }
+
+    fn prepare_device_packet(
+        &self,
+        device_pk: &threshold_crypto::PublicKey,
+    ) -> Result<LoginPacket, AuthError> {
+        let device_loc = devices::location(device_pk);
+        let sealed = devices::seal(device_pk, &self.device_data())?;
+
+        Self::sign_login_packet(device_loc, sealed, &create_client_id(&device_loc.0))
+    }
+
+    // Returns what a registered device needs to log in.
+    fn device_data(&self) -> DeviceData {
+        let auth_inner = self.auth_inner.borrow();
+
+        DeviceData {
+            acc_loc: auth_inner.acc_loc,
+            password: auth_inner.user_cred.password.clone(),
+            account: auth_inner.acc.clone(),
+        }
+    }
+
+    /// Returns `true` if the account packet was encrypted with an outdated key derivation
+    /// function when logging in. Calling `update_account_packet` re-encrypts it with the one this
+    /// client was created with.
+    pub fn kdf_outdated(&self) -> bool {
+        self.auth_inner.borrow().kdf_outdated
+    }
+
+    /// Changes the password of the account. `current_password` must match the password the
+    /// account is currently encrypted with.
+    ///
+    /// The account packet stays at the same location and is re-encrypted in a single update, so
+    /// at any point in time either the current or the new password unlocks the account. The
+    /// copies stored for registered devices are updated to check the new password afterwards.
+    pub fn change_password(
+        &self,
+        current_password: &[u8],
+        new_password: &[u8],
+    ) -> Box<AuthFuture<()>> {
+        trace!("Changing account password.");
+
+        fry!(self.check_password(current_password));
+
+        let (new_cred, updated_packet, account_packet_id) = {
+            let auth_inner = self.auth_inner.borrow();
+            let new_cred = UserCred::new(
+                utils::derive_password_secret(new_password),
+                auth_inner.user_cred.pin.clone(),
+            );
+            let account_packet_id = create_client_id(&auth_inner.acc_loc.0);
+            let updated_packet = fry!(Self::prepare_account_packet_update(
+                auth_inner.acc_loc,
+                &auth_inner.acc,
+                &new_cred,
+                auth_inner.kdf,
+                &account_packet_id
+            ));
+
+            (new_cred, updated_packet, account_packet_id)
+        };
+
+        let auth_inner = Rc::clone(&self.auth_inner);
+        let client = self.clone();
+
+        self.replace_login_packet(updated_packet, account_packet_id)
+            .map(move |()| auth_inner.borrow_mut().user_cred = new_cred)
+            .and_then(move |()| client.refresh_device_packets())
+            .into_box()
+    }
+
+    /// Moves the account to a new locator. `password` must match the password the account is
+    /// currently encrypted with.
+    ///
+    /// The account packet is first stored at the location derived from the new locator, and the
+    /// packet at the old location is only wiped once that succeeded. An interruption at any point
+    /// therefore leaves at least one of the locators working. The network doesn't support
+    /// deleting login packets yet, so the old packet is overwritten with an empty one instead.
+    /// If wiping fails the account has already moved, so the change still succeeds and only a
+    /// warning is logged; the old locator then keeps working too.
+    /// If account recovery has been set up, the recovery copy is updated to point to the new
+    /// location afterwards, as are the copies stored for registered devices.
+    pub fn change_locator(&self, new_locator: &[u8], password: &[u8]) -> Box<AuthFuture<()>> {
+        trace!("Changing account locator.");
+
+        fry!(self.check_password(password));
+
+        let (keyword, pin) = utils::derive_locator_secrets(new_locator);
+        let new_acc_loc = fry!(Account::generate_network_id(&keyword, &pin));
+
+        let (old_acc_loc, new_cred, new_packet) = {
+            let auth_inner = self.auth_inner.borrow();
+            if auth_inner.acc_loc == new_acc_loc {
+                return err!(AuthError::from(
+                    "The new locator is the same as the current one"
+                ));
+            }
+
+            let new_cred = UserCred::new(auth_inner.user_cred.password.clone(), pin);
+            let new_packet = fry!(Self::prepare_account_packet_update(
+                new_acc_loc,
+                &auth_inner.acc,
+                &new_cred,
+                auth_inner.kdf,
+                &create_client_id(&new_acc_loc.0)
+            ));
+
+            (auth_inner.acc_loc, new_cred, new_packet)
+        };
+
+        let client = self.clone();
+        let client2 = self.clone();
+        let client3 = self.clone();
+
+        self.insert_login_packet(new_packet)
+            .map_err(AuthError::from)
+            .and_then(move |()| {
+                {
+                    let mut auth_inner = client.auth_inner.borrow_mut();
+                    auth_inner.acc_loc = new_acc_loc;
+                    auth_inner.user_cred = new_cred;
+                }
+
+                client.wipe_login_packet(old_acc_loc).or_else(|error| {
+                    warn!(
+                        "Failed to wipe the login packet at the old location: {}",
+                        error
+                    );
+                    Ok(())
+                })
+            })
+            .and_then(move |()| client2.refresh_recovery_packet())
+            .and_then(move |()| client3.refresh_device_packets())
+            .into_box()
+    }
+
+    /// Checks that the account is currently encrypted with the given password. Fails the same way
+    /// logging in with a wrong password does.
+    pub(crate) fn check_password(&self, password: &[u8]) -> Result<(), AuthError> {
+        if utils::derive_password_secret(password) == self.auth_inner.borrow().user_cred.password {
+            Ok(())
+        } else {
+            Err(AuthError::from(CoreError::SymmetricDecipherFailure))
+        }
+    }
+
+    /// Overwrites the login packet at the given location with an empty one. The network doesn't
+    /// support deleting login packets yet.
+    pub(crate) fn wipe_login_packet(&self, acc_loc: XorName) -> Box<AuthFuture<()>> {
+        let packet_id = create_client_id(&acc_loc.0);
+        let wiped_packet = fry!(Self::sign_login_packet(acc_loc, Vec::new(), &packet_id));
+
+        self.replace_login_packet(wiped_packet, packet_id)
+    }
+
+    // Replaces the login packet at its destination. The request is signed by the throw-away
+    // identity that is allowed to access the packet, over a connection of its own.
+    fn replace_login_packet(
+        &self,
+        updated_packet: LoginPacket,
+        account_packet_id: ClientFullId,
+    ) -> Box<AuthFuture<()>> {
         let mut client_inner = self.inner.borrow_mut();
 
         let mut cm = client_inner.cm().clone();
@@ -427,6 +1107,9 @@ impl AuthClient {
         let signature =
             account_packet_id.sign(&unwrap!(bincode::serialize(&(&request, message_id))));
 
+        let account_packet_id = SafeKey::client(account_packet_id);
+        let account_pub_id = account_packet_id.public_id();
+
         let account_pub_id2 = account_pub_id.clone();
 
         Box::new(
@@ -477,7 +1160,10 @@ impl Client for AuthClient {
 
     fn full_id(&self) -> SafeKey {
         let auth_inner = self.auth_inner.borrow();
-        auth_inner.acc.maid_keys.client_safe_key()
+        match auth_inner.signer {
+            Some(ref signer) => SafeKey::external(Arc::clone(signer)),
+            None => auth_inner.acc.maid_keys.client_safe_key(),
+        }
     }
 
     fn owner_key(&self) -> PublicKey {
@@ -527,6 +1213,13 @@ struct AuthInner {
     acc: Account,
     acc_loc: XorName,
     user_cred: UserCred,
+    // KDF the account packet is encrypted with whenever it is stored.
+    kdf: Kdf,
+    // Whether the account packet was encrypted with a different KDF than `kdf`.
+    kdf_outdated: bool,
+    // Signs the client's requests instead of the secret key in `acc`, if set.
+    signer: Option<Arc<dyn Signer>>,
+    near_limit_reports: NearLimitReports,
 }
 
 // ------------------------------------------------------------
@@ -535,12 +1228,12 @@ struct AuthInner {
 
 #[derive(Clone)]
 struct UserCred {
-    pin: Vec<u8>,
-    password: Vec<u8>,
+    pin: SecretBytes,
+    password: SecretBytes,
 }
 
 impl UserCred {
-    fn new(password: Vec<u8>, pin: Vec<u8>) -> Self {
+    fn new(password: SecretBytes, pin: SecretBytes) -> Self {
         Self { pin, password }
     }
 }
@@ -553,7 +1246,7 @@ mod tests {
     use safe_core::client::test_create_balance;
     use safe_core::ok;
     use safe_core::utils::test_utils::{
-        calculate_new_balance, finish, gen_client_id, random_client, setup_client,
+        calculate_new_balance, finish, gen_client_id, random_client, setup_client, TEST_KDF,
     };
     use safe_core::{utils, CoreError, DIR_TAG};
     use safe_nd::{Coins, Error as SndError, MDataKind};
@@ -582,13 +1275,22 @@ mod tests {
             &sec_0,
             &sec_1,
             client_id.clone(),
+            TEST_KDF,
             el.handle(),
             core_tx.clone(),
             net_tx.clone(),
         ));
 
         // Account creation - same secrets - should fail
-        match AuthClient::registered(&sec_0, &sec_1, client_id, el.handle(), core_tx, net_tx) {
+        match AuthClient::registered(
+            &sec_0,
+            &sec_1,
+            client_id,
+            TEST_KDF,
+            el.handle(),
+            core_tx,
+            net_tx,
+        ) {
             Ok(_) => panic!("Account name hijacking should fail"),
             Err(AuthError::SndError(SndError::LoginPacketExists)) => (),
             Err(err) => panic!("{:?}", err),
@@ -613,6 +1315,7 @@ mod tests {
                 match AuthClient::login(
                     &sec_0,
                     &sec_1,
+                    TEST_KDF,
                     el_h.clone(),
                     core_tx.clone(),
                     net_tx.clone(),
@@ -620,18 +1323,86 @@ mod tests {
                     Err(AuthError::SndError(SndError::NoSuchLoginPacket)) => (),
                     x => panic!("Unexpected Login outcome: {:?}", x),
                 }
-                AuthClient::registered(&sec_0, &sec_1, client_id, el_h, core_tx, net_tx)
+                AuthClient::registered(&sec_0, &sec_1, client_id, TEST_KDF, el_h, core_tx, net_tx)
             },
             |_| finish(),
         );
 
         setup_client(
             &(),
-            |el_h, core_tx, net_tx| AuthClient::login(&sec_0, &sec_1, el_h, core_tx, net_tx),
+            |el_h, core_tx, net_tx| {
+                AuthClient::login(&sec_0, &sec_1, TEST_KDF, el_h, core_tx, net_tx)
+            },
             |_| finish(),
         );
     }
 
+    // Test that account packets encrypted with an outdated KDF are detected on login and
+    // upgraded by updating the account packet.
+    #[test]
+    fn kdf_upgrade() {
+        let sec_0 = unwrap!(utils::generate_random_string(10));
+        let sec_1 = unwrap!(utils::generate_random_string(10));
+        let client_id = gen_client_id();
+
+        unwrap!(test_create_balance(
+            &client_id,
+            unwrap!(Coins::from_str("10"))
+        ));
+
+        setup_client(
+            &(),
+            |el_h, core_tx, net_tx| {
+                AuthClient::registered(&sec_0, &sec_1, client_id, TEST_KDF, el_h, core_tx, net_tx)
+            },
+            |client| {
+                assert!(!client.kdf_outdated());
+
+                // Replace the account packet with one encrypted with the legacy KDF.
+                let auth_inner = client.auth_inner.borrow();
+                let encrypted_account = unwrap!(auth_inner.acc.encrypt_with_kdf(
+                    &auth_inner.user_cred.password,
+                    &auth_inner.user_cred.pin,
+                    Kdf::LEGACY
+                ));
+                let account_packet_id = create_client_id(&auth_inner.acc_loc.0);
+                let legacy_packet = unwrap!(AuthClient::sign_login_packet(
+                    auth_inner.acc_loc,
+                    encrypted_account,
+                    &account_packet_id
+                ));
+
+                client.replace_login_packet(legacy_packet, account_packet_id)
+            },
+        );
+
+        setup_client(
+            &(),
+            |el_h, core_tx, net_tx| {
+                AuthClient::login(&sec_0, &sec_1, TEST_KDF, el_h, core_tx, net_tx)
+            },
+            |client| {
+                assert!(client.kdf_outdated());
+
+                let client2 = client.clone();
+                client
+                    .update_account_packet()
+                    .map(move |()| assert!(!client2.kdf_outdated()))
+            },
+        );
+
+        setup_client(
+            &(),
+            |el_h, core_tx, net_tx| {
+                AuthClient::login(&sec_0, &sec_1, TEST_KDF, el_h, core_tx, net_tx)
+            },
+            |client| {
+                assert!(!client.kdf_outdated());
+                finish()
+            },
+        );
+    }
+
     // Test logging in using a seeded account.
     #[test]
     fn seeded_login() {
@@ -645,6 +1416,7 @@ mod tests {
             match AuthClient::registered_with_seed(
                 &invalid_seed,
                 client_id,
+                TEST_KDF,
                 el.handle(),
                 core_tx,
                 net_tx,
@@ -658,7 +1430,8 @@ mod tests {
             let (core_tx, _): (AuthMsgTx, _) = mpsc::unbounded();
             let (net_tx, _) = mpsc::unbounded();
 
-            match AuthClient::login_with_seed(&invalid_seed, el.handle(), core_tx, net_tx) {
+            match AuthClient::login_with_seed(&invalid_seed, TEST_KDF, el.handle(), core_tx, net_tx)
+            {
                 Err(AuthError::Unexpected(_)) => (),
                 _ => panic!("Expected a failure"),
             }
@@ -677,6 +1450,7 @@ mod tests {
             |el_h, core_tx, net_tx| {
                 match AuthClient::login_with_seed(
                     &seed,
+                    TEST_KDF,
                     el_h.clone(),
                     core_tx.clone(),
                     net_tx.clone(),
@@ -684,14 +1458,16 @@ mod tests {
                     Err(AuthError::SndError(SndError::NoSuchLoginPacket)) => (),
                     x => panic!("Unexpected Login outcome: {:?}", x),
                 }
-                AuthClient::registered_with_seed(&seed, client_id, el_h, core_tx, net_tx)
+                AuthClient::registered_with_seed(&seed, client_id, TEST_KDF, el_h, core_tx, net_tx)
             },
             |_| finish(),
         );
 
         setup_client(
             &(),
-            |el_h, core_tx, net_tx| AuthClient::login_with_seed(&seed, el_h, core_tx, net_tx),
+            |el_h, core_tx, net_tx| {
+                AuthClient::login_with_seed(&seed, TEST_KDF, el_h, core_tx, net_tx)
+            },
             |_| finish(),
         );
     }
@@ -714,7 +1490,7 @@ mod tests {
         setup_client(
             &(),
             |el_h, core_tx, net_tx| {
-                AuthClient::registered(&sec_0, &sec_1, client_id, el_h, core_tx, net_tx)
+                AuthClient::registered(&sec_0, &sec_1, client_id, TEST_KDF, el_h, core_tx, net_tx)
             },
             move |client| {
                 assert!(client.set_access_container(dir));
@@ -724,7 +1500,9 @@ mod tests {
 
         setup_client(
             &(),
-            |el_h, core_tx, net_tx| AuthClient::login(&sec_0, &sec_1, el_h, core_tx, net_tx),
+            |el_h, core_tx, net_tx| {
+                AuthClient::login(&sec_0, &sec_1, TEST_KDF, el_h, core_tx, net_tx)
+            },
             move |client| {
                 let got_dir = client.access_container();
                 assert_eq!(got_dir, dir_clone);
@@ -751,7 +1529,7 @@ mod tests {
         setup_client(
             &(),
             |el_h, core_tx, net_tx| {
-                AuthClient::registered(&sec_0, &sec_1, client_id, el_h, core_tx, net_tx)
+                AuthClient::registered(&sec_0, &sec_1, client_id, TEST_KDF, el_h, core_tx, net_tx)
             },
             move |client| {
                 assert!(client.set_config_root_dir(dir));
@@ -761,7 +1539,9 @@ mod tests {
 
         setup_client(
             &(),
-            |el_h, core_tx, net_tx| AuthClient::login(&sec_0, &sec_1, el_h, core_tx, net_tx),
+            |el_h, core_tx, net_tx| {
+                AuthClient::login(&sec_0, &sec_1, TEST_KDF, el_h, core_tx, net_tx)
+            },
             move |client| {
                 let got_dir = client.config_root_dir();
                 assert_eq!(got_dir, dir_clone);
@@ -865,7 +1645,7 @@ mod tests {
         let maid_keys = ClientKeys::new(&mut thread_rng());
         let acc = unwrap!(Account::new(maid_keys.clone()));
 
-        let acc_ciphertext = unwrap!(acc.encrypt(&password, &pin));
+        let acc_ciphertext = unwrap!(acc.encrypt_with_kdf(&password, &pin, TEST_KDF));
 
         let client_full_id = create_client_id(&acc_loc.0);
 
@@ -949,7 +1729,9 @@ mod tests {
 
         setup_client(
             &(),
-            |el_h, core_tx, net_tx| AuthClient::login(&sec_0, &sec_1, el_h, core_tx, net_tx),
+            |el_h, core_tx, net_tx| {
+                AuthClient::login(&sec_0, &sec_1, TEST_KDF, el_h, core_tx, net_tx)
+            },
             move |client| {
                 client.get_balance(None).and_then(move |balance| {
                     assert_eq!(balance, five_coins);
@@ -12,17 +12,22 @@
 use super::{AuthError, AuthFuture};
 use crate::client::AuthClient;
 use bincode::{deserialize, serialize};
+use chrono::{DateTime, Utc};
 use futures::future::{self, Either, Loop};
 use futures::Future;
 use log::trace;
 use safe_core::core_structs::AppKeys;
+use safe_core::crypto::shared_secretbox;
 use safe_core::fry;
-use safe_core::ipc::req::AppExchangeInfo;
+use safe_core::ipc::req::{AppExchangeInfo, ContainerPermissions, ShareAData, ShareMData};
 use safe_core::ipc::IpcError;
-use safe_core::{Client, CoreError, FutureExt};
-use safe_nd::{EntryError, Error as SndError, MDataSeqEntryActions};
+use safe_core::{Client, ClientKeys, CoreError, FutureExt, MDataInfo};
+use safe_nd::{
+    ADataAddress, AppPermissions, Coins, EntryError, Error as SndError, MDataSeqEntryActions,
+};
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use std::collections::{HashMap, VecDeque};
+use threshold_crypto::PublicKey;
 use tiny_keccak::sha3_256;
 
 /// App data stored in the authenticator configuration.
@@ -40,18 +45,170 @@ pub struct AppInfo {
     pub keys: AppKeys,
 }
 
+/// Period over which an app's coin allowance applies.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub enum AllowancePeriod {
+    /// The limit applies to everything the app spends once the allowance has been granted.
+    Total,
+    /// The limit applies to what the app spends within a day. The app's allowance wallet is
+    /// topped back up to the limit once a day has passed.
+    Daily,
+}
+
+/// Coin allowance granted to an app, stored in the authenticator configuration.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct AppAllowance {
+    /// Maximum amount of coins the app may spend within the period.
+    pub limit: Coins,
+    /// Period over which the limit applies.
+    pub period: AllowancePeriod,
+    /// Time the current period started at, i.e. when the allowance was granted or, for `Daily`
+    /// allowances, last refilled.
+    pub period_start: DateTime<Utc>,
+    /// Amount of coins moved to the app's allowance wallet within the current period, net of
+    /// any moved back.
+    pub funded: Coins,
+    /// Whether the app's `transfer_coins` permission on the account's balance was withdrawn when
+    /// the allowance was granted, so that it's given back when the allowance is removed.
+    pub withdrew_transfer_coins: bool,
+}
+
+/// Entry of the audit log, recording a decision of the authenticator about an app.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct AuditEntry {
+    /// Time the decision was made.
+    pub time: DateTime<Utc>,
+    /// ID of the app the decision is about.
+    pub app_id: String,
+    /// The decision.
+    pub decision: AuditDecision,
+}
+
+/// Location of the audit log, stored in the authenticator configuration.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct AuditLogInfo {
+    /// Address of the append-only data the log entries are appended to.
+    pub address: ADataAddress,
+    /// Key the log entries are encrypted with.
+    pub enc_key: shared_secretbox::Key,
+}
+
+/// Decision of the authenticator recorded in the audit log.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum AuditDecision {
+    /// The app was authorised.
+    Authorised {
+        /// Permissions granted to the app.
+        app_permissions: AppPermissions,
+        /// Containers the app was given access to.
+        containers: HashMap<String, ContainerPermissions>,
+        /// Time the authorisation expires at, if any.
+        expires_at: Option<DateTime<Utc>>,
+    },
+    /// The app was given access to additional containers.
+    ContainersGranted {
+        /// Containers the app was given access to.
+        containers: HashMap<String, ContainerPermissions>,
+    },
+    /// The permissions of the app were modified.
+    PermissionsModified {
+        /// New permissions of the app, if they were changed.
+        app_permissions: Option<AppPermissions>,
+        /// New permissions for the containers. Empty sets drop the access to the container.
+        containers: HashMap<String, ContainerPermissions>,
+    },
+    /// The app was given access to Mutable Data.
+    MDataShared {
+        /// The shared Mutable Data and the permissions granted on it.
+        mdata: Vec<ShareMData>,
+    },
+    /// The app was given access to Append-only Data.
+    ADataShared {
+        /// The shared Append-only Data and the permissions granted on it.
+        adata: Vec<ShareAData>,
+    },
+    /// The access of the app was revoked.
+    Revoked,
+}
+
+/// Device registered to use the account.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct DeviceInfo {
+    /// Name of the device, as given by the user.
+    pub name: String,
+    /// Public key of the device.
+    pub public_key: PublicKey,
+    /// Time the device was registered at.
+    pub registered_at: DateTime<Utc>,
+    /// Time the device last logged in at.
+    pub last_login: DateTime<Utc>,
+}
+
+/// Progress of a client key rotation, kept until the rotation completes so that it can be
+/// resumed after an interruption.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct KeyRotation {
+    /// Keys the account used before the rotation.
+    pub old_keys: ClientKeys,
+    /// Keys the account uses after the rotation.
+    pub new_keys: ClientKeys,
+    /// Config root re-encrypted with fresh keys.
+    pub config_root: MDataInfo,
+    /// Whether the ownership of the data and the coin balance have started moving to the new
+    /// keys. The entries encrypted with the old keys aren't copied again once this is set.
+    pub moving_balance: bool,
+}
+
 /// Config file key under which the list of registered apps is stored.
 pub const KEY_APPS: &[u8] = b"apps";
 
+/// Config file key under which the expiry times of app authorisations are stored.
+///
+/// They are kept apart from the registered apps so that older authenticators can still read those.
+pub const KEY_APP_EXPIRIES: &[u8] = b"app-expiries";
+
 /// Config file key under which the revocation queue is stored.
 pub const KEY_APP_REVOCATION_QUEUE: &[u8] = b"revocation-queue";
 
+/// Config file key under which the app coin allowances are stored.
+pub const KEY_APP_ALLOWANCES: &[u8] = b"app-allowances";
+
+/// Config file key under which the location of the audit log is stored.
+pub const KEY_AUDIT_LOG: &[u8] = b"audit-log";
+
+/// Config file key under which the public key derived from the account recovery phrase is stored.
+pub const KEY_RECOVERY_KEY: &[u8] = b"recovery-key";
+
+/// Config file key under which the progress of a client key rotation is stored.
+pub const KEY_KEY_ROTATION: &[u8] = b"key-rotation";
+
+/// Config file key under which the registered devices are stored.
+pub const KEY_DEVICES: &[u8] = b"devices";
+
+/// Config file key under which the append-only data owned by the account is stored.
+///
+/// Append-only data isn't referenced from the containers, so it's tracked here to be found when
+/// the account is deleted. The audit log is stored under `KEY_AUDIT_LOG` instead.
+pub const KEY_OWNED_ADATA: &[u8] = b"owned-adata";
+
 /// Maps from a SHA-3 hash of an app ID to app info.
 pub type Apps = HashMap<[u8; 32], AppInfo>;
+/// Maps from a SHA-3 hash of an app ID to the time the authorisation of the app expires at.
+/// Apps without an entry never expire.
+pub type AppExpiries = HashMap<[u8; 32], DateTime<Utc>>;
 /// Contains a queue of revocations that are currently running or have failed.
 /// String refers to `app_id`.
 pub type RevocationQueue = VecDeque<String>;
 
+/// Maps from an app ID to the coin allowance granted to that app.
+pub type Allowances = HashMap<String, AppAllowance>;
+
+/// Maps from a SHA-3 hash of a device public key to device info.
+pub type Devices = HashMap<[u8; 32], DeviceInfo>;
+
+/// Addresses of the append-only data owned by the account, other than the audit log.
+pub type OwnedAData = Vec<ADataAddress>;
+
 /// Bump the current version to obtain new version.
 pub fn next_version(version: Option<u64>) -> u64 {
     version.map_or(0, |v| v + 1)
@@ -103,6 +260,32 @@ pub fn remove_app(
     })
 }
 
+/// Retrieves the expiry times of app authorisations.
+pub fn list_app_expiries(client: &AuthClient) -> Box<AuthFuture<(Option<u64>, AppExpiries)>> {
+    get_entry(client, KEY_APP_EXPIRIES)
+}
+
+/// Set the time the authorisation of the given app expires at. `None` means it never expires.
+pub fn set_app_expiry(
+    client: &AuthClient,
+    expiries: AppExpiries,
+    new_version: u64,
+    app_id: &str,
+    expires_at: Option<DateTime<Utc>>,
+) -> Box<AuthFuture<(u64, AppExpiries)>> {
+    let hash = sha3_256(app_id.as_bytes());
+    mutate_entry(
+        client,
+        KEY_APP_EXPIRIES,
+        expiries,
+        new_version,
+        move |expiries| match expires_at {
+            Some(expires_at) => expiries.insert(hash, expires_at) != Some(expires_at),
+            None => expiries.remove(&hash).is_some(),
+        },
+    )
+}
+
 /// Get authenticator's revocation queue.
 /// Returns version and the revocation queue in a tuple.
 /// If the queue is not found on the config file, returns `None`.
@@ -196,6 +379,207 @@ pub fn repush_to_app_revocation_queue(
     )
 }
 
+/// Retrieves the coin allowances granted to apps.
+pub fn list_allowances(client: &AuthClient) -> Box<AuthFuture<(Option<u64>, Allowances)>> {
+    get_entry(client, KEY_APP_ALLOWANCES)
+}
+
+/// Set the coin allowance of the given app, or remove it if `allowance` is `None`.
+pub fn set_allowance(
+    client: &AuthClient,
+    allowances: Allowances,
+    new_version: u64,
+    app_id: &str,
+    allowance: Option<AppAllowance>,
+) -> Box<AuthFuture<(u64, Allowances)>> {
+    trace!("Setting coin allowance of app with ID {}...", app_id);
+
+    let app_id = app_id.to_string();
+    mutate_entry(
+        client,
+        KEY_APP_ALLOWANCES,
+        allowances,
+        new_version,
+        move |allowances| match allowance {
+            Some(ref allowance) => {
+                let previous = allowances.insert(app_id.clone(), allowance.clone());
+                previous.as_ref() != Some(allowance)
+            }
+            None => allowances.remove(&app_id).is_some(),
+        },
+    )
+}
+
+/// Retrieves the location of the audit log. Returns `None` if nothing was recorded yet.
+pub fn get_audit_log(client: &AuthClient) -> Box<AuthFuture<(Option<u64>, Option<AuditLogInfo>)>> {
+    get_entry(client, KEY_AUDIT_LOG)
+}
+
+/// Set the location of the audit log, unless one was set already.
+/// Returns the location in use.
+pub fn set_audit_log(
+    client: &AuthClient,
+    log: Option<AuditLogInfo>,
+    new_version: u64,
+    new_log: AuditLogInfo,
+) -> Box<AuthFuture<(u64, Option<AuditLogInfo>)>> {
+    mutate_entry(client, KEY_AUDIT_LOG, log, new_version, move |log| {
+        if log.is_some() {
+            false
+        } else {
+            *log = Some(new_log.clone());
+            true
+        }
+    })
+}
+
+/// Retrieves the public key derived from the account recovery phrase, if one was set up.
+pub fn get_recovery_key(client: &AuthClient) -> Box<AuthFuture<(Option<u64>, Option<PublicKey>)>> {
+    get_entry(client, KEY_RECOVERY_KEY)
+}
+
+/// Set the public key derived from the account recovery phrase, replacing the previous one.
+pub fn set_recovery_key(
+    client: &AuthClient,
+    recovery_key: Option<PublicKey>,
+    new_version: u64,
+    new_recovery_key: PublicKey,
+) -> Box<AuthFuture<(u64, Option<PublicKey>)>> {
+    trace!("Setting the account recovery key...");
+
+    mutate_entry(
+        client,
+        KEY_RECOVERY_KEY,
+        recovery_key,
+        new_version,
+        move |recovery_key| recovery_key.replace(new_recovery_key) != Some(new_recovery_key),
+    )
+}
+
+/// Returns the key under which the device with the given public key is registered.
+pub fn device_id(public_key: &PublicKey) -> [u8; 32] {
+    sha3_256(&public_key.to_bytes())
+}
+
+/// Retrieves the devices registered to use the account.
+pub fn list_devices(client: &AuthClient) -> Box<AuthFuture<(Option<u64>, Devices)>> {
+    get_entry(client, KEY_DEVICES)
+}
+
+/// Register the given device, or update its info if it's registered already.
+pub fn put_device(
+    client: &AuthClient,
+    devices: Devices,
+    new_version: u64,
+    device: DeviceInfo,
+) -> Box<AuthFuture<(u64, Devices)>> {
+    let hash = device_id(&device.public_key);
+    mutate_entry(client, KEY_DEVICES, devices, new_version, move |devices| {
+        devices.insert(hash, device.clone()).as_ref() != Some(&device)
+    })
+}
+
+/// Set the time the device with the given ID last logged in at.
+/// Does nothing if the device is not registered.
+pub fn set_device_last_login(
+    client: &AuthClient,
+    devices: Devices,
+    new_version: u64,
+    device_id: [u8; 32],
+    last_login: DateTime<Utc>,
+) -> Box<AuthFuture<(u64, Devices)>> {
+    mutate_entry(
+        client,
+        KEY_DEVICES,
+        devices,
+        new_version,
+        move |devices| match devices.get_mut(&device_id) {
+            Some(ref mut device) => {
+                device.last_login = last_login;
+                true
+            }
+            None => false,
+        },
+    )
+}
+
+/// Remove the device with the given ID from the registered devices.
+pub fn remove_device(
+    client: &AuthClient,
+    devices: Devices,
+    new_version: u64,
+    device_id: [u8; 32],
+) -> Box<AuthFuture<(u64, Devices)>> {
+    mutate_entry(client, KEY_DEVICES, devices, new_version, move |devices| {
+        devices.remove(&device_id).is_some()
+    })
+}
+
+/// Retrieves the append-only data owned by the account, other than the audit log.
+pub fn list_owned_adata(client: &AuthClient) -> Box<AuthFuture<(Option<u64>, OwnedAData)>> {
+    get_entry(client, KEY_OWNED_ADATA)
+}
+
+/// Add the given append-only data to the data owned by the account.
+/// Does nothing if it's tracked already.
+pub fn add_owned_adata(
+    client: &AuthClient,
+    adata: OwnedAData,
+    new_version: u64,
+    address: ADataAddress,
+) -> Box<AuthFuture<(u64, OwnedAData)>> {
+    mutate_entry(client, KEY_OWNED_ADATA, adata, new_version, move |adata| {
+        if adata.contains(&address) {
+            false
+        } else {
+            adata.push(address);
+            true
+        }
+    })
+}
+
+/// Remove the given append-only data from the data owned by the account.
+pub fn remove_owned_adata(
+    client: &AuthClient,
+    adata: OwnedAData,
+    new_version: u64,
+    address: ADataAddress,
+) -> Box<AuthFuture<(u64, OwnedAData)>> {
+    mutate_entry(client, KEY_OWNED_ADATA, adata, new_version, move |adata| {
+        let len = adata.len();
+        adata.retain(|tracked| *tracked != address);
+        adata.len() != len
+    })
+}
+
+/// Retrieves the progress of an unfinished client key rotation, if any.
+pub fn get_key_rotation(
+    client: &AuthClient,
+) -> Box<AuthFuture<(Option<u64>, Option<KeyRotation>)>> {
+    get_entry(client, KEY_KEY_ROTATION)
+}
+
+/// Set the progress of a client key rotation. Passing `None` marks the rotation as finished.
+pub fn set_key_rotation(
+    client: &AuthClient,
+    rotation: Option<KeyRotation>,
+    new_version: u64,
+    new_rotation: Option<KeyRotation>,
+) -> Box<AuthFuture<(u64, Option<KeyRotation>)>> {
+    trace!("Updating the client key rotation progress...");
+
+    mutate_entry(
+        client,
+        KEY_KEY_ROTATION,
+        rotation,
+        new_version,
+        move |rotation| {
+            *rotation = new_rotation.clone();
+            true
+        },
+    )
+}
+
 fn get_entry<T>(client: &AuthClient, key: &[u8]) -> Box<AuthFuture<(Option<u64>, T)>>
 where
     T: Default + DeserializeOwned + Serialize + 'static,
@@ -269,6 +653,11 @@ where
 }
 
 /// Atomically mutate the given value and store it in the network.
+///
+/// Several authenticators logged into the same account can mutate the same entry concurrently,
+/// so `item` may be outdated. If the entry was changed in the meantime, `f` is applied again to
+/// the current value. If `f` leaves an outdated `item` unchanged, it's applied to the current
+/// value too before concluding there is nothing to store.
 fn mutate_entry<T, F>(
     client: &AuthClient,
     key: &[u8],
@@ -284,8 +673,8 @@ where
     let key = key.to_vec();
 
     future::loop_fn(
-        (key, new_version, item),
-        move |(key, new_version, mut item)| {
+        (key, new_version, item, false),
+        move |(key, new_version, mut item, fresh)| {
             let c2 = client.clone();
             let c3 = client.clone();
 
@@ -297,15 +686,23 @@ where
                             _,
                         ))) => {
                             let f = get_entry(&c3, &key).map(move |(version, item)| {
-                                Loop::Continue((key, next_version(version), item))
+                                Loop::Continue((key, next_version(version), item, true))
                             });
                             Either::A(f)
                         }
                         _ => Either::B(future::err(error)),
                     });
-                Either::A(f)
+                Either::A(Either::A(f))
+            } else if fresh {
+                Either::B(future::ok(Loop::Break((
+                    new_version.saturating_sub(1),
+                    item,
+                ))))
             } else {
-                Either::B(future::ok(Loop::Break((new_version - 1, item))))
+                let f = get_entry(&c3, &key).map(move |(version, item)| {
+                    Loop::Continue((key, next_version(version), item, true))
+                });
+                Either::A(Either::B(f))
             }
         },
     )
@@ -0,0 +1,513 @@
+// Copyright 2019 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+//! Account deletion.
+//!
+//! Deleting an account removes everything reachable from the access container and the config
+//! root, in this order:
+//!
+//! 1. The unpublished immutable data holding the files stored in the containers.
+//! 2. The default containers and the app containers.
+//! 3. The append-only data owned by the account, including the audit log.
+//! 4. The auth keys of the apps.
+//! 5. The recovery packet, if account recovery has been set up.
+//! 6. The config root and the access container.
+//! 7. The coin balance, which is transferred to a destination of the user's choice.
+//! 8. The account packet.
+//!
+//! Data that is gone already is skipped, so an interrupted deletion can be resumed by logging in
+//! and deleting the account again. A dry run reports what would be deleted without changing
+//! anything.
+//!
+//! Append-only data isn't referenced from the containers, so only the audit log and the data
+//! tracked in the config root, i.e. data shared with apps or adopted from them, is found.
+//!
+//! Some data can't be deleted and is kept: published data, files written by apps and data owned
+//! by the keys the account used before its client keys were rotated. The network doesn't support
+//! deleting login packets or coin balances yet, so the login packets are overwritten with empty
+//! ones and the balance is left empty.
+
+use super::{AuthError, AuthFuture};
+use crate::access_container;
+use crate::client::AuthClient;
+use crate::config;
+use crate::ffi::deletion::DeletionReport as FfiDeletionReport;
+use crate::recovery;
+use bincode::deserialize;
+use futures::stream::{self, Stream};
+use futures::Future;
+use log::trace;
+use safe_core::crypto::shared_secretbox;
+use safe_core::nfs::{file_helper, File, NfsError};
+use safe_core::{fry, ok, AuthActions, Client, CoreError, FutureExt, MDataInfo};
+use safe_nd::{
+    ADataAddress, ADataIndex, Coins, Error as SndError, IData, MDataAddress, PublicKey, XorName,
+};
+
+// Maximum number of requests sent to the network at once.
+const MAX_CONCURRENT_REQUESTS: usize = 16;
+
+// Append-only data along with whether the account owns it, or `None` if it's gone already.
+type ScannedAData = Option<(ADataAddress, bool)>;
+
+/// What deleting the account removes, or would remove in case of a dry run.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DeletionReport {
+    /// Containers, including the access container and the config root.
+    pub mdata: Vec<MDataAddress>,
+    /// Unpublished immutable data holding the contents of files.
+    pub idata: Vec<XorName>,
+    /// Containers that are kept, as they're owned by other keys.
+    pub kept_mdata: Vec<MDataAddress>,
+    /// Append-only data, including the audit log.
+    pub adata: Vec<ADataAddress>,
+    /// Append-only data that is kept, as it's published or owned by other keys.
+    pub kept_adata: Vec<ADataAddress>,
+    /// Number of files that are kept, as they're published or owned by other keys.
+    pub kept_files: usize,
+    /// Auth keys of the apps.
+    pub auth_keys: Vec<PublicKey>,
+    /// Coins transferred to the destination.
+    pub balance: Coins,
+    /// Locations of the login packets that are wiped.
+    pub login_packets: Vec<XorName>,
+}
+
+impl DeletionReport {
+    /// Construct FFI wrapper for the native Rust object, consuming self.
+    pub fn into_repr_c(self) -> FfiDeletionReport {
+        FfiDeletionReport {
+            mdata_count: self.mdata.len(),
+            idata_count: self.idata.len(),
+            kept_mdata_count: self.kept_mdata.len(),
+            kept_files_count: self.kept_files,
+            auth_keys_count: self.auth_keys.len(),
+            balance: self.balance.as_nano(),
+            login_packets_count: self.login_packets.len(),
+            adata_count: self.adata.len(),
+            kept_adata_count: self.kept_adata.len(),
+        }
+    }
+}
+
+// Everything reachable from the account, along with what is needed to delete it.
+struct Survey {
+    report: DeletionReport,
+    // Containers other than the access container and the config root.
+    containers: Vec<MDataAddress>,
+    // The access container and the config root.
+    roots: Vec<MDataAddress>,
+    auth_keys_version: u64,
+    acc_loc: XorName,
+    recovery_loc: Option<XorName>,
+}
+
+// Result of scanning a container for files.
+struct ContainerScan {
+    address: MDataAddress,
+    owned: bool,
+    // Names of the immutable data of each file, or `None` for files that are kept.
+    files: Vec<Option<Vec<XorName>>>,
+}
+
+/// Deletes the account, transferring its coin balance to `destination`. `password` must match
+/// the password the account is currently encrypted with. If `dry_run` is set, nothing is changed
+/// and the report lists what would be deleted.
+pub fn delete_account(
+    client: &AuthClient,
+    password: &[u8],
+    destination: XorName,
+    dry_run: bool,
+) -> Box<AuthFuture<DeletionReport>> {
+    trace!("Deleting the account (dry run: {})...", dry_run);
+
+    fry!(client.check_password(password));
+
+    let client = client.clone();
+
+    survey(&client)
+        .and_then(move |survey| {
+            if dry_run {
+                ok!(survey.report)
+            } else {
+                delete(&client, survey, destination)
+            }
+        })
+        .into_box()
+}
+
+// Finds everything reachable from the account.
+fn survey(client: &AuthClient) -> Box<AuthFuture<Survey>> {
+    let c2 = client.clone();
+    let acc_loc = client.recovery_data().acc_loc;
+
+    let c3 = client.clone();
+    let roots = stream::iter_ok(vec![client.access_container(), client.config_root_dir()])
+        .map(move |info| scan_root(&c3, &info))
+        .buffered(MAX_CONCURRENT_REQUESTS)
+        .collect();
+    let recovery_pk = config::get_recovery_key(client)
+        .map(|(_, recovery_pk)| recovery_pk)
+        .then(|res| or_missing(res, None));
+    let auth_keys = client.list_auth_keys_and_version().map_err(AuthError::from);
+    let balance = client.get_balance(None).map_err(AuthError::from);
+
+    containers(client)
+        .and_then(move |containers| {
+            stream::iter_ok(containers)
+                .map(move |info| scan_container(&c2, info))
+                .buffered(MAX_CONCURRENT_REQUESTS)
+                .collect()
+        })
+        .join5(roots, recovery_pk, auth_keys, balance)
+        .join(adata(client))
+        .map(
+            move |((scans, roots, recovery_pk, (auth_keys, auth_keys_version), balance), adata)| {
+                let recovery_loc = recovery_pk.map(|pk| recovery::location(&pk));
+                let mut report = DeletionReport {
+                    mdata: Vec::new(),
+                    idata: Vec::new(),
+                    kept_mdata: Vec::new(),
+                    adata: Vec::new(),
+                    kept_adata: Vec::new(),
+                    kept_files: 0,
+                    auth_keys: auth_keys.into_iter().map(|(key, _)| key).collect(),
+                    balance,
+                    login_packets: Some(acc_loc).into_iter().chain(recovery_loc).collect(),
+                };
+
+                let mut containers = Vec::new();
+                for scan in scans.into_iter().flatten() {
+                    for file in scan.files {
+                        match file {
+                            Some(names) => {
+                                for name in names {
+                                    if !report.idata.contains(&name) {
+                                        report.idata.push(name);
+                                    }
+                                }
+                            }
+                            None => report.kept_files += 1,
+                        }
+                    }
+
+                    if scan.owned {
+                        containers.push(scan.address);
+                    } else {
+                        report.kept_mdata.push(scan.address);
+                    }
+                }
+
+                let mut owned_roots = Vec::new();
+                for (address, owned) in roots.into_iter().flatten() {
+                    if owned {
+                        owned_roots.push(address);
+                    } else {
+                        report.kept_mdata.push(address);
+                    }
+                }
+
+                report.mdata = containers.iter().chain(&owned_roots).cloned().collect();
+
+                for (address, owned) in adata.into_iter().flatten() {
+                    if owned {
+                        report.adata.push(address);
+                    } else {
+                        report.kept_adata.push(address);
+                    }
+                }
+
+                Survey {
+                    report,
+                    containers,
+                    roots: owned_roots,
+                    auth_keys_version,
+                    acc_loc,
+                    recovery_loc,
+                }
+            },
+        )
+        .into_box()
+}
+
+// Deletes everything found by `survey`.
+fn delete(
+    client: &AuthClient,
+    survey: Survey,
+    destination: XorName,
+) -> Box<AuthFuture<DeletionReport>> {
+    let Survey {
+        mut report,
+        containers,
+        roots,
+        auth_keys_version,
+        acc_loc,
+        recovery_loc,
+    } = survey;
+
+    let c2 = client.clone();
+    let c3 = client.clone();
+    let c4 = client.clone();
+    let c5 = client.clone();
+    let c6 = client.clone();
+    let c7 = client.clone();
+    let c8 = client.clone();
+    let c9 = client.clone();
+    let c10 = client.clone();
+    let auth_keys = report.auth_keys.clone();
+    let adata = report.adata.clone();
+
+    trace!("Deleting {} files...", report.idata.len());
+
+    stream::iter_ok(report.idata.clone())
+        .map(move |name| c9.del_unpub_idata(name).then(ignore_missing))
+        .buffer_unordered(MAX_CONCURRENT_REQUESTS)
+        .for_each(|()| Ok(()))
+        .and_then(move |()| {
+            trace!("Deleting {} containers...", containers.len());
+
+            stream::iter_ok(containers)
+                .map(move |address| c2.delete_mdata(address).then(ignore_missing))
+                .buffer_unordered(MAX_CONCURRENT_REQUESTS)
+                .for_each(|()| Ok(()))
+        })
+        .and_then(move |()| {
+            trace!("Deleting {} append-only data...", adata.len());
+
+            stream::iter_ok(adata)
+                .map(move |address| c10.delete_adata(address).then(ignore_missing))
+                .buffer_unordered(MAX_CONCURRENT_REQUESTS)
+                .for_each(|()| Ok(()))
+        })
+        .and_then(move |()| {
+            trace!("Deleting {} auth keys...", auth_keys.len());
+
+            stream::iter_ok::<_, CoreError>(auth_keys)
+                .fold(auth_keys_version, move |version, key| {
+                    c3.del_auth_key(key, version + 1).map(move |()| version + 1)
+                })
+        })
+        .map_err(AuthError::from)
+        .and_then(move |_| match recovery_loc {
+            Some(recovery_loc) => c4.wipe_login_packet(recovery_loc),
+            None => ok!(()),
+        })
+        .and_then(move |()| {
+            stream::iter_ok(roots)
+                .map(move |address| c5.delete_mdata(address).then(ignore_missing))
+                .buffer_unordered(MAX_CONCURRENT_REQUESTS)
+                .for_each(|()| Ok(()))
+                .map_err(AuthError::from)
+        })
+        .and_then(move |_| c6.get_balance(None).map_err(AuthError::from))
+        .and_then(move |balance| {
+            trace!("Transferring {} coins to {:?}...", balance, destination);
+
+            if balance.as_nano() == 0 {
+                ok!(balance)
+            } else {
+                c7.transfer_coins(None, destination, balance, None)
+                    .map(move |_| balance)
+                    .map_err(AuthError::from)
+                    .into_box()
+            }
+        })
+        .and_then(move |balance| {
+            report.balance = balance;
+            c8.wipe_login_packet(acc_loc).map(move |()| report)
+        })
+        .into_box()
+}
+
+// Returns the default containers and the app containers listed in the access container.
+fn containers(client: &AuthClient) -> Box<AuthFuture<Vec<MDataInfo>>> {
+    let c2 = client.clone();
+
+    access_container::fetch_authenticator_entry(client)
+        .map(|(_, entry)| entry.into_iter().map(|(_, info)| info).collect::<Vec<_>>())
+        .then(|res| or_missing(res, Vec::new()))
+        .join(
+            config::list_apps(client)
+                .map(|(_, apps)| apps)
+                .then(|res| or_missing(res, Default::default())),
+        )
+        .and_then(move |(containers, apps)| {
+            stream::iter_ok(apps)
+                .map(move |(_, app)| {
+                    access_container::fetch_entry(&c2, &app.info.id, app.keys)
+                        .map(|(_, entry)| entry.unwrap_or_default())
+                        .then(|res| or_missing(res, Default::default()))
+                })
+                .buffered(MAX_CONCURRENT_REQUESTS)
+                .collect()
+                .map(move |entries: Vec<_>| {
+                    let mut containers = containers;
+
+                    for (_, (info, _)) in entries.into_iter().flatten() {
+                        if !containers
+                            .iter()
+                            .any(|container| container.address() == info.address())
+                        {
+                            containers.push(info);
+                        }
+                    }
+
+                    // The entries come from hash maps, so they're sorted to keep the reports of
+                    // the dry run and of the deletion in the same order.
+                    containers.sort_by_key(|info| *info.address());
+                    containers
+                })
+        })
+        .into_box()
+}
+
+// Returns the audit log and the append-only data tracked in the config root, along with whether
+// the account owns them. Data that is gone already is `None`.
+fn adata(client: &AuthClient) -> Box<AuthFuture<Vec<ScannedAData>>> {
+    let c2 = client.clone();
+
+    config::get_audit_log(client)
+        .map(|(_, log)| log.map(|log| log.address))
+        .then(|res| or_missing(res, None))
+        .join(
+            config::list_owned_adata(client)
+                .map(|(_, adata)| adata)
+                .then(|res| or_missing(res, Vec::new())),
+        )
+        .and_then(move |(audit_log, adata)| {
+            let addresses: Vec<_> = audit_log
+                .into_iter()
+                .chain(
+                    adata
+                        .into_iter()
+                        .filter(|address| Some(*address) != audit_log),
+                )
+                .collect();
+
+            stream::iter_ok(addresses)
+                .map(move |address| scan_adata(&c2, address))
+                .buffered(MAX_CONCURRENT_REQUESTS)
+                .collect()
+        })
+        .into_box()
+}
+
+// Returns whether the account owns the append-only data and can delete it, i.e. it's
+// unpublished. Returns `None` if it's gone already.
+fn scan_adata(client: &AuthClient, address: ADataAddress) -> Box<AuthFuture<ScannedAData>> {
+    let owner = client.owner_key();
+
+    client
+        .get_adata_owners(address, ADataIndex::FromEnd(1))
+        .map(move |current| Some((address, !address.is_pub() && current.public_key == owner)))
+        .map_err(AuthError::from)
+        .then(|res| or_missing(res, None))
+        .into_box()
+}
+
+// Returns the address of the access container or the config root, and whether the account owns
+// it. Returns `None` if it's gone already.
+fn scan_root(
+    client: &AuthClient,
+    info: &MDataInfo,
+) -> Box<AuthFuture<Option<(MDataAddress, bool)>>> {
+    let address = *info.address();
+    let owner = client.owner_key();
+
+    client
+        .get_seq_mdata_shell(info.name(), info.type_tag())
+        .map(move |shell| Some((address, *shell.owner() == owner)))
+        .map_err(AuthError::from)
+        .then(|res| or_missing(res, None))
+        .into_box()
+}
+
+// Finds the files stored in the container. Returns `None` if the container is gone already.
+fn scan_container(client: &AuthClient, info: MDataInfo) -> Box<AuthFuture<Option<ContainerScan>>> {
+    let c2 = client.clone();
+    let owner = client.owner_key();
+
+    client
+        .get_seq_mdata_shell(info.name(), info.type_tag())
+        .join(client.list_seq_mdata_entries(info.name(), info.type_tag()))
+        .map_err(AuthError::from)
+        .and_then(move |(shell, entries)| {
+            // Entries that don't hold files are skipped, as are files deleted by NFS, which
+            // leaves empty values behind.
+            let files: Vec<File> = entries
+                .values()
+                .filter(|value| !value.data.is_empty())
+                .filter_map(|value| info.decrypt(&value.data).ok())
+                .filter_map(|plaintext| deserialize(&plaintext).ok())
+                .collect();
+            let encryption_key = info.enc_key().cloned();
+
+            stream::iter_ok(files)
+                .map(move |file| scan_file(&c2, file, encryption_key.clone()))
+                .buffered(MAX_CONCURRENT_REQUESTS)
+                .collect()
+                .map(move |files| {
+                    Some(ContainerScan {
+                        address: *info.address(),
+                        owned: *shell.owner() == owner,
+                        files,
+                    })
+                })
+        })
+        .then(|res| or_missing(res, None))
+        .into_box()
+}
+
+// Returns the names of the immutable data holding the file, or `None` if the file is kept.
+fn scan_file(
+    client: &AuthClient,
+    file: File,
+    encryption_key: Option<shared_secretbox::Key>,
+) -> Box<AuthFuture<Option<Vec<XorName>>>> {
+    if file.published() {
+        return ok!(None);
+    }
+
+    let c2 = client.clone();
+    let owner = client.owner_key();
+
+    client
+        .get_idata(file.data_address())
+        .map_err(AuthError::from)
+        .and_then(move |data| match data {
+            IData::Unpub(ref data) if *data.owner() == owner => {
+                file_helper::data_names(c2, &file, encryption_key)
+                    .map(Some)
+                    .map_err(AuthError::from)
+                    .into_box()
+            }
+            _ => ok!(None),
+        })
+        .then(|res| or_missing(res, Some(Vec::new())))
+        .into_box()
+}
+
+// Deleting data that is gone already succeeds, so an interrupted deletion can be resumed.
+fn ignore_missing(result: Result<(), CoreError>) -> Result<(), CoreError> {
+    match result {
+        Err(CoreError::DataError(SndError::NoSuchData)) => Ok(()),
+        result => result,
+    }
+}
+
+// Treats data that is gone already as empty, so an interrupted deletion can be resumed.
+fn or_missing<T>(result: Result<T, AuthError>, missing: T) -> Result<T, AuthError> {
+    match result {
+        Err(AuthError::CoreError(CoreError::DataError(SndError::NoSuchData)))
+        | Err(AuthError::CoreError(CoreError::DataError(SndError::NoSuchEntry)))
+        | Err(AuthError::NfsError(NfsError::CoreError(CoreError::DataError(
+            SndError::NoSuchData,
+        )))) => Ok(missing),
+        result => result,
+    }
+}
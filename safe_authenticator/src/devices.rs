@@ -0,0 +1,217 @@
+// Copyright 2019 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+//! Devices using the account.
+//!
+//! Every device an authenticator runs on can be registered with a name. Registering generates a
+//! device key which the device keeps; the matching public key is listed in the user's config
+//! root. A copy of the account, along with the location of its account packet and the secret
+//! derived from the password, is stored on the network encrypted to the public key, in a packet
+//! of its own. The device logs in by decrypting that copy with its device key and checking the
+//! password against it, and the copies are refreshed whenever the account packet is updated.
+//!
+//! Logging a device out wipes its copy and removes it from the list, so it can't log in with its
+//! device key anymore, then rotates the client keys so that the sessions it has open can't access
+//! the account anymore either. Like any other device, it can still log in with the locator and
+//! the password, so those should be changed too if the device was lost.
+
+use super::{config, rotation, AuthError, AuthFuture};
+use crate::client::AuthClient;
+use crate::config::DeviceInfo;
+use crate::ffi::devices::Device as FfiDevice;
+use bincode::{deserialize, serialize};
+use chrono::Utc;
+use futures::Future;
+use log::trace;
+use rand::rngs::StdRng;
+use rand::{thread_rng, Rng, SeedableRng};
+use safe_core::client::account::Account;
+use safe_core::crypto::SecretBytes;
+use safe_core::{ok, CoreError, FutureExt};
+use safe_nd::XorName;
+use serde::{Deserialize, Serialize};
+use std::ffi::CString;
+use threshold_crypto::{Ciphertext, PublicKey, SecretKey};
+use tiny_keccak::sha3_256;
+
+// Domain separator for the location of the device packets.
+const DEVICE_LOCATION_TAG: &[u8] = b"device-login";
+
+/// Secret a device presents to prove it's registered to use the account.
+pub type DeviceKey = [u8; 32];
+
+/// ID of a registered device.
+pub type DeviceId = [u8; 32];
+
+/// Data a registered device logs in with, stored encrypted to the device public key.
+#[derive(Deserialize, Serialize)]
+pub(crate) struct DeviceData {
+    /// Location of the account packet.
+    pub acc_loc: XorName,
+    /// Secret derived from the account password.
+    pub password: SecretBytes,
+    /// The account itself.
+    pub account: Account,
+}
+
+/// Device registered to use the account.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Device {
+    /// ID of the device.
+    pub id: DeviceId,
+    /// Information about the device.
+    pub info: DeviceInfo,
+}
+
+impl Device {
+    /// Construct FFI wrapper for the native Rust object, consuming self.
+    pub fn into_repr_c(self) -> Result<FfiDevice, AuthError> {
+        let Self { id, info } = self;
+
+        Ok(FfiDevice {
+            id,
+            name: CString::new(info.name)?.into_raw(),
+            registered_at_sec: info.registered_at.timestamp(),
+            last_login_sec: info.last_login.timestamp(),
+        })
+    }
+}
+
+/// Register a new device with the given name. Returns the device key, which the device has to
+/// store to be able to log in.
+pub fn register(client: &AuthClient, name: &str) -> Box<AuthFuture<DeviceKey>> {
+    trace!("Registering device {}...", name);
+
+    let c2 = client.clone();
+    let c3 = client.clone();
+    let device_key: DeviceKey = thread_rng().gen();
+    let now = Utc::now();
+    let device = DeviceInfo {
+        name: name.to_string(),
+        public_key: public_key(&device_key),
+        registered_at: now,
+        last_login: now,
+    };
+
+    client
+        .store_device_packet(&device.public_key)
+        .and_then(move |()| config::list_devices(&c2))
+        .and_then(move |(version, devices)| {
+            config::put_device(&c3, devices, config::next_version(version), device)
+        })
+        .map(move |_| device_key)
+        .into_box()
+}
+
+/// Record a login of the device with the given key. Fails with `AuthError::UnknownDevice` if the
+/// device isn't registered.
+pub fn log_in(client: &AuthClient, device_key: &DeviceKey) -> Box<AuthFuture<DeviceId>> {
+    let client = client.clone();
+    let device_id = device_id(device_key);
+
+    config::list_devices(&client)
+        .and_then(move |(version, devices)| {
+            config::set_device_last_login(
+                &client,
+                devices,
+                config::next_version(version),
+                device_id,
+                Utc::now(),
+            )
+        })
+        .and_then(move |(_, devices)| {
+            if devices.contains_key(&device_id) {
+                Ok(device_id)
+            } else {
+                Err(AuthError::UnknownDevice)
+            }
+        })
+        .into_box()
+}
+
+/// Returns the devices registered to use the account.
+pub fn list(client: &AuthClient) -> Box<AuthFuture<Vec<Device>>> {
+    config::list_devices(client)
+        .map(|(_, devices)| {
+            devices
+                .into_iter()
+                .map(|(id, info)| Device { id, info })
+                .collect()
+        })
+        .into_box()
+}
+
+/// Log out the device with the given ID and rotate the client keys, ending the sessions the
+/// device has open. Does nothing if the device isn't registered.
+///
+/// The copy of the account stored for the device is wiped before the device is removed from the
+/// list, so that retrying after a failure wipes it again.
+pub fn log_out(client: &AuthClient, device_id: DeviceId) -> Box<AuthFuture<()>> {
+    trace!("Logging out device {:?}...", device_id);
+
+    let client = client.clone();
+
+    config::list_devices(&client)
+        .and_then(move |(version, devices)| {
+            let device_pk = match devices.get(&device_id) {
+                Some(device) => device.public_key,
+                None => return ok!(()),
+            };
+            let c2 = client.clone();
+            let c3 = client.clone();
+
+            client
+                .wipe_login_packet(location(&device_pk))
+                .and_then(move |()| {
+                    config::remove_device(&c2, devices, config::next_version(version), device_id)
+                })
+                .and_then(move |_| rotation::rotate_client_keys(&c3))
+                .into_box()
+        })
+        .into_box()
+}
+
+/// Returns the ID of the device the given key belongs to.
+pub fn device_id(device_key: &DeviceKey) -> DeviceId {
+    config::device_id(&public_key(device_key))
+}
+
+/// Derives the secret key of the device from its device key.
+pub(crate) fn secret_key(device_key: &DeviceKey) -> SecretKey {
+    let mut rng = StdRng::from_seed(*device_key);
+    rng.gen()
+}
+
+/// Returns the location of the packet the device with the given public key logs in with.
+pub(crate) fn location(public_key: &PublicKey) -> XorName {
+    let mut input = DEVICE_LOCATION_TAG.to_vec();
+    input.extend_from_slice(&public_key.to_bytes());
+
+    XorName(sha3_256(&input))
+}
+
+/// Encrypts the device data to the given device public key.
+pub(crate) fn seal(public_key: &PublicKey, data: &DeviceData) -> Result<Vec<u8>, AuthError> {
+    let ciphertext = public_key.encrypt(serialize(data)?);
+    Ok(serialize(&ciphertext)?)
+}
+
+/// Decrypts device data using the device secret key.
+pub(crate) fn open(secret_key: &SecretKey, sealed: &[u8]) -> Result<DeviceData, AuthError> {
+    let ciphertext: Ciphertext =
+        deserialize(sealed).map_err(|_| CoreError::AsymmetricDecipherFailure)?;
+    let plaintext = secret_key
+        .decrypt(&ciphertext)
+        .ok_or(CoreError::AsymmetricDecipherFailure)?;
+
+    Ok(deserialize(&plaintext)?)
+}
+
+fn public_key(device_key: &DeviceKey) -> PublicKey {
+    secret_key(device_key).public_key()
+}
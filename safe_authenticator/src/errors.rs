@@ -48,6 +48,10 @@ pub enum AuthError {
     NoSuchContainer(String),
     /// Couldn't authenticate app that is pending revocation.
     PendingRevocation,
+    /// The device is not registered to use the account, or was logged out.
+    UnknownDevice,
+    /// The backup doesn't match the account on the network.
+    BackupMismatch,
 }
 
 impl Display for AuthError {
@@ -75,6 +79,13 @@ impl Display for AuthError {
                 formatter,
                 "Couldn't authenticate app that is pending revocation"
             ),
+            Self::UnknownDevice => {
+                write!(formatter, "The device is not registered to use the account")
+            }
+            Self::BackupMismatch => write!(
+                formatter,
+                "The backup doesn't match the account on the network"
+            ),
         }
     }
 }
@@ -10,6 +10,8 @@ use crate::apps::{
     apps_accessing_mutable_data, list_registered, list_revoked, remove_revoked_app,
     RegisteredApp as NativeRegisteredApp,
 };
+#[cfg(feature = "mock-network")]
+use crate::apps::{list_usage, AppUsageSummary as NativeAppUsageSummary};
 use crate::ffi::errors::{Error, Result};
 use crate::Authenticator;
 use ffi_utils::call_result_cb;
@@ -37,6 +39,9 @@ pub struct RegisteredApp {
     pub containers_len: usize,
     /// Permissions allowed for the application
     pub app_permissions: AppPermissions,
+    /// Time the authorisation of the application expires at, in seconds since the Unix epoch.
+    /// `0` if it never expires.
+    pub expires_at_sec: i64,
 }
 
 impl Drop for RegisteredApp {
@@ -50,6 +55,19 @@ impl Drop for RegisteredApp {
     }
 }
 
+/// Resources consumed by an application registered in the authenticator.
+#[repr(C)]
+pub struct AppUsageSummary {
+    /// Unique application identifier.
+    pub app_info: AppExchangeInfo,
+    /// Number of successful mutations performed by the application.
+    pub mutations: u64,
+    /// Total size of the mutation requests made by the application, in bytes.
+    pub bytes: u64,
+    /// Amount of coins spent on the application's mutations, in nano coins.
+    pub spent: u64,
+}
+
 /// Permission for Apps
 #[repr(C)]
 #[derive(Clone, Copy)]
@@ -165,6 +183,49 @@ pub unsafe extern "C" fn auth_registered_apps(
     })
 }
 
+/// Get the resources consumed by each app registered in the authenticator, including revoked
+/// apps.
+///
+/// Only available against the mock vault, as vaults don't attribute mutations to app keys yet.
+///
+/// Callback parameters: user data, error code, app usage vector, vector size
+#[cfg(feature = "mock-network")]
+#[no_mangle]
+pub unsafe extern "C" fn auth_apps_usage(
+    auth: *const Authenticator,
+    user_data: *mut c_void,
+    o_cb: extern "C" fn(
+        user_data: *mut c_void,
+        result: *const FfiResult,
+        app_usage: *const AppUsageSummary,
+        app_usage_len: usize,
+    ),
+) {
+    let user_data = OpaqueCtx(user_data);
+
+    catch_unwind_cb(user_data.0, o_cb, || -> Result<_> {
+        (*auth).send(move |client| {
+            list_usage(client)
+                .and_then(move |usage| {
+                    let usage: Vec<_> = usage
+                        .into_iter()
+                        .map(NativeAppUsageSummary::into_repr_c)
+                        .collect::<std::result::Result<_, _>>()?;
+                    o_cb(user_data.0, FFI_RESULT_OK, usage.as_safe_ptr(), usage.len());
+
+                    Ok(())
+                })
+                .map_err(move |e| {
+                    call_result_cb!(Err::<(), _>(Error::from(e)), user_data, o_cb);
+                })
+                .into_box()
+                .into()
+        })?;
+
+        Ok(())
+    })
+}
+
 /// Return a list of apps having access to an arbitrary MD object.
 /// `md_name` and `md_type_tag` together correspond to a single MD.
 #[no_mangle]
@@ -266,6 +327,8 @@ mod tests {
                 app_container: false,
                 app_permissions: Default::default(),
                 containers: HashMap::new(),
+                response_key: None,
+                ttl: None,
             },
         ));
 
@@ -326,6 +389,8 @@ mod tests {
                 app_container: true,
                 app_permissions: Default::default(),
                 containers: HashMap::new(),
+                response_key: None,
+                ttl: None,
             },
         ));
 
@@ -391,6 +456,8 @@ mod tests {
                 app_container: true,
                 app_permissions: Default::default(),
                 containers: HashMap::new(),
+                response_key: None,
+                ttl: None,
             },
         ));
 
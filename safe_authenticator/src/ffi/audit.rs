@@ -0,0 +1,116 @@
+// Copyright 2019 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+use crate::audit;
+use crate::config::AuditEntry as NativeAuditEntry;
+use crate::ffi::errors::{Error, Result};
+use crate::Authenticator;
+use ffi_utils::call_result_cb;
+use ffi_utils::{catch_unwind_cb, FfiResult, OpaqueCtx, SafePtr, FFI_RESULT_OK};
+use futures::Future;
+use safe_core::FutureExt;
+use std::ffi::CString;
+use std::os::raw::{c_char, c_void};
+
+/// Entry of the audit log, recording a decision of the authenticator about an app.
+#[repr(C)]
+pub struct AuditEntry {
+    /// Time the decision was made, in seconds since the Unix epoch.
+    pub timestamp_sec: i64,
+    /// Nanoseconds part of the time the decision was made.
+    pub timestamp_nsec: u32,
+    /// ID of the app the decision is about.
+    pub app_id: *const c_char,
+    /// The decision, encoded as JSON.
+    pub decision: *const c_char,
+}
+
+impl Drop for AuditEntry {
+    fn drop(&mut self) {
+        unsafe {
+            let _ = CString::from_raw(self.app_id as *mut _);
+            let _ = CString::from_raw(self.decision as *mut _);
+        }
+    }
+}
+
+/// Get the audit log of the decisions made by the authenticator, oldest first.
+///
+/// Callback parameters: user data, error code, audit entries vector, vector size
+#[no_mangle]
+pub unsafe extern "C" fn auth_audit_log(
+    auth: *const Authenticator,
+    user_data: *mut c_void,
+    o_cb: extern "C" fn(
+        user_data: *mut c_void,
+        result: *const FfiResult,
+        entries: *const AuditEntry,
+        entries_len: usize,
+    ),
+) {
+    let user_data = OpaqueCtx(user_data);
+
+    catch_unwind_cb(user_data.0, o_cb, || -> Result<_> {
+        (*auth).send(move |client| {
+            audit::list(client)
+                .and_then(move |log| {
+                    let entries: Vec<_> = log
+                        .into_iter()
+                        .map(NativeAuditEntry::into_repr_c)
+                        .collect::<std::result::Result<_, _>>()?;
+                    o_cb(
+                        user_data.0,
+                        FFI_RESULT_OK,
+                        entries.as_safe_ptr(),
+                        entries.len(),
+                    );
+
+                    Ok(())
+                })
+                .map_err(move |e| {
+                    call_result_cb!(Err::<(), _>(Error::from(e)), user_data, o_cb);
+                })
+                .into_box()
+                .into()
+        })?;
+
+        Ok(())
+    })
+}
+
+/// Export the audit log of the decisions made by the authenticator as a JSON array, oldest
+/// first.
+///
+/// Callback parameters: user data, error code, JSON string
+#[no_mangle]
+pub unsafe extern "C" fn auth_export_audit_log(
+    auth: *const Authenticator,
+    user_data: *mut c_void,
+    o_cb: extern "C" fn(user_data: *mut c_void, result: *const FfiResult, json: *const c_char),
+) {
+    let user_data = OpaqueCtx(user_data);
+
+    catch_unwind_cb(user_data.0, o_cb, || -> Result<_> {
+        (*auth).send(move |client| {
+            audit::export(client)
+                .and_then(move |json| {
+                    let json = CString::new(json)?;
+                    o_cb(user_data.0, FFI_RESULT_OK, json.as_ptr());
+
+                    Ok(())
+                })
+                .map_err(move |e| {
+                    call_result_cb!(Err::<(), _>(Error::from(e)), user_data, o_cb);
+                })
+                .into_box()
+                .into()
+        })?;
+
+        Ok(())
+    })
+}
@@ -0,0 +1,234 @@
+// Copyright 2019 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+use crate::allowances::{
+    list_app_allowances, remove_app_allowance, set_app_allowance, set_near_limit_observer,
+    AllowanceStatus as NativeAllowanceStatus,
+};
+use crate::config::AllowancePeriod;
+use crate::ffi::errors::{Error, Result};
+use crate::Authenticator;
+use ffi_utils::call_result_cb;
+use ffi_utils::{catch_unwind_cb, FfiResult, OpaqueCtx, ReprC, SafePtr, FFI_RESULT_OK};
+use futures::Future;
+use log::warn;
+use safe_core::core_structs::TransactionRecord as NativeTransactionRecord;
+use safe_core::ffi::coins::TransactionRecord;
+use safe_core::Client;
+use safe_core::FutureExt;
+use safe_nd::Coins;
+use std::ffi::CString;
+use std::os::raw::{c_char, c_void};
+
+/// Spending of an app measured against its coin allowance. Amounts are in nano coins.
+#[repr(C)]
+pub struct AppAllowanceStatus {
+    /// App ID.
+    pub app_id: *const c_char,
+    /// Amount of coins the app may spend.
+    pub limit: u64,
+    /// `true` if the limit applies per day, `false` if it applies in total.
+    pub daily: bool,
+    /// Amount of coins the app has spent within the current period.
+    pub spent: u64,
+    /// Amount of coins left in the app's allowance wallet.
+    pub remaining: u64,
+    /// `true` if the app has spent its whole allowance.
+    pub exhausted: bool,
+    /// `true` if the app is close to its limit and the user should be notified.
+    pub near_limit: bool,
+}
+
+impl Drop for AppAllowanceStatus {
+    fn drop(&mut self) {
+        unsafe {
+            let _ = CString::from_raw(self.app_id as *mut _);
+        }
+    }
+}
+
+/// Grant a coin allowance of `limit` nano coins to a registered app, or change the limit of its
+/// existing allowance. If `daily` is `true` the limit applies to each day, otherwise it applies
+/// to everything the app spends from now on. The allowance is moved from the account's balance
+/// to the app's allowance wallet, which is the only balance the app can spend from afterwards.
+#[no_mangle]
+pub unsafe extern "C" fn auth_set_app_allowance(
+    auth: *const Authenticator,
+    app_id: *const c_char,
+    limit: u64,
+    daily: bool,
+    user_data: *mut c_void,
+    o_cb: extern "C" fn(user_data: *mut c_void, result: *const FfiResult),
+) {
+    let user_data = OpaqueCtx(user_data);
+
+    catch_unwind_cb(user_data.0, o_cb, || -> Result<_> {
+        let app_id = String::clone_from_repr_c(app_id)?;
+        let limit = Coins::from_nano(limit)?;
+        let period = if daily {
+            AllowancePeriod::Daily
+        } else {
+            AllowancePeriod::Total
+        };
+
+        (*auth).send(move |client| {
+            set_app_allowance(client, &app_id, limit, period)
+                .then(move |res| {
+                    call_result_cb!(res.map_err(Error::from), user_data, o_cb);
+                    Ok(())
+                })
+                .into_box()
+                .into()
+        })
+    })
+}
+
+/// Remove the coin allowance of an app. Coins left in its allowance wallet are moved back to the
+/// account's balance.
+#[no_mangle]
+pub unsafe extern "C" fn auth_rm_app_allowance(
+    auth: *const Authenticator,
+    app_id: *const c_char,
+    user_data: *mut c_void,
+    o_cb: extern "C" fn(user_data: *mut c_void, result: *const FfiResult),
+) {
+    let user_data = OpaqueCtx(user_data);
+
+    catch_unwind_cb(user_data.0, o_cb, || -> Result<_> {
+        let app_id = String::clone_from_repr_c(app_id)?;
+
+        (*auth).send(move |client| {
+            remove_app_allowance(client, &app_id)
+                .then(move |res| {
+                    call_result_cb!(res.map_err(Error::from), user_data, o_cb);
+                    Ok(())
+                })
+                .into_box()
+                .into()
+        })
+    })
+}
+
+/// Get the spending of every app with a coin allowance, refilling any daily allowances that are
+/// due first. Apps that are `near_limit` should be brought to the user's attention.
+///
+/// Callback parameters: user data, error code, allowance statuses vector, vector size
+#[no_mangle]
+pub unsafe extern "C" fn auth_app_allowances(
+    auth: *const Authenticator,
+    user_data: *mut c_void,
+    o_cb: extern "C" fn(
+        user_data: *mut c_void,
+        result: *const FfiResult,
+        statuses: *const AppAllowanceStatus,
+        statuses_len: usize,
+    ),
+) {
+    let user_data = OpaqueCtx(user_data);
+
+    catch_unwind_cb(user_data.0, o_cb, || -> Result<_> {
+        (*auth).send(move |client| {
+            list_app_allowances(client)
+                .and_then(move |statuses| {
+                    let statuses: Vec<_> = statuses
+                        .into_iter()
+                        .map(NativeAllowanceStatus::into_repr_c)
+                        .collect::<std::result::Result<_, _>>()?;
+                    o_cb(
+                        user_data.0,
+                        FFI_RESULT_OK,
+                        statuses.as_safe_ptr(),
+                        statuses.len(),
+                    );
+
+                    Ok(())
+                })
+                .map_err(move |e| {
+                    call_result_cb!(Err::<(), _>(Error::from(e)), user_data, o_cb);
+                })
+                .into_box()
+                .into()
+        })?;
+
+        Ok(())
+    })
+}
+
+/// Register `o_near_limit` to be called with the allowance status of each app that comes near the
+/// limit of its coin allowance, replacing any function registered before. The apps already near
+/// their limit are reported right away. Apps spend without going through the authenticator, so
+/// their spending is noticed on login, whenever `auth_app_allowances` is called and after an
+/// allowance is changed.
+///
+/// Callback parameters: user data, error code
+#[no_mangle]
+pub unsafe extern "C" fn auth_set_near_limit_observer(
+    auth: *const Authenticator,
+    user_data: *mut c_void,
+    o_near_limit: extern "C" fn(user_data: *mut c_void, status: *const AppAllowanceStatus),
+    o_cb: extern "C" fn(user_data: *mut c_void, result: *const FfiResult),
+) {
+    let user_data = OpaqueCtx(user_data);
+
+    catch_unwind_cb(user_data.0, o_cb, || -> Result<_> {
+        (*auth).send(move |client| {
+            set_near_limit_observer(client, move |status| match status.clone().into_repr_c() {
+                Ok(status) => o_near_limit(user_data.0, &status),
+                Err(e) => warn!("Failed to report app {}: {:?}", status.app_id, e),
+            })
+            .then(move |res| {
+                call_result_cb!(res.map_err(Error::from), user_data, o_cb);
+                Ok(())
+            })
+            .into_box()
+            .into()
+        })
+    })
+}
+
+/// Get the transaction history of the account's coin balance, oldest first.
+///
+/// Callback parameters: user data, error code, transaction records vector, vector size
+#[no_mangle]
+pub unsafe extern "C" fn auth_transaction_history(
+    auth: *const Authenticator,
+    user_data: *mut c_void,
+    o_cb: extern "C" fn(
+        user_data: *mut c_void,
+        result: *const FfiResult,
+        transactions: *const TransactionRecord,
+        transactions_len: usize,
+    ),
+) {
+    let user_data = OpaqueCtx(user_data);
+    catch_unwind_cb(user_data.0, o_cb, || -> Result<_> {
+        (*auth).send(move |client| {
+            client
+                .get_transaction_history(None)
+                .map(move |history| {
+                    let history: Vec<_> = history
+                        .into_iter()
+                        .map(NativeTransactionRecord::into_repr_c)
+                        .collect();
+                    o_cb(
+                        user_data.0,
+                        FFI_RESULT_OK,
+                        history.as_safe_ptr(),
+                        history.len(),
+                    );
+                })
+                .map_err(move |e| {
+                    call_result_cb!(Err::<(), _>(Error::from(e)), user_data, o_cb);
+                })
+                .into_box()
+                .into()
+        })?;
+
+        Ok(())
+    })
+}
@@ -0,0 +1,82 @@
+// Copyright 2019 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+use crate::deletion;
+use crate::ffi::errors::{Error, Result};
+use crate::Authenticator;
+use ffi_utils::call_result_cb;
+use ffi_utils::{catch_unwind_cb, FfiResult, OpaqueCtx, ReprC, FFI_RESULT_OK};
+use futures::Future;
+use safe_core::ffi::arrays::XorNameArray;
+use safe_core::FutureExt;
+use safe_nd::XorName;
+use std::os::raw::{c_char, c_void};
+
+/// Summary of what deleting the account removes, or would remove in case of a dry run.
+#[repr(C)]
+pub struct DeletionReport {
+    /// Number of containers, including the access container and the config root.
+    pub mdata_count: usize,
+    /// Number of unpublished immutable data chunks holding the contents of files.
+    pub idata_count: usize,
+    /// Number of containers that are kept, as they're owned by other keys.
+    pub kept_mdata_count: usize,
+    /// Number of files that are kept, as they're published or owned by other keys.
+    pub kept_files_count: usize,
+    /// Number of auth keys of apps.
+    pub auth_keys_count: usize,
+    /// Nano coins transferred to the destination.
+    pub balance: u64,
+    /// Number of login packets that are wiped.
+    pub login_packets_count: usize,
+    /// Number of append-only data, including the audit log.
+    pub adata_count: usize,
+    /// Number of append-only data that is kept, as it's published or owned by other keys.
+    pub kept_adata_count: usize,
+}
+
+/// Delete the logged in account, transferring its coin balance to the balance at `destination`.
+/// `password` must match the password the account is currently encrypted with. If `dry_run` is
+/// `true`, nothing is changed and the report lists what would be deleted. The account can't be
+/// logged into anymore once the deletion succeeded.
+///
+/// Callback parameters: user data, error code, deletion report
+#[no_mangle]
+pub unsafe extern "C" fn auth_delete_account(
+    auth: *const Authenticator,
+    password: *const c_char,
+    destination: *const XorNameArray,
+    dry_run: bool,
+    user_data: *mut c_void,
+    o_cb: extern "C" fn(
+        user_data: *mut c_void,
+        result: *const FfiResult,
+        report: *const DeletionReport,
+    ),
+) {
+    let user_data = OpaqueCtx(user_data);
+    let destination = XorName(*destination);
+
+    catch_unwind_cb(user_data.0, o_cb, || -> Result<_> {
+        let password = String::clone_from_repr_c(password)?;
+
+        (*auth).send(move |client| {
+            deletion::delete_account(client, password.as_bytes(), destination, dry_run)
+                .map(move |report| {
+                    o_cb(user_data.0, FFI_RESULT_OK, &report.into_repr_c());
+                })
+                .map_err(move |e| {
+                    call_result_cb!(Err::<(), _>(Error::from(e)), user_data, o_cb);
+                })
+                .into_box()
+                .into()
+        })?;
+
+        Ok(())
+    })
+}
@@ -0,0 +1,144 @@
+// Copyright 2019 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+use crate::devices::{self, Device as NativeDevice, DeviceId, DeviceKey};
+use crate::ffi::errors::{Error, Result};
+use crate::Authenticator;
+use ffi_utils::call_result_cb;
+use ffi_utils::{catch_unwind_cb, FfiResult, OpaqueCtx, ReprC, SafePtr, FFI_RESULT_OK};
+use futures::Future;
+use safe_core::FutureExt;
+use std::ffi::CString;
+use std::os::raw::{c_char, c_void};
+
+/// Device registered to use the account.
+#[repr(C)]
+pub struct Device {
+    /// ID of the device.
+    pub id: DeviceId,
+    /// Name of the device.
+    pub name: *const c_char,
+    /// Time the device was registered at, in seconds since the Unix epoch.
+    pub registered_at_sec: i64,
+    /// Time the device last logged in at, in seconds since the Unix epoch.
+    pub last_login_sec: i64,
+}
+
+impl Drop for Device {
+    fn drop(&mut self) {
+        unsafe {
+            let _ = CString::from_raw(self.name as *mut _);
+        }
+    }
+}
+
+/// Register the device the authenticator runs on with the given name. The device key passed to
+/// the callback has to be stored by the device, as it's needed to log in with `login_device`.
+///
+/// Callback parameters: user data, error code, device key
+#[no_mangle]
+pub unsafe extern "C" fn auth_register_device(
+    auth: *const Authenticator,
+    name: *const c_char,
+    user_data: *mut c_void,
+    o_cb: extern "C" fn(
+        user_data: *mut c_void,
+        result: *const FfiResult,
+        device_key: *const DeviceKey,
+    ),
+) {
+    let user_data = OpaqueCtx(user_data);
+
+    catch_unwind_cb(user_data.0, o_cb, || -> Result<_> {
+        let name = String::clone_from_repr_c(name)?;
+
+        (*auth).send(move |client| {
+            devices::register(client, &name)
+                .map(move |device_key| {
+                    o_cb(user_data.0, FFI_RESULT_OK, &device_key);
+                })
+                .map_err(move |e| {
+                    call_result_cb!(Err::<(), _>(Error::from(e)), user_data, o_cb);
+                })
+                .into_box()
+                .into()
+        })?;
+
+        Ok(())
+    })
+}
+
+/// Get the devices registered to use the account.
+///
+/// Callback parameters: user data, error code, devices vector, vector size
+#[no_mangle]
+pub unsafe extern "C" fn auth_devices(
+    auth: *const Authenticator,
+    user_data: *mut c_void,
+    o_cb: extern "C" fn(
+        user_data: *mut c_void,
+        result: *const FfiResult,
+        devices: *const Device,
+        devices_len: usize,
+    ),
+) {
+    let user_data = OpaqueCtx(user_data);
+
+    catch_unwind_cb(user_data.0, o_cb, || -> Result<_> {
+        (*auth).send(move |client| {
+            devices::list(client)
+                .and_then(move |devices| {
+                    let devices: Vec<_> = devices
+                        .into_iter()
+                        .map(NativeDevice::into_repr_c)
+                        .collect::<std::result::Result<_, _>>()?;
+                    o_cb(
+                        user_data.0,
+                        FFI_RESULT_OK,
+                        devices.as_safe_ptr(),
+                        devices.len(),
+                    );
+
+                    Ok(())
+                })
+                .map_err(move |e| {
+                    call_result_cb!(Err::<(), _>(Error::from(e)), user_data, o_cb);
+                })
+                .into_box()
+                .into()
+        })?;
+
+        Ok(())
+    })
+}
+
+/// Log out the device with the given ID, so that it can't log in with its device key anymore.
+/// The client keys are rotated as well, ending the sessions the device has open. Other sessions
+/// of the account, including other authenticators, have to log in again too.
+#[no_mangle]
+pub unsafe extern "C" fn auth_log_out_device(
+    auth: *const Authenticator,
+    device_id: *const DeviceId,
+    user_data: *mut c_void,
+    o_cb: extern "C" fn(user_data: *mut c_void, result: *const FfiResult),
+) {
+    let user_data = OpaqueCtx(user_data);
+    let device_id = *device_id;
+
+    catch_unwind_cb(user_data.0, o_cb, || -> Result<_> {
+        (*auth).send(move |client| {
+            devices::log_out(client, device_id)
+                .then(move |res| {
+                    call_result_cb!(res.map_err(Error::from), user_data, o_cb);
+                    Ok(())
+                })
+                .into_box()
+                .into()
+        })
+    })
+}
@@ -11,3 +11,5 @@
 // These error codes are positive so as not to conflict with the shared error codes, which are negative
 pub const ERR_ACCOUNT_CONTAINERS_CREATION: i32 = 1;
 pub const ERR_PENDING_REVOCATION: i32 = 2;
+pub const ERR_UNKNOWN_DEVICE: i32 = 3;
+pub const ERR_BACKUP_MISMATCH: i32 = 4;
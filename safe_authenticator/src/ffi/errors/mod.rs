@@ -147,8 +147,15 @@ impl ErrorCode for Error {
                 IpcError::Unexpected(_) => ERR_UNEXPECTED,
                 IpcError::StringError(_) => ERR_STRING_ERROR,
                 IpcError::ShareMDataDenied => ERR_SHARE_MDATA_DENIED,
+                IpcError::ShareADataDenied => ERR_SHARE_ADATA_DENIED,
+                IpcError::TransOwnershipDenied => ERR_TRANS_OWNERSHIP_DENIED,
+                IpcError::PermissionsDenied => ERR_PERMISSIONS_DENIED,
                 IpcError::InvalidOwner(..) => ERR_INVALID_OWNER,
                 IpcError::IncompatibleMockStatus => ERR_INCOMPATIBLE_MOCK_STATUS,
+                IpcError::ReqIdMismatch => ERR_REQ_ID_MISMATCH,
+                IpcError::InvalidSignature => ERR_IPC_INVALID_SIGNATURE,
+                IpcError::UnsupportedVersion(_) => ERR_UNSUPPORTED_VERSION,
+                IpcError::TransOwnershipFailed(..) => ERR_TRANS_OWNERSHIP_FAILED,
             },
             AuthError::NfsError(ref err) => match *err {
                 NfsError::CoreError(ref err) => core_error_code(err),
@@ -165,6 +172,8 @@ impl ErrorCode for Error {
             AuthError::AccountContainersCreation(_) => ERR_ACCOUNT_CONTAINERS_CREATION,
             AuthError::NoSuchContainer(_) => ERR_NO_SUCH_CONTAINER,
             AuthError::PendingRevocation => ERR_PENDING_REVOCATION,
+            AuthError::UnknownDevice => ERR_UNKNOWN_DEVICE,
+            AuthError::BackupMismatch => ERR_BACKUP_MISMATCH,
             AuthError::Unexpected(_) => ERR_UNEXPECTED,
         }
     }
@@ -223,6 +232,9 @@ fn core_error_code(err: &CoreError) -> i32 {
         CoreError::RequestTimeout => ERR_REQUEST_TIMEOUT,
         CoreError::ConfigError(_) => ERR_CONFIG_FILE,
         CoreError::IoError(_) => ERR_IO,
+        CoreError::InsufficientSignatureShares => ERR_INSUFFICIENT_SIGNATURE_SHARES,
+        CoreError::CostEstimateUnsupported => ERR_COST_ESTIMATE_UNSUPPORTED,
+        CoreError::TransactionHistoryUnsupported => ERR_TRANSACTION_HISTORY_UNSUPPORTED,
         CoreError::Unexpected(_) => ERR_UNEXPECTED,
     }
 }
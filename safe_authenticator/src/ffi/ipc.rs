@@ -8,31 +8,41 @@
 
 use crate::access_container;
 use crate::app_auth;
-use crate::config;
+use crate::audit;
+use crate::config::{self, AuditDecision};
 use crate::ffi::errors::{Error, Result};
-use crate::ipc::{decode_ipc_msg, decode_share_mdata_req, encode_response};
-use crate::revocation::{flush_app_revocation_queue, revoke_app};
+use crate::ipc::{
+    decode_ipc_msg, decode_share_adata_req, decode_share_mdata_req, decode_trans_ownership_req,
+    encode_decode_error, encode_response, encode_sealed_response, registered_app_keys, share_adata,
+    trans_ownership,
+};
+use crate::revocation::{flush_app_revocation_queue, revoke_app, revoke_expired_apps};
 use crate::{AuthError, Authenticator};
-use ffi_utils::{call_result_cb, ffi_error};
+use ffi_utils::{call_result_cb, ffi_error, try_cb};
 use ffi_utils::{
     catch_unwind_cb, FfiResult, NativeResult, OpaqueCtx, ReprC, SafePtr, FFI_RESULT_OK,
 };
 use futures::{stream, Future, Stream};
 use log::debug;
 use safe_core::client::Client;
-use safe_core::ffi::ipc::req::{AuthReq, ContainersReq, ShareMDataRequest};
-use safe_core::ffi::ipc::resp::MetadataResponse;
+use safe_core::ffi::ipc::req::{
+    AuthReq, ContainersReq, PermissionsReq, ShareADataRequest, ShareMDataRequest,
+    TransOwnershipRequest,
+};
+use safe_core::ffi::ipc::resp::{MetadataResponse, OwnershipChange};
 use safe_core::ipc::req::{
     AuthReq as NativeAuthReq, ContainersReq as NativeContainersReq, IpcReq,
-    ShareMDataReq as NativeShareMDataReq,
+    PermissionsReq as NativePermissionsReq, ShareADataReq as NativeShareADataReq,
+    ShareMDataReq as NativeShareMDataReq, TransOwnershipReq as NativeTransOwnershipReq,
 };
 use safe_core::ipc::resp::IpcResp;
-use safe_core::ipc::{decode_msg, IpcError, IpcMsg};
+use safe_core::ipc::{decode_msg_with_format, IpcError, IpcFormat, IpcMsg};
 use safe_core::{client, CoreError, FutureExt};
 use safe_core::{fry, ok};
 use safe_nd::MDataAddress;
 use std::ffi::CStr;
 use std::os::raw::{c_char, c_void};
+use std::ptr;
 
 /// Decodes a given encoded IPC message without requiring an authorised account.
 #[no_mangle]
@@ -51,7 +61,10 @@ pub unsafe extern "C" fn auth_unregistered_decode_ipc_msg(
 
     catch_unwind_cb(user_data.0, o_err, || -> Result<_> {
         let msg_raw = CStr::from_ptr(msg).to_str()?;
-        let msg = decode_msg(msg_raw)?;
+        let msg = match decode_msg_with_format(msg_raw) {
+            Ok((msg, _)) => msg,
+            Err(err) => return report_decode_error(err, user_data, o_err),
+        };
 
         match msg {
             IpcMsg::Req {
@@ -78,7 +91,85 @@ pub unsafe extern "C" fn auth_unregistered_decode_ipc_msg(
     })
 }
 
+/// Decoded IPC request is an `AuthReq`, set in `DecodedIpcReq::auth`.
+pub const IPC_REQ_AUTH: u32 = 0;
+/// Decoded IPC request is a `ContainersReq`, set in `DecodedIpcReq::containers`.
+pub const IPC_REQ_CONTAINERS: u32 = 1;
+/// Decoded IPC request is an unregistered request, with its extra data set in
+/// `DecodedIpcReq::extra_data`.
+pub const IPC_REQ_UNREGISTERED: u32 = 2;
+/// Decoded IPC request is a `ShareMDataReq`, set in `DecodedIpcReq::share_mdata` along with the
+/// metadata of the shared data.
+pub const IPC_REQ_SHARE_MDATA: u32 = 3;
+/// Decoded IPC request is a `ShareADataReq`, set in `DecodedIpcReq::share_adata`.
+pub const IPC_REQ_SHARE_ADATA: u32 = 4;
+/// Decoded IPC request is a `TransOwnershipReq`, set in `DecodedIpcReq::trans_ownership` along
+/// with the ownership changes it asks for.
+pub const IPC_REQ_TRANS_OWNERSHIP: u32 = 5;
+/// Decoded IPC request is a `PermissionsReq`, set in `DecodedIpcReq::permissions`.
+pub const IPC_REQ_PERMISSIONS: u32 = 6;
+
+/// IPC request decoded by `auth_decode_ipc_req`. Only the fields of the request's `kind` are set,
+/// the others are null. Fields for new kinds of requests are appended, so callers built against
+/// an older version keep working.
+#[repr(C)]
+pub struct DecodedIpcReq {
+    /// Kind of the request, one of the `IPC_REQ_*` constants.
+    pub kind: u32,
+    /// Request ID.
+    pub req_id: u32,
+    /// Set for `IPC_REQ_AUTH`.
+    pub auth: *const AuthReq,
+    /// Set for `IPC_REQ_CONTAINERS`.
+    pub containers: *const ContainersReq,
+    /// Set for `IPC_REQ_UNREGISTERED`.
+    pub extra_data: *const u8,
+    /// Length of `extra_data`.
+    pub extra_data_len: usize,
+    /// Set for `IPC_REQ_SHARE_MDATA`.
+    pub share_mdata: *const ShareMDataRequest,
+    /// Metadata of the shared mutable data, set for `IPC_REQ_SHARE_MDATA`.
+    pub metadata: *const MetadataResponse,
+    /// Length of `metadata`.
+    pub metadata_len: usize,
+    /// Set for `IPC_REQ_SHARE_ADATA`.
+    pub share_adata: *const ShareADataRequest,
+    /// Set for `IPC_REQ_TRANS_OWNERSHIP`.
+    pub trans_ownership: *const TransOwnershipRequest,
+    /// Ownership changes the request asks for, set for `IPC_REQ_TRANS_OWNERSHIP`.
+    pub ownership_changes: *const OwnershipChange,
+    /// Length of `ownership_changes`.
+    pub ownership_changes_len: usize,
+    /// Set for `IPC_REQ_PERMISSIONS`.
+    pub permissions: *const PermissionsReq,
+}
+
+impl DecodedIpcReq {
+    fn new(kind: u32, req_id: u32) -> Self {
+        Self {
+            kind,
+            req_id,
+            auth: ptr::null(),
+            containers: ptr::null(),
+            extra_data: ptr::null(),
+            extra_data_len: 0,
+            share_mdata: ptr::null(),
+            metadata: ptr::null(),
+            metadata_len: 0,
+            share_adata: ptr::null(),
+            trans_ownership: ptr::null(),
+            ownership_changes: ptr::null(),
+            ownership_changes_len: 0,
+            permissions: ptr::null(),
+        }
+    }
+}
+
 /// Decodes a given encoded IPC message and calls a corresponding callback.
+///
+/// Only the requests this function has a callback for are decoded. Any other request is reported
+/// through `o_err`; use `auth_decode_ipc_req` or `auth_decode_ipc_req_by_kind` to decode every kind
+/// of request.
 #[no_mangle]
 pub unsafe extern "C" fn auth_decode_ipc_msg(
     auth: *const Authenticator,
@@ -104,92 +195,304 @@ pub unsafe extern "C" fn auth_decode_ipc_msg(
     let user_data = OpaqueCtx(user_data);
 
     catch_unwind_cb(user_data.0, o_err, || -> Result<_> {
-        let msg_raw = CStr::from_ptr(msg).to_str()?;
-        let msg = decode_msg(msg_raw)?;
+        decode_req(auth, msg, user_data, o_err, move |req| match req.kind {
+            IPC_REQ_AUTH => o_auth(user_data.0, req.req_id, req.auth),
+            IPC_REQ_CONTAINERS => o_containers(user_data.0, req.req_id, req.containers),
+            IPC_REQ_UNREGISTERED => {
+                o_unregistered(user_data.0, req.req_id, req.extra_data, req.extra_data_len)
+            }
+            IPC_REQ_SHARE_MDATA => o_share_mdata(
+                user_data.0,
+                req.req_id,
+                req.share_mdata,
+                req.metadata,
+                req.metadata_len,
+            ),
+            _ => {
+                let err = Error::from(AuthError::Unexpected(
+                    "Request not supported by auth_decode_ipc_msg".to_owned(),
+                ));
+                call_result_cb!(Err::<(), _>(err), user_data, o_err);
+            }
+        })
+    })
+}
 
-        (*auth).send(move |client| {
-            let c1 = client.clone();
-            decode_ipc_msg(client, msg)
-                .and_then(move |msg| match msg {
-                    Ok(IpcMsg::Req {
-                        request: IpcReq::Auth(auth_req),
-                        req_id,
-                    }) => {
-                        let repr_c = fry!(auth_req.into_repr_c().map_err(AuthError::IpcError));
-                        o_auth(user_data.0, req_id, &repr_c);
-                        ok!(())
-                    }
-                    Ok(IpcMsg::Req {
-                        request: IpcReq::Containers(cont_req),
-                        req_id,
-                    }) => {
-                        let repr_c = fry!(cont_req.into_repr_c().map_err(AuthError::IpcError));
-                        o_containers(user_data.0, req_id, &repr_c);
-                        ok!(())
-                    }
-                    Ok(IpcMsg::Req {
-                        request: IpcReq::Unregistered(extra_data),
-                        req_id,
-                    }) => {
-                        o_unregistered(
-                            user_data.0,
-                            req_id,
-                            extra_data.as_safe_ptr(),
-                            extra_data.len(),
-                        );
-                        ok!(())
-                    }
-                    Ok(IpcMsg::Req {
-                        request: IpcReq::ShareMData(share_mdata_req),
-                        req_id,
-                    }) => decode_share_mdata_req(&c1, &share_mdata_req)
-                        .and_then(move |metadata_cont| {
-                            let share_mdata_req_repr_c = share_mdata_req.into_repr_c()?;
-
-                            let mut ffi_metadata_cont = Vec::with_capacity(metadata_cont.len());
-                            for metadata in metadata_cont {
-                                if let Some(metadata) = metadata {
-                                    ffi_metadata_cont.push(metadata);
-                                } else {
-                                    ffi_metadata_cont.push(MetadataResponse::invalid());
-                                }
-                            }
+/// Decodes a given encoded IPC request of any kind and passes it to `o_req`. The decoded request
+/// is only valid for the duration of the callback.
+///
+/// New kinds of requests are passed to the same callback, so unlike `auth_decode_ipc_msg` this
+/// function keeps its signature as requests are added.
+#[no_mangle]
+pub unsafe extern "C" fn auth_decode_ipc_req(
+    auth: *const Authenticator,
+    msg: *const c_char,
+    user_data: *mut c_void,
+    o_req: extern "C" fn(user_data: *mut c_void, req: *const DecodedIpcReq),
+    o_err: extern "C" fn(user_data: *mut c_void, result: *const FfiResult, response: *const c_char),
+) {
+    let user_data = OpaqueCtx(user_data);
 
-                            o_share_mdata(
-                                user_data.0,
-                                req_id,
-                                &share_mdata_req_repr_c,
-                                ffi_metadata_cont.as_ptr(),
-                                ffi_metadata_cont.len(),
-                            );
+    catch_unwind_cb(user_data.0, o_err, || -> Result<_> {
+        decode_req(auth, msg, user_data, o_err, move |req| {
+            o_req(user_data.0, req)
+        })
+    })
+}
 
-                            Ok(())
-                        })
-                        .into_box(),
-                    Err((error_code, description, err)) => {
-                        let res = fry!(NativeResult {
-                            error_code,
-                            description: Some(description),
+/// Decodes a given encoded IPC request and calls the callback of its kind. Decodes the same
+/// requests as `auth_decode_ipc_req`, for bindings that can't read `DecodedIpcReq`.
+///
+/// A callback is added here with every new kind of request; until then the request is reported
+/// through `o_err`, as `auth_decode_ipc_msg` does.
+#[no_mangle]
+pub unsafe extern "C" fn auth_decode_ipc_req_by_kind(
+    auth: *const Authenticator,
+    msg: *const c_char,
+    user_data: *mut c_void,
+    o_auth: extern "C" fn(user_data: *mut c_void, req_id: u32, req: *const AuthReq),
+    o_containers: extern "C" fn(user_data: *mut c_void, req_id: u32, req: *const ContainersReq),
+    o_unregistered: extern "C" fn(
+        user_data: *mut c_void,
+        req_id: u32,
+        extra_data: *const u8,
+        extra_data_len: usize,
+    ),
+    o_share_mdata: extern "C" fn(
+        user_data: *mut c_void,
+        req_id: u32,
+        req: *const ShareMDataRequest,
+        metadata: *const MetadataResponse,
+        metadata_len: usize,
+    ),
+    o_share_adata: extern "C" fn(
+        user_data: *mut c_void,
+        req_id: u32,
+        req: *const ShareADataRequest,
+    ),
+    o_trans_ownership: extern "C" fn(
+        user_data: *mut c_void,
+        req_id: u32,
+        req: *const TransOwnershipRequest,
+        changes: *const OwnershipChange,
+        changes_len: usize,
+    ),
+    o_permissions: extern "C" fn(user_data: *mut c_void, req_id: u32, req: *const PermissionsReq),
+    o_err: extern "C" fn(user_data: *mut c_void, result: *const FfiResult, response: *const c_char),
+) {
+    let user_data = OpaqueCtx(user_data);
+
+    catch_unwind_cb(user_data.0, o_err, || -> Result<_> {
+        decode_req(auth, msg, user_data, o_err, move |req| match req.kind {
+            IPC_REQ_AUTH => o_auth(user_data.0, req.req_id, req.auth),
+            IPC_REQ_CONTAINERS => o_containers(user_data.0, req.req_id, req.containers),
+            IPC_REQ_UNREGISTERED => {
+                o_unregistered(user_data.0, req.req_id, req.extra_data, req.extra_data_len)
+            }
+            IPC_REQ_SHARE_MDATA => o_share_mdata(
+                user_data.0,
+                req.req_id,
+                req.share_mdata,
+                req.metadata,
+                req.metadata_len,
+            ),
+            IPC_REQ_SHARE_ADATA => o_share_adata(user_data.0, req.req_id, req.share_adata),
+            IPC_REQ_TRANS_OWNERSHIP => o_trans_ownership(
+                user_data.0,
+                req.req_id,
+                req.trans_ownership,
+                req.ownership_changes,
+                req.ownership_changes_len,
+            ),
+            IPC_REQ_PERMISSIONS => o_permissions(user_data.0, req.req_id, req.permissions),
+            _ => {
+                let err = Error::from(AuthError::Unexpected(
+                    "Request not supported by auth_decode_ipc_req_by_kind".to_owned(),
+                ));
+                call_result_cb!(Err::<(), _>(err), user_data, o_err);
+            }
+        })
+    })
+}
+
+// Decodes the request on the authenticator's event loop and passes it to `o_req`. Requests that
+// can't be granted are reported through `o_err`, with the encoded error response to send back.
+unsafe fn decode_req<F>(
+    auth: *const Authenticator,
+    msg: *const c_char,
+    user_data: OpaqueCtx,
+    o_err: extern "C" fn(user_data: *mut c_void, result: *const FfiResult, response: *const c_char),
+    o_req: F,
+) -> Result<()>
+where
+    F: FnOnce(&DecodedIpcReq) + Send + 'static,
+{
+    let msg_raw = CStr::from_ptr(msg).to_str()?;
+    let (msg, format) = match decode_msg_with_format(msg_raw) {
+        Ok(decoded) => decoded,
+        Err(err) => return report_decode_error(err, user_data, o_err),
+    };
+    if let IpcMsg::Req { req_id, .. } = msg {
+        (*auth).req_formats.insert(req_id, format);
+    }
+
+    (*auth).send(move |client| {
+        let c1 = client.clone();
+        decode_ipc_msg(client, msg, format)
+            .and_then(move |msg| match msg {
+                Ok(IpcMsg::Req {
+                    request: IpcReq::Auth(auth_req),
+                    req_id,
+                }) => {
+                    let repr_c = fry!(auth_req.into_repr_c().map_err(AuthError::IpcError));
+                    o_req(&DecodedIpcReq {
+                        auth: &repr_c,
+                        ..DecodedIpcReq::new(IPC_REQ_AUTH, req_id)
+                    });
+                    ok!(())
+                }
+                Ok(IpcMsg::Req {
+                    request: IpcReq::Containers(cont_req),
+                    req_id,
+                }) => {
+                    let repr_c = fry!(cont_req.into_repr_c().map_err(AuthError::IpcError));
+                    o_req(&DecodedIpcReq {
+                        containers: &repr_c,
+                        ..DecodedIpcReq::new(IPC_REQ_CONTAINERS, req_id)
+                    });
+                    ok!(())
+                }
+                Ok(IpcMsg::Req {
+                    request: IpcReq::Permissions(perms_req),
+                    req_id,
+                }) => {
+                    let repr_c = fry!(perms_req.into_repr_c().map_err(AuthError::IpcError));
+                    o_req(&DecodedIpcReq {
+                        permissions: &repr_c,
+                        ..DecodedIpcReq::new(IPC_REQ_PERMISSIONS, req_id)
+                    });
+                    ok!(())
+                }
+                Ok(IpcMsg::Req {
+                    request: IpcReq::Unregistered(extra_data),
+                    req_id,
+                }) => {
+                    o_req(&DecodedIpcReq {
+                        extra_data: extra_data.as_safe_ptr(),
+                        extra_data_len: extra_data.len(),
+                        ..DecodedIpcReq::new(IPC_REQ_UNREGISTERED, req_id)
+                    });
+                    ok!(())
+                }
+                Ok(IpcMsg::Req {
+                    request: IpcReq::ShareMData(share_mdata_req),
+                    req_id,
+                }) => decode_share_mdata_req(&c1, &share_mdata_req)
+                    .and_then(move |metadata_cont| {
+                        let share_mdata_req_repr_c = share_mdata_req.into_repr_c()?;
+
+                        let mut ffi_metadata_cont = Vec::with_capacity(metadata_cont.len());
+                        for metadata in metadata_cont {
+                            if let Some(metadata) = metadata {
+                                ffi_metadata_cont.push(metadata);
+                            } else {
+                                ffi_metadata_cont.push(MetadataResponse::invalid());
+                            }
                         }
-                        .into_repr_c());
-                        o_err(user_data.0, &res, err.as_ptr());
-                        ok!(())
-                    }
-                    Ok(IpcMsg::Resp { .. }) | Ok(IpcMsg::Revoked { .. }) | Ok(IpcMsg::Err(..)) => {
-                        let err =
-                            Error::from(AuthError::Unexpected("Unexpected msg type".to_owned()));
-                        call_result_cb!(Err::<(), _>(err), user_data, o_err);
-                        ok!(())
+
+                        o_req(&DecodedIpcReq {
+                            share_mdata: &share_mdata_req_repr_c,
+                            metadata: ffi_metadata_cont.as_ptr(),
+                            metadata_len: ffi_metadata_cont.len(),
+                            ..DecodedIpcReq::new(IPC_REQ_SHARE_MDATA, req_id)
+                        });
+
+                        Ok(())
+                    })
+                    .into_box(),
+                Ok(IpcMsg::Req {
+                    request: IpcReq::ShareAData(share_adata_req),
+                    req_id,
+                }) => decode_share_adata_req(&c1, &share_adata_req)
+                    .and_then(move |()| {
+                        let share_adata_req_repr_c = share_adata_req.into_repr_c()?;
+                        o_req(&DecodedIpcReq {
+                            share_adata: &share_adata_req_repr_c,
+                            ..DecodedIpcReq::new(IPC_REQ_SHARE_ADATA, req_id)
+                        });
+                        Ok(())
+                    })
+                    .into_box(),
+                Ok(IpcMsg::Req {
+                    request: IpcReq::TransOwnership(trans_ownership_req),
+                    req_id,
+                }) => decode_trans_ownership_req(&c1, &trans_ownership_req)
+                    .and_then(move |changes| {
+                        let trans_ownership_req_repr_c = trans_ownership_req.into_repr_c()?;
+                        let ffi_changes = changes
+                            .into_iter()
+                            .map(|change| change.into_repr_c())
+                            .collect::<std::result::Result<Vec<_>, _>>()?;
+
+                        o_req(&DecodedIpcReq {
+                            trans_ownership: &trans_ownership_req_repr_c,
+                            ownership_changes: ffi_changes.as_ptr(),
+                            ownership_changes_len: ffi_changes.len(),
+                            ..DecodedIpcReq::new(IPC_REQ_TRANS_OWNERSHIP, req_id)
+                        });
+
+                        Ok(())
+                    })
+                    .into_box(),
+                Err((error_code, description, err)) => {
+                    let res = fry!(NativeResult {
+                        error_code,
+                        description: Some(description),
                     }
-                })
-                .map_err(move |err| {
-                    call_result_cb!(Err::<(), _>(Error::from(err)), user_data, o_err);
-                })
-                .into_box()
-                .into()
-        })?;
-        Ok(())
-    })
+                    .into_repr_c());
+                    o_err(user_data.0, &res, err.as_ptr());
+                    ok!(())
+                }
+                Ok(IpcMsg::Resp { .. })
+                | Ok(IpcMsg::Revoked { .. })
+                | Ok(IpcMsg::Err(..))
+                | Ok(IpcMsg::Sealed(..)) => {
+                    let err = Error::from(AuthError::Unexpected("Unexpected msg type".to_owned()));
+                    call_result_cb!(Err::<(), _>(err), user_data, o_err);
+                    ok!(())
+                }
+            })
+            .map_err(move |err| {
+                call_result_cb!(Err::<(), _>(Error::from(err)), user_data, o_err);
+            })
+            .into_box()
+            .into()
+    })?;
+
+    Ok(())
+}
+
+// Reports a message that failed to decode through `o_err`, along with the encoded error response
+// to send back if the app can be told about the failure.
+unsafe fn report_decode_error(
+    err: IpcError,
+    user_data: OpaqueCtx,
+    o_err: extern "C" fn(user_data: *mut c_void, result: *const FfiResult, response: *const c_char),
+) -> Result<()> {
+    let response = match encode_decode_error(&err)? {
+        Some(response) => response,
+        None => return Err(Error::from(err)),
+    };
+
+    let err = Error::from(err);
+    let (error_code, description) = ffi_error!(err);
+    let res = NativeResult {
+        error_code,
+        description: Some(description),
+    }
+    .into_repr_c()?;
+    o_err(user_data.0, &res, response.as_ptr());
+    Ok(())
 }
 
 /// Revoke app access.
@@ -208,7 +511,7 @@ pub unsafe extern "C" fn auth_revoke_app(
         (*auth).send(move |client| {
             revoke_app(client, &app_id)
                 .and_then(move |_| {
-                    let resp = encode_response(&IpcMsg::Revoked { app_id })?;
+                    let resp = encode_response(&IpcMsg::Revoked { app_id }, IpcFormat::legacy())?;
                     o_cb(user_data.0, FFI_RESULT_OK, resp.as_ptr());
                     Ok(())
                 })
@@ -245,7 +548,34 @@ pub unsafe extern "C" fn auth_flush_app_revocation_queue(
     })
 }
 
+/// Revoke the apps whose authorisation has expired.
+///
+/// Should be called periodically while the user is logged in.
+#[no_mangle]
+pub unsafe extern "C" fn auth_revoke_expired_apps(
+    auth: *const Authenticator,
+    user_data: *mut c_void,
+    o_cb: extern "C" fn(user_data: *mut c_void, result: *const FfiResult),
+) {
+    let user_data = OpaqueCtx(user_data);
+
+    catch_unwind_cb(user_data.0, o_cb, || -> Result<_> {
+        (*auth).send(move |client| {
+            revoke_expired_apps(client)
+                .then(move |res| {
+                    call_result_cb!(res.map(|_| ()).map_err(Error::from), user_data, o_cb);
+                    Ok(())
+                })
+                .into_box()
+                .into()
+        })
+    })
+}
+
 /// Encodes a response to unregistered client authentication request.
+///
+/// The response is encoded in the legacy format, as unregistered requests are decoded without an
+/// authenticator to remember their format.
 #[no_mangle]
 pub unsafe extern "C" fn encode_unregistered_resp(
     req_id: u32,
@@ -256,20 +586,28 @@ pub unsafe extern "C" fn encode_unregistered_resp(
     let user_data = OpaqueCtx(user_data);
 
     catch_unwind_cb(user_data.0, o_cb, || -> Result<()> {
+        let format = IpcFormat::legacy();
+
         if is_granted {
             let bootstrap_cfg = client::bootstrap_config()?;
 
-            let resp = encode_response(&IpcMsg::Resp {
-                req_id,
-                response: IpcResp::Unregistered(Ok(bootstrap_cfg)),
-            })?;
+            let resp = encode_response(
+                &IpcMsg::Resp {
+                    req_id,
+                    response: IpcResp::Unregistered(Ok(bootstrap_cfg)),
+                },
+                format,
+            )?;
 
             o_cb(user_data.0, FFI_RESULT_OK, resp.as_ptr());
         } else {
-            let resp = encode_response(&IpcMsg::Resp {
-                req_id,
-                response: IpcResp::Unregistered(Err(IpcError::AuthDenied)),
-            })?;
+            let resp = encode_response(
+                &IpcMsg::Resp {
+                    req_id,
+                    response: IpcResp::Unregistered(Err(IpcError::AuthDenied)),
+                },
+                format,
+            )?;
 
             o_cb(user_data.0, FFI_RESULT_OK, resp.as_ptr());
         }
@@ -291,26 +629,41 @@ pub unsafe extern "C" fn encode_auth_resp(
 
     catch_unwind_cb(user_data.0, o_cb, || -> Result<()> {
         let auth_req = NativeAuthReq::clone_from_repr_c(req)?;
+        let format = (*auth).req_formats.take_reply_format(req_id);
+        let response_key = auth_req.response_key;
 
         if is_granted {
             (*auth).send(move |client| {
+                let c2 = client.clone();
+                let c3 = client.clone();
+
                 app_auth::authenticate(client, auth_req)
                     .map_err(Error::from)
                     .and_then(move |auth_granted| {
-                        let resp = encode_response(&IpcMsg::Resp {
-                            req_id,
-                            response: IpcResp::Auth(Ok(auth_granted)),
-                        })?;
+                        let resp = encode_sealed_response(
+                            &c2,
+                            &IpcMsg::Resp {
+                                req_id,
+                                response: IpcResp::Auth(Ok(auth_granted)),
+                            },
+                            response_key.as_ref(),
+                            format,
+                        )?;
 
                         o_cb(user_data.0, FFI_RESULT_OK, resp.as_ptr());
                         Ok(())
                     })
                     .or_else(move |e| -> Result<()> {
                         let (error_code, description) = ffi_error!(e);
-                        let resp = encode_response(&IpcMsg::Resp {
-                            req_id,
-                            response: IpcResp::Auth(Err(e.into())),
-                        })?;
+                        let resp = encode_sealed_response(
+                            &c3,
+                            &IpcMsg::Resp {
+                                req_id,
+                                response: IpcResp::Auth(Err(e.into())),
+                            },
+                            response_key.as_ref(),
+                            format,
+                        )?;
                         let result = NativeResult {
                             error_code,
                             description: Some(description),
@@ -325,11 +678,35 @@ pub unsafe extern "C" fn encode_auth_resp(
                     .into_box()
                     .into()
             })?;
-        } else {
-            let response = encode_response(&IpcMsg::Resp {
-                req_id,
-                response: IpcResp::Auth(Err(IpcError::AuthDenied)),
+        } else if response_key.is_some() {
+            // Signing the sealed response needs the client.
+            (*auth).send(move |client| {
+                let resp = try_cb!(
+                    encode_sealed_response(
+                        client,
+                        &IpcMsg::Resp {
+                            req_id,
+                            response: IpcResp::Auth(Err(IpcError::AuthDenied)),
+                        },
+                        response_key.as_ref(),
+                        format,
+                    )
+                    .map_err(Error::from),
+                    user_data.0,
+                    o_cb
+                );
+
+                o_cb(user_data.0, FFI_RESULT_OK, resp.as_ptr());
+                None
             })?;
+        } else {
+            let response = encode_response(
+                &IpcMsg::Resp {
+                    req_id,
+                    response: IpcResp::Auth(Err(IpcError::AuthDenied)),
+                },
+                format,
+            )?;
 
             o_cb(user_data.0, FFI_RESULT_OK, response.as_ptr());
         }
@@ -352,17 +729,25 @@ pub unsafe extern "C" fn encode_containers_resp(
 
     catch_unwind_cb(user_data.0, o_cb, || -> Result<()> {
         let cont_req = NativeContainersReq::clone_from_repr_c(req)?;
+        let format = (*auth).req_formats.take_reply_format(req_id);
 
         if is_granted {
             let permissions = cont_req.containers.clone();
             let app_id = cont_req.app.id;
+            let decision = AuditDecision::ContainersGranted {
+                containers: cont_req.containers,
+            };
 
             (*auth).send(move |client| {
                 let c2 = client.clone();
                 let c3 = client.clone();
                 let c4 = client.clone();
+                let c5 = client.clone();
 
                 config::get_app(client, &app_id)
+                    .and_then(move |app| {
+                        audit::record(&c5, &app.info.id, decision).map(move |()| app)
+                    })
                     .and_then(move |app| {
                         let app_pk = app.keys.public_key();
                         access_container::update_container_perms(&c2, permissions, app_pk)
@@ -399,20 +784,26 @@ pub unsafe extern "C" fn encode_containers_resp(
                         access_container::put_entry(&c4, &app_id, &app_keys, &perms, version)
                     })
                     .and_then(move |_| {
-                        let resp = encode_response(&IpcMsg::Resp {
-                            req_id,
-                            response: IpcResp::Containers(Ok(())),
-                        })?;
+                        let resp = encode_response(
+                            &IpcMsg::Resp {
+                                req_id,
+                                response: IpcResp::Containers(Ok(())),
+                            },
+                            format,
+                        )?;
                         o_cb(user_data.0, FFI_RESULT_OK, resp.as_ptr());
                         Ok(())
                     })
                     .map_err(Error::from)
                     .or_else(move |e| -> Result<()> {
                         let (error_code, description) = ffi_error!(e);
-                        let resp = encode_response(&IpcMsg::Resp {
-                            req_id,
-                            response: IpcResp::Containers(Err(e.into())),
-                        })?;
+                        let resp = encode_response(
+                            &IpcMsg::Resp {
+                                req_id,
+                                response: IpcResp::Containers(Err(e.into())),
+                            },
+                            format,
+                        )?;
                         let result = NativeResult {
                             error_code,
                             description: Some(description),
@@ -426,10 +817,81 @@ pub unsafe extern "C" fn encode_containers_resp(
                     .into()
             })?;
         } else {
-            let response = encode_response(&IpcMsg::Resp {
-                req_id,
-                response: IpcResp::Containers(Err(IpcError::AuthDenied)),
+            let response = encode_response(
+                &IpcMsg::Resp {
+                    req_id,
+                    response: IpcResp::Containers(Err(IpcError::AuthDenied)),
+                },
+                format,
+            )?;
+
+            o_cb(user_data.0, FFI_RESULT_OK, response.as_ptr());
+        }
+
+        Ok(())
+    });
+}
+
+/// Modify the permissions of an app.
+#[no_mangle]
+pub unsafe extern "C" fn encode_permissions_resp(
+    auth: *const Authenticator,
+    req: *const PermissionsReq,
+    req_id: u32,
+    is_granted: bool,
+    user_data: *mut c_void,
+    o_cb: extern "C" fn(user_data: *mut c_void, result: *const FfiResult, response: *const c_char),
+) {
+    let user_data = OpaqueCtx(user_data);
+
+    catch_unwind_cb(user_data.0, o_cb, || -> Result<()> {
+        let perms_req = NativePermissionsReq::clone_from_repr_c(req)?;
+        let format = (*auth).req_formats.take_reply_format(req_id);
+
+        if is_granted {
+            (*auth).send(move |client| {
+                app_auth::modify_permissions(client, perms_req)
+                    .and_then(move |()| {
+                        let resp = encode_response(
+                            &IpcMsg::Resp {
+                                req_id,
+                                response: IpcResp::Permissions(Ok(())),
+                            },
+                            format,
+                        )?;
+                        o_cb(user_data.0, FFI_RESULT_OK, resp.as_ptr());
+                        Ok(())
+                    })
+                    .map_err(Error::from)
+                    .or_else(move |e| -> Result<()> {
+                        let (error_code, description) = ffi_error!(e);
+                        let resp = encode_response(
+                            &IpcMsg::Resp {
+                                req_id,
+                                response: IpcResp::Permissions(Err(e.into())),
+                            },
+                            format,
+                        )?;
+                        let result = NativeResult {
+                            error_code,
+                            description: Some(description),
+                        }
+                        .into_repr_c()?;
+                        o_cb(user_data.0, &result, resp.as_ptr());
+                        Ok(())
+                    })
+                    .map_err(move |e| debug!("Unexpected error: {:?}", e))
+                    .into_box()
+                    .into()
             })?;
+        } else {
+            let response = encode_response(
+                &IpcMsg::Resp {
+                    req_id,
+                    response: IpcResp::Permissions(Err(IpcError::PermissionsDenied)),
+                },
+                format,
+            )?;
 
             o_cb(user_data.0, FFI_RESULT_OK, response.as_ptr());
         }
@@ -452,44 +914,128 @@ pub unsafe extern "C" fn encode_share_mdata_resp(
 
     catch_unwind_cb(user_data.0, o_cb, || -> Result<()> {
         let share_mdata_req = NativeShareMDataReq::clone_from_repr_c(req)?;
+        let format = (*auth).req_formats.take_reply_format(req_id);
 
         if is_granted {
             (*auth).send(move |client| {
                 let client_cloned0 = client.clone();
                 let client_cloned1 = client.clone();
+                let client_cloned2 = client.clone();
                 let user_data = user_data.0;
 
                 config::get_app(client, &share_mdata_req.app.id)
                     .and_then(move |app_info| {
                         let user = app_info.keys.public_key();
                         let num_mdata = share_mdata_req.mdata.len();
+                        let decision = AuditDecision::MDataShared {
+                            mdata: share_mdata_req.mdata.clone(),
+                        };
 
-                        stream::iter_ok(share_mdata_req.mdata.into_iter())
+                        audit::record(&client_cloned2, &app_info.info.id, decision)
+                            .map(move |()| stream::iter_ok(share_mdata_req.mdata.into_iter()))
+                            .flatten_stream()
                             .map(move |mdata| {
                                 client_cloned0
                                     .get_seq_mdata_shell(mdata.name, mdata.type_tag)
                                     .map(|md| (md.version(), mdata))
+                                    .map_err(AuthError::from)
                             })
                             .buffer_unordered(num_mdata)
                             .map(move |(version, mdata)| {
-                                client_cloned1.set_mdata_user_permissions(
-                                    MDataAddress::Seq {
-                                        name: mdata.name,
-                                        tag: mdata.type_tag,
+                                client_cloned1
+                                    .set_mdata_user_permissions(
+                                        MDataAddress::Seq {
+                                            name: mdata.name,
+                                            tag: mdata.type_tag,
+                                        },
+                                        user,
+                                        mdata.perms,
+                                        version + 1,
+                                    )
+                                    .map_err(AuthError::from)
+                            })
+                            .buffer_unordered(num_mdata)
+                            .for_each(|()| Ok(()))
+                            .and_then(move |()| {
+                                let resp = encode_response(
+                                    &IpcMsg::Resp {
+                                        req_id,
+                                        response: IpcResp::ShareMData(Ok(())),
                                     },
-                                    user,
-                                    mdata.perms,
-                                    version + 1,
+                                    format,
                                 )
+                                .map_err(AuthError::IpcError)?;
+                                o_cb(user_data, FFI_RESULT_OK, resp.as_ptr());
+                                Ok(())
                             })
-                            .buffer_unordered(num_mdata)
-                            .map_err(AuthError::CoreError)
+                            .into_box()
+                    })
+                    .map_err(move |e| {
+                        call_result_cb!(Err::<(), _>(Error::from(e)), user_data, o_cb);
+                    })
+                    .into_box()
+                    .into()
+            })?;
+        } else {
+            let resp = encode_response(
+                &IpcMsg::Resp {
+                    req_id,
+                    response: IpcResp::ShareMData(Err(IpcError::ShareMDataDenied)),
+                },
+                format,
+            )?;
+
+            o_cb(user_data.0, FFI_RESULT_OK, resp.as_ptr());
+        }
+
+        Ok(())
+    })
+}
+
+/// Encode share append-only data response.
+#[no_mangle]
+pub unsafe extern "C" fn encode_share_adata_resp(
+    auth: *const Authenticator,
+    req: *const ShareADataRequest,
+    req_id: u32,
+    is_granted: bool,
+    user_data: *mut c_void,
+    o_cb: extern "C" fn(user_data: *mut c_void, result: *const FfiResult, response: *const c_char),
+) {
+    let user_data = OpaqueCtx(user_data);
+
+    catch_unwind_cb(user_data.0, o_cb, || -> Result<()> {
+        let share_adata_req = NativeShareADataReq::clone_from_repr_c(req)?;
+        let format = (*auth).req_formats.take_reply_format(req_id);
+
+        if is_granted {
+            (*auth).send(move |client| {
+                let c2 = client.clone();
+                let c3 = client.clone();
+                let user_data = user_data.0;
+
+                config::get_app(client, &share_adata_req.app.id)
+                    .and_then(move |app_info| {
+                        let user = app_info.keys.public_key();
+                        let num_adata = share_adata_req.adata.len();
+                        let decision = AuditDecision::ADataShared {
+                            adata: share_adata_req.adata.clone(),
+                        };
+
+                        audit::record(&c3, &app_info.info.id, decision)
+                            .map(move |()| stream::iter_ok(share_adata_req.adata.into_iter()))
+                            .flatten_stream()
+                            .map(move |adata| share_adata(&c2, user, adata))
+                            .buffer_unordered(num_adata)
                             .for_each(|()| Ok(()))
                             .and_then(move |()| {
-                                let resp = encode_response(&IpcMsg::Resp {
-                                    req_id,
-                                    response: IpcResp::ShareMData(Ok(())),
-                                })
+                                let resp = encode_response(
+                                    &IpcMsg::Resp {
+                                        req_id,
+                                        response: IpcResp::ShareAData(Ok(())),
+                                    },
+                                    format,
+                                )
                                 .map_err(AuthError::IpcError)?;
                                 o_cb(user_data, FFI_RESULT_OK, resp.as_ptr());
                                 Ok(())
@@ -503,10 +1049,98 @@ pub unsafe extern "C" fn encode_share_mdata_resp(
                     .into()
             })?;
         } else {
-            let resp = encode_response(&IpcMsg::Resp {
-                req_id,
-                response: IpcResp::ShareMData(Err(IpcError::ShareMDataDenied)),
+            let resp = encode_response(
+                &IpcMsg::Resp {
+                    req_id,
+                    response: IpcResp::ShareAData(Err(IpcError::ShareADataDenied)),
+                },
+                format,
+            )?;
+
+            o_cb(user_data.0, FFI_RESULT_OK, resp.as_ptr());
+        }
+
+        Ok(())
+    })
+}
+
+/// Encode transfer ownership response.
+#[no_mangle]
+pub unsafe extern "C" fn encode_trans_ownership_resp(
+    auth: *const Authenticator,
+    req: *const TransOwnershipRequest,
+    req_id: u32,
+    is_granted: bool,
+    user_data: *mut c_void,
+    o_cb: extern "C" fn(user_data: *mut c_void, result: *const FfiResult, response: *const c_char),
+) {
+    let user_data = OpaqueCtx(user_data);
+
+    catch_unwind_cb(user_data.0, o_cb, || -> Result<()> {
+        let trans_ownership_req = NativeTransOwnershipReq::clone_from_repr_c(req)?;
+        let format = (*auth).req_formats.take_reply_format(req_id);
+
+        if is_granted {
+            (*auth).send(move |client| {
+                let c2 = client.clone();
+                let user_data = user_data.0;
+
+                let app_id = trans_ownership_req.app.id.clone();
+                let num_data = trans_ownership_req.data.len();
+
+                registered_app_keys(client, &app_id)
+                    .and_then(move |app_keys| {
+                        stream::iter_ok::<_, AuthError>(trans_ownership_req.data.into_iter())
+                            .map(move |item| {
+                                let data = (*item.address.name(), item.address.tag());
+                                trans_ownership(&c2, item, app_keys.clone()).then(move |result| {
+                                    if let Err(error) = result {
+                                        debug!(
+                                            "Failed to transfer the ownership of {:?}: {:?}",
+                                            data, error
+                                        );
+                                        return Ok(Some(data));
+                                    }
+                                    Ok::<_, AuthError>(None)
+                                })
+                            })
+                            .buffer_unordered(num_data.max(1))
+                            .filter_map(|failed| failed)
+                            .collect()
+                    })
+                    .and_then(move |failed| {
+                        // Some of the data may have been transferred already, so the app is told
+                        // which transfers failed rather than getting a plain error.
+                        let response = if failed.is_empty() {
+                            Ok(())
+                        } else {
+                            Err(IpcError::TransOwnershipFailed(failed))
+                        };
+                        let resp = encode_response(
+                            &IpcMsg::Resp {
+                                req_id,
+                                response: IpcResp::TransOwnership(response),
+                            },
+                            format,
+                        )
+                        .map_err(AuthError::IpcError)?;
+                        o_cb(user_data, FFI_RESULT_OK, resp.as_ptr());
+                        Ok(())
+                    })
+                    .map_err(move |e| {
+                        call_result_cb!(Err::<(), _>(Error::from(e)), user_data, o_cb);
+                    })
+                    .into_box()
+                    .into()
             })?;
+        } else {
+            let resp = encode_response(
+                &IpcMsg::Resp {
+                    req_id,
+                    response: IpcResp::TransOwnership(Err(IpcError::TransOwnershipDenied)),
+                },
+                format,
+            )?;
 
             o_cb(user_data.0, FFI_RESULT_OK, resp.as_ptr());
         }
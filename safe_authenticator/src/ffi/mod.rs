@@ -12,6 +12,14 @@
 
 /// Apps management
 pub mod apps;
+/// Audit log
+pub mod audit;
+/// Coin balance information
+pub mod coins;
+/// Account deletion
+pub mod deletion;
+/// Devices using the account
+pub mod devices;
 /// Errors
 pub mod errors;
 /// Authenticator communication with apps
@@ -19,23 +27,37 @@ pub mod ipc;
 /// Logging utilities
 pub mod logging;
 
+use crate::backup;
+use crate::devices::DeviceKey;
 use crate::ffi::errors::{Error, Result};
-use crate::Authenticator;
-use ffi_utils::try_cb;
-use ffi_utils::{catch_unwind_cb, FfiResult, OpaqueCtx, ReprC, FFI_RESULT_OK};
+use crate::rotation;
+use crate::{AuthError, Authenticator};
+use bincode::{deserialize, serialize};
+use ffi_utils::{call_result_cb, try_cb};
+use ffi_utils::{
+    catch_unwind_cb, vec_clone_from_raw_parts, FfiResult, OpaqueCtx, ReprC, FFI_RESULT_OK,
+};
+use futures::Future;
 use log::trace;
 use rand::thread_rng;
-use safe_core::{config_handler, test_create_balance, Client};
-use safe_nd::{ClientFullId, Coins};
-use std::ffi::{CStr, OsStr};
+use safe_core::ffi::arrays::{BlsPublicKey, Signature};
+use safe_core::ffi::signer::{send_signature, FfiSigner, SignatureTx};
+use safe_core::{config_handler, test_create_balance, Client, FutureExt};
+use safe_nd::{ClientFullId, ClientPublicId, Coins, PublicId, PublicKey};
+use std::ffi::{CStr, CString, OsStr};
 use std::os::raw::{c_char, c_void};
+use std::ptr;
 use std::str::FromStr;
+use std::sync::Arc;
 use unwrap::unwrap;
 
 /// Create a registered client. This or any one of the other companion
 /// functions to get an authenticator instance must be called before initiating any
 /// operation allowed by this module. The `user_data` parameter corresponds to the
 /// first parameter of the `o_cb` and `o_disconnect_notifier_cb` callbacks.
+///
+/// No recovery phrase is set up for the account; `create_acc_with_recovery` sets one up as part
+/// of the account creation.
 #[no_mangle]
 pub unsafe extern "C" fn create_acc(
     account_locator: *const c_char,
@@ -77,6 +99,62 @@ pub unsafe extern "C" fn create_acc(
     })
 }
 
+/// Create a registered client and set up a recovery phrase for it, which should be shown to the
+/// user to be written down. The `user_data` parameter corresponds to the first parameter of the
+/// `o_cb` and `o_disconnect_notifier_cb` callbacks.
+///
+/// If the phrase can't be set up, the account is created nonetheless and the phrase is null, in
+/// which case `auth_set_up_recovery` should be retried.
+///
+/// Callback parameters: user data, error code, authenticator, recovery phrase
+#[no_mangle]
+pub unsafe extern "C" fn create_acc_with_recovery(
+    account_locator: *const c_char,
+    account_password: *const c_char,
+    user_data: *mut c_void,
+    o_disconnect_notifier_cb: extern "C" fn(user_data: *mut c_void),
+    o_cb: extern "C" fn(
+        user_data: *mut c_void,
+        result: *const FfiResult,
+        authenticator: *mut Authenticator,
+        recovery_phrase: *const c_char,
+    ),
+) {
+    let user_data = OpaqueCtx(user_data);
+
+    catch_unwind_cb(user_data, o_cb, || -> Result<_> {
+        trace!("Authenticator - create a client account with a recovery phrase.");
+
+        let acc_locator = String::clone_from_repr_c(account_locator)?;
+        let acc_password = String::clone_from_repr_c(account_password)?;
+        // FIXME: Send client id via FFI API too
+        let client_id = ClientFullId::new_bls(&mut thread_rng());
+        unwrap!(test_create_balance(
+            &client_id,
+            unwrap!(Coins::from_str("10"))
+        ));
+
+        let (authenticator, recovery_phrase) = Authenticator::create_acc_with_recovery(
+            acc_locator,
+            acc_password,
+            client_id,
+            move || o_disconnect_notifier_cb(user_data.0),
+        )?;
+        let recovery_phrase = recovery_phrase.map(CString::new).transpose()?;
+
+        o_cb(
+            user_data.0,
+            FFI_RESULT_OK,
+            Box::into_raw(Box::new(authenticator)),
+            recovery_phrase
+                .as_ref()
+                .map_or(ptr::null(), |recovery_phrase| recovery_phrase.as_ptr()),
+        );
+
+        Ok(())
+    })
+}
+
 /// Log into a registered account. This or any one of the other companion
 /// functions to get an authenticator instance must be called before initiating
 /// any operation allowed for authenticator. The `user_data` parameter corresponds to the
@@ -115,6 +193,367 @@ pub unsafe extern "C" fn login(
     })
 }
 
+/// Log into a registered account whose requests are signed by the caller, e.g. with a hardware
+/// wallet holding the account's key, instead of with the secret key stored in the account.
+/// `public_id` is the serialised public ID of the account, as returned by
+/// `auth_account_public_id`. `o_sign` is called, possibly from another thread, with each
+/// message to sign and the channel to send the signature back on with `auth_send_signature`.
+/// The `user_data` parameter corresponds to the first parameter of the `o_sign`, `o_cb` and
+/// `o_disconnect_notifier_cb` callbacks, and must stay valid as long as the authenticator.
+#[no_mangle]
+pub unsafe extern "C" fn login_with_signer(
+    account_locator: *const c_char,
+    account_password: *const c_char,
+    public_id: *const u8,
+    public_id_len: usize,
+    user_data: *mut c_void,
+    o_sign: extern "C" fn(
+        user_data: *mut c_void,
+        msg: *const u8,
+        msg_len: usize,
+        signature_tx: *mut SignatureTx,
+    ),
+    o_disconnect_notifier_cb: unsafe extern "C" fn(user_data: *mut c_void),
+    o_cb: extern "C" fn(
+        user_data: *mut c_void,
+        result: *const FfiResult,
+        authenticaor: *mut Authenticator,
+    ),
+) {
+    let user_data = OpaqueCtx(user_data);
+
+    catch_unwind_cb(user_data, o_cb, || -> Result<_> {
+        trace!("Authenticator - log in a registered client with an external signer.");
+
+        let acc_locator = String::clone_from_repr_c(account_locator)?;
+        let acc_password = String::clone_from_repr_c(account_password)?;
+        let public_id = vec_clone_from_raw_parts(public_id, public_id_len);
+        let public_id: ClientPublicId = deserialize(&public_id).map_err(AuthError::from)?;
+        let signer = Arc::new(FfiSigner::new(
+            PublicId::Client(public_id),
+            user_data.0,
+            o_sign,
+        ));
+
+        let authenticator =
+            Authenticator::login_with_signer(acc_locator, acc_password, signer, move || {
+                o_disconnect_notifier_cb(user_data.0)
+            })?;
+
+        o_cb(
+            user_data.0,
+            FFI_RESULT_OK,
+            Box::into_raw(Box::new(authenticator)),
+        );
+
+        Ok(())
+    })
+}
+
+/// Send the signature requested by `o_sign` of `login_with_signer` back on `signature_tx`,
+/// which can't be used afterwards. Pass a null `signature` to refuse to sign.
+#[no_mangle]
+pub unsafe extern "C" fn auth_send_signature(
+    signature_tx: *mut SignatureTx,
+    signature: *const Signature,
+    user_data: *mut c_void,
+    o_cb: extern "C" fn(user_data: *mut c_void, result: *const FfiResult),
+) {
+    catch_unwind_cb(user_data, o_cb, || -> Result<_> {
+        send_signature(signature_tx, signature).map_err(AuthError::from)?;
+        o_cb(user_data, FFI_RESULT_OK);
+        Ok(())
+    })
+}
+
+/// Log into a registered account from a registered device, using the device key obtained when
+/// registering the device with `auth_register_device`. Fails if the device was logged out. The
+/// `user_data` parameter corresponds to the first parameter of the `o_cb` and
+/// `o_disconnect_notifier_cb` callbacks.
+#[no_mangle]
+pub unsafe extern "C" fn login_device(
+    account_locator: *const c_char,
+    account_password: *const c_char,
+    device_key: *const DeviceKey,
+    user_data: *mut c_void,
+    o_disconnect_notifier_cb: unsafe extern "C" fn(user_data: *mut c_void),
+    o_cb: extern "C" fn(
+        user_data: *mut c_void,
+        result: *const FfiResult,
+        authenticator: *mut Authenticator,
+    ),
+) {
+    let user_data = OpaqueCtx(user_data);
+
+    catch_unwind_cb(user_data, o_cb, || -> Result<_> {
+        trace!("Authenticator - log in a registered device.");
+
+        let acc_locator = String::clone_from_repr_c(account_locator)?;
+        let acc_password = String::clone_from_repr_c(account_password)?;
+
+        let authenticator =
+            Authenticator::login_device(acc_locator, acc_password, *device_key, move || {
+                o_disconnect_notifier_cb(user_data.0)
+            })?;
+
+        o_cb(
+            user_data.0,
+            FFI_RESULT_OK,
+            Box::into_raw(Box::new(authenticator)),
+        );
+
+        Ok(())
+    })
+}
+
+/// Regain access to a registered account using its recovery phrase, replacing its password with
+/// `new_password`. This or any one of the other companion functions to get an authenticator
+/// instance must be called before initiating any operation allowed for authenticator. The
+/// `user_data` parameter corresponds to the first parameter of the `o_cb` and
+/// `o_disconnect_notifier_cb` callbacks.
+#[no_mangle]
+pub unsafe extern "C" fn recover_account(
+    recovery_phrase: *const c_char,
+    new_password: *const c_char,
+    user_data: *mut c_void,
+    o_disconnect_notifier_cb: unsafe extern "C" fn(user_data: *mut c_void),
+    o_cb: extern "C" fn(
+        user_data: *mut c_void,
+        result: *const FfiResult,
+        authenticator: *mut Authenticator,
+    ),
+) {
+    let user_data = OpaqueCtx(user_data);
+
+    catch_unwind_cb(user_data, o_cb, || -> Result<_> {
+        trace!("Authenticator - recover a registered client.");
+
+        let recovery_phrase = String::clone_from_repr_c(recovery_phrase)?;
+        let new_password = String::clone_from_repr_c(new_password)?;
+
+        let authenticator =
+            Authenticator::recover_account(recovery_phrase, new_password, move || {
+                o_disconnect_notifier_cb(user_data.0)
+            })?;
+
+        o_cb(
+            user_data.0,
+            FFI_RESULT_OK,
+            Box::into_raw(Box::new(authenticator)),
+        );
+
+        Ok(())
+    })
+}
+
+/// Regain access to a registered account using a backup exported with `auth_export_backup`,
+/// replacing its password with `new_password`. The backup is verified against the network first.
+/// This or any one of the other companion functions to get an authenticator instance must be
+/// called before initiating any operation allowed for authenticator. The `user_data` parameter
+/// corresponds to the first parameter of the `o_cb` and `o_disconnect_notifier_cb` callbacks.
+#[no_mangle]
+pub unsafe extern "C" fn import_backup(
+    backup: *const u8,
+    backup_len: usize,
+    passphrase: *const c_char,
+    new_password: *const c_char,
+    user_data: *mut c_void,
+    o_disconnect_notifier_cb: unsafe extern "C" fn(user_data: *mut c_void),
+    o_cb: extern "C" fn(
+        user_data: *mut c_void,
+        result: *const FfiResult,
+        authenticator: *mut Authenticator,
+    ),
+) {
+    let user_data = OpaqueCtx(user_data);
+
+    catch_unwind_cb(user_data, o_cb, || -> Result<_> {
+        trace!("Authenticator - import an account backup.");
+
+        let backup = vec_clone_from_raw_parts(backup, backup_len);
+        let passphrase = String::clone_from_repr_c(passphrase)?;
+        let new_password = String::clone_from_repr_c(new_password)?;
+
+        let authenticator =
+            Authenticator::import_backup(&backup, passphrase, new_password, move || {
+                o_disconnect_notifier_cb(user_data.0)
+            })?;
+
+        o_cb(
+            user_data.0,
+            FFI_RESULT_OK,
+            Box::into_raw(Box::new(authenticator)),
+        );
+
+        Ok(())
+    })
+}
+
+/// Set up a new recovery phrase for the logged in account. A previously set up phrase stops
+/// working.
+///
+/// Callback parameters: user data, error code, recovery phrase
+#[no_mangle]
+pub unsafe extern "C" fn auth_set_up_recovery(
+    auth: *const Authenticator,
+    user_data: *mut c_void,
+    o_cb: extern "C" fn(
+        user_data: *mut c_void,
+        result: *const FfiResult,
+        recovery_phrase: *const c_char,
+    ),
+) {
+    let user_data = OpaqueCtx(user_data);
+
+    catch_unwind_cb(user_data.0, o_cb, || -> Result<_> {
+        trace!("Authenticator - set up account recovery.");
+
+        (*auth).send(move |client| {
+            client
+                .set_up_recovery()
+                .and_then(move |recovery_phrase| {
+                    let recovery_phrase = CString::new(recovery_phrase)?;
+                    o_cb(user_data.0, FFI_RESULT_OK, recovery_phrase.as_ptr());
+
+                    Ok(())
+                })
+                .map_err(move |e| {
+                    call_result_cb!(Err::<(), _>(Error::from(e)), user_data, o_cb);
+                })
+                .into_box()
+                .into()
+        })?;
+
+        Ok(())
+    })
+}
+
+/// Export a backup of the logged in account, encrypted with `passphrase`. The backup can be used
+/// with `import_backup` to regain access to the account.
+///
+/// Callback parameters: user data, error code, backup, backup size
+#[no_mangle]
+pub unsafe extern "C" fn auth_export_backup(
+    auth: *const Authenticator,
+    passphrase: *const c_char,
+    user_data: *mut c_void,
+    o_cb: extern "C" fn(
+        user_data: *mut c_void,
+        result: *const FfiResult,
+        backup: *const u8,
+        backup_len: usize,
+    ),
+) {
+    let user_data = OpaqueCtx(user_data);
+
+    catch_unwind_cb(user_data.0, o_cb, || -> Result<_> {
+        trace!("Authenticator - export an account backup.");
+
+        let passphrase = String::clone_from_repr_c(passphrase)?;
+
+        (*auth).send(move |client| {
+            backup::export(client, &passphrase)
+                .map(move |backup| {
+                    o_cb(user_data.0, FFI_RESULT_OK, backup.as_ptr(), backup.len());
+                })
+                .map_err(move |e| {
+                    call_result_cb!(Err::<(), _>(Error::from(e)), user_data, o_cb);
+                })
+                .into_box()
+                .into()
+        })?;
+
+        Ok(())
+    })
+}
+
+/// Change the password of the logged in account. The current password is required to confirm
+/// the change.
+#[no_mangle]
+pub unsafe extern "C" fn auth_change_password(
+    auth: *const Authenticator,
+    current_password: *const c_char,
+    new_password: *const c_char,
+    user_data: *mut c_void,
+    o_cb: extern "C" fn(user_data: *mut c_void, result: *const FfiResult),
+) {
+    let user_data = OpaqueCtx(user_data);
+
+    catch_unwind_cb(user_data.0, o_cb, || -> Result<_> {
+        trace!("Authenticator - change the account password.");
+
+        let current_password = String::clone_from_repr_c(current_password)?;
+        let new_password = String::clone_from_repr_c(new_password)?;
+
+        (*auth).send(move |client| {
+            client
+                .change_password(current_password.as_bytes(), new_password.as_bytes())
+                .then(move |res| {
+                    call_result_cb!(res.map_err(Error::from), user_data, o_cb);
+                    Ok(())
+                })
+                .into_box()
+                .into()
+        })
+    })
+}
+
+/// Move the logged in account to a new locator. The password is required to confirm the
+/// change.
+#[no_mangle]
+pub unsafe extern "C" fn auth_change_locator(
+    auth: *const Authenticator,
+    new_locator: *const c_char,
+    password: *const c_char,
+    user_data: *mut c_void,
+    o_cb: extern "C" fn(user_data: *mut c_void, result: *const FfiResult),
+) {
+    let user_data = OpaqueCtx(user_data);
+
+    catch_unwind_cb(user_data.0, o_cb, || -> Result<_> {
+        trace!("Authenticator - change the account locator.");
+
+        let new_locator = String::clone_from_repr_c(new_locator)?;
+        let password = String::clone_from_repr_c(password)?;
+
+        (*auth).send(move |client| {
+            client
+                .change_locator(new_locator.as_bytes(), password.as_bytes())
+                .then(move |res| {
+                    call_result_cb!(res.map_err(Error::from), user_data, o_cb);
+                    Ok(())
+                })
+                .into_box()
+                .into()
+        })
+    })
+}
+
+/// Replace the client keys of the logged in account with new ones. Calling this after an
+/// interrupted rotation resumes it.
+#[no_mangle]
+pub unsafe extern "C" fn auth_rotate_client_keys(
+    auth: *const Authenticator,
+    user_data: *mut c_void,
+    o_cb: extern "C" fn(user_data: *mut c_void, result: *const FfiResult),
+) {
+    let user_data = OpaqueCtx(user_data);
+
+    catch_unwind_cb(user_data.0, o_cb, || -> Result<_> {
+        trace!("Authenticator - rotate the client keys.");
+
+        (*auth).send(move |client| {
+            rotation::rotate_client_keys(client)
+                .then(move |res| {
+                    call_result_cb!(res.map_err(Error::from), user_data, o_cb);
+                    Ok(())
+                })
+                .into_box()
+                .into()
+        })
+    })
+}
+
 /// Try to restore a failed connection with the network.
 #[no_mangle]
 pub unsafe extern "C" fn auth_reconnect(
@@ -136,6 +575,69 @@ pub unsafe extern "C" fn auth_reconnect(
     })
 }
 
+/// Returns the public key of the user's account, which signs sealed IPC responses.
+///
+/// Apps given this key out of band can verify the responses with `open_sealed_ipc_msg`.
+#[no_mangle]
+pub unsafe extern "C" fn auth_account_public_key(
+    auth: *const Authenticator,
+    user_data: *mut c_void,
+    o_cb: extern "C" fn(
+        user_data: *mut c_void,
+        result: *const FfiResult,
+        public_key: *const BlsPublicKey,
+    ),
+) {
+    catch_unwind_cb(user_data, o_cb, || -> Result<_> {
+        let user_data = OpaqueCtx(user_data);
+        (*auth).send(move |client| {
+            let public_key = match client.public_key() {
+                PublicKey::Bls(public_key) => Ok(public_key.to_bytes()),
+                _ => Err(Error::from(AuthError::Unexpected(
+                    "The account key isn't a BLS key".to_owned(),
+                ))),
+            };
+            let public_key = try_cb!(public_key, user_data.0, o_cb);
+
+            o_cb(user_data.0, FFI_RESULT_OK, &public_key);
+            None
+        })
+    })
+}
+
+/// Returns the serialised public ID of the user's account, which `login_with_signer` needs to
+/// log in before the account is fetched.
+#[no_mangle]
+pub unsafe extern "C" fn auth_account_public_id(
+    auth: *const Authenticator,
+    user_data: *mut c_void,
+    o_cb: extern "C" fn(
+        user_data: *mut c_void,
+        result: *const FfiResult,
+        public_id: *const u8,
+        public_id_len: usize,
+    ),
+) {
+    catch_unwind_cb(user_data, o_cb, || -> Result<_> {
+        let user_data = OpaqueCtx(user_data);
+        (*auth).send(move |client| {
+            let public_id = try_cb!(
+                serialize(&client.public_id()).map_err(|err| Error::from(AuthError::from(err))),
+                user_data.0,
+                o_cb
+            );
+
+            o_cb(
+                user_data.0,
+                FFI_RESULT_OK,
+                public_id.as_ptr(),
+                public_id.len(),
+            );
+            None
+        })
+    })
+}
+
 /// Sets the path from which the `safe_core.config` file will be read.
 #[no_mangle]
 pub unsafe extern "C" fn auth_set_config_dir_path(
@@ -172,9 +674,10 @@ mod tests {
     use crate::ffi::auth_is_mock;
     use crate::run;
     use crate::AuthError;
-    use ffi_utils::test_utils::call_1;
+    use ffi_utils::test_utils::{call_0, call_1, call_2, call_vec_u8};
     use futures::Future;
-    use safe_core::{utils, FutureExt};
+    use safe_core::utils::test_utils::TEST_KDF;
+    use safe_core::{utils, CoreError, FutureExt};
     use safe_nd::PubImmutableData;
     use std::ffi::CString;
     use std::os::raw::c_void;
@@ -235,6 +738,220 @@ mod tests {
         }
     }
 
+    // Test changing the password and the locator of an account.
+    // 1. Change the password, checking that the current password is verified.
+    // 2. Verify that only the new password unlocks the account.
+    // 3. Change the locator and verify that only the new locator unlocks the account.
+    #[test]
+    fn change_credentials() {
+        let locator = unwrap!(utils::generate_random_string(10));
+        let password = unwrap!(utils::generate_random_string(10));
+        let new_password = unwrap!(utils::generate_random_string(10));
+        let new_locator = unwrap!(utils::generate_random_string(10));
+
+        let acc_locator = unwrap!(CString::new(locator.clone()));
+        let acc_password = unwrap!(CString::new(password.clone()));
+        let acc_new_password = unwrap!(CString::new(new_password.clone()));
+        let acc_new_locator = unwrap!(CString::new(new_locator.clone()));
+
+        let auth: *mut Authenticator = unsafe {
+            unwrap!(call_1(|ud, cb| create_acc(
+                acc_locator.as_ptr(),
+                acc_password.as_ptr(),
+                ud,
+                disconnect_cb,
+                cb,
+            )))
+        };
+
+        // The current password must match.
+        let res = unsafe {
+            call_0(|ud, cb| {
+                auth_change_password(
+                    auth,
+                    acc_new_password.as_ptr(),
+                    acc_new_password.as_ptr(),
+                    ud,
+                    cb,
+                )
+            })
+        };
+        assert!(res.is_err());
+
+        unsafe {
+            unwrap!(call_0(|ud, cb| auth_change_password(
+                auth,
+                acc_password.as_ptr(),
+                acc_new_password.as_ptr(),
+                ud,
+                cb,
+            )))
+        };
+
+        match Authenticator::login_with_kdf(locator.clone(), password.clone(), TEST_KDF, || ()) {
+            Err(AuthError::CoreError(CoreError::SymmetricDecipherFailure)) => (),
+            Err(x) => panic!("Unexpected {:?}", x),
+            Ok(_) => panic!("Unexpected success"),
+        }
+        let _ = unwrap!(Authenticator::login_with_kdf(
+            locator.clone(),
+            new_password.clone(),
+            TEST_KDF,
+            || ()
+        ));
+
+        // Moving the account is confirmed with the new password.
+        unsafe {
+            unwrap!(call_0(|ud, cb| auth_change_locator(
+                auth,
+                acc_new_locator.as_ptr(),
+                acc_new_password.as_ptr(),
+                ud,
+                cb,
+            )))
+        };
+
+        match Authenticator::login_with_kdf(locator, new_password.clone(), TEST_KDF, || ()) {
+            Err(AuthError::CoreError(CoreError::SymmetricDecipherFailure)) => (),
+            Err(x) => panic!("Unexpected {:?}", x),
+            Ok(_) => panic!("Unexpected success"),
+        }
+        let _ = unwrap!(Authenticator::login_with_kdf(
+            new_locator,
+            new_password,
+            TEST_KDF,
+            || ()
+        ));
+
+        unsafe { auth_free(auth) };
+    }
+
+    // Test regaining access to an account with its recovery phrase.
+    #[test]
+    fn recover_with_phrase() {
+        let locator = unwrap!(utils::generate_random_string(10));
+        let password = unwrap!(utils::generate_random_string(10));
+        let new_password = unwrap!(utils::generate_random_string(10));
+
+        let acc_locator = unwrap!(CString::new(locator.clone()));
+        let acc_password = unwrap!(CString::new(password.clone()));
+        let acc_new_password = unwrap!(CString::new(new_password.clone()));
+
+        let (auth, old_phrase): (*mut Authenticator, String) = unsafe {
+            unwrap!(call_2(|ud, cb| create_acc_with_recovery(
+                acc_locator.as_ptr(),
+                acc_password.as_ptr(),
+                ud,
+                disconnect_cb,
+                cb,
+            )))
+        };
+
+        // Setting up a new phrase disables the previous one.
+        let phrase: String =
+            unsafe { unwrap!(call_1(|ud, cb| auth_set_up_recovery(auth, ud, cb))) };
+        assert_ne!(old_phrase, phrase);
+
+        unsafe { auth_free(auth) };
+
+        assert!(Authenticator::recover_account(old_phrase, new_password.clone(), || ()).is_err());
+        assert!(Authenticator::recover_account(
+            "not a valid recovery phrase".to_string(),
+            new_password.clone(),
+            || ()
+        )
+        .is_err());
+
+        let acc_phrase = unwrap!(CString::new(phrase));
+        let auth: *mut Authenticator = unsafe {
+            unwrap!(call_1(|ud, cb| recover_account(
+                acc_phrase.as_ptr(),
+                acc_new_password.as_ptr(),
+                ud,
+                disconnect_cb,
+                cb,
+            )))
+        };
+        unsafe { auth_free(auth) };
+
+        match Authenticator::login_with_kdf(locator.clone(), password, TEST_KDF, || ()) {
+            Err(AuthError::CoreError(CoreError::SymmetricDecipherFailure)) => (),
+            Err(x) => panic!("Unexpected {:?}", x),
+            Ok(_) => panic!("Unexpected success"),
+        }
+        let _ = unwrap!(Authenticator::login_with_kdf(
+            locator,
+            new_password,
+            TEST_KDF,
+            || ()
+        ));
+    }
+
+    // Test regaining access to an account with an exported backup.
+    #[test]
+    fn export_and_import_backup() {
+        let locator = unwrap!(utils::generate_random_string(10));
+        let password = unwrap!(utils::generate_random_string(10));
+        let passphrase = unwrap!(utils::generate_random_string(10));
+        let new_password = unwrap!(utils::generate_random_string(10));
+
+        let acc_locator = unwrap!(CString::new(locator.clone()));
+        let acc_password = unwrap!(CString::new(password.clone()));
+        let backup_passphrase = unwrap!(CString::new(passphrase));
+        let acc_new_password = unwrap!(CString::new(new_password.clone()));
+
+        let auth: *mut Authenticator = unsafe {
+            unwrap!(call_1(|ud, cb| create_acc(
+                acc_locator.as_ptr(),
+                acc_password.as_ptr(),
+                ud,
+                disconnect_cb,
+                cb,
+            )))
+        };
+
+        let backup = unsafe {
+            unwrap!(call_vec_u8(|ud, cb| auth_export_backup(
+                auth,
+                backup_passphrase.as_ptr(),
+                ud,
+                cb
+            )))
+        };
+        unsafe { auth_free(auth) };
+
+        match Authenticator::import_backup(&backup, "wrong passphrase", "password", || ()) {
+            Err(AuthError::CoreError(CoreError::SymmetricDecipherFailure)) => (),
+            Err(x) => panic!("Unexpected {:?}", x),
+            Ok(_) => panic!("Unexpected success"),
+        }
+
+        let auth: *mut Authenticator = unsafe {
+            unwrap!(call_1(|ud, cb| import_backup(
+                backup.as_ptr(),
+                backup.len(),
+                backup_passphrase.as_ptr(),
+                acc_new_password.as_ptr(),
+                ud,
+                disconnect_cb,
+                cb,
+            )))
+        };
+        unsafe { auth_free(auth) };
+
+        match Authenticator::login_with_kdf(locator.clone(), password, TEST_KDF, || ()) {
+            Err(AuthError::CoreError(CoreError::SymmetricDecipherFailure)) => (),
+            Err(x) => panic!("Unexpected {:?}", x),
+            Ok(_) => panic!("Unexpected success"),
+        }
+        let _ = unwrap!(Authenticator::login_with_kdf(
+            locator,
+            new_password,
+            TEST_KDF,
+            || ()
+        ));
+    }
+
     // Test disconnection and reconnection with the authenticator.
     #[cfg(all(test, feature = "mock-network"))]
     #[ignore] // FIXME: ignoring this test for now until we figure out the disconnection semantics for Phase 1
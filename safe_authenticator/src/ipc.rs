@@ -19,28 +19,84 @@ use ffi_utils::StringError;
 use futures::future::{self, Either};
 use futures::Future;
 use log::trace;
-use safe_core::core_structs::{UserMetadata, METADATA_KEY};
+use lru_cache::LruCache;
+use safe_core::client::SafeKey;
+use safe_core::core_structs::{AppKeys, OwnershipChange, UserMetadata, METADATA_KEY};
 use safe_core::ffi::ipc::resp::MetadataResponse as FfiUserMetadata;
-use safe_core::ipc::req::{IpcReq, ShareMDataReq};
+use safe_core::ipc::req::{
+    IpcReq, OwnedData, ShareAData, ShareADataReq, ShareMDataReq, TransOwnership, TransOwnershipReq,
+};
 use safe_core::ipc::resp::IpcResp;
-use safe_core::ipc::{self, IpcError, IpcMsg};
-use safe_core::{err, ok};
-use safe_core::{Client, CoreError, FutureExt};
-use safe_nd::{Error as SndError, XorName};
+use safe_core::ipc::{self, IpcError, IpcFormat, IpcMsg, SealedResp, IPC_CAP_SEALED_RESP};
+use safe_core::{err, fry, ok};
+use safe_core::{Client, CoreError, CoreFuture, FutureExt};
+use safe_nd::{
+    ADataAddress, ADataIndex, ADataOwner, ADataPubPermissions, ADataUnpubPermissions, ADataUser,
+    Error as SndError, PublicKey, XorName,
+};
+use std::collections::BTreeMap;
 use std::ffi::CString;
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+// Number of unanswered requests whose wire format is remembered.
+const REQ_FORMATS_CACHE_SIZE: usize = 100;
+
+/// Wire formats of the requests an authenticator has decoded, keyed by request ID, so that they
+/// are answered in the same format.
+pub struct ReqFormats(Mutex<LruCache<u32, IpcFormat>>);
+
+impl ReqFormats {
+    /// Remember the format of a decoded request.
+    pub fn insert(&self, req_id: u32, format: IpcFormat) {
+        let _ = self.lock().insert(req_id, format.reply_format());
+    }
+
+    /// Returns the format to answer the request `req_id` in, forgetting the request.
+    ///
+    /// Requests that weren't decoded by this authenticator are answered in the legacy format.
+    pub fn take_reply_format(&self, req_id: u32) -> IpcFormat {
+        self.lock()
+            .remove(&req_id)
+            .unwrap_or_else(IpcFormat::legacy)
+    }
+
+    fn lock(&self) -> MutexGuard<LruCache<u32, IpcFormat>> {
+        // The formats are only a hint, so they are still usable if another thread panicked.
+        self.0.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl Default for ReqFormats {
+    fn default() -> Self {
+        ReqFormats(Mutex::new(LruCache::new(REQ_FORMATS_CACHE_SIZE)))
+    }
+}
 
 /// Decodes a given encoded IPC message and returns either an `IpcMsg` struct or
 /// an error code + description & an encoded `IpcMsg::Resp` in case of an error
+///
+/// `format` is the format the message was received in.
 #[allow(clippy::type_complexity)]
 pub fn decode_ipc_msg(
     client: &AuthClient,
     msg: IpcMsg,
+    format: IpcFormat,
 ) -> Box<AuthFuture<Result<IpcMsg, (i32, String, CString)>>> {
     match msg {
         IpcMsg::Req {
             request: IpcReq::Auth(auth_req),
             req_id,
         } => {
+            if auth_req.response_key.is_some() && !format.has_capability(IPC_CAP_SEALED_RESP) {
+                // The app wouldn't be able to open the sealed response it asks for.
+                return ok!(Err(fry!(reject_req(
+                    req_id,
+                    IpcResp::Auth(Err(IpcError::InvalidMsg)),
+                    IpcError::InvalidMsg,
+                    format.reply_format(),
+                ))));
+            }
+
             // Ok status should be returned for all app states (including
             // Revoked and Authenticated).
             ok!(Ok(IpcMsg::Req {
@@ -62,6 +118,20 @@ pub fn decode_ipc_msg(
             req_id,
             request: IpcReq::ShareMData(share_mdata_req),
         })),
+        IpcMsg::Req {
+            request: IpcReq::ShareAData(share_adata_req),
+            req_id,
+        } => ok!(Ok(IpcMsg::Req {
+            req_id,
+            request: IpcReq::ShareAData(share_adata_req),
+        })),
+        IpcMsg::Req {
+            request: IpcReq::TransOwnership(trans_ownership_req),
+            req_id,
+        } => ok!(Ok(IpcMsg::Req {
+            req_id,
+            request: IpcReq::TransOwnership(trans_ownership_req),
+        })),
         IpcMsg::Req {
             request: IpcReq::Containers(cont_req),
             req_id,
@@ -69,45 +139,122 @@ pub fn decode_ipc_msg(
             trace!("Handling IpcReq::Containers({:?})", cont_req);
 
             let app_id = cont_req.app.id.clone();
-            let c2 = client.clone();
-
-            config::list_apps(client)
-                .and_then(move |(_config_version, config)| app_state(&c2, &config, &app_id))
-                .and_then(move |app_state| {
-                    match app_state {
-                        AppState::Authenticated => Ok(Ok(IpcMsg::Req {
-                            req_id,
-                            request: IpcReq::Containers(cont_req),
-                        })),
-                        AppState::Revoked | AppState::NotAuthenticated => {
-                            // App is not authenticated
-                            let (error_code, description) =
-                                ffi_error!(FFIError::from(IpcError::UnknownApp));
-
-                            let response = IpcMsg::Resp {
-                                response: IpcResp::Auth(Err(IpcError::UnknownApp)),
-                                req_id,
-                            };
-                            let encoded_response = encode_response(&response)?;
+            authenticated_app_req(
+                client,
+                &app_id,
+                req_id,
+                IpcReq::Containers(cont_req),
+                IpcResp::Auth(Err(IpcError::UnknownApp)),
+                format.reply_format(),
+            )
+        }
+        IpcMsg::Req {
+            request: IpcReq::Permissions(perms_req),
+            req_id,
+        } => {
+            trace!("Handling IpcReq::Permissions({:?})", perms_req);
 
-                            Ok(Err((error_code, description, encoded_response)))
-                        }
-                    }
-                })
-                .into_box()
+            let app_id = perms_req.app.id.clone();
+            authenticated_app_req(
+                client,
+                &app_id,
+                req_id,
+                IpcReq::Permissions(perms_req),
+                IpcResp::Permissions(Err(IpcError::UnknownApp)),
+                format.reply_format(),
+            )
         }
-        IpcMsg::Resp { .. } | IpcMsg::Revoked { .. } | IpcMsg::Err(..) => {
+        IpcMsg::Resp { .. } | IpcMsg::Revoked { .. } | IpcMsg::Err(..) | IpcMsg::Sealed(..) => {
             return err!(AuthError::IpcError(IpcError::InvalidMsg));
         }
     }
 }
 
-/// Encode `IpcMsg` into a `CString`, using base32 encoding.
-pub fn encode_response(msg: &IpcMsg) -> Result<CString, IpcError> {
-    let response = ipc::encode_msg(msg)?;
+// Passes the request through if the app is authenticated, otherwise fails with the given
+// response, encoded in `reply_format`.
+#[allow(clippy::type_complexity)]
+fn authenticated_app_req(
+    client: &AuthClient,
+    app_id: &str,
+    req_id: u32,
+    request: IpcReq,
+    unknown_app_response: IpcResp,
+    reply_format: IpcFormat,
+) -> Box<AuthFuture<Result<IpcMsg, (i32, String, CString)>>> {
+    let app_id = app_id.to_string();
+    let c2 = client.clone();
+
+    config::list_apps(client)
+        .and_then(move |(_config_version, config)| app_state(&c2, &config, &app_id))
+        .and_then(move |app_state| {
+            match app_state {
+                AppState::Authenticated => Ok(Ok(IpcMsg::Req { req_id, request })),
+                AppState::Revoked | AppState::NotAuthenticated => {
+                    // App is not authenticated
+                    Ok(Err(reject_req(
+                        req_id,
+                        unknown_app_response,
+                        IpcError::UnknownApp,
+                        reply_format,
+                    )?))
+                }
+            }
+        })
+        .into_box()
+}
+
+// Returns the error code and description of `error`, with the encoded response rejecting the
+// request.
+fn reject_req(
+    req_id: u32,
+    response: IpcResp,
+    error: IpcError,
+    reply_format: IpcFormat,
+) -> Result<(i32, String, CString), IpcError> {
+    let error = FFIError::from(error);
+    let (error_code, description) = ffi_error!(error);
+    let response = encode_response(&IpcMsg::Resp { response, req_id }, reply_format)?;
+    Ok((error_code, description, response))
+}
+
+/// Encode the response to a message that failed to decode with `error`, if the app can be told.
+///
+/// Apps using an unsupported version of the protocol are answered in the legacy format, which
+/// all of them understand.
+pub fn encode_decode_error(error: &IpcError) -> Result<Option<CString>, IpcError> {
+    match *error {
+        IpcError::UnsupportedVersion(version) => Ok(Some(encode_response(
+            &IpcMsg::Err(IpcError::UnsupportedVersion(version)),
+            IpcFormat::legacy(),
+        )?)),
+        _ => Ok(None),
+    }
+}
+
+/// Encode `IpcMsg` into a `CString`, in the given wire format.
+pub fn encode_response(msg: &IpcMsg, format: IpcFormat) -> Result<CString, IpcError> {
+    let response = ipc::encode_msg_as(msg, format)?;
     Ok(CString::new(response).map_err(StringError::from)?)
 }
 
+/// Encode the response `IpcMsg` into a `CString`, sealing it to `response_key` if there is one.
+///
+/// Sealed responses are encrypted to the app's ephemeral key and signed by the user's account.
+pub fn encode_sealed_response(
+    client: &AuthClient,
+    msg: &IpcMsg,
+    response_key: Option<&threshold_crypto::PublicKey>,
+    format: IpcFormat,
+) -> Result<CString, IpcError> {
+    match response_key {
+        Some(key) => {
+            let sealed = SealedResp::seal(msg, key, &client.client_keys().client_id)?;
+            encode_response(&IpcMsg::Sealed(sealed), format)
+        }
+        None => encode_response(msg, format),
+    }
+}
+
 enum ShareMDataError {
     InvalidOwner(XorName, u64),
     InvalidMetadata,
@@ -191,3 +338,341 @@ pub fn decode_share_mdata_req(
         })
         .into_box()
 }
+
+/// Decodes the `ShareAData` IPC request, verifying that the user owns all the requested
+/// `AppendOnlyData`.
+pub fn decode_share_adata_req(client: &AuthClient, req: &ShareADataReq) -> Box<AuthFuture<()>> {
+    let user = client.public_key();
+    let futures: Vec<_> = req
+        .adata
+        .iter()
+        .map(|adata| {
+            let address = adata.address;
+
+            client
+                .get_adata_owners(address, ADataIndex::FromEnd(1))
+                .map(move |owner| {
+                    if owner.public_key == user {
+                        None
+                    } else {
+                        Some((*address.name(), address.tag()))
+                    }
+                })
+                .map_err(AuthError::from)
+        })
+        .collect();
+
+    future::join_all(futures)
+        .and_then(|results| {
+            let invalids: Vec<_> = results.into_iter().filter_map(|invalid| invalid).collect();
+
+            if invalids.is_empty() {
+                Ok(())
+            } else {
+                Err(AuthError::IpcError(IpcError::InvalidOwner(invalids)))
+            }
+        })
+        .into_box()
+}
+
+/// Grants the app with the given key the permissions requested for a shared `AppendOnlyData`,
+/// keeping the permissions of all other users.
+pub fn share_adata(
+    client: &AuthClient,
+    app_pk: PublicKey,
+    adata: ShareAData,
+) -> Box<AuthFuture<()>> {
+    let c2 = client.clone();
+    let c4 = client.clone();
+    let ShareAData { address, perms } = adata;
+
+    client
+        .get_adata_indices(address)
+        .and_then(move |indices| {
+            let permissions_index = indices.permissions_index();
+            let current = ADataIndex::FromEnd(1);
+
+            if address.is_pub() {
+                let c3 = c2.clone();
+                let existing = if permissions_index == 0 {
+                    ok!(BTreeMap::new())
+                } else {
+                    c2.get_pub_adata_permissions_at_index(address, current)
+                        .map(|existing| existing.permissions)
+                        .into_box()
+                };
+
+                existing
+                    .and_then(move |mut permissions| {
+                        let _ = permissions
+                            .insert(ADataUser::Key(app_pk), perms.into_pub_permission_set());
+                        let permissions = ADataPubPermissions {
+                            permissions,
+                            entries_index: indices.entries_index(),
+                            owners_index: indices.owners_index(),
+                        };
+                        c3.add_pub_adata_permissions(address, permissions, permissions_index)
+                    })
+                    .into_box()
+            } else {
+                let c3 = c2.clone();
+                let existing = if permissions_index == 0 {
+                    ok!(BTreeMap::new())
+                } else {
+                    c2.get_unpub_adata_permissions_at_index(address, current)
+                        .map(|existing| existing.permissions)
+                        .into_box()
+                };
+
+                existing
+                    .and_then(move |mut permissions| {
+                        let _ = permissions.insert(app_pk, perms.into_unpub_permission_set());
+                        let permissions = ADataUnpubPermissions {
+                            permissions,
+                            entries_index: indices.entries_index(),
+                            owners_index: indices.owners_index(),
+                        };
+                        c3.add_unpub_adata_permissions(address, permissions, permissions_index)
+                    })
+                    .into_box()
+            }
+        })
+        .map_err(AuthError::from)
+        .and_then(move |()| track_owned_adata(&c4, address, true))
+        .into_box()
+}
+
+/// Decodes the `TransOwnership` IPC request, verifying that the authenticator can transfer all
+/// the requested data and returning a user-facing description of each ownership change.
+///
+/// Data can be transferred from the user to any key, or from the requesting app to the user.
+pub fn decode_trans_ownership_req(
+    client: &AuthClient,
+    req: &TransOwnershipReq,
+) -> Box<AuthFuture<Vec<OwnershipChange>>> {
+    let c2 = client.clone();
+    let data = req.data.clone();
+    let app_name = req.app.name.clone();
+    let num_data = data.len();
+
+    registered_app_keys(client, &req.app.id)
+        .and_then(move |app_keys| {
+            let user = c2.public_key();
+            let futures: Vec<_> = data
+                .into_iter()
+                .map(|item| {
+                    let TransOwnership { address, new_owner } = item;
+                    let app_keys = app_keys.clone();
+                    let app_name = app_name.clone();
+
+                    current_owner(&c2, address)
+                        .map(move |current_owner| {
+                            let grantor = ownership_grantor(
+                                user,
+                                app_keys.as_ref(),
+                                current_owner,
+                                new_owner,
+                            )
+                            .ok_or((*address.name(), address.tag()))?;
+
+                            let description = match grantor {
+                                _ if new_owner == current_owner => {
+                                    format!("{} is already owned by your account", address)
+                                }
+                                Grantor::User => format!(
+                                    "Ownership of {} will be transferred from your account to {:?}",
+                                    address, new_owner
+                                ),
+                                Grantor::App(_) => format!(
+                                    "Ownership of {} will be transferred from {} to your account",
+                                    address, app_name
+                                ),
+                            };
+
+                            Ok(OwnershipChange {
+                                current_owner,
+                                new_owner,
+                                description,
+                            })
+                        })
+                        .map_err(AuthError::from)
+                })
+                .collect();
+
+            future::join_all(futures)
+        })
+        .and_then(move |results| {
+            let mut changes = Vec::with_capacity(num_data);
+            let mut invalids = Vec::with_capacity(num_data);
+
+            for result in results {
+                match result {
+                    Ok(change) => changes.push(change),
+                    Err(invalid) => invalids.push(invalid),
+                }
+            }
+
+            if invalids.is_empty() {
+                Ok(changes)
+            } else {
+                Err(AuthError::IpcError(IpcError::InvalidOwner(invalids)))
+            }
+        })
+        .into_box()
+}
+
+/// Returns the keys of the app with the given ID, or `None` if it isn't registered.
+pub fn registered_app_keys(client: &AuthClient, app_id: &str) -> Box<AuthFuture<Option<AppKeys>>> {
+    config::get_app(client, app_id)
+        .map(|app| Some(app.keys))
+        .or_else(|error| match error {
+            AuthError::IpcError(IpcError::UnknownApp) => Ok(None),
+            error => Err(error),
+        })
+        .into_box()
+}
+
+/// Transfers the ownership of a `MutableData` or `AppendOnlyData` to the requested key. The data
+/// must be owned either by the user, or by the app with the given keys if it's transferred to
+/// the user.
+pub fn trans_ownership(
+    client: &AuthClient,
+    item: TransOwnership,
+    app_keys: Option<AppKeys>,
+) -> Box<AuthFuture<()>> {
+    let c2 = client.clone();
+    let user = client.public_key();
+    let TransOwnership { address, new_owner } = item;
+
+    current_owner(client, address)
+        .map_err(AuthError::from)
+        .and_then(move |current_owner| {
+            match ownership_grantor(user, app_keys.as_ref(), current_owner, new_owner) {
+                Some(_) if new_owner == current_owner => ok!(()),
+                Some(grantor) => change_owner(&c2, address, new_owner, grantor),
+                None => err!(AuthError::IpcError(IpcError::InvalidOwner(vec![(
+                    *address.name(),
+                    address.tag()
+                )]))),
+            }
+        })
+        .into_box()
+}
+
+// Key on whose behalf the authenticator transfers the ownership of data.
+enum Grantor {
+    User,
+    App(SafeKey),
+}
+
+// The user can give away the data it owns, and adopt the data owned by the requesting app.
+fn ownership_grantor(
+    user: PublicKey,
+    app_keys: Option<&AppKeys>,
+    current_owner: PublicKey,
+    new_owner: PublicKey,
+) -> Option<Grantor> {
+    if current_owner == user {
+        return Some(Grantor::User);
+    }
+
+    app_keys
+        .filter(|app_keys| current_owner == app_keys.public_key() && new_owner == user)
+        .map(|app_keys| Grantor::App(app_keys.app_safe_key()))
+}
+
+fn change_owner(
+    client: &AuthClient,
+    address: OwnedData,
+    new_owner: PublicKey,
+    grantor: Grantor,
+) -> Box<AuthFuture<()>> {
+    let c2 = client.clone();
+    let signer = match grantor {
+        Grantor::User => client.full_id(),
+        Grantor::App(app_key) => app_key,
+    };
+
+    match address {
+        OwnedData::MData(address) => {
+            let name = *address.name();
+            let tag = address.tag();
+            let version = if address.is_seq() {
+                client
+                    .get_seq_mdata_shell(name, tag)
+                    .map(|shell| shell.version())
+                    .into_box()
+            } else {
+                client
+                    .get_unseq_mdata_shell(name, tag)
+                    .map(|shell| shell.version())
+                    .into_box()
+            };
+
+            version
+                .and_then(move |version| {
+                    c2.change_mdata_owner_as(address, new_owner, version + 1, &signer)
+                })
+                .map_err(AuthError::from)
+                .into_box()
+        }
+        OwnedData::AData(address) => {
+            let c3 = client.clone();
+            let owned = new_owner == client.public_key();
+
+            client
+                .get_adata_indices(address)
+                .and_then(move |indices| {
+                    let owner = ADataOwner {
+                        public_key: new_owner,
+                        entries_index: indices.entries_index(),
+                        permissions_index: indices.permissions_index(),
+                    };
+                    c2.set_adata_owners_as(address, owner, indices.owners_index(), &signer)
+                })
+                .map_err(AuthError::from)
+                .and_then(move |()| track_owned_adata(&c3, address, owned))
+                .into_box()
+        }
+    }
+}
+
+// Records whether the account owns the `AppendOnlyData`, so that it's deleted along with the
+// account.
+fn track_owned_adata(
+    client: &AuthClient,
+    address: ADataAddress,
+    owned: bool,
+) -> Box<AuthFuture<()>> {
+    let c2 = client.clone();
+
+    config::list_owned_adata(client)
+        .and_then(move |(version, adata)| {
+            let version = config::next_version(version);
+
+            if owned {
+                config::add_owned_adata(&c2, adata, version, address)
+            } else {
+                config::remove_owned_adata(&c2, adata, version, address)
+            }
+        })
+        .map(|_| ())
+        .into_box()
+}
+
+fn current_owner(client: &AuthClient, address: OwnedData) -> Box<CoreFuture<PublicKey>> {
+    match address {
+        OwnedData::MData(address) if address.is_seq() => client
+            .get_seq_mdata_shell(*address.name(), address.tag())
+            .map(|shell| *shell.owner())
+            .into_box(),
+        OwnedData::MData(address) => client
+            .get_unseq_mdata_shell(*address.name(), address.tag())
+            .map(|shell| *shell.owner())
+            .into_box(),
+        OwnedData::AData(address) => client
+            .get_adata_owners(address, ADataIndex::FromEnd(1))
+            .map(|owner| owner.public_key)
+            .into_box(),
+    }
+}
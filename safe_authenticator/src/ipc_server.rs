@@ -0,0 +1,501 @@
+// Copyright 2019 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+//! Local IPC transports for desktop authenticators.
+//!
+//! Authorisation requests are normally handed to the authenticator through a URI scheme. The
+//! servers in this module accept the same encoded requests over a Unix domain socket or a
+//! localhost HTTP endpoint instead, so that CLI tools and browsers on the same machine can be
+//! authorised by a running authenticator.
+//!
+//! Over the Unix domain socket a request is a single line containing the encoded `IpcMsg`, and
+//! the encoded response is written back as a single line. Over HTTP the encoded request is the
+//! body of a `POST` and the response is the body of the reply. HTTP requests must come from one of
+//! the allowed origins, and once an app is authorised from an origin, requests for that app from
+//! anywhere else are denied.
+//!
+//! Each connection is served on its own thread, so a client waiting for the user's consent doesn't
+//! hold up the others.
+
+use crate::app_auth;
+use crate::ffi::errors::Error as FFIError;
+use crate::ipc::{decode_ipc_msg, encode_sealed_response};
+use crate::{run, AuthError, AuthFuture, Authenticator};
+use futures::Future;
+use log::{debug, warn};
+use safe_core::ipc::req::IpcReq;
+use safe_core::ipc::resp::IpcResp;
+use safe_core::ipc::{self, AuthGranted, AuthReq, IpcError, IpcMsg};
+use safe_core::{ok, FutureExt};
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{Ipv4Addr, SocketAddr, TcpListener, TcpStream};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+use unwrap::unwrap;
+
+#[cfg(unix)]
+use std::fs;
+#[cfg(unix)]
+use std::io::{Error as IoError, ErrorKind};
+#[cfg(unix)]
+use std::os::unix::fs::{DirBuilderExt, FileTypeExt, PermissionsExt};
+#[cfg(unix)]
+use std::os::unix::net::{UnixListener, UnixStream};
+#[cfg(unix)]
+use std::path::{Path, PathBuf};
+
+// Maximum size of an encoded request.
+const MAX_REQUEST_SIZE: usize = 64 * 1024;
+// Maximum number of connections served at once.
+const MAX_CONNECTIONS: usize = 16;
+// How long to wait for a client to send its request.
+const READ_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Asks the user whether to grant an authorisation request received over a local transport.
+///
+/// Receives the request and the origin it came from, which is `None` for the Unix domain socket.
+pub type ConsentFn = dyn Fn(&AuthReq, Option<&str>) -> bool + Send + Sync;
+
+// Origin an app is pinned to.
+struct Pin {
+    origin: Option<String>,
+    // Whether the app was authorised from `origin`. Until then the pin only lasts while requests
+    // from `origin` are being handled.
+    is_authorised: bool,
+    pending: usize,
+}
+
+/// Handles encoded IPC requests on behalf of the local transports.
+pub struct IpcHandler {
+    authenticator: Arc<Authenticator>,
+    consent: Box<ConsentFn>,
+    pinned_origins: Mutex<HashMap<String, Pin>>,
+}
+
+impl IpcHandler {
+    /// Create a new handler, granting the requests `consent` agrees to.
+    pub fn new(authenticator: Arc<Authenticator>, consent: Box<ConsentFn>) -> Self {
+        Self {
+            authenticator,
+            consent,
+            pinned_origins: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Handle an encoded IPC request from the given origin, returning the encoded response.
+    ///
+    /// Only authorisation requests are served.
+    pub fn handle(&self, request: &str, origin: Option<&str>) -> String {
+        match self.handle_auth_req(request.trim(), origin) {
+            Ok(response) => response,
+            Err(err) => {
+                debug!("Local IPC request failed: {}", err);
+                let err: IpcError = FFIError::from(err).into();
+                ipc::encode_msg(&IpcMsg::Err(err)).unwrap_or_default()
+            }
+        }
+    }
+
+    fn handle_auth_req(&self, request: &str, origin: Option<&str>) -> Result<String, AuthError> {
+        let (msg, format) = ipc::decode_msg_with_format(request)?;
+        match msg {
+            IpcMsg::Req {
+                request: IpcReq::Auth(_),
+                ..
+            } => (),
+            _ => {
+                return Err(AuthError::IpcError(IpcError::from(
+                    "Only authorisation requests are served over this transport",
+                )));
+            }
+        }
+
+        let decoded = run(&self.authenticator, move |client| {
+            decode_ipc_msg(client, msg, format)
+        })?;
+
+        let (req_id, auth_req) = match decoded {
+            Ok(IpcMsg::Req {
+                req_id,
+                request: IpcReq::Auth(auth_req),
+            }) => (req_id, auth_req),
+            Ok(_) => return Err(AuthError::IpcError(IpcError::InvalidMsg)),
+            Err((_, _, response)) => return Ok(response.to_str()?.to_owned()),
+        };
+
+        let app_id = auth_req.app.id.clone();
+        let response_key = auth_req.response_key;
+        // Pinning the origin before asking for consent stops concurrent requests from other
+        // origins being granted while the user decides.
+        let is_pinned = self.pin_origin(&app_id, origin);
+        let is_granted = is_pinned && (self.consent)(&auth_req, origin);
+
+        let result = run(&self.authenticator, move |client| {
+            let c2 = client.clone();
+
+            let result: Box<AuthFuture<Result<AuthGranted, IpcError>>> = if is_granted {
+                app_auth::authenticate(client, auth_req)
+                    .then(|result| -> Result<_, AuthError> {
+                        Ok(result.map_err(|err| FFIError::from(err).into()))
+                    })
+                    .into_box()
+            } else {
+                ok!(Err(IpcError::AuthDenied))
+            };
+
+            result.and_then(move |result| {
+                let is_authorised = result.is_ok();
+                let msg = IpcMsg::Resp {
+                    req_id,
+                    response: IpcResp::Auth(result),
+                };
+                let response = encode_sealed_response(
+                    &c2,
+                    &msg,
+                    response_key.as_ref(),
+                    format.reply_format(),
+                )?;
+                Ok((response, is_authorised))
+            })
+        });
+
+        if is_pinned {
+            let is_authorised = result.as_ref().map(|(_, is_authorised)| *is_authorised);
+            self.unpin_origin(&app_id, is_authorised.unwrap_or(false));
+        }
+
+        Ok(result?.0.to_str()?.to_owned())
+    }
+
+    // Pins the app to `origin` while its request is handled. Returns `false` if the app is pinned
+    // to a different origin.
+    fn pin_origin(&self, app_id: &str, origin: Option<&str>) -> bool {
+        let mut pins = unwrap!(self.pinned_origins.lock());
+        let pin = pins.entry(app_id.to_owned()).or_insert_with(|| Pin {
+            origin: origin.map(str::to_owned),
+            is_authorised: false,
+            pending: 0,
+        });
+
+        if pin.origin.as_ref().map(String::as_str) != origin {
+            return false;
+        }
+        pin.pending += 1;
+        true
+    }
+
+    // Releases the pin taken by `pin_origin`, keeping it for good if the app was authorised.
+    fn unpin_origin(&self, app_id: &str, is_authorised: bool) {
+        let mut pins = unwrap!(self.pinned_origins.lock());
+        if let Entry::Occupied(mut entry) = pins.entry(app_id.to_owned()) {
+            let pin = entry.get_mut();
+            pin.pending -= 1;
+            pin.is_authorised |= is_authorised;
+
+            if !pin.is_authorised && pin.pending == 0 {
+                let _ = entry.remove();
+            }
+        }
+    }
+}
+
+enum Listener {
+    #[cfg(unix)]
+    Unix(UnixListener),
+    Http(TcpListener, Vec<String>),
+}
+
+/// Serves IPC requests over a local transport until dropped.
+pub struct IpcServer {
+    addr: IpcServerAddr,
+    stop: Arc<AtomicBool>,
+    joiner: Option<JoinHandle<()>>,
+}
+
+/// Address an `IpcServer` listens on.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum IpcServerAddr {
+    /// Path of a Unix domain socket.
+    #[cfg(unix)]
+    Unix(PathBuf),
+    /// Localhost HTTP endpoint.
+    Http(SocketAddr),
+}
+
+impl IpcServer {
+    /// Serve requests over a Unix domain socket at `path`, accessible only to the current user.
+    ///
+    /// A stale socket left at `path` is replaced, but not one another server still listens on.
+    #[cfg(unix)]
+    pub fn unix<P: AsRef<Path>>(handler: Arc<IpcHandler>, path: P) -> Result<Self, AuthError> {
+        let path = path.as_ref().to_path_buf();
+        if let Ok(metadata) = fs::symlink_metadata(&path) {
+            if !metadata.file_type().is_socket() {
+                return Err(AuthError::from(IoError::new(
+                    ErrorKind::AlreadyExists,
+                    format!("{} exists and is not a socket", path.display()),
+                )));
+            }
+            if UnixStream::connect(&path).is_ok() {
+                return Err(AuthError::from(IoError::new(
+                    ErrorKind::AddrInUse,
+                    format!("{} is in use by another server", path.display()),
+                )));
+            }
+        }
+
+        // The socket is bound in a directory only the current user can enter and moved into place
+        // once its permissions are restricted, so nobody else can connect to it in between.
+        let parent = path.parent().unwrap_or_else(|| Path::new("."));
+        let private_dir = parent.join(format!(".safe_ipc_{:016x}", rand::random::<u64>()));
+        fs::DirBuilder::new().mode(0o700).create(&private_dir)?;
+
+        let tmp_path = private_dir.join("socket");
+        let result = UnixListener::bind(&tmp_path).and_then(|listener| {
+            fs::set_permissions(&tmp_path, fs::Permissions::from_mode(0o600))?;
+            fs::rename(&tmp_path, &path)?;
+            Ok(listener)
+        });
+        let _ = fs::remove_file(&tmp_path);
+        let _ = fs::remove_dir(&private_dir);
+        let listener = result?;
+
+        Ok(Self::start(
+            handler,
+            Listener::Unix(listener),
+            IpcServerAddr::Unix(path),
+        ))
+    }
+
+    /// Serve requests over HTTP on the loopback interface.
+    ///
+    /// Only requests with one of the `allowed_origins` in their `Origin` header are served,
+    /// e.g. `http://localhost:3000`. Pass port `0` to pick any free port.
+    pub fn http(
+        handler: Arc<IpcHandler>,
+        port: u16,
+        allowed_origins: Vec<String>,
+    ) -> Result<Self, AuthError> {
+        let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, port))?;
+        let addr = listener.local_addr()?;
+
+        Ok(Self::start(
+            handler,
+            Listener::Http(listener, allowed_origins),
+            IpcServerAddr::Http(addr),
+        ))
+    }
+
+    /// Returns the address the server listens on.
+    pub fn addr(&self) -> &IpcServerAddr {
+        &self.addr
+    }
+
+    fn start(handler: Arc<IpcHandler>, listener: Listener, addr: IpcServerAddr) -> Self {
+        let stop = Arc::new(AtomicBool::new(false));
+        let stop2 = Arc::clone(&stop);
+        let connections = Arc::new(AtomicUsize::new(0));
+
+        let joiner = thread::spawn(move || match listener {
+            #[cfg(unix)]
+            Listener::Unix(listener) => {
+                for stream in listener.incoming() {
+                    if stop2.load(Ordering::SeqCst) {
+                        break;
+                    }
+                    match stream {
+                        Ok(stream) => {
+                            let handler = Arc::clone(&handler);
+                            spawn_connection(&connections, move || serve_unix(&handler, stream));
+                        }
+                        Err(err) => warn!("Failed to accept local IPC connection: {}", err),
+                    }
+                }
+            }
+            Listener::Http(listener, allowed_origins) => {
+                let allowed_origins = Arc::new(allowed_origins);
+                for stream in listener.incoming() {
+                    if stop2.load(Ordering::SeqCst) {
+                        break;
+                    }
+                    match stream {
+                        Ok(stream) => {
+                            let handler = Arc::clone(&handler);
+                            let allowed_origins = Arc::clone(&allowed_origins);
+                            spawn_connection(&connections, move || {
+                                serve_http(&handler, &allowed_origins, stream)
+                            });
+                        }
+                        Err(err) => warn!("Failed to accept local IPC connection: {}", err),
+                    }
+                }
+            }
+        });
+
+        Self {
+            addr,
+            stop,
+            joiner: Some(joiner),
+        }
+    }
+}
+
+// Serves a connection on its own thread. Connections over `MAX_CONNECTIONS` are dropped.
+fn spawn_connection<F>(connections: &Arc<AtomicUsize>, serve: F)
+where
+    F: FnOnce() -> Result<(), AuthError> + Send + 'static,
+{
+    // Decrements the number of connections when the connection is done with, even on panic.
+    struct Guard(Arc<AtomicUsize>);
+
+    impl Drop for Guard {
+        fn drop(&mut self) {
+            let _ = self.0.fetch_sub(1, Ordering::SeqCst);
+        }
+    }
+
+    let guard = Guard(Arc::clone(connections));
+    if connections.fetch_add(1, Ordering::SeqCst) >= MAX_CONNECTIONS {
+        warn!("Too many local IPC connections, dropping one");
+        return;
+    }
+
+    let _ = thread::spawn(move || {
+        let _guard = guard;
+        if let Err(err) = serve() {
+            warn!("Failed to serve local IPC request: {}", err);
+        }
+    });
+}
+
+impl Drop for IpcServer {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::SeqCst);
+
+        // Wake up the listener so it notices it should stop.
+        match self.addr {
+            #[cfg(unix)]
+            IpcServerAddr::Unix(ref path) => {
+                let _ = UnixStream::connect(path);
+                let _ = fs::remove_file(path);
+            }
+            IpcServerAddr::Http(addr) => {
+                let _ = TcpStream::connect(addr);
+            }
+        }
+
+        if let Some(joiner) = self.joiner.take() {
+            let _ = joiner.join();
+        }
+    }
+}
+
+#[cfg(unix)]
+fn serve_unix(handler: &IpcHandler, mut stream: UnixStream) -> Result<(), AuthError> {
+    stream.set_read_timeout(Some(READ_TIMEOUT))?;
+
+    let mut request = String::new();
+    let _ = BufReader::new(&stream)
+        .take(MAX_REQUEST_SIZE as u64)
+        .read_line(&mut request)?;
+
+    let response = handler.handle(&request, None);
+    writeln!(stream, "{}", response)?;
+    Ok(())
+}
+
+fn serve_http(
+    handler: &IpcHandler,
+    allowed_origins: &[String],
+    mut stream: TcpStream,
+) -> Result<(), AuthError> {
+    stream.set_read_timeout(Some(READ_TIMEOUT))?;
+    let mut reader = BufReader::new(stream.try_clone()?).take(MAX_REQUEST_SIZE as u64);
+
+    let mut line = String::new();
+    let _ = reader.read_line(&mut line)?;
+    let method = line.split_whitespace().next().unwrap_or("").to_owned();
+
+    let mut origin = None;
+    let mut content_length = 0;
+    loop {
+        line.clear();
+        if reader.read_line(&mut line)? == 0 {
+            return write_http(&mut stream, "400 Bad Request", None, "");
+        }
+        let header = line.trim_end();
+        if header.is_empty() {
+            break;
+        }
+        if let Some(idx) = header.find(':') {
+            let value = header[idx + 1..].trim();
+            match header[..idx].trim().to_ascii_lowercase().as_str() {
+                "origin" => origin = Some(value.to_owned()),
+                "content-length" => content_length = value.parse().unwrap_or(usize::max_value()),
+                _ => (),
+            }
+        }
+    }
+
+    // The body is read even if the request is refused, as closing the connection with unread data
+    // resets it before the client gets the response.
+    let mut body = Vec::new();
+    if content_length <= MAX_REQUEST_SIZE {
+        body.resize(content_length, 0);
+        reader.read_exact(&mut body)?;
+    }
+
+    let origin = match origin {
+        Some(origin) if allowed_origins.contains(&origin) => origin,
+        _ => return write_http(&mut stream, "403 Forbidden", None, ""),
+    };
+
+    match method.as_str() {
+        // CORS preflight.
+        "OPTIONS" => write_http(&mut stream, "204 No Content", Some(&origin), ""),
+        "POST" if content_length <= MAX_REQUEST_SIZE => {
+            let request = String::from_utf8(body)?;
+
+            let response = handler.handle(&request, Some(&origin));
+            write_http(&mut stream, "200 OK", Some(&origin), &response)
+        }
+        "POST" => write_http(&mut stream, "413 Payload Too Large", Some(&origin), ""),
+        _ => write_http(&mut stream, "405 Method Not Allowed", Some(&origin), ""),
+    }
+}
+
+fn write_http(
+    stream: &mut TcpStream,
+    status: &str,
+    origin: Option<&str>,
+    body: &str,
+) -> Result<(), AuthError> {
+    write!(stream, "HTTP/1.1 {}\r\n", status)?;
+    if let Some(origin) = origin {
+        write!(
+            stream,
+            "Access-Control-Allow-Origin: {}\r\n\
+             Access-Control-Allow-Methods: POST, OPTIONS\r\n\
+             Access-Control-Allow-Headers: Content-Type\r\n\
+             Vary: Origin\r\n",
+            origin
+        )?;
+    }
+    write!(
+        stream,
+        "Content-Type: text/plain\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        body.len(),
+        body
+    )?;
+    stream.flush()?;
+    Ok(())
+}
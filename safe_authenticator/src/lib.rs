@@ -35,6 +35,10 @@
 // Export FFI interface
 
 pub use crate::ffi::apps::*;
+pub use crate::ffi::audit::*;
+pub use crate::ffi::coins::*;
+pub use crate::ffi::deletion::*;
+pub use crate::ffi::devices::*;
 pub use crate::ffi::errors::codes::*;
 pub use crate::ffi::ipc::*;
 pub use crate::ffi::logging::*;
@@ -46,14 +50,22 @@ pub use self::errors::AuthError;
 pub use client::AuthClient;
 
 pub mod access_container;
+pub mod allowances;
 pub mod app_auth;
 pub mod app_container;
 pub mod apps;
+pub mod audit;
+pub mod backup;
 pub mod config;
+pub mod deletion;
+pub mod devices;
 pub mod errors;
 pub mod ffi;
 pub mod ipc;
+pub mod ipc_server;
+pub mod recovery;
 pub mod revocation;
+pub mod rotation;
 #[cfg(any(test, feature = "testing"))]
 pub mod test_utils;
 
@@ -62,21 +74,25 @@ mod std_dirs;
 #[cfg(test)]
 mod tests;
 
+use crate::devices::DeviceKey;
 use crate::ffi::errors::Error;
+use crate::ipc::ReqFormats;
 use futures::stream::Stream;
 use futures::sync::mpsc;
 use futures::{Future, IntoFuture};
-use log::{debug, info};
+use log::{debug, info, warn};
+use safe_core::client::account::Kdf;
+use safe_core::client::Signer;
 use safe_core::ok;
 #[cfg(any(test, feature = "testing"))]
 use safe_core::utils::test_utils::gen_client_id;
 #[cfg(feature = "mock-network")]
 use safe_core::ConnectionManager;
 use safe_core::{event_loop, CoreMsg, CoreMsgTx, FutureExt, NetworkEvent, NetworkTx};
-use safe_nd::ClientFullId;
+use safe_nd::{ClientFullId, XorName};
 use std::sync::mpsc as std_mpsc;
 use std::sync::mpsc::sync_channel;
-use std::sync::Mutex;
+use std::sync::{Arc, Mutex};
 use std::thread::JoinHandle;
 use tokio::runtime::current_thread::{Handle, Runtime};
 use unwrap::unwrap;
@@ -101,6 +117,8 @@ macro_rules! try_tx {
 pub struct Authenticator {
     /// Channel to communicate with the core event loop.
     pub core_tx: Mutex<AuthMsgTx>,
+    /// Wire formats of the requests being answered.
+    pub req_formats: ReqFormats,
     _core_joiner: JoinHandle<()>,
 }
 
@@ -122,6 +140,52 @@ impl Authenticator {
         client_id: ClientFullId,
         disconnect_notifier: N,
     ) -> Result<Self, AuthError>
+    where
+        N: FnMut() + Send + 'static,
+        S: Into<String>,
+    {
+        Self::create_acc_with_kdf(
+            locator,
+            password,
+            client_id,
+            Kdf::default(),
+            disconnect_notifier,
+        )
+    }
+
+    /// Create a new account and set up a recovery phrase for it. Returns the authenticator along
+    /// with the phrase, which should be shown to the user to be written down. If the phrase
+    /// can't be set up, the account is created nonetheless and `None` is returned instead, in
+    /// which case `set_up_recovery` should be retried.
+    pub fn create_acc_with_recovery<S, N>(
+        locator: S,
+        password: S,
+        client_id: ClientFullId,
+        disconnect_notifier: N,
+    ) -> Result<(Self, Option<String>), AuthError>
+    where
+        N: FnMut() + Send + 'static,
+        S: Into<String>,
+    {
+        let authenticator = Self::create_acc(locator, password, client_id, disconnect_notifier)?;
+        let recovery_phrase = authenticator
+            .set_up_recovery()
+            .map_err(|error| warn!("Failed to set up a recovery phrase: {:?}", error))
+            .ok();
+
+        Ok((authenticator, recovery_phrase))
+    }
+
+    /// Create a new account, encrypting it using `kdf` instead of `Kdf::default()`. Weaker
+    /// functions make the password easier to guess for anyone holding the account packet, so
+    /// this is only meant for tests.
+    pub fn create_acc_with_kdf<S, N>(
+        locator: S,
+        password: S,
+        client_id: ClientFullId,
+        kdf: Kdf,
+        disconnect_notifier: N,
+    ) -> Result<Self, AuthError>
     where
         N: FnMut() + Send + 'static,
         S: Into<String>,
@@ -131,7 +195,7 @@ impl Authenticator {
 
         Self::create_acc_impl(
             move |el_h, core_tx, net_tx| {
-                AuthClient::registered(&locator, &password, client_id, el_h, core_tx, net_tx)
+                AuthClient::registered(&locator, &password, client_id, kdf, el_h, core_tx, net_tx)
             },
             disconnect_notifier,
         )
@@ -203,12 +267,29 @@ impl Authenticator {
 
         Ok(Self {
             core_tx: Mutex::new(core_tx),
+            req_formats: ReqFormats::default(),
             _core_joiner: joiner,
         })
     }
 
     /// Log in to an existing account
     pub fn login<S, N>(locator: S, password: S, disconnect_notifier: N) -> Result<Self, AuthError>
+    where
+        S: Into<String>,
+        N: FnMut() + Send + 'static,
+    {
+        Self::login_with_kdf(locator, password, Kdf::default(), disconnect_notifier)
+    }
+
+    /// Log in to an existing account, re-encrypting it using `kdf` instead of `Kdf::default()`
+    /// if it was encrypted using another function. Weaker functions make the password easier to
+    /// guess for anyone holding the account packet, so this is only meant for tests.
+    pub fn login_with_kdf<S, N>(
+        locator: S,
+        password: S,
+        kdf: Kdf,
+        disconnect_notifier: N,
+    ) -> Result<Self, AuthError>
     where
         S: Into<String>,
         N: FnMut() + Send + 'static,
@@ -218,12 +299,165 @@ impl Authenticator {
 
         Self::login_impl(
             move |el_h, core_tx, net_tx| {
-                AuthClient::login(&locator, &password, el_h, core_tx, net_tx)
+                AuthClient::login(&locator, &password, kdf, el_h, core_tx, net_tx)
             },
             disconnect_notifier,
         )
     }
 
+    /// Log in to an existing account whose requests are signed by `signer`, e.g. a hardware
+    /// wallet holding the account's key, instead of the secret key stored in the account. Fails
+    /// if the signer doesn't hold the account's key.
+    pub fn login_with_signer<S, N>(
+        locator: S,
+        password: S,
+        signer: Arc<dyn Signer>,
+        disconnect_notifier: N,
+    ) -> Result<Self, AuthError>
+    where
+        S: Into<String>,
+        N: FnMut() + Send + 'static,
+    {
+        let locator = locator.into();
+        let password = password.into();
+
+        Self::login_impl(
+            move |el_h, core_tx, net_tx| {
+                AuthClient::login_with_signer(
+                    &locator,
+                    &password,
+                    signer,
+                    Kdf::default(),
+                    el_h,
+                    core_tx,
+                    net_tx,
+                )
+            },
+            disconnect_notifier,
+        )
+    }
+
+    /// Log in to an existing account from a registered device, using the copy of the account
+    /// stored for it. Fails with `AuthError::UnknownDevice` if the device isn't registered or
+    /// was logged out.
+    pub fn login_device<S, N>(
+        locator: S,
+        password: S,
+        device_key: DeviceKey,
+        disconnect_notifier: N,
+    ) -> Result<Self, AuthError>
+    where
+        S: Into<String>,
+        N: FnMut() + Send + 'static,
+    {
+        Self::login_device_with_kdf(
+            locator,
+            password,
+            device_key,
+            Kdf::default(),
+            disconnect_notifier,
+        )
+    }
+
+    /// Log in to an existing account from a registered device, encrypting the account packet
+    /// using `kdf` instead of `Kdf::default()` when it's updated. Weaker functions make the
+    /// password easier to guess for anyone holding the account packet, so this is only meant for
+    /// tests.
+    pub fn login_device_with_kdf<S, N>(
+        locator: S,
+        password: S,
+        device_key: DeviceKey,
+        kdf: Kdf,
+        disconnect_notifier: N,
+    ) -> Result<Self, AuthError>
+    where
+        S: Into<String>,
+        N: FnMut() + Send + 'static,
+    {
+        let locator = locator.into();
+        let password = password.into();
+
+        let authenticator = Self::login_impl(
+            move |el_h, core_tx, net_tx| {
+                AuthClient::login_device(
+                    &locator,
+                    &password,
+                    &device_key,
+                    kdf,
+                    el_h,
+                    core_tx,
+                    net_tx,
+                )
+            },
+            disconnect_notifier,
+        )?;
+        let _ = run(&authenticator, move |client| {
+            devices::log_in(client, &device_key)
+        })?;
+
+        Ok(authenticator)
+    }
+
+    /// Regain access to an existing account using its recovery phrase, replacing the password
+    /// with `new_password`. The account locator stays the same.
+    pub fn recover_account<S, N>(
+        recovery_phrase: S,
+        new_password: S,
+        disconnect_notifier: N,
+    ) -> Result<Self, AuthError>
+    where
+        S: Into<String>,
+        N: FnMut() + Send + 'static,
+    {
+        let recovery_phrase = recovery_phrase.into();
+        let new_password = new_password.into();
+
+        Self::login_impl(
+            move |el_h, core_tx, net_tx| {
+                AuthClient::recover(
+                    &recovery_phrase,
+                    &new_password,
+                    Kdf::default(),
+                    el_h,
+                    core_tx,
+                    net_tx,
+                )
+            },
+            disconnect_notifier,
+        )
+    }
+
+    /// Regain access to an existing account using a backup exported with `export_backup`,
+    /// replacing the password with `new_password`. The backup is verified against the network
+    /// first. The account locator stays the one the account had when the backup was exported.
+    /// Apps from the backup the account doesn't know about anymore are registered again.
+    pub fn import_backup<S, N>(
+        backup: &[u8],
+        passphrase: S,
+        new_password: S,
+        disconnect_notifier: N,
+    ) -> Result<Self, AuthError>
+    where
+        S: Into<String>,
+        N: FnMut() + Send + 'static,
+    {
+        let mut backup = backup::open(backup, &passphrase.into())?;
+        let (apps, app_entries, app_expiries) = backup.take_apps();
+        let new_password = new_password.into();
+
+        let authenticator = Self::login_impl(
+            move |el_h, core_tx, net_tx| {
+                AuthClient::restore(backup, &new_password, Kdf::default(), el_h, core_tx, net_tx)
+            },
+            disconnect_notifier,
+        )?;
+        run(&authenticator, move |client| {
+            backup::restore_apps(client, apps, app_entries, app_expiries)
+        })?;
+
+        Ok(authenticator)
+    }
+
     /// Log in to an existing account.
     pub fn login_impl<F: Send + 'static, N>(
         create_client_fn: F,
@@ -293,13 +527,116 @@ impl Authenticator {
             }
         };
 
+        // Re-encrypt account packets that still use an outdated key derivation function.
+        core_tx.unbounded_send(CoreMsg::new(|client: &AuthClient, &()| {
+            if !client.kdf_outdated() {
+                return None;
+            }
+
+            client
+                .update_account_packet()
+                .map_err(|error| warn!("Failed to upgrade the account packet: {:?}", error))
+                .into_box()
+                .into()
+        }))?;
+
+        // Revoke the apps whose authorisation expired while the user was logged out.
+        core_tx.unbounded_send(CoreMsg::new(|client, &()| {
+            revocation::revoke_expired_apps(client)
+                .map(|_| ())
+                .map_err(|error| warn!("Failed to revoke expired apps: {:?}", error))
+                .into_box()
+                .into()
+        }))?;
+
+        // Refill the daily coin allowances that became due while the user was logged out.
+        core_tx.unbounded_send(CoreMsg::new(|client, &()| {
+            allowances::refill_allowances(client)
+                .map_err(|error| warn!("Failed to refill coin allowances: {:?}", error))
+                .into_box()
+                .into()
+        }))?;
+
         Ok(Self {
             core_tx: Mutex::new(core_tx),
+            req_formats: ReqFormats::default(),
             _core_joiner: joiner,
         })
     }
 }
 
+impl Authenticator {
+    /// Change the password of the logged in account. The current password is required to
+    /// confirm the change.
+    pub fn change_password<S>(&self, current_password: S, new_password: S) -> Result<(), AuthError>
+    where
+        S: Into<String>,
+    {
+        let current_password = current_password.into();
+        let new_password = new_password.into();
+
+        run(self, move |client| {
+            client.change_password(current_password.as_bytes(), new_password.as_bytes())
+        })
+    }
+
+    /// Move the logged in account to a new locator. The password is required to confirm the
+    /// change.
+    pub fn change_locator<S>(&self, new_locator: S, password: S) -> Result<(), AuthError>
+    where
+        S: Into<String>,
+    {
+        let new_locator = new_locator.into();
+        let password = password.into();
+
+        run(self, move |client| {
+            client.change_locator(new_locator.as_bytes(), password.as_bytes())
+        })
+    }
+
+    /// Replace the client keys of the logged in account with new ones. Calling this after an
+    /// interrupted rotation resumes it.
+    pub fn rotate_client_keys(&self) -> Result<(), AuthError> {
+        run(self, rotation::rotate_client_keys)
+    }
+
+    /// Set up a new recovery phrase for the logged in account and return it. A previously set up
+    /// phrase stops working.
+    pub fn set_up_recovery(&self) -> Result<String, AuthError> {
+        run(self, |client| client.set_up_recovery())
+    }
+
+    /// Delete the logged in account, transferring its coin balance to `destination`. The
+    /// password is required to confirm the deletion. If `dry_run` is set, nothing is changed and
+    /// the report lists what would be deleted.
+    pub fn delete_account<S>(
+        &self,
+        password: S,
+        destination: XorName,
+        dry_run: bool,
+    ) -> Result<deletion::DeletionReport, AuthError>
+    where
+        S: Into<String>,
+    {
+        let password = password.into();
+
+        run(self, move |client| {
+            deletion::delete_account(client, password.as_bytes(), destination, dry_run)
+        })
+    }
+
+    /// Export a backup of the logged in account, encrypted with `passphrase`. The backup can be
+    /// used with `import_backup` to regain access to the account.
+    pub fn export_backup<S>(&self, passphrase: S) -> Result<Vec<u8>, AuthError>
+    where
+        S: Into<String>,
+    {
+        let passphrase = passphrase.into();
+
+        run(self, move |client| backup::export(client, &passphrase))
+    }
+}
+
 /// Run the given closure inside the event loop of the authenticator. The closure
 /// should return a future which will then be driven to completion and its result
 /// returned.
@@ -328,8 +665,12 @@ where
 
 #[cfg(any(test, feature = "testing"))]
 impl Authenticator {
-    /// Create a new account with given seed.
-    pub fn create_acc_with_seed<S, N>(seed: S, disconnect_notifier: N) -> Result<Self, AuthError>
+    /// Create a new account with given seed, encrypted using `kdf`.
+    pub fn create_acc_with_seed<S, N>(
+        seed: S,
+        kdf: Kdf,
+        disconnect_notifier: N,
+    ) -> Result<Self, AuthError>
     where
         S: Into<String>,
         N: FnMut() + Send + 'static,
@@ -338,21 +679,28 @@ impl Authenticator {
         let client_id = gen_client_id();
         Self::login_impl(
             move |el_h, core_tx, net_tx| {
-                AuthClient::registered_with_seed(&seed, client_id, el_h, core_tx, net_tx)
+                AuthClient::registered_with_seed(&seed, client_id, kdf, el_h, core_tx, net_tx)
             },
             disconnect_notifier,
         )
     }
 
     /// Login to an existing account using the same seed that was used during account creation.
-    pub fn login_with_seed<S, N>(seed: S, disconnect_notifier: N) -> Result<Self, AuthError>
+    /// The account is re-encrypted using `kdf` if it was encrypted using another function.
+    pub fn login_with_seed<S, N>(
+        seed: S,
+        kdf: Kdf,
+        disconnect_notifier: N,
+    ) -> Result<Self, AuthError>
     where
         S: Into<String>,
         N: FnMut() + Send + 'static,
     {
         let seed = seed.into();
         Self::login_impl(
-            move |el_h, core_tx, net_tx| AuthClient::login_with_seed(&seed, el_h, core_tx, net_tx),
+            move |el_h, core_tx, net_tx| {
+                AuthClient::login_with_seed(&seed, kdf, el_h, core_tx, net_tx)
+            },
             disconnect_notifier,
         )
     }
@@ -365,6 +713,7 @@ impl Authenticator {
         locator: S,
         password: S,
         client_id: ClientFullId,
+        kdf: Kdf,
         disconnect_notifier: N,
         connection_manager_wrapper_fn: F,
     ) -> Result<Self, AuthError>
@@ -382,6 +731,7 @@ impl Authenticator {
                     &locator,
                     &password,
                     client_id,
+                    kdf,
                     el_h,
                     core_tx_clone,
                     net_tx,
@@ -396,6 +746,7 @@ impl Authenticator {
     fn login_with_hook<F, S, N>(
         locator: S,
         password: S,
+        kdf: Kdf,
         disconnect_notifier: N,
         connection_manager_wrapper_fn: F,
     ) -> Result<Self, AuthError>
@@ -412,6 +763,7 @@ impl Authenticator {
                 AuthClient::login_with_hook(
                     &locator,
                     &password,
+                    kdf,
                     el_h,
                     core_tx,
                     net_tx,
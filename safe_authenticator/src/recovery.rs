@@ -0,0 +1,83 @@
+// Copyright 2019 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+//! Account recovery using a mnemonic phrase.
+//!
+//! The recovery phrase is a BIP39 mnemonic from which a key pair is derived. A copy of the
+//! account, along with the location of its account packet, is stored on the network encrypted to
+//! the public key, in a packet of its own. The public key is kept in the user's config root so the
+//! copy can be refreshed whenever the account packet is updated. Knowing the phrase is enough to
+//! decrypt the copy and set a new password, without knowing the old one.
+
+use crate::AuthError;
+use bincode::{deserialize, serialize};
+use bip39::{Language, Mnemonic, MnemonicType, Seed};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use safe_core::client::account::Account;
+use safe_core::crypto::SecretBytes;
+use safe_core::CoreError;
+use safe_nd::XorName;
+use serde::{Deserialize, Serialize};
+use threshold_crypto::{Ciphertext, PublicKey, SecretKey};
+use tiny_keccak::sha3_256;
+
+// Domain separator for the location of the recovery packet.
+const RECOVERY_LOCATION_TAG: &[u8] = b"account-recovery";
+
+/// Data needed to regain access to an account, stored encrypted to the recovery public key.
+#[derive(Deserialize, Serialize)]
+pub(crate) struct RecoveryData {
+    /// Location of the account packet.
+    pub acc_loc: XorName,
+    /// PIN derived from the account locator.
+    pub pin: SecretBytes,
+    /// The account itself.
+    pub account: Account,
+}
+
+/// Generates a new random recovery phrase.
+pub fn generate_phrase() -> String {
+    Mnemonic::new(MnemonicType::Words24, Language::English).into_phrase()
+}
+
+/// Derives the recovery secret key from the given phrase. Fails if the phrase is not a valid
+/// mnemonic.
+pub(crate) fn secret_key(phrase: &str) -> Result<SecretKey, AuthError> {
+    let mnemonic = Mnemonic::from_phrase(phrase, Language::English)
+        .map_err(|_| AuthError::from("Invalid recovery phrase"))?;
+    let seed = Seed::new(&mnemonic, "");
+    let mut rng = StdRng::from_seed(sha3_256(seed.as_bytes()));
+
+    Ok(rng.gen())
+}
+
+/// Returns the location of the recovery packet belonging to the given recovery key.
+pub(crate) fn location(public_key: &PublicKey) -> XorName {
+    let mut input = RECOVERY_LOCATION_TAG.to_vec();
+    input.extend_from_slice(&public_key.to_bytes());
+
+    XorName(sha3_256(&input))
+}
+
+/// Encrypts the recovery data to the given recovery key.
+pub(crate) fn seal(public_key: &PublicKey, data: &RecoveryData) -> Result<Vec<u8>, AuthError> {
+    let ciphertext = public_key.encrypt(serialize(data)?);
+    Ok(serialize(&ciphertext)?)
+}
+
+/// Decrypts recovery data using the recovery secret key.
+pub(crate) fn open(secret_key: &SecretKey, sealed: &[u8]) -> Result<RecoveryData, AuthError> {
+    let ciphertext: Ciphertext =
+        deserialize(sealed).map_err(|_| CoreError::AsymmetricDecipherFailure)?;
+    let plaintext = secret_key
+        .decrypt(&ciphertext)
+        .ok_or(CoreError::AsymmetricDecipherFailure)?;
+
+    Ok(deserialize(&plaintext)?)
+}
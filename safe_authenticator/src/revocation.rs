@@ -10,10 +10,13 @@
 
 use super::{AuthError, AuthFuture};
 use crate::access_container;
+use crate::app_auth::{app_state, AppState};
+use crate::audit;
 use crate::client::AuthClient;
-use crate::config::{self, AppInfo, RevocationQueue};
+use crate::config::{self, AppInfo, AuditDecision, RevocationQueue};
+use chrono::Utc;
 use futures::future::{self, Either, Loop};
-use futures::Future;
+use futures::{stream, Future, Stream};
 use log::trace;
 use safe_core::recoverable_apis;
 use safe_core::{client::AuthActions, Client, CoreError, FutureExt, MDataInfo};
@@ -57,6 +60,76 @@ pub fn flush_app_revocation_queue(client: &AuthClient) -> Box<AuthFuture<()>> {
         .into_box()
 }
 
+/// Push the authenticated apps whose authorisation has expired onto the revocation queue.
+/// Returns the IDs of the apps that were pushed.
+pub fn enqueue_expired_apps(client: &AuthClient) -> Box<AuthFuture<Vec<String>>> {
+    let client = client.clone();
+    let c2 = client.clone();
+    let now = Utc::now();
+
+    config::list_apps(&client)
+        .join(config::list_app_expiries(&client))
+        .and_then(move |((_, apps), (_, expiries))| {
+            let states: Vec<_> = expiries
+                .iter()
+                .filter(|(_, expires_at)| **expires_at <= now)
+                .filter_map(|(hash, _)| apps.get(hash))
+                .map(|app| {
+                    let app_id = app.info.id.clone();
+                    app_state(&client, &apps, &app_id).map(move |state| (app_id, state))
+                })
+                .collect();
+
+            future::join_all(states)
+        })
+        .and_then(move |states| {
+            let expired: Vec<_> = states
+                .into_iter()
+                .filter(|(_, state)| *state == AppState::Authenticated)
+                .map(|(app_id, _)| app_id)
+                .collect();
+
+            stream::iter_ok::<_, AuthError>(expired)
+                .and_then(move |app_id| {
+                    let c3 = c2.clone();
+
+                    trace!("Authorisation of app with ID {} has expired", app_id);
+
+                    config::get_app_revocation_queue(&c2).and_then(move |(version, queue)| {
+                        config::push_to_app_revocation_queue(
+                            &c3,
+                            queue,
+                            config::next_version(version),
+                            &app_id,
+                        )
+                        .map(move |_| app_id)
+                    })
+                })
+                .collect()
+        })
+        .into_box()
+}
+
+/// Revoke the authenticated apps whose authorisation has expired.
+/// Returns the IDs of the expired apps.
+///
+/// Meant to be called periodically by the authenticator. It is also run on login.
+pub fn revoke_expired_apps(client: &AuthClient) -> Box<AuthFuture<Vec<String>>> {
+    let client = client.clone();
+
+    enqueue_expired_apps(&client)
+        .and_then(move |expired| {
+            if expired.is_empty() {
+                ok!(expired)
+            } else {
+                flush_app_revocation_queue(&client)
+                    .map(move |()| expired)
+                    .into_box()
+            }
+        })
+        .into_box()
+}
+
 // Try to revoke all apps in the revocation queue. If app revocation results in an error, move the
 // app to the back of the queue. Keep track of failed apps and if one fails again after moving to
 // the end of the queue, return its error. In other words, we revoke all the apps that we can and
@@ -128,6 +201,9 @@ fn revoke_single_app(client: &AuthClient, app_id: &str) -> Box<AuthFuture<()>> {
     let c2 = client.clone();
     let c3 = client.clone();
     let c4 = client.clone();
+    let c5 = client.clone();
+
+    let app_id = app_id.to_string();
 
     // 1. Delete the app key from the Client Handlers
     // 2. Remove the app key from containers permissions
@@ -136,7 +212,8 @@ fn revoke_single_app(client: &AuthClient, app_id: &str) -> Box<AuthFuture<()>> {
     //    make sure that we use correct encryption keys if the previous revoke
     //    attempt has failed)
     // 4. Remove the revoked app from the access container
-    config::get_app(client, app_id)
+    // 5. Record the revocation in the audit log
+    config::get_app(client, &app_id)
         .and_then(move |app| delete_app_auth_key(&c2, app.keys.public_key()).map(move |_| app))
         .and_then(move |app| {
             access_container::fetch_entry(&c3, &app.info.id, app.keys.clone()).and_then(
@@ -156,6 +233,7 @@ fn revoke_single_app(client: &AuthClient, app_id: &str) -> Box<AuthFuture<()>> {
                 },
             )
         })
+        .and_then(move |()| audit::record_done(&c5, &app_id, AuditDecision::Revoked))
         .into_box()
 }
 
@@ -0,0 +1,599 @@
+// Copyright 2019 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+//! Client key rotation.
+//!
+//! Rotating replaces the keys the account uses to access the network with freshly generated ones:
+//!
+//! 1. New client keys and a re-encrypted copy of the config root info are generated and stored
+//!    in the config root, so an interrupted rotation can be resumed.
+//! 2. The config root entries are copied under the new encryption keys, and the authenticator
+//!    entry of the access container under the new symmetric key.
+//! 3. The old and the new keys are given full permissions on the access container, the config
+//!    root and the default containers.
+//! 4. The ownership of the containers and of the audit log is handed over to the new keys.
+//! 5. The coin balance is moved to the new keys.
+//! 6. The account packet is updated to use the new keys.
+//! 7. The entries encrypted with the old keys are deleted and the permissions of the old keys
+//!    are revoked.
+//!
+//! The network doesn't support changing the owner of mutable data yet, so against it the old
+//! keys remain the owners of the existing containers, as well as of the unpublished immutable
+//! data stored before the rotation. Apps authorised before the rotation keep acting on behalf of
+//! the old keys and have to be authorised again.
+
+use super::{AuthError, AuthFuture};
+use crate::access_container::{self, AUTHENTICATOR_ENTRY};
+use crate::client::AuthClient;
+use crate::config::{self, KeyRotation};
+use futures::{future, stream, Future, Stream};
+use log::{trace, warn};
+use rand::thread_rng;
+use safe_core::{
+    err, fry, ok, recoverable_apis, Client, ClientKeys, CoreError, CoreFuture, FutureExt,
+};
+use safe_nd::{
+    ADataIndex, ADataOwner, ADataUnpubPermissionSet, ADataUnpubPermissions, Coins,
+    Error as SndError, MDataAction, MDataAddress, MDataPermissionSet, MDataSeqEntryActions,
+    PublicKey,
+};
+use std::collections::BTreeMap;
+use unwrap::unwrap;
+
+/// Rotate the client keys of the account, or resume a rotation that was interrupted. Keys held by
+/// an external signer can't be rotated.
+pub fn rotate_client_keys(client: &AuthClient) -> Box<AuthFuture<()>> {
+    // The new keys would be held in memory, taking the account away from its signer.
+    if client.has_external_signer() {
+        return err!(AuthError::Unexpected(
+            "Keys held by an external signer can't be rotated".to_string()
+        ));
+    }
+
+    let c2 = client.clone();
+    let c3 = client.clone();
+
+    config::get_key_rotation(client)
+        .and_then(move |(version, rotation)| match rotation {
+            Some(rotation) => ok!((version, rotation)),
+            None => start_rotation(&c2, version),
+        })
+        .and_then(move |(version, rotation)| resume_rotation(&c3, version, rotation))
+        .into_box()
+}
+
+// Generate the new keys and store them as the progress of the rotation.
+fn start_rotation(
+    client: &AuthClient,
+    version: Option<u64>,
+) -> Box<AuthFuture<(Option<u64>, KeyRotation)>> {
+    trace!("Starting client key rotation...");
+
+    let mut config_root = client.config_root_dir();
+    config_root.start_new_enc_info();
+    config_root.commit_new_enc_info();
+
+    let rotation = KeyRotation {
+        old_keys: client.client_keys(),
+        new_keys: ClientKeys::new(&mut thread_rng()),
+        config_root,
+        moving_balance: false,
+    };
+
+    config::set_key_rotation(client, None, config::next_version(version), Some(rotation))
+        .and_then(|(version, rotation)| {
+            let rotation = rotation.ok_or_else(|| AuthError::from("Key rotation not stored"))?;
+            Ok((Some(version), rotation))
+        })
+        .into_box()
+}
+
+fn resume_rotation(
+    client: &AuthClient,
+    version: Option<u64>,
+    rotation: KeyRotation,
+) -> Box<AuthFuture<()>> {
+    if rotation.new_keys.public_key() == client.public_key() {
+        // The account packet has been updated already.
+        return finish_rotation(client, rotation);
+    }
+
+    let new_pk = rotation.new_keys.public_key();
+    let old_keys = rotation.old_keys.clone();
+    let old_keys2 = rotation.old_keys.clone();
+    let c2 = client.clone();
+    let c3 = client.clone();
+    let c4 = client.clone();
+    let c5 = client.clone();
+    let c6 = client.clone();
+
+    let prepared = if rotation.moving_balance {
+        // The data was prepared before the balance started moving.
+        ok!(())
+    } else {
+        let c7 = client.clone();
+        let c8 = client.clone();
+        let c9 = client.clone();
+        let new_keys = rotation.new_keys.clone();
+        let users = vec![rotation.old_keys.public_key(), new_pk];
+        let moving_balance = KeyRotation {
+            moving_balance: true,
+            ..rotation.clone()
+        };
+        let rotation2 = rotation.clone();
+
+        copy_config_entries(client, &rotation)
+            .and_then(move |()| copy_authenticator_entry(&c7, &new_keys))
+            .and_then(move |()| grant_container_perms(&c8, users))
+            .and_then(move |()| {
+                config::set_key_rotation(
+                    &c9,
+                    Some(rotation2),
+                    config::next_version(version),
+                    Some(moving_balance),
+                )
+            })
+            .map(|_| ())
+            .into_box()
+    };
+
+    prepared
+        .and_then(move |()| hand_over_containers(&c2, &old_keys, new_pk))
+        .and_then(move |()| hand_over_audit_log(&c3, &old_keys2, new_pk))
+        .and_then(move |()| move_balance(&c4, new_pk))
+        .and_then(move |()| {
+            c5.switch_client_keys(rotation.new_keys.clone(), rotation.config_root.clone())
+                .map(move |()| rotation)
+        })
+        .and_then(move |rotation| finish_rotation(&c6, rotation))
+        .into_box()
+}
+
+// Delete the entries encrypted with the old keys, revoke the permissions of the old keys and
+// mark the rotation as finished.
+fn finish_rotation(client: &AuthClient, rotation: KeyRotation) -> Box<AuthFuture<()>> {
+    trace!("Finishing client key rotation...");
+
+    let old_pk = rotation.old_keys.public_key();
+    let c2 = client.clone();
+    let c3 = client.clone();
+    let c4 = client.clone();
+    let c5 = client.clone();
+    let c6 = client.clone();
+
+    delete_old_config_entries(client)
+        .and_then(move |()| delete_old_authenticator_entry(&c2, &rotation.old_keys))
+        .and_then(move |()| revoke_container_perms(&c3, old_pk))
+        .and_then(move |()| revoke_audit_log_perms(&c4, old_pk))
+        .and_then(move |()| config::get_key_rotation(&c5))
+        .and_then(move |(version, rotation)| {
+            config::set_key_rotation(&c6, rotation, config::next_version(version), None)
+        })
+        .map(|_| ())
+        .into_box()
+}
+
+// Copy the config root entries encrypted with the current keys under the new ones. Copies made
+// by an earlier attempt are refreshed.
+fn copy_config_entries(client: &AuthClient, rotation: &KeyRotation) -> Box<AuthFuture<()>> {
+    let old_info = client.config_root_dir();
+    let new_info = rotation.config_root.clone();
+    let c2 = client.clone();
+
+    client
+        .list_seq_mdata_entries(old_info.name(), old_info.type_tag())
+        .map_err(AuthError::from)
+        .and_then(move |entries| {
+            let mut actions = MDataSeqEntryActions::new();
+
+            for (key, value) in &entries {
+                let plain_key = match old_info.decrypt(key) {
+                    Ok(plain_key) => plain_key,
+                    // Not encrypted with the current keys.
+                    Err(_) => continue,
+                };
+                let new_key = new_info.enc_entry_key(&plain_key)?;
+                let new_value = new_info.enc_entry_value(&old_info.decrypt(&value.data)?)?;
+
+                actions = match entries.get(&new_key) {
+                    Some(copy) => actions.update(new_key, new_value, copy.version + 1),
+                    None => actions.ins(new_key, new_value, 0),
+                };
+            }
+
+            Ok((old_info, actions))
+        })
+        .and_then(move |(old_info, actions)| {
+            c2.mutate_seq_mdata_entries(old_info.name(), old_info.type_tag(), actions)
+                .map_err(AuthError::from)
+        })
+        .into_box()
+}
+
+// Delete the config root entries that can't be decrypted with the current keys.
+fn delete_old_config_entries(client: &AuthClient) -> Box<AuthFuture<()>> {
+    let info = client.config_root_dir();
+    let c2 = client.clone();
+
+    client
+        .list_seq_mdata_entries(info.name(), info.type_tag())
+        .map_err(AuthError::from)
+        .and_then(move |entries| {
+            let actions = entries
+                .into_iter()
+                .filter(|(key, _)| info.decrypt(key).is_err())
+                .fold(MDataSeqEntryActions::new(), |actions, (key, value)| {
+                    actions.del(key, value.version + 1)
+                });
+
+            if actions.actions().is_empty() {
+                ok!(())
+            } else {
+                c2.mutate_seq_mdata_entries(info.name(), info.type_tag(), actions)
+                    .map_err(AuthError::from)
+                    .into_box()
+            }
+        })
+        .into_box()
+}
+
+// Store the authenticator entry of the access container under the new symmetric key.
+fn copy_authenticator_entry(client: &AuthClient, new_keys: &ClientKeys) -> Box<AuthFuture<()>> {
+    let access_container = client.access_container();
+    let new_enc_key = new_keys.enc_key.clone();
+    let new_key = fry!(access_container::enc_key(
+        &access_container,
+        AUTHENTICATOR_ENTRY,
+        &new_enc_key
+    ));
+
+    let c2 = client.clone();
+    let c3 = client.clone();
+
+    access_container::fetch_authenticator_entry(client)
+        .and_then(move |(_, containers)| {
+            let ciphertext =
+                access_container::encode_authenticator_entry(&containers, &new_enc_key)?;
+            Ok((access_container, ciphertext))
+        })
+        .and_then(move |(access_container, ciphertext)| {
+            c2.get_seq_mdata_value(
+                access_container.name(),
+                access_container.type_tag(),
+                new_key.clone(),
+            )
+            .then(move |result| match result {
+                Ok(copy) => {
+                    Ok(MDataSeqEntryActions::new().update(new_key, ciphertext, copy.version + 1))
+                }
+                Err(CoreError::DataError(SndError::NoSuchEntry)) => {
+                    Ok(MDataSeqEntryActions::new().ins(new_key, ciphertext, 0))
+                }
+                Err(error) => Err(AuthError::from(error)),
+            })
+            .and_then(move |actions| {
+                c3.mutate_seq_mdata_entries(
+                    access_container.name(),
+                    access_container.type_tag(),
+                    actions,
+                )
+                .map_err(AuthError::from)
+            })
+        })
+        .into_box()
+}
+
+// Delete the authenticator entry stored under the old symmetric key, if it's still there.
+fn delete_old_authenticator_entry(
+    client: &AuthClient,
+    old_keys: &ClientKeys,
+) -> Box<AuthFuture<()>> {
+    let access_container = client.access_container();
+    let old_key = fry!(access_container::enc_key(
+        &access_container,
+        AUTHENTICATOR_ENTRY,
+        &old_keys.enc_key
+    ));
+    let c2 = client.clone();
+
+    client
+        .get_seq_mdata_value(
+            access_container.name(),
+            access_container.type_tag(),
+            old_key.clone(),
+        )
+        .then(move |result| match result {
+            Ok(value) => {
+                let actions = MDataSeqEntryActions::new().del(old_key, value.version + 1);
+                c2.mutate_seq_mdata_entries(
+                    access_container.name(),
+                    access_container.type_tag(),
+                    actions,
+                )
+                .map_err(AuthError::from)
+                .into_box()
+            }
+            Err(CoreError::DataError(SndError::NoSuchEntry)) => ok!(()),
+            Err(error) => future::err(AuthError::from(error)).into_box(),
+        })
+        .into_box()
+}
+
+// Addresses of the containers owned by the account: the access container, the config root and
+// the default containers.
+fn owned_containers(client: &AuthClient) -> Box<AuthFuture<Vec<MDataAddress>>> {
+    let mut addresses: Vec<MDataAddress> = vec![
+        *client.access_container().address(),
+        *client.config_root_dir().address(),
+    ];
+
+    access_container::fetch_authenticator_entry(client)
+        .map(move |(_, containers)| {
+            addresses.extend(containers.values().map(|info| *info.address()));
+            addresses
+        })
+        .into_box()
+}
+
+// Give the users full permissions on the containers owned by the account.
+fn grant_container_perms(client: &AuthClient, users: Vec<PublicKey>) -> Box<AuthFuture<()>> {
+    let c2 = client.clone();
+    let perms = MDataPermissionSet::new()
+        .allow(MDataAction::Read)
+        .allow(MDataAction::Insert)
+        .allow(MDataAction::Update)
+        .allow(MDataAction::Delete)
+        .allow(MDataAction::ManagePermissions);
+
+    owned_containers(client)
+        .and_then(move |addresses| {
+            let reqs: Vec<_> = addresses
+                .into_iter()
+                .map(|address| {
+                    let c3 = c2.clone();
+                    let perms = perms.clone();
+
+                    // Permissions of the same container are set one user at a time, as each
+                    // change bumps its version.
+                    stream::iter_ok(users.clone()).for_each(move |user| {
+                        let c4 = c3.clone();
+                        let perms = perms.clone();
+
+                        c3.get_mdata_version(address)
+                            .and_then(move |version| {
+                                recoverable_apis::set_mdata_user_permissions(
+                                    &c4,
+                                    address,
+                                    user,
+                                    perms,
+                                    version + 1,
+                                )
+                            })
+                            .map_err(AuthError::from)
+                    })
+                })
+                .collect();
+
+            future::join_all(reqs).map(|_| ())
+        })
+        .into_box()
+}
+
+// Make the new keys the owner of the containers still owned by the old keys.
+fn hand_over_containers(
+    client: &AuthClient,
+    old_keys: &ClientKeys,
+    new_pk: PublicKey,
+) -> Box<AuthFuture<()>> {
+    let c2 = client.clone();
+    let old_pk = old_keys.public_key();
+    let signer = old_keys.client_safe_key();
+
+    owned_containers(client)
+        .and_then(move |addresses| {
+            let reqs: Vec<_> = addresses
+                .into_iter()
+                .map(|address| {
+                    let c3 = c2.clone();
+                    let signer = signer.clone();
+
+                    mdata_owner(&c2, address)
+                        .and_then(move |(owner, version)| {
+                            if owner != old_pk {
+                                // Handed over by an earlier attempt.
+                                return ok!(());
+                            }
+
+                            c3.change_mdata_owner_as(address, new_pk, version + 1, &signer)
+                                .or_else(move |error| match error {
+                                    CoreError::OperationForbidden => {
+                                        warn!(
+                                            "Can't change the owner of {:?}, it stays owned by \
+                                             the old client keys.",
+                                            address
+                                        );
+                                        Ok(())
+                                    }
+                                    error => Err(error),
+                                })
+                                .into_box()
+                        })
+                        .map_err(AuthError::from)
+                })
+                .collect();
+
+            future::join_all(reqs).map(|_| ())
+        })
+        .into_box()
+}
+
+// Revoke the permissions of the old keys on the containers owned by the account.
+fn revoke_container_perms(client: &AuthClient, old_pk: PublicKey) -> Box<AuthFuture<()>> {
+    let c2 = client.clone();
+
+    owned_containers(client)
+        .and_then(move |addresses| {
+            let reqs: Vec<_> = addresses
+                .into_iter()
+                .map(|address| {
+                    let c3 = c2.clone();
+
+                    c2.get_mdata_version(address)
+                        .and_then(move |version| {
+                            recoverable_apis::del_mdata_user_permissions(
+                                &c3,
+                                address,
+                                old_pk,
+                                version + 1,
+                            )
+                        })
+                        .map_err(AuthError::from)
+                })
+                .collect();
+
+            future::join_all(reqs).map(|_| ())
+        })
+        .into_box()
+}
+
+// Returns the owner and the version of the given mutable data.
+fn mdata_owner(client: &AuthClient, address: MDataAddress) -> Box<CoreFuture<(PublicKey, u64)>> {
+    if address.is_seq() {
+        client
+            .get_seq_mdata_shell(*address.name(), address.tag())
+            .map(|shell| (*shell.owner(), shell.version()))
+            .into_box()
+    } else {
+        client
+            .get_unseq_mdata_shell(*address.name(), address.tag())
+            .map(|shell| (*shell.owner(), shell.version()))
+            .into_box()
+    }
+}
+
+// Make the new keys the owner of the audit log, if there is one. Both keys keep full
+// permissions on it until the rotation finishes.
+fn hand_over_audit_log(
+    client: &AuthClient,
+    old_keys: &ClientKeys,
+    new_pk: PublicKey,
+) -> Box<AuthFuture<()>> {
+    let c2 = client.clone();
+    let old_pk = old_keys.public_key();
+    let signer = old_keys.client_safe_key();
+
+    config::get_audit_log(client)
+        .and_then(move |(_, log)| {
+            let address = match log {
+                Some(log) => log.address,
+                None => return ok!(()),
+            };
+            let c3 = c2.clone();
+            let c4 = c2.clone();
+
+            c2.get_adata_owners(address, ADataIndex::FromEnd(1))
+                .join(c2.get_adata_indices(address))
+                .and_then(move |(owner, indices)| {
+                    if owner.public_key == new_pk {
+                        // Handed over by an earlier attempt.
+                        return ok!(());
+                    }
+
+                    let permissions: BTreeMap<_, _> = vec![old_pk, new_pk]
+                        .into_iter()
+                        .map(|user| (user, ADataUnpubPermissionSet::new(true, true, true)))
+                        .collect();
+                    let permissions = ADataUnpubPermissions {
+                        permissions,
+                        entries_index: indices.entries_index(),
+                        owners_index: indices.owners_index(),
+                    };
+                    let owner = ADataOwner {
+                        public_key: new_pk,
+                        entries_index: indices.entries_index(),
+                        permissions_index: indices.permissions_index() + 1,
+                    };
+
+                    c3.add_unpub_adata_permissions(
+                        address,
+                        permissions,
+                        indices.permissions_index(),
+                    )
+                    .and_then(move |()| {
+                        c4.set_adata_owners_as(address, owner, indices.owners_index(), &signer)
+                    })
+                    .into_box()
+                })
+                .map_err(AuthError::from)
+                .into_box()
+        })
+        .into_box()
+}
+
+// Revoke the permissions of the old keys on the audit log, if there is one.
+fn revoke_audit_log_perms(client: &AuthClient, old_pk: PublicKey) -> Box<AuthFuture<()>> {
+    let c2 = client.clone();
+
+    config::get_audit_log(client)
+        .and_then(move |(_, log)| {
+            let address = match log {
+                Some(log) => log.address,
+                None => return ok!(()),
+            };
+            let c3 = c2.clone();
+
+            c2.get_unpub_adata_permissions_at_index(address, ADataIndex::FromEnd(1))
+                .join(c2.get_adata_indices(address))
+                .and_then(move |(mut permissions, indices)| {
+                    if permissions.permissions.remove(&old_pk).is_none() {
+                        return ok!(());
+                    }
+
+                    permissions.entries_index = indices.entries_index();
+                    permissions.owners_index = indices.owners_index();
+
+                    c3.add_unpub_adata_permissions(
+                        address,
+                        permissions,
+                        indices.permissions_index(),
+                    )
+                })
+                .map_err(AuthError::from)
+                .into_box()
+        })
+        .into_box()
+}
+
+// Move the whole coin balance to the new keys.
+fn move_balance(client: &AuthClient, new_pk: PublicKey) -> Box<AuthFuture<()>> {
+    trace!("Moving the coin balance to the new client keys...");
+
+    let c2 = client.clone();
+    let c3 = client.clone();
+
+    client
+        .create_balance(None, new_pk, unwrap!(Coins::from_nano(0)), None)
+        .map(|_| ())
+        .or_else(|error| match error {
+            // Created by an earlier attempt.
+            CoreError::DataError(SndError::BalanceExists) => Ok(()),
+            error => Err(error),
+        })
+        .and_then(move |()| c2.get_balance(None))
+        .and_then(move |balance| {
+            if balance.as_nano() == 0 {
+                ok!(())
+            } else {
+                c3.transfer_coins(None, new_pk.into(), balance, None)
+                    .map(|_| ())
+                    .into_box()
+            }
+        })
+        .map_err(AuthError::from)
+        .into_box()
+}
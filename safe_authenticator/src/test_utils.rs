@@ -12,6 +12,10 @@
 
 use crate::client::AuthClient;
 use crate::errors::AuthError;
+use crate::ffi::ipc::{
+    DecodedIpcReq, IPC_REQ_AUTH, IPC_REQ_CONTAINERS, IPC_REQ_PERMISSIONS, IPC_REQ_SHARE_ADATA,
+    IPC_REQ_SHARE_MDATA, IPC_REQ_TRANS_OWNERSHIP, IPC_REQ_UNREGISTERED,
+};
 use crate::ipc::decode_ipc_msg;
 use crate::{access_container, app_auth, config, revocation, run, Authenticator};
 use env_logger::{fmt::Formatter, Builder as LoggerBuilder};
@@ -22,19 +26,16 @@ use log::Record;
 use log::{error, trace};
 use safe_core::client::{test_create_balance, Client};
 use safe_core::core_structs::AccessContainerEntry;
-use safe_core::core_structs::UserMetadata;
+use safe_core::core_structs::{OwnershipChange, UserMetadata};
 use safe_core::crypto::shared_secretbox;
-use safe_core::ffi::ipc::req::{
-    AuthReq as FfiAuthReq, ContainersReq as FfiContainersReq, ShareMDataRequest as FfiShareMDataReq,
-};
-use safe_core::ffi::ipc::resp::MetadataResponse as FfiUserMetadata;
 use safe_core::ipc::req::{container_perms_into_permission_set, ContainerPermissions};
 use safe_core::ipc::{
-    self, AppExchangeInfo, AuthGranted, AuthReq, ContainersReq, IpcMsg, IpcReq, ShareMDataReq,
+    self, AppExchangeInfo, AuthGranted, AuthReq, ContainersReq, IpcError, IpcFormat, IpcMsg,
+    IpcReq, PermissionsReq, ShareADataReq, ShareMDataReq, TransOwnershipReq,
 };
 use safe_core::nfs::file_helper::{self, Version};
 use safe_core::nfs::{File, Mode};
-use safe_core::utils::test_utils::{gen_client_id, setup_client_with_net_obs};
+use safe_core::utils::test_utils::{gen_client_id, setup_client_with_net_obs, TEST_KDF};
 #[cfg(feature = "mock-network")]
 use safe_core::ConnectionManager;
 use safe_core::{utils, MDataInfo, NetworkEvent};
@@ -66,6 +67,8 @@ macro_rules! assert_match {
 pub enum Payload {
     /// Metadata.
     Metadata(Vec<(Option<UserMetadata>, XorName, u64)>),
+    /// Descriptions of ownership changes.
+    OwnershipChanges(Vec<OwnershipChange>),
 }
 
 /// Channel type.
@@ -103,10 +106,11 @@ pub fn create_authenticator() -> (Authenticator, String, String) {
         unwrap!(Coins::from_str("100"))
     ));
 
-    let auth = unwrap!(Authenticator::create_acc(
+    let auth = unwrap!(Authenticator::create_acc_with_kdf(
         locator.clone(),
         password.clone(),
         client_id,
+        TEST_KDF,
         || (),
     ));
 
@@ -142,7 +146,12 @@ pub fn create_account_and_login() -> Authenticator {
     let (_, locator, password) = create_authenticator();
 
     trace!("Created an account with random login and password, logging in");
-    unwrap!(Authenticator::login(locator, password, || ()))
+    unwrap!(Authenticator::login_with_kdf(
+        locator,
+        password,
+        TEST_KDF,
+        || ()
+    ))
 }
 
 /// Revokes an app, returning an error on failure.
@@ -173,6 +182,7 @@ where
     unwrap!(Authenticator::login_with_hook(
         locator,
         password,
+        TEST_KDF,
         || (),
         hook,
     ))
@@ -203,7 +213,9 @@ pub fn register_app(
 
     // Invoke `decode_ipc_msg` and expect to get AuthReq back.
     let ipc_req = unwrap!(run(authenticator, move |client| decode_ipc_msg(
-        client, msg
+        client,
+        msg,
+        IpcFormat::legacy()
     )));
     match ipc_req {
         Ok(IpcMsg::Req {
@@ -236,6 +248,8 @@ pub fn register_rand_app(
             get_balance: true,
         },
         containers: containers_req,
+        response_key: None,
+        ttl: None,
     };
 
     let auth_granted = register_app(authenticator, &auth_req)?;
@@ -471,6 +485,7 @@ where
             &acc_locator,
             &acc_password,
             client_id,
+            TEST_KDF,
             el_h,
             core_tx,
             net_tx,
@@ -485,52 +500,53 @@ where
 pub fn auth_decode_ipc_msg_helper(authenticator: &Authenticator, msg: &str) -> ChannelType {
     let (tx, rx) = mpsc::channel::<ChannelType>();
 
-    extern "C" fn auth_cb(user_data: *mut c_void, req_id: u32, req: *const FfiAuthReq) {
+    extern "C" fn req_cb(user_data: *mut c_void, req: *const DecodedIpcReq) {
         unsafe {
-            let req = match AuthReq::clone_from_repr_c(req) {
-                Ok(req) => req,
-                Err(_) => return send_via_user_data::<ChannelType>(user_data, Err((-2, None))),
-            };
-
-            let msg = IpcMsg::Req {
-                req_id,
-                request: IpcReq::Auth(req),
-            };
-
-            send_via_user_data::<ChannelType>(user_data, Ok((msg, None)))
+            match clone_decoded_req(&*req) {
+                Ok(decoded) => send_via_user_data::<ChannelType>(user_data, Ok(decoded)),
+                Err(_) => send_via_user_data::<ChannelType>(user_data, Err((-2, None))),
+            }
         }
     }
 
-    extern "C" fn containers_cb(user_data: *mut c_void, req_id: u32, req: *const FfiContainersReq) {
-        unsafe {
-            let req = match ContainersReq::clone_from_repr_c(req) {
-                Ok(req) => req,
-                Err(_) => return send_via_user_data::<ChannelType>(user_data, Err((-2, None))),
-            };
+    let ffi_msg = unwrap!(CString::new(msg));
+    let mut ud = Default::default();
 
-            let msg = IpcMsg::Req {
-                req_id,
-                request: IpcReq::Containers(req),
-            };
+    unsafe {
+        crate::ffi::ipc::auth_decode_ipc_req(
+            authenticator,
+            ffi_msg.as_ptr(),
+            sender_as_user_data(&tx, &mut ud),
+            req_cb,
+            err_cb,
+        );
+    };
 
-            send_via_user_data::<ChannelType>(user_data, Ok((msg, None)))
+    let ret = match rx.recv_timeout(Duration::from_secs(30)) {
+        Ok(r) => r,
+        Err(e) => {
+            error!("auth_decode_ipc_msg_helper: {:?}", e);
+            Err((-1, None))
         }
-    }
-
-    extern "C" fn share_mdata_cb(
-        user_data: *mut c_void,
-        req_id: u32,
-        req: *const FfiShareMDataReq,
-        ffi_metadata: *const FfiUserMetadata,
-        ffi_metadata_len: usize,
-    ) {
-        unsafe {
-            let req = match ShareMDataReq::clone_from_repr_c(req) {
-                Ok(req) => req,
-                Err(_) => return send_via_user_data::<ChannelType>(user_data, Err((-2, None))),
-            };
+    };
+    drop(tx);
+    ret
+}
 
-            let metadatas: Vec<_> = slice::from_raw_parts(ffi_metadata, ffi_metadata_len)
+// Converts a request passed to the `auth_decode_ipc_req` callback back to its native form.
+unsafe fn clone_decoded_req(req: &DecodedIpcReq) -> Result<(IpcMsg, Option<Payload>), IpcError> {
+    let (request, payload) = match req.kind {
+        IPC_REQ_AUTH => (IpcReq::Auth(AuthReq::clone_from_repr_c(req.auth)?), None),
+        IPC_REQ_CONTAINERS => (
+            IpcReq::Containers(ContainersReq::clone_from_repr_c(req.containers)?),
+            None,
+        ),
+        IPC_REQ_UNREGISTERED => (
+            IpcReq::Unregistered(vec_clone_from_raw_parts(req.extra_data, req.extra_data_len)),
+            None,
+        ),
+        IPC_REQ_SHARE_MDATA => {
+            let metadatas = slice::from_raw_parts(req.metadata, req.metadata_len)
                 .iter()
                 .map(|ffi_metadata| {
                     (
@@ -544,44 +560,39 @@ pub fn auth_decode_ipc_msg_helper(authenticator: &Authenticator, msg: &str) -> C
                     )
                 })
                 .collect();
-
-            let msg = IpcMsg::Req {
-                req_id,
-                request: IpcReq::ShareMData(req),
-            };
-
-            send_via_user_data::<ChannelType>(
-                user_data,
-                Ok((msg, Some(Payload::Metadata(metadatas)))),
+            (
+                IpcReq::ShareMData(ShareMDataReq::clone_from_repr_c(req.share_mdata)?),
+                Some(Payload::Metadata(metadatas)),
             )
         }
-    }
-
-    let ffi_msg = unwrap!(CString::new(msg));
-    let mut ud = Default::default();
-
-    unsafe {
-        crate::ffi::ipc::auth_decode_ipc_msg(
-            authenticator,
-            ffi_msg.as_ptr(),
-            sender_as_user_data(&tx, &mut ud),
-            auth_cb,
-            containers_cb,
-            unregistered_cb,
-            share_mdata_cb,
-            err_cb,
-        );
-    };
-
-    let ret = match rx.recv_timeout(Duration::from_secs(30)) {
-        Ok(r) => r,
-        Err(e) => {
-            error!("auth_decode_ipc_msg_helper: {:?}", e);
-            Err((-1, None))
+        IPC_REQ_SHARE_ADATA => (
+            IpcReq::ShareAData(ShareADataReq::clone_from_repr_c(req.share_adata)?),
+            None,
+        ),
+        IPC_REQ_TRANS_OWNERSHIP => {
+            let changes = slice::from_raw_parts(req.ownership_changes, req.ownership_changes_len)
+                .iter()
+                .map(|ffi_change| OwnershipChange::clone_from_repr_c(ffi_change))
+                .collect::<Result<_, _>>()?;
+            (
+                IpcReq::TransOwnership(TransOwnershipReq::clone_from_repr_c(req.trans_ownership)?),
+                Some(Payload::OwnershipChanges(changes)),
+            )
         }
+        IPC_REQ_PERMISSIONS => (
+            IpcReq::Permissions(PermissionsReq::clone_from_repr_c(req.permissions)?),
+            None,
+        ),
+        _ => return Err(IpcError::InvalidMsg),
     };
-    drop(tx);
-    ret
+
+    Ok((
+        IpcMsg::Req {
+            req_id: req.req_id,
+            request,
+        },
+        payload,
+    ))
 }
 
 /// Unregistered callback.
@@ -0,0 +1,132 @@
+// Copyright 2019 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+use super::utils::create_containers_req;
+use crate::config::{self, AuditDecision, AuditEntry};
+use crate::errors::AuthError;
+use crate::ffi::audit::auth_export_audit_log;
+use crate::test_utils::{create_account_and_login, rand_app, register_app, revoke};
+use crate::{app_auth, audit, run};
+use ffi_utils::test_utils::call_1;
+use futures::Future;
+use safe_core::btree_set;
+use safe_core::ipc::{AuthReq, Permission, PermissionsReq};
+use safe_core::Client;
+use safe_nd::ADataIndex;
+use std::collections::HashMap;
+use unwrap::unwrap;
+
+// Test that the decisions of the authenticator are recorded in the audit log.
+// 1. Authorise an app, modify its permissions and revoke it.
+// 2. Verify that each decision was recorded in order, with the permissions involved.
+// 3. Verify that each entry of the log is stored encrypted.
+// 4. Export the log as JSON through FFI.
+#[test]
+fn audit_log() {
+    let auth = create_account_and_login();
+
+    let app = rand_app();
+    let app_id = app.id.clone();
+
+    let auth_req = AuthReq {
+        app: app.clone(),
+        app_container: false,
+        app_permissions: Default::default(),
+        containers: create_containers_req(),
+        response_key: None,
+        ttl: None,
+    };
+    let _ = unwrap!(register_app(&auth, &auth_req));
+
+    let mut containers = HashMap::new();
+    let _ = containers.insert(
+        "_documents".to_owned(),
+        btree_set![Permission::Read, Permission::Insert],
+    );
+    let perms_req = PermissionsReq {
+        app,
+        containers: containers.clone(),
+        app_permissions: None,
+    };
+    unwrap!(run(&auth, move |client| {
+        app_auth::modify_permissions(client, perms_req)
+    }));
+
+    revoke(&auth, &app_id);
+
+    let log = unwrap!(run(&auth, audit::list));
+    let decisions: Vec<_> = log
+        .iter()
+        .map(|entry| {
+            assert_eq!(entry.app_id, app_id);
+            entry.decision.clone()
+        })
+        .collect();
+    assert_eq!(
+        decisions,
+        vec![
+            AuditDecision::Authorised {
+                app_permissions: Default::default(),
+                containers: create_containers_req(),
+                expires_at: None,
+            },
+            AuditDecision::PermissionsModified {
+                app_permissions: None,
+                containers,
+            },
+            AuditDecision::Revoked,
+        ]
+    );
+    assert!(log.windows(2).all(|pair| pair[0].time <= pair[1].time));
+
+    // The app ID must not appear in the clear anywhere in the config root or in the log.
+    let plain_id = app_id.clone().into_bytes();
+    let id = plain_id.clone();
+    unwrap!(run(&auth, move |client| {
+        let config_root = client.config_root_dir();
+
+        client
+            .list_seq_mdata_entries(config_root.name(), config_root.type_tag())
+            .map(move |entries| {
+                for (key, value) in entries {
+                    assert!(!contains(&key, &id));
+                    assert!(!contains(&value.data, &id));
+                }
+            })
+            .map_err(AuthError::from)
+    }));
+    unwrap!(run(&auth, move |client| {
+        let c2 = client.clone();
+
+        config::get_audit_log(client)
+            .and_then(move |(_, log)| {
+                let log = unwrap!(log);
+                c2.get_adata_range(
+                    log.address,
+                    (ADataIndex::FromStart(0), ADataIndex::FromEnd(0)),
+                )
+                .map_err(AuthError::from)
+            })
+            .map(move |entries| {
+                assert_eq!(entries.len(), 3);
+                for entry in entries {
+                    assert!(!contains(&entry.value, &plain_id));
+                }
+            })
+    }));
+
+    let json: String = unsafe { unwrap!(call_1(|ud, cb| auth_export_audit_log(&auth, ud, cb))) };
+    let exported: Vec<AuditEntry> = unwrap!(serde_json::from_str(&json));
+    assert_eq!(exported, log);
+}
+
+fn contains(haystack: &[u8], needle: &[u8]) -> bool {
+    haystack
+        .windows(needle.len())
+        .any(|window| window == needle)
+}
@@ -0,0 +1,138 @@
+// Copyright 2019 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+use crate::access_container;
+use crate::backup;
+use crate::config;
+use crate::errors::AuthError;
+use crate::run;
+use crate::test_utils::{
+    create_authenticator, get_app_or_err, rand_app, register_app, register_rand_app, revoke,
+    try_access_container,
+};
+use crate::Authenticator;
+use futures::Future;
+use safe_core::ipc::AuthReq;
+use safe_core::utils::test_utils::TEST_KDF;
+use safe_nd::AppPermissions;
+use tiny_keccak::sha3_256;
+use unwrap::unwrap;
+
+// Test the contents of an exported backup.
+// 1. Register two apps and revoke one of them.
+// 2. Export a backup and verify that it lists both apps, but only has an access container entry
+//    for the one that is still authorised.
+#[test]
+fn backup_contents() {
+    let (auth, _, _) = create_authenticator();
+    let (app_id, _) = unwrap!(register_rand_app(&auth, true, Default::default()));
+    let (revoked_app_id, _) = unwrap!(register_rand_app(&auth, false, Default::default()));
+    revoke(&auth, &revoked_app_id);
+
+    let sealed = unwrap!(auth.export_backup("passphrase"));
+    let backup = unwrap!(backup::open(&sealed, "passphrase"));
+
+    assert_eq!(backup.apps.len(), 2);
+    assert!(backup.authenticator_entry.contains_key("_documents"));
+    assert_eq!(backup.app_entries.len(), 1);
+    assert!(backup.app_entries.contains_key(&app_id));
+}
+
+// Test importing a backup that doesn't match the account anymore.
+// 1. Export a backup, then rotate the client keys of the account.
+// 2. Verify that importing the backup fails and leaves the account packet untouched.
+#[test]
+fn import_outdated_backup() {
+    let (auth, locator, password) = create_authenticator();
+
+    let sealed = unwrap!(auth.export_backup("passphrase"));
+    unwrap!(auth.rotate_client_keys());
+
+    match Authenticator::import_backup(&sealed, "passphrase", "new password", || ()) {
+        Err(AuthError::BackupMismatch) => (),
+        Err(x) => panic!("Unexpected {:?}", x),
+        Ok(_) => panic!("Unexpected success"),
+    }
+
+    let _ = unwrap!(Authenticator::login_with_kdf(
+        locator,
+        password,
+        TEST_KDF,
+        || ()
+    ));
+}
+
+// Test restoring an account from a backup.
+// 1. Register an app with an expiring authorisation, then export a backup.
+// 2. Forget the app and delete its access container entry, as if the config had been lost.
+// 3. Import the backup and verify that logging in with the new password works, and that the app
+//    is registered again along with its access container entry and expiry time.
+#[test]
+fn export_import_login() {
+    let (auth, locator, _) = create_authenticator();
+    let auth_req = AuthReq {
+        app: rand_app(),
+        app_container: true,
+        app_permissions: AppPermissions {
+            transfer_coins: true,
+            perform_mutations: true,
+            get_balance: true,
+        },
+        containers: Default::default(),
+        response_key: None,
+        ttl: Some(3600),
+    };
+    let app_id = auth_req.app.id.clone();
+    let auth_granted = unwrap!(register_app(&auth, &auth_req));
+
+    let sealed = unwrap!(auth.export_backup("passphrase"));
+
+    let app_id2 = app_id.clone();
+    let app_keys = auth_granted.app_keys.clone();
+    unwrap!(run(&auth, move |client| {
+        let c2 = client.clone();
+        let c3 = client.clone();
+        let c4 = client.clone();
+        let app_id3 = app_id2.clone();
+        let app_id4 = app_id2.clone();
+        let app_keys2 = app_keys.clone();
+
+        config::list_apps(client)
+            .and_then(move |(version, apps)| {
+                config::remove_app(&c2, apps, config::next_version(version), &app_id2)
+            })
+            .and_then(move |_| access_container::fetch_entry(&c3, &app_id3, app_keys2))
+            .and_then(move |(version, _)| {
+                access_container::delete_entry(&c4, &app_id4, &app_keys, version + 1)
+            })
+    }));
+    match get_app_or_err(&auth, &app_id) {
+        Err(_) => (),
+        Ok(_) => panic!("Unexpected success"),
+    }
+
+    let _ = unwrap!(Authenticator::import_backup(
+        &sealed,
+        "passphrase",
+        "new password",
+        || ()
+    ));
+
+    let auth = unwrap!(Authenticator::login_with_kdf(
+        locator,
+        "new password".to_string(),
+        TEST_KDF,
+        || ()
+    ));
+
+    let _ = unwrap!(get_app_or_err(&auth, &app_id));
+    assert!(try_access_container(&auth, app_id.clone(), auth_granted).is_some());
+
+    let (_, expiries) = unwrap!(run(&auth, config::list_app_expiries));
+    assert!(expiries.contains_key(&sha3_256(app_id.as_bytes())));
+}
@@ -0,0 +1,108 @@
+// Copyright 2019 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+use crate::errors::AuthError;
+use crate::run;
+use crate::test_utils::{
+    create_authenticator, create_file, get_container_from_authenticator_entry, register_rand_app,
+};
+use crate::Authenticator;
+use futures::Future;
+use safe_core::client::wallet_get_balance;
+use safe_core::utils::test_utils::{gen_client_id, TEST_KDF};
+use safe_core::{test_create_balance, Client, CoreError};
+use safe_nd::{Coins, Error as SndError, IDataAddress, XorName};
+use std::str::FromStr;
+use unwrap::unwrap;
+
+// Test deleting an account.
+// 1. Create a file in `_documents` and register an app with its own container, which creates the
+//    audit log.
+// 2. Do a dry run and verify that it lists the file, the containers, the audit log, the auth key
+//    of the app and the account packet, but changes nothing.
+// 3. Verify that the deletion fails with a wrong password.
+// 4. Delete the account and verify that the data is gone, the balance was transferred and
+//    logging in fails.
+#[test]
+fn delete_account() {
+    let (auth, locator, password) = create_authenticator();
+
+    let documents = unwrap!(get_container_from_authenticator_entry(&auth, "_documents"));
+    unwrap!(create_file(
+        &auth,
+        documents.clone(),
+        "private.txt",
+        vec![1; 10_000],
+        false
+    ));
+    let _ = unwrap!(register_rand_app(&auth, true, Default::default()));
+
+    let destination_id = gen_client_id();
+    unwrap!(test_create_balance(
+        &destination_id,
+        unwrap!(Coins::from_str("1"))
+    ));
+    let destination = XorName::from(*destination_id.public_id().public_key());
+
+    let dry_run = unwrap!(auth.delete_account(password.clone(), destination, true));
+    assert!(dry_run.idata.len() > 1);
+    assert!(dry_run.mdata.contains(documents.address()));
+    assert_eq!(dry_run.adata.len(), 1);
+    assert_eq!(dry_run.auth_keys.len(), 1);
+    assert_eq!(dry_run.login_packets.len(), 1);
+    assert!(dry_run.balance.as_nano() > 0);
+
+    let _ = unwrap!(Authenticator::login_with_kdf(
+        locator.clone(),
+        password.clone(),
+        TEST_KDF,
+        || ()
+    ));
+
+    match auth.delete_account("wrong password", destination, false) {
+        Err(AuthError::CoreError(CoreError::SymmetricDecipherFailure)) => (),
+        x => panic!("Unexpected {:?}", x),
+    }
+
+    let report = unwrap!(auth.delete_account(password.clone(), destination, false));
+    assert_eq!(report.mdata, dry_run.mdata);
+    assert_eq!(report.idata, dry_run.idata);
+    assert_eq!(report.adata, dry_run.adata);
+    assert_eq!(report.auth_keys, dry_run.auth_keys);
+
+    let name = report.idata[0];
+    let res = run(&auth, move |client| {
+        client
+            .get_idata(IDataAddress::Unpub(name))
+            .map_err(AuthError::from)
+    });
+    match res {
+        Err(AuthError::CoreError(CoreError::DataError(SndError::NoSuchData))) => (),
+        x => panic!("Unexpected {:?}", x),
+    }
+
+    let address = report.adata[0];
+    let res = run(&auth, move |client| {
+        client
+            .get_adata(address)
+            .map(|_| ())
+            .map_err(AuthError::from)
+    });
+    match res {
+        Err(AuthError::CoreError(CoreError::DataError(SndError::NoSuchData))) => (),
+        x => panic!("Unexpected {:?}", x),
+    }
+
+    let balance = unwrap!(wallet_get_balance(&destination_id));
+    assert_eq!(
+        balance.as_nano(),
+        unwrap!(Coins::from_str("1")).as_nano() + report.balance.as_nano()
+    );
+
+    assert!(Authenticator::login_with_kdf(locator, password, TEST_KDF, || ()).is_err());
+}
@@ -0,0 +1,206 @@
+// Copyright 2019 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+use crate::config::{self, DeviceInfo, Devices};
+use crate::devices::{self, Device, DeviceKey};
+use crate::errors::AuthError;
+use crate::ffi::devices::{auth_devices, auth_log_out_device, Device as FfiDevice};
+use crate::run;
+use crate::test_utils::create_authenticator;
+use crate::Authenticator;
+use chrono::Utc;
+use ffi_utils::test_utils::{call_0, call_vec};
+use ffi_utils::{ReprC, StringError};
+use futures::Future;
+use rand::{thread_rng, Rng};
+use safe_core::utils::test_utils::TEST_KDF;
+use safe_core::CoreError;
+use threshold_crypto::SecretKey;
+use unwrap::unwrap;
+
+struct DeviceName(String);
+
+impl ReprC for DeviceName {
+    type C = *const FfiDevice;
+    type Error = StringError;
+
+    unsafe fn clone_from_repr_c(repr_c: Self::C) -> Result<Self, Self::Error> {
+        Ok(DeviceName(String::clone_from_repr_c((*repr_c).name)?))
+    }
+}
+
+fn register(auth: &Authenticator, name: &str) -> DeviceKey {
+    let name = name.to_string();
+    unwrap!(run(auth, move |client| devices::register(client, &name)))
+}
+
+fn list_devices(auth: &Authenticator) -> (Option<u64>, Devices) {
+    unwrap!(run(auth, config::list_devices))
+}
+
+// Test registering devices, logging in with them and logging them out.
+// 1. Register two devices from two sessions of the same account.
+// 2. Verify that both are listed and can log in, but only with the right password.
+// 3. Log out one of the devices through FFI and verify that the sessions using the old client
+//    keys, including the one of the logged out device, can't access the account anymore.
+// 4. Verify that the logged out device can't log in anymore, while the other one still can.
+// 5. Verify that unregistered devices can't log in.
+#[test]
+fn device_sessions() {
+    let (auth, locator, password) = create_authenticator();
+    let other = unwrap!(Authenticator::login_with_kdf(
+        locator.clone(),
+        password.clone(),
+        TEST_KDF,
+        || ()
+    ));
+
+    let laptop_key = register(&auth, "laptop");
+    let phone_key = register(&other, "phone");
+
+    let listed = unwrap!(run(&auth, devices::list));
+    assert_eq!(listed.len(), 2);
+    let laptop = unwrap!(listed
+        .iter()
+        .find(|device| device.id == devices::device_id(&laptop_key)));
+    assert_eq!(laptop.info.name, "laptop");
+
+    let laptop_session = unwrap!(Authenticator::login_device(
+        locator.clone(),
+        password.clone(),
+        laptop_key,
+        || ()
+    ));
+    let _ = unwrap!(Authenticator::login_device(
+        locator.clone(),
+        password.clone(),
+        phone_key,
+        || ()
+    ));
+
+    match Authenticator::login_device(locator.clone(), "wrong".to_string(), phone_key, || ()) {
+        Err(AuthError::CoreError(CoreError::SymmetricDecipherFailure)) => (),
+        x => panic!("Unexpected {:?}", x.map(|_| ())),
+    }
+
+    let laptop_id = devices::device_id(&laptop_key);
+    unsafe {
+        unwrap!(call_0(|ud, cb| auth_log_out_device(
+            &other, &laptop_id, ud, cb
+        )))
+    };
+
+    assert!(run(&auth, devices::list).is_err());
+    assert!(run(&laptop_session, devices::list).is_err());
+
+    let auth = unwrap!(Authenticator::login_with_kdf(
+        locator.clone(),
+        password.clone(),
+        TEST_KDF,
+        || ()
+    ));
+    let listed: Vec<Device> = unwrap!(run(&auth, devices::list));
+    assert_eq!(listed.len(), 1);
+    assert_eq!(listed[0].id, devices::device_id(&phone_key));
+
+    let ffi_devices: Vec<String> = unsafe {
+        unwrap!(call_vec(|ud, cb| auth_devices(&auth, ud, cb)))
+            .into_iter()
+            .map(|DeviceName(name)| name)
+            .collect()
+    };
+    assert_eq!(ffi_devices, vec!["phone".to_string()]);
+
+    match Authenticator::login_device(locator.clone(), password.clone(), laptop_key, || ()) {
+        Err(AuthError::UnknownDevice) => (),
+        x => panic!("Unexpected {:?}", x.map(|_| ())),
+    }
+
+    let phone_session = unwrap!(Authenticator::login_device(
+        locator.clone(),
+        password.clone(),
+        phone_key,
+        || ()
+    ));
+    let _ = unwrap!(run(&phone_session, devices::list));
+
+    let unknown_key: DeviceKey = thread_rng().gen();
+    match Authenticator::login_device(locator, password, unknown_key, || ()) {
+        Err(AuthError::UnknownDevice) => (),
+        x => panic!("Unexpected {:?}", x.map(|_| ())),
+    }
+}
+
+// Test that concurrent updates of the config from two sessions are merged.
+// 1. Fetch the devices in one session, then register a device in another one.
+// 2. Register a device using the outdated list and verify that both devices are listed.
+// 3. Fetch the devices again, then remove one of them in the other session.
+// 4. Register the removed device again using the outdated list, which already contains it,
+//    and verify that it's listed again.
+#[test]
+fn concurrent_config_updates() {
+    let (auth, locator, password) = create_authenticator();
+    let other = unwrap!(Authenticator::login_with_kdf(
+        locator,
+        password,
+        TEST_KDF,
+        || ()
+    ));
+
+    let (version, outdated) = list_devices(&auth);
+    let _ = register(&other, "phone");
+
+    let laptop = device_info("laptop");
+    unwrap!(run(&auth, move |client| config::put_device(
+        client,
+        outdated,
+        config::next_version(version),
+        laptop
+    )
+    .map(|_| ())));
+
+    let (version, outdated) = list_devices(&auth);
+    assert_eq!(outdated.len(), 2);
+
+    let (laptop_id, laptop) = unwrap!(outdated
+        .iter()
+        .find(|(_, device)| device.name == "laptop")
+        .map(|(id, device)| (*id, device.clone())));
+    unwrap!(run(&other, move |client| {
+        let client = client.clone();
+
+        config::list_devices(&client)
+            .and_then(move |(version, devices)| {
+                config::remove_device(&client, devices, config::next_version(version), laptop_id)
+            })
+            .map(|_| ())
+    }));
+
+    unwrap!(run(&auth, move |client| config::put_device(
+        client,
+        outdated,
+        config::next_version(version),
+        laptop
+    )
+    .map(|_| ())));
+
+    let (_, devices) = list_devices(&auth);
+    assert_eq!(devices.len(), 2);
+    assert!(devices.contains_key(&laptop_id));
+}
+
+fn device_info(name: &str) -> DeviceInfo {
+    let now = Utc::now();
+
+    DeviceInfo {
+        name: name.to_string(),
+        public_key: thread_rng().gen::<SecretKey>().public_key(),
+        registered_at: now,
+        last_login: now,
+    }
+}
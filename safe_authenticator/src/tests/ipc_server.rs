@@ -0,0 +1,264 @@
+// Copyright 2019 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+#![cfg(unix)]
+
+use crate::ipc_server::{IpcHandler, IpcServer, IpcServerAddr};
+use crate::test_utils;
+use crate::AuthError;
+use safe_core::ipc::{
+    self, AppExchangeInfo, AuthReq, ContainersReq, IpcError, IpcMsg, IpcReq, IpcResp,
+};
+use std::collections::HashMap;
+use std::env;
+use std::fs;
+use std::io::{BufRead, BufReader, ErrorKind, Read, Write};
+use std::net::{SocketAddr, TcpStream};
+use std::os::unix::fs::PermissionsExt;
+use std::os::unix::net::{UnixListener, UnixStream};
+use std::path::Path;
+use std::sync::{mpsc, Arc, Mutex};
+use std::thread;
+use unwrap::unwrap;
+
+// Test authorising apps over a Unix domain socket.
+// 1. Authorise an app. It must be granted.
+// 2. Send a containers request. Only authorisation requests are served, so it must fail.
+// 3. Serve at the same path again while the first server listens. It must fail.
+// 4. Serve at the path of a stale socket, and authorise an app while the user denies everything.
+//    It must be denied.
+#[test]
+fn unix_socket_auth() {
+    let authenticator = Arc::new(test_utils::create_account_and_login());
+    let handler = Arc::new(IpcHandler::new(
+        Arc::clone(&authenticator),
+        Box::new(|_, origin| origin.is_none()),
+    ));
+
+    let path = env::temp_dir().join(format!("safe_authenticator_{}.sock", rand::random::<u64>()));
+    let server = unwrap!(IpcServer::unix(handler, &path));
+    assert_eq!(*server.addr(), IpcServerAddr::Unix(path.clone()));
+    assert_eq!(
+        unwrap!(fs::metadata(&path)).permissions().mode() & 0o777,
+        0o600
+    );
+
+    let app = test_utils::rand_app();
+    match unwrap!(ipc::decode_msg(&unix_request(&path, &auth_req_msg(app)))) {
+        IpcMsg::Resp {
+            response: IpcResp::Auth(Ok(_)),
+            ..
+        } => (),
+        x => panic!("Unexpected {:?}", x),
+    }
+
+    let msg = IpcMsg::Req {
+        req_id: ipc::gen_req_id(),
+        request: IpcReq::Containers(ContainersReq {
+            app: test_utils::rand_app(),
+            containers: HashMap::new(),
+        }),
+    };
+    match unwrap!(ipc::decode_msg(&unix_request(&path, &msg))) {
+        IpcMsg::Err(IpcError::Unexpected(_)) => (),
+        x => panic!("Unexpected {:?}", x),
+    }
+
+    let other_handler = Arc::new(IpcHandler::new(
+        Arc::clone(&authenticator),
+        Box::new(|_, _| false),
+    ));
+    match IpcServer::unix(other_handler, &path) {
+        Err(AuthError::IoError(ref err)) if err.kind() == ErrorKind::AddrInUse => (),
+        x => panic!("Unexpected {:?}", x.map(|server| server.addr().clone())),
+    }
+
+    drop(server);
+    assert!(!path.exists());
+
+    // A socket nobody listens on any more is replaced.
+    drop(unwrap!(UnixListener::bind(&path)));
+    assert!(path.exists());
+
+    let handler = Arc::new(IpcHandler::new(authenticator, Box::new(|_, _| false)));
+    let _server = unwrap!(IpcServer::unix(handler, &path));
+
+    match unwrap!(ipc::decode_msg(&unix_request(
+        &path,
+        &auth_req_msg(test_utils::rand_app())
+    ))) {
+        IpcMsg::Resp {
+            response: IpcResp::Auth(Err(IpcError::AuthDenied)),
+            ..
+        } => (),
+        x => panic!("Unexpected {:?}", x),
+    }
+}
+
+// Test that a client waiting for the user's consent doesn't hold up other clients.
+// 1. Send a request the user takes their time to consent to.
+// 2. Authorise another app meanwhile. It must be granted.
+// 3. Consent to the first request. It must be granted.
+#[test]
+fn unix_socket_concurrent_requests() {
+    let authenticator = Arc::new(test_utils::create_account_and_login());
+    let slow_app = test_utils::rand_app();
+    let slow_app_id = slow_app.id.clone();
+    let (consent_tx, consent_rx) = mpsc::channel();
+    let consent_rx = Mutex::new(consent_rx);
+
+    let handler = Arc::new(IpcHandler::new(
+        authenticator,
+        Box::new(move |auth_req, _| {
+            if auth_req.app.id == slow_app_id {
+                unwrap!(unwrap!(consent_rx.lock()).recv());
+            }
+            true
+        }),
+    ));
+
+    let path = env::temp_dir().join(format!("safe_authenticator_{}.sock", rand::random::<u64>()));
+    let _server = unwrap!(IpcServer::unix(handler, &path));
+
+    let path2 = path.clone();
+    let slow_request = thread::spawn(move || unix_request(&path2, &auth_req_msg(slow_app)));
+
+    match unwrap!(ipc::decode_msg(&unix_request(
+        &path,
+        &auth_req_msg(test_utils::rand_app())
+    ))) {
+        IpcMsg::Resp {
+            response: IpcResp::Auth(Ok(_)),
+            ..
+        } => (),
+        x => panic!("Unexpected {:?}", x),
+    }
+
+    unwrap!(consent_tx.send(()));
+    match unwrap!(ipc::decode_msg(&unwrap!(slow_request.join()))) {
+        IpcMsg::Resp {
+            response: IpcResp::Auth(Ok(_)),
+            ..
+        } => (),
+        x => panic!("Unexpected {:?}", x),
+    }
+}
+
+// Test authorising apps over HTTP.
+// 1. Requests without an allowed origin must be forbidden.
+// 2. Authorise an app from an allowed origin. It must be granted.
+// 3. Authorise the same app from another allowed origin. It must be denied.
+// 4. Authorise the same app from the first origin again. It must be granted.
+#[test]
+fn http_auth_with_origin_pinning() {
+    let authenticator = Arc::new(test_utils::create_account_and_login());
+    let handler = Arc::new(IpcHandler::new(authenticator, Box::new(|_, _| true)));
+
+    let origin1 = "http://localhost:3000";
+    let origin2 = "http://localhost:4000";
+    let server = unwrap!(IpcServer::http(
+        handler,
+        0,
+        vec![origin1.to_string(), origin2.to_string()]
+    ));
+    let addr = match *server.addr() {
+        IpcServerAddr::Http(addr) => addr,
+        ref x => panic!("Unexpected {:?}", x),
+    };
+    assert!(addr.ip().is_loopback());
+
+    let app = test_utils::rand_app();
+    let msg = auth_req_msg(app.clone());
+
+    let (status, _, _) = http_post(addr, None, &msg);
+    assert_eq!(status, "HTTP/1.1 403 Forbidden");
+    let (status, _, _) = http_post(addr, Some("http://example.com"), &msg);
+    assert_eq!(status, "HTTP/1.1 403 Forbidden");
+
+    let (status, headers, body) = http_post(addr, Some(origin1), &msg);
+    assert_eq!(status, "HTTP/1.1 200 OK");
+    assert!(headers.contains(&format!("Access-Control-Allow-Origin: {}", origin1)));
+    match unwrap!(ipc::decode_msg(&body)) {
+        IpcMsg::Resp {
+            response: IpcResp::Auth(Ok(_)),
+            ..
+        } => (),
+        x => panic!("Unexpected {:?}", x),
+    }
+
+    let (_, _, body) = http_post(addr, Some(origin2), &auth_req_msg(app.clone()));
+    match unwrap!(ipc::decode_msg(&body)) {
+        IpcMsg::Resp {
+            response: IpcResp::Auth(Err(IpcError::AuthDenied)),
+            ..
+        } => (),
+        x => panic!("Unexpected {:?}", x),
+    }
+
+    let (_, _, body) = http_post(addr, Some(origin1), &auth_req_msg(app));
+    match unwrap!(ipc::decode_msg(&body)) {
+        IpcMsg::Resp {
+            response: IpcResp::Auth(Ok(_)),
+            ..
+        } => (),
+        x => panic!("Unexpected {:?}", x),
+    }
+}
+
+fn auth_req_msg(app: AppExchangeInfo) -> IpcMsg {
+    IpcMsg::Req {
+        req_id: ipc::gen_req_id(),
+        request: IpcReq::Auth(AuthReq {
+            app,
+            app_container: false,
+            app_permissions: Default::default(),
+            containers: HashMap::new(),
+            response_key: None,
+            ttl: None,
+        }),
+    }
+}
+
+// Send the request over the Unix domain socket and return the response.
+fn unix_request(path: &Path, msg: &IpcMsg) -> String {
+    let mut stream = unwrap!(UnixStream::connect(path));
+    unwrap!(writeln!(stream, "{}", unwrap!(ipc::encode_msg(msg))));
+
+    let mut response = String::new();
+    let _ = unwrap!(BufReader::new(stream).read_line(&mut response));
+    response.trim_end().to_string()
+}
+
+// Post the request over HTTP and return the status line, the headers and the body.
+fn http_post(addr: SocketAddr, origin: Option<&str>, msg: &IpcMsg) -> (String, String, String) {
+    let body = unwrap!(ipc::encode_msg(msg));
+    let mut stream = unwrap!(TcpStream::connect(addr));
+
+    unwrap!(write!(stream, "POST / HTTP/1.1\r\nHost: {}\r\n", addr));
+    if let Some(origin) = origin {
+        unwrap!(write!(stream, "Origin: {}\r\n", origin));
+    }
+    unwrap!(write!(
+        stream,
+        "Content-Type: text/plain\r\nContent-Length: {}\r\n\r\n{}",
+        body.len(),
+        body
+    ));
+
+    let mut response = String::new();
+    let _ = unwrap!(stream.read_to_string(&mut response));
+
+    let mut parts = response.splitn(2, "\r\n\r\n");
+    let head = unwrap!(parts.next()).to_string();
+    let body = parts.next().unwrap_or("").to_string();
+    let mut lines = head.splitn(2, "\r\n");
+    let status = unwrap!(lines.next()).to_string();
+    let headers = lines.next().unwrap_or("").to_string();
+
+    (status, headers, body)
+}
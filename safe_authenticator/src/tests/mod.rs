@@ -8,9 +8,17 @@
 
 #![allow(unsafe_code)]
 
+mod audit;
+mod backup;
+mod deletion;
+mod devices;
+mod ipc_server;
 mod revocation;
+mod rotation;
 mod serialisation;
+mod share_adata;
 mod share_mdata;
+mod trans_ownership;
 mod utils;
 
 use crate::access_container as access_container_tools;
@@ -20,23 +28,40 @@ use crate::ffi::apps::AppPermissions as FfiAppPermissions;
 use crate::ffi::apps::*;
 use crate::ffi::errors::{ERR_INVALID_MSG, ERR_OPERATION_FORBIDDEN, ERR_UNKNOWN_APP};
 use crate::ffi::ipc::{
-    auth_revoke_app, encode_auth_resp, encode_containers_resp, encode_unregistered_resp,
+    auth_decode_ipc_msg, auth_decode_ipc_req_by_kind, auth_revoke_app, encode_auth_resp,
+    encode_containers_resp, encode_permissions_resp, encode_unregistered_resp,
 };
+use crate::ipc::encode_decode_error;
 use crate::std_dirs::{DEFAULT_PRIVATE_DIRS, DEFAULT_PUBLIC_DIRS};
-use crate::test_utils::{self, ChannelType};
-use crate::{app_container, run};
-use ffi_utils::test_utils::{call_1, call_vec, sender_as_user_data};
+use crate::test_utils::{self, ChannelType, Payload};
+use crate::{app_container, run, Authenticator};
+use ffi_utils::test_utils::{call_1, call_vec, send_via_user_data, sender_as_user_data};
 use ffi_utils::{ReprC, StringError};
 use futures::{future, Future};
-use safe_core::btree_set;
 use safe_core::config_handler::Config;
-use safe_core::ffi::error_codes::ERR_NO_SUCH_CONTAINER;
-use safe_core::ffi::ipc::req::AppExchangeInfo as FfiAppExchangeInfo;
-use safe_core::ipc::{self, AuthReq, ContainersReq, IpcError, IpcMsg, IpcReq, IpcResp, Permission};
-use safe_core::{app_container_name, mdata_info, AuthActions, Client};
-use safe_nd::AppPermissions;
+use safe_core::core_structs::OwnershipChange;
+use safe_core::crypto::shared_box;
+use safe_core::ffi::error_codes::{ERR_NO_SUCH_CONTAINER, ERR_UNEXPECTED};
+use safe_core::ffi::ipc::req::{
+    AppExchangeInfo as FfiAppExchangeInfo, AuthReq as FfiAuthReq,
+    ContainersReq as FfiContainersReq, PermissionsReq as FfiPermissionsReq,
+    ShareADataRequest as FfiShareADataReq, ShareMDataRequest as FfiShareMDataReq,
+    TransOwnershipRequest as FfiTransOwnershipReq,
+};
+use safe_core::ffi::ipc::resp::{
+    MetadataResponse as FfiMetadataResponse, OwnershipChange as FfiOwnershipChange,
+};
+use safe_core::ipc::{
+    self, AuthReq, ContainersReq, IpcEncoding, IpcError, IpcFormat, IpcMsg, IpcReq, IpcResp,
+    Permission, PermissionsReq, ShareADataReq, TransOwnershipReq, IPC_VERSION,
+};
+use safe_core::{app_container_name, mdata_info, AuthActions, Client, CoreError};
+use safe_core::{btree_set, ok};
+use safe_nd::{AppPermissions, Error as SndError};
 use std::collections::HashMap;
 use std::ffi::CString;
+use std::os::raw::c_void;
+use std::slice;
 use std::sync::mpsc;
 use std::time::Duration;
 use tiny_keccak::sha3_256;
@@ -47,18 +72,22 @@ mod mock_routing {
     use super::utils;
     use crate::access_container as access_container_tools;
     use crate::errors::AuthError;
+    use crate::ffi::coins::auth_transaction_history;
     use crate::run;
     use crate::std_dirs::{DEFAULT_PRIVATE_DIRS, DEFAULT_PUBLIC_DIRS};
     use crate::{test_utils, Authenticator};
+    use ffi_utils::test_utils::call_vec;
+    use ffi_utils::{ReprC, StringError};
     use futures::Future;
+    use safe_core::ffi::coins::TransactionRecord as FfiTransactionRecord;
     use safe_core::ipc::AuthReq;
     use safe_core::nfs::NfsError;
     use safe_core::utils::generate_random_string;
-    use safe_core::utils::test_utils::gen_client_id;
+    use safe_core::utils::test_utils::{gen_bls_keypair, gen_client_id, TEST_KDF};
     use safe_core::{
         app_container_name, test_create_balance, Client, ConnectionManager, CoreError,
     };
-    use safe_nd::{Coins, Error as SndError, Request, RequestType, Response};
+    use safe_nd::{Coins, Error as SndError, Request, RequestType, Response, XorName};
     use std::str::FromStr;
     use unwrap::unwrap;
 
@@ -113,6 +142,7 @@ mod mock_routing {
                 locator.clone(),
                 password.clone(),
                 client_id,
+                TEST_KDF,
                 || (),
                 cm_hook,
             );
@@ -126,7 +156,12 @@ mod mock_routing {
         }
 
         // Log in using the same credentials
-        let authenticator = unwrap!(Authenticator::login(locator, password, || ()));
+        let authenticator = unwrap!(Authenticator::login_with_kdf(
+            locator,
+            password,
+            TEST_KDF,
+            || ()
+        ));
 
         // Make sure that all default directories have been created after log in.
         let std_dir_names: Vec<_> = DEFAULT_PRIVATE_DIRS
@@ -224,6 +259,7 @@ mod mock_routing {
             locator.clone(),
             password.clone(),
             client_id,
+            TEST_KDF,
             || (),
             cm_hook,
         ));
@@ -234,6 +270,8 @@ mod mock_routing {
             app_container: true,
             app_permissions: Default::default(),
             containers: utils::create_containers_req(),
+            response_key: None,
+            ttl: None,
         };
         let app_id = auth_req.app.id.clone();
 
@@ -270,6 +308,7 @@ mod mock_routing {
         let auth = unwrap!(Authenticator::login_with_hook(
             locator.clone(),
             password.clone(),
+            TEST_KDF,
             || (),
             cm_hook,
         ));
@@ -297,6 +336,7 @@ mod mock_routing {
         let auth = unwrap!(Authenticator::login_with_hook(
             locator.clone(),
             password.clone(),
+            TEST_KDF,
             || (),
             cm_hook,
         ));
@@ -325,6 +365,7 @@ mod mock_routing {
         let auth = unwrap!(Authenticator::login_with_hook(
             locator.clone(),
             password.clone(),
+            TEST_KDF,
             || (),
             cm_hook,
         ));
@@ -335,7 +376,12 @@ mod mock_routing {
 
         // Now try to authenticate the app without network failure simulation -
         // it should succeed.
-        let auth = unwrap!(Authenticator::login(locator, password, || (),));
+        let auth = unwrap!(Authenticator::login_with_kdf(
+            locator,
+            password,
+            TEST_KDF,
+            || (),
+        ));
         let auth_granted = match test_utils::register_app(&auth, &auth_req) {
             Ok(auth_granted) => auth_granted,
             x => panic!("Unexpected {:?}", x),
@@ -372,6 +418,53 @@ mod mock_routing {
                 })
         }));
     }
+
+    // Test fetching the coin transaction history through the FFI.
+    // 1. Create an account and preload a new coin balance from it.
+    // 2. Fetch the transaction history using `auth_transaction_history`.
+    // 3. Verify that the latest entry records the coins sent to the new balance.
+    #[test]
+    fn transaction_history() {
+        struct Record {
+            id: u64,
+            sent: bool,
+            counterparty: XorName,
+            amount: u64,
+        }
+
+        impl ReprC for Record {
+            type C = *const FfiTransactionRecord;
+            type Error = StringError;
+
+            unsafe fn clone_from_repr_c(repr_c: Self::C) -> Result<Self, Self::Error> {
+                Ok(Record {
+                    id: (*repr_c).id,
+                    sent: (*repr_c).sent,
+                    counterparty: XorName((*repr_c).counterparty),
+                    amount: (*repr_c).amount,
+                })
+            }
+        }
+
+        let auth = test_utils::create_account_and_login();
+        let new_balance_owner = gen_bls_keypair().public_key();
+        let amount = unwrap!(Coins::from_str("1.5"));
+
+        let transaction = unwrap!(run(&auth, move |client| {
+            client
+                .create_balance(None, new_balance_owner, amount, None)
+                .map_err(AuthError::from)
+        }));
+
+        let history: Vec<Record> =
+            unsafe { unwrap!(call_vec(|ud, cb| auth_transaction_history(&auth, ud, cb))) };
+        let latest = unwrap!(history.last());
+
+        assert_eq!(latest.id, transaction.id);
+        assert!(latest.sent);
+        assert_eq!(latest.counterparty, XorName::from(new_balance_owner));
+        assert_eq!(latest.amount, amount.as_nano());
+    }
 }
 
 // Test creation and content of std dirs after account creation.
@@ -463,6 +556,8 @@ fn app_authentication() {
         app_container: true,
         app_permissions: Default::default(),
         containers,
+        response_key: None,
+        ttl: None,
     };
 
     let msg = IpcMsg::Req {
@@ -602,6 +697,8 @@ fn invalid_container_authentication() {
         app_container: true,
         app_permissions: Default::default(),
         containers,
+        response_key: None,
+        ttl: None,
     };
 
     // Try to send IpcReq::Auth - it should fail
@@ -624,6 +721,250 @@ fn invalid_container_authentication() {
     };
 }
 
+// Test sealed authentication responses.
+// 1. Authenticate an app that provided an ephemeral response key. The response must be sealed
+//    and only open with the matching secret key and request ID, and the user's account key.
+// 2. Deny another sealed request. The denial must be sealed too.
+#[test]
+fn sealed_app_authentication() {
+    let authenticator = test_utils::create_account_and_login();
+    let (response_key, secret_key) = shared_box::gen_keypair();
+
+    let req_id = ipc::gen_req_id();
+    let auth_req = AuthReq {
+        app: test_utils::rand_app(),
+        app_container: false,
+        app_permissions: Default::default(),
+        containers: HashMap::new(),
+        response_key: Some(response_key),
+        ttl: None,
+    };
+
+    let encoded_auth_resp: String = unsafe {
+        unwrap!(call_1(|ud, cb| {
+            let auth_req = unwrap!(auth_req.clone().into_repr_c());
+            encode_auth_resp(
+                &authenticator,
+                &auth_req,
+                req_id,
+                true, // is_granted
+                ud,
+                cb,
+            )
+        }))
+    };
+
+    let sealed = match unwrap!(ipc::decode_msg(&encoded_auth_resp)) {
+        IpcMsg::Sealed(sealed) => sealed,
+        x => panic!("Unexpected {:?}", x),
+    };
+    let owner_key = unwrap!(run(&authenticator, |client| ok!(client.public_key())));
+    assert_eq!(sealed.signer, owner_key);
+    assert_eq!(sealed.req_id, req_id);
+
+    match unwrap!(ipc::decode_sealed_msg(
+        &encoded_auth_resp,
+        &secret_key,
+        req_id,
+        Some(&owner_key)
+    )) {
+        (
+            IpcMsg::Resp {
+                response: IpcResp::Auth(Ok(_)),
+                ..
+            },
+            Some(signer),
+        ) => assert_eq!(signer, owner_key),
+        x => panic!("Unexpected {:?}", x),
+    }
+
+    // A response to a different request must be rejected.
+    match ipc::decode_sealed_msg(
+        &encoded_auth_resp,
+        &secret_key,
+        req_id.wrapping_add(1),
+        Some(&owner_key),
+    ) {
+        Err(IpcError::ReqIdMismatch) => (),
+        x => panic!("Unexpected {:?}", x),
+    }
+
+    // Nobody else can open the response.
+    let (_, other_secret_key) = shared_box::gen_keypair();
+    assert!(ipc::decode_sealed_msg(&encoded_auth_resp, &other_secret_key, req_id, None).is_err());
+
+    // The response is rejected unless it's signed by the account the app expects.
+    let other_key = unwrap!(run(&test_utils::create_account_and_login(), |client| ok!(
+        client.public_key()
+    )));
+    match ipc::decode_sealed_msg(&encoded_auth_resp, &secret_key, req_id, Some(&other_key)) {
+        Err(IpcError::InvalidSignature) => (),
+        x => panic!("Unexpected {:?}", x),
+    }
+
+    let req_id = ipc::gen_req_id();
+    let auth_req = AuthReq {
+        app: test_utils::rand_app(),
+        ..auth_req
+    };
+    let encoded_auth_resp: String = unsafe {
+        unwrap!(call_1(|ud, cb| {
+            let auth_req = unwrap!(auth_req.into_repr_c());
+            encode_auth_resp(
+                &authenticator,
+                &auth_req,
+                req_id,
+                false, // is_granted
+                ud,
+                cb,
+            )
+        }))
+    };
+
+    match unwrap!(ipc::decode_sealed_msg(
+        &encoded_auth_resp,
+        &secret_key,
+        req_id,
+        Some(&owner_key)
+    )) {
+        (
+            IpcMsg::Resp {
+                response: IpcResp::Auth(Err(IpcError::AuthDenied)),
+                ..
+            },
+            _,
+        ) => (),
+        x => panic!("Unexpected {:?}", x),
+    }
+}
+
+// Test that requests are answered in the wire format they were sent in.
+// 1. Send a JSON encoded auth request. The response must be JSON encoded too.
+// 2. Send a legacy encoded auth request. The response must use the legacy format.
+#[test]
+fn versioned_app_authentication() {
+    let authenticator = test_utils::create_account_and_login();
+
+    for format in vec![IpcFormat::current(IpcEncoding::Json), IpcFormat::legacy()] {
+        let req_id = ipc::gen_req_id();
+        let msg = IpcMsg::Req {
+            req_id,
+            request: IpcReq::Auth(AuthReq {
+                app: test_utils::rand_app(),
+                app_container: false,
+                app_permissions: Default::default(),
+                containers: HashMap::new(),
+                response_key: None,
+                ttl: None,
+            }),
+        };
+        let encoded_msg = unwrap!(ipc::encode_msg_as(&msg, format));
+
+        let auth_req = match unwrap!(test_utils::auth_decode_ipc_msg_helper(
+            &authenticator,
+            &encoded_msg
+        )) {
+            (
+                IpcMsg::Req {
+                    request: IpcReq::Auth(auth_req),
+                    ..
+                },
+                _,
+            ) => auth_req,
+            x => panic!("Unexpected {:?}", x),
+        };
+
+        let encoded_auth_resp: String = unsafe {
+            unwrap!(call_1(|ud, cb| {
+                let auth_req = unwrap!(auth_req.into_repr_c());
+                encode_auth_resp(
+                    &authenticator,
+                    &auth_req,
+                    req_id,
+                    true, // is_granted
+                    ud,
+                    cb,
+                )
+            }))
+        };
+
+        match unwrap!(ipc::decode_msg_with_format(&encoded_auth_resp)) {
+            (
+                IpcMsg::Resp {
+                    response: IpcResp::Auth(Ok(_)),
+                    ..
+                },
+                resp_format,
+            ) => assert_eq!(resp_format, format.reply_format()),
+            x => panic!("Unexpected {:?}", x),
+        }
+    }
+}
+
+// Test that requests the authenticator can't answer as asked are rejected with a response the
+// app understands.
+// 1. Ask for a sealed response in the legacy format, which can't advertise that the app is able
+//    to open it. The request must be rejected.
+// 2. Ask for it in the versioned format. The request must be accepted.
+// 3. Apps using an unsupported version must be told so in the legacy format.
+#[test]
+fn unsupported_ipc_requests() {
+    let authenticator = test_utils::create_account_and_login();
+    let (response_key, _) = shared_box::gen_keypair();
+
+    let msg = IpcMsg::Req {
+        req_id: ipc::gen_req_id(),
+        request: IpcReq::Auth(AuthReq {
+            app: test_utils::rand_app(),
+            app_container: false,
+            app_permissions: Default::default(),
+            containers: HashMap::new(),
+            response_key: Some(response_key),
+            ttl: None,
+        }),
+    };
+
+    let encoded_msg = unwrap!(ipc::encode_msg_as(&msg, IpcFormat::legacy()));
+    match test_utils::auth_decode_ipc_msg_helper(&authenticator, &encoded_msg) {
+        Err((
+            ERR_INVALID_MSG,
+            Some(IpcMsg::Resp {
+                response: IpcResp::Auth(Err(IpcError::InvalidMsg)),
+                ..
+            }),
+        )) => (),
+        x => panic!("Unexpected {:?}", x),
+    }
+
+    let encoded_msg = unwrap!(ipc::encode_msg_as(
+        &msg,
+        IpcFormat::current(IpcEncoding::Bincode)
+    ));
+    match unwrap!(test_utils::auth_decode_ipc_msg_helper(
+        &authenticator,
+        &encoded_msg
+    )) {
+        (
+            IpcMsg::Req {
+                request: IpcReq::Auth(_),
+                ..
+            },
+            _,
+        ) => (),
+        x => panic!("Unexpected {:?}", x),
+    }
+
+    let error = IpcError::UnsupportedVersion(IPC_VERSION + 1);
+    let response = unwrap!(unwrap!(encode_decode_error(&error)));
+    match unwrap!(ipc::decode_msg_with_format(unwrap!(response.to_str()))) {
+        (IpcMsg::Err(IpcError::UnsupportedVersion(version)), format) => {
+            assert_eq!(version, IPC_VERSION + 1);
+            assert_eq!(format, IpcFormat::legacy());
+        }
+        x => panic!("Unexpected {:?}", x),
+    }
+}
+
 // Test unregistered client authentication.
 // First, try to send a full auth request - it must fail with "Forbidden".
 // Then try to send a request for IpcReq::Unregistered, which must pass.
@@ -640,6 +981,8 @@ fn unregistered_authentication() {
             app_container: true,
             app_permissions: Default::default(),
             containers: utils::create_containers_req(),
+            response_key: None,
+            ttl: None,
         }),
     };
     let encoded_msg = unwrap!(ipc::encode_msg(&msg));
@@ -728,6 +1071,8 @@ fn authenticated_app_can_be_authenticated_again() {
         app_container: false,
         app_permissions: Default::default(),
         containers: Default::default(),
+        response_key: None,
+        ttl: None,
     };
 
     let req_id = ipc::gen_req_id();
@@ -835,6 +1180,8 @@ fn containers_access_request() {
         app_container: true,
         app_permissions: Default::default(),
         containers: utils::create_containers_req(),
+        response_key: None,
+        ttl: None,
     };
     let app_id = auth_req.app.id.clone();
 
@@ -892,6 +1239,183 @@ fn containers_access_request() {
     );
 }
 
+// Create and serialize a permissions request for a random app, make sure we get an error.
+#[test]
+fn permissions_unknown_app() {
+    let authenticator = test_utils::create_account_and_login();
+
+    let msg = IpcMsg::Req {
+        req_id: ipc::gen_req_id(),
+        request: IpcReq::Permissions(PermissionsReq {
+            app: test_utils::rand_app(),
+            containers: utils::create_containers_req(),
+            app_permissions: None,
+        }),
+    };
+    let encoded_msg = unwrap!(ipc::encode_msg(&msg));
+
+    match test_utils::auth_decode_ipc_msg_helper(&authenticator, &encoded_msg) {
+        Err((
+            code,
+            Some(IpcMsg::Resp {
+                response: IpcResp::Permissions(Err(IpcError::UnknownApp)),
+                ..
+            }),
+        )) if code == ERR_UNKNOWN_APP => (),
+        x => panic!("Unexpected {:?}", x),
+    };
+}
+
+// Test modifying the permissions of an authenticated app.
+// 1. Authenticate an app with access to "_documents" and "_videos".
+// 2. Downgrade "_videos" to read-only, drop "_documents", add "_downloads" and allow the app
+//    to perform mutations.
+// 3. Verify the access container entry, the container permissions and the app's key.
+// 4. Deny another request and verify nothing is granted.
+#[test]
+fn permissions_modify_request() {
+    let authenticator = test_utils::create_account_and_login();
+
+    let auth_req = AuthReq {
+        app: test_utils::rand_app(),
+        app_container: false,
+        app_permissions: Default::default(),
+        containers: utils::create_containers_req(),
+        response_key: None,
+        ttl: None,
+    };
+    let app_id = auth_req.app.id.clone();
+
+    let auth_granted = unwrap!(test_utils::register_app(&authenticator, &auth_req));
+    let app_pk = auth_granted.app_keys.public_key();
+    let (documents, _) = unwrap!(auth_granted
+        .access_container_entry
+        .get("_documents")
+        .cloned());
+
+    let mut containers = HashMap::new();
+    let _ = containers.insert("_videos".to_string(), btree_set![Permission::Read]);
+    let _ = containers.insert("_documents".to_string(), Default::default());
+    let _ = containers.insert("_downloads".to_string(), btree_set![Permission::Update]);
+    let app_permissions = AppPermissions {
+        transfer_coins: false,
+        perform_mutations: true,
+        get_balance: true,
+    };
+    let perms_req = PermissionsReq {
+        app: auth_req.app.clone(),
+        containers,
+        app_permissions: Some(app_permissions),
+    };
+
+    let msg = IpcMsg::Req {
+        req_id: ipc::gen_req_id(),
+        request: IpcReq::Permissions(perms_req.clone()),
+    };
+    let encoded_msg = unwrap!(ipc::encode_msg(&msg));
+
+    match unwrap!(test_utils::auth_decode_ipc_msg_helper(
+        &authenticator,
+        &encoded_msg
+    )) {
+        (
+            IpcMsg::Req {
+                request: IpcReq::Permissions(ref decoded),
+                ..
+            },
+            None,
+        ) => assert_eq!(*decoded, perms_req),
+        x => panic!("Unexpected {:?}", x),
+    };
+
+    let req_id = ipc::gen_req_id();
+    let encoded_perms_resp: String = unsafe {
+        unwrap!(call_1(|ud, cb| {
+            let perms_req = unwrap!(perms_req.into_repr_c());
+            encode_permissions_resp(
+                &authenticator,
+                &perms_req,
+                req_id,
+                true, // is_granted
+                ud,
+                cb,
+            )
+        }))
+    };
+
+    match ipc::decode_msg(&encoded_perms_resp) {
+        Ok(IpcMsg::Resp {
+            response: IpcResp::Permissions(Ok(())),
+            ..
+        }) => (),
+        x => panic!("Unexpected {:?}", x),
+    }
+
+    let mut expected = HashMap::new();
+    let _ = expected.insert("_videos".to_owned(), btree_set![Permission::Read]);
+    let _ = expected.insert("_downloads".to_owned(), btree_set![Permission::Update]);
+
+    let access_container = test_utils::access_container(&authenticator, app_id, auth_granted);
+    assert!(!access_container.contains_key("_documents"));
+    test_utils::compare_access_container_entries(
+        &authenticator,
+        app_pk,
+        access_container,
+        expected,
+    );
+
+    // The app must have lost its permissions for "_documents".
+    unwrap!(run(&authenticator, move |client| {
+        client
+            .list_mdata_user_permissions(*documents.address(), app_pk)
+            .then(|res| match res {
+                Err(CoreError::DataError(SndError::NoSuchKey)) => Ok(()),
+                x => Err(AuthError::Unexpected(format!("Unexpected {:?}", x))),
+            })
+    }));
+
+    let auth_keys = unwrap!(run(&authenticator, |client| {
+        client
+            .list_auth_keys_and_version()
+            .map(|(keys, _)| keys)
+            .map_err(AuthError::from)
+    }));
+    assert_eq!(auth_keys.get(&app_pk), Some(&app_permissions));
+
+    // Deny a request to grant every permission.
+    let perms_req = PermissionsReq {
+        app: auth_req.app,
+        containers: HashMap::new(),
+        app_permissions: Some(AppPermissions {
+            transfer_coins: true,
+            perform_mutations: true,
+            get_balance: true,
+        }),
+    };
+    let encoded_perms_resp: String = unsafe {
+        unwrap!(call_1(|ud, cb| {
+            let perms_req = unwrap!(perms_req.into_repr_c());
+            encode_permissions_resp(&authenticator, &perms_req, req_id, false, ud, cb)
+        }))
+    };
+
+    match ipc::decode_msg(&encoded_perms_resp) {
+        Ok(IpcMsg::Resp {
+            response: IpcResp::Permissions(Err(IpcError::PermissionsDenied)),
+            ..
+        }) => (),
+        x => panic!("Unexpected {:?}", x),
+    }
+
+    let auth_keys = unwrap!(run(&authenticator, |client| {
+        client
+            .list_auth_keys_and_version()
+            .map(|(keys, _)| keys)
+            .map_err(AuthError::from)
+    }));
+    assert_eq!(auth_keys.get(&app_pk), Some(&app_permissions));
+}
+
 struct RegisteredAppId {
     id: String,
     perms: FfiAppPermissions,
@@ -949,6 +1473,8 @@ fn lists_of_registered_and_revoked_apps() {
         app_container: false,
         app_permissions: Default::default(),
         containers: Default::default(),
+        response_key: None,
+        ttl: None,
     };
 
     let auth_req2 = AuthReq {
@@ -956,6 +1482,8 @@ fn lists_of_registered_and_revoked_apps() {
         app_container: false,
         app_permissions: Default::default(),
         containers: Default::default(),
+        response_key: None,
+        ttl: None,
     };
 
     let _ = unwrap!(test_utils::register_app(&authenticator, &auth_req1));
@@ -1038,6 +1566,8 @@ fn test_registered_apps() {
         app_container: false,
         app_permissions: app1_perms,
         containers: Default::default(),
+        response_key: None,
+        ttl: None,
     };
 
     // Permissions for App2
@@ -1052,6 +1582,8 @@ fn test_registered_apps() {
         app_container: false,
         app_permissions: app2_perms,
         containers: Default::default(),
+        response_key: None,
+        ttl: None,
     };
 
     // Register both the apps.
@@ -1083,6 +1615,282 @@ fn test_registered_apps() {
     }
 }
 
+// Test that `auth_decode_ipc_msg` keeps decoding the requests it has callbacks for, while newer
+// kinds of requests are reported through `o_err`.
+// 1. Decode an auth request. It must be passed to `o_auth`.
+// 2. Decode a permissions request for the registered app. It must be rejected.
+#[test]
+fn decode_with_legacy_callbacks() {
+    let authenticator = test_utils::create_account_and_login();
+
+    let auth_req = AuthReq {
+        app: test_utils::rand_app(),
+        app_container: false,
+        app_permissions: Default::default(),
+        containers: utils::create_containers_req(),
+        response_key: None,
+        ttl: None,
+    };
+    let req_id = ipc::gen_req_id();
+    let msg = IpcMsg::Req {
+        req_id,
+        request: IpcReq::Auth(auth_req.clone()),
+    };
+    let encoded_msg = unwrap!(ipc::encode_msg(&msg));
+
+    match unwrap!(legacy_decode_ipc_msg(&authenticator, &encoded_msg)) {
+        (
+            IpcMsg::Req {
+                req_id: received_req_id,
+                request: IpcReq::Auth(ref decoded),
+            },
+            None,
+        ) => {
+            assert_eq!(received_req_id, req_id);
+            assert_eq!(*decoded, auth_req);
+        }
+        x => panic!("Unexpected {:?}", x),
+    }
+
+    let _ = unwrap!(test_utils::register_app(&authenticator, &auth_req));
+    let msg = IpcMsg::Req {
+        req_id: ipc::gen_req_id(),
+        request: IpcReq::Permissions(PermissionsReq {
+            app: auth_req.app.clone(),
+            containers: Default::default(),
+            app_permissions: None,
+        }),
+    };
+    let encoded_msg = unwrap!(ipc::encode_msg(&msg));
+
+    match legacy_decode_ipc_msg(&authenticator, &encoded_msg) {
+        Err((ERR_UNEXPECTED, None)) => (),
+        x => panic!("Unexpected {:?}", x),
+    }
+}
+
+fn legacy_decode_ipc_msg(authenticator: &Authenticator, msg: &str) -> ChannelType {
+    let (tx, rx) = mpsc::channel::<ChannelType>();
+
+    extern "C" fn auth_cb(user_data: *mut c_void, req_id: u32, req: *const FfiAuthReq) {
+        unsafe {
+            let res = AuthReq::clone_from_repr_c(req)
+                .map(|req| {
+                    let msg = IpcMsg::Req {
+                        req_id,
+                        request: IpcReq::Auth(req),
+                    };
+                    (msg, None)
+                })
+                .map_err(|_| (-2, None));
+            send_via_user_data::<ChannelType>(user_data, res)
+        }
+    }
+
+    extern "C" fn containers_cb(user_data: *mut c_void, _: u32, _: *const FfiContainersReq) {
+        unsafe { send_via_user_data::<ChannelType>(user_data, Err((-3, None))) }
+    }
+
+    extern "C" fn share_mdata_cb(
+        user_data: *mut c_void,
+        _: u32,
+        _: *const FfiShareMDataReq,
+        _: *const FfiMetadataResponse,
+        _: usize,
+    ) {
+        unsafe { send_via_user_data::<ChannelType>(user_data, Err((-3, None))) }
+    }
+
+    let ffi_msg = unwrap!(CString::new(msg));
+    let mut ud = Default::default();
+
+    unsafe {
+        auth_decode_ipc_msg(
+            authenticator,
+            ffi_msg.as_ptr(),
+            sender_as_user_data(&tx, &mut ud),
+            auth_cb,
+            containers_cb,
+            test_utils::unregistered_cb,
+            share_mdata_cb,
+            test_utils::err_cb,
+        );
+    };
+
+    match rx.recv_timeout(Duration::from_secs(15)) {
+        Ok(r) => r,
+        Err(_) => Err((-1, None)),
+    }
+}
+
+// Test that `auth_decode_ipc_req_by_kind` passes the requests `auth_decode_ipc_msg` rejects to
+// the callbacks of their kind.
+// 1. Decode a permissions request for a registered app. It must be passed to `o_permissions`.
+// 2. Decode a request to share append-only data. It must be passed to `o_share_adata`.
+// 3. Decode a request to transfer ownership. It must be passed to `o_trans_ownership`.
+#[test]
+fn decode_with_callback_per_kind() {
+    let authenticator = test_utils::create_account_and_login();
+    let app_id = test_utils::rand_app();
+    let auth_req = AuthReq {
+        app: app_id.clone(),
+        app_container: false,
+        app_permissions: Default::default(),
+        containers: Default::default(),
+        response_key: None,
+        ttl: None,
+    };
+    let _ = unwrap!(test_utils::register_app(&authenticator, &auth_req));
+
+    let requests = vec![
+        IpcReq::Permissions(PermissionsReq {
+            app: app_id.clone(),
+            containers: Default::default(),
+            app_permissions: None,
+        }),
+        IpcReq::ShareAData(ShareADataReq {
+            app: app_id.clone(),
+            adata: vec![],
+        }),
+        IpcReq::TransOwnership(TransOwnershipReq {
+            app: app_id,
+            data: vec![],
+        }),
+    ];
+
+    for request in requests {
+        let req_id = ipc::gen_req_id();
+        let msg = IpcMsg::Req {
+            req_id,
+            request: request.clone(),
+        };
+        let encoded_msg = unwrap!(ipc::encode_msg(&msg));
+
+        match unwrap!(decode_ipc_msg_by_kind(&authenticator, &encoded_msg)) {
+            (
+                IpcMsg::Req {
+                    req_id: received_req_id,
+                    request: ref decoded,
+                },
+                _,
+            ) => {
+                assert_eq!(received_req_id, req_id);
+                assert_eq!(*decoded, request);
+            }
+            x => panic!("Unexpected {:?}", x),
+        }
+    }
+}
+
+fn decode_ipc_msg_by_kind(authenticator: &Authenticator, msg: &str) -> ChannelType {
+    let (tx, rx) = mpsc::channel::<ChannelType>();
+
+    extern "C" fn auth_cb(user_data: *mut c_void, _: u32, _: *const FfiAuthReq) {
+        unsafe { send_via_user_data::<ChannelType>(user_data, Err((-3, None))) }
+    }
+
+    extern "C" fn containers_cb(user_data: *mut c_void, _: u32, _: *const FfiContainersReq) {
+        unsafe { send_via_user_data::<ChannelType>(user_data, Err((-3, None))) }
+    }
+
+    extern "C" fn share_mdata_cb(
+        user_data: *mut c_void,
+        _: u32,
+        _: *const FfiShareMDataReq,
+        _: *const FfiMetadataResponse,
+        _: usize,
+    ) {
+        unsafe { send_via_user_data::<ChannelType>(user_data, Err((-3, None))) }
+    }
+
+    extern "C" fn share_adata_cb(
+        user_data: *mut c_void,
+        req_id: u32,
+        req: *const FfiShareADataReq,
+    ) {
+        unsafe {
+            let res = ShareADataReq::clone_from_repr_c(req)
+                .map(|req| {
+                    let msg = IpcMsg::Req {
+                        req_id,
+                        request: IpcReq::ShareAData(req),
+                    };
+                    (msg, None)
+                })
+                .map_err(|_| (-2, None));
+            send_via_user_data::<ChannelType>(user_data, res)
+        }
+    }
+
+    extern "C" fn trans_ownership_cb(
+        user_data: *mut c_void,
+        req_id: u32,
+        req: *const FfiTransOwnershipReq,
+        changes: *const FfiOwnershipChange,
+        changes_len: usize,
+    ) {
+        unsafe {
+            let res = TransOwnershipReq::clone_from_repr_c(req)
+                .and_then(|req| {
+                    let changes = slice::from_raw_parts(changes, changes_len)
+                        .iter()
+                        .map(|change| OwnershipChange::clone_from_repr_c(change))
+                        .collect::<Result<_, _>>()?;
+                    let msg = IpcMsg::Req {
+                        req_id,
+                        request: IpcReq::TransOwnership(req),
+                    };
+                    Ok((msg, Some(Payload::OwnershipChanges(changes))))
+                })
+                .map_err(|_| (-2, None));
+            send_via_user_data::<ChannelType>(user_data, res)
+        }
+    }
+
+    extern "C" fn permissions_cb(
+        user_data: *mut c_void,
+        req_id: u32,
+        req: *const FfiPermissionsReq,
+    ) {
+        unsafe {
+            let res = PermissionsReq::clone_from_repr_c(req)
+                .map(|req| {
+                    let msg = IpcMsg::Req {
+                        req_id,
+                        request: IpcReq::Permissions(req),
+                    };
+                    (msg, None)
+                })
+                .map_err(|_| (-2, None));
+            send_via_user_data::<ChannelType>(user_data, res)
+        }
+    }
+
+    let ffi_msg = unwrap!(CString::new(msg));
+    let mut ud = Default::default();
+
+    unsafe {
+        auth_decode_ipc_req_by_kind(
+            authenticator,
+            ffi_msg.as_ptr(),
+            sender_as_user_data(&tx, &mut ud),
+            auth_cb,
+            containers_cb,
+            test_utils::unregistered_cb,
+            share_mdata_cb,
+            share_adata_cb,
+            trans_ownership_cb,
+            permissions_cb,
+            test_utils::err_cb,
+        );
+    };
+
+    match rx.recv_timeout(Duration::from_secs(15)) {
+        Ok(r) => r,
+        Err(_) => Err((-1, None)),
+    }
+}
+
 fn unregistered_decode_ipc_msg(msg: &str) -> ChannelType {
     let (tx, rx) = mpsc::channel::<ChannelType>();
 
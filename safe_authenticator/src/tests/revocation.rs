@@ -10,6 +10,7 @@ use super::utils::{corrupt_container, create_containers_req};
 use crate::assert_match;
 use crate::{
     app_auth::{app_state, AppState},
+    apps,
     client::AuthClient,
     config::{self, get_app_revocation_queue, push_to_app_revocation_queue},
     errors::AuthError,
@@ -21,6 +22,7 @@ use crate::{
     },
     {access_container, run, AuthFuture, Authenticator},
 };
+use chrono::{Duration, Utc};
 use futures::{future, Future};
 use log::debug;
 use safe_core::btree_set;
@@ -173,7 +175,7 @@ mod mock_routing {
     use rand::FromEntropy;
     use safe_core::client::AuthActions;
     use safe_core::ipc::{IpcError, Permission};
-    use safe_core::utils::test_utils::Synchronizer;
+    use safe_core::utils::test_utils::{Synchronizer, TEST_KDF};
     use safe_core::ConnectionManager;
     use safe_nd::{Request, Response};
     use std::{
@@ -206,6 +208,8 @@ mod mock_routing {
             app_container: false,
             app_permissions: Default::default(),
             containers: create_containers_req(),
+            response_key: None,
+            ttl: None,
         };
         let app_id = auth_req.app.id.clone();
         let auth_granted = unwrap!(register_app(&auth, &auth_req));
@@ -230,9 +234,10 @@ mod mock_routing {
             true
         ));
 
-        let auth = unwrap!(Authenticator::login(
+        let auth = unwrap!(Authenticator::login_with_kdf(
             locator.clone(),
             password.clone(),
+            TEST_KDF,
             || (),
         ));
 
@@ -258,7 +263,12 @@ mod mock_routing {
         assert!(!auth_keys.contains_key(&auth_granted.app_keys.public_key()));
 
         // Login and revoke the app again.
-        let auth = unwrap!(Authenticator::login(locator, password, || (),));
+        let auth = unwrap!(Authenticator::login_with_kdf(
+            locator,
+            password,
+            TEST_KDF,
+            || (),
+        ));
 
         // App revocation should succeed
         revoke(&auth, &app_id);
@@ -290,6 +300,8 @@ mod mock_routing {
             app_container: false,
             app_permissions: Default::default(),
             containers: create_containers_req(),
+            response_key: None,
+            ttl: None,
         };
 
         let app_id = auth_req.app.id.clone();
@@ -329,6 +341,8 @@ mod mock_routing {
             app_container: false,
             app_permissions: Default::default(),
             containers: create_containers_req(),
+            response_key: None,
+            ttl: None,
         };
 
         let _ = unwrap!(register_app(&auth, &auth_req));
@@ -340,6 +354,8 @@ mod mock_routing {
             app_container: false,
             app_permissions: Default::default(),
             containers: create_containers_req(),
+            response_key: None,
+            ttl: None,
         };
 
         let _ = unwrap!(register_app(&auth, &auth_req));
@@ -375,7 +391,12 @@ mod mock_routing {
         }
 
         // Login again without simulated failures.
-        let auth = unwrap!(Authenticator::login(locator, password, || ()));
+        let auth = unwrap!(Authenticator::login_with_kdf(
+            locator,
+            password,
+            TEST_KDF,
+            || ()
+        ));
 
         // Flush the revocation queue and verify both apps get revoked.
         unsafe {
@@ -467,6 +488,7 @@ mod mock_routing {
                     let auth = unwrap!(Authenticator::login_with_hook(
                         locator,
                         password,
+                        TEST_KDF,
                         || (),
                         move |routing| sync.hook(routing),
                     ));
@@ -572,6 +594,7 @@ mod mock_routing {
                     let auth = unwrap!(Authenticator::login_with_hook(
                         locator,
                         password,
+                        TEST_KDF,
                         || (),
                         move |routing| sync.hook(routing),
                     ));
@@ -620,7 +643,12 @@ mod mock_routing {
         S: AsRef<str>,
     {
         // First, log in normally to obtain the access contained info.
-        let auth = unwrap!(Authenticator::login(locator, password, || ()));
+        let auth = unwrap!(Authenticator::login_with_kdf(
+            locator,
+            password,
+            TEST_KDF,
+            || ()
+        ));
         let ac_info = unwrap!(run(&auth, |client| Ok(client.access_container())));
 
         // Then, log in with a request hook that makes mutation of the access container
@@ -628,6 +656,7 @@ mod mock_routing {
         let auth = unwrap!(Authenticator::login_with_hook(
             locator,
             password,
+            TEST_KDF,
             || (),
             move |mut cm| -> ConnectionManager {
                 let ac_info = ac_info.clone();
@@ -668,6 +697,8 @@ fn app_revocation_and_reauth() {
         app_container: false,
         app_permissions: Default::default(),
         containers: create_containers_req(),
+        response_key: None,
+        ttl: None,
     };
     let app_id1 = auth_req1.app.id.clone();
     let auth_granted1 = unwrap!(register_app(&authenticator, &auth_req1));
@@ -677,6 +708,8 @@ fn app_revocation_and_reauth() {
         app_container: true,
         app_permissions: Default::default(),
         containers: create_containers_req(),
+        response_key: None,
+        ttl: None,
     };
     let app_id2 = auth_req2.app.id.clone();
     let auth_granted2 = unwrap!(register_app(&authenticator, &auth_req2));
@@ -859,6 +892,8 @@ fn revocation_symmetric_decipher_failure() {
         app_container: false,
         app_permissions: Default::default(),
         containers: create_containers_req(),
+        response_key: None,
+        ttl: None,
     };
     let app_id1 = auth_req1.app.id.clone();
     debug!("Registering app 1 with ID {}...", app_id1);
@@ -869,6 +904,8 @@ fn revocation_symmetric_decipher_failure() {
         app_container: true,
         app_permissions: Default::default(),
         containers: corrupt_containers,
+        response_key: None,
+        ttl: None,
     };
     let app_id2 = auth_req2.app.id.clone();
     debug!("Registering app 2 with ID {}...", app_id2);
@@ -879,6 +916,8 @@ fn revocation_symmetric_decipher_failure() {
         app_container: false,
         app_permissions: Default::default(),
         containers: create_containers_req(),
+        response_key: None,
+        ttl: None,
     };
     let app_id3 = auth_req3.app.id.clone();
     debug!("Registering app 3 with ID {}...", app_id3);
@@ -981,6 +1020,8 @@ fn flushing_empty_app_revocation_queue_does_not_mutate_network() {
         app_container: false,
         app_permissions: Default::default(),
         containers: create_containers_req(),
+        response_key: None,
+        ttl: None,
     };
     let _ = unwrap!(register_app(&auth, &auth_req));
     let app_id = auth_req.app.id;
@@ -1069,6 +1110,92 @@ fn revocation_with_unencrypted_container_entries() {
     }))
 }
 
+// Test that apps are revoked once their authorisation expires.
+// 1. Authorise an app with a TTL and an app without one.
+// 2. Verify that the expiry is reported for the registered apps.
+// 3. Sweep the expired apps. Nothing must be revoked yet.
+// 4. Move the expiry of the first app to the past and sweep again. It must be revoked while the
+//    other app stays authenticated.
+// 5. Re-authorise the revoked app without a TTL. It must no longer expire.
+#[test]
+fn app_authorisation_expiry() {
+    let auth = create_account_and_login();
+
+    let auth_req = |app, ttl| AuthReq {
+        app,
+        app_container: false,
+        app_permissions: Default::default(),
+        containers: HashMap::new(),
+        response_key: None,
+        ttl,
+    };
+
+    let expiring = rand_app();
+    let expiring_id = expiring.id.clone();
+    let permanent = rand_app();
+    let permanent_id = permanent.id.clone();
+
+    let _ = unwrap!(register_app(&auth, &auth_req(expiring.clone(), Some(3600))));
+    let _ = unwrap!(register_app(&auth, &auth_req(permanent, None)));
+
+    let registered = unwrap!(run(&auth, apps::list_registered));
+    let expiry = |app_id: &str| {
+        unwrap!(registered
+            .iter()
+            .find(|app| app.app_info.id == app_id)
+            .map(|app| app.expires_at))
+    };
+    let expires_at = unwrap!(expiry(&expiring_id));
+    assert!(expires_at > Utc::now() + Duration::minutes(59));
+    assert!(expires_at <= Utc::now() + Duration::hours(1));
+    assert_eq!(expiry(&permanent_id), None);
+
+    let expired = unwrap!(run(&auth, revocation::revoke_expired_apps));
+    assert!(expired.is_empty());
+
+    let app_id = expiring_id.clone();
+    unwrap!(run(&auth, move |client| {
+        let c2 = client.clone();
+        let expires_at = Utc::now() - Duration::seconds(1);
+
+        config::list_app_expiries(client)
+            .and_then(move |(version, expiries)| {
+                config::set_app_expiry(
+                    &c2,
+                    expiries,
+                    config::next_version(version),
+                    &app_id,
+                    Some(expires_at),
+                )
+            })
+            .map(|_| ())
+    }));
+
+    let expired = unwrap!(run(&auth, revocation::revoke_expired_apps));
+    assert_eq!(expired, vec![expiring_id.clone()]);
+
+    let (expiring_state, permanent_state) = {
+        let expiring_id = expiring_id.clone();
+        unwrap!(run(&auth, move |client| {
+            let c2 = client.clone();
+
+            config::list_apps(client).and_then(move |(_, apps)| {
+                app_state(&c2, &apps, &expiring_id).join(app_state(&c2, &apps, &permanent_id))
+            })
+        }))
+    };
+    assert_eq!(expiring_state, AppState::Revoked);
+    assert_eq!(permanent_state, AppState::Authenticated);
+
+    // The revoked app is not swept again.
+    let expired = unwrap!(run(&auth, revocation::revoke_expired_apps));
+    assert!(expired.is_empty());
+
+    let _ = unwrap!(register_app(&auth, &auth_req(expiring, None)));
+    let (_, expiries) = unwrap!(run(&auth, config::list_app_expiries));
+    assert!(expiries.is_empty());
+}
+
 fn count_mdata_entries(authenticator: &Authenticator, info: MDataInfo) -> usize {
     unwrap!(run(authenticator, move |client| {
         client
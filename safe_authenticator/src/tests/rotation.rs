@@ -0,0 +1,218 @@
+// Copyright 2019 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+use crate::config;
+use crate::errors::AuthError;
+use crate::run;
+use crate::test_utils::{
+    create_authenticator, create_file, fetch_file, get_app_or_err,
+    get_container_from_authenticator_entry, register_rand_app,
+};
+use crate::Authenticator;
+use futures::Future;
+use rand::thread_rng;
+use safe_core::client::{LocalSigner, SafeKey};
+use safe_core::utils::test_utils::TEST_KDF;
+use safe_core::Client;
+use safe_nd::{ClientFullId, PublicKey};
+use std::sync::Arc;
+use unwrap::unwrap;
+
+fn public_key(auth: &Authenticator) -> PublicKey {
+    unwrap!(run(auth, |client| Ok::<_, AuthError>(client.public_key())))
+}
+
+// Test rotating the client keys of an account.
+// 1. Register an app so that the config root isn't empty.
+// 2. Rotate the keys and verify that the account uses the new ones and owns the coins.
+// 3. Log in again and verify that the config, the authenticator entry and the default
+//    containers are still usable.
+#[test]
+fn rotate_client_keys() {
+    let (auth, locator, password) = create_authenticator();
+    let (app_id, _) = unwrap!(register_rand_app(&auth, false, Default::default()));
+    let old_pk = public_key(&auth);
+
+    unwrap!(auth.rotate_client_keys());
+
+    let new_pk = public_key(&auth);
+    assert_ne!(new_pk, old_pk);
+
+    let balance = unwrap!(run(&auth, |client| client
+        .get_balance(None)
+        .map_err(AuthError::from)));
+    assert!(balance.as_nano() > 0);
+
+    let auth = unwrap!(Authenticator::login_with_kdf(
+        locator,
+        password,
+        TEST_KDF,
+        || ()
+    ));
+    assert_eq!(public_key(&auth), new_pk);
+
+    let _ = unwrap!(get_app_or_err(&auth, &app_id));
+    let (_, rotation) = unwrap!(run(&auth, config::get_key_rotation));
+    assert!(rotation.is_none());
+
+    let documents = unwrap!(get_container_from_authenticator_entry(&auth, "_documents"));
+    unwrap!(create_file(
+        &auth,
+        documents.clone(),
+        "hello.txt",
+        vec![1; 10],
+        false
+    ));
+    let _ = unwrap!(fetch_file(&auth, documents, "hello.txt"));
+}
+
+// Test that rotating the client keys hands the account data over to the new keys.
+// 1. Register an app so that the audit log exists.
+// 2. Rotate the keys.
+// 3. Verify that the new keys own the access container and the audit log, and that the old
+//    keys have no permissions on them anymore.
+#[cfg(feature = "mock-network")]
+#[test]
+fn rotation_hands_over_data() {
+    use safe_nd::ADataIndex;
+
+    let (auth, _, _) = create_authenticator();
+    let _ = unwrap!(register_rand_app(&auth, false, Default::default()));
+    let old_pk = public_key(&auth);
+
+    unwrap!(auth.rotate_client_keys());
+    let new_pk = public_key(&auth);
+
+    let (owner, permissions) = unwrap!(run(&auth, |client| {
+        let access_container = client.access_container();
+        let c2 = client.clone();
+
+        client
+            .get_seq_mdata_shell(access_container.name(), access_container.type_tag())
+            .join(c2.list_mdata_permissions(*access_container.address()))
+            .map_err(AuthError::from)
+    }));
+    assert_eq!(*owner.owner(), new_pk);
+    assert!(!permissions.contains_key(&old_pk));
+
+    let (owner, permissions) = unwrap!(run(&auth, |client| {
+        let c2 = client.clone();
+
+        config::get_audit_log(client).and_then(move |(_, log)| {
+            let address = unwrap!(log).address;
+
+            c2.get_adata_owners(address, ADataIndex::FromEnd(1))
+                .join(c2.get_unpub_adata_permissions_at_index(address, ADataIndex::FromEnd(1)))
+                .map_err(AuthError::from)
+        })
+    }));
+    assert_eq!(owner.public_key, new_pk);
+    assert!(!permissions.permissions.contains_key(&old_pk));
+    assert!(permissions.permissions.contains_key(&new_pk));
+}
+
+// Test resuming an interrupted key rotation.
+// 1. Make the coin transfer fail, so that the rotation stops before the account packet is
+//    updated.
+// 2. Log in again and verify that the account still uses the old keys.
+// 3. Rotate again and verify that the keys generated by the first attempt are used.
+#[cfg(feature = "mock-network")]
+#[test]
+fn resume_client_key_rotation() {
+    use safe_core::ConnectionManager;
+    use safe_nd::{Error as SndError, Request, Response};
+
+    let (_, locator, password) = create_authenticator();
+
+    let auth = unwrap!(Authenticator::login_with_hook(
+        locator.clone(),
+        password.clone(),
+        TEST_KDF,
+        || (),
+        |mut cm: ConnectionManager| -> ConnectionManager {
+            cm.set_request_hook(|req| match *req {
+                Request::TransferCoins { .. } => {
+                    Some(Response::Transaction(Err(SndError::InsufficientBalance)))
+                }
+                _ => None,
+            });
+            cm
+        },
+    ));
+    let old_pk = public_key(&auth);
+
+    match auth.rotate_client_keys() {
+        Err(AuthError::CoreError(_)) => (),
+        x => panic!("Unexpected {:?}", x),
+    }
+
+    let auth = unwrap!(Authenticator::login_with_kdf(
+        locator.clone(),
+        password.clone(),
+        TEST_KDF,
+        || ()
+    ));
+    assert_eq!(public_key(&auth), old_pk);
+
+    let (_, rotation) = unwrap!(run(&auth, config::get_key_rotation));
+    let rotation = unwrap!(rotation);
+    assert!(rotation.moving_balance);
+
+    unwrap!(auth.rotate_client_keys());
+    assert_eq!(public_key(&auth), rotation.new_keys.public_key());
+
+    let auth = unwrap!(Authenticator::login_with_kdf(
+        locator,
+        password,
+        TEST_KDF,
+        || ()
+    ));
+    assert_eq!(public_key(&auth), rotation.new_keys.public_key());
+
+    let (_, rotation) = unwrap!(run(&auth, config::get_key_rotation));
+    assert!(rotation.is_none());
+}
+
+// Test that the keys of an account held by an external signer can't be rotated.
+// 1. Log in with a signer holding the account's key and verify that it signs the requests.
+// 2. Verify that rotating the keys fails and leaves the account's key in place.
+// 3. Verify that logging in with a signer holding other keys fails.
+#[test]
+fn rotate_external_signer_keys() {
+    let (auth, locator, password) = create_authenticator();
+    let full_id = unwrap!(run(&auth, |client| Ok::<_, AuthError>(client.full_id())));
+    let old_pk = full_id.public_key();
+    let signer = Arc::new(LocalSigner::new(full_id));
+
+    let auth = unwrap!(Authenticator::login_with_signer(
+        locator.clone(),
+        password.clone(),
+        signer.clone(),
+        || ()
+    ));
+    let signatures = signer.signatures();
+    let _ = unwrap!(run(&auth, |client| client
+        .get_balance(None)
+        .map_err(AuthError::from)));
+    assert!(signer.signatures() > signatures);
+
+    match auth.rotate_client_keys() {
+        Err(AuthError::Unexpected(_)) => (),
+        x => panic!("Unexpected {:?}", x),
+    }
+    assert_eq!(public_key(&auth), old_pk);
+
+    let other_signer = Arc::new(LocalSigner::new(SafeKey::client(ClientFullId::new_bls(
+        &mut thread_rng(),
+    ))));
+    match Authenticator::login_with_signer(locator, password, other_signer, || ()) {
+        Err(AuthError::Unexpected(_)) => (),
+        Err(err) => panic!("Unexpected {:?}", err),
+        Ok(_) => panic!("Unexpected success"),
+    }
+}
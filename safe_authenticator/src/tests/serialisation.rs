@@ -26,7 +26,7 @@ use safe_core::config_handler;
 use safe_core::core_structs::AccessContainerEntry;
 use safe_core::ipc::req::ContainerPermissions;
 use safe_core::ipc::{AppExchangeInfo, AuthReq, Permission};
-use safe_core::utils::test_utils::gen_client_id;
+use safe_core::utils::test_utils::{gen_client_id, TEST_KDF};
 use safe_core::{mock_vault_path, utils};
 use safe_core::{test_create_balance, Client, FutureExt, MDataInfo};
 use safe_nd::{ClientFullId, Coins};
@@ -54,10 +54,11 @@ fn serialisation_write_data() {
     // Set up a fresh mock vault.
     let stash = setup();
 
-    let auth = unwrap!(Authenticator::create_acc(
+    let auth = unwrap!(Authenticator::create_acc_with_kdf(
         stash.locator.clone(),
         stash.password.clone(),
         stash.client_id.clone(),
+        TEST_KDF,
         || (),
     ));
 
@@ -107,9 +108,10 @@ fn serialisation_read_data() {
     // Set up the mock vault, assuming the previous mock vault file still exists.
     let stash = setup();
 
-    let auth = unwrap!(Authenticator::login(
+    let auth = unwrap!(Authenticator::login_with_kdf(
         stash.locator.clone(),
         stash.password.clone(),
+        TEST_KDF,
         || (),
     ));
 
@@ -234,6 +236,8 @@ fn setup() -> Stash {
             app_permissions: Default::default(),
             app_container: false,
             containers: containers.clone(),
+            response_key: None,
+            ttl: None,
         }
     };
 
@@ -250,6 +254,8 @@ fn setup() -> Stash {
             app_container: false,
             app_permissions: Default::default(),
             containers,
+            response_key: None,
+            ttl: None,
         }
     };
     let client_id = gen_client_id();
@@ -0,0 +1,339 @@
+// Copyright 2019 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+use crate::errors::AuthError;
+use crate::ffi::errors::{ERR_INVALID_OWNER, ERR_NO_SUCH_DATA};
+use crate::ffi::ipc::encode_share_adata_resp;
+use crate::test_utils;
+use crate::{run, Authenticator};
+use ffi_utils::test_utils::call_1;
+use futures::Future;
+use safe_core::ipc::req::ShareADataPermissions;
+use safe_core::ipc::{self, AuthReq, IpcError, IpcMsg, IpcReq, IpcResp, ShareAData, ShareADataReq};
+use safe_core::utils::test_utils::gen_bls_keypair;
+use safe_core::Client;
+use safe_core::{btree_map, ok};
+use safe_nd::{
+    AData, ADataAddress, ADataIndex, ADataOwner, ADataPubPermissionSet, ADataPubPermissions,
+    ADataUnpubPermissionSet, ADataUnpubPermissions, ADataUser, AppendOnlyData,
+    PubSeqAppendOnlyData, PublicKey, UnpubSeqAppendOnlyData,
+};
+use unwrap::unwrap;
+
+// Test making an empty request to share append-only data.
+#[test]
+fn share_zero_adatas() {
+    let authenticator = test_utils::create_account_and_login();
+
+    let msg = IpcMsg::Req {
+        req_id: ipc::gen_req_id(),
+        request: IpcReq::ShareAData(ShareADataReq {
+            app: test_utils::rand_app(),
+            adata: vec![],
+        }),
+    };
+    let encoded_msg = unwrap!(ipc::encode_msg(&msg));
+
+    let decoded = unwrap!(test_utils::auth_decode_ipc_msg_helper(
+        &authenticator,
+        &encoded_msg
+    ));
+    match decoded {
+        (
+            IpcMsg::Req {
+                request: IpcReq::ShareAData(ShareADataReq { adata, .. }),
+                ..
+            },
+            None,
+        ) => assert_eq!(adata.len(), 0),
+        _ => panic!("Unexpected: {:?}", decoded),
+    };
+}
+
+// Test making a request to share published and unpublished append-only data.
+// 1. Put a published and an unpublished AData owned by the user.
+// 2. Decode the share request in the authenticator. It must be returned unchanged.
+// 3. Grant the request and verify that the app got the requested permissions while the
+//    permissions of the existing users were kept.
+#[test]
+fn share_some_adatas() {
+    let authenticator = test_utils::create_account_and_login();
+
+    let app_id = test_utils::rand_app();
+    let auth_req = AuthReq {
+        app: app_id.clone(),
+        app_container: false,
+        app_permissions: Default::default(),
+        containers: Default::default(),
+        response_key: None,
+        ttl: None,
+    };
+    let app_auth = unwrap!(test_utils::register_app(&authenticator, &auth_req));
+    let app_key = app_auth.app_keys.public_key();
+
+    let user = unwrap!(run(&authenticator, move |client| {
+        ok!(client.public_key())
+    }));
+    let other_user = gen_bls_keypair().public_key();
+
+    let pub_address = put_pub_adata(&authenticator, user, other_user);
+    let unpub_address = put_unpub_adata(&authenticator, user, other_user);
+
+    let adatas = vec![
+        ShareAData {
+            address: pub_address,
+            perms: ShareADataPermissions {
+                read: false,
+                append: true,
+                manage_permissions: false,
+            },
+        },
+        ShareAData {
+            address: unpub_address,
+            perms: ShareADataPermissions {
+                read: true,
+                append: true,
+                manage_permissions: false,
+            },
+        },
+    ];
+
+    let req_id = ipc::gen_req_id();
+    let req = ShareADataReq {
+        app: app_id,
+        adata: adatas.clone(),
+    };
+    let msg = IpcMsg::Req {
+        req_id,
+        request: IpcReq::ShareAData(req.clone()),
+    };
+    let encoded_msg = unwrap!(ipc::encode_msg(&msg));
+
+    let decoded = unwrap!(test_utils::auth_decode_ipc_msg_helper(
+        &authenticator,
+        &encoded_msg
+    ));
+    match decoded {
+        (
+            IpcMsg::Req {
+                request: IpcReq::ShareAData(ShareADataReq { adata, .. }),
+                ..
+            },
+            None,
+        ) => assert_eq!(adata, adatas),
+        _ => panic!("Unexpected: {:?}", decoded),
+    };
+
+    let req_c = unwrap!(req.into_repr_c());
+
+    let share_adata_resp: String = unsafe {
+        unwrap!(call_1(|ud, cb| encode_share_adata_resp(
+            &authenticator,
+            &req_c,
+            req_id,
+            true,
+            ud,
+            cb,
+        )))
+    };
+
+    match ipc::decode_msg(&share_adata_resp) {
+        Ok(IpcMsg::Resp {
+            response: IpcResp::ShareAData(Ok(())),
+            ..
+        }) => (),
+        x => panic!("Unexpected {:?}", x),
+    };
+
+    let (app_perms, other_perms) = unwrap!(run(&authenticator, move |client| {
+        let index = ADataIndex::FromEnd(1);
+
+        client
+            .get_pub_adata_user_permissions(pub_address, index, ADataUser::Key(app_key))
+            .join(client.get_pub_adata_user_permissions(
+                pub_address,
+                index,
+                ADataUser::Key(other_user),
+            ))
+            .map_err(AuthError::CoreError)
+    }));
+    assert_eq!(app_perms, ADataPubPermissionSet::new(true, false));
+    assert_eq!(other_perms, ADataPubPermissionSet::new(true, None));
+
+    let (app_perms, other_perms) = unwrap!(run(&authenticator, move |client| {
+        let index = ADataIndex::FromEnd(1);
+
+        client
+            .get_unpub_adata_user_permissions(unpub_address, index, app_key)
+            .join(client.get_unpub_adata_user_permissions(unpub_address, index, other_user))
+            .map_err(AuthError::CoreError)
+    }));
+    assert_eq!(app_perms, ADataUnpubPermissionSet::new(true, true, false));
+    assert_eq!(
+        other_perms,
+        ADataUnpubPermissionSet::new(true, false, false)
+    );
+}
+
+// Test making a request to share non-existent append-only data.
+#[test]
+fn share_invalid_adatas() {
+    let authenticator = test_utils::create_account_and_login();
+
+    let msg = IpcMsg::Req {
+        req_id: ipc::gen_req_id(),
+        request: IpcReq::ShareAData(ShareADataReq {
+            app: test_utils::rand_app(),
+            adata: vec![ShareAData {
+                address: ADataAddress::UnpubSeq {
+                    name: rand::random(),
+                    tag: 15_000,
+                },
+                perms: ShareADataPermissions {
+                    read: true,
+                    append: false,
+                    manage_permissions: false,
+                },
+            }],
+        }),
+    };
+    let encoded_msg = unwrap!(ipc::encode_msg(&msg));
+
+    match test_utils::auth_decode_ipc_msg_helper(&authenticator, &encoded_msg) {
+        Err((ERR_NO_SUCH_DATA, None)) => (),
+        x => panic!("Unexpected result: {:?}", x),
+    }
+}
+
+// Test making a request to share append-only data owned by another account, then denying it.
+#[test]
+fn share_adatas_with_ownership_error() {
+    let authenticator = test_utils::create_account_and_login();
+    let owner_authenticator = test_utils::create_account_and_login();
+
+    let owner = unwrap!(run(&owner_authenticator, move |client| {
+        ok!(client.public_key())
+    }));
+    let address = put_pub_adata(&owner_authenticator, owner, owner);
+
+    let req_id = ipc::gen_req_id();
+    let req = ShareADataReq {
+        app: test_utils::rand_app(),
+        adata: vec![ShareAData {
+            address,
+            perms: ShareADataPermissions {
+                read: false,
+                append: true,
+                manage_permissions: false,
+            },
+        }],
+    };
+    let msg = IpcMsg::Req {
+        req_id,
+        request: IpcReq::ShareAData(req.clone()),
+    };
+    let encoded_msg = unwrap!(ipc::encode_msg(&msg));
+
+    match test_utils::auth_decode_ipc_msg_helper(&authenticator, &encoded_msg) {
+        Err((ERR_INVALID_OWNER, None)) => (),
+        x => panic!("Unexpected result: {:?}", x),
+    };
+
+    let req_c = unwrap!(req.into_repr_c());
+
+    let share_adata_resp: String = unsafe {
+        unwrap!(call_1(|ud, cb| encode_share_adata_resp(
+            &authenticator,
+            &req_c,
+            req_id,
+            false,
+            ud,
+            cb,
+        )))
+    };
+
+    match ipc::decode_msg(&share_adata_resp) {
+        Ok(IpcMsg::Resp {
+            response: IpcResp::ShareAData(Err(IpcError::ShareADataDenied)),
+            ..
+        }) => (),
+        x => panic!("Unexpected {:?}", x),
+    };
+}
+
+// Put a published AData owned by `owner`, with `user` allowed to append.
+fn put_pub_adata(authenticator: &Authenticator, owner: PublicKey, user: PublicKey) -> ADataAddress {
+    let name = rand::random();
+    let tag = 15_000;
+    let mut data = PubSeqAppendOnlyData::new(name, tag);
+
+    unwrap!(data.append_permissions(
+        ADataPubPermissions {
+            permissions: btree_map![
+                ADataUser::Key(user) => ADataPubPermissionSet::new(true, None)
+            ],
+            entries_index: 0,
+            owners_index: 0,
+        },
+        0
+    ));
+    unwrap!(data.append_owner(
+        ADataOwner {
+            public_key: owner,
+            entries_index: 0,
+            permissions_index: 1,
+        },
+        0
+    ));
+
+    let address = *data.address();
+    unwrap!(run(authenticator, move |client| {
+        client
+            .put_adata(AData::PubSeq(data))
+            .map_err(AuthError::CoreError)
+    }));
+    address
+}
+
+// Put an unpublished AData owned by `owner`, with `user` allowed to read.
+fn put_unpub_adata(
+    authenticator: &Authenticator,
+    owner: PublicKey,
+    user: PublicKey,
+) -> ADataAddress {
+    let name = rand::random();
+    let tag = 15_000;
+    let mut data = UnpubSeqAppendOnlyData::new(name, tag);
+
+    unwrap!(data.append_permissions(
+        ADataUnpubPermissions {
+            permissions: btree_map![
+                user => ADataUnpubPermissionSet::new(true, false, false)
+            ],
+            entries_index: 0,
+            owners_index: 0,
+        },
+        0
+    ));
+    unwrap!(data.append_owner(
+        ADataOwner {
+            public_key: owner,
+            entries_index: 0,
+            permissions_index: 1,
+        },
+        0
+    ));
+
+    let address = *data.address();
+    unwrap!(run(authenticator, move |client| {
+        client
+            .put_adata(AData::UnpubSeq(data))
+            .map_err(AuthError::CoreError)
+    }));
+    address
+}
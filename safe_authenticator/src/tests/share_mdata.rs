@@ -167,6 +167,8 @@ fn share_some_mdatas_with_valid_metadata() {
         app_container: false,
         app_permissions: Default::default(),
         containers: Default::default(),
+        response_key: None,
+        ttl: None,
     };
 
     let app_auth = unwrap!(test_utils::register_app(&authenticator, &auth_req));
@@ -427,6 +429,8 @@ fn auth_apps_accessing_mdatas() {
             app_container: false,
             app_permissions: Default::default(),
             containers: Default::default(),
+            response_key: None,
+            ttl: None,
         };
 
         let app_auth = unwrap!(test_utils::register_app(&authenticator, &auth_req));
@@ -0,0 +1,445 @@
+// Copyright 2019 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+use crate::errors::AuthError;
+use crate::ffi::errors::ERR_INVALID_OWNER;
+use crate::ffi::ipc::encode_trans_ownership_resp;
+use crate::test_utils::{self, Payload};
+use crate::{run, Authenticator};
+use ffi_utils::test_utils::call_1;
+use futures::Future;
+use safe_core::ipc::req::OwnedData;
+use safe_core::ipc::{
+    self, AuthReq, IpcError, IpcMsg, IpcReq, IpcResp, TransOwnership, TransOwnershipReq,
+};
+use safe_core::ok;
+use safe_core::utils::test_utils::gen_bls_keypair;
+use safe_core::Client;
+use safe_nd::{
+    AData, ADataAddress, ADataIndex, ADataOwner, AppPermissions, AppendOnlyData, MDataAddress,
+    PubSeqAppendOnlyData, PublicKey, SeqMutableData, XorName,
+};
+use unwrap::unwrap;
+
+// Test making an empty request to transfer ownership.
+#[test]
+fn trans_ownership_of_nothing() {
+    let authenticator = test_utils::create_account_and_login();
+
+    let msg = IpcMsg::Req {
+        req_id: ipc::gen_req_id(),
+        request: IpcReq::TransOwnership(TransOwnershipReq {
+            app: test_utils::rand_app(),
+            data: vec![],
+        }),
+    };
+    let encoded_msg = unwrap!(ipc::encode_msg(&msg));
+
+    let decoded = unwrap!(test_utils::auth_decode_ipc_msg_helper(
+        &authenticator,
+        &encoded_msg
+    ));
+    match decoded {
+        (
+            IpcMsg::Req {
+                request: IpcReq::TransOwnership(TransOwnershipReq { data, .. }),
+                ..
+            },
+            Some(Payload::OwnershipChanges(changes)),
+        ) => {
+            assert!(data.is_empty());
+            assert!(changes.is_empty());
+        }
+        _ => panic!("Unexpected: {:?}", decoded),
+    };
+}
+
+// Test handing an append-only data over to another account.
+// 1. Put an AData owned by the user.
+// 2. Decode the transfer request in the authenticator and verify the described change.
+// 3. Grant the request and verify that the other account is now the owner.
+#[test]
+fn trans_adata_ownership() {
+    let authenticator = test_utils::create_account_and_login();
+    let recipient_authenticator = test_utils::create_account_and_login();
+
+    let app_id = test_utils::rand_app();
+    let auth_req = AuthReq {
+        app: app_id.clone(),
+        app_container: false,
+        app_permissions: Default::default(),
+        containers: Default::default(),
+        response_key: None,
+        ttl: None,
+    };
+    let _ = unwrap!(test_utils::register_app(&authenticator, &auth_req));
+
+    let user = unwrap!(run(&authenticator, move |client| {
+        ok!(client.public_key())
+    }));
+    let recipient = unwrap!(run(&recipient_authenticator, move |client| {
+        ok!(client.public_key())
+    }));
+    let address = put_adata(&authenticator, user);
+
+    let req_id = ipc::gen_req_id();
+    let req = TransOwnershipReq {
+        app: app_id,
+        data: vec![TransOwnership {
+            address: OwnedData::AData(address),
+            new_owner: recipient,
+        }],
+    };
+    let msg = IpcMsg::Req {
+        req_id,
+        request: IpcReq::TransOwnership(req.clone()),
+    };
+    let encoded_msg = unwrap!(ipc::encode_msg(&msg));
+
+    let decoded = unwrap!(test_utils::auth_decode_ipc_msg_helper(
+        &authenticator,
+        &encoded_msg
+    ));
+    match decoded {
+        (
+            IpcMsg::Req {
+                request: IpcReq::TransOwnership(ref decoded_req),
+                ..
+            },
+            Some(Payload::OwnershipChanges(ref changes)),
+        ) => {
+            assert_eq!(*decoded_req, req);
+            assert_eq!(changes.len(), 1);
+            assert_eq!(changes[0].current_owner, user);
+            assert_eq!(changes[0].new_owner, recipient);
+            assert!(!changes[0].description.is_empty());
+        }
+        _ => panic!("Unexpected: {:?}", decoded),
+    };
+
+    let req_c = unwrap!(req.into_repr_c());
+
+    let trans_ownership_resp: String = unsafe {
+        unwrap!(call_1(|ud, cb| encode_trans_ownership_resp(
+            &authenticator,
+            &req_c,
+            req_id,
+            true,
+            ud,
+            cb,
+        )))
+    };
+
+    match ipc::decode_msg(&trans_ownership_resp) {
+        Ok(IpcMsg::Resp {
+            response: IpcResp::TransOwnership(Ok(())),
+            ..
+        }) => (),
+        x => panic!("Unexpected {:?}", x),
+    };
+
+    let owner = unwrap!(run(&authenticator, move |client| {
+        client
+            .get_adata_owners(address, ADataIndex::FromEnd(1))
+            .map_err(AuthError::CoreError)
+    }));
+    assert_eq!(owner.public_key, recipient);
+}
+
+// Test handing a mutable data over to an app, then adopting it back.
+// 1. Put an MData owned by the user and register an app allowed to mutate data.
+// 2. Transfer the MData to the app's key and verify the new owner.
+// 3. Decode the app's request to transfer it back and verify the described change.
+// 4. Grant the request and verify that the user owns the MData again.
+#[test]
+fn trans_mdata_ownership() {
+    let authenticator = test_utils::create_account_and_login();
+
+    let app_id = test_utils::rand_app();
+    let auth_req = AuthReq {
+        app: app_id.clone(),
+        app_container: false,
+        app_permissions: AppPermissions {
+            perform_mutations: true,
+            ..Default::default()
+        },
+        containers: Default::default(),
+        response_key: None,
+        ttl: None,
+    };
+    let auth_granted = unwrap!(test_utils::register_app(&authenticator, &auth_req));
+    let app_key = auth_granted.app_keys.public_key();
+
+    let user = unwrap!(run(&authenticator, move |client| {
+        ok!(client.public_key())
+    }));
+    let name = rand::random();
+    let tag = 15_000;
+    let mdata =
+        SeqMutableData::new_with_data(name, tag, Default::default(), Default::default(), user);
+    unwrap!(run(&authenticator, move |client| {
+        client
+            .put_seq_mutable_data(mdata)
+            .map_err(AuthError::CoreError)
+    }));
+    let address = OwnedData::MData(MDataAddress::Seq { name, tag });
+
+    let req = TransOwnershipReq {
+        app: app_id.clone(),
+        data: vec![TransOwnership {
+            address,
+            new_owner: app_key,
+        }],
+    };
+    match grant_trans_ownership(&authenticator, &req, ipc::gen_req_id()) {
+        IpcResp::TransOwnership(Ok(())) => (),
+        x => panic!("Unexpected {:?}", x),
+    }
+    assert_eq!(mdata_owner(&authenticator, name, tag), app_key);
+
+    let req_id = ipc::gen_req_id();
+    let req = TransOwnershipReq {
+        app: app_id,
+        data: vec![TransOwnership {
+            address,
+            new_owner: user,
+        }],
+    };
+    let msg = IpcMsg::Req {
+        req_id,
+        request: IpcReq::TransOwnership(req.clone()),
+    };
+    let encoded_msg = unwrap!(ipc::encode_msg(&msg));
+
+    match unwrap!(test_utils::auth_decode_ipc_msg_helper(
+        &authenticator,
+        &encoded_msg
+    )) {
+        (_, Some(Payload::OwnershipChanges(ref changes))) => {
+            assert_eq!(changes.len(), 1);
+            assert_eq!(changes[0].current_owner, app_key);
+            assert_eq!(changes[0].new_owner, user);
+        }
+        x => panic!("Unexpected: {:?}", x),
+    };
+
+    match grant_trans_ownership(&authenticator, &req, req_id) {
+        IpcResp::TransOwnership(Ok(())) => (),
+        x => panic!("Unexpected {:?}", x),
+    }
+    assert_eq!(mdata_owner(&authenticator, name, tag), user);
+}
+
+// Test that an app can't use a transfer request to give data it owns to another key.
+#[test]
+fn trans_app_data_to_other_key() {
+    let authenticator = test_utils::create_account_and_login();
+
+    let app_id = test_utils::rand_app();
+    let auth_req = AuthReq {
+        app: app_id.clone(),
+        app_container: false,
+        app_permissions: Default::default(),
+        containers: Default::default(),
+        response_key: None,
+        ttl: None,
+    };
+    let auth_granted = unwrap!(test_utils::register_app(&authenticator, &auth_req));
+    let app_key = auth_granted.app_keys.public_key();
+
+    let user = unwrap!(run(&authenticator, move |client| {
+        ok!(client.public_key())
+    }));
+    let address = put_adata(&authenticator, user);
+
+    let req = TransOwnershipReq {
+        app: app_id.clone(),
+        data: vec![TransOwnership {
+            address: OwnedData::AData(address),
+            new_owner: app_key,
+        }],
+    };
+    match grant_trans_ownership(&authenticator, &req, ipc::gen_req_id()) {
+        IpcResp::TransOwnership(Ok(())) => (),
+        x => panic!("Unexpected {:?}", x),
+    }
+
+    let req = TransOwnershipReq {
+        app: app_id,
+        data: vec![TransOwnership {
+            address: OwnedData::AData(address),
+            new_owner: gen_bls_keypair().public_key(),
+        }],
+    };
+    let msg = IpcMsg::Req {
+        req_id: ipc::gen_req_id(),
+        request: IpcReq::TransOwnership(req),
+    };
+    let encoded_msg = unwrap!(ipc::encode_msg(&msg));
+
+    match test_utils::auth_decode_ipc_msg_helper(&authenticator, &encoded_msg) {
+        Err((ERR_INVALID_OWNER, None)) => (),
+        x => panic!("Unexpected result: {:?}", x),
+    };
+}
+
+// Test that the app is told which data couldn't be transferred when only some of the granted
+// transfers succeed.
+#[test]
+fn trans_ownership_with_partial_failure() {
+    let authenticator = test_utils::create_account_and_login();
+    let owner_authenticator = test_utils::create_account_and_login();
+
+    let user = unwrap!(run(&authenticator, move |client| {
+        ok!(client.public_key())
+    }));
+    let owner = unwrap!(run(&owner_authenticator, move |client| {
+        ok!(client.public_key())
+    }));
+    let owned = put_adata(&authenticator, user);
+    let not_owned = put_adata(&owner_authenticator, owner);
+
+    let req = TransOwnershipReq {
+        app: test_utils::rand_app(),
+        data: vec![
+            TransOwnership {
+                address: OwnedData::AData(owned),
+                new_owner: owner,
+            },
+            TransOwnership {
+                address: OwnedData::AData(not_owned),
+                new_owner: user,
+            },
+        ],
+    };
+
+    match grant_trans_ownership(&authenticator, &req, ipc::gen_req_id()) {
+        IpcResp::TransOwnership(Err(IpcError::TransOwnershipFailed(failed))) => {
+            assert_eq!(failed, vec![(*not_owned.name(), not_owned.tag())]);
+        }
+        x => panic!("Unexpected {:?}", x),
+    }
+
+    let new_owner = unwrap!(run(&authenticator, move |client| {
+        client
+            .get_adata_owners(owned, ADataIndex::FromEnd(1))
+            .map_err(AuthError::CoreError)
+    }));
+    assert_eq!(new_owner.public_key, owner);
+}
+
+// Test making a request to transfer data owned by another account, then denying it.
+#[test]
+fn trans_ownership_with_ownership_error() {
+    let authenticator = test_utils::create_account_and_login();
+    let owner_authenticator = test_utils::create_account_and_login();
+
+    let owner = unwrap!(run(&owner_authenticator, move |client| {
+        ok!(client.public_key())
+    }));
+    let user = unwrap!(run(&authenticator, move |client| {
+        ok!(client.public_key())
+    }));
+    let address = put_adata(&owner_authenticator, owner);
+
+    let req_id = ipc::gen_req_id();
+    let req = TransOwnershipReq {
+        app: test_utils::rand_app(),
+        data: vec![TransOwnership {
+            address: OwnedData::AData(address),
+            new_owner: user,
+        }],
+    };
+    let msg = IpcMsg::Req {
+        req_id,
+        request: IpcReq::TransOwnership(req.clone()),
+    };
+    let encoded_msg = unwrap!(ipc::encode_msg(&msg));
+
+    match test_utils::auth_decode_ipc_msg_helper(&authenticator, &encoded_msg) {
+        Err((ERR_INVALID_OWNER, None)) => (),
+        x => panic!("Unexpected result: {:?}", x),
+    };
+
+    let req_c = unwrap!(req.into_repr_c());
+
+    let trans_ownership_resp: String = unsafe {
+        unwrap!(call_1(|ud, cb| encode_trans_ownership_resp(
+            &authenticator,
+            &req_c,
+            req_id,
+            false,
+            ud,
+            cb,
+        )))
+    };
+
+    match ipc::decode_msg(&trans_ownership_resp) {
+        Ok(IpcMsg::Resp {
+            response: IpcResp::TransOwnership(Err(IpcError::TransOwnershipDenied)),
+            ..
+        }) => (),
+        x => panic!("Unexpected {:?}", x),
+    };
+}
+
+// Put a published AData owned by `owner`.
+fn put_adata(authenticator: &Authenticator, owner: PublicKey) -> ADataAddress {
+    let mut data = PubSeqAppendOnlyData::new(rand::random(), 15_000);
+
+    unwrap!(data.append_owner(
+        ADataOwner {
+            public_key: owner,
+            entries_index: 0,
+            permissions_index: 0,
+        },
+        0
+    ));
+
+    let address = *data.address();
+    unwrap!(run(authenticator, move |client| {
+        client
+            .put_adata(AData::PubSeq(data))
+            .map_err(AuthError::CoreError)
+    }));
+    address
+}
+
+// Grants the given transfer request and returns the response sent to the app.
+fn grant_trans_ownership(
+    authenticator: &Authenticator,
+    req: &TransOwnershipReq,
+    req_id: u32,
+) -> IpcResp {
+    let req_c = unwrap!(req.clone().into_repr_c());
+
+    let resp: String = unsafe {
+        unwrap!(call_1(|ud, cb| encode_trans_ownership_resp(
+            authenticator,
+            &req_c,
+            req_id,
+            true,
+            ud,
+            cb,
+        )))
+    };
+
+    match unwrap!(ipc::decode_msg(&resp)) {
+        IpcMsg::Resp { response, .. } => response,
+        x => panic!("Unexpected {:?}", x),
+    }
+}
+
+fn mdata_owner(authenticator: &Authenticator, name: XorName, tag: u64) -> PublicKey {
+    unwrap!(run(authenticator, move |client| {
+        client
+            .get_seq_mdata_shell(name, tag)
+            .map(|shell| *shell.owner())
+            .map_err(AuthError::CoreError)
+    }))
+}
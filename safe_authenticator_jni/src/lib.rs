@@ -26,15 +26,18 @@ use jni::strings::JNIStr;
 use jni::sys::{jbyte, jbyteArray, jint, jlong, jobject, jsize};
 use jni::{signature::JavaType, JNIEnv, JavaVM};
 use log::error;
+use safe_authenticator::devices::{DeviceId, DeviceKey};
 use safe_authenticator::*;
 use safe_core::arrays::*;
+use safe_core::ffi::coins::TransactionRecord;
 use safe_core::ffi::ipc::req::{
-    AppExchangeInfo, AuthReq, ContainerPermissions, ContainersReq, PermissionSet, ShareMData,
-    ShareMDataRequest,
+    ADataPermissionSet, AppExchangeInfo, AuthReq, ContainerPermissions, ContainersReq,
+    PermissionSet, PermissionsReq, ShareAData, ShareADataRequest, ShareMData, ShareMDataRequest,
+    TransOwnership, TransOwnershipRequest,
 };
 use safe_core::ffi::ipc::resp::{
     AccessContInfo, AccessContainerEntry, AppAccess, AppKeys, AuthGranted, ContainerInfo,
-    MDataEntry, MDataKey, MDataValue, MetadataResponse,
+    MDataEntry, MDataKey, MDataValue, MetadataResponse, OwnershipChange,
 };
 use safe_core::ffi::nfs::File;
 use safe_core::ffi::*;
@@ -289,6 +292,32 @@ gen_object_array_converter!(
     MetadataResponse,
     "net/maidsafe/safe_authenticator/MetadataResponse"
 );
+gen_object_array_converter!(
+    find_class,
+    TransactionRecord,
+    "net/maidsafe/safe_authenticator/TransactionRecord"
+);
+gen_object_array_converter!(
+    find_class,
+    AppAllowanceStatus,
+    "net/maidsafe/safe_authenticator/AppAllowanceStatus"
+);
+gen_object_array_converter!(
+    find_class,
+    OwnershipChange,
+    "net/maidsafe/safe_authenticator/OwnershipChange"
+);
+gen_object_array_converter!(
+    find_class,
+    AppUsageSummary,
+    "net/maidsafe/safe_authenticator/AppUsageSummary"
+);
+gen_object_array_converter!(
+    find_class,
+    AuditEntry,
+    "net/maidsafe/safe_authenticator/AuditEntry"
+);
+gen_object_array_converter!(find_class, Device, "net/maidsafe/safe_authenticator/Device");
 
 extern "C" fn call_auth_disconnect_cb(ctx: *mut c_void) {
     unsafe {
@@ -435,5 +464,269 @@ pub unsafe extern "system" fn Java_net_maidsafe_safe_1authenticator_NativeBindin
     );
 }
 
+extern "C" fn call_create_acc_with_recovery_cb(
+    ctx: *mut c_void,
+    result: *const FfiResult,
+    authenticator: *mut Authenticator,
+    recovery_phrase: *const c_char,
+) {
+    unsafe {
+        let guard = jni_unwrap!(EnvGuard::new(JVM.as_ref()));
+        let env = guard.env();
+        let mut cbs = Box::from_raw(ctx as *mut [Option<GlobalRef>; 2usize]);
+        if let Some(cb) = cbs[1usize].take() {
+            let result = if result.is_null() {
+                JObject::null()
+            } else {
+                jni_unwrap!((*result).to_java(&env))
+            };
+            let authenticator = authenticator as jlong;
+            let recovery_phrase: JObject = if recovery_phrase.is_null() {
+                JObject::null()
+            } else {
+                jni_unwrap!(recovery_phrase.to_java(&env)).into()
+            };
+            jni_unwrap!(env.call_method(
+                cb.as_obj(),
+                "call",
+                "(Lnet/maidsafe/safe_authenticator/FfiResult;JLjava/lang/String;)V",
+                &[
+                    result.into(),
+                    authenticator.into(),
+                    recovery_phrase.into(),
+                ],
+            ));
+        }
+        // do not drop the disconnect_notifier_cb
+        mem::forget(cbs);
+    }
+}
+
+#[link(name = "safe_authenticator")]
+extern "C" {
+    fn create_acc_with_recovery(
+        account_locator: *const c_char,
+        account_password: *const c_char,
+        user_data: *mut c_void,
+        o_disconnect_notifier_cb: extern "C" fn(user_data: *mut c_void),
+        o_cb: extern "C" fn(
+            user_data: *mut c_void,
+            result: *const FfiResult,
+            authenticator: *mut Authenticator,
+            recovery_phrase: *const c_char,
+        ),
+    );
+}
+
+#[no_mangle]
+pub unsafe extern "system" fn Java_net_maidsafe_safe_1authenticator_NativeBindings_createAccWithRecovery(
+    env: JNIEnv,
+    _class: JClass,
+    account_locator: JString,
+    account_password: JString,
+    o_disconnect_notifier_cb: JObject,
+    o_cb: JObject,
+) {
+    let account_locator = jni_unwrap!(CString::from_java(&env, account_locator));
+    let account_password = jni_unwrap!(CString::from_java(&env, account_password));
+    let ctx = gen_ctx!(env, o_disconnect_notifier_cb, o_cb);
+
+    create_acc_with_recovery(
+        account_locator.as_ptr(),
+        account_password.as_ptr(),
+        ctx,
+        call_auth_disconnect_cb,
+        call_create_acc_with_recovery_cb,
+    );
+}
+
+#[link(name = "safe_authenticator")]
+extern "C" {
+    fn recover_account(
+        recovery_phrase: *const c_char,
+        new_password: *const c_char,
+        user_data: *mut c_void,
+        o_disconnect_notifier_cb: unsafe extern "C" fn(user_data: *mut c_void),
+        o_cb: extern "C" fn(
+            user_data: *mut c_void,
+            result: *const FfiResult,
+            authenticator: *mut Authenticator,
+        ),
+    );
+}
+
+#[no_mangle]
+pub unsafe extern "system" fn Java_net_maidsafe_safe_1authenticator_NativeBindings_recoverAccount(
+    env: JNIEnv,
+    _class: JClass,
+    recovery_phrase: JString,
+    new_password: JString,
+    o_disconnect_notifier_cb: JObject,
+    o_cb: JObject,
+) {
+    let recovery_phrase = jni_unwrap!(CString::from_java(&env, recovery_phrase));
+    let new_password = jni_unwrap!(CString::from_java(&env, new_password));
+    let ctx = gen_ctx!(env, o_disconnect_notifier_cb, o_cb);
+    recover_account(
+        recovery_phrase.as_ptr(),
+        new_password.as_ptr(),
+        ctx,
+        call_auth_disconnect_cb,
+        call_login_cb,
+    );
+}
+
+#[link(name = "safe_authenticator")]
+extern "C" {
+    fn login_device(
+        account_locator: *const c_char,
+        account_password: *const c_char,
+        device_key: *const DeviceKey,
+        user_data: *mut c_void,
+        o_disconnect_notifier_cb: unsafe extern "C" fn(user_data: *mut c_void),
+        o_cb: extern "C" fn(
+            user_data: *mut c_void,
+            result: *const FfiResult,
+            authenticator: *mut Authenticator,
+        ),
+    );
+}
+
+#[no_mangle]
+pub unsafe extern "system" fn Java_net_maidsafe_safe_1authenticator_NativeBindings_loginDevice(
+    env: JNIEnv,
+    _class: JClass,
+    account_locator: JString,
+    account_password: JString,
+    device_key: JObject,
+    o_disconnect_notifier_cb: JObject,
+    o_cb: JObject,
+) {
+    let account_locator = jni_unwrap!(CString::from_java(&env, account_locator));
+    let account_password = jni_unwrap!(CString::from_java(&env, account_password));
+    let device_key = jni_unwrap!(DeviceKey::from_java(&env, device_key));
+    let ctx = gen_ctx!(env, o_disconnect_notifier_cb, o_cb);
+    login_device(
+        account_locator.as_ptr(),
+        account_password.as_ptr(),
+        &device_key,
+        ctx,
+        call_auth_disconnect_cb,
+        call_login_cb,
+    );
+}
+
+#[link(name = "safe_authenticator")]
+extern "C" {
+    fn import_backup(
+        backup: *const u8,
+        backup_len: usize,
+        passphrase: *const c_char,
+        new_password: *const c_char,
+        user_data: *mut c_void,
+        o_disconnect_notifier_cb: unsafe extern "C" fn(user_data: *mut c_void),
+        o_cb: extern "C" fn(
+            user_data: *mut c_void,
+            result: *const FfiResult,
+            authenticator: *mut Authenticator,
+        ),
+    );
+}
+
+#[no_mangle]
+pub unsafe extern "system" fn Java_net_maidsafe_safe_1authenticator_NativeBindings_importBackup(
+    env: JNIEnv,
+    _class: JClass,
+    backup: JObject,
+    passphrase: JString,
+    new_password: JString,
+    o_disconnect_notifier_cb: JObject,
+    o_cb: JObject,
+) {
+    let backup = jni_unwrap!(Vec::from_java(&env, backup));
+    let passphrase = jni_unwrap!(CString::from_java(&env, passphrase));
+    let new_password = jni_unwrap!(CString::from_java(&env, new_password));
+    let ctx = gen_ctx!(env, o_disconnect_notifier_cb, o_cb);
+    import_backup(
+        backup.as_ptr(),
+        backup.len(),
+        passphrase.as_ptr(),
+        new_password.as_ptr(),
+        ctx,
+        call_auth_disconnect_cb,
+        call_login_cb,
+    );
+}
+
+extern "C" fn call_near_limit_observer_cb(ctx: *mut c_void, status: *const AppAllowanceStatus) {
+    unsafe {
+        let guard = jni_unwrap!(EnvGuard::new(JVM.as_ref()));
+        let env = guard.env();
+        let cbs = Box::from_raw(ctx as *mut [Option<GlobalRef>; 2usize]);
+        if let Some(ref cb) = cbs[0usize] {
+            let status = jni_unwrap!((*status).to_java(&env));
+            jni_unwrap!(env.call_method(
+                cb.as_obj(),
+                "call",
+                "(Lnet/maidsafe/safe_authenticator/AppAllowanceStatus;)V",
+                &[status.into()],
+            ));
+        }
+        // do not drop the callbacks, the observer is called again
+        mem::forget(cbs);
+    }
+}
+
+extern "C" fn call_set_near_limit_observer_cb(ctx: *mut c_void, result: *const FfiResult) {
+    unsafe {
+        let guard = jni_unwrap!(EnvGuard::new(JVM.as_ref()));
+        let env = guard.env();
+        let mut cbs = Box::from_raw(ctx as *mut [Option<GlobalRef>; 2usize]);
+        if let Some(cb) = cbs[1usize].take() {
+            let result = if result.is_null() {
+                JObject::null()
+            } else {
+                jni_unwrap!((*result).to_java(&env))
+            };
+            jni_unwrap!(env.call_method(
+                cb.as_obj(),
+                "call",
+                "(Lnet/maidsafe/safe_authenticator/FfiResult;)V",
+                &[result.into()],
+            ));
+        }
+        // do not drop the near_limit_observer_cb
+        mem::forget(cbs);
+    }
+}
+
+#[link(name = "safe_authenticator")]
+extern "C" {
+    fn auth_set_near_limit_observer(
+        auth: *const Authenticator,
+        user_data: *mut c_void,
+        o_near_limit: extern "C" fn(user_data: *mut c_void, status: *const AppAllowanceStatus),
+        o_cb: extern "C" fn(user_data: *mut c_void, result: *const FfiResult),
+    );
+}
+
+#[no_mangle]
+pub unsafe extern "system" fn Java_net_maidsafe_safe_1authenticator_NativeBindings_authSetNearLimitObserver(
+    env: JNIEnv,
+    _class: JClass,
+    auth: jlong,
+    o_near_limit: JObject,
+    o_cb: JObject,
+) {
+    let auth = auth as *const Authenticator;
+    let ctx = gen_ctx!(env, o_near_limit, o_cb);
+    auth_set_near_limit_observer(
+        auth,
+        ctx,
+        call_near_limit_observer_cb,
+        call_set_near_limit_observer_cb,
+    );
+}
+
 // Include automatically generated bindings
 include!("../../bindings/java/safe_authenticator/jni.rs");
@@ -8,10 +8,11 @@
 
 use crate::client::id::SafeKey;
 use crate::client::MDataInfo;
-use crate::crypto::{shared_box, shared_secretbox};
+use crate::crypto::{self, shared_box, shared_secretbox, SecretBytes};
 use crate::errors::CoreError;
 use crate::utils::{SymEncKey, SymEncNonce, SYM_ENC_KEY_LEN, SYM_ENC_NONCE_LEN};
 use crate::DIR_TAG;
+use argon2;
 use bincode::{deserialize, serialize};
 use hmac::Hmac;
 use miscreant::aead::Aead;
@@ -22,11 +23,139 @@ use safe_nd::{ClientFullId, MDataKind, PublicKey, XorName, XOR_NAME_LEN};
 use serde::{Deserialize, Serialize};
 use sha3::Sha3_256;
 use std::convert::TryInto;
+use std::fmt::{self, Debug, Formatter};
 use threshold_crypto;
 use tiny_keccak::sha3_256;
 use unwrap::unwrap;
 
-const ITERATIONS: usize = 10000;
+/// Number of PBKDF2 iterations used by accounts created before the KDF was recorded with the
+/// account. Also used to derive the account location, which can't change.
+const LEGACY_ITERATIONS: u32 = 10000;
+
+/// Prefix marking an account encrypted inside an `AccountEnvelope`. Legacy accounts are raw
+/// ciphertext and don't carry it.
+const ENVELOPE_MAGIC: &[u8] = b"SAFEACC\0";
+
+const ENVELOPE_SALT_LEN: usize = 32;
+
+// Argon2id parameters for newly encrypted accounts.
+const ARGON2_MEM_COST_KIB: u32 = 64 * 1024;
+const ARGON2_TIME_COST: u32 = 3;
+const ARGON2_LANES: u32 = 1;
+
+// Upper bounds on the parameters accepted from an encrypted account, so that a tampered account
+// packet can't make logging in exhaust the memory or take forever.
+const MAX_PBKDF2_ITERATIONS: u32 = 10_000_000;
+const MAX_ARGON2_MEM_COST_KIB: u32 = 1024 * 1024;
+const MAX_ARGON2_TIME_COST: u32 = 16;
+const MAX_ARGON2_LANES: u32 = 16;
+
+/// Key derivation function used to derive the account encryption key from the user's
+/// credentials.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Deserialize, Serialize)]
+pub enum Kdf {
+    /// PBKDF2-HMAC-SHA3-256. Only used by legacy accounts.
+    Pbkdf2 {
+        /// Number of iterations.
+        iterations: u32,
+    },
+    /// Argon2id, version 0x13.
+    Argon2id {
+        /// Memory cost, in KiB.
+        mem_cost_kib: u32,
+        /// Number of passes over the memory.
+        time_cost: u32,
+        /// Degree of parallelism.
+        lanes: u32,
+    },
+}
+
+impl Kdf {
+    /// KDF of accounts encrypted before the KDF was recorded with the account.
+    pub const LEGACY: Self = Kdf::Pbkdf2 {
+        iterations: LEGACY_ITERATIONS,
+    };
+
+    /// Returns `true` if the parameters are within the bounds accepted when decrypting an account.
+    pub fn is_within_bounds(self) -> bool {
+        match self {
+            Kdf::Pbkdf2 { iterations } => iterations <= MAX_PBKDF2_ITERATIONS,
+            Kdf::Argon2id {
+                mem_cost_kib,
+                time_cost,
+                lanes,
+            } => {
+                mem_cost_kib <= MAX_ARGON2_MEM_COST_KIB
+                    && time_cost <= MAX_ARGON2_TIME_COST
+                    && lanes <= MAX_ARGON2_LANES
+            }
+        }
+    }
+
+    /// Derives a key from `input` and `salt`, filling the whole of `output`.
+    pub fn derive_key(self, output: &mut [u8], input: &[u8], salt: &[u8]) -> Result<(), CoreError> {
+        match self {
+            Kdf::Pbkdf2 { iterations } => {
+                pbkdf2::pbkdf2::<Hmac<Sha3_256>>(input, salt, iterations as usize, output);
+            }
+            Kdf::Argon2id {
+                mem_cost_kib,
+                time_cost,
+                lanes,
+            } => {
+                let config = argon2::Config {
+                    variant: argon2::Variant::Argon2id,
+                    version: argon2::Version::Version13,
+                    mem_cost: mem_cost_kib,
+                    time_cost,
+                    lanes,
+                    thread_mode: argon2::ThreadMode::Sequential,
+                    secret: &[],
+                    ad: &[],
+                    hash_length: output.len() as u32,
+                };
+                let hash = argon2::hash_raw(input, salt, &config)
+                    .map_err(|_| CoreError::UnsuccessfulPwHash)?;
+                output.copy_from_slice(&hash);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Default for Kdf {
+    /// The KDF new accounts are encrypted with.
+    fn default() -> Self {
+        Kdf::Argon2id {
+            mem_cost_kib: ARGON2_MEM_COST_KIB,
+            time_cost: ARGON2_TIME_COST,
+            lanes: ARGON2_LANES,
+        }
+    }
+}
+
+// Encrypted account along with what is needed to derive its encryption key from the user's
+// credentials.
+#[derive(Deserialize, Serialize)]
+enum AccountEnvelope {
+    V1 {
+        kdf: Kdf,
+        salt: [u8; ENVELOPE_SALT_LEN],
+        ciphertext: Vec<u8>,
+    },
+}
+
+impl AccountEnvelope {
+    // Returns `None` for legacy accounts, which are not wrapped in an envelope.
+    fn open(encrypted_account: &[u8]) -> Option<Self> {
+        if encrypted_account.starts_with(ENVELOPE_MAGIC) {
+            deserialize(&encrypted_account[ENVELOPE_MAGIC.len()..]).ok()
+        } else {
+            None
+        }
+    }
+}
 
 /// Object representing the User Account information on the network.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
@@ -56,60 +185,124 @@ impl Account {
 
     /// Symmetrically encrypts Account using User's credentials.
     ///
-    /// Credentials are passed through key-derivation-function first.
+    /// Credentials are passed through the default key-derivation-function first.
     pub fn encrypt(&self, password: &[u8], pin: &[u8]) -> Result<Vec<u8>, CoreError> {
-        let serialised_self = serialize(self)?;
-        let (key, nonce) = Self::generate_crypto_keys(password, pin)?;
+        self.encrypt_with_kdf(password, pin, Kdf::default())
+    }
+
+    /// Symmetrically encrypts Account using User's credentials.
+    ///
+    /// Credentials are passed through the given key-derivation-function first, which is recorded
+    /// along with the ciphertext.
+    pub fn encrypt_with_kdf(
+        &self,
+        password: &[u8],
+        pin: &[u8],
+        kdf: Kdf,
+    ) -> Result<Vec<u8>, CoreError> {
+        let serialised_self = SecretBytes::new(serialize(self)?);
+        let salt: [u8; ENVELOPE_SALT_LEN] = thread_rng().gen();
+        let (mut key, nonce) =
+            Self::generate_crypto_keys(kdf, password, &envelope_salt(pin, &salt))?;
         let mut cipher = Aes128SivAead::new(&key);
+        crypto::wipe(&mut key);
+
+        let envelope = AccountEnvelope::V1 {
+            kdf,
+            salt,
+            ciphertext: cipher.seal(&nonce, &[], &serialised_self),
+        };
+
+        let mut encrypted_self = ENVELOPE_MAGIC.to_vec();
+        encrypted_self.extend(serialize(&envelope)?);
 
-        Ok(cipher.seal(&nonce, &[], &serialised_self))
+        Ok(encrypted_self)
     }
 
     /// Symmetrically decrypts Account using User's credentials.
     ///
-    /// Credentials are passed through key-derivation-function first.
+    /// Credentials are passed through the key-derivation-function the account was encrypted
+    /// with first. Legacy accounts, which don't record it, are decrypted too. Accounts recording
+    /// parameters beyond `Kdf::is_within_bounds` are rejected without deriving the key.
     pub fn decrypt(encrypted_self: &[u8], password: &[u8], pin: &[u8]) -> Result<Self, CoreError> {
-        let (key, nonce) = Self::generate_crypto_keys(password, pin)?;
+        let (mut key, nonce, ciphertext) = match AccountEnvelope::open(encrypted_self) {
+            Some(AccountEnvelope::V1 {
+                kdf,
+                salt,
+                ciphertext,
+            }) => {
+                if !kdf.is_within_bounds() {
+                    return Err(CoreError::Unexpected(format!(
+                        "Unsupported key derivation parameters: {:?}",
+                        kdf
+                    )));
+                }
+
+                let (key, nonce) =
+                    Self::generate_crypto_keys(kdf, password, &envelope_salt(pin, &salt))?;
+                (key, nonce, ciphertext)
+            }
+            None => {
+                let (key, nonce) =
+                    Self::generate_crypto_keys(Kdf::LEGACY, password, &sha3_256(pin))?;
+                (key, nonce, encrypted_self.to_vec())
+            }
+        };
         let mut cipher = Aes128SivAead::new(&key);
+        crypto::wipe(&mut key);
 
-        let decrypted_self = cipher
-            .open(&nonce, &[], &encrypted_self)
-            .map_err(|_| CoreError::SymmetricDecipherFailure)?;
+        let decrypted_self = SecretBytes::new(
+            cipher
+                .open(&nonce, &[], &ciphertext)
+                .map_err(|_| CoreError::SymmetricDecipherFailure)?,
+        );
 
         Ok(deserialize(&decrypted_self)?)
     }
 
+    /// Returns the key-derivation-function the given encrypted account was encrypted with.
+    /// Accounts encrypted with anything but `Kdf::default()` should be re-encrypted.
+    pub fn kdf(encrypted_self: &[u8]) -> Kdf {
+        match AccountEnvelope::open(encrypted_self) {
+            Some(AccountEnvelope::V1 { kdf, .. }) => kdf,
+            None => Kdf::LEGACY,
+        }
+    }
+
     /// Generates User's Identity for the network using supplied credentials in
     /// a deterministic way.  This is similar to the username in various places.
     pub fn generate_network_id(keyword: &[u8], pin: &[u8]) -> Result<XorName, CoreError> {
         let mut id = XorName([0; XOR_NAME_LEN]);
-        Self::derive_key(&mut id.0[..], keyword, pin);
+        Kdf::LEGACY.derive_key(&mut id.0[..], keyword, &sha3_256(pin))?;
 
         Ok(id)
     }
 
     fn generate_crypto_keys(
+        kdf: Kdf,
         password: &[u8],
-        pin: &[u8],
+        salt: &[u8],
     ) -> Result<(SymEncKey, SymEncNonce), CoreError> {
         let mut output = [0; SYM_ENC_KEY_LEN + SYM_ENC_NONCE_LEN];
-        Self::derive_key(&mut output[..], password, pin);
+        kdf.derive_key(&mut output[..], password, salt)?;
 
         // OK to unwrap here, as we guaranteed the slices have the correct length.
         let key: SymEncKey = unwrap!(output[..SYM_ENC_KEY_LEN].try_into());
         let nonce: SymEncNonce = unwrap!(output[SYM_ENC_KEY_LEN..].try_into());
+        crypto::wipe(&mut output);
 
         Ok((key, nonce))
     }
+}
 
-    fn derive_key(output: &mut [u8], input: &[u8], user_salt: &[u8]) {
-        let salt = sha3_256(user_salt);
-        pbkdf2::pbkdf2::<Hmac<Sha3_256>>(input, &salt, ITERATIONS, output)
-    }
+// Binds the random salt recorded in the envelope to the user's PIN.
+fn envelope_salt(pin: &[u8], salt: &[u8]) -> [u8; 32] {
+    sha3_256(&[pin, salt].concat())
 }
 
-/// Client signing and encryption keypairs.
-#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+/// Client signing and encryption keypairs. The secret keys are wiped from memory when dropped,
+/// and only the public keys are shown by `Debug`.
+#[derive(Clone, PartialEq, Deserialize, Serialize)]
 pub struct ClientKeys {
     /// This is the identity of the Client in the Network.
     pub client_id: ClientFullId,
@@ -149,6 +342,16 @@ impl ClientKeys {
     }
 }
 
+impl Debug for ClientKeys {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter
+            .debug_struct("ClientKeys")
+            .field("public_key", &self.public_key())
+            .field("enc_public_key", &self.enc_public_key)
+            .finish()
+    }
+}
+
 impl Default for ClientKeys {
     fn default() -> Self {
         Self::new(&mut thread_rng())
@@ -206,23 +409,39 @@ mod tests {
         let password1 = b"super great password";
         let password2 = b"even better password";
 
-        let keys1 = unwrap!(Account::generate_crypto_keys(password1, b"0"));
-        let keys2 = unwrap!(Account::generate_crypto_keys(password1, b"1234"));
-        let keys3 = unwrap!(Account::generate_crypto_keys(
+        // Argon2 rejects salts shorter than 8 bytes.
+        for kdf in &[Kdf::LEGACY, Kdf::default()] {
+            let keys1 = unwrap!(Account::generate_crypto_keys(*kdf, password1, b"salt 0000"));
+            let keys2 = unwrap!(Account::generate_crypto_keys(*kdf, password1, b"salt 1234"));
+            let keys3 = unwrap!(Account::generate_crypto_keys(
+                *kdf,
+                password1,
+                u32::MAX.to_string().as_bytes(),
+            ));
+            assert_ne!(keys1, keys2);
+            assert_ne!(keys1, keys3);
+            assert_ne!(keys2, keys3);
+
+            let keys1 = unwrap!(Account::generate_crypto_keys(*kdf, password1, b"salt 0000"));
+            let keys2 = unwrap!(Account::generate_crypto_keys(*kdf, password2, b"salt 0000"));
+            assert_ne!(keys1, keys2);
+
+            let keys1 = unwrap!(Account::generate_crypto_keys(*kdf, password1, b"salt 0000"));
+            let keys2 = unwrap!(Account::generate_crypto_keys(*kdf, password1, b"salt 0000"));
+            assert_eq!(keys1, keys2);
+        }
+
+        let legacy_keys = unwrap!(Account::generate_crypto_keys(
+            Kdf::LEGACY,
             password1,
-            u32::MAX.to_string().as_bytes(),
+            b"salt 0000"
         ));
-        assert_ne!(keys1, keys2);
-        assert_ne!(keys1, keys3);
-        assert_ne!(keys2, keys3);
-
-        let keys1 = unwrap!(Account::generate_crypto_keys(password1, b"0"));
-        let keys2 = unwrap!(Account::generate_crypto_keys(password2, b"0"));
-        assert_ne!(keys1, keys2);
-
-        let keys1 = unwrap!(Account::generate_crypto_keys(password1, b"0"));
-        let keys2 = unwrap!(Account::generate_crypto_keys(password1, b"0"));
-        assert_eq!(keys1, keys2);
+        let keys = unwrap!(Account::generate_crypto_keys(
+            Kdf::default(),
+            password1,
+            b"salt 0000"
+        ));
+        assert_ne!(legacy_keys, keys);
     }
 
     // Test serialising and deserialising accounts.
@@ -250,5 +469,92 @@ mod tests {
 
         let decrypted = unwrap!(Account::decrypt(&encrypted, password, pin));
         assert_eq!(account, decrypted);
+        assert_eq!(Account::kdf(&encrypted), Kdf::default());
+
+        // The salt is random, so encrypting twice gives different ciphertexts.
+        assert_ne!(unwrap!(account.encrypt(password, pin)), encrypted);
+
+        match Account::decrypt(&encrypted, b"wrong password", pin) {
+            Err(CoreError::SymmetricDecipherFailure) => (),
+            x => panic!("Unexpected {:?}", x),
+        }
+        match Account::decrypt(&encrypted, password, b"1001") {
+            Err(CoreError::SymmetricDecipherFailure) => (),
+            x => panic!("Unexpected {:?}", x),
+        }
+    }
+
+    // Test that accounts encrypted before the KDF was recorded can still be decrypted.
+    #[test]
+    fn legacy_decryption() {
+        let account = unwrap!(Account::new(ClientKeys::default()));
+
+        let password = b"impossible to guess";
+        let pin = b"1000";
+
+        let (key, nonce) = unwrap!(Account::generate_crypto_keys(
+            Kdf::LEGACY,
+            password,
+            &sha3_256(pin)
+        ));
+        let mut cipher = Aes128SivAead::new(&key);
+        let encrypted = cipher.seal(&nonce, &[], &unwrap!(serialize(&account)));
+
+        assert_eq!(Account::kdf(&encrypted), Kdf::LEGACY);
+        let decrypted = unwrap!(Account::decrypt(&encrypted, password, pin));
+        assert_eq!(account, decrypted);
+
+        // Re-encrypting upgrades the KDF.
+        let upgraded = unwrap!(decrypted.encrypt(password, pin));
+        assert_eq!(Account::kdf(&upgraded), Kdf::default());
+        assert_eq!(unwrap!(Account::decrypt(&upgraded, password, pin)), account);
+    }
+
+    // Test that accounts recording excessive KDF parameters are rejected.
+    #[test]
+    fn kdf_bounds() {
+        assert!(Kdf::LEGACY.is_within_bounds());
+        assert!(Kdf::default().is_within_bounds());
+
+        let account = unwrap!(Account::new(ClientKeys::default()));
+        let password = b"impossible to guess";
+        let pin = b"1000";
+
+        for kdf in &[
+            Kdf::Pbkdf2 {
+                iterations: MAX_PBKDF2_ITERATIONS + 1,
+            },
+            Kdf::Argon2id {
+                mem_cost_kib: MAX_ARGON2_MEM_COST_KIB + 1,
+                time_cost: 1,
+                lanes: 1,
+            },
+            Kdf::Argon2id {
+                mem_cost_kib: 64,
+                time_cost: MAX_ARGON2_TIME_COST + 1,
+                lanes: 1,
+            },
+            Kdf::Argon2id {
+                mem_cost_kib: 64,
+                time_cost: 1,
+                lanes: MAX_ARGON2_LANES + 1,
+            },
+        ] {
+            assert!(!kdf.is_within_bounds());
+
+            // Forge an envelope without running the KDF.
+            let envelope = AccountEnvelope::V1 {
+                kdf: *kdf,
+                salt: [0; ENVELOPE_SALT_LEN],
+                ciphertext: unwrap!(serialize(&account)),
+            };
+            let mut encrypted = ENVELOPE_MAGIC.to_vec();
+            encrypted.extend(unwrap!(serialize(&envelope)));
+
+            match Account::decrypt(&encrypted, password, pin) {
+                Err(CoreError::Unexpected(_)) => (),
+                x => panic!("Unexpected {:?}", x),
+            }
+        }
     }
 }
@@ -6,7 +6,7 @@
 // KIND, either express or implied. Please review the Licences for the specific language governing
 // permissions and limitations relating to use of the SAFE Network Software.
 
-use crate::client::account::{Account as ClientAccount, ClientKeys};
+use crate::client::account::{Account as ClientAccount, ClientKeys, Kdf};
 #[cfg(feature = "mock-network")]
 use crate::client::mock::ConnectionManager;
 use crate::client::{req, AuthActions, Client, Inner, SafeKey, IMMUT_DATA_CACHE_SIZE};
@@ -40,9 +40,11 @@ pub struct CoreClient {
 
 impl CoreClient {
     /// This will create a basic Client object which is sufficient only for testing purposes.
+    /// The account is encrypted using `kdf`.
     pub fn new(
         acc_locator: &str,
         acc_password: &str,
+        kdf: Kdf,
         el_handle: Handle,
         core_tx: CoreMsgTx<Self, ()>,
         net_tx: NetworkTx,
@@ -50,6 +52,7 @@ impl CoreClient {
         Self::new_impl(
             acc_locator.as_bytes(),
             acc_password.as_bytes(),
+            kdf,
             el_handle,
             core_tx,
             net_tx,
@@ -60,6 +63,7 @@ impl CoreClient {
     fn new_impl<F>(
         acc_locator: &[u8],
         acc_password: &[u8],
+        kdf: Kdf,
         el_handle: Handle,
         core_tx: CoreMsgTx<Self, ()>,
         net_tx: NetworkTx,
@@ -75,7 +79,7 @@ impl CoreClient {
         let acc_loc = ClientAccount::generate_network_id(&keyword, &pin)?;
         let maid_keys = ClientKeys::new(&mut thread_rng());
         let acc = ClientAccount::new(maid_keys.clone())?;
-        let acc_ciphertext = acc.encrypt(&password, &pin)?;
+        let acc_ciphertext = acc.encrypt_with_kdf(&password, &pin, kdf)?;
 
         let (client_pk, client_full_id) = {
             let mut seeder: Vec<u8> = Vec::with_capacity(acc_locator.len() + acc_password.len());
@@ -86,10 +90,7 @@ impl CoreClient {
             let mut rng = StdRng::from_seed(seed);
 
             let client_full_id = ClientFullId::new_bls(&mut rng);
-            (
-                *client_full_id.public_id().public_key(),
-                SafeKey::client(client_full_id),
-            )
+            (*client_full_id.public_id().public_key(), client_full_id)
         };
 
         let sig = client_full_id.sign(&acc_ciphertext);
@@ -6,6 +6,9 @@
 // KIND, either express or implied. Please review the Licences for the specific language governing
 // permissions and limitations relating to use of the SAFE Network Software.
 
+use super::signer::Signer;
+use crate::event_loop::CoreFuture;
+use crate::ok;
 use safe_nd::{AppFullId, ClientFullId, PublicId, PublicKey, Signature};
 use std::sync::Arc;
 
@@ -16,6 +19,8 @@ pub enum SafeKey {
     App(Arc<AppFullId>),
     /// Represents a network client.
     Client(Arc<ClientFullId>),
+    /// Represents a client or an app whose keys are held by an external signer.
+    External(Arc<dyn Signer>),
 }
 
 impl SafeKey {
@@ -29,11 +34,18 @@ impl SafeKey {
         Self::App(Arc::new(full_id))
     }
 
-    /// Signs a given message using the App / Client full id as required.
-    pub fn sign(&self, msg: &[u8]) -> Signature {
+    /// Creates a full ID delegating to an external signer.
+    pub fn external(signer: Arc<dyn Signer>) -> Self {
+        Self::External(signer)
+    }
+
+    /// Signs a given message using the App / Client full id as required. Local keys sign right
+    /// away, while an external signer may take a while or fail to sign it.
+    pub fn sign(&self, msg: &[u8]) -> Box<CoreFuture<Signature>> {
         match self {
-            Self::App(app_full_id) => app_full_id.sign(msg),
-            Self::Client(client_full_id) => client_full_id.sign(msg),
+            Self::App(app_full_id) => ok!(app_full_id.sign(msg)),
+            Self::Client(client_full_id) => ok!(client_full_id.sign(msg)),
+            Self::External(signer) => signer.sign(msg),
         }
     }
 
@@ -42,6 +54,7 @@ impl SafeKey {
         match self {
             Self::App(app_full_id) => PublicId::App(app_full_id.public_id().clone()),
             Self::Client(client_full_id) => PublicId::Client(client_full_id.public_id().clone()),
+            Self::External(signer) => signer.public_id(),
         }
     }
 
@@ -50,6 +63,7 @@ impl SafeKey {
         match self {
             Self::App(app_full_id) => *app_full_id.public_id().public_key(),
             Self::Client(client_full_id) => *client_full_id.public_id().public_key(),
+            Self::External(signer) => signer.public_key(),
         }
     }
 }
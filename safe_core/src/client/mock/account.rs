@@ -7,18 +7,18 @@
 // permissions and limitations relating to use of the SAFE Network Software.
 
 use crate::config_handler::Config;
+use crate::core_structs::{AppUsage, TransactionRecord};
 use safe_nd::{AppPermissions, Coins, Error, PublicKey};
 use serde::{Deserialize, Serialize};
-use std::collections::{BTreeMap, VecDeque};
+use std::collections::BTreeMap;
 
-pub const DEFAULT_MAX_CREDITS: usize = 100;
 // pub const DEFAULT_COINS: &str = "100";
 
 #[derive(Deserialize, Serialize)]
 pub struct CoinBalance {
     owner: PublicKey,
     value: Coins,
-    credits: VecDeque<Credit>,
+    history: Vec<TransactionRecord>,
 }
 
 impl CoinBalance {
@@ -26,14 +26,13 @@ impl CoinBalance {
         Self {
             owner,
             value,
-            credits: VecDeque::new(),
+            history: Vec::new(),
         }
     }
 
-    pub fn credit_balance(&mut self, amount: Coins, transaction_id: u64) -> Result<(), Error> {
+    pub fn credit_balance(&mut self, amount: Coins) -> Result<(), Error> {
         if let Some(new_balance) = self.value.checked_add(amount) {
             self.value = new_balance;
-            self.add_transaction(amount, transaction_id);
             Ok(())
         } else {
             Err(Error::ExcessiveValue)
@@ -53,27 +52,21 @@ impl CoinBalance {
         self.value
     }
 
-    fn add_transaction(&mut self, amount: Coins, transaction_id: u64) {
-        if self.credits.len() == DEFAULT_MAX_CREDITS {
-            let _ = self.credits.pop_back();
-        }
-        let credit = Credit {
-            amount,
-            transaction_id,
-        };
-        self.credits.push_front(credit);
+    // Appends a completed transaction to the history of this balance.
+    pub fn record_transaction(&mut self, record: TransactionRecord) {
+        self.history.push(record);
     }
-}
 
-#[derive(Deserialize, Serialize)]
-pub struct Credit {
-    amount: Coins,
-    transaction_id: u64, // TODO: use Uuid
+    // Returns the transactions of this balance, oldest first.
+    pub fn history(&self) -> &[TransactionRecord] {
+        &self.history
+    }
 }
 
 #[derive(Deserialize, Serialize)]
 pub struct Account {
     auth_keys: BTreeMap<PublicKey, AppPermissions>,
+    app_usage: BTreeMap<PublicKey, AppUsage>,
     version: u64,
     config: Config,
 }
@@ -82,6 +75,7 @@ impl Account {
     pub fn new(config: Config) -> Self {
         Account {
             auth_keys: Default::default(),
+            app_usage: Default::default(),
             version: 0,
             config,
         }
@@ -123,6 +117,21 @@ impl Account {
         &self.auth_keys
     }
 
+    // Adds a mutation performed by the app with the given key to its usage counters.
+    pub fn record_app_usage(&mut self, key: PublicKey, cost: Coins, bytes: u64) {
+        let usage = self.app_usage.entry(key).or_insert_with(AppUsage::default);
+        usage.mutations += 1;
+        usage.bytes += bytes;
+        if let Some(spent) = usage.spent.checked_add(cost) {
+            usage.spent = spent;
+        }
+    }
+
+    // Returns the usage counters of all apps that performed mutations on this account.
+    pub fn app_usage(&self) -> &BTreeMap<PublicKey, AppUsage> {
+        &self.app_usage
+    }
+
     fn validate_version(&self, version: u64) -> Result<(), Error> {
         if version == self.version + 1 {
             Ok(())
@@ -10,6 +10,7 @@ use super::vault::{self, Vault};
 use crate::config_handler::{get_config, Config};
 use crate::{
     client::SafeKey,
+    core_structs::{AppUsage, TransactionRecord},
     network_event::{NetworkEvent, NetworkTx},
     CoreError, CoreFuture,
 };
@@ -17,8 +18,10 @@ use crate::{err, ok};
 use lazy_static::lazy_static;
 use log::trace;
 use quic_p2p::{self, Config as QuicP2pConfig};
-use safe_nd::{Coins, Message, PublicId, PublicKey, Request, RequestType, Response, XorName};
-use std::collections::HashSet;
+use safe_nd::{
+    Coins, MDataAddress, Message, PublicId, PublicKey, Request, RequestType, Response, XorName,
+};
+use std::collections::{BTreeMap, HashSet};
 use std::env;
 use std::sync::{Arc, Mutex};
 use unwrap::unwrap;
@@ -137,6 +140,54 @@ impl ConnectionManager {
         vault.mock_increment_balance(coin_balance_name, amount)
     }
 
+    /// Returns the amount of coins the mock vault would charge for the given request.
+    pub fn estimate_cost(&self, request: &Request) -> Result<Coins, safe_nd::Error> {
+        let vault = vault::lock(&self.vault, false);
+        vault.estimate_cost(request)
+    }
+
+    /// Returns the transaction history of the coin balance belonging to the given identity.
+    /// For apps, this is the history of their owner's balance.
+    pub fn transaction_history(
+        &self,
+        pub_id: &PublicId,
+    ) -> Result<Vec<TransactionRecord>, safe_nd::Error> {
+        let (owner_pk, requester_pk) = match pub_id {
+            PublicId::App(app_id) => (*app_id.owner().public_key(), *app_id.public_key()),
+            PublicId::Client(client_id) => (*client_id.public_key(), *client_id.public_key()),
+            PublicId::Node(_) => return Err(safe_nd::Error::AccessDenied),
+        };
+        let vault = vault::lock(&self.vault, false);
+        vault.get_transaction_history(owner_pk.into(), requester_pk)
+    }
+
+    /// Returns the resources consumed by each app of the account belonging to the given identity.
+    /// Apps can't read the usage of their owner's account.
+    pub fn app_usage(
+        &self,
+        pub_id: &PublicId,
+    ) -> Result<BTreeMap<PublicKey, AppUsage>, safe_nd::Error> {
+        let client_pk = match pub_id {
+            PublicId::Client(client_id) => *client_id.public_key(),
+            PublicId::App(_) | PublicId::Node(_) => return Err(safe_nd::Error::AccessDenied),
+        };
+        let vault = vault::lock(&self.vault, false);
+        vault.get_app_usage(client_pk.into(), client_pk)
+    }
+
+    /// Changes the owner of a mutable data on behalf of the given identity, which must be its
+    /// current owner. Vaults don't accept such requests yet, so this bypasses the message layer.
+    pub fn change_mdata_owner(
+        &self,
+        pub_id: &PublicId,
+        address: MDataAddress,
+        new_owner: PublicKey,
+        version: u64,
+    ) -> Result<(), safe_nd::Error> {
+        let mut vault = vault::lock(&self.vault, true);
+        vault.change_mdata_owner(pub_id, address, new_owner, version)
+    }
+
     /// Create coin balance in the mock network arbitrarily.
     pub fn create_balance(&self, owner: PublicKey, amount: Coins) {
         let mut vault = vault::lock(&self.vault, true);
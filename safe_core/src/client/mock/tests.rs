@@ -11,23 +11,27 @@
 #![allow(unused_imports)] // Remove this after fixing all the tests
 
 use crate::client::mock::vault::Vault;
-use crate::client::{SafeKey, COST_OF_PUT};
-use crate::config_handler::{Config, DevConfig};
+use crate::client::threshold::{
+    CoOwner, LocalCoOwner, SignatureCollector, SigningRequest, ThresholdOwners, ThresholdSigner,
+};
+use crate::client::{req, LocalSigner, SafeKey, COST_OF_PUT};
+use crate::config_handler::{Config, DevConfig, MockPricing};
 use crate::utils::test_utils::{gen_app_id, gen_client_id};
-use crate::{utils, NetworkEvent, QuicP2pConfig};
+use crate::{utils, CoreError, NetworkEvent, QuicP2pConfig};
 
 use super::connection_manager::ConnectionManager;
 use crate::btree_map;
-use bincode::serialize;
+use bincode::{serialize, serialized_size};
 use futures::sync::mpsc::{self, UnboundedReceiver};
 use futures::Future;
 use rand::thread_rng;
 use safe_nd::{
-    ADataPubPermissionSet, AppFullId, AppPermissions, ClientFullId, Coins, Error, IData, MData,
-    MDataAction, MDataAddress, MDataEntries, MDataEntryActions, MDataPermissionSet,
-    MDataSeqEntryAction, MDataSeqEntryActions, MDataSeqValue, MDataValue, MDataValues, Message,
-    MessageId, PubImmutableData, PublicId, PublicKey, Request, RequestType, Response,
-    SeqMutableData, UnpubImmutableData, UnseqMutableData, XorName,
+    AData, ADataAddress, ADataAppendOperation, ADataEntry, ADataOwner, ADataPubPermissionSet,
+    ADataPubPermissions, ADataUser, AppFullId, AppPermissions, AppendOnlyData, ClientFullId, Coins,
+    Error, IData, MData, MDataAction, MDataAddress, MDataEntries, MDataEntryActions,
+    MDataPermissionSet, MDataSeqEntryAction, MDataSeqEntryActions, MDataSeqValue, MDataValue,
+    MDataValues, Message, MessageId, PubImmutableData, PubSeqAppendOnlyData, PublicId, PublicKey,
+    Request, RequestType, Response, SeqMutableData, UnpubImmutableData, UnseqMutableData, XorName,
 };
 use std::collections::{BTreeMap, BTreeSet};
 use std::convert::TryInto;
@@ -63,7 +67,9 @@ fn process_request(
     let sign = request.get_type() != RequestType::PublicGet;
     let message_id = MessageId::new();
     let signature = if sign {
-        Some(sender.sign(&unwrap!(serialize(&(&request, message_id)))))
+        Some(unwrap!(sender
+            .sign(&unwrap!(serialize(&(&request, message_id))))
+            .wait()))
     } else {
         None
     };
@@ -1278,6 +1284,68 @@ fn auth_actions_from_app() {
     );
 }
 
+// Test that mutations made by apps are attributed to their keys.
+#[test]
+fn app_usage() {
+    let (mut connection_manager, _, client_safe_key, _) = setup(None);
+
+    let app_perms = AppPermissions {
+        transfer_coins: false,
+        get_balance: false,
+        perform_mutations: true,
+    };
+    let (app_key, mut app_conn_manager, _) =
+        register_new_app(&mut connection_manager, &client_safe_key, app_perms);
+    let (idle_app_key, ..) = register_new_app(&mut connection_manager, &client_safe_key, app_perms);
+
+    let mut bytes = 0;
+    let mut spent = unwrap!(Coins::from_nano(0));
+
+    for _ in 0..2 {
+        let data: IData = PubImmutableData::new(unwrap!(utils::generate_random_vector(100))).into();
+        bytes += unwrap!(serialized_size(&data));
+        let request = Request::PutIData(data);
+        spent = unwrap!(spent.checked_add(unwrap!(connection_manager.estimate_cost(&request))));
+
+        send_req_expect_ok!(&mut app_conn_manager, &app_key, request, ());
+    }
+
+    // Failed mutations are not counted.
+    send_req_expect_failure!(
+        &mut app_conn_manager,
+        &app_key,
+        Request::DeleteMData(MDataAddress::Unseq {
+            name: rand::random(),
+            tag: 15000,
+        }),
+        Error::NoSuchData
+    );
+
+    // Mutations made by the owner are not attributed to any app.
+    let data: IData = PubImmutableData::new(unwrap!(utils::generate_random_vector(100))).into();
+    send_req_expect_ok!(
+        &mut connection_manager,
+        &client_safe_key,
+        Request::PutIData(data),
+        ()
+    );
+
+    let usage = unwrap!(connection_manager.app_usage(&client_safe_key.public_id()));
+    assert_eq!(usage.len(), 1);
+    assert!(!usage.contains_key(&idle_app_key.public_key()));
+
+    let app_usage = unwrap!(usage.get(&app_key.public_key()));
+    assert_eq!(app_usage.mutations, 2);
+    assert_eq!(app_usage.bytes, bytes);
+    assert_eq!(app_usage.spent, spent);
+
+    // Apps can't read the usage of their owner's account.
+    match app_conn_manager.app_usage(&app_key.public_id()) {
+        Err(Error::AccessDenied) => (),
+        x => panic!("Unexpected {:?}", x),
+    }
+}
+
 // Exhaust the account balance and ensure that mutations fail.
 #[test]
 fn low_balance_check() {
@@ -1288,6 +1356,7 @@ fn low_balance_check() {
                 mock_unlimited_coins: *unlimited,
                 mock_in_memory_storage: false,
                 mock_vault_path: None,
+                mock_pricing: Default::default(),
             }),
         }));
 
@@ -1355,6 +1424,79 @@ fn low_balance_check() {
     }
 }
 
+// Check that mutations are charged according to the configured pricing model, that the cost
+// estimate matches the amount actually charged, and that deletions are free.
+#[test]
+fn custom_pricing() {
+    let pricing = MockPricing {
+        idata: 10,
+        mdata: 20,
+        adata: 30,
+        account: 40,
+        per_byte: 2,
+    };
+    let (mut connection_manager, _, client_safe_key, owner_key) = setup(Some(Config {
+        quic_p2p: QuicP2pConfig::with_default_cert(),
+        dev: Some(DevConfig {
+            mock_unlimited_coins: false,
+            mock_in_memory_storage: false,
+            mock_vault_path: None,
+            mock_pricing: pricing.clone(),
+        }),
+    }));
+
+    let get_balance = |connection_manager: &mut ConnectionManager| -> Coins {
+        match process_request(connection_manager, &client_safe_key, Request::GetBalance) {
+            Response::GetBalance(res) => unwrap!(res),
+            res => panic!("Unexpected response {:?}", res),
+        }
+    };
+
+    // Reads are free.
+    assert_eq!(
+        unwrap!(connection_manager.estimate_cost(&Request::GetBalance)),
+        unwrap!(Coins::from_nano(0))
+    );
+
+    // Storing immutable data is charged its base price plus the price of every byte.
+    let idata: IData =
+        UnpubImmutableData::new(unwrap!(utils::generate_random_vector(100)), owner_key).into();
+    let put_request = Request::PutIData(idata.clone());
+    let cost = unwrap!(connection_manager.estimate_cost(&put_request));
+    let expected = pricing.idata + pricing.per_byte * unwrap!(serialized_size(&idata));
+    assert_eq!(cost, unwrap!(Coins::from_nano(expected)));
+
+    let balance = get_balance(&mut connection_manager);
+    send_req_expect_ok!(&mut connection_manager, &client_safe_key, put_request, ());
+    let balance = unwrap!(balance.checked_sub(cost));
+    assert_eq!(get_balance(&mut connection_manager), balance);
+
+    // Mutable data is priced separately.
+    let data: MData = UnseqMutableData::new(rand::random(), 1000, owner_key).into();
+    let put_request = Request::PutMData(data.clone());
+    let cost = unwrap!(connection_manager.estimate_cost(&put_request));
+    let expected = pricing.mdata + pricing.per_byte * unwrap!(serialized_size(&data));
+    assert_eq!(cost, unwrap!(Coins::from_nano(expected)));
+
+    send_req_expect_ok!(&mut connection_manager, &client_safe_key, put_request, ());
+    let balance = unwrap!(balance.checked_sub(cost));
+    assert_eq!(get_balance(&mut connection_manager), balance);
+
+    // Deletions are free.
+    let delete_request = Request::DeleteMData(*data.address());
+    assert_eq!(
+        unwrap!(connection_manager.estimate_cost(&delete_request)),
+        unwrap!(Coins::from_nano(0))
+    );
+    send_req_expect_ok!(
+        &mut connection_manager,
+        &client_safe_key,
+        delete_request,
+        ()
+    );
+    assert_eq!(get_balance(&mut connection_manager), balance);
+}
+
 // Test that using an invalid mock-vault path does not work.
 #[test]
 #[should_panic]
@@ -1377,6 +1519,7 @@ fn invalid_config_mock_vault_path() {
             mock_unlimited_coins: false,
             mock_in_memory_storage: false,
             mock_vault_path: Some(String::from("./this_path_should_not_exist")),
+            mock_pricing: Default::default(),
         }),
     }));
 }
@@ -1404,6 +1547,7 @@ fn config_mock_vault_path() {
             mock_unlimited_coins: false,
             mock_in_memory_storage: false,
             mock_vault_path: Some(String::from("./tmp")),
+            mock_pricing: Default::default(),
         }),
     }));
     // Put MutableData. Should succeed.
@@ -1525,6 +1669,215 @@ fn request_hooks() {
     );
 }
 
+// Test signing requests with an external signer.
+// 1. Put unpublished immutable data owned by the client, signing the request with an external
+//    signer holding the client keys.
+// 2. Verify that the data can be fetched and that the signer signed both requests.
+// 3. Take the signer offline and verify that requests fail without reaching the vault.
+#[test]
+fn external_signer() {
+    let (mut connection_manager, _, client_safe_key, owner_key) = setup(None);
+    let signer = Arc::new(LocalSigner::new(client_safe_key.clone()));
+    let external_key = SafeKey::external(signer.clone());
+    assert_eq!(external_key.public_key(), owner_key);
+
+    let value = unwrap!(utils::generate_random_vector::<u8>(100));
+    let data: IData = UnpubImmutableData::new(value, owner_key).into();
+    let address = *data.address();
+    send_req_expect_ok!(
+        &mut connection_manager,
+        &external_key,
+        Request::PutIData(data.clone()),
+        ()
+    );
+    send_req_expect_ok!(
+        &mut connection_manager,
+        &external_key,
+        Request::GetIData(address),
+        data
+    );
+    assert_eq!(signer.signatures(), 2);
+
+    signer.set_offline(true);
+    match req(&mut connection_manager, Request::GetBalance, &external_key) {
+        Err(CoreError::Unexpected(_)) => (),
+        Err(error) => panic!("Unexpected {:?}", error),
+        Ok(response) => panic!("Unexpected {:?}", response),
+    }
+    assert_eq!(signer.signatures(), 2);
+}
+
+// Test data owned by a threshold key set.
+// 1. Create a 2-of-3 key set and put MData and AData owned by it, signed by two co-owners.
+// 2. Mutate both, signed by another pair of co-owners.
+// 3. Verify that a single co-owner can't sign on behalf of the key set, and that its own client
+//    keys can't mutate the data.
+#[test]
+fn threshold_ownership() {
+    let (mut connection_manager, _, client_safe_key, _) = setup(None);
+
+    let (owners, shares) = unwrap!(ThresholdOwners::generate(2, 3));
+    assert_eq!(owners.threshold(), 2);
+    assert_eq!(owners.count(), 3);
+    assert_eq!(*owners.public_id().public_key(), owners.public_key());
+    connection_manager.create_balance(owners.public_key(), unwrap!(Coins::from_str("10")));
+
+    let co_owners: Vec<Arc<dyn CoOwner>> = shares
+        .into_iter()
+        .enumerate()
+        .map(|(index, share)| -> Arc<dyn CoOwner> { Arc::new(LocalCoOwner::new(index, share)) })
+        .collect();
+    let signer = |indices: &[usize]| {
+        let co_owners = indices
+            .iter()
+            .map(|index| co_owners[*index].clone())
+            .collect();
+        SafeKey::external(Arc::new(ThresholdSigner::new(owners.clone(), co_owners)))
+    };
+    let first_pair = signer(&[0, 1]);
+    let second_pair = signer(&[1, 2]);
+    let single = signer(&[2]);
+
+    // MData
+    let name = rand::random();
+    let tag = 1000u64;
+    let data = SeqMutableData::new(name, tag, owners.public_key());
+    let address = *data.address();
+    send_req_expect_ok!(
+        &mut connection_manager,
+        &first_pair,
+        Request::PutMData(data.into()),
+        ()
+    );
+
+    let actions = MDataSeqEntryActions::new().ins(b"key".to_vec(), b"value".to_vec(), 0);
+    send_req_expect_ok!(
+        &mut connection_manager,
+        &second_pair,
+        Request::MutateMDataEntries {
+            address,
+            actions: actions.into(),
+        },
+        ()
+    );
+    let response = process_request(
+        &mut connection_manager,
+        &first_pair,
+        Request::GetMDataVersion(address),
+    );
+    assert_eq!(response, Response::GetMDataVersion(Ok(0)));
+
+    let actions = MDataSeqEntryActions::new().update(b"key".to_vec(), b"other".to_vec(), 1);
+    let mutation = Request::MutateMDataEntries {
+        address,
+        actions: actions.into(),
+    };
+    match req(&mut connection_manager, mutation.clone(), &single) {
+        Err(CoreError::InsufficientSignatureShares) => (),
+        Err(error) => panic!("Unexpected {:?}", error),
+        Ok(response) => panic!("Unexpected {:?}", response),
+    }
+    send_req_expect_failure!(
+        &mut connection_manager,
+        &client_safe_key,
+        mutation,
+        Error::AccessDenied
+    );
+
+    // AData
+    let name = rand::random();
+    let tag = 1000u64;
+    let address = ADataAddress::PubSeq { name, tag };
+    let mut data = PubSeqAppendOnlyData::new(name, tag);
+    let mut permissions = BTreeMap::new();
+    let _ = permissions.insert(
+        ADataUser::Key(owners.public_key()),
+        ADataPubPermissionSet::new(true, true),
+    );
+    unwrap!(data.append_permissions(
+        ADataPubPermissions {
+            permissions,
+            entries_index: 0,
+            owners_index: 0,
+        },
+        0
+    ));
+    unwrap!(data.append_owner(
+        ADataOwner {
+            public_key: owners.public_key(),
+            entries_index: 0,
+            permissions_index: 1,
+        },
+        0
+    ));
+    send_req_expect_ok!(
+        &mut connection_manager,
+        &first_pair,
+        Request::PutAData(AData::PubSeq(data)),
+        ()
+    );
+
+    let append = |value: &[u8]| ADataAppendOperation {
+        address,
+        values: vec![ADataEntry::new(value.to_vec(), value.to_vec())],
+    };
+    send_req_expect_ok!(
+        &mut connection_manager,
+        &second_pair,
+        Request::AppendSeq {
+            append: append(b"first"),
+            index: 0,
+        },
+        ()
+    );
+    send_req_expect_failure!(
+        &mut connection_manager,
+        &client_safe_key,
+        Request::AppendSeq {
+            append: append(b"second"),
+            index: 1,
+        },
+        Error::AccessDenied
+    );
+}
+
+// Test collecting partial signatures.
+// 1. Verify that shares claiming to come from another co-owner or signing other bytes are rejected.
+// 2. Verify that the combined signature can't be made until enough valid shares are collected.
+#[test]
+fn threshold_signature_collection() {
+    let (owners, shares) = unwrap!(ThresholdOwners::generate(2, 3));
+    let request = SigningRequest::for_request(&Request::GetBalance, MessageId::new());
+    let request = unwrap!(request);
+    assert_eq!(unwrap!(request.request()).0, Request::GetBalance);
+
+    let mut collector = SignatureCollector::new(owners.clone(), request.clone());
+
+    let mut misattributed = request.sign(0, &shares[0]);
+    misattributed.index = 1;
+    match collector.add(misattributed) {
+        Err(CoreError::DataError(Error::InvalidSignature)) => (),
+        x => panic!("Unexpected {:?}", x),
+    }
+
+    let other_request = SigningRequest::new(b"other".to_vec());
+    match collector.add(other_request.sign(1, &shares[1])) {
+        Err(CoreError::DataError(Error::InvalidSignature)) => (),
+        x => panic!("Unexpected {:?}", x),
+    }
+
+    assert!(!unwrap!(collector.add(request.sign(0, &shares[0]))));
+    match collector.signature() {
+        Err(CoreError::InsufficientSignatureShares) => (),
+        x => panic!("Unexpected {:?}", x),
+    }
+
+    assert!(unwrap!(collector.add(request.sign(2, &shares[2]))));
+    let signature = unwrap!(collector.signature());
+    let public_key = owners.public_key();
+    assert!(public_key.verify(&signature, request.payload()).is_ok());
+}
+
 // Setup a connection manager for a new account with a shared, global vault or with a
 // new, non-shared vault by providing a config.
 fn setup(
@@ -9,9 +9,10 @@
 use super::DataId;
 use super::{Account, CoinBalance};
 use crate::client::mock::connection_manager::unlimited_coins;
-use crate::client::COST_OF_PUT;
-use crate::config_handler::{Config, DevConfig};
-use bincode::{deserialize, serialize};
+use crate::config_handler::{Config, DevConfig, MockPricing};
+use crate::core_structs::{AppUsage, TransactionDirection, TransactionRecord};
+use bincode::{deserialize, serialize, serialized_size};
+use chrono::Utc;
 use fs2::FileExt;
 use log::{debug, trace, warn};
 use safe_nd::{
@@ -152,9 +153,47 @@ fn check_perms_mdata(data: &MData, request: &Request, requester: PublicKey) -> S
     }
 }
 
+// Returns the price of the given request according to the pricing model, along with the number
+// of bytes it stores. Deletions, reads, coin transfers and auth key management are free.
+fn mutation_cost(pricing: &MockPricing, request: &Request) -> SndResult<(Coins, u64)> {
+    let (base, bytes) = match request {
+        Request::PutIData(data) => (pricing.idata, stored_size(data)?),
+        Request::PutMData(data) => (pricing.mdata, stored_size(data)?),
+        Request::MutateMDataEntries { actions, .. } => (pricing.mdata, stored_size(actions)?),
+        Request::SetMDataUserPermissions { .. } | Request::DelMDataUserPermissions { .. } => {
+            (pricing.mdata, 0)
+        }
+        Request::PutAData(data) => (pricing.adata, stored_size(data)?),
+        Request::AppendSeq { append, .. } | Request::AppendUnseq(append) => {
+            (pricing.adata, stored_size(&append.values)?)
+        }
+        Request::AddPubADataPermissions { .. }
+        | Request::AddUnpubADataPermissions { .. }
+        | Request::SetADataOwner { .. } => (pricing.adata, 0),
+        Request::CreateBalance { .. } => (pricing.account, 0),
+        Request::CreateLoginPacket(login_packet)
+        | Request::CreateLoginPacketFor {
+            new_login_packet: login_packet,
+            ..
+        } => (pricing.account, login_packet.data().len() as u64),
+        _ => (0, 0),
+    };
+    let price = pricing
+        .per_byte
+        .checked_mul(bytes)
+        .and_then(|bytes_price| bytes_price.checked_add(base))
+        .ok_or(SndError::ExcessiveValue)?;
+    Ok((Coins::from_nano(price)?, bytes))
+}
+
+fn stored_size<T: Serialize>(data: &T) -> SndResult<u64> {
+    serialized_size(data).map_err(|_| SndError::from("Failed to serialise data"))
+}
+
 enum Operation {
     TransferCoins,
-    Mutation,
+    // Mutation costing the given amount.
+    Mutation(Coins),
     GetBalance,
 }
 
@@ -234,7 +273,7 @@ impl Vault {
                 return Err(SndError::NoSuchBalance);
             }
         };
-        balance.credit_balance(amount, rand::random())
+        balance.credit_balance(amount)
     }
 
     fn get_balance(&self, coins_balance_id: &XorName) -> SndResult<Coins> {
@@ -247,6 +286,71 @@ impl Vault {
         )
     }
 
+    /// Returns the transaction history of the given coin balance, oldest first.
+    /// Apps need the `get_balance` permission to read it.
+    pub fn get_transaction_history(
+        &self,
+        owner: XorName,
+        requester_pk: PublicKey,
+    ) -> SndResult<Vec<TransactionRecord>> {
+        self.authorise_operations(&[Operation::GetBalance], owner, requester_pk)?;
+        self.get_coin_balance(&owner)
+            .map(|balance| balance.history().to_vec())
+            .ok_or(SndError::NoSuchBalance)
+    }
+
+    /// Returns the resources consumed by each app of the given account.
+    /// Only the owner of the account can read it.
+    pub fn get_app_usage(
+        &self,
+        owner: XorName,
+        requester_pk: PublicKey,
+    ) -> SndResult<BTreeMap<PublicKey, AppUsage>> {
+        if owner != XorName::from(requester_pk) {
+            return Err(SndError::AccessDenied);
+        }
+        let account = self.get_account(&owner).ok_or_else(|| {
+            debug!("Account not found for {:?}", owner);
+            SndError::AccessDenied
+        })?;
+        Ok(account.app_usage().clone())
+    }
+
+    /// Changes the owner of the given mutable data. Only its current owner can do that; apps
+    /// also need the `perform_mutations` permission. Like permission changes it's free.
+    pub fn change_mdata_owner(
+        &mut self,
+        requester: &PublicId,
+        address: MDataAddress,
+        new_owner: PublicKey,
+        version: u64,
+    ) -> SndResult<()> {
+        let requester_pk = match requester {
+            PublicId::App(app_id) => *app_id.public_key(),
+            PublicId::Client(client_id) => *client_id.public_key(),
+            PublicId::Node(_) => return Err(SndError::AccessDenied),
+        };
+        self.authorise_operations(
+            &[Operation::Mutation(Coins::from_nano(0)?)],
+            *requester.name(),
+            requester_pk,
+        )?;
+
+        let data_name = DataId::Mutable(address);
+        let mut data = match self.get_data(&data_name) {
+            Some(Data::Mutable(data)) => data,
+            _ => return Err(SndError::NoSuchData),
+        };
+        data.check_is_owner(requester_pk)?;
+        match data {
+            MData::Seq(ref mut data) => data.change_owner(new_owner, version)?,
+            MData::Unseq(ref mut data) => data.change_owner(new_owner, version)?,
+        }
+        self.insert_data(data_name, Data::Mutable(data));
+
+        Ok(())
+    }
+
     // Checks if the given balance has sufficient coins for the given `amount` of Operation.
     fn has_sufficient_balance(&self, balance: Coins, amount: Coins) -> bool {
         unlimited_coins(&self.config) || balance.checked_sub(amount).is_some()
@@ -264,9 +368,9 @@ impl Vault {
         // Checks if the requester is the owner
         if owner == requester {
             for operation in operations {
-                // Mutation operations must be checked for min COST_OF_PUT balance
-                if let Operation::Mutation = operation {
-                    if !self.has_sufficient_balance(balance, *COST_OF_PUT) {
+                // Mutation operations must be checked for sufficient balance to cover their cost
+                if let Operation::Mutation(cost) = operation {
+                    if !self.has_sufficient_balance(balance, *cost) {
                         return Err(SndError::InsufficientBalance);
                     }
                 }
@@ -299,12 +403,12 @@ impl Vault {
                         return Err(SndError::AccessDenied);
                     }
                 }
-                Operation::Mutation => {
+                Operation::Mutation(cost) => {
                     if !perms.perform_mutations {
                         debug!("Performing mutations not authorised");
                         return Err(SndError::AccessDenied);
                     }
-                    if !self.has_sufficient_balance(balance, *COST_OF_PUT) {
+                    if !self.has_sufficient_balance(balance, *cost) {
                         return Err(SndError::InsufficientBalance);
                     }
                 }
@@ -313,12 +417,39 @@ impl Vault {
         Ok(())
     }
 
-    // Commit a mutation.
-    pub fn commit_mutation(&mut self, account: &XorName) {
-        if !unlimited_coins(&self.config) {
-            let balance = unwrap!(self.get_coin_balance_mut(account));
-            // Cannot fail - Balance is checked before
-            unwrap!(balance.debit_balance(*COST_OF_PUT));
+    // Commit a mutation, charging its cost to the given account's balance.
+    pub fn commit_mutation(&mut self, account: &XorName, cost: Coins) -> SndResult<()> {
+        if unlimited_coins(&self.config) {
+            return Ok(());
+        }
+        match self.get_coin_balance_mut(account) {
+            Some(balance) => balance.debit_balance(cost),
+            None => {
+                debug!("Balance not found for {:?}", account);
+                Err(SndError::NoSuchBalance)
+            }
+        }
+    }
+
+    /// Returns the amount of coins that would be charged for the given request.
+    pub fn estimate_cost(&self, request: &Request) -> SndResult<Coins> {
+        self.mutation_cost(request).map(|(cost, _)| cost)
+    }
+
+    // Returns the amount of coins charged for the given request, along with the number of bytes
+    // it stores.
+    fn mutation_cost(&self, request: &Request) -> SndResult<(Coins, u64)> {
+        let pricing = self
+            .config
+            .dev
+            .as_ref()
+            .map(|dev| dev.mock_pricing.clone())
+            .unwrap_or_default();
+        let (cost, bytes) = mutation_cost(&pricing, request)?;
+        if unlimited_coins(&self.config) {
+            Ok((Coins::from_nano(0)?, bytes))
+        } else {
+            Ok((cost, bytes))
         }
     }
 
@@ -359,6 +490,7 @@ impl Vault {
         destination: XorName,
         amount: Coins,
         transaction_id: u64,
+        signer: PublicKey,
     ) -> SndResult<Transaction> {
         let unlimited = unlimited_coins(&self.config);
         match self.get_coin_balance_mut(&source) {
@@ -370,9 +502,26 @@ impl Vault {
             None => return Err(SndError::NoSuchBalance),
         };
         match self.get_coin_balance_mut(&destination) {
-            Some(balance) => balance.credit_balance(amount, transaction_id)?,
+            Some(balance) => balance.credit_balance(amount)?,
             None => return Err(SndError::NoSuchBalance),
         };
+
+        let timestamp = Utc::now();
+        let record = |direction, counterparty| TransactionRecord {
+            id: transaction_id,
+            direction,
+            counterparty,
+            amount,
+            signer,
+            timestamp,
+        };
+        if let Some(balance) = self.get_coin_balance_mut(&source) {
+            balance.record_transaction(record(TransactionDirection::Sent, destination));
+        }
+        if let Some(balance) = self.get_coin_balance_mut(&destination) {
+            balance.record_transaction(record(TransactionDirection::Received, source));
+        }
+
         Ok(Transaction {
             id: transaction_id,
             amount,
@@ -443,6 +592,25 @@ impl Vault {
             }
         };
 
+        // Price of the request, charged to the owner's balance if it's a mutation.
+        let (cost, bytes) = match self.mutation_cost(request) {
+            Ok(cost_and_bytes) => cost_and_bytes,
+            Err(err) => {
+                let response = request.error_response(err);
+                return Ok(Message::Response {
+                    response,
+                    message_id,
+                });
+            }
+        };
+
+        // The requester is consumed below, so keep the account name of apps for usage accounting.
+        let app_owner_name = match requester {
+            PublicId::App(ref app_id) => Some(*app_id.owner_name()),
+            _ => None,
+        };
+        let request_type = request.get_type();
+
         let response = match request.clone() {
             //
             // Immutable Data
@@ -476,6 +644,7 @@ impl Vault {
                         DataId::Immutable(*idata.address()),
                         Data::Immutable(idata),
                         requester,
+                        cost,
                     )
                 };
                 Response::Mutation(result)
@@ -528,7 +697,13 @@ impl Vault {
                 } else {
                     self.authorise_operations(&[Operation::TransferCoins], source, requester_pk)
                         .and_then(|()| {
-                            self.transfer_coins(source, destination, amount, transaction_id)
+                            self.transfer_coins(
+                                source,
+                                destination,
+                                amount,
+                                transaction_id,
+                                requester_pk,
+                            )
                         })
                 };
                 Response::Transaction(result)
@@ -548,24 +723,29 @@ impl Vault {
                         amount,
                     })
                 } else {
-                    let mut req_perms = vec![Operation::Mutation];
+                    let mut req_perms = vec![Operation::Mutation(cost)];
                     if amount == unwrap!(Coins::from_str("0")) {
                         req_perms.push(Operation::TransferCoins);
                     }
                     self.authorise_operations(req_perms.as_slice(), source, requester_pk)
                         .and_then(|_| self.get_balance(&source))
                         .and_then(|source_balance| {
-                            let total_amount = amount
-                                .checked_add(*COST_OF_PUT)
-                                .ok_or(SndError::ExcessiveValue)?;
+                            let total_amount =
+                                amount.checked_add(cost).ok_or(SndError::ExcessiveValue)?;
                             if !self.has_sufficient_balance(source_balance, total_amount) {
                                 return Err(SndError::InsufficientBalance);
                             }
                             self.create_balance(destination, new_balance_owner)
                         })
                         .and_then(|()| {
-                            self.commit_mutation(&source);
-                            self.transfer_coins(source, destination, amount, transaction_id)
+                            self.commit_mutation(&source, cost)?;
+                            self.transfer_coins(
+                                source,
+                                destination,
+                                amount,
+                                transaction_id,
+                                requester_pk,
+                            )
                         })
                 };
                 Response::Transaction(result)
@@ -575,7 +755,7 @@ impl Vault {
 
                 let result = self
                     .authorise_operations(&[Operation::GetBalance], coin_balance_id, requester_pk)
-                    .and_then(move |_| self.get_balance(&coin_balance_id));
+                    .and_then(|_| self.get_balance(&coin_balance_id));
                 Response::GetBalance(result)
             }
             // ===== Account =====
@@ -591,7 +771,7 @@ impl Vault {
                 // If a login packet at the given destination exists return an error.
                 let result = if let Err(e) = {
                     // Check if the requester is authorized to perform coin transactions, mutate, and read balance.
-                    let mut req_perms = vec![Operation::Mutation];
+                    let mut req_perms = vec![Operation::Mutation(cost)];
                     if amount == unwrap!(Coins::from_str("0")) {
                         req_perms.push(Operation::TransferCoins);
                     }
@@ -601,9 +781,8 @@ impl Vault {
                 } else {
                     self.get_balance(&source)
                         .and_then(|source_balance| {
-                            let debit_amt = amount
-                                .checked_add(*COST_OF_PUT)
-                                .ok_or(SndError::ExcessiveValue)?;
+                            let debit_amt =
+                                amount.checked_add(cost).ok_or(SndError::ExcessiveValue)?;
                             if !self.has_sufficient_balance(source_balance, debit_amt) {
                                 return Err(SndError::InsufficientBalance);
                             }
@@ -613,8 +792,14 @@ impl Vault {
                         })
                         .and_then(|_| {
                             // Debit the requester's wallet the cost of `CreateLoginPacketFor`
-                            self.commit_mutation(&source);
-                            self.transfer_coins(source, new_balance_dest, amount, transaction_id)
+                            self.commit_mutation(&source, cost)?;
+                            self.transfer_coins(
+                                source,
+                                new_balance_dest,
+                                amount,
+                                transaction_id,
+                                requester_pk,
+                            )
                         })
                         .and_then(|_| {
                             if self
@@ -642,7 +827,7 @@ impl Vault {
                 let source = owner_pk.into();
 
                 if let Err(e) =
-                    self.authorise_operations(&[Operation::Mutation], source, requester_pk)
+                    self.authorise_operations(&[Operation::Mutation(cost)], source, requester_pk)
                 {
                     Response::Mutation(Err(e))
                 } else if self.get_login_packet(account_data.destination()).is_some() {
@@ -651,11 +836,10 @@ impl Vault {
                     let result = self
                         .get_balance(&source)
                         .and_then(|source_balance| {
-                            if !self.has_sufficient_balance(source_balance, *COST_OF_PUT) {
+                            if !self.has_sufficient_balance(source_balance, cost) {
                                 return Err(SndError::InsufficientBalance);
                             }
-                            self.commit_mutation(&source);
-                            Ok(())
+                            self.commit_mutation(&source, cost)
                         })
                         .map(|_| self.insert_login_packet(account_data));
                     Response::Mutation(result)
@@ -712,7 +896,12 @@ impl Vault {
                 let result = if data.owner() != owner_pk {
                     Err(SndError::InvalidOwners)
                 } else {
-                    self.put_data(DataId::Mutable(address), Data::Mutable(data), requester)
+                    self.put_data(
+                        DataId::Mutable(address),
+                        Data::Mutable(data),
+                        requester,
+                        cost,
+                    )
                 };
                 Response::Mutation(result)
             }
@@ -841,8 +1030,8 @@ impl Vault {
 
                         let data_name = DataId::Mutable(address);
                         data.set_user_permissions(user, permissions, version)?;
+                        self.commit_mutation(requester.name(), cost)?;
                         self.insert_data(data_name, Data::Mutable(data));
-                        self.commit_mutation(requester.name());
 
                         Ok(())
                     });
@@ -864,8 +1053,8 @@ impl Vault {
 
                         let data_name = DataId::Mutable(address);
                         data.del_user_permissions(user, version)?;
+                        self.commit_mutation(requester.name(), cost)?;
                         self.insert_data(data_name, Data::Mutable(data));
-                        self.commit_mutation(requester.name());
 
                         Ok(())
                     });
@@ -901,20 +1090,20 @@ impl Vault {
                 address,
                 ref actions,
             } => {
-                let result =
-                    self.get_mdata(address, requester_pk, request)
-                        .and_then(move |mut data| {
-                            if address != *data.address() {
-                                return Err(SndError::NoSuchData);
-                            }
+                let result = self
+                    .get_mdata(address, requester_pk, request)
+                    .and_then(|mut data| {
+                        if address != *data.address() {
+                            return Err(SndError::NoSuchData);
+                        }
 
-                            let data_name = DataId::Mutable(address);
-                            data.mutate_entries(actions.clone(), requester_pk)?;
-                            self.insert_data(data_name, Data::Mutable(data));
-                            self.commit_mutation(requester.name());
+                        let data_name = DataId::Mutable(address);
+                        data.mutate_entries(actions.clone(), requester_pk)?;
+                        self.commit_mutation(requester.name(), cost)?;
+                        self.insert_data(data_name, Data::Mutable(data));
 
-                            Ok(())
-                        });
+                        Ok(())
+                    });
                 Response::Mutation(result)
             }
             //
@@ -933,6 +1122,7 @@ impl Vault {
                                 DataId::AppendOnly(address),
                                 Data::AppendOnly(adata),
                                 requester,
+                                cost,
                             )
                         }
                     }
@@ -948,7 +1138,7 @@ impl Vault {
                 let id = DataId::AppendOnly(address);
                 let result = self
                     .get_adata(address, requester_pk, request)
-                    .and_then(move |data| match data {
+                    .and_then(|data| match data {
                         // Cannot be deleted as it is a published data.
                         AData::PubSeq(_) | AData::PubUnseq(_) => Err(SndError::InvalidOperation),
                         AData::UnpubSeq(_) | AData::UnpubUnseq(_) => {
@@ -1048,16 +1238,16 @@ impl Vault {
                 let id = DataId::AppendOnly(append.address);
                 let result = self
                     .get_adata(append.address, requester_pk, request)
-                    .and_then(move |data| match data {
+                    .and_then(|data| match data {
                         AData::PubSeq(mut adata) => {
                             adata.append(append.values, index)?;
-                            self.commit_mutation(requester.name());
+                            self.commit_mutation(requester.name(), cost)?;
                             self.insert_data(id, Data::AppendOnly(AData::PubSeq(adata)));
                             Ok(())
                         }
                         AData::UnpubSeq(mut adata) => {
                             adata.append(append.values, index)?;
-                            self.commit_mutation(requester.name());
+                            self.commit_mutation(requester.name(), cost)?;
                             self.insert_data(id, Data::AppendOnly(AData::UnpubSeq(adata)));
                             Ok(())
                         }
@@ -1069,16 +1259,16 @@ impl Vault {
                 let id = DataId::AppendOnly(append.address);
                 let result = self
                     .get_adata(append.address, requester_pk, request)
-                    .and_then(move |data| match data {
+                    .and_then(|data| match data {
                         AData::PubUnseq(mut adata) => {
                             adata.append(append.values)?;
-                            self.commit_mutation(requester.name());
+                            self.commit_mutation(requester.name(), cost)?;
                             self.insert_data(id, Data::AppendOnly(AData::PubUnseq(adata)));
                             Ok(())
                         }
                         AData::UnpubUnseq(mut adata) => {
                             adata.append(append.values)?;
-                            self.commit_mutation(requester.name());
+                            self.commit_mutation(requester.name(), cost)?;
                             self.insert_data(id, Data::AppendOnly(AData::UnpubUnseq(adata)));
                             Ok(())
                         }
@@ -1094,11 +1284,11 @@ impl Vault {
                 let id = DataId::AppendOnly(address);
                 let result = self
                     .get_adata(address, requester_pk, request)
-                    .and_then(move |data| match address {
+                    .and_then(|data| match address {
                         ADataAddress::PubSeq { .. } => match data {
                             AData::PubSeq(mut adata) => {
                                 adata.append_permissions(permissions, permissions_index)?;
-                                self.commit_mutation(requester.name());
+                                self.commit_mutation(requester.name(), cost)?;
                                 self.insert_data(id, Data::AppendOnly(AData::PubSeq(adata)));
                                 Ok(())
                             }
@@ -1107,7 +1297,7 @@ impl Vault {
                         ADataAddress::PubUnseq { .. } => match data {
                             AData::PubUnseq(mut adata) => {
                                 adata.append_permissions(permissions, permissions_index)?;
-                                self.commit_mutation(requester.name());
+                                self.commit_mutation(requester.name(), cost)?;
                                 self.insert_data(id, Data::AppendOnly(AData::PubUnseq(adata)));
                                 Ok(())
                             }
@@ -1129,7 +1319,7 @@ impl Vault {
                         ADataAddress::UnpubSeq { .. } => match data.clone() {
                             AData::UnpubSeq(mut adata) => {
                                 adata.append_permissions(permissions, permissions_index)?;
-                                self.commit_mutation(requester.name());
+                                self.commit_mutation(requester.name(), cost)?;
                                 self.insert_data(id, Data::AppendOnly(AData::UnpubSeq(adata)));
                                 Ok(())
                             }
@@ -1138,7 +1328,7 @@ impl Vault {
                         ADataAddress::UnpubUnseq { .. } => match data {
                             AData::UnpubUnseq(mut adata) => {
                                 adata.append_permissions(permissions, permissions_index)?;
-                                self.commit_mutation(requester.name());
+                                self.commit_mutation(requester.name(), cost)?;
                                 self.insert_data(id, Data::AppendOnly(AData::UnpubUnseq(adata)));
                                 Ok(())
                             }
@@ -1156,11 +1346,11 @@ impl Vault {
                 let id = DataId::AppendOnly(address);
                 let result = self
                     .get_adata(address, requester_pk, request)
-                    .and_then(move |data| match address {
+                    .and_then(|data| match address {
                         ADataAddress::PubSeq { .. } => match data {
                             AData::PubSeq(mut adata) => {
                                 adata.append_owner(owner, owners_index)?;
-                                self.commit_mutation(requester.name());
+                                self.commit_mutation(requester.name(), cost)?;
                                 self.insert_data(id, Data::AppendOnly(AData::PubSeq(adata)));
                                 Ok(())
                             }
@@ -1169,7 +1359,7 @@ impl Vault {
                         ADataAddress::PubUnseq { .. } => match data {
                             AData::PubUnseq(mut adata) => {
                                 adata.append_owner(owner, owners_index)?;
-                                self.commit_mutation(requester.name());
+                                self.commit_mutation(requester.name(), cost)?;
                                 self.insert_data(id, Data::AppendOnly(AData::PubUnseq(adata)));
                                 Ok(())
                             }
@@ -1178,7 +1368,7 @@ impl Vault {
                         ADataAddress::UnpubSeq { .. } => match data.clone() {
                             AData::UnpubSeq(mut adata) => {
                                 adata.append_owner(owner, owners_index)?;
-                                self.commit_mutation(requester.name());
+                                self.commit_mutation(requester.name(), cost)?;
                                 self.insert_data(id, Data::AppendOnly(AData::UnpubSeq(adata)));
                                 Ok(())
                             }
@@ -1187,7 +1377,7 @@ impl Vault {
                         ADataAddress::UnpubUnseq { .. } => match data {
                             AData::UnpubUnseq(mut adata) => {
                                 adata.append_owner(owner, owners_index)?;
-                                self.commit_mutation(requester.name());
+                                self.commit_mutation(requester.name(), cost)?;
                                 self.insert_data(id, Data::AppendOnly(AData::UnpubUnseq(adata)));
                                 Ok(())
                             }
@@ -1216,6 +1406,15 @@ impl Vault {
             }
         };
 
+        // Attribute successful mutations made by apps to the app's key in the owner's account.
+        if let (Some(owner_name), RequestType::Mutation, Response::Mutation(Ok(()))) =
+            (app_owner_name, request_type, &response)
+        {
+            if let Some(account) = self.get_account_mut(&owner_name) {
+                account.record_app_usage(requester_pk, cost, bytes);
+            }
+        }
+
         Ok(Message::Response {
             response,
             message_id,
@@ -1302,6 +1501,7 @@ impl Vault {
         data_name: DataId,
         data: Data,
         requester: PublicId,
+        cost: Coins,
     ) -> SndResult<()> {
         let (name, key) = match requester.clone() {
             PublicId::Client(client_public_id) => {
@@ -1312,19 +1512,18 @@ impl Vault {
             }
             _ => return Err(SndError::AccessDenied),
         };
-        self.authorise_operations(&[Operation::Mutation], name, key)?;
+        self.authorise_operations(&[Operation::Mutation(cost)], name, key)?;
         if self.contains_data(&data_name) {
             // Published Immutable Data is de-duplicated
             if let DataId::Immutable(addr) = data_name {
                 if addr.is_pub() {
-                    self.commit_mutation(&requester.name());
-                    return Ok(());
+                    return self.commit_mutation(requester.name(), cost);
                 }
             }
             Err(SndError::DataExists)
         } else {
+            self.commit_mutation(requester.name(), cost)?;
             self.insert_data(data_name, data);
-            self.commit_mutation(&requester.name());
             Ok(())
         }
     }
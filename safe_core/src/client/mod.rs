@@ -15,10 +15,13 @@ pub mod core_client;
 pub mod mdata_info;
 /// Various APIs wrapped to provide resiliance for common network operations.
 pub mod recoverable_apis;
+/// Threshold ownership of data.
+pub mod threshold;
 
 mod id;
 #[cfg(feature = "mock-network")]
 mod mock;
+mod signer;
 
 pub use self::account::ClientKeys;
 pub use self::id::SafeKey;
@@ -27,17 +30,24 @@ pub use self::mdata_info::MDataInfo;
 pub use self::mock::vault::mock_vault_path;
 #[cfg(feature = "mock-network")]
 pub use self::mock::ConnectionManager as MockConnectionManager;
+#[cfg(any(test, feature = "testing"))]
+pub use self::signer::LocalSigner;
+pub use self::signer::Signer;
 
 #[cfg(feature = "mock-network")]
 use self::mock::ConnectionManager;
 use crate::config_handler::Config;
 #[cfg(not(feature = "mock-network"))]
 use crate::connection_manager::ConnectionManager;
+#[cfg(feature = "mock-network")]
+use crate::core_structs::AppUsage;
+use crate::core_structs::TransactionRecord;
 use crate::crypto::{shared_box, shared_secretbox};
 use crate::errors::CoreError;
 use crate::event_loop::{CoreFuture, CoreMsgTx};
 use crate::ipc::BootstrapConfig;
 use crate::network_event::{NetworkEvent, NetworkTx};
+use crate::ok;
 use crate::utils::FutureExt;
 use futures::{future, sync::mpsc, Future};
 use lazy_static::lazy_static;
@@ -47,8 +57,8 @@ use safe_nd::{
     AData, ADataAddress, ADataAppendOperation, ADataEntries, ADataEntry, ADataIndex, ADataIndices,
     ADataOwner, ADataPermissions, ADataPubPermissionSet, ADataPubPermissions,
     ADataUnpubPermissionSet, ADataUnpubPermissions, ADataUser, AppPermissions, ClientFullId, Coins,
-    IData, IDataAddress, LoginPacket, MData, MDataAddress, MDataEntries, MDataEntryActions,
-    MDataPermissionSet, MDataSeqEntries, MDataSeqEntryActions, MDataSeqValue,
+    Error as SndError, IData, IDataAddress, LoginPacket, MData, MDataAddress, MDataEntries,
+    MDataEntryActions, MDataPermissionSet, MDataSeqEntries, MDataSeqEntryActions, MDataSeqValue,
     MDataUnseqEntryActions, MDataValue, MDataValues, Message, MessageId, PublicId, PublicKey,
     Request, RequestType, Response, SeqMutableData, Transaction, UnseqMutableData, XorName,
 };
@@ -79,10 +89,16 @@ pub fn bootstrap_config() -> Result<BootstrapConfig, CoreError> {
 fn send(client: &impl Client, request: Request) -> Box<CoreFuture<Response>> {
     // `sign` should be false for GETs on published data, true otherwise.
     let sign = request.get_type() != RequestType::PublicGet;
-    let request = client.compose_message(request, sign);
-    let inner = client.inner();
-    let cm = &mut inner.borrow_mut().connection_manager;
-    cm.send(&client.public_id(), &request)
+    let client = client.clone();
+
+    client
+        .compose_message(request, sign)
+        .and_then(move |request| {
+            let inner = client.inner();
+            let cm = &mut inner.borrow_mut().connection_manager;
+            cm.send(&client.public_id(), &request)
+        })
+        .into_box()
 }
 
 // Sends a mutation request to a new routing.
@@ -114,22 +130,35 @@ fn send_as_helper(
     request: Request,
     client_id: Option<&ClientFullId>,
 ) -> Box<CoreFuture<Response>> {
-    let (message, identity) = match client_id {
-        Some(id) => (sign_request(request, id), SafeKey::client(id.clone())),
-        None => (client.compose_message(request, true), client.full_id()),
+    let identity = match client_id {
+        Some(id) => SafeKey::client(id.clone()),
+        None => client.full_id(),
     };
+    send_signed(client, request, identity)
+}
 
+// Sends a request signed by the given identity, which may be an app's rather than the client's.
+fn send_signed(
+    client: &impl Client,
+    request: Request,
+    identity: SafeKey,
+) -> Box<CoreFuture<Response>> {
     let pub_id = identity.public_id();
-
-    let inner = client.inner();
-
-    let cm = &mut inner.borrow_mut().connection_manager;
-    let mut cm2 = cm.clone();
-
-    Box::new(
-        cm.bootstrap(identity)
-            .and_then(move |_| cm2.send(&pub_id, &message)),
-    )
+    let client = client.clone();
+
+    sign_request(request, &identity)
+        .and_then(move |message| {
+            let mut cm = client.inner().borrow().connection_manager.clone();
+
+            // Like `send`, send through the client's own connection manager rather than a clone,
+            // which the request hooks of the mock network can't intercept.
+            cm.bootstrap(identity).and_then(move |_| {
+                let inner = client.inner();
+                let cm = &mut inner.borrow_mut().connection_manager;
+                cm.send(&pub_id, &message)
+            })
+        })
+        .into_box()
 }
 
 /// Trait providing an interface for self-authentication client implementations, so they can
@@ -178,24 +207,18 @@ pub trait Client: Clone + 'static {
     fn secret_symmetric_key(&self) -> shared_secretbox::Key;
 
     /// Create a `Message` from the given request.
-    /// This function adds the requester signature and message ID.
-    fn compose_message(&self, request: Request, sign: bool) -> Message {
-        let message_id = MessageId::new();
-
-        let signature = if sign {
-            Some(
-                self.full_id()
-                    .sign(&unwrap!(bincode::serialize(&(&request, message_id)))),
-            )
-        } else {
-            None
-        };
+    /// This function adds the requester signature and message ID. Fails if the request has to be
+    /// signed and the signer of the client can't sign it.
+    fn compose_message(&self, request: Request, sign: bool) -> Box<CoreFuture<Message>> {
+        if sign {
+            return sign_request(request, &self.full_id());
+        }
 
-        Message::Request {
+        ok!(Message::Request {
             request,
-            message_id,
-            signature,
-        }
+            message_id: MessageId::new(),
+            signature: None,
+        })
     }
 
     /// Set request timeout.
@@ -307,6 +330,57 @@ pub trait Client: Clone + 'static {
         send_as!(self, Request::GetBalance, Response::GetBalance, client_id)
     }
 
+    /// Get the transaction history of the current coin balance, oldest first.
+    /// If `client_id` is provided, the history of that client's balance is fetched instead.
+    ///
+    /// Only available against the mock vault, as vaults don't keep a queryable transaction history
+    /// yet. Against the real network this fails with `CoreError::TransactionHistoryUnsupported`.
+    fn get_transaction_history(
+        &self,
+        client_id: Option<&ClientFullId>,
+    ) -> Box<CoreFuture<Vec<TransactionRecord>>> {
+        trace!("Get transaction history for {:?}", client_id);
+
+        let pub_id = client_id.map_or_else(
+            || self.public_id(),
+            |id| PublicId::Client(id.public_id().clone()),
+        );
+        let inner = self.inner();
+        let result = inner
+            .borrow()
+            .connection_manager
+            .transaction_history(&pub_id)
+            .map_err(CoreError::from);
+
+        future::result(result).into_box()
+    }
+
+    /// Estimate the amount of coins that would be charged for performing the given requests.
+    /// Nothing is sent to the network.
+    ///
+    /// Only the mock vault can estimate costs, following its pricing model exactly. Vaults don't
+    /// answer price queries yet, so against the real network this fails with
+    /// `CoreError::CostEstimateUnsupported`.
+    fn estimate_cost(&self, requests: &[Request]) -> Box<CoreFuture<Coins>> {
+        trace!("Estimate the cost of {} requests", requests.len());
+
+        let inner = self.inner();
+        let inner = inner.borrow();
+        let result = requests
+            .iter()
+            .try_fold(unwrap!(Coins::from_nano(0)), |total, request| {
+                let cost = inner
+                    .connection_manager
+                    .estimate_cost(request)
+                    .map_err(CoreError::from)?;
+                total
+                    .checked_add(cost)
+                    .ok_or_else(|| CoreError::from(SndError::ExcessiveValue))
+            });
+
+        future::result(result).into_box()
+    }
+
     /// Put immutable data to the network.
     fn put_idata(&self, data: impl Into<IData>) -> Box<CoreFuture<()>> {
         let idata: IData = data.into();
@@ -946,6 +1020,37 @@ pub trait Client: Clone + 'static {
         )
     }
 
+    /// Set new Owners to AData on behalf of `current_owner`, e.g. an app of this client which
+    /// owns the data.
+    fn set_adata_owners_as(
+        &self,
+        address: ADataAddress,
+        owner: ADataOwner,
+        owners_index: u64,
+        current_owner: &SafeKey,
+    ) -> Box<CoreFuture<()>> {
+        trace!(
+            "Set Owners to AppendOnly Data {:?} as {:?}",
+            address.name(),
+            current_owner.public_key()
+        );
+
+        send_signed(
+            self,
+            Request::SetADataOwner {
+                address,
+                owner,
+                owners_index,
+            },
+            current_owner.clone(),
+        )
+        .and_then(|res| match res {
+            Response::Mutation(res) => res.map_err(CoreError::from),
+            _ => Err(CoreError::ReceivedUnexpectedEvent),
+        })
+        .into_box()
+    }
+
     /// Set new Owners to AData
     fn get_adata_owners(
         &self,
@@ -1033,16 +1138,52 @@ pub trait Client: Clone + 'static {
         )
     }
 
-    /// Sends an ownership transfer request.
-    #[allow(unused)]
+    /// Changes the owner of a mutable data owned by this client.
+    ///
+    /// Vaults don't support changing the owner of mutable data yet, so against the real network
+    /// this always fails with `CoreError::OperationForbidden`. The mock vault supports it.
     fn change_mdata_owner(
         &self,
-        name: XorName,
-        tag: u64,
+        address: MDataAddress,
+        new_owner: PublicKey,
+        version: u64,
+    ) -> Box<CoreFuture<()>> {
+        self.change_mdata_owner_as(address, new_owner, version, &self.full_id())
+    }
+
+    /// Changes the owner of a mutable data on behalf of `owner`, e.g. an app of this client
+    /// which owns the data. See `change_mdata_owner`.
+    fn change_mdata_owner_as(
+        &self,
+        address: MDataAddress,
         new_owner: PublicKey,
         version: u64,
+        owner: &SafeKey,
     ) -> Box<CoreFuture<()>> {
-        unimplemented!();
+        trace!(
+            "ChangeMDataOwner for {:?} to {:?} at version {}",
+            address,
+            new_owner,
+            version
+        );
+
+        #[cfg(feature = "mock-network")]
+        {
+            let inner = self.inner();
+            let result = inner
+                .borrow()
+                .connection_manager
+                .change_mdata_owner(&owner.public_id(), address, new_owner, version)
+                .map_err(CoreError::from);
+
+            future::result(result).into_box()
+        }
+
+        #[cfg(not(feature = "mock-network"))]
+        {
+            let _ = owner;
+            future::err(CoreError::OperationForbidden).into_box()
+        }
     }
 
     #[cfg(any(
@@ -1248,6 +1389,35 @@ pub trait AuthActions: Client + Clone + 'static {
             .into_box()
     }
 
+    /// Stores a new login packet at its destination, paid for by the current account.
+    fn insert_login_packet(&self, new_login_packet: LoginPacket) -> Box<CoreFuture<()>> {
+        trace!(
+            "Insert a login packet at {:?}",
+            new_login_packet.destination()
+        );
+
+        send_mutation(self, Request::CreateLoginPacket(new_login_packet))
+    }
+
+    /// Fetches the resources consumed by each authorised key, keyed by the app's public key.
+    /// Keys that haven't performed any mutations are not listed.
+    ///
+    /// Vaults don't attribute mutations to app keys yet, so this is only available against the
+    /// mock vault.
+    #[cfg(feature = "mock-network")]
+    fn list_app_usage(&self) -> Box<CoreFuture<BTreeMap<PublicKey, AppUsage>>> {
+        trace!("ListAppUsage");
+
+        let inner = self.inner();
+        let result = inner
+            .borrow()
+            .connection_manager
+            .app_usage(&self.public_id())
+            .map_err(CoreError::from);
+
+        future::result(result).into_box()
+    }
+
     /// Adds a new authorised key.
     fn ins_auth_key(
         &self,
@@ -1289,16 +1459,18 @@ pub trait AuthActions: Client + Clone + 'static {
     }
 }
 
-fn sign_request(request: Request, client_id: &ClientFullId) -> Message {
+// Signs the request with the given keys, which may be held by an external signer.
+fn sign_request(request: Request, signer: &SafeKey) -> Box<CoreFuture<Message>> {
     let message_id = MessageId::new();
 
-    let signature = Some(client_id.sign(&unwrap!(bincode::serialize(&(&request, message_id)))));
-
-    Message::Request {
-        request,
-        message_id,
-        signature,
-    }
+    signer
+        .sign(&unwrap!(bincode::serialize(&(&request, message_id))))
+        .map(move |signature| Message::Request {
+            request,
+            message_id,
+            signature: Some(signature),
+        })
+        .into_box()
 }
 
 // TODO: Consider deprecating this struct once trait fields are stable. See
@@ -1350,7 +1522,8 @@ pub fn req(
     full_id_new: &SafeKey,
 ) -> Result<Response, CoreError> {
     let message_id = MessageId::new();
-    let signature = full_id_new.sign(&unwrap!(bincode::serialize(&(&request, message_id))));
+    let signature =
+        block_on_all(full_id_new.sign(&unwrap!(bincode::serialize(&(&request, message_id)))))?;
 
     block_on_all(cm.send(
         &full_id_new.public_id(),
@@ -1365,6 +1538,8 @@ pub fn req(
 #[cfg(test)]
 mod tests {
     use super::*;
+    #[cfg(feature = "mock-network")]
+    use crate::core_structs::TransactionDirection;
     use crate::utils::generate_random_vector;
     use crate::utils::test_utils::{
         calculate_new_balance, gen_bls_keypair, gen_client_id, random_client,
@@ -1967,6 +2142,67 @@ mod tests {
         });
     }
 
+    // 1. Create a client with a wallet and preload an anonymous wallet from it.
+    // 2. Transfer some coins back from the anonymous wallet to the client.
+    // 3. Fetch the transaction histories of both wallets and verify the recorded entries.
+    #[cfg(feature = "mock-network")]
+    #[test]
+    fn transaction_history() {
+        random_client(move |client| {
+            let c2 = client.clone();
+            let c3 = client.clone();
+            let c4 = client.clone();
+            let wallet: XorName = client.owner_key().into();
+
+            let client_id = gen_client_id();
+            let anon_wallet: XorName = (*client_id.public_id().public_key()).into();
+            let bls_pk = *client_id.public_id().public_key();
+
+            client
+                .test_set_balance(None, unwrap!(Coins::from_str("50.0")))
+                .and_then(move |_| {
+                    c2.create_balance(None, bls_pk, unwrap!(Coins::from_str("10.0")), None)
+                })
+                .and_then(move |preload| {
+                    c3.transfer_coins(
+                        Some(&client_id.clone()),
+                        wallet,
+                        unwrap!(Coins::from_str("4.0")),
+                        None,
+                    )
+                    .and_then(move |transfer| {
+                        c3.get_transaction_history(Some(&client_id))
+                            .map(move |history| (preload, transfer, history))
+                    })
+                })
+                .and_then(move |(preload, transfer, history)| {
+                    assert_eq!(history.len(), 2);
+                    assert_eq!(history[0].id, preload.id);
+                    assert_eq!(history[0].direction, TransactionDirection::Received);
+                    assert_eq!(history[0].counterparty, wallet);
+                    assert_eq!(history[0].amount, unwrap!(Coins::from_str("10.0")));
+                    assert_eq!(history[1].id, transfer.id);
+                    assert_eq!(history[1].direction, TransactionDirection::Sent);
+                    assert_eq!(history[1].counterparty, wallet);
+                    assert_eq!(history[1].amount, unwrap!(Coins::from_str("4.0")));
+                    assert!(history[0].timestamp <= history[1].timestamp);
+
+                    c4.get_transaction_history(None)
+                        .map(move |history| (preload, transfer, history))
+                })
+                .and_then(move |(preload, transfer, history)| {
+                    let recent: Vec<_> = history.iter().rev().take(2).collect();
+                    assert_eq!(recent[0].id, transfer.id);
+                    assert_eq!(recent[0].direction, TransactionDirection::Received);
+                    assert_eq!(recent[0].counterparty, anon_wallet);
+                    assert_eq!(recent[1].id, preload.id);
+                    assert_eq!(recent[1].direction, TransactionDirection::Sent);
+                    assert_eq!(recent[1].counterparty, anon_wallet);
+                    Ok::<_, CoreError>(())
+                })
+        });
+    }
+
     // 1. Create a client that PUTs some mdata on the network
     // 2. Create a different client that tries to delete the data. It should panic.
     #[test]
@@ -2807,6 +3043,51 @@ mod tests {
         assert_eq!(new_client_balance, unwrap!(Coins::from_str("20")));
     }
 
+    // 1. Estimate the cost of putting immutable and mutable data and then deleting the latter.
+    // 2. Perform the requests.
+    // 3. Verify that the balance has been debited by exactly the estimated amount.
+    #[test]
+    pub fn estimate_cost_test() {
+        random_client(move |client| {
+            let c2 = client.clone();
+            let c3 = client.clone();
+            let c4 = client.clone();
+            let c5 = client.clone();
+            let c6 = client.clone();
+
+            let idata = UnpubImmutableData::new(
+                unwrap!(generate_random_vector::<u8>(10)),
+                client.public_key(),
+            );
+            let mdata = UnseqMutableData::new(rand::random(), 10, client.public_key());
+            let address = *mdata.address();
+            let requests = vec![
+                Request::PutIData(idata.clone().into()),
+                Request::PutMData(mdata.clone().into()),
+                Request::DeleteMData(address),
+            ];
+
+            client
+                .estimate_cost(&requests)
+                .join(c2.get_balance(None))
+                .and_then(move |(cost, balance)| {
+                    // Deletions are free.
+                    let expected = unwrap!(Coins::from_nano(2 * COST_OF_PUT.as_nano()));
+                    assert_eq!(cost, expected);
+
+                    c3.put_idata(idata)
+                        .and_then(move |_| c4.put_unseq_mutable_data(mdata))
+                        .and_then(move |_| c5.delete_mdata(address))
+                        .map(move |_| (cost, balance))
+                })
+                .and_then(move |(cost, balance)| {
+                    c6.get_balance(None).map(move |new_balance| {
+                        assert_eq!(new_balance, unwrap!(balance.checked_sub(cost)))
+                    })
+                })
+        });
+    }
+
     // 1. Store different variants of unpublished data on the network.
     // 2. Get the balance of the client.
     // 3. Delete data from the network.
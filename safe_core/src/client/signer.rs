@@ -0,0 +1,82 @@
+// Copyright 2019 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+#[cfg(any(test, feature = "testing"))]
+use super::SafeKey;
+use crate::event_loop::CoreFuture;
+#[cfg(any(test, feature = "testing"))]
+use crate::{err, errors::CoreError};
+use safe_nd::{PublicId, PublicKey, Signature};
+#[cfg(any(test, feature = "testing"))]
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+/// Signs requests on behalf of a client or an app whose keys are kept outside of this process,
+/// e.g. by another process, a hardware security module or a group of threshold signers.
+pub trait Signer: Send + Sync {
+    /// Returns the public ID of the keys the signer holds.
+    fn public_id(&self) -> PublicId;
+
+    /// Returns the public key the signatures can be verified with.
+    fn public_key(&self) -> PublicKey;
+
+    /// Signs the given message, failing if the signer can't be reached or refuses to sign it.
+    /// The signature may take a while, e.g. if it has to be approved by the user, so it's
+    /// returned as a future.
+    fn sign(&self, msg: &[u8]) -> Box<CoreFuture<Signature>>;
+}
+
+/// Signer holding the keys in process memory, standing in for an external signer in tests.
+#[cfg(any(test, feature = "testing"))]
+pub struct LocalSigner {
+    key: SafeKey,
+    signatures: AtomicUsize,
+    offline: AtomicBool,
+}
+
+#[cfg(any(test, feature = "testing"))]
+impl LocalSigner {
+    /// Creates a signer holding the given keys.
+    pub fn new(key: SafeKey) -> Self {
+        Self {
+            key,
+            signatures: AtomicUsize::new(0),
+            offline: AtomicBool::new(false),
+        }
+    }
+
+    /// Returns the number of signatures made so far.
+    pub fn signatures(&self) -> usize {
+        self.signatures.load(Ordering::SeqCst)
+    }
+
+    /// Makes the signer refuse to sign, like an external signer that can't be reached.
+    pub fn set_offline(&self, offline: bool) {
+        self.offline.store(offline, Ordering::SeqCst);
+    }
+}
+
+#[cfg(any(test, feature = "testing"))]
+impl Signer for LocalSigner {
+    fn public_id(&self) -> PublicId {
+        self.key.public_id()
+    }
+
+    fn public_key(&self) -> PublicKey {
+        self.key.public_key()
+    }
+
+    fn sign(&self, msg: &[u8]) -> Box<CoreFuture<Signature>> {
+        if self.offline.load(Ordering::SeqCst) {
+            return err!(CoreError::Unexpected("Signer is offline".to_string()));
+        }
+
+        let _ = self.signatures.fetch_add(1, Ordering::SeqCst);
+
+        self.key.sign(msg)
+    }
+}
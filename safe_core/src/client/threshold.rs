@@ -0,0 +1,305 @@
+// Copyright 2019 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+//! Threshold ownership of data.
+//!
+//! Data can be owned by the public key of a BLS key set shared by `n` co-owners, each of whom
+//! holds a share of the secret key. Any `k` of them can sign a request on behalf of the key set by
+//! combining their partial signatures. The result is an ordinary BLS signature, so vaults handle
+//! threshold owned data like any other data.
+//!
+//! Signatures are collected as follows:
+//!
+//! 1. The initiator wraps the bytes to be signed, i.e. the serialised request and message ID, in a
+//!    `SigningRequest` and sends it to the co-owners.
+//! 2. Each co-owner checks the request and answers with a `PartialSignature`.
+//! 3. The initiator adds the answers to a `SignatureCollector`, which verifies them against the
+//!    key set and combines them once `k` valid shares are in.
+//!
+//! `ThresholdSigner` runs these steps over a list of `CoOwner`s, so that it can be used as an
+//! external signer of a `SafeKey`.
+
+use super::signer::Signer;
+use crate::errors::CoreError;
+use crate::event_loop::CoreFuture;
+use crate::ok;
+use crate::utils::FutureExt;
+use bincode::{deserialize, serialize};
+use futures::future::{self, Either, Loop};
+use futures::Future;
+use log::warn;
+use rand::thread_rng;
+use safe_nd::{
+    ClientFullId, ClientPublicId, Error as SndError, MessageId, PublicId, PublicKey, Request,
+    Signature,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use threshold_crypto::poly::Poly;
+use threshold_crypto::{PublicKeySet, SecretKey, SecretKeySet, SecretKeyShare, SignatureShare};
+
+/// Public keys of a set of co-owners.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct ThresholdOwners {
+    public_id: ClientPublicId,
+    public_keys: PublicKeySet,
+    count: usize,
+}
+
+impl ThresholdOwners {
+    /// Creates the key set of `count` co-owners, any `threshold` of whom can sign on its behalf.
+    /// Returns the secret key shares to hand out to the co-owners, by index.
+    pub fn generate(
+        threshold: usize,
+        count: usize,
+    ) -> Result<(Self, Vec<SecretKeyShare>), CoreError> {
+        if threshold == 0 || threshold > count {
+            return Err(CoreError::from("Invalid threshold"));
+        }
+
+        let poly = Poly::random(threshold - 1, &mut thread_rng());
+        // The client ID is made from the secret key itself, which is dropped right after.
+        let secret_key = SecretKey::from_mut(&mut poly.evaluate(0));
+        let public_id = ClientFullId::from(secret_key).public_id().clone();
+        let secret_keys = SecretKeySet::from(poly);
+        let shares = (0..count)
+            .map(|index| secret_keys.secret_key_share(index))
+            .collect();
+
+        let owners = Self {
+            public_id,
+            public_keys: secret_keys.public_keys(),
+            count,
+        };
+
+        Ok((owners, shares))
+    }
+
+    /// Creates the key set from public keys generated elsewhere, along with the client ID of its
+    /// public key. Fails if the client ID is of another key.
+    pub fn new(
+        public_id: ClientPublicId,
+        public_keys: PublicKeySet,
+        count: usize,
+    ) -> Result<Self, CoreError> {
+        if *public_id.public_key() != PublicKey::Bls(public_keys.public_key()) {
+            return Err(CoreError::from("Client ID of another key"));
+        }
+
+        Ok(Self {
+            public_id,
+            public_keys,
+            count,
+        })
+    }
+
+    /// Returns the public key owning the data.
+    pub fn public_key(&self) -> PublicKey {
+        PublicKey::Bls(self.public_keys.public_key())
+    }
+
+    /// Returns the client ID the co-owners send requests as.
+    pub fn public_id(&self) -> &ClientPublicId {
+        &self.public_id
+    }
+
+    /// Returns the number of partial signatures required to sign on behalf of the key set.
+    pub fn threshold(&self) -> usize {
+        self.public_keys.threshold() + 1
+    }
+
+    /// Returns the number of co-owners.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+}
+
+/// Bytes the co-owners are asked to sign.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct SigningRequest {
+    payload: Vec<u8>,
+}
+
+impl SigningRequest {
+    /// Creates a signing request for the given bytes.
+    pub fn new(payload: Vec<u8>) -> Self {
+        Self { payload }
+    }
+
+    /// Creates a signing request for a network request.
+    pub fn for_request(request: &Request, message_id: MessageId) -> Result<Self, CoreError> {
+        Ok(Self::new(serialize(&(request, message_id))?))
+    }
+
+    /// Returns the bytes to sign.
+    pub fn payload(&self) -> &[u8] {
+        &self.payload
+    }
+
+    /// Returns the network request to sign, so that co-owners can check it before signing. Returns
+    /// `None` if the payload isn't a request, e.g. when signing a handshake challenge.
+    pub fn request(&self) -> Option<(Request, MessageId)> {
+        deserialize(&self.payload).ok()
+    }
+
+    /// Signs the request with the secret key share of the co-owner with the given index.
+    pub fn sign(&self, index: usize, secret_key_share: &SecretKeyShare) -> PartialSignature {
+        PartialSignature {
+            index,
+            share: secret_key_share.sign(&self.payload),
+        }
+    }
+}
+
+/// Signature share of a single co-owner.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct PartialSignature {
+    /// Index of the co-owner.
+    pub index: usize,
+    /// Signature share.
+    pub share: SignatureShare,
+}
+
+/// Collects partial signatures of a signing request until there are enough to combine them.
+pub struct SignatureCollector {
+    owners: ThresholdOwners,
+    request: SigningRequest,
+    shares: BTreeMap<usize, SignatureShare>,
+}
+
+impl SignatureCollector {
+    /// Creates a collector for the given signing request.
+    pub fn new(owners: ThresholdOwners, request: SigningRequest) -> Self {
+        Self {
+            owners,
+            request,
+            shares: BTreeMap::new(),
+        }
+    }
+
+    /// Returns the signing request to send to the co-owners.
+    pub fn request(&self) -> &SigningRequest {
+        &self.request
+    }
+
+    /// Adds a partial signature, returning whether there are enough of them now. Fails with
+    /// `SndError::InvalidSignature` if the share wasn't made by the co-owner it claims to be from.
+    pub fn add(&mut self, partial: PartialSignature) -> Result<bool, CoreError> {
+        let PartialSignature { index, share } = partial;
+        let public_key_share = self.owners.public_keys.public_key_share(index);
+
+        if index >= self.owners.count || !public_key_share.verify(&share, &self.request.payload) {
+            return Err(CoreError::from(SndError::InvalidSignature));
+        }
+
+        let _ = self.shares.insert(index, share);
+
+        Ok(self.is_complete())
+    }
+
+    /// Returns whether there are enough partial signatures to combine them.
+    pub fn is_complete(&self) -> bool {
+        self.shares.len() >= self.owners.threshold()
+    }
+
+    /// Combines the partial signatures into a signature of the key set. Fails with
+    /// `CoreError::InsufficientSignatureShares` if there aren't enough of them.
+    pub fn signature(&self) -> Result<Signature, CoreError> {
+        if !self.is_complete() {
+            return Err(CoreError::InsufficientSignatureShares);
+        }
+
+        let signature = self
+            .owners
+            .public_keys
+            .combine_signatures(self.shares.iter().map(|(index, share)| (*index, share)))
+            .map_err(|error| CoreError::Unexpected(format!("{:?}", error)))?;
+
+        Ok(Signature::Bls(signature))
+    }
+}
+
+/// Co-owner asked for partial signatures, e.g. over the network or in another process.
+pub trait CoOwner: Send + Sync {
+    /// Returns the partial signature of the request, or fails if the co-owner can't be reached or
+    /// refuses to sign it.
+    fn sign(&self, request: &SigningRequest) -> Box<CoreFuture<PartialSignature>>;
+}
+
+/// Co-owner holding its secret key share in process memory.
+pub struct LocalCoOwner {
+    index: usize,
+    secret_key_share: SecretKeyShare,
+}
+
+impl LocalCoOwner {
+    /// Creates the co-owner with the given index.
+    pub fn new(index: usize, secret_key_share: SecretKeyShare) -> Self {
+        Self {
+            index,
+            secret_key_share,
+        }
+    }
+}
+
+impl CoOwner for LocalCoOwner {
+    fn sign(&self, request: &SigningRequest) -> Box<CoreFuture<PartialSignature>> {
+        ok!(request.sign(self.index, &self.secret_key_share))
+    }
+}
+
+/// Signs on behalf of a key set by collecting partial signatures from its co-owners.
+pub struct ThresholdSigner {
+    owners: ThresholdOwners,
+    co_owners: Vec<Arc<dyn CoOwner>>,
+}
+
+impl ThresholdSigner {
+    /// Creates a signer asking the given co-owners, in order, until it has enough partial
+    /// signatures.
+    pub fn new(owners: ThresholdOwners, co_owners: Vec<Arc<dyn CoOwner>>) -> Self {
+        Self { owners, co_owners }
+    }
+}
+
+impl Signer for ThresholdSigner {
+    fn public_id(&self) -> PublicId {
+        PublicId::Client(self.owners.public_id().clone())
+    }
+
+    fn public_key(&self) -> PublicKey {
+        self.owners.public_key()
+    }
+
+    fn sign(&self, msg: &[u8]) -> Box<CoreFuture<Signature>> {
+        let collector =
+            SignatureCollector::new(self.owners.clone(), SigningRequest::new(msg.to_vec()));
+        let co_owners = self.co_owners.clone();
+
+        future::loop_fn((collector, 0), move |(mut collector, index)| {
+            if let Some(co_owner) = co_owners.get(index) {
+                let f = co_owner.sign(collector.request()).then(move |result| {
+                    match result.and_then(|partial| collector.add(partial)) {
+                        Ok(true) => return Ok(Loop::Break(collector)),
+                        Ok(false) => (),
+                        Err(error) => {
+                            warn!("Co-owner didn't provide a valid signature share: {}", error)
+                        }
+                    }
+                    Ok(Loop::Continue((collector, index + 1)))
+                });
+                Either::A(f)
+            } else {
+                Either::B(future::ok(Loop::Break(collector)))
+            }
+        })
+        .and_then(|collector| collector.signature())
+        .into_box()
+    }
+}
@@ -6,7 +6,7 @@
 // KIND, either express or implied. Please review the Licences for the specific language governing
 // permissions and limitations relating to use of the SAFE Network Software.
 
-use crate::CoreError;
+use crate::{client::COST_OF_PUT, CoreError};
 use directories::ProjectDirs;
 use lazy_static::lazy_static;
 use log::{info, trace};
@@ -131,6 +131,41 @@ pub struct DevConfig {
     pub mock_in_memory_storage: bool,
     /// Set the mock-vault path if using file store (`mock_in_memory_storage` is `false`).
     pub mock_vault_path: Option<String>,
+    /// Pricing model used by the mock-vault to charge for mutations.
+    #[serde(default)]
+    pub mock_pricing: MockPricing,
+}
+
+/// Prices, in nano coins, charged by the mock-vault for each kind of mutation.
+///
+/// The cost of a mutation is its base price plus `per_byte` for every byte of data it stores.
+/// Deletions, reads and coin transfers are always free.
+#[derive(Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+pub struct MockPricing {
+    /// Base price of storing `ImmutableData`.
+    pub idata: u64,
+    /// Base price of storing or mutating `MutableData`.
+    pub mdata: u64,
+    /// Base price of storing or mutating `AppendOnlyData`.
+    pub adata: u64,
+    /// Base price of creating login packets and coin balances.
+    pub account: u64,
+    /// Price of every stored byte, added on top of the base price.
+    pub per_byte: u64,
+}
+
+impl Default for MockPricing {
+    // Flat-rate pricing, charging `COST_OF_PUT` for every mutation regardless of its size.
+    fn default() -> Self {
+        let flat = COST_OF_PUT.as_nano();
+        Self {
+            idata: flat,
+            mdata: flat,
+            adata: flat,
+            account: flat,
+            per_byte: 0,
+        }
+    }
 }
 
 /// Reads the `safe_core` config file and returns it or a default if this fails.
@@ -9,14 +9,15 @@
 mod connection_group;
 
 use crate::{
-    client::SafeKey, network_event::NetworkEvent, network_event::NetworkTx, CoreError, CoreFuture,
+    client::SafeKey, core_structs::TransactionRecord, network_event::NetworkEvent,
+    network_event::NetworkTx, CoreError, CoreFuture,
 };
 use crate::{fry, ok};
 use connection_group::ConnectionGroup;
 use futures::{future, Future};
 use log::{error, trace};
 use quic_p2p::Config as QuicP2pConfig;
-use safe_nd::{Message, PublicId, Response};
+use safe_nd::{Coins, Message, PublicId, Request, Response};
 use std::{
     cell::RefCell,
     collections::{hash_map::Entry, HashMap},
@@ -74,6 +75,22 @@ impl ConnectionManager {
     pub fn disconnect(&mut self, pub_id: &PublicId) -> Box<CoreFuture<()>> {
         self.inner.borrow_mut().disconnect(pub_id)
     }
+
+    /// Would return the amount of coins the network will charge for the given request, but vaults
+    /// don't answer price queries yet. Fails with `CoreError::CostEstimateUnsupported` rather than
+    /// making up a fee. Only the mock vault can estimate costs.
+    pub fn estimate_cost(&self, _request: &Request) -> Result<Coins, CoreError> {
+        Err(CoreError::CostEstimateUnsupported)
+    }
+
+    /// Would return the transaction history of the coin balance belonging to the given identity.
+    /// Fails with `CoreError::TransactionHistoryUnsupported`.
+    pub fn transaction_history(
+        &self,
+        _pub_id: &PublicId,
+    ) -> Result<Vec<TransactionRecord>, CoreError> {
+        Err(CoreError::TransactionHistoryUnsupported)
+    }
 }
 
 struct Inner {
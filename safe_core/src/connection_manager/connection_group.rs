@@ -9,7 +9,7 @@
 use crate::{client::SafeKey, err, utils, CoreError, CoreFuture};
 use bincode::{deserialize, serialize};
 use bytes::Bytes;
-use crossbeam_channel::{self, Receiver};
+use crossbeam_channel::{self, select, Receiver};
 use futures::{
     sync::oneshot::{self, Sender},
     Future,
@@ -23,7 +23,7 @@ use quic_p2p::{
 use rand::Rng;
 use safe_nd::{
     ConnectionInfo, HandshakeRequest, HandshakeResponse, Message, MessageId, NodePublicId,
-    PublicId, Request, Response,
+    PublicId, Request, Response, Signature,
 };
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::{
@@ -35,6 +35,7 @@ use std::{
     time::Duration,
 };
 use tokio::prelude::FutureExt;
+use tokio::runtime::current_thread::block_on_all;
 use unwrap::unwrap;
 
 /// Request timeout in seconds.
@@ -44,6 +45,10 @@ lazy_static! {
     static ref GROUP_COUNTER: AtomicU64 = AtomicU64::new(0);
 }
 
+// Signature of the challenge from the elder with the given address. Challenges are signed on a
+// thread of their own, as external signers can take a while to answer.
+type SignedChallenge = (SocketAddr, Result<Signature, CoreError>);
+
 // Represents a connection or connection attempt to one of the group's elder vaults.  `public_id`
 // will be `None` if we haven't received the `Challenge::Request` from this vault yet.
 #[derive(Clone)]
@@ -77,12 +82,14 @@ impl ConnectionGroup {
         connection_hook: Sender<Result<(), CoreError>>,
     ) -> Result<Self, CoreError> {
         let (event_tx, event_rx) = crossbeam_channel::unbounded();
+        let (signature_tx, signature_rx) = crossbeam_channel::unbounded();
 
         let mut quic_p2p = Builder::new(event_tx).with_config(config).build()?;
 
         let mut initial_state = Bootstrapping {
             connection_hook,
             full_id,
+            signature_tx,
         };
         initial_state.init(&mut quic_p2p);
 
@@ -93,7 +100,7 @@ impl ConnectionGroup {
             state: State::Bootstrapping(initial_state),
         }));
 
-        let _ = setup_quic_p2p_event_loop(&inner, event_rx);
+        let _ = setup_quic_p2p_event_loop(&inner, event_rx, signature_rx);
 
         Ok(Self { inner })
     }
@@ -111,6 +118,7 @@ impl ConnectionGroup {
 struct Bootstrapping {
     connection_hook: Sender<Result<(), CoreError>>,
     full_id: SafeKey,
+    signature_tx: crossbeam_channel::Sender<SignedChallenge>,
 }
 
 impl Bootstrapping {
@@ -168,6 +176,7 @@ impl Bootstrapping {
 
 struct JoiningElder {
     elder: Elder,
+    signing_challenge: bool,
     sent_challenge: bool,
 }
 
@@ -175,6 +184,7 @@ struct Joining {
     connected_elders: HashMap<SocketAddr, JoiningElder>,
     connection_hook: Sender<Result<(), CoreError>>,
     full_id: SafeKey,
+    signature_tx: crossbeam_channel::Sender<SignedChallenge>,
 }
 
 impl Joining {
@@ -190,6 +200,7 @@ impl Joining {
             connected_elders: Default::default(),
             connection_hook: old_state.connection_hook,
             full_id: old_state.full_id,
+            signature_tx: old_state.signature_tx,
         }
     }
 
@@ -202,33 +213,71 @@ impl Joining {
     /// Handle a challenge request from a newly-connected vault.
     fn handle_challenge(
         &mut self,
-        quic_p2p: &mut QuicP2p,
         sender_addr: SocketAddr,
         _sender_id: NodePublicId,
         challenge: Vec<u8>,
     ) {
         if let Some(connected) = self.connected_elders.get_mut(&sender_addr) {
             // safe to unwrap as we just found this elder before calling this method.
-            if connected.sent_challenge {
+            if connected.signing_challenge || connected.sent_challenge {
                 warn!("Already sent challenge to {:?}; ignoring.", sender_addr);
                 return;
             }
-            let token = rand::thread_rng().gen();
-            let response = HandshakeRequest::ChallengeResult(self.full_id.sign(&challenge));
-            let msg = Bytes::from(unwrap!(serialize(&response)));
-            quic_p2p.send(connected.elder.peer.clone(), msg, token);
-            connected.sent_challenge = true;
+            connected.signing_challenge = true;
+
+            // The signature is handed back to the event thread through `signature_tx`, so other
+            // events keep being handled while the signer takes its time.
+            let full_id = self.full_id.clone();
+            let signature_tx = self.signature_tx.clone();
+            let _ = thread::spawn(move || {
+                let signature = full_id
+                    .sign(&challenge)
+                    .timeout(Duration::from_secs(REQUEST_TIMEOUT_SECS))
+                    .map_err(|e| e.into_inner().unwrap_or(CoreError::RequestTimeout));
+                let _ = signature_tx.send((sender_addr, block_on_all(signature)));
+            });
         } else {
             // Doesn't have this connected peer?
         }
     }
 
+    /// Handle the signature of a challenge, sending it to the vault which asked for it.
+    fn handle_signed_challenge(
+        &mut self,
+        quic_p2p: &mut QuicP2p,
+        (sender_addr, result): SignedChallenge,
+    ) -> Transition {
+        if let Some(connected) = self.connected_elders.get_mut(&sender_addr) {
+            connected.signing_challenge = false;
+            match result {
+                Ok(signature) => {
+                    let token = rand::thread_rng().gen();
+                    let response = HandshakeRequest::ChallengeResult(signature);
+                    let msg = Bytes::from(unwrap!(serialize(&response)));
+                    quic_p2p.send(connected.elder.peer.clone(), msg, token);
+                    connected.sent_challenge = true;
+                }
+                Err(error) => {
+                    error!("Failed to sign challenge from {:?}: {}", sender_addr, error);
+                    return Transition::None;
+                }
+            }
+        }
+
+        if self.is_everyone_joined() {
+            Transition::ToConnected
+        } else {
+            Transition::None
+        }
+    }
+
     fn handle_connected_to(&mut self, quic_p2p: &mut QuicP2p, peer: Peer) {
         if let Peer::Node { ref node_info } = &peer {
             let _ = self.connected_elders.insert(
                 node_info.peer_addr,
                 JoiningElder {
                     elder: Elder::new(node_info.clone()),
+                    signing_challenge: false,
                     sent_challenge: false,
                 },
             );
@@ -247,18 +296,14 @@ impl Joining {
 
     fn handle_new_message(
         &mut self,
-        quic_p2p: &mut QuicP2p,
+        _quic_p2p: &mut QuicP2p,
         peer_addr: SocketAddr,
         msg: Bytes,
     ) -> Transition {
         match deserialize(&msg) {
             Ok(HandshakeResponse::Challenge(PublicId::Node(node_public_id), challenge)) => {
                 trace!("Got the challenge from {:?}", peer_addr);
-                self.handle_challenge(quic_p2p, peer_addr, node_public_id, challenge);
-
-                if self.is_everyone_joined() {
-                    return Transition::ToConnected;
-                }
+                self.handle_challenge(peer_addr, node_public_id, challenge);
             }
             Ok(HandshakeResponse::InvalidSection) => {
                 //
@@ -477,6 +522,18 @@ impl State {
             State::Terminated => Transition::None,
         }
     }
+
+    fn handle_signed_challenge(
+        &mut self,
+        quic_p2p: &mut QuicP2p,
+        signed: SignedChallenge,
+    ) -> Transition {
+        match self {
+            State::Joining(state) => state.handle_signed_challenge(quic_p2p, signed),
+            // The group has joined or given up in the meantime.
+            _state => Transition::None,
+        }
+    }
 }
 
 struct Inner {
@@ -536,14 +593,7 @@ impl Inner {
                 let transition = self
                     .state
                     .handle_new_message(&mut self.quic_p2p, peer_addr, msg);
-
-                match transition {
-                    Transition::None => (), // do nothing
-                    t => {
-                        let old_state = mem::replace(&mut self.state, State::Terminated);
-                        self.state = old_state.apply_transition(&mut self.quic_p2p, t);
-                    }
-                }
+                self.apply_transition(transition);
             }
             Finish => {
                 info!("Received unexpected event: {}", event);
@@ -552,6 +602,23 @@ impl Inner {
         }
     }
 
+    fn handle_signed_challenge(&mut self, signed: SignedChallenge) {
+        let transition = self
+            .state
+            .handle_signed_challenge(&mut self.quic_p2p, signed);
+        self.apply_transition(transition);
+    }
+
+    fn apply_transition(&mut self, transition: Transition) {
+        match transition {
+            Transition::None => (), // do nothing
+            t => {
+                let old_state = mem::replace(&mut self.state, State::Terminated);
+                self.state = old_state.apply_transition(&mut self.quic_p2p, t);
+            }
+        }
+    }
+
     fn handle_bootstrap_failure(&mut self) {
         /*
             TODO: handle this properly as part of the new bootstrap process
@@ -613,24 +680,37 @@ impl Inner {
 fn setup_quic_p2p_event_loop(
     inner: &Arc<Mutex<Inner>>,
     event_rx: Receiver<Event>,
+    mut signature_rx: Receiver<SignedChallenge>,
 ) -> JoinHandle<()> {
     let inner_weak = Arc::downgrade(inner);
 
-    thread::spawn(move || {
-        while let Ok(event) = event_rx.recv() {
-            match event {
-                Event::Finish => break, // Graceful shutdown
-                event => {
-                    if let Some(inner) = inner_weak.upgrade() {
-                        let mut inner = unwrap!(inner.lock());
-                        inner.handle_quic_p2p_event(event);
-                    } else {
-                        // Event loop got dropped
-                        trace!("Gracefully terminating quic-p2p event loop");
-                        break;
-                    }
+    thread::spawn(move || loop {
+        // Either an event from quic-p2p or the signature of a challenge.
+        let event = select! {
+            recv(event_rx) -> event => match event {
+                Ok(Event::Finish) | Err(_) => break, // Graceful shutdown
+                Ok(event) => Ok(event),
+            },
+            recv(signature_rx) -> signed => match signed {
+                Ok(signed) => Err(signed),
+                // No more challenges are being signed once the group has joined.
+                Err(_) => {
+                    signature_rx = crossbeam_channel::never();
+                    continue;
                 }
+            },
+        };
+
+        if let Some(inner) = inner_weak.upgrade() {
+            let mut inner = unwrap!(inner.lock());
+            match event {
+                Ok(event) => inner.handle_quic_p2p_event(event),
+                Err(signed) => inner.handle_signed_challenge(signed),
             }
+        } else {
+            // Event loop got dropped
+            trace!("Gracefully terminating quic-p2p event loop");
+            break;
         }
     })
 }
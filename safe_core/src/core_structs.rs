@@ -6,12 +6,12 @@
 // KIND, either express or implied. Please review the Licences for the specific language governing
 // permissions and limitations relating to use of the SAFE Network Software.
 
-
 #![allow(unsafe_code)]
 
 ///! Core structs for network operations
 use crate::client::{MDataInfo, SafeKey};
-use crate::crypto::{shared_box, shared_secretbox};
+use crate::crypto::{self, shared_box, shared_secretbox};
+use crate::ffi::coins as coins_ffi;
 use crate::ffi::ipc::resp as ffi;
 use crate::ipc::req::{
     container_perms_from_repr_c, container_perms_into_repr_c, permission_set_clone_from_repr_c,
@@ -21,10 +21,13 @@ use crate::ipc::IpcError;
 use crate::utils::{symmetric_encrypt, SymEncKey, SymEncNonce, SYM_ENC_NONCE_LEN};
 use crate::CoreError;
 use bincode::{deserialize, serialize};
+use chrono::{DateTime, Utc};
 use ffi_utils::{vec_clone_from_raw_parts, vec_into_raw_parts, ReprC, StringError};
-use rand::thread_rng;
+use rand::rngs::StdRng;
+use rand::{thread_rng, SeedableRng};
 use safe_nd::{
-    AppFullId, ClientPublicId, MDataAddress, MDataPermissionSet, MDataSeqValue, PublicKey, XorName,
+    AppFullId, ClientFullId, ClientPublicId, Coins, MDataAddress, MDataPermissionSet,
+    MDataSeqValue, PublicKey, XorName,
 };
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -43,6 +46,9 @@ pub static METADATA_KEY: &[u8] = b"_metadata";
 #[no_mangle]
 pub static METADATA_KEY_LEN: usize = 9;
 
+// Domain separator for deriving the key of an app's allowance wallet.
+const ALLOWANCE_WALLET_TAG: &[u8] = b"app-allowance-wallet";
+
 /// Represents the needed keys to work with the data.
 #[derive(Clone, Serialize, Deserialize, Debug, Eq, PartialEq)]
 pub struct AppKeys {
@@ -81,6 +87,20 @@ impl AppKeys {
         *self.app_full_id.public_id().public_key()
     }
 
+    /// Returns the identity owning the app's allowance wallet, the coin balance the authenticator
+    /// funds with the app's coin allowance. It's derived from the symmetric key, so that both the
+    /// app and the authenticator can sign for the wallet.
+    pub fn allowance_wallet_id(&self) -> ClientFullId {
+        let mut seed_material = [ALLOWANCE_WALLET_TAG, &self.enc_key[..]].concat();
+        let mut seed = sha3_256(&seed_material);
+        crypto::wipe(&mut seed_material);
+
+        let id = ClientFullId::new_bls(&mut StdRng::from_seed(seed));
+        crypto::wipe(&mut seed);
+
+        id
+    }
+
     /// Constructs FFI wrapper for the native Rust object, consuming self.
     pub fn into_repr_c(self) -> Result<ffi::AppKeys, IpcError> {
         let Self {
@@ -333,6 +353,72 @@ impl ReprC for AppAccess {
     }
 }
 
+/// Description of a pending change of the owner of some data.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OwnershipChange {
+    /// Key currently owning the data.
+    pub current_owner: PublicKey,
+    /// Key that will own the data.
+    pub new_owner: PublicKey,
+    /// User-facing description of the change.
+    pub description: String,
+}
+
+impl OwnershipChange {
+    /// Construct FFI wrapper for the native Rust object, consuming self.
+    pub fn into_repr_c(self) -> Result<ffi::OwnershipChange, IpcError> {
+        let Self {
+            current_owner,
+            new_owner,
+            description,
+        } = self;
+
+        let current_owner = match current_owner {
+            PublicKey::Bls(key) => key.to_bytes(),
+            // TODO: FFI repr for PublicKey
+            _ => return Err(IpcError::from("Unsupported key type")),
+        };
+        let new_owner = match new_owner {
+            PublicKey::Bls(key) => key.to_bytes(),
+            // TODO: FFI repr for PublicKey
+            _ => return Err(IpcError::from("Unsupported key type")),
+        };
+
+        Ok(ffi::OwnershipChange {
+            current_owner,
+            new_owner,
+            description: CString::new(description)
+                .map_err(StringError::from)?
+                .into_raw(),
+        })
+    }
+}
+
+impl ReprC for OwnershipChange {
+    type C = *const ffi::OwnershipChange;
+    type Error = IpcError;
+
+    unsafe fn clone_from_repr_c(repr_c: Self::C) -> Result<Self, Self::Error> {
+        let ffi::OwnershipChange {
+            current_owner,
+            new_owner,
+            description,
+        } = *repr_c;
+
+        Ok(Self {
+            current_owner: PublicKey::from(
+                threshold_crypto::PublicKey::from_bytes(current_owner)
+                    .map_err(|_| IpcError::EncodeDecodeError)?,
+            ),
+            new_owner: PublicKey::from(
+                threshold_crypto::PublicKey::from_bytes(new_owner)
+                    .map_err(|_| IpcError::EncodeDecodeError)?,
+            ),
+            description: String::clone_from_repr_c(description)?,
+        })
+    }
+}
+
 /// Metadata for `MutableData`.
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 pub struct UserMetadata {
@@ -507,6 +593,67 @@ impl ReprC for MDataEntry {
     }
 }
 
+/// Direction of a coin transaction, relative to the balance it is recorded in.
+#[derive(Clone, Copy, Serialize, Deserialize, Debug, Eq, PartialEq)]
+pub enum TransactionDirection {
+    /// Coins were sent from the balance.
+    Sent,
+    /// Coins were received into the balance.
+    Received,
+}
+
+/// Entry in the transaction history of a coin balance.
+#[derive(Clone, Serialize, Deserialize, Debug, Eq, PartialEq)]
+pub struct TransactionRecord {
+    /// Transaction ID.
+    pub id: u64,
+    /// Whether the coins were sent or received.
+    pub direction: TransactionDirection,
+    /// Name of the balance on the other side of the transaction.
+    pub counterparty: XorName,
+    /// Amount of coins transferred.
+    pub amount: Coins,
+    /// Key that signed the transfer request. For transfers made by an app this is the app's key.
+    pub signer: PublicKey,
+    /// Time at which the transaction was processed.
+    pub timestamp: DateTime<Utc>,
+}
+
+impl TransactionRecord {
+    /// Constructs FFI wrapper for the native Rust object, consuming self.
+    pub fn into_repr_c(self) -> coins_ffi::TransactionRecord {
+        coins_ffi::TransactionRecord {
+            id: self.id,
+            sent: self.direction == TransactionDirection::Sent,
+            counterparty: self.counterparty.0,
+            amount: self.amount.as_nano(),
+            timestamp_sec: self.timestamp.timestamp(),
+            timestamp_nsec: self.timestamp.timestamp_subsec_nanos(),
+        }
+    }
+}
+
+/// Resources consumed by an app on behalf of its owner.
+#[derive(Clone, Copy, Serialize, Deserialize, Debug, Eq, PartialEq)]
+pub struct AppUsage {
+    /// Number of successful mutations performed by the app.
+    pub mutations: u64,
+    /// Total size of the mutation requests made by the app, in bytes.
+    pub bytes: u64,
+    /// Amount of coins charged to the owner's balance for the app's mutations.
+    pub spent: Coins,
+}
+
+impl Default for AppUsage {
+    fn default() -> Self {
+        Self {
+            mutations: 0,
+            bytes: 0,
+            spent: unwrap!(Coins::from_nano(0)),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
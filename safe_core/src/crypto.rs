@@ -9,9 +9,142 @@
 //! Secret encryption and signing keys with more secure cloning semantics. These
 //! keys implement implicit sharing of the underlying sensitive data to avoid
 //! multiple copies of it stored in the memory, preventing certain class of attacks.
+//!
+//! Secret data is wiped from memory when it's dropped, and is left out of `Debug` output. With
+//! the `lock-memory` feature, the pages holding it are also locked so that they aren't swapped
+//! to disk. Locking is best effort: it fails silently when the process exceeds its limit of
+//! locked memory.
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt::{self, Debug};
+use std::ops::Deref;
+use zeroize::Zeroize;
+
+/// Overwrites the buffer with zeroes, in a way that isn't optimised away.
+pub fn wipe(buf: &mut [u8]) {
+    buf.zeroize();
+
+    #[cfg(test)]
+    tests::record_wipe(buf);
+}
+
+// Locks the pages holding the buffer in memory.
+#[cfg(feature = "lock-memory")]
+#[allow(unsafe_code)]
+fn lock(buf: &mut [u8]) {
+    if !buf.is_empty() {
+        let _ = unsafe { memsec::mlock(buf.as_mut_ptr(), buf.len()) };
+    }
+}
+
+#[cfg(not(feature = "lock-memory"))]
+fn lock(_buf: &mut [u8]) {}
+
+// Wipes the buffer and unlocks the pages holding it.
+#[allow(unsafe_code)]
+fn unlock(buf: &mut [u8]) {
+    wipe(buf);
+
+    #[cfg(feature = "lock-memory")]
+    {
+        if !buf.is_empty() {
+            let _ = unsafe { memsec::munlock(buf.as_mut_ptr(), buf.len()) };
+        }
+    }
+}
+
+/// Secret bytes, such as the password and PIN derived from the account credentials, wiped from
+/// memory when dropped. Serialised like a `Vec<u8>`.
+#[derive(Default)]
+pub struct SecretBytes(Vec<u8>);
+
+impl SecretBytes {
+    /// Takes ownership of the given secret bytes.
+    pub fn new(mut data: Vec<u8>) -> Self {
+        // Growing the vector would leave a copy of the secret in the old allocation, and so could
+        // shrinking it. Instead, the secret is copied into an exact-size allocation and the whole
+        // of the old one, spare capacity included, is wiped.
+        if data.len() < data.capacity() {
+            let exact = data.to_vec();
+            data.resize(data.capacity(), 0);
+            wipe(&mut data);
+            data = exact;
+        }
+        lock(&mut data);
+
+        Self(data)
+    }
+}
+
+impl From<Vec<u8>> for SecretBytes {
+    fn from(data: Vec<u8>) -> Self {
+        Self::new(data)
+    }
+}
+
+impl<'a> From<&'a [u8]> for SecretBytes {
+    fn from(data: &'a [u8]) -> Self {
+        Self::new(data.to_vec())
+    }
+}
+
+impl Deref for SecretBytes {
+    type Target = [u8];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Clone for SecretBytes {
+    fn clone(&self) -> Self {
+        Self::from(&self.0[..])
+    }
+}
+
+impl PartialEq for SecretBytes {
+    // Compares in constant time for a given length, so that comparing secrets doesn't leak how
+    // much of them matches.
+    fn eq(&self, other: &Self) -> bool {
+        self.0.len() == other.0.len()
+            && self
+                .0
+                .iter()
+                .zip(other.0.iter())
+                .fold(0, |diff, (a, b)| diff | (a ^ b))
+                == 0
+    }
+}
+
+impl Eq for SecretBytes {}
+
+impl Debug for SecretBytes {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        write!(f, "SecretBytes({} bytes)", self.0.len())
+    }
+}
+
+impl Serialize for SecretBytes {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for SecretBytes {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Vec::deserialize(deserializer).map(Self::new)
+    }
+}
+
+impl Drop for SecretBytes {
+    fn drop(&mut self) {
+        unlock(&mut self.0);
+    }
+}
 
 /// Symmetric encryption utilities.
 pub mod shared_secretbox {
+    use super::{lock, unlock, wipe};
     use crate::utils::{self, SymEncKey};
     use serde::{Deserialize, Serialize};
     use std::convert::TryInto;
@@ -20,8 +153,9 @@ pub mod shared_secretbox {
     use std::sync::Arc;
     use unwrap::unwrap;
 
-    /// Shared symmetric encryption key.
+    /// Shared symmetric encryption key. Wiped from memory once the last of its clones is dropped.
     #[derive(Clone, Eq, PartialEq, Serialize, Deserialize)]
+    #[serde(from = "SymEncKey")]
     pub struct Key(Arc<SymEncKey>);
 
     impl Key {
@@ -32,7 +166,12 @@ pub mod shared_secretbox {
             // state which means it's destructor wouldn't be called and the old
             // memory location wouldn't be zeroed - leaving the sensitive data
             // dangling in the memory.
-            Self(Arc::new(*inner))
+            let mut key = Arc::new(*inner);
+            if let Some(key) = Arc::get_mut(&mut key) {
+                lock(key);
+            }
+
+            Self(key)
         }
 
         /// Create new key from the given raw key data.
@@ -40,19 +179,31 @@ pub mod shared_secretbox {
             // FIXME: this function subverts the purpose of this module - it
             // copies the sensitive data. Possible fix might be to take the input by
             // mutable reference and zero it afterwards.
-            Self(Arc::new(*data))
+            Self::new(data)
         }
 
         /// Create new key from the data in the given slice.
         pub fn from_slice(data: &[u8]) -> Option<Self> {
-            let key: SymEncKey = unwrap!(data.try_into());
-            Some(Self(Arc::new(key)))
+            let mut key: SymEncKey = unwrap!(data.try_into());
+            let result = Self::new(&key);
+            wipe(&mut key);
+
+            Some(result)
+        }
+    }
+
+    impl From<SymEncKey> for Key {
+        fn from(mut key: SymEncKey) -> Self {
+            let result = Self::new(&key);
+            wipe(&mut key);
+
+            result
         }
     }
 
     /// Generate new random shared symmetric encryption key.
     pub fn gen_key() -> Key {
-        Key::new(&utils::generate_sym_enc_key())
+        Key::from(utils::generate_sym_enc_key())
     }
 
     impl Deref for Key {
@@ -65,7 +216,15 @@ pub mod shared_secretbox {
 
     impl Debug for Key {
         fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
-            self.0.fmt(f)
+            write!(f, "Key(..)")
+        }
+    }
+
+    impl Drop for Key {
+        fn drop(&mut self) {
+            if let Some(key) = Arc::get_mut(&mut self.0) {
+                unlock(key);
+            }
         }
     }
 }
@@ -78,7 +237,8 @@ pub mod shared_box {
     use std::ops::Deref;
     use threshold_crypto::{serde_impl::SerdeSecret, SecretKey as BlsSecretKey};
 
-    /// Shared secret encryption key.
+    /// Shared secret encryption key. `threshold_crypto` keeps the key in locked memory and wipes
+    /// it when dropped.
     #[derive(Clone, Eq, PartialEq, Serialize, Deserialize)]
     pub struct SecretKey(SerdeSecret<BlsSecretKey>);
 
@@ -115,7 +275,134 @@ pub mod shared_box {
 
     impl Debug for SecretKey {
         fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
-            self.0.fmt(f)
+            write!(f, "SecretKey(..)")
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::shared_secretbox::Key;
+    use super::*;
+    use crate::client::ClientKeys;
+    use crate::utils;
+    use rand::thread_rng;
+    use std::cell::RefCell;
+
+    thread_local! {
+        // Address and contents of the buffers wiped on this thread.
+        static WIPED: RefCell<Vec<(usize, Vec<u8>)>> = RefCell::new(Vec::new());
+    }
+
+    pub(super) fn record_wipe(buf: &[u8]) {
+        WIPED.with(|wiped| {
+            wiped
+                .borrow_mut()
+                .push((buf.as_ptr() as usize, buf.to_vec()))
+        });
+    }
+
+    // Returns whether a buffer of the given length was wiped at the given address. Only the
+    // address is kept, so that checking doesn't read memory that has been freed.
+    fn was_wiped(addr: usize, len: usize) -> bool {
+        WIPED.with(|wiped| {
+            wiped.borrow().iter().any(|(wiped_addr, contents)| {
+                *wiped_addr == addr
+                    && contents.len() == len
+                    && contents.iter().all(|byte| *byte == 0)
+            })
+        })
+    }
+
+    fn clear_wiped() {
+        WIPED.with(|wiped| wiped.borrow_mut().clear());
+    }
+
+    // Test that secret bytes are wiped when dropped, including their clones.
+    #[test]
+    fn secret_bytes_wiped_on_drop() {
+        clear_wiped();
+
+        let secret = SecretBytes::from(vec![0xAA; 64]);
+        let clone = secret.clone();
+        assert_eq!(secret, clone);
+
+        let secret_addr = secret.as_ptr() as usize;
+        let clone_addr = clone.as_ptr() as usize;
+        drop(secret);
+        drop(clone);
+
+        assert!(was_wiped(secret_addr, 64));
+        assert!(was_wiped(clone_addr, 64));
+    }
+
+    // Test that secret bytes taken from a vector with spare capacity are moved to an exact-size
+    // allocation, and that the whole of the original one is wiped.
+    #[test]
+    fn secret_bytes_from_vec_with_spare_capacity() {
+        clear_wiped();
+
+        let mut data = Vec::with_capacity(128);
+        data.extend_from_slice(&[0xAA; 64]);
+        let data_addr = data.as_ptr() as usize;
+
+        let secret = SecretBytes::new(data);
+        assert_eq!(&secret[..], &[0xAA; 64][..]);
+        assert!(was_wiped(data_addr, 128));
+
+        let secret_addr = secret.as_ptr() as usize;
+        drop(secret);
+        assert!(was_wiped(secret_addr, 64));
+    }
+
+    // Test that a shared key is only wiped once its last clone is dropped.
+    #[test]
+    fn shared_key_wiped_on_last_drop() {
+        clear_wiped();
+
+        let key = Key::from([0xAA; 32]);
+        let clone = key.clone();
+        let addr = key.as_ptr() as usize;
+
+        drop(key);
+        assert!(!was_wiped(addr, 32));
+
+        drop(clone);
+        assert!(was_wiped(addr, 32));
+    }
+
+    // Test that the derived account secrets are wiped when dropped.
+    #[test]
+    fn derived_secrets_wiped_on_drop() {
+        clear_wiped();
+
+        let (password, keyword, pin) = utils::derive_secrets(b"locator", b"password");
+        let addrs: Vec<_> = [&password, &keyword, &pin]
+            .iter()
+            .map(|secret| (secret.as_ptr() as usize, secret.len()))
+            .collect();
+
+        drop(password);
+        drop(keyword);
+        drop(pin);
+
+        for (addr, len) in addrs {
+            assert!(was_wiped(addr, len));
         }
     }
+
+    // Test that secrets are left out of `Debug` output.
+    #[test]
+    fn debug_hides_secrets() {
+        let secret = SecretBytes::from(vec![0xAA; 8]);
+        assert_eq!(format!("{:?}", secret), "SecretBytes(8 bytes)");
+
+        let key = Key::from([0xAA; 32]);
+        assert_eq!(format!("{:?}", key), "Key(..)");
+
+        let keys = ClientKeys::new(&mut thread_rng());
+        let enc_key = format!("{:?}", &keys.enc_key[..4]);
+        let enc_key = enc_key.trim_start_matches('[').trim_end_matches(']');
+        assert!(!format!("{:?}", keys).contains(enc_key));
+    }
 }
@@ -60,6 +60,12 @@ pub enum CoreError {
     IoError(io::Error),
     /// QuicP2p error.
     QuicP2p(quic_p2p::Error),
+    /// Not enough co-owners provided valid signature shares to sign on behalf of a threshold key.
+    InsufficientSignatureShares,
+    /// The network can't estimate the cost of requests.
+    CostEstimateUnsupported,
+    /// The network doesn't keep a queryable transaction history.
+    TransactionHistoryUnsupported,
 }
 
 impl<'a> From<&'a str> for CoreError {
@@ -167,6 +173,15 @@ impl Debug for CoreError {
             }
             Self::IoError(ref error) => write!(formatter, "CoreError::IoError -> {:?}", error),
             Self::QuicP2p(ref error) => write!(formatter, "CoreError::QuicP2p -> {:?}", error),
+            Self::InsufficientSignatureShares => {
+                write!(formatter, "CoreError::InsufficientSignatureShares")
+            }
+            Self::CostEstimateUnsupported => {
+                write!(formatter, "CoreError::CostEstimateUnsupported")
+            }
+            Self::TransactionHistoryUnsupported => {
+                write!(formatter, "CoreError::TransactionHistoryUnsupported")
+            }
         }
     }
 }
@@ -212,6 +227,16 @@ impl Display for CoreError {
             Self::ConfigError(ref error) => write!(formatter, "Config file error: {}", error),
             Self::IoError(ref error) => write!(formatter, "Io error: {}", error),
             Self::QuicP2p(ref error) => write!(formatter, "QuicP2P error: {}", error),
+            Self::InsufficientSignatureShares => {
+                write!(formatter, "Not enough valid signature shares")
+            }
+            Self::CostEstimateUnsupported => {
+                write!(formatter, "The network can't estimate the cost of requests")
+            }
+            Self::TransactionHistoryUnsupported => write!(
+                formatter,
+                "The network doesn't keep a queryable transaction history"
+            ),
         }
     }
 }
@@ -238,6 +263,9 @@ impl StdError for CoreError {
             Self::ConfigError(ref error) => error.description(),
             Self::IoError(ref error) => error.description(),
             Self::QuicP2p(ref error) => error.description(),
+            Self::InsufficientSignatureShares => "Insufficient signature shares",
+            Self::CostEstimateUnsupported => "Cost estimate unsupported",
+            Self::TransactionHistoryUnsupported => "Transaction history unsupported",
         }
     }
 
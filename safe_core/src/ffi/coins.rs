@@ -0,0 +1,28 @@
+// Copyright 2019 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+use crate::arrays::XorNameArray;
+
+/// FFI-wrapper for `TransactionRecord`.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct TransactionRecord {
+    /// Transaction ID.
+    pub id: u64,
+    // NOTE: `repr[C]` enums don't seem to be supported by JNI right now, so we use a bool.
+    /// `true` if the coins were sent from the balance, `false` if they were received.
+    pub sent: bool,
+    /// Name of the balance on the other side of the transaction.
+    pub counterparty: XorNameArray,
+    /// Amount of coins transferred, in nano coins.
+    pub amount: u64,
+    /// Time of the transaction (seconds part).
+    pub timestamp_sec: i64,
+    /// Time of the transaction (nanoseconds part).
+    pub timestamp_nsec: u32,
+}
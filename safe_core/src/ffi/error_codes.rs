@@ -26,6 +26,9 @@ pub const ERR_SELF_ENCRYPTION: i32 = -13;
 pub const ERR_REQUEST_TIMEOUT: i32 = -14;
 pub const ERR_CONFIG_FILE: i32 = -15;
 pub const ERR_IO: i32 = -16;
+pub const ERR_INSUFFICIENT_SIGNATURE_SHARES: i32 = -17;
+pub const ERR_COST_ESTIMATE_UNSUPPORTED: i32 = -18;
+pub const ERR_TRANSACTION_HISTORY_UNSUPPORTED: i32 = -19;
 
 // Data type errors
 pub const ERR_ACCESS_DENIED: i32 = -100;
@@ -53,6 +56,13 @@ pub const ERR_STRING_ERROR: i32 = -205;
 pub const ERR_SHARE_MDATA_DENIED: i32 = -206;
 pub const ERR_INVALID_OWNER: i32 = -207;
 pub const ERR_INCOMPATIBLE_MOCK_STATUS: i32 = -208;
+pub const ERR_SHARE_ADATA_DENIED: i32 = -209;
+pub const ERR_TRANS_OWNERSHIP_DENIED: i32 = -210;
+pub const ERR_PERMISSIONS_DENIED: i32 = -211;
+pub const ERR_REQ_ID_MISMATCH: i32 = -212;
+pub const ERR_IPC_INVALID_SIGNATURE: i32 = -213;
+pub const ERR_UNSUPPORTED_VERSION: i32 = -214;
+pub const ERR_TRANS_OWNERSHIP_FAILED: i32 = -215;
 
 // NFS errors.
 pub const ERR_FILE_EXISTS: i32 = -300;
@@ -6,7 +6,7 @@
 // KIND, either express or implied. Please review the Licences for the specific language governing
 // permissions and limitations relating to use of the SAFE Network Software.
 
-use crate::ffi::arrays::XorNameArray;
+use crate::ffi::arrays::{BlsPublicKey, XorNameArray};
 use crate::ipc::req::permission_set_into_repr_c;
 use ffi_utils::callback::CallbackArgs;
 use ffi_utils::{vec_from_raw_parts, ReprC};
@@ -119,6 +119,15 @@ pub struct AuthReq {
 
     /// Size of container permissions array.
     pub containers_len: usize,
+
+    /// `true` if the response should be sealed to `response_key`.
+    pub seal_response: bool,
+
+    /// Ephemeral public key of the app. Ignored if `seal_response` is `false`.
+    pub response_key: BlsPublicKey,
+
+    /// Number of seconds the authorisation stays valid for, or `0` if it never expires.
+    pub ttl: u64,
 }
 
 impl Drop for AuthReq {
@@ -154,6 +163,37 @@ impl Drop for ContainersReq {
     }
 }
 
+/// Request to modify the permissions of an authorised app.
+#[repr(C)]
+pub struct PermissionsReq {
+    /// Exchange info.
+    pub app: AppExchangeInfo,
+    /// New permissions for the listed containers. A permission set with no permissions
+    /// drops the access to the container.
+    pub containers: *const ContainerPermissions,
+    /// Size of containers array.
+    pub containers_len: usize,
+    /// `true` if the app permissions below should replace the current ones.
+    pub update_app_permissions: bool,
+    /// App has permission to transfer coins on behalf of the user.
+    pub app_permission_transfer_coins: bool,
+    /// App has permission to perform mutations on behalf of the user.
+    pub app_permission_perform_mutations: bool,
+    /// App has permission to read balance on behalf of the user.
+    pub app_permission_get_balance: bool,
+}
+
+impl Drop for PermissionsReq {
+    fn drop(&mut self) {
+        unsafe {
+            let _ = vec_from_raw_parts(
+                self.containers as *mut ContainerPermissions,
+                self.containers_len,
+            );
+        }
+    }
+}
+
 #[repr(C)]
 /// For use in `ShareMDataReq`. Represents a specific `MutableData` that is being shared.
 pub struct ShareMData {
@@ -183,3 +223,87 @@ impl Drop for ShareMDataRequest {
         }
     }
 }
+
+/// Represents a requested set of permissions for an append-only data.
+#[repr(C)]
+#[derive(Copy, Clone, Default, Debug, Eq, PartialEq)]
+pub struct ADataPermissionSet {
+    /// Whether to allow reading. Ignored for published data.
+    pub read: bool,
+    /// Whether to allow appending.
+    pub append: bool,
+    /// Whether to allow modifying permissions.
+    pub manage_permissions: bool,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+/// For use in `ShareADataReq`. Represents a specific `AppendOnlyData` that is being shared.
+pub struct ShareAData {
+    /// The append-only data name.
+    pub name: XorNameArray,
+    /// The append-only data type.
+    pub type_tag: u64,
+    /// Whether the data is published.
+    pub published: bool,
+    /// Whether the data is sequenced.
+    pub sequenced: bool,
+    /// The permissions being requested.
+    pub perms: ADataPermissionSet,
+}
+
+#[repr(C)]
+/// Represents a request to share append-only data
+pub struct ShareADataRequest {
+    /// Info about the app requesting shared access
+    pub app: AppExchangeInfo,
+    /// List of AData addresses and permissions that need to be shared
+    pub adata: *const ShareAData,
+    /// Length of the adata array
+    pub adata_len: usize,
+}
+
+impl Drop for ShareADataRequest {
+    fn drop(&mut self) {
+        unsafe {
+            let _ = vec_from_raw_parts(self.adata as *mut ShareAData, self.adata_len);
+        }
+    }
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+/// For use in `TransOwnershipReq`. Represents a specific data object whose owner should change.
+pub struct TransOwnership {
+    /// The data name.
+    pub name: XorNameArray,
+    /// The data type.
+    pub type_tag: u64,
+    /// Whether the data is an append-only data (otherwise it is a mutable data).
+    pub append_only: bool,
+    /// Whether the data is published. Ignored for mutable data.
+    pub published: bool,
+    /// Whether the data is sequenced.
+    pub sequenced: bool,
+    /// The key that should own the data.
+    pub new_owner: BlsPublicKey,
+}
+
+#[repr(C)]
+/// Represents a request to transfer the ownership of data
+pub struct TransOwnershipRequest {
+    /// Info about the app requesting the transfer
+    pub app: AppExchangeInfo,
+    /// List of data addresses and their new owners
+    pub data: *const TransOwnership,
+    /// Length of the data array
+    pub data_len: usize,
+}
+
+impl Drop for TransOwnershipRequest {
+    fn drop(&mut self) {
+        unsafe {
+            let _ = vec_from_raw_parts(self.data as *mut TransOwnership, self.data_len);
+        }
+    }
+}
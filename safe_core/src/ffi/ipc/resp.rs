@@ -233,3 +233,24 @@ pub struct MDataEntry {
     /// Mutable data value.
     pub value: MDataValue,
 }
+
+/// Description of an ownership change, shown to the user before granting a transfer.
+#[repr(C)]
+pub struct OwnershipChange {
+    /// Key currently owning the data.
+    pub current_owner: BlsPublicKey,
+    /// Key that will own the data.
+    pub new_owner: BlsPublicKey,
+    /// Human-readable description of the change.
+    pub description: *const c_char,
+}
+
+impl Drop for OwnershipChange {
+    fn drop(&mut self) {
+        unsafe {
+            if !self.description.is_null() {
+                let _ = CString::from_raw(self.description as *mut _);
+            }
+        }
+    }
+}
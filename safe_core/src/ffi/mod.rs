@@ -10,12 +10,16 @@
 
 /// Type definitions for arrays that are FFI input params.
 pub mod arrays;
+/// Coin transactions.
+pub mod coins;
 /// FFI Error Codes
 pub mod error_codes;
 /// IPC utilities.
 pub mod ipc;
 /// NFS API.
 pub mod nfs;
+/// Signers whose keys are held by the FFI caller.
+pub mod signer;
 
 use self::arrays::*;
 use safe_nd::MDataKind as NativeMDataKind;
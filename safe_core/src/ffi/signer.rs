@@ -0,0 +1,112 @@
+// Copyright 2019 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+use crate::client::Signer;
+use crate::errors::CoreError;
+use crate::event_loop::CoreFuture;
+use crate::ffi::arrays::Signature as FfiSignature;
+use crate::FutureExt;
+use ffi_utils::OpaqueCtx;
+use futures::sync::oneshot;
+use futures::Future;
+use safe_nd::{PublicId, PublicKey, Signature};
+use std::os::raw::c_void;
+
+/// Channel the signature requested from an `FfiSigner` is sent back on. Opaque to FFI callers,
+/// which pass it back along with the signature once it's made.
+pub type SignatureTx = oneshot::Sender<Option<Signature>>;
+
+/// Callback asking the FFI caller to sign `msg`. The signature, or the refusal to sign, must be
+/// sent back on `signature_tx` exactly once, either right away or later from any thread.
+pub type SignCb = extern "C" fn(
+    user_data: *mut c_void,
+    msg: *const u8,
+    msg_len: usize,
+    signature_tx: *mut SignatureTx,
+);
+
+/// Signer whose keys are held by the FFI caller, e.g. in a hardware wallet.
+pub struct FfiSigner {
+    public_id: PublicId,
+    user_data: OpaqueCtx,
+    o_sign: SignCb,
+}
+
+impl FfiSigner {
+    /// Creates a signer for the keys with the given public ID, asking `o_sign` for signatures.
+    /// `user_data` is passed to every call of `o_sign`, so it must stay valid as long as the
+    /// signer is in use.
+    pub fn new(public_id: PublicId, user_data: *mut c_void, o_sign: SignCb) -> Self {
+        Self {
+            public_id,
+            user_data: OpaqueCtx(user_data),
+            o_sign,
+        }
+    }
+}
+
+// The FFI caller is required to accept calls to `o_sign` from any thread.
+unsafe impl Sync for FfiSigner {}
+
+impl Signer for FfiSigner {
+    fn public_id(&self) -> PublicId {
+        self.public_id.clone()
+    }
+
+    fn public_key(&self) -> PublicKey {
+        self.public_id.public_key()
+    }
+
+    fn sign(&self, msg: &[u8]) -> Box<CoreFuture<Signature>> {
+        let (signature_tx, signature_rx) = oneshot::channel();
+        (self.o_sign)(
+            self.user_data.0,
+            msg.as_ptr(),
+            msg.len(),
+            Box::into_raw(Box::new(signature_tx)),
+        );
+
+        signature_rx
+            .then(|result| match result {
+                Ok(Some(signature)) => Ok(signature),
+                Ok(None) | Err(_) => Err(CoreError::Unexpected(
+                    "The external signer refused to sign".to_string(),
+                )),
+            })
+            .into_box()
+    }
+}
+
+/// Sends the signature made for an `FfiSigner` back on `signature_tx`, taking ownership of it.
+/// A null `signature` refuses the request, and so does one which isn't a valid BLS signature.
+///
+/// # Safety
+///
+/// `signature_tx` must have been passed to the `SignCb` of an `FfiSigner`, and not sent on
+/// before.
+pub unsafe fn send_signature(
+    signature_tx: *mut SignatureTx,
+    signature: *const FfiSignature,
+) -> Result<(), CoreError> {
+    let signature_tx = Box::from_raw(signature_tx);
+    let (signature, result) = if signature.is_null() {
+        (None, Ok(()))
+    } else {
+        match threshold_crypto::Signature::from_bytes(*signature) {
+            Ok(signature) => (Some(Signature::Bls(signature)), Ok(())),
+            Err(_) => (
+                None,
+                Err(CoreError::Unexpected("Invalid BLS signature".to_string())),
+            ),
+        }
+    };
+
+    // The request may have been given up on, in which case there's nobody to send it to.
+    let _ = signature_tx.send(signature);
+    result
+}
@@ -18,7 +18,10 @@ use bincode::{deserialize, serialize};
 use futures::Future;
 use log::trace;
 
-use safe_nd::{IData, IDataAddress, PubImmutableData, UnpubImmutableData};
+use safe_nd::{
+    Coins, IData, IDataAddress, PubImmutableData, Request, UnpubImmutableData, XorName,
+    XOR_NAME_LEN,
+};
 use self_encryption::{DataMap, SelfEncryptor, Storage};
 use serde::{Deserialize, Serialize};
 
@@ -57,6 +60,38 @@ pub fn gen_data_map(
     write_with_self_encryptor(se_storage, client, value, published, encryption_key)
 }
 
+/// Estimate the amount of coins that would be charged for storing the given raw bytes, i.e. for
+/// putting all the chunks written by the `create` function in this module along with the
+/// `ImmutableData` it returns. Nothing is uploaded to the network. See `Client::estimate_cost` for
+/// which networks can estimate costs.
+pub fn estimate_cost(
+    client: &impl Client,
+    value: &[u8],
+    published: bool,
+    encryption_key: Option<shared_secretbox::Key>,
+) -> Box<CoreFuture<Coins>> {
+    trace!("Estimating the cost of storing ImmutableData.");
+    let client = client.clone();
+    let se_storage = SelfEncryptionStorageDryRun::recording(client.clone(), published);
+    write_with_self_encryptor(
+        se_storage.clone(),
+        client.clone(),
+        value,
+        published,
+        encryption_key,
+    )
+    .and_then(move |data| {
+        let mut requests: Vec<_> = se_storage
+            .chunks()
+            .into_iter()
+            .map(Request::PutIData)
+            .collect();
+        requests.push(Request::PutIData(data));
+        client.estimate_cost(&requests)
+    })
+    .into_box()
+}
+
 /// Get the raw bytes from `ImmutableData` created via the `create` function in this module.
 pub fn extract_value(
     client: &impl Client,
@@ -65,8 +100,8 @@ pub fn extract_value(
 ) -> Box<CoreFuture<Vec<u8>>> {
     let published = data.is_pub();
     let se_storage = SelfEncryptionStorage::new(client.clone(), published);
-    unpack(se_storage.clone(), client.clone(), data)
-        .and_then(move |value| {
+    unpack(se_storage.clone(), client.clone(), data, Vec::new())
+        .and_then(move |(value, _)| {
             let data_map = if let Some(key) = decryption_key {
                 let plain_text = utils::symmetric_decrypt(&value, &key)?;
                 deserialize(&plain_text)?
@@ -83,6 +118,46 @@ pub fn extract_value(
         .into_box()
 }
 
+/// Get the names of the chunks written by the `create` function in this module when creating the
+/// given `ImmutableData`, i.e. the chunks holding its value. The name of the data itself is not
+/// included.
+pub fn chunk_names(
+    client: &impl Client,
+    data: &IData,
+    decryption_key: Option<shared_secretbox::Key>,
+) -> Box<CoreFuture<Vec<XorName>>> {
+    let se_storage = SelfEncryptionStorage::new(client.clone(), data.is_pub());
+    unpack(se_storage, client.clone(), data, Vec::new())
+        .and_then(move |(value, mut names)| {
+            let data_map = if let Some(key) = decryption_key {
+                let plain_text = utils::symmetric_decrypt(&value, &key)?;
+                deserialize(&plain_text)?
+            } else {
+                deserialize(&value)?
+            };
+            names.extend(data_map_chunk_names(&data_map));
+
+            Ok(names)
+        })
+        .into_box()
+}
+
+/// Get the names of the chunks the given `DataMap` refers to.
+pub fn data_map_chunk_names(data_map: &DataMap) -> Vec<XorName> {
+    match *data_map {
+        DataMap::Chunks(ref chunks) => chunks
+            .iter()
+            .filter(|chunk| chunk.hash.len() == XOR_NAME_LEN)
+            .map(|chunk| {
+                let mut name = [0; XOR_NAME_LEN];
+                name.copy_from_slice(&chunk.hash);
+                XorName(name)
+            })
+            .collect(),
+        DataMap::Content(_) | DataMap::None => Vec::new(),
+    }
+}
+
 /// Get immutable data from the network and extract its value, decrypting it in the process (if keys
 /// provided). This combines `get_idata` in `Client` and `extract_value` in this module into one
 /// function.
@@ -164,13 +239,21 @@ where
     }
 }
 
-fn unpack<S>(se_storage: S, client: impl Client, data: &IData) -> Box<CoreFuture<Vec<u8>>>
+// Returns the value along with the names of the chunks read to get it, appended to `chunk_names`.
+fn unpack<S>(
+    se_storage: S,
+    client: impl Client,
+    data: &IData,
+    mut chunk_names: Vec<XorName>,
+) -> Box<CoreFuture<(Vec<u8>, Vec<XorName>)>>
 where
     S: Storage<Error = SEStorageError> + Clone + 'static,
 {
     match fry!(deserialize(data.value())) {
-        DataTypeEncoding::Serialised(value) => ok!(value),
+        DataTypeEncoding::Serialised(value) => ok!((value, chunk_names)),
         DataTypeEncoding::DataMap(data_map) => {
+            chunk_names.extend(data_map_chunk_names(&data_map));
+
             let self_encryptor = fry!(SelfEncryptor::new(se_storage.clone(), data_map));
             let length = self_encryptor.len();
             self_encryptor
@@ -178,7 +261,7 @@ where
                 .map_err(From::from)
                 .and_then(move |serialised_data| {
                     let data = fry!(deserialize(&serialised_data));
-                    unpack(se_storage, client, &data)
+                    unpack(se_storage, client, &data, chunk_names)
                 })
                 .into_box()
         }
@@ -344,6 +427,32 @@ mod tests {
         }
     }
 
+    // 1. Estimate the cost of storing 2mb of data.
+    // 2. Create the data and put it to the network.
+    // 3. Verify that the balance was debited by exactly the estimated amount.
+    #[test]
+    fn estimate_cost_of_storing() {
+        let value = unwrap!(utils::generate_random_vector(2 * 1024 * 1024));
+
+        random_client(move |client| {
+            let client2 = client.clone();
+            let client3 = client.clone();
+            let client4 = client.clone();
+            let client5 = client.clone();
+
+            estimate_cost(client, &value, false, None)
+                .join(client2.get_balance(None))
+                .and_then(move |(cost, balance)| {
+                    create(&client3, &value, false, None)
+                        .and_then(move |data| client4.put_idata(data))
+                        .and_then(move |_| client5.get_balance(None))
+                        .map(move |new_balance| {
+                            assert_eq!(new_balance, unwrap!(balance.checked_sub(cost)));
+                        })
+                })
+        })
+    }
+
     fn gen_data_then_map_create_and_retrieve(
         size: usize,
         published: bool,
@@ -36,13 +36,29 @@ pub enum IpcError {
     UnknownApp,
     /// User denied request for shared access to MD.
     ShareMDataDenied,
-    /// Requested shared access to non-owned MD.
+    /// Requested shared access to non-owned MD or AData.
     InvalidOwner(Vec<(XorName, u64)>),
     /// Message sent between mock and non-mock versions.
     IncompatibleMockStatus,
 
     /// Unexpected error.
     Unexpected(String),
+
+    // Variants are encoded by index, so new ones go below to keep the encoding of the ones above.
+    /// User denied request for shared access to AData.
+    ShareADataDenied,
+    /// User denied request to transfer the ownership of data.
+    TransOwnershipDenied,
+    /// The ownership of the listed data couldn't be transferred, although the user granted it.
+    TransOwnershipFailed(Vec<(XorName, u64)>),
+    /// User denied request to modify app permissions.
+    PermissionsDenied,
+    /// Response doesn't belong to the pending request.
+    ReqIdMismatch,
+    /// Signature of a sealed response is invalid.
+    InvalidSignature,
+    /// Message uses a version of the wire format that isn't supported.
+    UnsupportedVersion(u16),
 }
 
 impl<T: 'static> From<SendError<T>> for IpcError {
@@ -69,6 +85,12 @@ impl From<SerialisationError> for IpcError {
     }
 }
 
+impl From<serde_json::Error> for IpcError {
+    fn from(_err: serde_json::Error) -> Self {
+        Self::EncodeDecodeError
+    }
+}
+
 impl From<StringError> for IpcError {
     fn from(err: StringError) -> Self {
         Self::StringError(err)
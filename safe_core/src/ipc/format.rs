@@ -0,0 +1,323 @@
+// Copyright 2019 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+use super::{encode_msg, IpcError, IpcMsg};
+use bincode::{deserialize, serialize};
+use data_encoding::{BASE32_NOPAD, BASE64URL_NOPAD};
+use serde::{Deserialize, Serialize};
+use std::cmp;
+
+/// Newest version of the IPC wire format.
+pub const IPC_VERSION: u16 = 1;
+/// Oldest versioned wire format that can be decoded.
+///
+/// Version `0` is the legacy unversioned format, which is always accepted.
+pub const IPC_MIN_VERSION: u16 = 1;
+
+/// The sender can open responses sealed to `AuthReq::response_key`.
+pub const IPC_CAP_SEALED_RESP: u32 = 0x1;
+/// The sender understands the JSON encoding.
+pub const IPC_CAP_JSON: u32 = 0x2;
+/// Capabilities advertised in every versioned message.
+pub const IPC_CAPABILITIES: u32 = IPC_CAP_SEALED_RESP | IPC_CAP_JSON;
+
+// Starts the binary versioned format. The legacy format starts with the `IpcMsg` variant index
+// instead, so the two can't be confused.
+const MAGIC: &[u8; 4] = b"SIPC";
+
+/// Encoding of an IPC message.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum IpcEncoding {
+    /// Bincode, encoded as base32. Used by the native libraries.
+    Bincode,
+    /// JSON, encoded as base64url. Meant for web apps.
+    Json,
+}
+
+/// Wire format of an IPC message.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct IpcFormat {
+    /// Protocol version. `0` is the legacy unversioned format.
+    pub version: u16,
+    /// Encoding of the message.
+    pub encoding: IpcEncoding,
+    /// Capabilities advertised by the sender. Always `0` in the legacy format.
+    pub capabilities: u32,
+}
+
+impl IpcFormat {
+    /// The legacy unversioned format, understood by all apps and authenticators.
+    pub fn legacy() -> Self {
+        Self {
+            version: 0,
+            encoding: IpcEncoding::Bincode,
+            capabilities: 0,
+        }
+    }
+
+    /// The newest format, using the given encoding.
+    pub fn current(encoding: IpcEncoding) -> Self {
+        Self {
+            version: IPC_VERSION,
+            encoding,
+            capabilities: IPC_CAPABILITIES,
+        }
+    }
+
+    /// Returns `true` if the sender advertised the given capability.
+    pub fn has_capability(&self, capability: u32) -> bool {
+        self.capabilities & capability == capability
+    }
+
+    /// The format to reply to a message received in this format.
+    ///
+    /// Legacy messages are answered in the legacy format. Otherwise the reply uses the same
+    /// encoding and the newest version both sides understand.
+    pub fn reply_format(&self) -> Self {
+        if self.version == 0 {
+            return Self::legacy();
+        }
+
+        Self {
+            version: cmp::min(self.version, IPC_VERSION),
+            ..Self::current(self.encoding)
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct JsonEnvelope<M> {
+    version: u16,
+    capabilities: u32,
+    mock: bool,
+    msg: M,
+}
+
+/// Encode `IpcMsg` into string, using the given format.
+///
+/// Versioned messages always advertise `IPC_CAPABILITIES`.
+pub fn encode_msg_as(msg: &IpcMsg, format: IpcFormat) -> Result<String, IpcError> {
+    if format.version == 0 {
+        return encode_msg(msg);
+    }
+    check_version(format.version)?;
+
+    let mock = cfg!(feature = "mock-network");
+
+    match format.encoding {
+        IpcEncoding::Bincode => {
+            let mut encoded = MAGIC.to_vec();
+            encoded.extend_from_slice(&format.version.to_le_bytes());
+            encoded.extend(serialize(&(IPC_CAPABILITIES, mock, msg))?);
+            Ok(format!("b{}", BASE32_NOPAD.encode(&encoded)))
+        }
+        IpcEncoding::Json => {
+            let envelope = JsonEnvelope {
+                version: format.version,
+                capabilities: IPC_CAPABILITIES,
+                mock,
+                msg,
+            };
+            let encoded = serde_json::to_vec(&envelope)?;
+            // Multibase prefix for base64url.
+            Ok(format!("u{}", BASE64URL_NOPAD.encode(&encoded)))
+        }
+    }
+}
+
+/// Decode `IpcMsg` in any supported format, returning the format it was encoded in.
+pub fn decode_msg_with_format(encoded: &str) -> Result<(IpcMsg, IpcFormat), IpcError> {
+    let mut chars = encoded.chars();
+    match chars.next().ok_or(IpcError::InvalidMsg)? {
+        // Encoded as base32
+        'b' | 'B' => {
+            let decoded = BASE32_NOPAD.decode(chars.as_str().as_bytes())?;
+            if decoded.starts_with(MAGIC) {
+                decode_bincode(&decoded[MAGIC.len()..])
+            } else {
+                let (msg, mock): (IpcMsg, bool) = deserialize(&decoded)?;
+                check_mock(mock)?;
+                Ok((msg, IpcFormat::legacy()))
+            }
+        }
+        // Encoded as base64url
+        'u' => decode_json(&BASE64URL_NOPAD.decode(chars.as_str().as_bytes())?),
+        // Fail if not encoded as base32 or base64url
+        _ => Err(IpcError::EncodeDecodeError),
+    }
+}
+
+fn decode_bincode(decoded: &[u8]) -> Result<(IpcMsg, IpcFormat), IpcError> {
+    if decoded.len() < 2 {
+        return Err(IpcError::InvalidMsg);
+    }
+    let version = u16::from_le_bytes([decoded[0], decoded[1]]);
+    check_version(version)?;
+
+    let (capabilities, mock, msg): (u32, bool, IpcMsg) = deserialize(&decoded[2..])?;
+    check_mock(mock)?;
+
+    Ok((
+        msg,
+        IpcFormat {
+            version,
+            encoding: IpcEncoding::Bincode,
+            capabilities,
+        },
+    ))
+}
+
+fn decode_json(decoded: &[u8]) -> Result<(IpcMsg, IpcFormat), IpcError> {
+    // Check the version before parsing the rest, which might have changed.
+    let value: serde_json::Value = serde_json::from_slice(decoded)?;
+    let version = value
+        .get("version")
+        .and_then(serde_json::Value::as_u64)
+        .ok_or(IpcError::InvalidMsg)?;
+    if version > u64::from(u16::max_value()) {
+        return Err(IpcError::UnsupportedVersion(u16::max_value()));
+    }
+    check_version(version as u16)?;
+
+    let envelope: JsonEnvelope<IpcMsg> = serde_json::from_value(value)?;
+    check_mock(envelope.mock)?;
+
+    Ok((
+        envelope.msg,
+        IpcFormat {
+            version: envelope.version,
+            encoding: IpcEncoding::Json,
+            capabilities: envelope.capabilities,
+        },
+    ))
+}
+
+fn check_version(version: u16) -> Result<(), IpcError> {
+    if version < IPC_MIN_VERSION || version > IPC_VERSION {
+        Err(IpcError::UnsupportedVersion(version))
+    } else {
+        Ok(())
+    }
+}
+
+fn check_mock(mock: bool) -> Result<(), IpcError> {
+    if mock ^ cfg!(feature = "mock-network") {
+        Err(IpcError::IncompatibleMockStatus)
+    } else {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::btree_set;
+    use crate::ipc::{decode_msg, AppExchangeInfo, AuthReq, IpcReq, Permission};
+    use std::collections::HashMap;
+    use unwrap::unwrap;
+
+    fn auth_req_msg() -> IpcMsg {
+        let mut containers = HashMap::new();
+        let _ = containers.insert(
+            "_documents".to_string(),
+            btree_set![Permission::Read, Permission::Insert],
+        );
+
+        IpcMsg::Req {
+            req_id: 7,
+            request: IpcReq::Auth(AuthReq {
+                app: AppExchangeInfo {
+                    id: "net.maidsafe.test".to_string(),
+                    scope: None,
+                    name: "Test".to_string(),
+                    vendor: "MaidSafe".to_string(),
+                },
+                app_container: true,
+                app_permissions: Default::default(),
+                containers,
+                response_key: None,
+                ttl: None,
+            }),
+        }
+    }
+
+    // Test that messages survive a round trip in every format and that the format is detected.
+    #[test]
+    fn encode_decode_formats() {
+        let formats = vec![
+            IpcFormat::legacy(),
+            IpcFormat::current(IpcEncoding::Bincode),
+            IpcFormat::current(IpcEncoding::Json),
+        ];
+
+        for format in formats {
+            let encoded = unwrap!(encode_msg_as(&auth_req_msg(), format));
+            let (msg, decoded_format) = unwrap!(decode_msg_with_format(&encoded));
+
+            assert_eq!(msg, auth_req_msg());
+            assert_eq!(decoded_format, format);
+            assert_eq!(unwrap!(decode_msg(&encoded)), auth_req_msg());
+        }
+
+        // The legacy encoding is unchanged.
+        assert_eq!(
+            unwrap!(encode_msg_as(&auth_req_msg(), IpcFormat::legacy())),
+            unwrap!(encode_msg(&auth_req_msg()))
+        );
+    }
+
+    // Test that messages from newer or older protocol versions are rejected with a clear error.
+    #[test]
+    fn unsupported_version() {
+        let mut encoded = MAGIC.to_vec();
+        encoded.extend_from_slice(&(IPC_VERSION + 1).to_le_bytes());
+        encoded.extend(unwrap!(serialize(&(0u32, false, "future format"))));
+        let encoded = format!("b{}", BASE32_NOPAD.encode(&encoded));
+
+        match decode_msg_with_format(&encoded) {
+            Err(IpcError::UnsupportedVersion(version)) => assert_eq!(version, IPC_VERSION + 1),
+            x => panic!("Unexpected {:?}", x),
+        }
+
+        let json = format!(
+            r#"{{"version":{},"capabilities":0,"mock":false,"msg":"future format"}}"#,
+            IPC_VERSION + 1
+        );
+        let encoded = format!("u{}", BASE64URL_NOPAD.encode(json.as_bytes()));
+
+        match decode_msg_with_format(&encoded) {
+            Err(IpcError::UnsupportedVersion(version)) => assert_eq!(version, IPC_VERSION + 1),
+            x => panic!("Unexpected {:?}", x),
+        }
+
+        let format = IpcFormat {
+            version: IPC_VERSION + 1,
+            ..IpcFormat::current(IpcEncoding::Bincode)
+        };
+        match encode_msg_as(&auth_req_msg(), format) {
+            Err(IpcError::UnsupportedVersion(_)) => (),
+            x => panic!("Unexpected {:?}", x),
+        }
+    }
+
+    // Test negotiating the format of a reply.
+    #[test]
+    fn reply_format() {
+        assert_eq!(IpcFormat::legacy().reply_format(), IpcFormat::legacy());
+
+        let newer = IpcFormat {
+            version: IPC_VERSION + 1,
+            encoding: IpcEncoding::Json,
+            capabilities: IPC_CAP_JSON,
+        };
+        let reply = newer.reply_format();
+        assert_eq!(reply, IpcFormat::current(IpcEncoding::Json));
+        assert!(reply.has_capability(IPC_CAP_SEALED_RESP));
+        assert!(!newer.has_capability(IPC_CAP_SEALED_RESP));
+    }
+}
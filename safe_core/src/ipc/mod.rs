@@ -12,19 +12,30 @@ pub mod req;
 pub mod resp;
 
 mod errors;
+mod format;
+mod sealed;
 
 pub use self::errors::IpcError;
+pub use self::format::{
+    decode_msg_with_format, encode_msg_as, IpcEncoding, IpcFormat, IPC_CAPABILITIES, IPC_CAP_JSON,
+    IPC_CAP_SEALED_RESP, IPC_MIN_VERSION, IPC_VERSION,
+};
 pub use self::req::{
-    AppExchangeInfo, AuthReq, ContainersReq, IpcReq, Permission, ShareMData, ShareMDataReq,
+    AppExchangeInfo, AuthReq, ContainersReq, IpcReq, Permission, PermissionsReq, ShareAData,
+    ShareADataReq, ShareMData, ShareMDataReq, TransOwnership, TransOwnershipReq,
 };
 pub use self::resp::{AuthGranted, IpcResp};
+pub use self::sealed::SealedResp;
 
-use bincode::{deserialize, serialize};
+#[cfg(any(test, feature = "testing"))]
+use bincode::deserialize;
+use bincode::serialize;
 use data_encoding::BASE32_NOPAD;
 #[cfg(any(test, feature = "testing"))]
 use ffi_utils;
 use quic_p2p::NodeInfo;
 use rand::{self, Rng};
+use safe_nd::PublicKey;
 use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
 use std::u32;
@@ -57,9 +68,15 @@ pub enum IpcMsg {
     },
     /// Generic error like couldn't parse IpcMsg etc.
     Err(IpcError),
+    /// Response sealed to the ephemeral key from `AuthReq::response_key`.
+    ///
+    /// Appended last so that the legacy variants keep their encoding.
+    Sealed(SealedResp),
 }
 
-/// Encode `IpcMsg` into string, using base32 encoding.
+/// Encode `IpcMsg` into string, using the legacy base32 encoding.
+///
+/// See `encode_msg_as` for the versioned formats.
 pub fn encode_msg(msg: &IpcMsg) -> Result<String, IpcError> {
     // We also add a multicodec compatible prefix. For more details please follow
     // https://github.com/multiformats/multicodec/blob/master/table.csv
@@ -75,22 +92,11 @@ pub fn encode_msg_64(msg: &IpcMsg) -> Result<String, IpcError> {
     Ok(ffi_utils::base64_encode(&serialize(msg)?))
 }
 
-/// Decode `IpcMsg` encoded with base32 encoding.
+/// Decode `IpcMsg` in any supported format.
+///
+/// Both the legacy format and the versioned formats produced by `encode_msg_as` are accepted.
 pub fn decode_msg(encoded: &str) -> Result<IpcMsg, IpcError> {
-    let mut chars = encoded.chars();
-    let decoded = match chars.next().ok_or(IpcError::InvalidMsg)? {
-        // Encoded as base32
-        'b' | 'B' => BASE32_NOPAD.decode(chars.as_str().as_bytes())?,
-        // Fail if not encoded as base32
-        _ => return Err(IpcError::EncodeDecodeError),
-    };
-
-    let (msg, mock): (IpcMsg, bool) = deserialize(&decoded)?;
-    if mock ^ cfg!(feature = "mock-network") {
-        return Err(IpcError::IncompatibleMockStatus);
-    }
-
-    Ok(msg)
+    decode_msg_with_format(encoded).map(|(msg, _)| msg)
 }
 
 /// Decode `IpcMsg` encoded with base64 encoding.
@@ -103,6 +109,30 @@ pub fn decode_msg_64(encoded: &str) -> Result<IpcMsg, IpcError> {
     )?)
 }
 
+/// Decode `IpcMsg` in any supported format, opening it if it was sealed.
+///
+/// Meant for apps which set `AuthReq::response_key`. Sealed responses are verified against
+/// `signer` and decrypted with `secret_key`, and rejected unless they answer the request with
+/// `expected_req_id`. Without `signer`, the signer of the response is trusted on first use, as
+/// described in `SealedResp::open`. The verified signer is returned along with the response, for
+/// the app to pin. Unsealed responses are rejected, as anyone could have sent them. Other
+/// messages, like revocation notices, are returned as they are, without a signer.
+pub fn decode_sealed_msg(
+    encoded: &str,
+    secret_key: &threshold_crypto::SecretKey,
+    expected_req_id: u32,
+    signer: Option<&PublicKey>,
+) -> Result<(IpcMsg, Option<PublicKey>), IpcError> {
+    match decode_msg(encoded)? {
+        IpcMsg::Sealed(sealed) => {
+            let msg = sealed.open(secret_key, expected_req_id, signer)?;
+            Ok((msg, Some(sealed.signer)))
+        }
+        IpcMsg::Resp { .. } => Err(IpcError::InvalidMsg),
+        msg => Ok((msg, None)),
+    }
+}
+
 /// Generate unique request ID.
 pub fn gen_req_id() -> u32 {
     // Generate the number in range 1..MAX inclusive.
@@ -13,8 +13,12 @@ use ffi_utils::{vec_into_raw_parts, ReprC, StringError};
 use safe_nd::AppPermissions;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use threshold_crypto::PK_SIZE as BLS_PUBLIC_KEY_LEN;
 
 /// Represents an authorisation request.
+///
+/// Requests setting `response_key` or `ttl` use a newer encoding, which authenticators released
+/// before those options existed reject.
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 pub struct AuthReq {
     /// The application identifier for this request
@@ -25,6 +29,66 @@ pub struct AuthReq {
     pub app_permissions: AppPermissions,
     /// The list of containers the app wishes to access (and desired permissions).
     pub containers: HashMap<String, ContainerPermissions>,
+    /// Ephemeral public key of the app. If present, the authenticator seals its response
+    /// to this key and signs it, so that only the requesting app can open it.
+    pub response_key: Option<threshold_crypto::PublicKey>,
+    /// Number of seconds the authorisation stays valid for. `None` if it never expires.
+    ///
+    /// Once expired, the authenticator revokes the app.
+    pub ttl: Option<u64>,
+}
+
+// Encoding of the `AuthReq`s without any of the options added since the first release.
+#[derive(Serialize, Deserialize)]
+pub(super) struct LegacyAuthReq {
+    app: AppExchangeInfo,
+    app_container: bool,
+    app_permissions: AppPermissions,
+    containers: HashMap<String, ContainerPermissions>,
+}
+
+impl LegacyAuthReq {
+    // Returns the request back if it can't be encoded in the legacy layout.
+    pub(super) fn from_auth_req(req: AuthReq) -> Result<Self, AuthReq> {
+        if req.response_key.is_some() || req.ttl.is_some() {
+            return Err(req);
+        }
+
+        let AuthReq {
+            app,
+            app_container,
+            app_permissions,
+            containers,
+            ..
+        } = req;
+
+        Ok(Self {
+            app,
+            app_container,
+            app_permissions,
+            containers,
+        })
+    }
+}
+
+impl From<LegacyAuthReq> for AuthReq {
+    fn from(req: LegacyAuthReq) -> Self {
+        let LegacyAuthReq {
+            app,
+            app_container,
+            app_permissions,
+            containers,
+        } = req;
+
+        Self {
+            app,
+            app_container,
+            app_permissions,
+            containers,
+            response_key: None,
+            ttl: None,
+        }
+    }
 }
 
 impl AuthReq {
@@ -35,6 +99,8 @@ impl AuthReq {
             app_container,
             app_permissions,
             containers,
+            response_key,
+            ttl,
         } = self;
 
         let containers = containers_into_vec(containers).map_err(StringError::from)?;
@@ -48,6 +114,11 @@ impl AuthReq {
             app_permission_get_balance: app_permissions.get_balance,
             containers: containers_ptr,
             containers_len,
+            seal_response: response_key.is_some(),
+            response_key: response_key
+                .map(|key| key.to_bytes())
+                .unwrap_or([0; BLS_PUBLIC_KEY_LEN]),
+            ttl: ttl.unwrap_or(0),
         })
     }
 }
@@ -69,6 +140,17 @@ impl ReprC for AuthReq {
                 get_balance: (*repr_c).app_permission_get_balance,
             },
             containers: containers_from_repr_c((*repr_c).containers, (*repr_c).containers_len)?,
+            response_key: if (*repr_c).seal_response {
+                Some(threshold_crypto::PublicKey::from_bytes(
+                    (*repr_c).response_key,
+                )?)
+            } else {
+                None
+            },
+            ttl: match (*repr_c).ttl {
+                0 => None,
+                ttl => Some(ttl),
+            },
         })
     }
 }
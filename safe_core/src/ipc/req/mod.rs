@@ -10,11 +10,19 @@
 
 mod auth;
 mod containers;
+mod permissions;
+mod share_adata;
 mod share_mdata;
+mod trans_ownership;
 
 pub use self::auth::AuthReq;
 pub use self::containers::ContainersReq;
+pub use self::permissions::PermissionsReq;
+pub use self::share_adata::{ShareAData, ShareADataPermissions, ShareADataReq};
 pub use self::share_mdata::{ShareMData, ShareMDataReq};
+pub use self::trans_ownership::{OwnedData, TransOwnership, TransOwnershipReq};
+
+use self::auth::LegacyAuthReq;
 
 use crate::ffi::ipc::req::{
     AppExchangeInfo as FfiAppExchangeInfo, ContainerPermissions as FfiContainerPermissions,
@@ -48,8 +56,8 @@ pub enum Permission {
 pub type ContainerPermissions = BTreeSet<Permission>;
 
 /// IPC request.
-// TODO: `TransOwnership` variant
-#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+#[serde(from = "WireIpcReq", into = "WireIpcReq")]
 pub enum IpcReq {
     /// Authentication request.
     Auth(AuthReq),
@@ -60,6 +68,60 @@ pub enum IpcReq {
     Unregistered(Vec<u8>),
     /// Share mutable data.
     ShareMData(ShareMDataReq),
+    /// Share append-only data.
+    ShareAData(ShareADataReq),
+    /// Transfer the ownership of data.
+    TransOwnership(TransOwnershipReq),
+    /// Modify the permissions of an authorised app.
+    Permissions(PermissionsReq),
+}
+
+// Encoding of `IpcReq`. Authorisation requests using any of the options added to `AuthReq` since
+// the first release are sent as `AuthV2`, so that the encoding of plain authorisation requests is
+// unchanged, and older authenticators reject the new ones instead of misreading them.
+// New variants must be appended.
+#[derive(Serialize, Deserialize)]
+enum WireIpcReq {
+    Auth(LegacyAuthReq),
+    Containers(ContainersReq),
+    Unregistered(Vec<u8>),
+    ShareMData(ShareMDataReq),
+    ShareAData(ShareADataReq),
+    TransOwnership(TransOwnershipReq),
+    Permissions(PermissionsReq),
+    AuthV2(AuthReq),
+}
+
+impl From<WireIpcReq> for IpcReq {
+    fn from(req: WireIpcReq) -> Self {
+        match req {
+            WireIpcReq::Auth(req) => IpcReq::Auth(req.into()),
+            WireIpcReq::Containers(req) => IpcReq::Containers(req),
+            WireIpcReq::Unregistered(extra_data) => IpcReq::Unregistered(extra_data),
+            WireIpcReq::ShareMData(req) => IpcReq::ShareMData(req),
+            WireIpcReq::ShareAData(req) => IpcReq::ShareAData(req),
+            WireIpcReq::TransOwnership(req) => IpcReq::TransOwnership(req),
+            WireIpcReq::Permissions(req) => IpcReq::Permissions(req),
+            WireIpcReq::AuthV2(req) => IpcReq::Auth(req),
+        }
+    }
+}
+
+impl From<IpcReq> for WireIpcReq {
+    fn from(req: IpcReq) -> Self {
+        match req {
+            IpcReq::Auth(req) => match LegacyAuthReq::from_auth_req(req) {
+                Ok(req) => WireIpcReq::Auth(req),
+                Err(req) => WireIpcReq::AuthV2(req),
+            },
+            IpcReq::Containers(req) => WireIpcReq::Containers(req),
+            IpcReq::Unregistered(extra_data) => WireIpcReq::Unregistered(extra_data),
+            IpcReq::ShareMData(req) => WireIpcReq::ShareMData(req),
+            IpcReq::ShareAData(req) => WireIpcReq::ShareAData(req),
+            IpcReq::TransOwnership(req) => WireIpcReq::TransOwnership(req),
+            IpcReq::Permissions(req) => WireIpcReq::Permissions(req),
+        }
+    }
 }
 
 /// Consumes the object and returns the wrapped raw pointer.
@@ -302,12 +364,68 @@ mod tests {
     use super::*;
     use crate::btree_set;
     use crate::ffi::ipc::req::PermissionSet as FfiPermissionSet;
+    use bincode::{deserialize, serialize};
     use ffi_utils::ReprC;
-    use safe_nd::MDataAction;
+    use safe_nd::{AppPermissions, MDataAction};
     use std::collections::HashMap;
     use std::ffi::CStr;
     use unwrap::unwrap;
 
+    // Test that authorisation requests without the newer options keep their original encoding,
+    // and that the ones using them survive a round trip.
+    #[test]
+    fn auth_req_encoding() {
+        // Layout of `AuthReq` in the first release.
+        #[derive(Serialize)]
+        enum OriginalIpcReq {
+            Auth {
+                app: AppExchangeInfo,
+                app_container: bool,
+                app_permissions: AppPermissions,
+                containers: HashMap<String, ContainerPermissions>,
+            },
+        }
+
+        let app = AppExchangeInfo {
+            id: "net.maidsafe.test".to_string(),
+            scope: None,
+            name: "Test".to_string(),
+            vendor: "MaidSafe".to_string(),
+        };
+        let mut containers = HashMap::new();
+        let _ = containers.insert("_documents".to_string(), btree_set![Permission::Read]);
+
+        let original = OriginalIpcReq::Auth {
+            app: app.clone(),
+            app_container: true,
+            app_permissions: Default::default(),
+            containers: containers.clone(),
+        };
+        let req = IpcReq::Auth(AuthReq {
+            app,
+            app_container: true,
+            app_permissions: Default::default(),
+            containers,
+            response_key: None,
+            ttl: None,
+        });
+
+        let encoded = unwrap!(serialize(&req));
+        assert_eq!(encoded, unwrap!(serialize(&original)));
+        assert_eq!(unwrap!(deserialize::<IpcReq>(&encoded)), req);
+
+        let req = match req {
+            IpcReq::Auth(auth_req) => IpcReq::Auth(AuthReq {
+                response_key: Some(threshold_crypto::SecretKey::random().public_key()),
+                ttl: Some(3600),
+                ..auth_req
+            }),
+            _ => unreachable!(),
+        };
+        let encoded = unwrap!(serialize(&req));
+        assert_eq!(unwrap!(deserialize::<IpcReq>(&encoded)), req);
+    }
+
     // Test converting `ContainerPermissions` to its FFI representation and back again.
     #[test]
     fn container_permissions() {
@@ -423,12 +541,15 @@ mod tests {
             app_container: false,
             app_permissions: Default::default(),
             containers: HashMap::new(),
+            response_key: None,
+            ttl: Some(3600),
         };
 
         let ffi = unwrap!(a.into_repr_c());
 
         assert_eq!(ffi.app_container, false);
         assert_eq!(ffi.containers_len, 0);
+        assert_eq!(ffi.ttl, 3600);
 
         let a = unsafe { unwrap!(AuthReq::clone_from_repr_c(&ffi)) };
 
@@ -438,6 +559,7 @@ mod tests {
         assert_eq!(a.app.vendor, "4");
         assert_eq!(a.app_container, false);
         assert_eq!(a.containers.len(), 0);
+        assert_eq!(a.ttl, Some(3600));
     }
 
     // Test converting a `ContainersReq` object to its FFI representation and back again.
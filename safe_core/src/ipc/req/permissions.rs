@@ -0,0 +1,101 @@
+// Copyright 2019 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+use super::{
+    container_perms_from_repr_c, containers_into_vec, AppExchangeInfo, ContainerPermissions,
+};
+use crate::ffi::ipc::req as ffi;
+use crate::ipc::errors::IpcError;
+use ffi_utils::{vec_into_raw_parts, ReprC, StringError};
+use safe_nd::AppPermissions;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::slice;
+
+/// Request to modify the permissions of an already authorised app.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct PermissionsReq {
+    /// Exchange info
+    pub app: AppExchangeInfo,
+    /// New permissions for the listed containers. They replace the permissions the app has for
+    /// these containers; an empty set drops the access to the container. Containers not listed
+    /// are left unchanged.
+    pub containers: HashMap<String, ContainerPermissions>,
+    /// New app permissions, e.g. allowing to work with the user's coin balance. `None` leaves
+    /// them unchanged.
+    pub app_permissions: Option<AppPermissions>,
+}
+
+impl PermissionsReq {
+    /// Construct FFI wrapper for the native Rust object, consuming self.
+    pub fn into_repr_c(self) -> Result<ffi::PermissionsReq, IpcError> {
+        let Self {
+            app,
+            containers,
+            app_permissions,
+        } = self;
+
+        let containers = containers_into_vec(containers).map_err(StringError::from)?;
+        let (containers_ptr, containers_len) = vec_into_raw_parts(containers);
+        let (update_app_permissions, app_permissions) = match app_permissions {
+            Some(app_permissions) => (true, app_permissions),
+            None => (false, AppPermissions::default()),
+        };
+
+        Ok(ffi::PermissionsReq {
+            app: app.into_repr_c()?,
+            containers: containers_ptr,
+            containers_len,
+            update_app_permissions,
+            app_permission_transfer_coins: app_permissions.transfer_coins,
+            app_permission_perform_mutations: app_permissions.perform_mutations,
+            app_permission_get_balance: app_permissions.get_balance,
+        })
+    }
+}
+
+impl ReprC for PermissionsReq {
+    type C = *const ffi::PermissionsReq;
+    type Error = IpcError;
+
+    /// Constructs the object from the FFI counterpart.
+    ///
+    /// After calling this function, the subobjects memory is owned by the resulting object.
+    unsafe fn clone_from_repr_c(repr_c: Self::C) -> Result<Self, Self::Error> {
+        Ok(Self {
+            app: AppExchangeInfo::clone_from_repr_c(&(*repr_c).app)?,
+            containers: slice::from_raw_parts((*repr_c).containers, (*repr_c).containers_len)
+                .iter()
+                .map(|raw| {
+                    let access = &raw.access;
+                    let perms = if access.read
+                        || access.insert
+                        || access.update
+                        || access.delete
+                        || access.manage_permissions
+                    {
+                        container_perms_from_repr_c(*access)?
+                    } else {
+                        // No permissions at all drop the access to the container.
+                        ContainerPermissions::new()
+                    };
+                    Ok((String::clone_from_repr_c(raw.cont_name)?, perms))
+                })
+                .collect::<Result<_, IpcError>>()?,
+            app_permissions: if (*repr_c).update_app_permissions {
+                Some(AppPermissions {
+                    transfer_coins: (*repr_c).app_permission_transfer_coins,
+                    perform_mutations: (*repr_c).app_permission_perform_mutations,
+                    get_balance: (*repr_c).app_permission_get_balance,
+                })
+            } else {
+                None
+            },
+        })
+    }
+}
@@ -0,0 +1,153 @@
+// Copyright 2019 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+use super::AppExchangeInfo;
+use crate::ffi::ipc::req as ffi;
+use crate::ipc::errors::IpcError;
+use ffi_utils::{vec_into_raw_parts, ReprC};
+use safe_nd::{ADataAddress, ADataKind, ADataPubPermissionSet, ADataUnpubPermissionSet, XorName};
+use serde::{Deserialize, Serialize};
+use std::slice;
+
+/// Represents a request to share append-only data.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct ShareADataReq {
+    /// Info about the app requesting shared access.
+    pub app: AppExchangeInfo,
+    /// List of AData addresses and permissions that need to be shared.
+    pub adata: Vec<ShareAData>,
+}
+
+/// For use in `ShareADataReq`. Represents a specific `AppendOnlyData` that is being shared.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct ShareAData {
+    /// The append-only data address.
+    pub address: ADataAddress,
+    /// The permissions being requested.
+    pub perms: ShareADataPermissions,
+}
+
+/// Permissions requested for an `AppendOnlyData`.
+#[derive(Copy, Clone, Default, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct ShareADataPermissions {
+    /// Read the data. Ignored for published data, which can be read by anyone.
+    pub read: bool,
+    /// Append to the data.
+    pub append: bool,
+    /// Modify the permissions of the data.
+    pub manage_permissions: bool,
+}
+
+impl ShareADataPermissions {
+    /// Returns the permission set to grant for published data.
+    pub fn into_pub_permission_set(self) -> ADataPubPermissionSet {
+        ADataPubPermissionSet::new(self.append, self.manage_permissions)
+    }
+
+    /// Returns the permission set to grant for unpublished data.
+    pub fn into_unpub_permission_set(self) -> ADataUnpubPermissionSet {
+        ADataUnpubPermissionSet::new(self.read, self.append, self.manage_permissions)
+    }
+}
+
+impl ShareADataReq {
+    /// Construct FFI wrapper for the native Rust object, consuming self.
+    pub fn into_repr_c(self) -> Result<ffi::ShareADataRequest, IpcError> {
+        let adata_repr_c: Vec<_> = self
+            .adata
+            .into_iter()
+            .map(ShareAData::into_repr_c)
+            .collect();
+
+        let (adata, adata_len) = vec_into_raw_parts(adata_repr_c);
+
+        Ok(ffi::ShareADataRequest {
+            app: self.app.into_repr_c()?,
+            adata,
+            adata_len,
+        })
+    }
+}
+
+impl ReprC for ShareADataReq {
+    type C = *const ffi::ShareADataRequest;
+    type Error = IpcError;
+
+    unsafe fn clone_from_repr_c(repr_c: Self::C) -> Result<Self, Self::Error> {
+        Ok(Self {
+            app: AppExchangeInfo::clone_from_repr_c(&(*repr_c).app)?,
+            adata: {
+                let adata = slice::from_raw_parts((*repr_c).adata, (*repr_c).adata_len);
+                adata
+                    .iter()
+                    .map(|c| ShareAData::clone_from_repr_c(c))
+                    .collect::<Result<_, _>>()?
+            },
+        })
+    }
+}
+
+impl ShareAData {
+    /// Construct FFI wrapper for the native Rust object, consuming self.
+    pub fn into_repr_c(self) -> ffi::ShareAData {
+        let ShareADataPermissions {
+            read,
+            append,
+            manage_permissions,
+        } = self.perms;
+
+        ffi::ShareAData {
+            name: self.address.name().0,
+            type_tag: self.address.tag(),
+            published: self.address.is_pub(),
+            sequenced: self.address.is_seq(),
+            perms: ffi::ADataPermissionSet {
+                read,
+                append,
+                manage_permissions,
+            },
+        }
+    }
+}
+
+impl ReprC for ShareAData {
+    type C = *const ffi::ShareAData;
+    type Error = IpcError;
+
+    unsafe fn clone_from_repr_c(repr_c: Self::C) -> Result<Self, Self::Error> {
+        let ffi::ShareAData {
+            name,
+            type_tag,
+            published,
+            sequenced,
+            perms,
+        } = *repr_c;
+
+        let perms = ShareADataPermissions {
+            read: perms.read,
+            append: perms.append,
+            manage_permissions: perms.manage_permissions,
+        };
+        let granted = perms.append || perms.manage_permissions || (!published && perms.read);
+        if !granted {
+            return Err(IpcError::from("No permissions were provided"));
+        }
+
+        let kind = match (published, sequenced) {
+            (true, true) => ADataKind::PubSeq,
+            (true, false) => ADataKind::PubUnseq,
+            (false, true) => ADataKind::UnpubSeq,
+            (false, false) => ADataKind::UnpubUnseq,
+        };
+
+        Ok(Self {
+            address: ADataAddress::from_kind(kind, XorName(name), type_tag),
+            perms,
+        })
+    }
+}
@@ -0,0 +1,184 @@
+// Copyright 2019 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+use super::AppExchangeInfo;
+use crate::ffi::ipc::req as ffi;
+use crate::ipc::errors::IpcError;
+use ffi_utils::{vec_into_raw_parts, ReprC};
+use safe_nd::{ADataAddress, ADataKind, MDataAddress, PublicKey, XorName};
+use serde::{Deserialize, Serialize};
+use std::fmt::{self, Display, Formatter};
+use std::slice;
+
+/// Represents a request to transfer the ownership of data.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct TransOwnershipReq {
+    /// Info about the app requesting the transfer.
+    pub app: AppExchangeInfo,
+    /// List of data objects and their new owners.
+    pub data: Vec<TransOwnership>,
+}
+
+/// For use in `TransOwnershipReq`. Represents a specific data object whose owner should change.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct TransOwnership {
+    /// Address of the data.
+    pub address: OwnedData,
+    /// Key that should own the data.
+    pub new_owner: PublicKey,
+}
+
+/// Address of a data object that has an owner.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub enum OwnedData {
+    /// Mutable data.
+    MData(MDataAddress),
+    /// Append-only data.
+    AData(ADataAddress),
+}
+
+impl OwnedData {
+    /// Name of the data.
+    pub fn name(&self) -> &XorName {
+        match *self {
+            OwnedData::MData(ref address) => address.name(),
+            OwnedData::AData(ref address) => address.name(),
+        }
+    }
+
+    /// Type tag of the data.
+    pub fn tag(&self) -> u64 {
+        match *self {
+            OwnedData::MData(ref address) => address.tag(),
+            OwnedData::AData(ref address) => address.tag(),
+        }
+    }
+}
+
+impl Display for OwnedData {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        let kind = match *self {
+            OwnedData::MData(_) => "MutableData",
+            OwnedData::AData(ref address) if address.is_pub() => "published AppendOnlyData",
+            OwnedData::AData(_) => "AppendOnlyData",
+        };
+        write!(
+            formatter,
+            "{} {:?} with type tag {}",
+            kind,
+            self.name(),
+            self.tag()
+        )
+    }
+}
+
+impl TransOwnershipReq {
+    /// Construct FFI wrapper for the native Rust object, consuming self.
+    pub fn into_repr_c(self) -> Result<ffi::TransOwnershipRequest, IpcError> {
+        let data_repr_c: Vec<_> = self
+            .data
+            .into_iter()
+            .map(TransOwnership::into_repr_c)
+            .collect::<Result<_, _>>()?;
+
+        let (data, data_len) = vec_into_raw_parts(data_repr_c);
+
+        Ok(ffi::TransOwnershipRequest {
+            app: self.app.into_repr_c()?,
+            data,
+            data_len,
+        })
+    }
+}
+
+impl ReprC for TransOwnershipReq {
+    type C = *const ffi::TransOwnershipRequest;
+    type Error = IpcError;
+
+    unsafe fn clone_from_repr_c(repr_c: Self::C) -> Result<Self, Self::Error> {
+        Ok(Self {
+            app: AppExchangeInfo::clone_from_repr_c(&(*repr_c).app)?,
+            data: {
+                let data = slice::from_raw_parts((*repr_c).data, (*repr_c).data_len);
+                data.iter()
+                    .map(|c| TransOwnership::clone_from_repr_c(c))
+                    .collect::<Result<_, _>>()?
+            },
+        })
+    }
+}
+
+impl TransOwnership {
+    /// Construct FFI wrapper for the native Rust object, consuming self.
+    pub fn into_repr_c(self) -> Result<ffi::TransOwnership, IpcError> {
+        let (append_only, published, sequenced) = match self.address {
+            OwnedData::MData(address) => (false, false, address.is_seq()),
+            OwnedData::AData(address) => (true, address.is_pub(), address.is_seq()),
+        };
+
+        let new_owner = match self.new_owner {
+            PublicKey::Bls(key) => key.to_bytes(),
+            // TODO: FFI repr for PublicKey
+            _ => return Err(IpcError::from("Unsupported key type")),
+        };
+
+        Ok(ffi::TransOwnership {
+            name: self.address.name().0,
+            type_tag: self.address.tag(),
+            append_only,
+            published,
+            sequenced,
+            new_owner,
+        })
+    }
+}
+
+impl ReprC for TransOwnership {
+    type C = *const ffi::TransOwnership;
+    type Error = IpcError;
+
+    unsafe fn clone_from_repr_c(repr_c: Self::C) -> Result<Self, Self::Error> {
+        let ffi::TransOwnership {
+            name,
+            type_tag,
+            append_only,
+            published,
+            sequenced,
+            new_owner,
+        } = *repr_c;
+
+        let name = XorName(name);
+        let address = if append_only {
+            let kind = match (published, sequenced) {
+                (true, true) => ADataKind::PubSeq,
+                (true, false) => ADataKind::PubUnseq,
+                (false, true) => ADataKind::UnpubSeq,
+                (false, false) => ADataKind::UnpubUnseq,
+            };
+            OwnedData::AData(ADataAddress::from_kind(kind, name, type_tag))
+        } else if sequenced {
+            OwnedData::MData(MDataAddress::Seq {
+                name,
+                tag: type_tag,
+            })
+        } else {
+            OwnedData::MData(MDataAddress::Unseq {
+                name,
+                tag: type_tag,
+            })
+        };
+
+        Ok(Self {
+            address,
+            new_owner: PublicKey::from(
+                threshold_crypto::PublicKey::from_bytes(new_owner)
+                    .map_err(|_| IpcError::EncodeDecodeError)?,
+            ),
+        })
+    }
+}
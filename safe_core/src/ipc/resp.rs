@@ -22,7 +22,6 @@ use serde::{Deserialize, Serialize};
 use std::slice;
 
 /// IPC response.
-// TODO: `TransOwnership` variant
 #[allow(clippy::large_enum_variant)]
 #[derive(Debug, Eq, PartialEq, Serialize, Deserialize)]
 pub enum IpcResp {
@@ -34,6 +33,12 @@ pub enum IpcResp {
     Unregistered(Result<BootstrapConfig, IpcError>),
     /// Share mutable data.
     ShareMData(Result<(), IpcError>),
+    /// Share append-only data.
+    ShareAData(Result<(), IpcError>),
+    /// Transfer the ownership of data.
+    TransOwnership(Result<(), IpcError>),
+    /// Modified app permissions.
+    Permissions(Result<(), IpcError>),
 }
 
 /// It represents the authentication response.
@@ -0,0 +1,231 @@
+// Copyright 2019 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+use super::{IpcError, IpcMsg};
+use bincode::{deserialize, serialize};
+use safe_nd::{ClientFullId, PublicKey, Signature};
+use serde::{Deserialize, Serialize};
+use threshold_crypto::{Ciphertext, SecretKey};
+
+/// IPC response encrypted to the ephemeral key of the requesting app and signed by the
+/// authenticator.
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub struct SealedResp {
+    /// Request ID. Kept in the clear so that it can be checked before decrypting.
+    pub req_id: u32,
+    /// Public key of the signer.
+    pub signer: PublicKey,
+    /// Encrypted `IpcMsg::Resp`.
+    pub ciphertext: Ciphertext,
+    /// Signature over `req_id`, `signer` and `ciphertext`.
+    pub signature: Signature,
+}
+
+impl SealedResp {
+    /// Seal the `IpcMsg::Resp` to `recipient`, signing it with `signer`.
+    pub fn seal(
+        msg: &IpcMsg,
+        recipient: &threshold_crypto::PublicKey,
+        signer: &ClientFullId,
+    ) -> Result<Self, IpcError> {
+        let req_id = match *msg {
+            IpcMsg::Resp { req_id, .. } => req_id,
+            _ => return Err(IpcError::InvalidMsg),
+        };
+
+        let ciphertext = recipient.encrypt(serialize(msg)?);
+        let signer_key = *signer.public_id().public_key();
+        let signature = signer.sign(&serialize(&(req_id, &signer_key, &ciphertext))?);
+
+        Ok(Self {
+            req_id,
+            signer: signer_key,
+            ciphertext,
+            signature,
+        })
+    }
+
+    /// Verify and decrypt the sealed response to the request with the given ID.
+    ///
+    /// If `signer` is given, the response must be signed by it: the key of the account the app
+    /// expects to answer, pinned from an earlier response or given to the app out of band.
+    /// Otherwise the signer is trusted on first use: the response only has to be signed by the key
+    /// it names in `self.signer`, which the app should pin for later responses. As anyone can seal
+    /// a response to the public `AuthReq::response_key`, this only holds when the first response
+    /// comes straight from the authenticator. Responses to any other request are rejected, so old
+    /// responses can't be replayed.
+    pub fn open(
+        &self,
+        secret_key: &SecretKey,
+        expected_req_id: u32,
+        signer: Option<&PublicKey>,
+    ) -> Result<IpcMsg, IpcError> {
+        if self.req_id != expected_req_id {
+            return Err(IpcError::ReqIdMismatch);
+        }
+        if signer.map_or(false, |signer| self.signer != *signer) {
+            return Err(IpcError::InvalidSignature);
+        }
+
+        let signed = serialize(&(self.req_id, &self.signer, &self.ciphertext))?;
+        self.signer
+            .verify(&self.signature, &signed)
+            .map_err(|_| IpcError::InvalidSignature)?;
+
+        let plaintext = secret_key
+            .decrypt(&self.ciphertext)
+            .ok_or(IpcError::EncodeDecodeError)?;
+        let msg: IpcMsg = deserialize(&plaintext)?;
+
+        match msg {
+            IpcMsg::Resp { req_id, .. } if req_id != self.req_id => Err(IpcError::ReqIdMismatch),
+            IpcMsg::Resp { .. } => Ok(msg),
+            _ => Err(IpcError::InvalidMsg),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crypto::shared_box;
+    use crate::ipc::{decode_sealed_msg, encode_msg, IpcResp};
+    use crate::utils::test_utils::gen_client_id;
+    use unwrap::unwrap;
+
+    fn resp(req_id: u32) -> IpcMsg {
+        IpcMsg::Resp {
+            req_id,
+            response: IpcResp::Containers(Ok(())),
+        }
+    }
+
+    // Test that a sealed response can only be opened by the recipient for the right request.
+    #[test]
+    fn seal_and_open() {
+        let signer = gen_client_id();
+        let (public_key, secret_key) = shared_box::gen_keypair();
+        let (_, other_secret_key) = shared_box::gen_keypair();
+
+        let sealed = unwrap!(SealedResp::seal(&resp(1), &public_key, &signer));
+        assert_eq!(sealed.signer, *signer.public_id().public_key());
+
+        let signer_key = *signer.public_id().public_key();
+        assert_eq!(
+            unwrap!(sealed.open(&secret_key, 1, Some(&signer_key))),
+            resp(1)
+        );
+        assert_eq!(unwrap!(sealed.open(&secret_key, 1, None)), resp(1));
+        assert_eq!(
+            sealed.open(&secret_key, 2, Some(&signer_key)),
+            Err(IpcError::ReqIdMismatch)
+        );
+        assert_eq!(
+            sealed.open(&other_secret_key, 1, Some(&signer_key)),
+            Err(IpcError::EncodeDecodeError)
+        );
+    }
+
+    // Test that tampering with a sealed response invalidates its signature.
+    #[test]
+    fn tampered_sealed_resp() {
+        let signer = gen_client_id();
+        let signer_key = *signer.public_id().public_key();
+        let (public_key, secret_key) = shared_box::gen_keypair();
+
+        // Replace the ciphertext with one for a different request.
+        let mut sealed = unwrap!(SealedResp::seal(&resp(1), &public_key, &signer));
+        sealed.ciphertext = public_key.encrypt(unwrap!(serialize(&resp(2))));
+        assert_eq!(
+            sealed.open(&secret_key, 1, Some(&signer_key)),
+            Err(IpcError::InvalidSignature)
+        );
+
+        // Replace the signer with someone else.
+        let mut sealed = unwrap!(SealedResp::seal(&resp(1), &public_key, &signer));
+        sealed.signer = *gen_client_id().public_id().public_key();
+        assert_eq!(
+            sealed.open(&secret_key, 1, Some(&signer_key)),
+            Err(IpcError::InvalidSignature)
+        );
+        // The signature is checked against the claimed signer even if none is pinned yet.
+        assert_eq!(
+            sealed.open(&secret_key, 1, None),
+            Err(IpcError::InvalidSignature)
+        );
+
+        // Anyone can seal a response to the public response key, but it's rejected unless it's
+        // signed by the expected account.
+        let forger = gen_client_id();
+        let forged = unwrap!(SealedResp::seal(&resp(1), &public_key, &forger));
+        assert_eq!(
+            forged.open(&secret_key, 1, Some(&signer_key)),
+            Err(IpcError::InvalidSignature)
+        );
+
+        // Only responses can be sealed.
+        let revoked = IpcMsg::Revoked {
+            app_id: "app".to_string(),
+        };
+        match SealedResp::seal(&revoked, &public_key, &signer) {
+            Err(IpcError::InvalidMsg) => (),
+            x => panic!("Unexpected {:?}", x),
+        }
+    }
+
+    // Test that sealed responses are opened, while unsealed responses are rejected as they may
+    // have been sent by anyone.
+    #[test]
+    fn decode_sealed_and_legacy() {
+        let signer = gen_client_id();
+        let signer_key = *signer.public_id().public_key();
+        let (public_key, secret_key) = shared_box::gen_keypair();
+
+        let sealed = unwrap!(SealedResp::seal(&resp(1), &public_key, &signer));
+        let encoded = unwrap!(encode_msg(&IpcMsg::Sealed(sealed)));
+        assert_eq!(
+            unwrap!(decode_sealed_msg(
+                &encoded,
+                &secret_key,
+                1,
+                Some(&signer_key)
+            )),
+            (resp(1), Some(signer_key))
+        );
+        // Without a pinned signer, the signer of the response is returned to be pinned.
+        assert_eq!(
+            unwrap!(decode_sealed_msg(&encoded, &secret_key, 1, None)),
+            (resp(1), Some(signer_key))
+        );
+        assert_eq!(
+            decode_sealed_msg(&encoded, &secret_key, 2, Some(&signer_key)),
+            Err(IpcError::ReqIdMismatch)
+        );
+
+        let encoded = unwrap!(encode_msg(&resp(1)));
+        assert_eq!(
+            decode_sealed_msg(&encoded, &secret_key, 1, Some(&signer_key)),
+            Err(IpcError::InvalidMsg)
+        );
+
+        // Revocation notices aren't responses, so they aren't sealed.
+        let revoked = IpcMsg::Revoked {
+            app_id: "app".to_string(),
+        };
+        let encoded = unwrap!(encode_msg(&revoked));
+        assert_eq!(
+            unwrap!(decode_sealed_msg(
+                &encoded,
+                &secret_key,
+                1,
+                Some(&signer_key)
+            )),
+            (revoked, None)
+        );
+    }
+}
@@ -36,6 +36,7 @@
 // Export FFI interface.
 
 pub use ffi::arrays::*;
+pub use ffi::coins::*;
 pub use ffi::ipc::req::*;
 pub use ffi::ipc::resp::*;
 pub use ffi::nfs::*;
@@ -9,14 +9,15 @@
 use crate::client::{Client, MDataInfo};
 use crate::crypto::shared_secretbox;
 use crate::errors::CoreError;
-use crate::nfs::{File, Mode, NfsError, NfsFuture, Reader, Writer};
+use crate::immutable_data;
+use crate::nfs::{data_map, File, Mode, NfsError, NfsFuture, Reader, Writer};
 use crate::self_encryption_storage::SelfEncryptionStorage;
 use crate::utils::FutureExt;
 use crate::{fry, ok};
 use bincode::{deserialize, serialize};
 use futures::{Future, IntoFuture};
 use log::trace;
-use safe_nd::{Error as SndError, MDataSeqEntryActions};
+use safe_nd::{Error as SndError, MDataSeqEntryActions, XorName};
 use serde::{Deserialize, Serialize};
 
 /// Enum specifying which version should be used in places where a version is required.
@@ -93,6 +94,30 @@ pub fn read<C: Client>(
     )
 }
 
+/// Return the names of the immutable data holding the file, i.e. its data map and the chunks of
+/// its contents.
+pub fn data_names<C: Client>(
+    client: C,
+    file: &File,
+    encryption_key: Option<shared_secretbox::Key>,
+) -> Box<NfsFuture<Vec<XorName>>> {
+    let address = file.data_address();
+    let client2 = client.clone();
+    let encryption_key2 = encryption_key.clone();
+
+    client
+        .get_idata(address)
+        .and_then(move |data| immutable_data::chunk_names(&client2, &data, encryption_key2))
+        .map_err(NfsError::from)
+        .join(data_map::get(&client, address, encryption_key))
+        .map(move |(mut names, data_map)| {
+            names.push(*address.name());
+            names.extend(immutable_data::data_map_chunk_names(&data_map));
+            names
+        })
+        .into_box()
+}
+
 /// Delete a file from the directory.
 ///
 /// If `version` is `Version::GetNext`, the current version is first retrieved from the network, and
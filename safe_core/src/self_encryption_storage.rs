@@ -12,8 +12,10 @@ use futures::{self, Future};
 use log::trace;
 use safe_nd::{IData, IDataAddress, PubImmutableData, UnpubImmutableData, XorName, XOR_NAME_LEN};
 use self_encryption::{Storage, StorageError};
+use std::cell::RefCell;
 use std::error::Error;
 use std::fmt::{self, Display, Formatter};
+use std::rc::Rc;
 
 /// Network storage is the concrete type which self-encryption crate will use
 /// to put or get data from the network.
@@ -122,12 +124,37 @@ impl StorageError for SEStorageError {}
 pub struct SelfEncryptionStorageDryRun<C: Client> {
     client: C,
     published: bool,
+    // Chunks that would have been stored, if recording was requested.
+    chunks: Option<Rc<RefCell<Vec<IData>>>>,
 }
 
 impl<C: Client> SelfEncryptionStorageDryRun<C> {
     /// Create a new SelfEncryptionStorage instance.
     pub fn new(client: C, published: bool) -> Self {
-        Self { client, published }
+        Self {
+            client,
+            published,
+            chunks: None,
+        }
+    }
+
+    /// Create a new instance which keeps the chunks that would have been stored, so they can be
+    /// retrieved with `chunks`.
+    pub fn recording(client: C, published: bool) -> Self {
+        Self {
+            client,
+            published,
+            chunks: Some(Default::default()),
+        }
+    }
+
+    /// Returns the chunks that would have been stored so far. Always empty unless this instance
+    /// was created with `recording`.
+    pub fn chunks(&self) -> Vec<IData> {
+        self.chunks
+            .as_ref()
+            .map(|chunks| chunks.borrow().clone())
+            .unwrap_or_default()
     }
 }
 
@@ -144,11 +171,19 @@ impl<C: Client> Storage for SelfEncryptionStorageDryRun<C> {
     fn put(
         &mut self,
         _: Vec<u8>,
-        _data: Vec<u8>,
+        data: Vec<u8>,
     ) -> Box<dyn Future<Item = (), Error = Self::Error>> {
         trace!("Self encrypt invoked PutIData dry run.");
-        // We do nothing here just return ok so self-encrpytion can finish
+        // We don't store anything here, just return ok so self-encrpytion can finish
         // and generate chunk addresses and datamap if required
+        if let Some(ref chunks) = self.chunks {
+            let immutable_data: IData = if self.published {
+                PubImmutableData::new(data).into()
+            } else {
+                UnpubImmutableData::new(data, self.client.public_key()).into()
+            };
+            chunks.borrow_mut().push(immutable_data);
+        }
         ok!(())
     }
 
@@ -16,6 +16,7 @@ mod futures;
 
 pub use self::futures::FutureExt;
 
+use crate::crypto::{self, SecretBytes};
 use crate::errors::CoreError;
 use bincode::{deserialize, serialize};
 use log::error;
@@ -184,17 +185,39 @@ where
         .collect()
 }
 
-/// Derive Password, Keyword and PIN (in order).
-pub fn derive_secrets(acc_locator: &[u8], acc_password: &[u8]) -> (Vec<u8>, Vec<u8>, Vec<u8>) {
-    let locator_hash = sha3_512(acc_locator);
-
-    let pin = sha3_512(&locator_hash[SHA3_512_HASH_LEN / 2..]).to_vec();
-    let keyword = locator_hash.to_vec();
-    let password = sha3_512(acc_password).to_vec();
+/// Derive Password, Keyword and PIN (in order). They're wiped from memory when dropped.
+pub fn derive_secrets(
+    acc_locator: &[u8],
+    acc_password: &[u8],
+) -> (SecretBytes, SecretBytes, SecretBytes) {
+    let (keyword, pin) = derive_locator_secrets(acc_locator);
+    let password = derive_password_secret(acc_password);
 
     (password, keyword, pin)
 }
 
+/// Derive the keyword and the PIN from the account locator, as returned by `derive_secrets`.
+pub fn derive_locator_secrets(acc_locator: &[u8]) -> (SecretBytes, SecretBytes) {
+    let mut locator_hash = sha3_512(acc_locator);
+    let mut pin_hash = sha3_512(&locator_hash[SHA3_512_HASH_LEN / 2..]);
+
+    let pin = SecretBytes::from(&pin_hash[..]);
+    let keyword = SecretBytes::from(&locator_hash[..]);
+    crypto::wipe(&mut pin_hash);
+    crypto::wipe(&mut locator_hash);
+
+    (keyword, pin)
+}
+
+/// Derive the password secret from the account password, as returned by `derive_secrets`.
+pub fn derive_password_secret(acc_password: &[u8]) -> SecretBytes {
+    let mut password_hash = sha3_512(acc_password);
+    let password = SecretBytes::from(&password_hash[..]);
+    crypto::wipe(&mut password_hash);
+
+    password
+}
+
 /// Convert binary data to a diplay-able format
 #[inline]
 pub fn bin_data_format(data: &[u8]) -> String {
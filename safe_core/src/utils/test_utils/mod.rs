@@ -12,6 +12,7 @@ mod sync;
 #[cfg(feature = "mock-network")]
 pub use self::sync::Synchronizer;
 
+use crate::client::account::Kdf;
 use crate::client::core_client::CoreClient;
 use crate::client::{Client, COST_OF_PUT};
 use crate::event_loop::{self, CoreMsg, CoreMsgTx};
@@ -28,6 +29,14 @@ use std::sync::mpsc as std_mpsc;
 use tokio::runtime::current_thread::{Handle, Runtime};
 use unwrap::unwrap;
 
+/// Cheap key derivation function for the accounts created by tests, which would otherwise spend
+/// most of their time deriving keys. Never use it for real accounts.
+pub const TEST_KDF: Kdf = Kdf::Argon2id {
+    mem_cost_kib: 64,
+    time_cost: 1,
+    lanes: 1,
+};
+
 /// Generates a random BLS secret and public keypair.
 pub fn gen_bls_keypair() -> Keypair {
     let mut rng = rand::thread_rng();
@@ -66,7 +75,7 @@ where
     let c = |el_h, core_tx, net_tx| {
         let acc_locator = unwrap!(utils::generate_random_string(10));
         let acc_password = unwrap!(utils::generate_random_string(10));
-        CoreClient::new(&acc_locator, &acc_password, el_h, core_tx, net_tx)
+        CoreClient::new(&acc_locator, &acc_password, TEST_KDF, el_h, core_tx, net_tx)
     };
     setup_client_with_net_obs(&(), c, n, r)
 }
@@ -633,6 +633,8 @@ fn ffi_authorise_app(auth_h: *mut Authenticator, app_info: &AppExchangeInfo) ->
             get_balance: true,
         },
         containers: create_containers_req(),
+        response_key: None,
+        ttl: None,
     };
     let ffi_auth_req = unwrap!(auth_req.clone().into_repr_c());
 